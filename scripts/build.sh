@@ -15,6 +15,8 @@ echo "==> Building all contracts in release mode..."
 cargo build \
     --release \
     --target wasm32-unknown-unknown \
+    --workspace \
+    --exclude sysbreak-integration-tests \
     --manifest-path "$WORKSPACE_DIR/Cargo.toml"
 
 WASM_DIR="$WORKSPACE_DIR/target/wasm32-unknown-unknown/release"
@@ -24,6 +26,9 @@ CONTRACTS=(
     "sysbreak_achievement_nft"
     "sysbreak_credit_bridge"
     "sysbreak_corporation_dao"
+    "sysbreak_marketplace"
+    "sysbreak_staking"
+    "sysbreak_escrow_vesting"
 )
 
 for contract in "${CONTRACTS[@]}"; do
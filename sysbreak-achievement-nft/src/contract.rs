@@ -1,13 +1,21 @@
+use std::collections::BTreeSet;
+
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
-    Timestamp, WasmMsg,
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, HexBinary, MessageInfo, Order, Reply,
+    Response, StdResult, Storage, SubMsg, SubMsgResult, Timestamp, WasmMsg,
 };
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::Map;
+use sysbreak_common::reject_funds;
+use sysbreak_events::{BurnEvent, MintEvent, TransferEvent};
 
 use crate::error::ContractError;
 use crate::helpers::{
-    assert_minter, assert_not_paused, assert_not_soulbound, assert_owner, is_authorized,
-    reject_funds,
+    assert_authorized_contract, assert_category_registered, assert_minter, assert_not_paused, assert_not_revoked,
+    assert_not_soulbound, assert_owner, attestation_domain, attestation_hash, attestation_payload,
+    claim_leaf, clear_approval, count_token, count_unbind, counted_in_stats, is_authorized,
+    live_approval, move_count, move_holding, uncount_token, validate_category, validate_pubkey,
+    verify_merkle_proof,
 };
 use crate::msg::*;
 use crate::state::*;
@@ -15,6 +23,11 @@ use crate::state::*;
 const CONTRACT_NAME: &str = "crates.io:sysbreak-achievement-nft";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const MAX_BATCH_SIZE: u32 = 25;
+const MAX_MINT_TO_MANY: u32 = 200;
+const MAX_BURN_BATCH_SIZE: u32 = 100;
+const DEFAULT_BACKFILL_LIMIT: u32 = 500;
+const MAX_BACKFILL_LIMIT: u32 = 5_000;
+
 const DEFAULT_QUERY_LIMIT: u32 = 30;
 const MAX_QUERY_LIMIT: u32 = 100;
 
@@ -33,25 +46,29 @@ pub fn instantiate(
 
     let config = Config {
         owner,
-        minter,
         paused: false,
         name: msg.name,
         symbol: msg.symbol,
+        attestation_signer: None,
+        notify_contract: None,
     };
     CONFIG.save(deps.storage, &config)?;
+    MINTERS.save(deps.storage, &minter, &true)?;
     TOKEN_COUNT.save(deps.storage, &0u64)?;
+    COLLECTION_TOTALS.save(deps.storage, &CollectionTotals::default())?;
+    CATEGORIES_SEEDED.save(deps.storage, &true)?;
 
     Ok(Response::new()
         .add_attribute("action", "instantiate")
         .add_attribute("contract", CONTRACT_NAME)
         .add_attribute("owner", config.owner.as_str())
-        .add_attribute("minter", config.minter.as_str()))
+        .add_attribute("minter", minter.as_str()))
 }
 
 // ─── Execute: Minting ───────────────────────────────────────────────────────
 
 pub fn execute_mint(
-    deps: DepsMut,
+    mut deps: DepsMut,
     _env: Env,
     info: MessageInfo,
     to: String,
@@ -62,13 +79,14 @@ pub fn execute_mint(
     rarity: String,
     token_uri: Option<String>,
     soulbound: bool,
+    tier: Option<u8>,
 ) -> Result<Response, ContractError> {
     assert_not_paused(deps.as_ref())?;
     assert_minter(deps.as_ref(), &info.sender)?;
 
     let recipient = deps.api.addr_validate(&to)?;
     let token_id = mint_single(
-        deps,
+        deps.branch(),
         &recipient,
         achievement_id.clone(),
         category,
@@ -77,14 +95,58 @@ pub fn execute_mint(
         rarity,
         token_uri,
         soulbound,
+        tier.unwrap_or_default(),
     )?;
+    let hook = mint_hook(deps.as_ref(), &recipient, &achievement_id, &token_id)?;
 
     Ok(Response::new()
+        .add_submessages(hook)
         .add_attribute("action", "mint")
-        .add_attribute("token_id", &token_id)
-        .add_attribute("to", recipient.as_str())
         .add_attribute("achievement_id", &achievement_id)
-        .add_attribute("soulbound", soulbound.to_string()))
+        .add_attribute("soulbound", soulbound.to_string())
+        .add_event(MintEvent {
+            token_id,
+            owner: recipient.to_string(),
+            minter: info.sender.to_string(),
+        }))
+}
+
+/// Mint triggered by another contract, e.g. a corporation DAO proposal that
+/// awards "Corporation Founded" to its founder
+pub fn execute_mint_for(
+    mut deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    mint: MintRequest,
+) -> Result<Response, ContractError> {
+    assert_not_paused(deps.as_ref())?;
+    assert_authorized_contract(deps.as_ref(), &info.sender)?;
+
+    let recipient = deps.api.addr_validate(&mint.to)?;
+    let token_id = mint_single(
+        deps.branch(),
+        &recipient,
+        mint.achievement_id.clone(),
+        mint.category,
+        mint.earned_at,
+        mint.description,
+        mint.rarity,
+        mint.token_uri,
+        mint.soulbound,
+        mint.tier.unwrap_or_default(),
+    )?;
+    let hook = mint_hook(deps.as_ref(), &recipient, &mint.achievement_id, &token_id)?;
+
+    Ok(Response::new()
+        .add_submessages(hook)
+        .add_attribute("action", "mint_for")
+        .add_attribute("achievement_id", &mint.achievement_id)
+        .add_attribute("soulbound", mint.soulbound.to_string())
+        .add_event(MintEvent {
+            token_id,
+            owner: recipient.to_string(),
+            minter: info.sender.to_string(),
+        }))
 }
 
 pub fn execute_batch_mint(
@@ -111,7 +173,8 @@ pub fn execute_batch_mint(
         .map(|m| Ok((deps.api.addr_validate(&m.to)?, m)))
         .collect::<Result<Vec<_>, ContractError>>()?;
 
-    let mut token_ids = Vec::with_capacity(validated.len());
+    let mut events = Vec::with_capacity(validated.len());
+    let mut hooks = vec![];
     for (recipient, req) in validated {
         let token_id = mint_single(
             deps.branch(),
@@ -123,15 +186,125 @@ pub fn execute_batch_mint(
             req.rarity.clone(),
             req.token_uri.clone(),
             req.soulbound,
+            req.tier.unwrap_or_default(),
         )?;
-        token_ids.push(token_id);
+        hooks.extend(mint_hook(
+            deps.as_ref(),
+            &recipient,
+            &req.achievement_id,
+            &token_id,
+        )?);
+        events.push(MintEvent {
+            token_id,
+            owner: recipient.to_string(),
+            minter: info.sender.to_string(),
+        });
     }
 
     Ok(Response::new()
+        .add_submessages(hooks)
         .add_attribute("action", "batch_mint")
-        .add_attribute("count", token_ids.len().to_string())
-        .add_attribute("first_token_id", &token_ids[0])
-        .add_attribute("last_token_id", &token_ids[token_ids.len() - 1]))
+        .add_attribute("count", events.len().to_string())
+        .add_events(events))
+}
+
+/// Grant the same achievement to many players (minter only). Recipients who
+/// already hold it are skipped rather than failing the batch.
+pub fn execute_mint_to_many(
+    mut deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    achievement_id: String,
+    category: String,
+    rarity: String,
+    description: String,
+    earned_at: Timestamp,
+    soulbound: bool,
+    recipients: Vec<String>,
+) -> Result<Response, ContractError> {
+    assert_not_paused(deps.as_ref())?;
+    assert_minter(deps.as_ref(), &info.sender)?;
+
+    if recipients.is_empty() {
+        return Err(ContractError::EmptyBatch);
+    }
+    if recipients.len() as u32 > MAX_MINT_TO_MANY {
+        return Err(ContractError::BatchTooLarge {
+            max: MAX_MINT_TO_MANY,
+        });
+    }
+    assert_category_registered(deps.as_ref(), &category)?;
+
+    let recipients = recipients
+        .iter()
+        .map(|r| deps.api.addr_validate(r))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut events = Vec::with_capacity(recipients.len());
+    let mut hooks = vec![];
+    let mut skipped = 0u32;
+    for recipient in recipients {
+        if ACHIEVEMENT_INDEX.has(deps.storage, (&recipient, &achievement_id)) {
+            skipped += 1;
+            continue;
+        }
+        let token_id = mint_single(
+            deps.branch(),
+            &recipient,
+            achievement_id.clone(),
+            category.clone(),
+            earned_at,
+            description.clone(),
+            rarity.clone(),
+            None,
+            soulbound,
+            0,
+        )?;
+        hooks.extend(mint_hook(
+            deps.as_ref(),
+            &recipient,
+            &achievement_id,
+            &token_id,
+        )?);
+        events.push(MintEvent {
+            token_id,
+            owner: recipient.to_string(),
+            minter: info.sender.to_string(),
+        });
+    }
+
+    Ok(Response::new()
+        .add_submessages(hooks)
+        .add_attribute("action", "mint_to_many")
+        .add_attribute("achievement_id", achievement_id)
+        .add_attribute("count", events.len().to_string())
+        .add_attribute("skipped", skipped.to_string())
+        .add_events(events))
+}
+
+/// Hook message for the configured notify contract, if any. Failures come back
+/// through `reply` instead of reverting the mint.
+fn mint_hook(
+    deps: Deps,
+    owner: &Addr,
+    achievement_id: &str,
+    token_id: &str,
+) -> StdResult<Option<SubMsg>> {
+    let Some(notify_contract) = CONFIG.load(deps.storage)?.notify_contract else {
+        return Ok(None);
+    };
+    let hook = WasmMsg::Execute {
+        contract_addr: notify_contract.to_string(),
+        msg: to_json_binary(&AchievementHookMsg::AchievementMintedHook {
+            owner: owner.to_string(),
+            achievement_id: achievement_id.to_string(),
+            token_id: token_id.to_string(),
+        })?,
+        funds: vec![],
+    };
+    Ok(Some(
+        SubMsg::reply_on_error(hook, NOTIFY_REPLY_ID).with_gas_limit(NOTIFY_GAS_LIMIT),
+    ))
 }
 
 /// Atomic check-and-mint: deduplication + token creation in a single call.
@@ -145,7 +318,14 @@ fn mint_single(
     rarity: String,
     token_uri: Option<String>,
     soulbound: bool,
+    tier: u8,
 ) -> Result<String, ContractError> {
+    // Ids are only safe to issue once LAST_TOKEN_ID has been rebuilt
+    if BACKFILL.may_load(deps.storage)?.is_some_and(|p| !p.done) {
+        return Err(ContractError::BackfillInProgress);
+    }
+    assert_category_registered(deps.as_ref(), &category)?;
+
     // Dedup check: same achievement_id cannot be minted twice to the same address
     if ACHIEVEMENT_INDEX
         .may_load(deps.storage, (recipient, &achievement_id))?
@@ -157,9 +337,9 @@ fn mint_single(
         });
     }
 
-    let mut count = TOKEN_COUNT.load(deps.storage)?;
-    count += 1;
-    let token_id = count.to_string();
+    let count = TOKEN_COUNT.load(deps.storage)? + 1;
+    let id = LAST_TOKEN_ID.may_load(deps.storage)?.unwrap_or_default() + 1;
+    let token_id = id.to_string();
 
     let data = TokenData {
         owner: recipient.clone(),
@@ -169,25 +349,140 @@ fn mint_single(
             earned_at,
             description,
             rarity,
+            tier,
         },
         token_uri,
         soulbound,
+        revoked: None,
     };
 
     TOKENS.save(deps.storage, &token_id, &data)?;
     ACHIEVEMENT_INDEX.save(deps.storage, (recipient, &achievement_id), &token_id)?;
+    ACHIEVEMENT_OWNERS.save(deps.storage, (&achievement_id, recipient), &token_id)?;
     // FIX: M-06 — maintain owner index for efficient queries
     OWNER_TOKENS.save(deps.storage, (recipient, &token_id), &true)?;
+    OWNER_CATEGORY_TOKENS.save(
+        deps.storage,
+        (recipient, &data.metadata.category, &token_id),
+        &true,
+    )?;
+    count_token(deps.storage, &data)?;
     TOKEN_COUNT.save(deps.storage, &count)?;
+    LAST_TOKEN_ID.save(deps.storage, &id)?;
 
     Ok(token_id)
 }
 
+// ─── Execute: Merkle Claims ─────────────────────────────────────────────────
+
+pub fn execute_set_claim_root(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    campaign_id: String,
+    root: HexBinary,
+    expiry: Timestamp,
+    metadata: ClaimMetadata,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if root.len() != 32 {
+        return Err(ContractError::InvalidMerkleRoot);
+    }
+    if expiry <= env.block.time {
+        return Err(ContractError::InvalidExpiry);
+    }
+    if CLAIM_CAMPAIGNS.has(deps.storage, &campaign_id) {
+        return Err(ContractError::CampaignExists { campaign_id });
+    }
+
+    CLAIM_CAMPAIGNS.save(
+        deps.storage,
+        &campaign_id,
+        &ClaimCampaign {
+            root: root.clone(),
+            expiry,
+            metadata,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_claim_root")
+        .add_attribute("campaign_id", &campaign_id)
+        .add_attribute("root", root.to_hex())
+        .add_attribute("expiry", expiry.seconds().to_string()))
+}
+
+/// Player mints their own achievement from a campaign. earned_at is the claim
+/// block time; the usual per-owner dedup still applies.
+pub fn execute_claim(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    campaign_id: String,
+    achievement_id: String,
+    proof: Vec<HexBinary>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    let campaign = CLAIM_CAMPAIGNS
+        .may_load(deps.storage, &campaign_id)?
+        .ok_or_else(|| ContractError::CampaignNotFound {
+            campaign_id: campaign_id.clone(),
+        })?;
+    if env.block.time >= campaign.expiry {
+        return Err(ContractError::CampaignExpired { campaign_id });
+    }
+    if CLAIMED.has(deps.storage, (&campaign_id, &info.sender, &achievement_id)) {
+        return Err(ContractError::AlreadyClaimed {
+            campaign_id,
+            achievement_id,
+        });
+    }
+    let leaf = claim_leaf(&info.sender, &achievement_id);
+    if !verify_merkle_proof(&campaign.root, leaf, &proof) {
+        return Err(ContractError::InvalidProof);
+    }
+
+    CLAIMED.save(
+        deps.storage,
+        (&campaign_id, &info.sender, &achievement_id),
+        &true,
+    )?;
+    let metadata = campaign.metadata;
+    let token_id = mint_single(
+        deps.branch(),
+        &info.sender,
+        achievement_id.clone(),
+        metadata.category,
+        env.block.time,
+        metadata.description,
+        metadata.rarity,
+        metadata.token_uri,
+        metadata.soulbound,
+        0,
+    )?;
+    let hook = mint_hook(deps.as_ref(), &info.sender, &achievement_id, &token_id)?;
+
+    Ok(Response::new()
+        .add_submessages(hook)
+        .add_attribute("action", "claim")
+        .add_attribute("campaign_id", &campaign_id)
+        .add_attribute("achievement_id", &achievement_id)
+        .add_event(MintEvent {
+            token_id,
+            owner: info.sender.to_string(),
+            minter: info.sender.to_string(),
+        }))
+}
+
 // ─── Execute: Transfers (soulbound enforcement) ─────────────────────────────
 
 pub fn execute_transfer_nft(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     recipient: String,
     token_id: String,
@@ -196,8 +491,9 @@ pub fn execute_transfer_nft(
     assert_not_paused(deps.as_ref())?;
     // Soulbound check MUST happen before any authorization check
     assert_not_soulbound(deps.as_ref(), &token_id)?;
+    assert_not_revoked(deps.as_ref(), &token_id)?;
 
-    if !is_authorized(deps.as_ref(), &token_id, &info.sender)? {
+    if !is_authorized(deps.as_ref(), &env.block, &token_id, &info.sender)? {
         return Err(ContractError::Unauthorized {
             role: "owner or approved".to_string(),
         });
@@ -217,24 +513,44 @@ pub fn execute_transfer_nft(
         (&new_owner, &token.metadata.achievement_id),
         &token_id,
     )?;
+    ACHIEVEMENT_OWNERS.remove(deps.storage, (&token.metadata.achievement_id, &old_owner));
+    ACHIEVEMENT_OWNERS.save(
+        deps.storage,
+        (&token.metadata.achievement_id, &new_owner),
+        &token_id,
+    )?;
     // FIX: M-06 — update owner index
     OWNER_TOKENS.remove(deps.storage, (&old_owner, &token_id));
     OWNER_TOKENS.save(deps.storage, (&new_owner, &token_id), &true)?;
+    OWNER_CATEGORY_TOKENS.remove(
+        deps.storage,
+        (&old_owner, &token.metadata.category, &token_id),
+    );
+    OWNER_CATEGORY_TOKENS.save(
+        deps.storage,
+        (&new_owner, &token.metadata.category, &token_id),
+        &true,
+    )?;
+    if counted_in_stats(deps.storage, &token_id)? {
+        move_holding(deps.storage, &old_owner, &new_owner)?;
+    }
 
     token.owner = new_owner.clone();
     TOKENS.save(deps.storage, &token_id, &token)?;
-    TOKEN_APPROVALS.remove(deps.storage, &token_id);
+    clear_approval(deps.storage, &token_id);
 
     Ok(Response::new()
         .add_attribute("action", "transfer_nft")
-        .add_attribute("token_id", &token_id)
-        .add_attribute("from", old_owner.as_str())
-        .add_attribute("to", new_owner.as_str()))
+        .add_event(TransferEvent {
+            token_id,
+            from: old_owner.to_string(),
+            to: new_owner.to_string(),
+        }))
 }
 
 pub fn execute_send_nft(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     contract: String,
     token_id: String,
@@ -243,8 +559,9 @@ pub fn execute_send_nft(
     reject_funds(&info)?; // FIX: M-08
     assert_not_paused(deps.as_ref())?;
     assert_not_soulbound(deps.as_ref(), &token_id)?;
+    assert_not_revoked(deps.as_ref(), &token_id)?;
 
-    if !is_authorized(deps.as_ref(), &token_id, &info.sender)? {
+    if !is_authorized(deps.as_ref(), &env.block, &token_id, &info.sender)? {
         return Err(ContractError::Unauthorized {
             role: "owner or approved".to_string(),
         });
@@ -264,13 +581,31 @@ pub fn execute_send_nft(
         (&contract_addr, &token.metadata.achievement_id),
         &token_id,
     )?;
+    ACHIEVEMENT_OWNERS.remove(deps.storage, (&token.metadata.achievement_id, &old_owner));
+    ACHIEVEMENT_OWNERS.save(
+        deps.storage,
+        (&token.metadata.achievement_id, &contract_addr),
+        &token_id,
+    )?;
     // FIX: M-06 — update owner index
     OWNER_TOKENS.remove(deps.storage, (&old_owner, &token_id));
     OWNER_TOKENS.save(deps.storage, (&contract_addr, &token_id), &true)?;
+    OWNER_CATEGORY_TOKENS.remove(
+        deps.storage,
+        (&old_owner, &token.metadata.category, &token_id),
+    );
+    OWNER_CATEGORY_TOKENS.save(
+        deps.storage,
+        (&contract_addr, &token.metadata.category, &token_id),
+        &true,
+    )?;
+    if counted_in_stats(deps.storage, &token_id)? {
+        move_holding(deps.storage, &old_owner, &contract_addr)?;
+    }
 
     token.owner = contract_addr.clone();
     TOKENS.save(deps.storage, &token_id, &token)?;
-    TOKEN_APPROVALS.remove(deps.storage, &token_id);
+    clear_approval(deps.storage, &token_id);
 
     let callback = cw721::receiver::Cw721ReceiveMsg {
         sender: info.sender.to_string(),
@@ -279,31 +614,36 @@ pub fn execute_send_nft(
     };
     let callback_msg = WasmMsg::Execute {
         contract_addr: contract_addr.to_string(),
-        msg: to_json_binary(&callback)?,
+        // Wrapped as `{"receive_nft": ...}` so standard cw721 receivers can parse it
+        msg: callback.into_json_binary()?,
         funds: vec![],
     };
 
     Ok(Response::new()
         .add_message(callback_msg)
         .add_attribute("action", "send_nft")
-        .add_attribute("token_id", &token_id)
-        .add_attribute("from", old_owner.as_str())
-        .add_attribute("to", contract_addr.as_str()))
+        .add_event(TransferEvent {
+            token_id,
+            from: old_owner.to_string(),
+            to: contract_addr.to_string(),
+        }))
 }
 
 // ─── Execute: Approvals (soulbound enforcement) ─────────────────────────────
 
 pub fn execute_approve(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     spender: String,
     token_id: String,
+    expires: Option<Expiration>,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?; // FIX: M-08
     assert_not_paused(deps.as_ref())?;
     // Soulbound tokens cannot be approved for transfer
     assert_not_soulbound(deps.as_ref(), &token_id)?;
+    assert_not_revoked(deps.as_ref(), &token_id)?;
 
     let token = TOKENS.load(deps.storage, &token_id).map_err(|_| {
         ContractError::TokenNotFound {
@@ -316,13 +656,23 @@ pub fn execute_approve(
         });
     }
 
+    if expires.is_some_and(|e| e.is_expired(&env.block)) {
+        return Err(ContractError::InvalidExpiry);
+    }
+
     let spender_addr = deps.api.addr_validate(&spender)?;
     TOKEN_APPROVALS.save(deps.storage, &token_id, &spender_addr)?;
+    match &expires {
+        Some(e) => APPROVAL_EXPIRIES.save(deps.storage, &token_id, e)?,
+        None => APPROVAL_EXPIRIES.remove(deps.storage, &token_id),
+    }
 
+    let expires = expires.map_or_else(|| "never".to_string(), |e| e.to_string());
     Ok(Response::new()
         .add_attribute("action", "approve")
         .add_attribute("token_id", &token_id)
-        .add_attribute("spender", spender_addr.as_str()))
+        .add_attribute("spender", spender_addr.as_str())
+        .add_attribute("expires", expires))
 }
 
 pub fn execute_revoke(
@@ -343,7 +693,7 @@ pub fn execute_revoke(
         });
     }
 
-    TOKEN_APPROVALS.remove(deps.storage, &token_id);
+    clear_approval(deps.storage, &token_id);
 
     Ok(Response::new()
         .add_attribute("action", "revoke")
@@ -391,25 +741,28 @@ pub fn execute_propose_minter(
     _env: Env,
     info: MessageInfo,
     new_minter: String,
+    replaces: Option<String>,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
-    assert_owner(deps.as_ref(), &info.sender)?;
-
-    if PENDING_MINTER.may_load(deps.storage)?.is_some() {
-        return Err(ContractError::MinterTransferAlreadyPending);
-    }
-
-    let proposed = deps.api.addr_validate(&new_minter)?;
-    PENDING_MINTER.save(
-        deps.storage,
-        &PendingMinterTransfer {
-            proposed_minter: proposed.clone(),
+    let proposed_minter = deps.api.addr_validate(&new_minter)?;
+    let replaces = match replaces {
+        Some(address) => {
+            let minter = deps.api.addr_validate(&address)?;
+            if !MINTERS.has(deps.storage, &minter) {
+                return Err(ContractError::NotMinter { address });
+            }
+            Some(minter)
+        }
+        None => None,
+    };
+    Ok(MINTER_TRANSFER.propose(
+        deps,
+        &info,
+        &CONFIG,
+        PendingMinterTransfer {
+            proposed_minter,
+            replaces,
         },
-    )?;
-
-    Ok(Response::new()
-        .add_attribute("action", "propose_minter")
-        .add_attribute("proposed_minter", proposed.as_str()))
+    )?)
 }
 
 pub fn execute_accept_minter(
@@ -417,24 +770,24 @@ pub fn execute_accept_minter(
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
-    let pending = PENDING_MINTER
-        .may_load(deps.storage)?
-        .ok_or(ContractError::NoMinterTransferPending)?;
-
-    if info.sender != pending.proposed_minter {
-        return Err(ContractError::NotPendingMinter);
+    // The new key joins the minter set; only a key named for rotation is
+    // revoked, so the other services keep minting
+    let pending = MINTER_TRANSFER.take(deps.storage, &info)?;
+    let mut res = Response::new()
+        .add_attribute("action", "accept_minter")
+        .add_attribute("new_minter", pending.proposed_minter.as_str());
+    if let Some(old) = &pending.replaces {
+        if !MINTERS.has(deps.storage, old) {
+            return Err(ContractError::NotMinter {
+                address: old.to_string(),
+            });
+        }
+        MINTERS.remove(deps.storage, old);
+        res = res.add_attribute("replaced", old.as_str());
     }
+    MINTERS.save(deps.storage, &pending.proposed_minter, &true)?;
 
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.minter = pending.proposed_minter.clone();
-        Ok(c)
-    })?;
-    PENDING_MINTER.remove(deps.storage);
-
-    Ok(Response::new()
-        .add_attribute("action", "accept_minter")
-        .add_attribute("new_minter", pending.proposed_minter.as_str()))
+    Ok(res)
 }
 
 pub fn execute_cancel_minter_transfer(
@@ -442,195 +795,773 @@ pub fn execute_cancel_minter_transfer(
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
+    Ok(MINTER_TRANSFER.cancel(deps, &info, &CONFIG)?)
+}
+
+/// Authorize another minter key directly (owner only)
+pub fn execute_add_minter(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
 
-    if PENDING_MINTER.may_load(deps.storage)?.is_none() {
-        return Err(ContractError::NoMinterTransferPending);
+    let minter = deps.api.addr_validate(&address)?;
+    if MINTERS.has(deps.storage, &minter) {
+        return Err(ContractError::AlreadyMinter { address });
     }
+    MINTERS.save(deps.storage, &minter, &true)?;
 
-    PENDING_MINTER.remove(deps.storage);
-    Ok(Response::new().add_attribute("action", "cancel_minter_transfer"))
+    Ok(Response::new()
+        .add_attribute("action", "add_minter")
+        .add_attribute("minter", minter.as_str()))
 }
 
-pub fn execute_pause(
+pub fn execute_remove_minter(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    address: String,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
+    reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
 
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.paused = true;
-        Ok(c)
-    })?;
+    let minter = deps.api.addr_validate(&address)?;
+    if !MINTERS.has(deps.storage, &minter) {
+        return Err(ContractError::NotMinter { address });
+    }
+    MINTERS.remove(deps.storage, &minter);
 
-    Ok(Response::new().add_attribute("action", "pause"))
+    Ok(Response::new()
+        .add_attribute("action", "remove_minter")
+        .add_attribute("minter", minter.as_str()))
 }
 
-pub fn execute_unpause(
+pub fn execute_add_category(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    category: String,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
+    reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
 
-    let config = CONFIG.load(deps.storage)?;
-    if !config.paused {
-        return Err(ContractError::NotPaused);
+    validate_category(&category)?;
+    if CATEGORIES.has(deps.storage, &category) {
+        return Err(ContractError::CategoryAlreadyRegistered { category });
     }
+    CATEGORIES.save(deps.storage, &category, &true)?;
 
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.paused = false;
-        Ok(c)
-    })?;
-
-    Ok(Response::new().add_attribute("action", "unpause"))
+    Ok(Response::new()
+        .add_attribute("action", "add_category")
+        .add_attribute("category", category))
 }
 
-// FIX: L-02 — burn function (minter only)
-pub fn execute_burn(
+pub fn execute_remove_category(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    token_id: String,
+    category: String,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?;
-    assert_minter(deps.as_ref(), &info.sender)?;
-
-    let token = TOKENS.load(deps.storage, &token_id).map_err(|_| {
-        ContractError::TokenNotFound {
-            token_id: token_id.clone(),
-        }
-    })?;
-
-    ACHIEVEMENT_INDEX.remove(deps.storage, (&token.owner, &token.metadata.achievement_id));
-    OWNER_TOKENS.remove(deps.storage, (&token.owner, &token_id));
-    TOKENS.remove(deps.storage, &token_id);
-    TOKEN_APPROVALS.remove(deps.storage, &token_id);
+    assert_owner(deps.as_ref(), &info.sender)?;
 
-    let mut count = TOKEN_COUNT.load(deps.storage)?;
-    count = count.saturating_sub(1);
-    TOKEN_COUNT.save(deps.storage, &count)?;
+    if !CATEGORIES.has(deps.storage, &category) {
+        return Err(ContractError::UnknownCategory { category });
+    }
+    CATEGORIES.remove(deps.storage, &category);
 
     Ok(Response::new()
-        .add_attribute("action", "burn")
-        .add_attribute("token_id", &token_id))
+        .add_attribute("action", "remove_category")
+        .add_attribute("category", category))
 }
 
-// FIX: H-04 — two-step owner transfer
-pub fn execute_propose_owner(
+pub fn execute_add_authorized_contract(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    new_owner: String,
+    address: String,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
-    if PENDING_OWNER.may_load(deps.storage)?.is_some() {
-        return Err(ContractError::OwnerTransferAlreadyPending);
+
+    let contract = deps.api.addr_validate(&address)?;
+    if AUTHORIZED_CONTRACTS.has(deps.storage, &contract) {
+        return Err(ContractError::AlreadyAuthorizedContract { address });
     }
-    let proposed = deps.api.addr_validate(&new_owner)?;
-    PENDING_OWNER.save(
-        deps.storage,
-        &PendingOwnerTransfer {
-            proposed_owner: proposed.clone(),
-        },
-    )?;
+    AUTHORIZED_CONTRACTS.save(deps.storage, &contract, &true)?;
+
     Ok(Response::new()
-        .add_attribute("action", "propose_owner")
-        .add_attribute("proposed_owner", proposed.as_str()))
+        .add_attribute("action", "add_authorized_contract")
+        .add_attribute("contract", contract.as_str()))
 }
 
-pub fn execute_accept_owner(
+pub fn execute_remove_authorized_contract(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    address: String,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?;
-    let pending = PENDING_OWNER
-        .may_load(deps.storage)?
-        .ok_or(ContractError::NoOwnerTransferPending)?;
-    if info.sender != pending.proposed_owner {
-        return Err(ContractError::NotPendingOwner);
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let contract = deps.api.addr_validate(&address)?;
+    if !AUTHORIZED_CONTRACTS.has(deps.storage, &contract) {
+        return Err(ContractError::NotAuthorizedContract { address });
     }
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.owner = pending.proposed_owner.clone();
-        Ok(c)
-    })?;
-    PENDING_OWNER.remove(deps.storage);
+    AUTHORIZED_CONTRACTS.remove(deps.storage, &contract);
+
     Ok(Response::new()
-        .add_attribute("action", "accept_owner")
-        .add_attribute("new_owner", pending.proposed_owner.as_str()))
+        .add_attribute("action", "remove_authorized_contract")
+        .add_attribute("contract", contract.as_str()))
 }
 
-pub fn execute_cancel_owner_transfer(
+pub fn execute_pause(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?;
-    assert_owner(deps.as_ref(), &info.sender)?;
-    if PENDING_OWNER.may_load(deps.storage)?.is_none() {
-        return Err(ContractError::NoOwnerTransferPending);
-    }
-    PENDING_OWNER.remove(deps.storage);
-    Ok(Response::new().add_attribute("action", "cancel_owner_transfer"))
+    Ok(sysbreak_common::execute_pause(deps, &info, &CONFIG)?)
 }
 
-// FIX: I-01 — emergency fund sweep
-pub fn execute_sweep_funds(
+pub fn execute_unpause(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    denom: String,
-    amount: cosmwasm_std::Uint128,
-    recipient: String,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?;
-    assert_owner(deps.as_ref(), &info.sender)?;
-    let recipient_addr = deps.api.addr_validate(&recipient)?;
-    let msg = cosmwasm_std::BankMsg::Send {
-        to_address: recipient_addr.to_string(),
-        amount: vec![cosmwasm_std::Coin { denom, amount }],
-    };
-    Ok(Response::new()
-        .add_message(msg)
-        .add_attribute("action", "sweep_funds")
-        .add_attribute("amount", amount.to_string())
-        .add_attribute("recipient", recipient_addr.as_str()))
+    Ok(sysbreak_common::execute_unpause(deps, &info, &CONFIG)?)
 }
 
-// ─── Queries ────────────────────────────────────────────────────────────────
+// FIX: L-02 — burn function (minter only)
+pub fn execute_burn(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
 
-pub fn query_config(deps: Deps) -> StdResult<Binary> {
-    let config = CONFIG.load(deps.storage)?;
-    to_json_binary(&config)
+    let owner = burn_single(deps, &token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "burn")
+        .add_event(BurnEvent {
+            token_id,
+            owner: owner.to_string(),
+            sender: info.sender.to_string(),
+        }))
 }
 
-pub fn query_nft_info(deps: Deps, token_id: String) -> StdResult<Binary> {
-    let token = TOKENS.load(deps.storage, &token_id)?;
+/// Burn up to MAX_BURN_BATCH_SIZE tokens atomically (minter only)
+pub fn execute_batch_burn(
+    mut deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_ids: Vec<String>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
+
+    if token_ids.is_empty() {
+        return Err(ContractError::EmptyBatch);
+    }
+    if token_ids.len() as u32 > MAX_BURN_BATCH_SIZE {
+        return Err(ContractError::BatchTooLarge {
+            max: MAX_BURN_BATCH_SIZE,
+        });
+    }
+
+    // Validate every id before mutating anything
+    for token_id in &token_ids {
+        if !TOKENS.has(deps.storage, token_id) {
+            return Err(ContractError::TokenNotFound {
+                token_id: token_id.clone(),
+            });
+        }
+    }
+    let mut events = Vec::with_capacity(token_ids.len());
+    for token_id in token_ids {
+        let owner = burn_single(deps.branch(), &token_id)?;
+        events.push(BurnEvent {
+            token_id,
+            owner: owner.to_string(),
+            sender: info.sender.to_string(),
+        });
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "batch_burn")
+        .add_attribute("count", events.len().to_string())
+        .add_events(events))
+}
+
+/// Remove a token and every index entry that references it. Returns the last owner.
+fn burn_single(deps: DepsMut, token_id: &str) -> Result<Addr, ContractError> {
+    let token = TOKENS.load(deps.storage, token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.to_string(),
+        }
+    })?;
+
+    ACHIEVEMENT_INDEX.remove(deps.storage, (&token.owner, &token.metadata.achievement_id));
+    ACHIEVEMENT_OWNERS.remove(deps.storage, (&token.metadata.achievement_id, &token.owner));
+    OWNER_TOKENS.remove(deps.storage, (&token.owner, token_id));
+    OWNER_CATEGORY_TOKENS.remove(
+        deps.storage,
+        (&token.owner, &token.metadata.category, token_id),
+    );
+    if counted_in_stats(deps.storage, token_id)? {
+        uncount_token(deps.storage, &token)?;
+    }
+    TOKENS.remove(deps.storage, token_id);
+    clear_approval(deps.storage, token_id);
+    UNBIND_REQUESTS.remove(deps.storage, token_id);
+
+    let mut count = TOKEN_COUNT.load(deps.storage)?;
+    count = count.saturating_sub(1);
+    TOKEN_COUNT.save(deps.storage, &count)?;
+    Ok(token.owner)
+}
+
+/// Fix presentation fields in place (minter only). achievement_id, earned_at,
+/// owner and soulbound are not editable, so indexes and token ids are unaffected.
+pub fn execute_update_metadata(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+    description: Option<String>,
+    rarity: Option<String>,
+    token_uri: Option<String>,
+    category: Option<String>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
+
+    let mut token = TOKENS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+
+    let counted = counted_in_stats(deps.storage, &token_id)?;
+    let mut res = Response::new()
+        .add_attribute("action", "update_metadata")
+        .add_attribute("token_id", &token_id);
+    if let Some(new) = description {
+        res = res
+            .add_attribute("old_description", &token.metadata.description)
+            .add_attribute("new_description", &new);
+        token.metadata.description = new;
+    }
+    if let Some(new) = rarity {
+        res = res
+            .add_attribute("old_rarity", &token.metadata.rarity)
+            .add_attribute("new_rarity", &new);
+        if counted {
+            move_count(&RARITY_COUNTS, deps.storage, &token.metadata.rarity, &new)?;
+        }
+        token.metadata.rarity = new;
+    }
+    if let Some(new) = category {
+        assert_category_registered(deps.as_ref(), &new)?;
+        res = res
+            .add_attribute("old_category", &token.metadata.category)
+            .add_attribute("new_category", &new);
+        OWNER_CATEGORY_TOKENS.remove(
+            deps.storage,
+            (&token.owner, &token.metadata.category, &token_id),
+        );
+        OWNER_CATEGORY_TOKENS.save(deps.storage, (&token.owner, &new, &token_id), &true)?;
+        if counted {
+            move_count(
+                &CATEGORY_COUNTS,
+                deps.storage,
+                &token.metadata.category,
+                &new,
+            )?;
+        }
+        token.metadata.category = new;
+    }
+    if let Some(new) = token_uri {
+        res = res
+            .add_attribute("old_token_uri", token.token_uri.as_deref().unwrap_or(""))
+            .add_attribute("new_token_uri", &new);
+        token.token_uri = Some(new);
+    }
+
+    TOKENS.save(deps.storage, &token_id, &token)?;
+    Ok(res)
+}
+
+/// Raise a tiered achievement in place (minter only). Tiers only go up, and the
+/// token keeps its id and dedup index entry.
+pub fn execute_upgrade_achievement(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+    new_tier: u8,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
+
+    let mut token = TOKENS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if token.revoked.is_some() {
+        return Err(ContractError::TokenRevoked { token_id });
+    }
+
+    let old_tier = token.metadata.tier;
+    if new_tier <= old_tier {
+        return Err(ContractError::TierNotHigher {
+            current: old_tier,
+            requested: new_tier,
+        });
+    }
+    token.metadata.tier = new_tier;
+    TOKENS.save(deps.storage, &token_id, &token)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "upgrade_achievement")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("owner", token.owner.as_str())
+        .add_attribute("achievement_id", &token.metadata.achievement_id)
+        .add_attribute("old_tier", old_tier.to_string())
+        .add_attribute("new_tier", new_tier.to_string()))
+}
+
+/// Store the sender's showcase. Every token must currently be theirs and not
+/// revoked; later transfers and burns are filtered out when queried.
+pub fn execute_set_showcase(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_ids: Vec<String>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    if token_ids.len() > MAX_SHOWCASE_SIZE {
+        return Err(ContractError::ShowcaseTooLarge {
+            max: MAX_SHOWCASE_SIZE,
+        });
+    }
+
+    for (i, token_id) in token_ids.iter().enumerate() {
+        if token_ids[..i].contains(token_id) {
+            return Err(ContractError::DuplicateShowcaseToken {
+                token_id: token_id.clone(),
+            });
+        }
+        let token = TOKENS.load(deps.storage, token_id).map_err(|_| {
+            ContractError::TokenNotFound {
+                token_id: token_id.clone(),
+            }
+        })?;
+        if token.owner != info.sender {
+            return Err(ContractError::Unauthorized {
+                role: "token owner".to_string(),
+            });
+        }
+        if token.revoked.is_some() {
+            return Err(ContractError::TokenRevoked {
+                token_id: token_id.clone(),
+            });
+        }
+    }
+
+    if token_ids.is_empty() {
+        SHOWCASES.remove(deps.storage, &info.sender);
+    } else {
+        SHOWCASES.save(deps.storage, &info.sender, &token_ids)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_showcase")
+        .add_attribute("owner", info.sender.as_str())
+        .add_attribute("count", token_ids.len().to_string()))
+}
+
+/// Holder asks for a soulbound token to become transferable. Nothing changes
+/// until the minter approves; the request is dropped if the token is burned.
+pub fn execute_request_unbind(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    let token = TOKENS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if token.owner != info.sender {
+        return Err(ContractError::Unauthorized {
+            role: "token owner".to_string(),
+        });
+    }
+    if token.revoked.is_some() {
+        return Err(ContractError::TokenRevoked { token_id });
+    }
+    if !token.soulbound {
+        return Err(ContractError::NotSoulbound { token_id });
+    }
+    if UNBIND_REQUESTS.has(deps.storage, &token_id) {
+        return Err(ContractError::UnbindAlreadyRequested { token_id });
+    }
+
+    UNBIND_REQUESTS.save(
+        deps.storage,
+        &token_id,
+        &UnbindRequest {
+            requested_by: info.sender.clone(),
+            requested_at: env.block.time,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "request_unbind")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("owner", info.sender.as_str()))
+}
+
+/// Minter approves a holder's unbind request and clears the soulbound flag.
+pub fn execute_approve_unbind(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
+
+    let request = UNBIND_REQUESTS
+        .may_load(deps.storage, &token_id)?
+        .ok_or_else(|| ContractError::NoUnbindRequest {
+            token_id: token_id.clone(),
+        })?;
+    let mut token = TOKENS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if token.revoked.is_some() {
+        return Err(ContractError::TokenRevoked { token_id });
+    }
+
+    token.soulbound = false;
+    TOKENS.save(deps.storage, &token_id, &token)?;
+    if counted_in_stats(deps.storage, &token_id)? {
+        count_unbind(deps.storage)?;
+    }
+    UNBIND_REQUESTS.remove(deps.storage, &token_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "approve_unbind")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("owner", token.owner.as_str())
+        .add_attribute("requested_by", request.requested_by.as_str())
+        .add_attribute("requested_at", request.requested_at.seconds().to_string()))
+}
+
+/// Strip an achievement without burning it (minter only). The token stays in
+/// storage with its revocation reason, keeps blocking a re-mint of the same
+/// achievement to the holder, and no longer counts for HasAchievement.
+pub fn execute_revoke_achievement(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
+
+    let mut token = TOKENS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if token.revoked.is_some() {
+        return Err(ContractError::TokenRevoked { token_id });
+    }
+
+    token.revoked = Some(Revocation {
+        reason: reason.clone(),
+        revoked_by: info.sender.clone(),
+        revoked_at: env.block.time,
+    });
+    TOKENS.save(deps.storage, &token_id, &token)?;
+    clear_approval(deps.storage, &token_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_achievement")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("owner", token.owner.as_str())
+        .add_attribute("achievement_id", &token.metadata.achievement_id)
+        .add_attribute("reason", reason))
+}
+
+// FIX: H-04 — two-step owner transfer
+pub fn execute_propose_owner(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    new_owner: String,
+) -> Result<Response, ContractError> {
+    let proposed_owner = deps.api.addr_validate(&new_owner)?;
+    Ok(OWNER_TRANSFER.propose(
+        deps,
+        &info,
+        &CONFIG,
+        PendingOwnerTransfer { proposed_owner },
+    )?)
+}
+
+pub fn execute_accept_owner(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(OWNER_TRANSFER.accept(deps, &info, &CONFIG)?)
+}
+
+pub fn execute_cancel_owner_transfer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(OWNER_TRANSFER.cancel(deps, &info, &CONFIG)?)
+}
+
+// FIX: I-01 — emergency fund sweep
+pub fn execute_sweep_funds(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    denom: String,
+    amount: cosmwasm_std::Uint128,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+    let msg = cosmwasm_std::BankMsg::Send {
+        to_address: recipient_addr.to_string(),
+        amount: vec![cosmwasm_std::Coin { denom, amount }],
+    };
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "sweep_funds")
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("recipient", recipient_addr.as_str()))
+}
+
+// ─── Execute: Attestations ──────────────────────────────────────────────────
+
+/// Digest of `token_id`'s current attestation payload on this deployment.
+fn current_attestation_hash(deps: Deps, env: &Env, token_id: &str) -> Result<Vec<u8>, ContractError> {
+    let token = TOKENS.load(deps.storage, token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.to_string(),
+        }
+    })?;
+    if token.revoked.is_some() {
+        return Err(ContractError::TokenRevoked {
+            token_id: token_id.to_string(),
+        });
+    }
+    let domain = attestation_domain(&env.block.chain_id, env.contract.address.as_str());
+    Ok(attestation_hash(
+        &domain,
+        &attestation_payload(token_id, &token),
+    ))
+}
+
+/// Holder asks the oracle to attest their achievement. The oracle watches for
+/// this action, signs `message_hash` and relays it back with SubmitAttestation.
+pub fn execute_request_attestation(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    let token = TOKENS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if token.owner != info.sender {
+        return Err(ContractError::Unauthorized {
+            role: "token owner".to_string(),
+        });
+    }
+    let message_hash = current_attestation_hash(deps.as_ref(), &env, &token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "request_attestation")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("owner", info.sender.as_str())
+        .add_attribute("payload_version", ATTESTATION_PAYLOAD_VERSION.to_string())
+        .add_attribute("message_hash", HexBinary::from(message_hash).to_hex()))
+}
+
+pub fn execute_submit_attestation(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let signer = CONFIG
+        .load(deps.storage)?
+        .attestation_signer
+        .ok_or(ContractError::AttestationSignerNotSet)?;
+
+    let message_hash = current_attestation_hash(deps.as_ref(), &env, &token_id)?;
+    let valid = deps
+        .api
+        .secp256k1_verify(&message_hash, &signature, &signer)
+        .map_err(|_| ContractError::InvalidAttestationSignature)?;
+    if !valid {
+        return Err(ContractError::InvalidAttestationSignature);
+    }
+
+    ATTESTATIONS.save(
+        deps.storage,
+        &token_id,
+        &Attestation {
+            payload_version: ATTESTATION_PAYLOAD_VERSION,
+            message_hash: Binary::from(message_hash),
+            signature,
+            attested_at: env.block.time,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "submit_attestation")
+        .add_attribute("token_id", &token_id))
+}
+
+pub fn execute_set_attestation_signer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    pubkey: Option<Binary>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+    if let Some(pubkey) = &pubkey {
+        validate_pubkey(pubkey)?;
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    config.attestation_signer = pubkey;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_attestation_signer")
+        .add_attribute("enabled", config.attestation_signer.is_some().to_string()))
+}
+
+pub fn execute_set_notify_contract(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: Option<String>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+    let notify_contract = address.map(|a| deps.api.addr_validate(&a)).transpose()?;
+
+    let mut config = CONFIG.load(deps.storage)?;
+    config.notify_contract = notify_contract;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_notify_contract")
+        .add_attribute(
+            "notify_contract",
+            config
+                .notify_contract
+                .map_or("none".to_string(), |a| a.to_string()),
+        ))
+}
+
+// ─── Reply ──────────────────────────────────────────────────────────────────
+
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        NOTIFY_REPLY_ID => reply_notify(deps, msg),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+/// Circuit breaker: a failing hook keeps the mint and turns notifications off
+/// so a broken target cannot block minting.
+fn reply_notify(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let error = match msg.result {
+        SubMsgResult::Ok(_) => return Ok(Response::new()),
+        SubMsgResult::Err(err) => err,
+    };
+    let mut config = CONFIG.load(deps.storage)?;
+    let Some(notify_contract) = config.notify_contract.take() else {
+        // Already switched off by an earlier hook in the same batch
+        return Ok(Response::new());
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "notify_disabled")
+        .add_attribute("notify_contract", notify_contract)
+        .add_attribute("error", error))
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+pub fn query_config(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_json_binary(&config)
+}
+
+fn load_nft_info(deps: Deps, token_id: String) -> StdResult<NftInfoResponse> {
+    let token = TOKENS.load(deps.storage, &token_id)?;
     let approval = TOKEN_APPROVALS
         .may_load(deps.storage, &token_id)?
         .map(|a| a.to_string());
+    let approval_expires = APPROVAL_EXPIRIES.may_load(deps.storage, &token_id)?;
 
-    to_json_binary(&NftInfoResponse {
+    Ok(NftInfoResponse {
         token_id,
         owner: token.owner.to_string(),
         metadata: token.metadata,
         token_uri: token.token_uri,
         soulbound: token.soulbound,
         approval,
+        approval_expires,
+        revoked: token.revoked,
     })
 }
 
-pub fn query_owner_of(deps: Deps, token_id: String) -> StdResult<Binary> {
+pub fn query_nft_info(deps: Deps, token_id: String) -> StdResult<Binary> {
+    to_json_binary(&load_nft_info(deps, token_id)?)
+}
+
+pub fn query_owner_of(deps: Deps, env: Env, token_id: String) -> StdResult<Binary> {
     let token = TOKENS.load(deps.storage, &token_id)?;
-    let approval = TOKEN_APPROVALS
-        .may_load(deps.storage, &token_id)?
-        .map(|a| a.to_string());
+    let approval = live_approval(deps.storage, &env.block, &token_id)?.map(|a| a.to_string());
     let approvals = approval.into_iter().collect();
 
     to_json_binary(&OwnerOfResponse {
@@ -686,6 +1617,27 @@ pub fn query_num_tokens(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&NumTokensResponse { count })
 }
 
+pub fn query_collection_stats(deps: Deps) -> StdResult<Binary> {
+    let totals = COLLECTION_TOTALS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let total = TOKEN_COUNT.load(deps.storage)?;
+    let counts = |map: &Map<&str, u64>| -> StdResult<Vec<StatCount>> {
+        map.range(deps.storage, None, None, Order::Ascending)
+            .map(|r| r.map(|(name, count)| StatCount { name, count }))
+            .collect()
+    };
+
+    to_json_binary(&CollectionStatsResponse {
+        total,
+        by_category: counts(&CATEGORY_COUNTS)?,
+        by_rarity: counts(&RARITY_COUNTS)?,
+        soulbound: totals.soulbound,
+        transferable: total.saturating_sub(totals.soulbound),
+        unique_holders: totals.holders,
+    })
+}
+
 pub fn query_has_achievement(
     deps: Deps,
     owner: String,
@@ -693,9 +1645,14 @@ pub fn query_has_achievement(
 ) -> StdResult<Binary> {
     let owner_addr = deps.api.addr_validate(&owner)?;
     let token_id = ACHIEVEMENT_INDEX.may_load(deps.storage, (&owner_addr, &achievement_id))?;
+    // A revoked token still occupies the index but no longer counts as held
+    let has_achievement = match &token_id {
+        Some(id) => TOKENS.load(deps.storage, id)?.revoked.is_none(),
+        None => false,
+    };
 
     to_json_binary(&AchievementCheckResponse {
-        has_achievement: token_id.is_some(),
+        has_achievement,
         token_id,
     })
 }
@@ -712,41 +1669,92 @@ pub fn query_achievements_by_owner(
         .as_deref()
         .map(cw_storage_plus::Bound::exclusive);
 
-    let achievements: Vec<NftInfoResponse> = TOKENS
-        .range(deps.storage, start, None, Order::Ascending)
-        .filter_map(|item| {
-            let (token_id, data) = item.ok()?;
-            if data.owner == owner_addr {
-                let approval = TOKEN_APPROVALS
-                    .may_load(deps.storage, &token_id)
-                    .ok()?
-                    .map(|a| a.to_string());
-                Some(NftInfoResponse {
-                    token_id,
-                    owner: data.owner.to_string(),
-                    metadata: data.metadata,
-                    token_uri: data.token_uri,
-                    soulbound: data.soulbound,
-                    approval,
-                })
-            } else {
-                None
-            }
-        })
+    let achievements: Vec<NftInfoResponse> = OWNER_TOKENS
+        .prefix(&owner_addr)
+        .keys(deps.storage, start, None, Order::Ascending)
         .take(limit)
-        .collect();
+        .map(|token_id| load_nft_info(deps, token_id?))
+        .collect::<StdResult<_>>()?;
 
     to_json_binary(&AchievementsResponse { achievements })
 }
 
-pub fn query_approval(deps: Deps, token_id: String, spender: String) -> StdResult<Binary> {
+pub fn query_achievements_by_owner_and_category(
+    deps: Deps,
+    owner: String,
+    category: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let achievements: Vec<NftInfoResponse> = OWNER_CATEGORY_TOKENS
+        .prefix((&owner_addr, &category))
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|token_id| load_nft_info(deps, token_id?))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&AchievementsResponse { achievements })
+}
+
+pub fn query_owners_of_achievement(
+    deps: Deps,
+    achievement_id: String,
+    min_tier: Option<u8>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let min_tier = min_tier.unwrap_or_default();
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(cw_storage_plus::Bound::exclusive);
+
+    let mut owners: Vec<AchievementOwner> = Vec::new();
+    for item in ACHIEVEMENT_OWNERS.prefix(&achievement_id).range(
+        deps.storage,
+        start,
+        None,
+        Order::Ascending,
+    ) {
+        if owners.len() >= limit {
+            break;
+        }
+        let (owner, token_id) = item?;
+        let tier = TOKENS.load(deps.storage, &token_id)?.metadata.tier;
+        if tier >= min_tier {
+            owners.push(AchievementOwner {
+                owner: owner.to_string(),
+                token_id,
+                tier,
+            });
+        }
+    }
+
+    to_json_binary(&OwnersOfAchievementResponse { owners })
+}
+
+pub fn query_approval(
+    deps: Deps,
+    env: Env,
+    token_id: String,
+    spender: String,
+) -> StdResult<Binary> {
     let spender_addr = deps.api.addr_validate(&spender)?;
-    let approved = TOKEN_APPROVALS
-        .may_load(deps.storage, &token_id)?
-        .map(|a| a == spender_addr)
-        .unwrap_or(false);
+    let approved = live_approval(deps.storage, &env.block, &token_id)? == Some(spender_addr);
+    let expires = if approved {
+        APPROVAL_EXPIRIES.may_load(deps.storage, &token_id)?
+    } else {
+        None
+    };
 
-    to_json_binary(&ApprovalResponse { approved })
+    to_json_binary(&ApprovalResponse { approved, expires })
 }
 
 pub fn query_operator(deps: Deps, owner: String, operator: String) -> StdResult<Binary> {
@@ -759,32 +1767,310 @@ pub fn query_operator(deps: Deps, owner: String, operator: String) -> StdResult<
     to_json_binary(&OperatorResponse { approved })
 }
 
+pub fn query_unbind_requests(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let requests: Vec<UnbindRequestInfo> = UNBIND_REQUESTS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(token_id, request)| UnbindRequestInfo {
+                token_id,
+                owner: request.requested_by.to_string(),
+                requested_at: request.requested_at,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&UnbindRequestsResponse { requests })
+}
+
+pub fn query_claim_campaign(deps: Deps, campaign_id: String) -> StdResult<Binary> {
+    to_json_binary(&CLAIM_CAMPAIGNS.may_load(deps.storage, &campaign_id)?)
+}
+
+pub fn query_is_claimed(
+    deps: Deps,
+    campaign_id: String,
+    address: String,
+    achievement_id: String,
+) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    to_json_binary(&CLAIMED.has(deps.storage, (&campaign_id, &addr, &achievement_id)))
+}
+
+pub fn query_minters(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(cw_storage_plus::Bound::exclusive);
+
+    let minters: Vec<String> = MINTERS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|k| k.map(|addr| addr.to_string()))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&MintersResponse { minters })
+}
+
+pub fn query_categories(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.as_deref().map(cw_storage_plus::Bound::exclusive);
+
+    let categories: Vec<String> = CATEGORIES
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&CategoriesResponse { categories })
+}
+
+pub fn query_authorized_contracts(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(cw_storage_plus::Bound::exclusive);
+
+    let contracts: Vec<String> = AUTHORIZED_CONTRACTS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|k| k.map(|addr| addr.to_string()))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&AuthorizedContractsResponse { contracts })
+}
+
+pub fn query_attestation_payload(deps: Deps, env: Env, token_id: String) -> StdResult<Binary> {
+    let token = TOKENS.load(deps.storage, &token_id)?;
+    let domain = attestation_domain(&env.block.chain_id, env.contract.address.as_str());
+    let payload = attestation_payload(&token_id, &token);
+    let message_hash = attestation_hash(&domain, &payload);
+    to_json_binary(&AttestationPayloadResponse {
+        payload_version: ATTESTATION_PAYLOAD_VERSION,
+        payload: Binary::from(payload),
+        domain_separator: Binary::from(domain),
+        message_hash: Binary::from(message_hash),
+    })
+}
+
+pub fn query_attestation(deps: Deps, env: Env, token_id: String) -> StdResult<Binary> {
+    let attestation = ATTESTATIONS.may_load(deps.storage, &token_id)?;
+    let current = match (
+        &attestation,
+        current_attestation_hash(deps, &env, &token_id),
+    ) {
+        (Some(a), Ok(hash)) => a.message_hash.as_slice() == hash.as_slice(),
+        _ => false,
+    };
+    to_json_binary(&AttestationResponse {
+        attestation,
+        current,
+    })
+}
+
+pub fn query_showcase(deps: Deps, owner: String) -> StdResult<Binary> {
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    let token_ids = SHOWCASES
+        .may_load(deps.storage, &owner_addr)?
+        .unwrap_or_default();
+
+    let mut tokens = Vec::with_capacity(token_ids.len());
+    for token_id in token_ids {
+        let still_held = TOKENS
+            .may_load(deps.storage, &token_id)?
+            .is_some_and(|t| t.owner == owner_addr && t.revoked.is_none());
+        if still_held {
+            tokens.push(load_nft_info(deps, token_id)?);
+        }
+    }
+
+    to_json_binary(&ShowcaseResponse { owner, tokens })
+}
+
 pub fn query_pending_minter(deps: Deps) -> StdResult<Binary> {
-    let pending = PENDING_MINTER.may_load(deps.storage)?;
-    to_json_binary(&pending)
+    MINTER_TRANSFER.query_pending(deps)
 }
 
 // FIX: H-04
 pub fn query_pending_owner(deps: Deps) -> StdResult<Binary> {
-    to_json_binary(&PENDING_OWNER.may_load(deps.storage)?)
+    OWNER_TRANSFER.query_pending(deps)
 }
 
 // ─── Migrate ────────────────────────────────────────────────────────────────
 
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    // The single config.minter becomes the first entry of the minter set
+    if let Ok(legacy) = CONFIG_V1.load(deps.storage) {
+        MINTERS.save(deps.storage, &legacy.minter, &true)?;
+        CONFIG.save(
+            deps.storage,
+            &Config {
+                owner: legacy.owner,
+                paused: legacy.paused,
+                name: legacy.name,
+                symbol: legacy.symbol,
+                attestation_signer: None,
+                notify_contract: None,
+            },
+        )?;
+    }
+
+    // Categories the operator names, e.g. ones no token uses yet
+    for category in &msg.categories {
+        validate_category(category)?;
+        CATEGORIES.save(deps.storage, category, &true)?;
+    }
+
+    // Mints need a registered category; the first upgrade to the registry
+    // starts it with every valid category the stats have seen. Variants like
+    // "Combat" stay unregistered and are reported for the owner to clean up.
+    let mut rejected = BTreeSet::new();
+    if !CATEGORIES_SEEDED.may_load(deps.storage)?.unwrap_or(false) {
+        let in_use = CATEGORY_COUNTS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for category in in_use {
+            seed_category(deps.storage, &category, &mut rejected)?;
+        }
+        CATEGORIES_SEEDED.save(deps.storage, &true)?;
+    }
+
     // FIX: M-06 — backfill OWNER_TOKENS index by scanning TOKENS
     // FIX: I-02 — migrate() should be updated for future state changes
-    let all_tokens: Vec<(String, TokenData)> = TOKENS
-        .range(deps.storage, None, None, Order::Ascending)
+    let progress = match msg.backfill {
+        None => backfill_owner_indexes(deps, BackfillProgress::default(), None, &mut rejected)?,
+        Some(BackfillMsg { start_after, limit }) => {
+            // A finished backfill starts over; an unfinished one resumes
+            let mut progress = BACKFILL
+                .may_load(deps.storage)?
+                .filter(|p| !p.done)
+                .unwrap_or_default();
+            if start_after.is_some() {
+                progress.cursor = start_after;
+            }
+            let limit = limit
+                .unwrap_or(DEFAULT_BACKFILL_LIMIT)
+                .min(MAX_BACKFILL_LIMIT) as usize;
+            backfill_owner_indexes(deps, progress, Some(limit), &mut rejected)?
+        }
+    };
+
+    let mut res = Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("version", CONTRACT_VERSION)
+        .add_attribute("backfilled", progress.processed.to_string())
+        .add_attribute("done", progress.done.to_string());
+    if !rejected.is_empty() {
+        let rejected: Vec<String> = rejected.into_iter().collect();
+        res = res.add_attribute("rejected_categories", rejected.join(","));
+    }
+    Ok(res)
+}
+
+/// Register a category found on existing tokens, or note it in `rejected`
+/// if it is not a valid slug
+fn seed_category(
+    storage: &mut dyn Storage,
+    category: &str,
+    rejected: &mut BTreeSet<String>,
+) -> StdResult<()> {
+    if validate_category(category).is_err() {
+        rejected.insert(category.to_string());
+        return Ok(());
+    }
+    CATEGORIES.save(storage, category, &true)
+}
+
+/// Index up to `limit` tokens after `progress.cursor` (all remaining tokens
+/// when None) into the owner and achievement indexes, and save the progress.
+fn backfill_owner_indexes(
+    deps: DepsMut,
+    mut progress: BackfillProgress,
+    limit: Option<usize>,
+    rejected: &mut BTreeSet<String>,
+) -> StdResult<BackfillProgress> {
+    let start = progress
+        .cursor
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+    // A fresh run counts the tokens into the stats if nothing has counted them yet
+    if progress.processed == 0 {
+        progress.count_stats = !COLLECTION_TOTALS.exists(deps.storage);
+    }
+    let tokens: Vec<(String, TokenData)> = TOKENS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit.unwrap_or(usize::MAX))
         .collect::<StdResult<Vec<_>>>()?;
 
-    for (token_id, data) in &all_tokens {
+    for (token_id, data) in &tokens {
         OWNER_TOKENS.save(deps.storage, (&data.owner, token_id), &true)?;
+        OWNER_CATEGORY_TOKENS.save(
+            deps.storage,
+            (&data.owner, &data.metadata.category, token_id),
+            &true,
+        )?;
+        ACHIEVEMENT_OWNERS.save(
+            deps.storage,
+            (&data.metadata.achievement_id, &data.owner),
+            token_id,
+        )?;
+        if progress.count_stats {
+            // Nothing was counted before, so the registry could not be seeded
+            seed_category(deps.storage, &data.metadata.category, rejected)?;
+            count_token(deps.storage, data)?;
+        }
+        if let Ok(id) = token_id.parse::<u64>() {
+            progress.highest_id = progress.highest_id.max(id);
+        }
+    }
+    progress.processed += tokens.len() as u64;
+    if let Some((last, _)) = tokens.last() {
+        progress.cursor = Some(last.clone());
     }
+    progress.done = limit.map_or(true, |limit| tokens.len() < limit);
 
-    Ok(Response::new()
-        .add_attribute("action", "migrate")
-        .add_attribute("version", CONTRACT_VERSION))
+    // Ids were previously derived from TOKEN_COUNT; resume after the highest live id
+    if progress.done && LAST_TOKEN_ID.may_load(deps.storage)?.is_none() {
+        LAST_TOKEN_ID.save(deps.storage, &progress.highest_id)?;
+    }
+    if progress.count_stats && !COLLECTION_TOTALS.exists(deps.storage) {
+        COLLECTION_TOTALS.save(deps.storage, &CollectionTotals::default())?;
+    }
+    BACKFILL.save(deps.storage, &progress)?;
+    Ok(progress)
+}
+
+pub fn query_migration_status(deps: Deps) -> StdResult<Binary> {
+    let progress = BACKFILL.may_load(deps.storage)?;
+    to_json_binary(&MigrationStatusResponse {
+        version: get_contract_version(deps.storage)?.version,
+        done: progress.as_ref().map_or(true, |p| p.done),
+        processed: progress.as_ref().map_or(0, |p| p.processed),
+        total_tokens: TOKEN_COUNT.load(deps.storage)?,
+        cursor: progress.and_then(|p| p.cursor),
+    })
 }
@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use sysbreak_common::AdminError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -6,29 +7,38 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    Admin(#[from] AdminError),
+
     #[error("unauthorized: only {role} can perform this action")]
     Unauthorized { role: String },
 
-    #[error("contract is paused")]
-    Paused,
-
-    #[error("contract is not paused")]
-    NotPaused,
-
-    #[error("batch mint exceeds maximum of {max} items")]
+    #[error("batch exceeds maximum of {max} items")]
     BatchTooLarge { max: u32 },
 
-    #[error("batch mint list is empty")]
+    #[error("batch list is empty")]
     EmptyBatch,
 
-    #[error("no minter transfer pending")]
-    NoMinterTransferPending,
+    #[error("{address} is already a minter")]
+    AlreadyMinter { address: String },
+
+    #[error("{address} is not a minter")]
+    NotMinter { address: String },
+
+    #[error("invalid category {category:?}: use 1..={max} lowercase letters, digits, '-' or '_'")]
+    InvalidCategory { category: String, max: usize },
+
+    #[error("category {category:?} is already registered")]
+    CategoryAlreadyRegistered { category: String },
 
-    #[error("caller is not the pending minter")]
-    NotPendingMinter,
+    #[error("category {category:?} is not registered")]
+    UnknownCategory { category: String },
 
-    #[error("minter transfer already pending")]
-    MinterTransferAlreadyPending,
+    #[error("{address} is already an authorized contract")]
+    AlreadyAuthorizedContract { address: String },
+
+    #[error("{address} is not an authorized contract")]
+    NotAuthorizedContract { address: String },
 
     #[error("token is soulbound and cannot be transferred")]
     Soulbound,
@@ -42,17 +52,65 @@ pub enum ContractError {
     #[error("token not found: {token_id}")]
     TokenNotFound { token_id: String },
 
-    // FIX: H-04 — two-step owner transfer errors
-    #[error("no owner transfer pending")]
-    NoOwnerTransferPending,
+    #[error("token {token_id} has been revoked")]
+    TokenRevoked { token_id: String },
+
+    #[error("token {token_id} is not soulbound")]
+    NotSoulbound { token_id: String },
+
+    #[error("unbind already requested for token {token_id}")]
+    UnbindAlreadyRequested { token_id: String },
+
+    #[error("no unbind request pending for token {token_id}")]
+    NoUnbindRequest { token_id: String },
+
+    #[error("new tier {requested} must be higher than current tier {current}")]
+    TierNotHigher { current: u8, requested: u8 },
+
+    #[error("claim campaign {campaign_id} already exists")]
+    CampaignExists { campaign_id: String },
+
+    #[error("claim campaign not found: {campaign_id}")]
+    CampaignNotFound { campaign_id: String },
+
+    #[error("claim campaign {campaign_id} has expired")]
+    CampaignExpired { campaign_id: String },
+
+    #[error("merkle root must be 32 bytes")]
+    InvalidMerkleRoot,
+
+    #[error("expiry must be in the future")]
+    InvalidExpiry,
+
+    #[error("invalid merkle proof")]
+    InvalidProof,
+
+    #[error(
+        "invalid public key length: {length} bytes (expected 33 compressed or 65 uncompressed)"
+    )]
+    InvalidPubkeyLength { length: usize },
+
+    #[error("no attestation signer configured")]
+    AttestationSignerNotSet,
+
+    #[error("invalid attestation signature")]
+    InvalidAttestationSignature,
+
+    #[error("achievement {achievement_id} already claimed from campaign {campaign_id}")]
+    AlreadyClaimed {
+        campaign_id: String,
+        achievement_id: String,
+    },
+
+    #[error("showcase holds at most {max} tokens")]
+    ShowcaseTooLarge { max: usize },
 
-    #[error("caller is not the pending owner")]
-    NotPendingOwner,
+    #[error("token {token_id} is listed twice in the showcase")]
+    DuplicateShowcaseToken { token_id: String },
 
-    #[error("owner transfer already pending")]
-    OwnerTransferAlreadyPending,
+    #[error("owner index backfill in progress; minting resumes once it completes")]
+    BackfillInProgress,
 
-    // FIX: M-08 — reject unexpected funds
-    #[error("unexpected funds sent with this message")]
-    UnexpectedFunds,
+    #[error("unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
 }
@@ -1,36 +1,67 @@
-use cosmwasm_std::{Addr, Deps, MessageInfo, StdResult};
+use cosmwasm_std::{Addr, Binary, BlockInfo, Deps, HexBinary, StdResult, Storage};
+use cw_storage_plus::Map;
+use sha2::{Digest, Sha256};
 
 use crate::error::ContractError;
-use crate::state::{CONFIG, OPERATOR_APPROVALS, TOKENS, TOKEN_APPROVALS};
+use crate::state::{
+    CollectionTotals, TokenData, APPROVAL_EXPIRIES, AUTHORIZED_CONTRACTS, BACKFILL, CATEGORIES,
+    CATEGORY_COUNTS, COLLECTION_TOTALS, CONFIG, HOLDER_BALANCES, MINTERS, OPERATOR_APPROVALS,
+    RARITY_COUNTS, TOKENS, TOKEN_APPROVALS,
+};
 
 pub fn assert_owner(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if *sender != config.owner {
+    Ok(sysbreak_common::assert_owner(deps, &CONFIG, sender)?)
+}
+
+pub fn assert_minter(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    if !MINTERS.has(deps.storage, sender) {
         return Err(ContractError::Unauthorized {
-            role: "owner".to_string(),
+            role: "minter".to_string(),
         });
     }
     Ok(())
 }
 
-pub fn assert_minter(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if *sender != config.minter {
+pub fn assert_authorized_contract(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    if !AUTHORIZED_CONTRACTS.has(deps.storage, sender) {
         return Err(ContractError::Unauthorized {
-            role: "minter".to_string(),
+            role: "authorized contract".to_string(),
         });
     }
     Ok(())
 }
 
-pub fn assert_not_paused(deps: Deps) -> Result<(), ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if config.paused {
-        return Err(ContractError::Paused);
+pub fn assert_category_registered(deps: Deps, category: &str) -> Result<(), ContractError> {
+    if !CATEGORIES.has(deps.storage, category) {
+        return Err(ContractError::UnknownCategory {
+            category: category.to_string(),
+        });
+    }
+    Ok(())
+}
+
+const MAX_CATEGORY_LEN: usize = 32;
+
+/// Registered categories are lowercase slugs: a-z, 0-9, '-' and '_'
+pub fn validate_category(category: &str) -> Result<(), ContractError> {
+    let valid = !category.is_empty()
+        && category.len() <= MAX_CATEGORY_LEN
+        && category
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_');
+    if !valid {
+        return Err(ContractError::InvalidCategory {
+            category: category.to_string(),
+            max: MAX_CATEGORY_LEN,
+        });
     }
     Ok(())
 }
 
+pub fn assert_not_paused(deps: Deps) -> Result<(), ContractError> {
+    Ok(sysbreak_common::assert_not_paused(deps, &CONFIG)?)
+}
+
 /// Verify the token is not soulbound. Called on every transfer/send/approve path.
 pub fn assert_not_soulbound(deps: Deps, token_id: &str) -> Result<(), ContractError> {
     let token = TOKENS.load(deps.storage, token_id).map_err(|_| {
@@ -44,27 +75,239 @@ pub fn assert_not_soulbound(deps: Deps, token_id: &str) -> Result<(), ContractEr
     Ok(())
 }
 
-// FIX: M-08 — reject unexpected funds
-pub fn reject_funds(info: &MessageInfo) -> Result<(), ContractError> {
-    if !info.funds.is_empty() {
-        return Err(ContractError::UnexpectedFunds);
+/// Revoked achievements are frozen in place as an audit record.
+pub fn assert_not_revoked(deps: Deps, token_id: &str) -> Result<(), ContractError> {
+    let token = TOKENS.load(deps.storage, token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.to_string(),
+        }
+    })?;
+    if token.revoked.is_some() {
+        return Err(ContractError::TokenRevoked {
+            token_id: token_id.to_string(),
+        });
     }
     Ok(())
 }
 
+/// The token's approved spender, unless the approval has expired.
+pub fn live_approval(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    token_id: &str,
+) -> StdResult<Option<Addr>> {
+    let expired = APPROVAL_EXPIRIES
+        .may_load(storage, token_id)?
+        .is_some_and(|expires| expires.is_expired(block));
+    if expired {
+        return Ok(None);
+    }
+    TOKEN_APPROVALS.may_load(storage, token_id)
+}
+
+/// Drop a token's approval along with its expiry.
+pub fn clear_approval(storage: &mut dyn Storage, token_id: &str) {
+    TOKEN_APPROVALS.remove(storage, token_id);
+    APPROVAL_EXPIRIES.remove(storage, token_id);
+}
+
 /// Check if `spender` is authorized to act on `token_id`.
-pub fn is_authorized(deps: Deps, token_id: &str, spender: &Addr) -> StdResult<bool> {
+pub fn is_authorized(
+    deps: Deps,
+    block: &BlockInfo,
+    token_id: &str,
+    spender: &Addr,
+) -> StdResult<bool> {
     let token = TOKENS.load(deps.storage, token_id)?;
     if *spender == token.owner {
         return Ok(true);
     }
-    if let Some(approved) = TOKEN_APPROVALS.may_load(deps.storage, token_id)? {
-        if approved == *spender {
-            return Ok(true);
-        }
+    if live_approval(deps.storage, block, token_id)?.as_ref() == Some(spender) {
+        return Ok(true);
     }
     if let Some(true) = OPERATOR_APPROVALS.may_load(deps.storage, (&token.owner, spender))? {
         return Ok(true);
     }
     Ok(false)
 }
+
+/// Merkle leaf for a claimable achievement. Bech32 addresses never contain ':',
+/// so the preimage splits unambiguously at the first separator.
+pub fn claim_leaf(address: &Addr, achievement_id: &str) -> [u8; 32] {
+    Sha256::digest(format!("{}:{}", address, achievement_id).as_bytes()).into()
+}
+
+/// Walk `proof` from `leaf` to the root, hashing each pair in sorted order.
+pub fn verify_merkle_proof(root: &HexBinary, leaf: [u8; 32], proof: &[HexBinary]) -> bool {
+    let mut hash = leaf;
+    for sibling in proof {
+        let sibling: [u8; 32] = match sibling.as_slice().try_into() {
+            Ok(s) => s,
+            Err(_) => return false,
+        };
+        let (a, b) = if hash <= sibling {
+            (hash, sibling)
+        } else {
+            (sibling, hash)
+        };
+        let mut hasher = Sha256::new();
+        hasher.update(a);
+        hasher.update(b);
+        hash = hasher.finalize().into();
+    }
+    root.as_slice() == hash
+}
+
+pub fn validate_pubkey(pubkey: &Binary) -> Result<(), ContractError> {
+    let len = pubkey.len();
+    if len != 33 && len != 65 {
+        return Err(ContractError::InvalidPubkeyLength { length: len });
+    }
+    Ok(())
+}
+
+/// Length-prefix `field` (4-byte big-endian) before appending it.
+fn push_field(buf: &mut Vec<u8>, field: &[u8]) {
+    buf.extend_from_slice(&(field.len() as u32).to_be_bytes());
+    buf.extend_from_slice(field);
+}
+
+/// SHA-256 over the length-prefixed fields "sysbreak-achievement-nft-domain",
+/// chain_id and contract_addr. Unlike the bridge's domain it carries no
+/// contract version: attestations are long-lived proofs and outlive migrations.
+pub fn attestation_domain(chain_id: &str, contract_addr: &str) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for field in ["sysbreak-achievement-nft-domain", chain_id, contract_addr] {
+        push_field(&mut buf, field.as_bytes());
+    }
+    Sha256::digest(&buf).to_vec()
+}
+
+/// Canonical attestation payload: the length-prefixed fields "attest_achievement",
+/// token_id, owner, achievement_id, tier (1 byte) and earned_at (unix seconds,
+/// 8-byte big-endian).
+pub fn attestation_payload(token_id: &str, token: &TokenData) -> Vec<u8> {
+    let mut buf = Vec::new();
+    push_field(&mut buf, b"attest_achievement");
+    push_field(&mut buf, token_id.as_bytes());
+    push_field(&mut buf, token.owner.as_bytes());
+    push_field(&mut buf, token.metadata.achievement_id.as_bytes());
+    push_field(&mut buf, &[token.metadata.tier]);
+    push_field(&mut buf, &token.metadata.earned_at.seconds().to_be_bytes());
+    buf
+}
+
+/// Digest the oracle signs: SHA-256(0x19 0x01 || domain || SHA-256(payload)).
+pub fn attestation_hash(domain: &[u8], payload: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update([0x19, 0x01]);
+    hasher.update(domain);
+    hasher.update(Sha256::digest(payload));
+    hasher.finalize().to_vec()
+}
+
+/// Whether `token_id` is already reflected in the collection stats. While a
+/// backfill is counting them, tokens past its cursor get counted when it
+/// reaches them, so changes to those tokens leave the counters alone.
+pub fn counted_in_stats(storage: &dyn Storage, token_id: &str) -> StdResult<bool> {
+    Ok(match BACKFILL.may_load(storage)? {
+        Some(p) if p.count_stats && !p.done => p.cursor.is_some_and(|c| token_id <= c.as_str()),
+        _ => true,
+    })
+}
+
+fn increment(counts: &Map<&str, u64>, storage: &mut dyn Storage, key: &str) -> StdResult<()> {
+    let count = counts.may_load(storage, key)?.unwrap_or_default();
+    counts.save(storage, key, &(count + 1))
+}
+
+fn decrement(counts: &Map<&str, u64>, storage: &mut dyn Storage, key: &str) -> StdResult<()> {
+    match counts.may_load(storage, key)?.unwrap_or_default() {
+        0 | 1 => counts.remove(storage, key),
+        count => counts.save(storage, key, &(count - 1))?,
+    }
+    Ok(())
+}
+
+/// Move one token between two keys of CATEGORY_COUNTS or RARITY_COUNTS.
+pub fn move_count(
+    counts: &Map<&str, u64>,
+    storage: &mut dyn Storage,
+    from: &str,
+    to: &str,
+) -> StdResult<()> {
+    decrement(counts, storage, from)?;
+    increment(counts, storage, to)
+}
+
+fn add_holding(
+    storage: &mut dyn Storage,
+    totals: &mut CollectionTotals,
+    owner: &Addr,
+) -> StdResult<()> {
+    let held = HOLDER_BALANCES
+        .may_load(storage, owner)?
+        .unwrap_or_default();
+    if held == 0 {
+        totals.holders += 1;
+    }
+    HOLDER_BALANCES.save(storage, owner, &(held + 1))
+}
+
+fn remove_holding(
+    storage: &mut dyn Storage,
+    totals: &mut CollectionTotals,
+    owner: &Addr,
+) -> StdResult<()> {
+    match HOLDER_BALANCES
+        .may_load(storage, owner)?
+        .unwrap_or_default()
+    {
+        0 => {}
+        1 => {
+            HOLDER_BALANCES.remove(storage, owner);
+            totals.holders = totals.holders.saturating_sub(1);
+        }
+        held => HOLDER_BALANCES.save(storage, owner, &(held - 1))?,
+    }
+    Ok(())
+}
+
+/// Add a minted (or backfilled) token to the collection stats.
+pub fn count_token(storage: &mut dyn Storage, token: &TokenData) -> StdResult<()> {
+    let mut totals = COLLECTION_TOTALS.may_load(storage)?.unwrap_or_default();
+    increment(&CATEGORY_COUNTS, storage, &token.metadata.category)?;
+    increment(&RARITY_COUNTS, storage, &token.metadata.rarity)?;
+    if token.soulbound {
+        totals.soulbound += 1;
+    }
+    add_holding(storage, &mut totals, &token.owner)?;
+    COLLECTION_TOTALS.save(storage, &totals)
+}
+
+/// Remove a burned token from the collection stats.
+pub fn uncount_token(storage: &mut dyn Storage, token: &TokenData) -> StdResult<()> {
+    let mut totals = COLLECTION_TOTALS.may_load(storage)?.unwrap_or_default();
+    decrement(&CATEGORY_COUNTS, storage, &token.metadata.category)?;
+    decrement(&RARITY_COUNTS, storage, &token.metadata.rarity)?;
+    if token.soulbound {
+        totals.soulbound = totals.soulbound.saturating_sub(1);
+    }
+    remove_holding(storage, &mut totals, &token.owner)?;
+    COLLECTION_TOTALS.save(storage, &totals)
+}
+
+/// Move one token's holding from `from` to `to` in the collection stats.
+pub fn move_holding(storage: &mut dyn Storage, from: &Addr, to: &Addr) -> StdResult<()> {
+    let mut totals = COLLECTION_TOTALS.may_load(storage)?.unwrap_or_default();
+    remove_holding(storage, &mut totals, from)?;
+    add_holding(storage, &mut totals, to)?;
+    COLLECTION_TOTALS.save(storage, &totals)
+}
+
+/// Count a soulbound token that became transferable.
+pub fn count_unbind(storage: &mut dyn Storage) -> StdResult<()> {
+    let mut totals = COLLECTION_TOTALS.may_load(storage)?.unwrap_or_default();
+    totals.soulbound = totals.soulbound.saturating_sub(1);
+    COLLECTION_TOTALS.save(storage, &totals)
+}
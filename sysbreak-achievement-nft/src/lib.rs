@@ -4,10 +4,13 @@ pub mod helpers;
 pub mod msg;
 pub mod state;
 
+// Entry points are only referenced by the wasm export shims, which are not
+// generated for native targets.
 #[cfg(not(feature = "library"))]
+#[allow(dead_code)]
 mod entry {
     use super::*;
-    use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+    use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
     use msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
     #[entry_point]
@@ -37,6 +40,7 @@ mod entry {
                 rarity,
                 token_uri,
                 soulbound,
+                tier,
             } => contract::execute_mint(
                 deps,
                 env,
@@ -49,10 +53,32 @@ mod entry {
                 rarity,
                 token_uri,
                 soulbound,
+                tier,
             ),
+            ExecuteMsg::MintFor { mint } => contract::execute_mint_for(deps, env, info, mint),
             ExecuteMsg::BatchMint { mints } => {
                 contract::execute_batch_mint(deps, env, info, mints)
             }
+            ExecuteMsg::MintToMany {
+                achievement_id,
+                category,
+                rarity,
+                description,
+                earned_at,
+                soulbound,
+                recipients,
+            } => contract::execute_mint_to_many(
+                deps,
+                env,
+                info,
+                achievement_id,
+                category,
+                rarity,
+                description,
+                earned_at,
+                soulbound,
+                recipients,
+            ),
             ExecuteMsg::TransferNft {
                 recipient,
                 token_id,
@@ -62,9 +88,11 @@ mod entry {
                 token_id,
                 msg,
             } => contract::execute_send_nft(deps, env, info, contract, token_id, msg),
-            ExecuteMsg::Approve { spender, token_id } => {
-                contract::execute_approve(deps, env, info, spender, token_id)
-            }
+            ExecuteMsg::Approve {
+                spender,
+                token_id,
+                expires,
+            } => contract::execute_approve(deps, env, info, spender, token_id, expires),
             ExecuteMsg::Revoke { token_id } => contract::execute_revoke(deps, env, info, token_id),
             ExecuteMsg::ApproveAll { operator } => {
                 contract::execute_approve_all(deps, env, info, operator)
@@ -72,10 +100,29 @@ mod entry {
             ExecuteMsg::RevokeAll { operator } => {
                 contract::execute_revoke_all(deps, env, info, operator)
             }
-            ExecuteMsg::ProposeMinter { new_minter } => {
-                contract::execute_propose_minter(deps, env, info, new_minter)
-            }
+            ExecuteMsg::ProposeMinter {
+                new_minter,
+                replaces,
+            } => contract::execute_propose_minter(deps, env, info, new_minter, replaces),
             ExecuteMsg::AcceptMinter {} => contract::execute_accept_minter(deps, env, info),
+            ExecuteMsg::AddMinter { address } => {
+                contract::execute_add_minter(deps, env, info, address)
+            }
+            ExecuteMsg::RemoveMinter { address } => {
+                contract::execute_remove_minter(deps, env, info, address)
+            }
+            ExecuteMsg::AddCategory { category } => {
+                contract::execute_add_category(deps, env, info, category)
+            }
+            ExecuteMsg::RemoveCategory { category } => {
+                contract::execute_remove_category(deps, env, info, category)
+            }
+            ExecuteMsg::AddAuthorizedContract { address } => {
+                contract::execute_add_authorized_contract(deps, env, info, address)
+            }
+            ExecuteMsg::RemoveAuthorizedContract { address } => {
+                contract::execute_remove_authorized_contract(deps, env, info, address)
+            }
             ExecuteMsg::CancelMinterTransfer {} => {
                 contract::execute_cancel_minter_transfer(deps, env, info)
             }
@@ -83,6 +130,72 @@ mod entry {
             ExecuteMsg::Unpause {} => contract::execute_unpause(deps, env, info),
             // FIX: L-02
             ExecuteMsg::Burn { token_id } => contract::execute_burn(deps, env, info, token_id),
+            ExecuteMsg::BatchBurn { token_ids } => {
+                contract::execute_batch_burn(deps, env, info, token_ids)
+            }
+            ExecuteMsg::UpdateMetadata {
+                token_id,
+                description,
+                rarity,
+                token_uri,
+                category,
+            } => contract::execute_update_metadata(
+                deps,
+                env,
+                info,
+                token_id,
+                description,
+                rarity,
+                token_uri,
+                category,
+            ),
+            ExecuteMsg::UpgradeAchievement { token_id, new_tier } => {
+                contract::execute_upgrade_achievement(deps, env, info, token_id, new_tier)
+            }
+            ExecuteMsg::SetClaimRoot {
+                campaign_id,
+                root,
+                expiry,
+                metadata,
+            } => contract::execute_set_claim_root(
+                deps,
+                env,
+                info,
+                campaign_id,
+                root,
+                expiry,
+                metadata,
+            ),
+            ExecuteMsg::Claim {
+                campaign_id,
+                achievement_id,
+                proof,
+            } => contract::execute_claim(deps, env, info, campaign_id, achievement_id, proof),
+            ExecuteMsg::RequestAttestation { token_id } => {
+                contract::execute_request_attestation(deps, env, info, token_id)
+            }
+            ExecuteMsg::SubmitAttestation {
+                token_id,
+                signature,
+            } => contract::execute_submit_attestation(deps, env, info, token_id, signature),
+            ExecuteMsg::SetAttestationSigner { pubkey } => {
+                contract::execute_set_attestation_signer(deps, env, info, pubkey)
+            }
+            ExecuteMsg::SetNotifyContract { address } => {
+                contract::execute_set_notify_contract(deps, env, info, address)
+            }
+            ExecuteMsg::SetShowcase { token_ids } => {
+                contract::execute_set_showcase(deps, env, info, token_ids)
+            }
+            ExecuteMsg::RequestUnbind { token_id } => {
+                contract::execute_request_unbind(deps, env, info, token_id)
+            }
+            ExecuteMsg::ApproveUnbind { token_id } => {
+                contract::execute_approve_unbind(deps, env, info, token_id)
+            }
+            ExecuteMsg::RevokeAchievement { token_id, reason } => {
+                contract::execute_revoke_achievement(deps, env, info, token_id, reason)
+            }
             // FIX: H-04
             ExecuteMsg::ProposeOwner { new_owner } => {
                 contract::execute_propose_owner(deps, env, info, new_owner)
@@ -99,11 +212,12 @@ mod entry {
     }
 
     #[entry_point]
-    pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> cosmwasm_std::StdResult<Binary> {
+    pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> cosmwasm_std::StdResult<Binary> {
         match msg {
             QueryMsg::Config {} => contract::query_config(deps),
+            QueryMsg::MigrationStatus {} => contract::query_migration_status(deps),
             QueryMsg::NftInfo { token_id } => contract::query_nft_info(deps, token_id),
-            QueryMsg::OwnerOf { token_id } => contract::query_owner_of(deps, token_id),
+            QueryMsg::OwnerOf { token_id } => contract::query_owner_of(deps, env, token_id),
             QueryMsg::Tokens {
                 owner,
                 start_after,
@@ -114,6 +228,7 @@ mod entry {
                 limit,
             } => contract::query_all_tokens(deps, start_after, limit),
             QueryMsg::NumTokens {} => contract::query_num_tokens(deps),
+            QueryMsg::CollectionStats {} => contract::query_collection_stats(deps),
             QueryMsg::HasAchievement {
                 owner,
                 achievement_id,
@@ -123,18 +238,72 @@ mod entry {
                 start_after,
                 limit,
             } => contract::query_achievements_by_owner(deps, owner, start_after, limit),
+            QueryMsg::AchievementsByOwnerAndCategory {
+                owner,
+                category,
+                start_after,
+                limit,
+            } => contract::query_achievements_by_owner_and_category(
+                deps,
+                owner,
+                category,
+                start_after,
+                limit,
+            ),
+            QueryMsg::OwnersOfAchievement {
+                achievement_id,
+                min_tier,
+                start_after,
+                limit,
+            } => contract::query_owners_of_achievement(
+                deps,
+                achievement_id,
+                min_tier,
+                start_after,
+                limit,
+            ),
             QueryMsg::Approval { token_id, spender } => {
-                contract::query_approval(deps, token_id, spender)
+                contract::query_approval(deps, env, token_id, spender)
             }
             QueryMsg::Operator { owner, operator } => {
                 contract::query_operator(deps, owner, operator)
             }
+            QueryMsg::UnbindRequests { start_after, limit } => {
+                contract::query_unbind_requests(deps, start_after, limit)
+            }
+            QueryMsg::ClaimCampaign { campaign_id } => {
+                contract::query_claim_campaign(deps, campaign_id)
+            }
+            QueryMsg::IsClaimed {
+                campaign_id,
+                address,
+                achievement_id,
+            } => contract::query_is_claimed(deps, campaign_id, address, achievement_id),
+            QueryMsg::Minters { start_after, limit } => {
+                contract::query_minters(deps, start_after, limit)
+            }
+            QueryMsg::Categories { start_after, limit } => {
+                contract::query_categories(deps, start_after, limit)
+            }
+            QueryMsg::AuthorizedContracts { start_after, limit } => {
+                contract::query_authorized_contracts(deps, start_after, limit)
+            }
+            QueryMsg::AttestationPayload { token_id } => {
+                contract::query_attestation_payload(deps, env, token_id)
+            }
+            QueryMsg::Attestation { token_id } => contract::query_attestation(deps, env, token_id),
+            QueryMsg::Showcase { owner } => contract::query_showcase(deps, owner),
             QueryMsg::PendingMinter {} => contract::query_pending_minter(deps),
             // FIX: H-04
             QueryMsg::PendingOwner {} => contract::query_pending_owner(deps),
         }
     }
 
+    #[entry_point]
+    pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, error::ContractError> {
+        contract::reply(deps, env, msg)
+    }
+
     #[entry_point]
     pub fn migrate(
         deps: DepsMut,
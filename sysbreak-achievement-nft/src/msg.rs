@@ -1,7 +1,8 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Timestamp;
+use cosmwasm_std::{Binary, HexBinary, Timestamp};
+pub use cw721::Expiration;
 
-use crate::state::AchievementMetadata;
+use crate::state::{AchievementMetadata, ClaimMetadata, Revocation};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -23,11 +24,30 @@ pub enum ExecuteMsg {
         rarity: String,
         token_uri: Option<String>,
         soulbound: bool,
+        /// Starting tier for tiered achievements; defaults to 0 (untiered)
+        tier: Option<u8>,
+    },
+    /// Mint a single achievement on behalf of an authorized contract, such as
+    /// the corporation DAO executing a milestone proposal (authorized contracts only)
+    MintFor {
+        mint: MintRequest,
     },
     /// Batch mint up to 25 achievements (minter only)
     BatchMint {
         mints: Vec<MintRequest>,
     },
+    /// Grant one achievement to up to 200 players (minter only). Players who
+    /// already hold it are skipped, so a longer list can be sent in chunks
+    /// and a chunk can be resent.
+    MintToMany {
+        achievement_id: String,
+        category: String,
+        rarity: String,
+        description: String,
+        earned_at: Timestamp,
+        soulbound: bool,
+        recipients: Vec<String>,
+    },
     /// Transfer an NFT — rejected if token is soulbound
     TransferNft {
         recipient: String,
@@ -39,10 +59,12 @@ pub enum ExecuteMsg {
         token_id: String,
         msg: cosmwasm_std::Binary,
     },
-    /// Approve a spender for a specific token — rejected if soulbound
+    /// Approve a spender for a specific token — rejected if soulbound.
+    /// The approval lapses at `expires` (block height or time) if set.
     Approve {
         spender: String,
         token_id: String,
+        expires: Option<Expiration>,
     },
     /// Revoke approval for a specific token
     Revoke {
@@ -56,12 +78,39 @@ pub enum ExecuteMsg {
     RevokeAll {
         operator: String,
     },
-    /// Step 1: propose a new minter (owner only)
+    /// Step 1: propose a new minter (owner only). `replaces` names a current
+    /// minter key to rotate out when the proposal is accepted.
     ProposeMinter {
         new_minter: String,
+        #[serde(default)]
+        replaces: Option<String>,
     },
-    /// Step 2: new minter accepts the role
+    /// Step 2: proposed minter accepts and joins the minter set
     AcceptMinter {},
+    /// Authorize an additional minter, e.g. one per game service (owner only)
+    AddMinter {
+        address: String,
+    },
+    /// Revoke a minter's authorization (owner only)
+    RemoveMinter {
+        address: String,
+    },
+    /// Register a category mints may use (owner only)
+    AddCategory {
+        category: String,
+    },
+    /// Stop new mints in a category; existing tokens keep it (owner only)
+    RemoveCategory {
+        category: String,
+    },
+    /// Allow a contract to mint through MintFor (owner only)
+    AddAuthorizedContract {
+        address: String,
+    },
+    /// Revoke a contract's MintFor access (owner only)
+    RemoveAuthorizedContract {
+        address: String,
+    },
     /// Cancel a pending minter transfer (owner only)
     CancelMinterTransfer {},
     /// Pause the contract (owner only)
@@ -70,6 +119,76 @@ pub enum ExecuteMsg {
     Unpause {},
     // FIX: L-02 — burn function
     Burn { token_id: String },
+    /// Burn up to 100 achievements atomically (minter only)
+    BatchBurn {
+        token_ids: Vec<String>,
+    },
+    /// Edit description, rarity, token_uri, or category in place; None keeps
+    /// the current value (minter only)
+    UpdateMetadata {
+        token_id: String,
+        description: Option<String>,
+        rarity: Option<String>,
+        token_uri: Option<String>,
+        category: Option<String>,
+    },
+    /// Raise a tiered achievement to a higher tier instead of minting a duplicate (minter only)
+    UpgradeAchievement {
+        token_id: String,
+        new_tier: u8,
+    },
+    /// Mark an achievement revoked, keeping it as an audit record (minter only)
+    RevokeAchievement {
+        token_id: String,
+        reason: String,
+    },
+    /// Publish a merkle root of (address, achievement_id) leaves that players
+    /// can claim until `expiry` (owner only). Campaign ids cannot be reused.
+    SetClaimRoot {
+        campaign_id: String,
+        root: HexBinary,
+        expiry: Timestamp,
+        metadata: ClaimMetadata,
+    },
+    /// Mint an achievement to the sender by proving its leaf in a campaign's root
+    Claim {
+        campaign_id: String,
+        achievement_id: String,
+        proof: Vec<HexBinary>,
+    },
+    /// Replace the sender's showcase with up to 12 of their tokens, in display
+    /// order. An empty list clears it.
+    SetShowcase {
+        token_ids: Vec<String>,
+    },
+    /// Step 1: holder consents to making a soulbound token transferable
+    RequestUnbind {
+        token_id: String,
+    },
+    /// Step 2: minter approves a pending unbind, clearing the soulbound flag
+    ApproveUnbind {
+        token_id: String,
+    },
+    /// Ask the oracle to attest a token for use on other chains (token owner
+    /// only). Emits the payload hash the oracle signs off-chain.
+    RequestAttestation {
+        token_id: String,
+    },
+    /// Store the oracle's signature over a token's current attestation payload.
+    /// Anyone can relay it; the signature is checked against the attestation signer.
+    SubmitAttestation {
+        token_id: String,
+        signature: Binary,
+    },
+    /// Set or clear the oracle key that signs attestations (owner only)
+    SetAttestationSigner {
+        pubkey: Option<Binary>,
+    },
+    /// Set or clear the contract notified of every mint (owner only). Setting
+    /// it again re-enables a hook that was switched off after an error.
+    SetNotifyContract {
+        address: Option<String>,
+    },
     // FIX: H-04 — two-step owner transfer
     ProposeOwner { new_owner: String },
     AcceptOwner {},
@@ -88,6 +207,7 @@ pub struct MintRequest {
     pub rarity: String,
     pub token_uri: Option<String>,
     pub soulbound: bool,
+    pub tier: Option<u8>,
 }
 
 #[cw_serde]
@@ -118,6 +238,10 @@ pub enum QueryMsg {
     /// Total minted count
     #[returns(NumTokensResponse)]
     NumTokens {},
+    /// Live token totals per category and rarity, soulbound vs transferable,
+    /// and the number of distinct holders. Revoked tokens are still counted.
+    #[returns(CollectionStatsResponse)]
+    CollectionStats {},
     /// Check if a specific achievement_id has been minted to a specific address
     #[returns(AchievementCheckResponse)]
     HasAchievement {
@@ -131,6 +255,23 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Get an owner's achievements in one category, paginated by token_id
+    #[returns(AchievementsResponse)]
+    AchievementsByOwnerAndCategory {
+        owner: String,
+        category: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// List holders of an achievement, paginated by owner address,
+    /// optionally only those at `min_tier` or above
+    #[returns(OwnersOfAchievementResponse)]
+    OwnersOfAchievement {
+        achievement_id: String,
+        min_tier: Option<u8>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     /// Check approval
     #[returns(ApprovalResponse)]
     Approval {
@@ -143,6 +284,54 @@ pub enum QueryMsg {
         owner: String,
         operator: String,
     },
+    /// List soulbound unbind requests awaiting minter approval, paginated by token_id
+    #[returns(UnbindRequestsResponse)]
+    UnbindRequests {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Get a claim campaign's root, expiry and metadata
+    #[returns(Option<crate::state::ClaimCampaign>)]
+    ClaimCampaign { campaign_id: String },
+    /// Whether `address` has already claimed `achievement_id` from a campaign
+    #[returns(bool)]
+    IsClaimed {
+        campaign_id: String,
+        address: String,
+        achievement_id: String,
+    },
+    /// List authorized minters, paginated by address
+    #[returns(MintersResponse)]
+    Minters {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// List registered categories, paginated by name
+    #[returns(CategoriesResponse)]
+    Categories {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// List contracts allowed to call MintFor, paginated by address
+    #[returns(AuthorizedContractsResponse)]
+    AuthorizedContracts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Canonical attestation payload for a token's current state and the
+    /// digest the oracle signs over it
+    #[returns(AttestationPayloadResponse)]
+    AttestationPayload { token_id: String },
+    /// Stored attestation for a token, and whether it still matches the token
+    #[returns(AttestationResponse)]
+    Attestation { token_id: String },
+    /// An owner's showcased tokens in their chosen order, skipping any no
+    /// longer held by them or revoked
+    #[returns(ShowcaseResponse)]
+    Showcase { owner: String },
+    /// Progress of a chunked migration
+    #[returns(MigrationStatusResponse)]
+    MigrationStatus {},
     /// Get pending minter transfer info
     #[returns(Option<crate::state::PendingMinterTransfer>)]
     PendingMinter {},
@@ -160,6 +349,9 @@ pub struct NftInfoResponse {
     pub token_uri: Option<String>,
     pub soulbound: bool,
     pub approval: Option<String>,
+    /// When `approval` lapses; it grants nothing once past
+    pub approval_expires: Option<Expiration>,
+    pub revoked: Option<Revocation>,
 }
 
 #[cw_serde]
@@ -173,13 +365,38 @@ pub struct TokensResponse {
     pub tokens: Vec<String>,
 }
 
+#[cw_serde]
+pub struct ShowcaseResponse {
+    pub owner: String,
+    pub tokens: Vec<NftInfoResponse>,
+}
+
 #[cw_serde]
 pub struct NumTokensResponse {
     pub count: u64,
 }
 
+#[cw_serde]
+pub struct StatCount {
+    pub name: String,
+    pub count: u64,
+}
+
+#[cw_serde]
+pub struct CollectionStatsResponse {
+    /// Live tokens, same as NumTokens
+    pub total: u64,
+    /// Sorted by name; categories and rarities with no live tokens are omitted
+    pub by_category: Vec<StatCount>,
+    pub by_rarity: Vec<StatCount>,
+    pub soulbound: u64,
+    pub transferable: u64,
+    pub unique_holders: u64,
+}
+
 #[cw_serde]
 pub struct AchievementCheckResponse {
+    /// False if the achievement was never minted to the owner or has been revoked
     pub has_achievement: bool,
     pub token_id: Option<String>,
 }
@@ -189,9 +406,23 @@ pub struct AchievementsResponse {
     pub achievements: Vec<NftInfoResponse>,
 }
 
+#[cw_serde]
+pub struct AchievementOwner {
+    pub owner: String,
+    pub token_id: String,
+    pub tier: u8,
+}
+
+#[cw_serde]
+pub struct OwnersOfAchievementResponse {
+    pub owners: Vec<AchievementOwner>,
+}
+
 #[cw_serde]
 pub struct ApprovalResponse {
     pub approved: bool,
+    /// When the approval lapses; None if it never does or none is held
+    pub expires: Option<Expiration>,
 }
 
 #[cw_serde]
@@ -199,5 +430,92 @@ pub struct OperatorResponse {
     pub approved: bool,
 }
 
+/// Sent to Config::notify_contract after each mint. A failing hook does not
+/// undo the mint; it switches notifications off until the owner sets them again.
 #[cw_serde]
-pub struct MigrateMsg {}
+pub enum AchievementHookMsg {
+    AchievementMintedHook {
+        owner: String,
+        achievement_id: String,
+        token_id: String,
+    },
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct MigrateMsg {
+    /// Unset (the plain `{}` payload) upgrades and rebuilds the owner indexes
+    /// over every token in one transaction; fine for small collections
+    #[serde(default)]
+    pub backfill: Option<BackfillMsg>,
+    /// Categories to register on top of the ones already in use, e.g.
+    /// "governance" for DAO badges that have not been minted yet
+    #[serde(default)]
+    pub categories: Vec<String>,
+}
+
+/// Upgrade and index at most `limit` tokens after `start_after` (default:
+/// where the previous chunk stopped). Repeat until MigrationStatus reports
+/// `done`; minting is disabled until then.
+#[cw_serde]
+pub struct BackfillMsg {
+    pub start_after: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct MigrationStatusResponse {
+    pub version: String,
+    /// False while a chunked backfill is running
+    pub done: bool,
+    /// Tokens indexed so far in the current (or last) backfill
+    pub processed: u64,
+    /// Live tokens in the collection
+    pub total_tokens: u64,
+    /// Last token indexed
+    pub cursor: Option<String>,
+}
+
+#[cw_serde]
+pub struct UnbindRequestInfo {
+    pub token_id: String,
+    pub owner: String,
+    pub requested_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct UnbindRequestsResponse {
+    pub requests: Vec<UnbindRequestInfo>,
+}
+
+#[cw_serde]
+pub struct MintersResponse {
+    pub minters: Vec<String>,
+}
+
+#[cw_serde]
+pub struct CategoriesResponse {
+    pub categories: Vec<String>,
+}
+
+#[cw_serde]
+pub struct AuthorizedContractsResponse {
+    pub contracts: Vec<String>,
+}
+
+#[cw_serde]
+pub struct AttestationPayloadResponse {
+    pub payload_version: u8,
+    /// Length-prefixed token fields, see `helpers::attestation_payload`
+    pub payload: Binary,
+    pub domain_separator: Binary,
+    /// SHA-256(0x19 0x01 || domain_separator || SHA-256(payload))
+    pub message_hash: Binary,
+}
+
+#[cw_serde]
+pub struct AttestationResponse {
+    pub attestation: Option<crate::state::Attestation>,
+    /// False once the token was transferred, upgraded, revoked or burned after attesting
+    pub current: bool,
+}
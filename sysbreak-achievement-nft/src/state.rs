@@ -1,27 +1,54 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Timestamp};
+use cosmwasm_std::{Addr, Binary, HexBinary, Timestamp};
+use cw721::Expiration;
 use cw_storage_plus::{Item, Map};
+pub use sysbreak_common::{PendingMinterTransfer, PendingOwnerTransfer};
+use sysbreak_common::{Owned, Pausable, RoleTransfer};
 
 /// Contract-level configuration
 #[cw_serde]
 pub struct Config {
     pub owner: Addr,
-    pub minter: Addr,
     pub paused: bool,
     pub name: String,
     pub symbol: String,
+    /// secp256k1 key of the oracle that signs attestation payloads; None
+    /// disables SubmitAttestation
+    #[serde(default)]
+    pub attestation_signer: Option<Binary>,
+    /// Contract sent an AchievementMintedHook on every mint; cleared if it errors
+    #[serde(default)]
+    pub notify_contract: Option<Addr>,
 }
 
-/// Two-step minter transfer state
+/// Config layout from before the minter set; only read by migrate
 #[cw_serde]
-pub struct PendingMinterTransfer {
-    pub proposed_minter: Addr,
+pub struct ConfigV1 {
+    pub owner: Addr,
+    pub minter: Addr,
+    pub paused: bool,
+    pub name: String,
+    pub symbol: String,
 }
 
-// FIX: H-04 — two-step owner transfer state
-#[cw_serde]
-pub struct PendingOwnerTransfer {
-    pub proposed_owner: Addr,
+impl Owned for Config {
+    fn owner(&self) -> &Addr {
+        &self.owner
+    }
+
+    fn set_owner(&mut self, owner: Addr) {
+        self.owner = owner;
+    }
+}
+
+impl Pausable for Config {
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
 }
 
 /// On-chain metadata for an achievement NFT
@@ -34,6 +61,10 @@ pub struct AchievementMetadata {
     pub earned_at: Timestamp,
     pub description: String,
     pub rarity: String,
+    /// Progression level for tiered achievements (e.g. 1 = Bronze, 2 = Silver,
+    /// 3 = Gold); 0 for untiered. Raised in place by UpgradeAchievement.
+    #[serde(default)]
+    pub tier: u8,
 }
 
 /// Full on-chain token data
@@ -42,19 +73,122 @@ pub struct TokenData {
     pub owner: Addr,
     pub metadata: AchievementMetadata,
     pub token_uri: Option<String>,
-    /// Immutable after mint — soulbound tokens reject all transfers
+    /// Soulbound tokens reject all transfers. Only cleared through the
+    /// RequestUnbind (holder) + ApproveUnbind (minter) flow.
     pub soulbound: bool,
+    /// Set when anti-cheat strips the achievement; the token is kept as an audit record
+    pub revoked: Option<Revocation>,
 }
 
+/// Why and when an achievement was revoked
+#[cw_serde]
+pub struct Revocation {
+    pub reason: String,
+    pub revoked_by: Addr,
+    pub revoked_at: Timestamp,
+}
+
+/// Holder's consent to make a soulbound token transferable, awaiting minter approval
+#[cw_serde]
+pub struct UnbindRequest {
+    pub requested_by: Addr,
+    pub requested_at: Timestamp,
+}
+
+/// Merkle-drop campaign: holders of a leaf (address, achievement_id) mint it themselves
+#[cw_serde]
+pub struct ClaimCampaign {
+    /// sha256 merkle root over `sha256("{address}:{achievement_id}")` leaves
+    pub root: HexBinary,
+    pub expiry: Timestamp,
+    /// Metadata applied to every achievement claimed from this campaign
+    pub metadata: ClaimMetadata,
+}
+
+/// Shared metadata for achievements minted through a claim campaign
+#[cw_serde]
+pub struct ClaimMetadata {
+    pub category: String,
+    pub description: String,
+    pub rarity: String,
+    pub token_uri: Option<String>,
+    pub soulbound: bool,
+}
+
+/// Oracle signature over a token's attestation payload, provable on other chains
+#[cw_serde]
+pub struct Attestation {
+    pub payload_version: u8,
+    /// The signed digest, as returned by the AttestationPayload query
+    pub message_hash: Binary,
+    pub signature: Binary,
+    pub attested_at: Timestamp,
+}
+
+/// How far a chunked migrate has rebuilt the owner indexes from TOKENS
+#[cw_serde]
+#[derive(Default)]
+pub struct BackfillProgress {
+    /// Last token indexed; the next chunk resumes after it
+    pub cursor: Option<String>,
+    /// Tokens indexed so far in this run
+    pub processed: u64,
+    /// Highest numeric token id seen, saved as LAST_TOKEN_ID when done
+    pub highest_id: u64,
+    pub done: bool,
+    /// This run also counts every token it indexes into the collection stats
+    #[serde(default)]
+    pub count_stats: bool,
+}
+
+/// Collection-wide counts kept next to CATEGORY_COUNTS and RARITY_COUNTS
+#[cw_serde]
+#[derive(Default)]
+pub struct CollectionTotals {
+    /// Live soulbound tokens; the rest of TOKEN_COUNT is transferable
+    pub soulbound: u64,
+    /// Addresses holding at least one token
+    pub holders: u64,
+}
+
+/// Attestation payload encoding; numbered like the credit bridge's structured
+/// withdrawal payload (v2), whose layout it follows
+pub const ATTESTATION_PAYLOAD_VERSION: u8 = 2;
+
 pub const CONFIG: Item<Config> = Item::new("config");
+/// Same key as CONFIG, decoded with the pre-minter-set layout
+pub const CONFIG_V1: Item<ConfigV1> = Item::new("config");
+/// Authorized minters (one per game service): minter_addr -> true
+pub const MINTERS: Map<&Addr, bool> = Map::new("minters");
+/// Categories a mint may use, so variants like "Combat" and "combat " cannot
+/// creep in: category -> true
+pub const CATEGORIES: Map<&str, bool> = Map::new("categories");
+/// Set once the registry has been seeded with the categories tokens already
+/// use, so later upgrades leave the owner's removals alone
+pub const CATEGORIES_SEEDED: Item<bool> = Item::new("categories_seeded");
+/// Contracts allowed to mint through MintFor, e.g. the corporation DAO when a
+/// milestone proposal executes: contract_addr -> true
+pub const AUTHORIZED_CONTRACTS: Map<&Addr, bool> = Map::new("authorized_contracts");
+/// Number of live tokens (decremented on burn)
 pub const TOKEN_COUNT: Item<u64> = Item::new("token_count");
-pub const PENDING_MINTER: Item<PendingMinterTransfer> = Item::new("pending_minter");
+/// Last issued token id. Separate from TOKEN_COUNT so ids are never reused after a burn.
+pub const LAST_TOKEN_ID: Item<u64> = Item::new("last_token_id");
+/// Reply id for mint hooks sent to Config::notify_contract
+pub const NOTIFY_REPLY_ID: u64 = 1;
+/// Gas a mint hook may use before it counts as failed
+pub const NOTIFY_GAS_LIMIT: u64 = 500_000;
+/// Owner index backfill run by migrate; minting is disabled until it is done
+pub const BACKFILL: Item<BackfillProgress> = Item::new("backfill");
+pub const MINTER_TRANSFER: RoleTransfer<PendingMinterTransfer> =
+    RoleTransfer::new("minter", "pending_minter");
 
 /// token_id (string of u64) -> TokenData
 pub const TOKENS: Map<&str, TokenData> = Map::new("ach_tokens");
 
 /// token_id -> spender Addr (single approval per token, only for non-soulbound)
 pub const TOKEN_APPROVALS: Map<&str, Addr> = Map::new("ach_approvals");
+/// token_id -> when its approval lapses; absent for approvals that never expire
+pub const APPROVAL_EXPIRIES: Map<&str, Expiration> = Map::new("ach_approval_expiries");
 
 /// (owner, operator) -> bool
 pub const OPERATOR_APPROVALS: Map<(&Addr, &Addr), bool> = Map::new("ach_operators");
@@ -63,9 +197,46 @@ pub const OPERATOR_APPROVALS: Map<(&Addr, &Addr), bool> = Map::new("ach_operator
 /// Prevents the same achievement from being minted twice to the same address.
 pub const ACHIEVEMENT_INDEX: Map<(&Addr, &str), String> = Map::new("ach_idx");
 
+/// Reverse of ACHIEVEMENT_INDEX: (achievement_id, owner_addr) -> token_id
+/// Lists the holders of an achievement without scanning TOKENS.
+pub const ACHIEVEMENT_OWNERS: Map<(&str, &Addr), String> = Map::new("ach_owners");
+
+/// Claim campaigns: campaign_id -> ClaimCampaign
+pub const CLAIM_CAMPAIGNS: Map<&str, ClaimCampaign> = Map::new("claim_campaigns");
+/// Claimed leaves: (campaign_id, claimer, achievement_id) -> true
+/// Kept separately from ACHIEVEMENT_INDEX so burning a claimed token does not re-open the claim.
+pub const CLAIMED: Map<(&str, &Addr, &str), bool> = Map::new("claimed");
+
+/// Latest stored attestation per token: token_id -> Attestation
+pub const ATTESTATIONS: Map<&str, Attestation> = Map::new("attestations");
+
+/// Pending soulbound unbind requests: token_id -> UnbindRequest
+pub const UNBIND_REQUESTS: Map<&str, UnbindRequest> = Map::new("unbind_requests");
+
 // FIX: H-04 — pending owner transfer storage
-pub const PENDING_OWNER: Item<PendingOwnerTransfer> = Item::new("pending_owner");
+pub const OWNER_TRANSFER: RoleTransfer<PendingOwnerTransfer> =
+    RoleTransfer::new("owner", "pending_owner");
 
 // FIX: M-06 — secondary index for efficient owner-based token queries
 /// (owner_addr, token_id) -> bool
 pub const OWNER_TOKENS: Map<(&Addr, &str), bool> = Map::new("owner_tokens");
+
+/// (owner_addr, category, token_id) -> bool
+/// Lists an owner's achievements in one category without scanning OWNER_TOKENS.
+pub const OWNER_CATEGORY_TOKENS: Map<(&Addr, &str, &str), bool> = Map::new("owner_category_tokens");
+
+/// Live tokens per category and per rarity: name -> count (removed at zero).
+/// Maintained on mint, burn and metadata edits so CollectionStats never scans TOKENS.
+pub const CATEGORY_COUNTS: Map<&str, u64> = Map::new("category_counts");
+pub const RARITY_COUNTS: Map<&str, u64> = Map::new("rarity_counts");
+/// Tokens held per address: owner_addr -> count (removed at zero)
+pub const HOLDER_BALANCES: Map<&Addr, u64> = Map::new("holder_balances");
+/// Absent until instantiate, or the first backfill after upgrading, starts counting
+pub const COLLECTION_TOTALS: Item<CollectionTotals> = Item::new("collection_totals");
+
+/// Owner-curated display order for profile pages: owner -> token_ids. Entries
+/// that were since burned or transferred are skipped when read and dropped on
+/// the owner's next SetShowcase.
+pub const SHOWCASES: Map<&Addr, Vec<String>> = Map::new("showcases");
+/// Most tokens an owner can showcase
+pub const MAX_SHOWCASE_SIZE: usize = 12;
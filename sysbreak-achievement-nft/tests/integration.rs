@@ -1,13 +1,34 @@
 use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi, MockQuerier};
-use cosmwasm_std::{from_json, Addr, MemoryStorage, OwnedDeps, Timestamp};
-
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, Event, HexBinary, MemoryStorage, OwnedDeps, Reply,
+    ReplyOn, SubMsgResult, Timestamp, WasmMsg,
+};
+use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+use sha2::{Digest, Sha256};
+
+use sysbreak_common::AdminError;
+use sysbreak_events::MintEvent;
 use sysbreak_achievement_nft::contract::*;
 use sysbreak_achievement_nft::error::ContractError;
 use sysbreak_achievement_nft::msg::*;
-use sysbreak_achievement_nft::state::Config;
+use sysbreak_achievement_nft::helpers::claim_leaf;
+use sysbreak_achievement_nft::state::{
+    ClaimMetadata, Config, ACHIEVEMENT_OWNERS, CATEGORIES, CATEGORIES_SEEDED, CATEGORY_COUNTS, COLLECTION_TOTALS, HOLDER_BALANCES,
+    NOTIFY_REPLY_ID, LAST_TOKEN_ID, OWNER_CATEGORY_TOKENS, OWNER_TOKENS, RARITY_COUNTS,
+};
 
 type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
 
+const SETUP_CATEGORIES: [&str; 7] = [
+    "combat",
+    "corporation",
+    "exploration",
+    "hacking",
+    "pve",
+    "retro",
+    "social",
+];
+
 fn a(deps: &Deps, name: &str) -> Addr {
     deps.api.addr_make(name)
 }
@@ -25,6 +46,10 @@ fn setup() -> Deps {
     };
     let info = message_info(&owner, &[]);
     instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+    for category in SETUP_CATEGORIES {
+        let info = message_info(&owner, &[]);
+        execute_add_category(deps.as_mut(), mock_env(), info, category.to_string()).unwrap();
+    }
     deps
 }
 
@@ -49,15 +74,13 @@ fn mint_achievement(
         "rare".to_string(),
         None,
         soulbound,
+        None,
     )
     .unwrap();
-    // Return the token_id from attributes
-    res.attributes
-        .iter()
-        .find(|a| a.key == "token_id")
-        .unwrap()
-        .value
-        .clone()
+    // Return the token_id from the mint event
+    let mint = &res.events[0];
+    assert_eq!(mint.ty, MintEvent::TYPE);
+    mint.attributes[0].value.clone()
 }
 
 // ─── Instantiation ──────────────────────────────────────────────────────────
@@ -67,7 +90,9 @@ fn test_instantiate() {
     let deps = setup();
     let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
     assert_eq!(config.owner, a(&deps, "owner"));
-    assert_eq!(config.minter, a(&deps, "minter"));
+    let minters: MintersResponse =
+        from_json(query_minters(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert_eq!(minters.minters, vec![a(&deps, "minter").to_string()]);
     assert!(!config.paused);
 }
 
@@ -114,6 +139,7 @@ fn test_mint_non_minter_fails() {
         "rare".to_string(),
         None,
         true,
+        None,
     )
     .unwrap_err();
 
@@ -148,6 +174,7 @@ fn test_duplicate_achievement_same_owner_fails() {
         "rare".to_string(),
         None,
         true,
+        None,
     )
     .unwrap_err();
 
@@ -203,6 +230,337 @@ fn test_has_achievement_query() {
     assert!(check.token_id.is_none());
 }
 
+// ─── Metadata Update ────────────────────────────────────────────────────────
+
+#[test]
+fn test_category_registry() {
+    let mut deps = setup();
+    let owner = a(&deps, "owner");
+    let minter = a(&deps, "minter");
+    let player = a(&deps, "player1");
+
+    let add = |deps: &mut Deps, sender: &Addr, category: &str| {
+        execute_add_category(
+            deps.as_mut(),
+            mock_env(),
+            message_info(sender, &[]),
+            category.to_string(),
+        )
+    };
+    let err = add(&mut deps, &minter, "stealth").unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized { .. })
+    ));
+    for variant in ["Combat", "combat ", ""] {
+        let err = add(&mut deps, &owner, variant).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidCategory { .. }));
+    }
+    let err = add(&mut deps, &owner, "combat").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CategoryAlreadyRegistered {
+            category: "combat".to_string()
+        }
+    );
+    add(&mut deps, &owner, "stealth").unwrap();
+
+    let categories = |deps: &Deps, start_after: Option<&str>, limit: Option<u32>| {
+        let res: CategoriesResponse = from_json(
+            query_categories(deps.as_ref(), start_after.map(str::to_string), limit).unwrap(),
+        )
+        .unwrap();
+        res.categories
+    };
+    assert_eq!(
+        categories(&deps, Some("pve"), Some(2)),
+        vec!["retro", "social"]
+    );
+    assert_eq!(categories(&deps, Some("social"), None), vec!["stealth"]);
+
+    // Unregistered categories cannot be minted or edited in
+    let mint = |deps: &mut Deps, achievement_id: &str, category: &str| {
+        execute_mint(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&minter, &[]),
+            player.to_string(),
+            achievement_id.to_string(),
+            category.to_string(),
+            Timestamp::from_seconds(1700000000),
+            "Test achievement".to_string(),
+            "rare".to_string(),
+            None,
+            true,
+            None,
+        )
+    };
+    let err = mint(&mut deps, "ghost", "Combat").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnknownCategory {
+            category: "Combat".to_string()
+        }
+    );
+    mint(&mut deps, "ghost", "stealth").unwrap();
+    let err = execute_update_metadata(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        "1".to_string(),
+        None,
+        None,
+        None,
+        Some("combat ".to_string()),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::UnknownCategory { .. }));
+
+    // Removing a category stops new mints; existing tokens keep it
+    execute_remove_category(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "stealth".to_string(),
+    )
+    .unwrap();
+    let err = mint(&mut deps, "shadow", "stealth").unwrap_err();
+    assert!(matches!(err, ContractError::UnknownCategory { .. }));
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), "1".to_string()).unwrap()).unwrap();
+    assert_eq!(nft.metadata.category, "stealth");
+    let err = execute_remove_category(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "stealth".to_string(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::UnknownCategory { .. }));
+}
+
+#[test]
+fn test_update_metadata() {
+    let mut deps = setup();
+    let minter = a(&deps, "minter");
+    let player = a(&deps, "player1");
+    let token_id = mint_achievement(&mut deps, "player1", "first_hack", true);
+
+    let err = execute_update_metadata(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        token_id.clone(),
+        Some("mine now".to_string()),
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "minter".to_string()
+        }
+    );
+
+    let res = execute_update_metadata(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        Some("First successful hack".to_string()),
+        None,
+        Some("ipfs://fixed-art".to_string()),
+        None,
+    )
+    .unwrap();
+    let attr = |key: &str| {
+        res.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .map(|a| a.value.clone())
+    };
+    assert_eq!(attr("old_description").as_deref(), Some("Test achievement"));
+    assert_eq!(
+        attr("new_description").as_deref(),
+        Some("First successful hack")
+    );
+    assert_eq!(attr("new_token_uri").as_deref(), Some("ipfs://fixed-art"));
+    assert_eq!(attr("old_rarity"), None);
+
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), token_id.clone()).unwrap()).unwrap();
+    assert_eq!(nft.metadata.description, "First successful hack");
+    assert_eq!(nft.metadata.rarity, "rare");
+    assert_eq!(nft.token_uri.as_deref(), Some("ipfs://fixed-art"));
+
+    // Same token id, dedup index intact
+    let check: AchievementCheckResponse = from_json(
+        query_has_achievement(deps.as_ref(), player.to_string(), "first_hack".to_string()).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(check.token_id, Some(token_id));
+}
+
+// ─── Tiered Achievements ────────────────────────────────────────────────────
+
+#[test]
+fn test_upgrade_achievement_tier() {
+    let mut deps = setup();
+    let minter = a(&deps, "minter");
+    let player = a(&deps, "player1");
+    let token_id = mint_achievement(&mut deps, "player1", "hacks_completed", true);
+    mint_achievement(&mut deps, "player2", "hacks_completed", true);
+
+    let err = execute_upgrade_achievement(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        token_id.clone(),
+        1,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "minter".to_string()
+        }
+    );
+
+    let res = execute_upgrade_achievement(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        2,
+    )
+    .unwrap();
+    let attr = |key: &str| {
+        res.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .map(|a| a.value.clone())
+    };
+    assert_eq!(attr("old_tier").as_deref(), Some("0"));
+    assert_eq!(attr("new_tier").as_deref(), Some("2"));
+
+    // Tiers only go up
+    let err = execute_upgrade_achievement(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        2,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TierNotHigher {
+            current: 2,
+            requested: 2
+        }
+    );
+
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), token_id.clone()).unwrap()).unwrap();
+    assert_eq!(nft.metadata.tier, 2);
+
+    // Only the upgraded holder passes the tier filter
+    let res: OwnersOfAchievementResponse = from_json(
+        query_owners_of_achievement(
+            deps.as_ref(),
+            "hacks_completed".to_string(),
+            Some(2),
+            None,
+            None,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.owners,
+        vec![AchievementOwner {
+            owner: player.to_string(),
+            token_id,
+            tier: 2,
+        }]
+    );
+}
+
+// ─── Revocation ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_revoke_achievement() {
+    let mut deps = setup();
+    let minter = a(&deps, "minter");
+    let player = a(&deps, "player1");
+    let token_id = mint_achievement(&mut deps, "player1", "speed_run", false);
+
+    let err = execute_revoke_achievement(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        token_id.clone(),
+        "self".to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "minter".to_string()
+        }
+    );
+
+    execute_revoke_achievement(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        "speedhack detected".to_string(),
+    )
+    .unwrap();
+
+    // Kept as an audit record, but no longer counts as held
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), token_id.clone()).unwrap()).unwrap();
+    let revoked = nft.revoked.unwrap();
+    assert_eq!(revoked.reason, "speedhack detected");
+    assert_eq!(revoked.revoked_by, minter);
+    let check: AchievementCheckResponse = from_json(
+        query_has_achievement(deps.as_ref(), player.to_string(), "speed_run".to_string()).unwrap(),
+    )
+    .unwrap();
+    assert!(!check.has_achievement);
+    assert_eq!(check.token_id, Some(token_id.clone()));
+
+    // Frozen in place
+    let player2 = a(&deps, "player2");
+    let err = execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        player2.to_string(),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TokenRevoked {
+            token_id: token_id.clone()
+        }
+    );
+    let err = execute_revoke_achievement(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        "again".to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::TokenRevoked { token_id });
+}
+
 // ─── Soulbound Enforcement ──────────────────────────────────────────────────
 
 #[test]
@@ -260,6 +618,7 @@ fn test_soulbound_approve_rejected() {
         info,
         player2.to_string(),
         token_id,
+        None,
     )
     .unwrap_err();
 
@@ -328,6 +687,7 @@ fn test_non_soulbound_approve_and_transfer() {
         info,
         player2.to_string(),
         token_id.clone(),
+        None,
     )
     .unwrap();
 
@@ -347,6 +707,89 @@ fn test_non_soulbound_approve_and_transfer() {
     assert_eq!(nft.owner, player2.to_string());
 }
 
+#[test]
+fn test_approval_expiry() {
+    let mut deps = setup();
+    let token_id = mint_achievement(&mut deps, "player1", "speed_run", false);
+    let player1 = a(&deps, "player1");
+    let market = a(&deps, "market");
+    let env = mock_env();
+
+    let err = execute_approve(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&player1, &[]),
+        market.to_string(),
+        token_id.clone(),
+        Some(Expiration::AtHeight(env.block.height)),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidExpiry);
+
+    let expires = Expiration::AtHeight(env.block.height + 10);
+    execute_approve(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&player1, &[]),
+        market.to_string(),
+        token_id.clone(),
+        Some(expires),
+    )
+    .unwrap();
+    let approval: ApprovalResponse = from_json(
+        query_approval(deps.as_ref(), env.clone(), token_id.clone(), market.to_string()).unwrap(),
+    )
+    .unwrap();
+    assert!(approval.approved);
+    assert_eq!(approval.expires, Some(expires));
+
+    // Once lapsed the approval grants nothing and drops out of OwnerOf
+    let mut later = env.clone();
+    later.block.height += 10;
+    let err = execute_transfer_nft(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&market, &[]),
+        market.to_string(),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized { .. }));
+    let approval: ApprovalResponse = from_json(
+        query_approval(deps.as_ref(), later.clone(), token_id.clone(), market.to_string()).unwrap(),
+    )
+    .unwrap();
+    assert!(!approval.approved);
+    let owner: OwnerOfResponse =
+        from_json(query_owner_of(deps.as_ref(), later.clone(), token_id.clone()).unwrap()).unwrap();
+    assert!(owner.approvals.is_empty());
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), token_id.clone()).unwrap()).unwrap();
+    assert_eq!(nft.approval_expires, Some(expires));
+
+    // Approving again without a deadline clears the old one
+    execute_approve(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&player1, &[]),
+        market.to_string(),
+        token_id.clone(),
+        None,
+    )
+    .unwrap();
+    execute_transfer_nft(
+        deps.as_mut(),
+        later,
+        message_info(&market, &[]),
+        market.to_string(),
+        token_id.clone(),
+    )
+    .unwrap();
+    let nft: NftInfoResponse = from_json(query_nft_info(deps.as_ref(), token_id).unwrap()).unwrap();
+    assert_eq!(nft.owner, market.to_string());
+    assert_eq!(nft.approval_expires, None);
+}
+
 #[test]
 fn test_unauthorized_transfer_fails() {
     let mut deps = setup();
@@ -389,6 +832,7 @@ fn test_batch_mint() {
             rarity: "common".to_string(),
             token_uri: None,
             soulbound: true,
+            tier: None,
         })
         .collect();
 
@@ -401,6 +845,64 @@ fn test_batch_mint() {
     assert_eq!(count.count, 5);
 }
 
+#[test]
+fn test_mint_to_many() {
+    let mut deps = setup();
+    let minter = a(&deps, "minter");
+    mint_achievement(&mut deps, "player1", "season_1", true);
+
+    let mint_to_many = |deps: &mut Deps, sender: &Addr, recipients: Vec<String>| {
+        execute_mint_to_many(
+            deps.as_mut(),
+            mock_env(),
+            message_info(sender, &[]),
+            "season_1".to_string(),
+            "pve".to_string(),
+            "epic".to_string(),
+            "Season 1 finisher".to_string(),
+            Timestamp::from_seconds(1700000000),
+            true,
+            recipients,
+        )
+    };
+    let players: Vec<String> = ["player1", "player2", "player3", "player2"]
+        .iter()
+        .map(|name| a(&deps, name).to_string())
+        .collect();
+
+    let player2 = a(&deps, "player2");
+    let err = mint_to_many(&mut deps, &player2, players.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized { .. }));
+    let too_many = vec![player2.to_string(); 201];
+    let err = mint_to_many(&mut deps, &minter, too_many).unwrap_err();
+    assert_eq!(err, ContractError::BatchTooLarge { max: 200 });
+
+    // Existing holders and repeats in the list are skipped
+    let res = mint_to_many(&mut deps, &minter, players.clone()).unwrap();
+    let attr = |key: &str| {
+        res.attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .unwrap()
+            .value
+            .clone()
+    };
+    assert_eq!(attr("count"), "2");
+    assert_eq!(attr("skipped"), "2");
+    let owners: Vec<_> = res
+        .events
+        .iter()
+        .map(|e| e.attributes[1].value.clone())
+        .collect();
+    assert_eq!(owners, [players[1].clone(), players[2].clone()]);
+
+    // Resending the chunk is a no-op
+    let res = mint_to_many(&mut deps, &minter, players).unwrap();
+    assert!(res.events.is_empty());
+    let count: NumTokensResponse = from_json(query_num_tokens(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(count.count, 3);
+}
+
 #[test]
 fn test_batch_mint_with_duplicate_fails() {
     let mut deps = setup();
@@ -421,6 +923,7 @@ fn test_batch_mint_with_duplicate_fails() {
             rarity: "common".to_string(),
             token_uri: None,
             soulbound: true,
+            tier: None,
         },
         MintRequest {
             to: player.to_string(),
@@ -431,6 +934,7 @@ fn test_batch_mint_with_duplicate_fails() {
             rarity: "common".to_string(),
             token_uri: None,
             soulbound: true,
+            tier: None,
         },
     ];
 
@@ -465,6 +969,7 @@ fn test_batch_mint_too_large_fails() {
             rarity: "common".to_string(),
             token_uri: None,
             soulbound: true,
+            tier: None,
         })
         .collect();
 
@@ -472,17 +977,137 @@ fn test_batch_mint_too_large_fails() {
     assert_eq!(err, ContractError::BatchTooLarge { max: 25 });
 }
 
-// ─── Pause ──────────────────────────────────────────────────────────────────
+// ─── Batch Burn ─────────────────────────────────────────────────────────────
 
 #[test]
-fn test_pause_blocks_mint_and_transfer() {
+fn test_batch_burn() {
     let mut deps = setup();
-    let owner = a(&deps, "owner");
     let minter = a(&deps, "minter");
     let player = a(&deps, "player1");
-    let player2 = a(&deps, "player2");
+    let spender = a(&deps, "spender");
+    let t1 = mint_achievement(&mut deps, "player1", "season_1_a", false);
+    let t2 = mint_achievement(&mut deps, "player1", "season_1_b", true);
+    let keep = mint_achievement(&mut deps, "player1", "permanent", true);
 
-    // Mint one non-soulbound before pausing
+    // Approval on a burned token must not linger
+    execute_approve(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        spender.to_string(),
+        t1.clone(),
+        None,
+    )
+    .unwrap();
+
+    // Atomic: an unknown id fails the whole batch
+    let err = execute_batch_burn(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        vec![t1.clone(), "999".to_string()],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TokenNotFound {
+            token_id: "999".to_string()
+        }
+    );
+    assert!(query_nft_info(deps.as_ref(), t1.clone()).is_ok());
+
+    execute_batch_burn(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        vec![t1.clone(), t2],
+    )
+    .unwrap();
+
+    let tokens: TokensResponse =
+        from_json(query_tokens(deps.as_ref(), player.to_string(), None, None).unwrap()).unwrap();
+    assert_eq!(tokens.tokens, vec![keep]);
+    let check: AchievementCheckResponse = from_json(
+        query_has_achievement(deps.as_ref(), player.to_string(), "season_1_a".to_string()).unwrap(),
+    )
+    .unwrap();
+    assert!(!check.has_achievement);
+    let approval: ApprovalResponse =
+        from_json(query_approval(deps.as_ref(), mock_env(), t1, a(&deps, "spender").to_string()).unwrap())
+            .unwrap();
+    assert!(!approval.approved);
+}
+
+#[test]
+fn test_burn_does_not_reuse_token_ids() {
+    let mut deps = setup();
+    let minter = a(&deps, "minter");
+    let t1 = mint_achievement(&mut deps, "player1", "a", false);
+    let t2 = mint_achievement(&mut deps, "player1", "b", false);
+
+    execute_batch_burn(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        vec![t1],
+    )
+    .unwrap();
+    let t3 = mint_achievement(&mut deps, "player1", "c", false);
+    assert_eq!(t3, "3");
+
+    // Token 2 is untouched and the live count reflects the burn
+    let nft: NftInfoResponse = from_json(query_nft_info(deps.as_ref(), t2).unwrap()).unwrap();
+    assert_eq!(nft.metadata.achievement_id, "b");
+    let num: NumTokensResponse = from_json(query_num_tokens(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(num.count, 2);
+}
+
+#[test]
+fn test_batch_burn_limits() {
+    let mut deps = setup();
+    let minter = a(&deps, "minter");
+    let player = a(&deps, "player1");
+
+    let err = execute_batch_burn(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        vec![],
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::EmptyBatch);
+
+    let ids: Vec<String> = (1..=101).map(|i| i.to_string()).collect();
+    let err =
+        execute_batch_burn(deps.as_mut(), mock_env(), message_info(&minter, &[]), ids).unwrap_err();
+    assert_eq!(err, ContractError::BatchTooLarge { max: 100 });
+
+    let err = execute_batch_burn(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        vec!["1".to_string()],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "minter".to_string()
+        }
+    );
+}
+
+// ─── Pause ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_pause_blocks_mint_and_transfer() {
+    let mut deps = setup();
+    let owner = a(&deps, "owner");
+    let minter = a(&deps, "minter");
+    let player = a(&deps, "player1");
+    let player2 = a(&deps, "player2");
+
+    // Mint one non-soulbound before pausing
     mint_achievement(&mut deps, "player1", "speed_run", false);
 
     // Pause
@@ -503,9 +1128,10 @@ fn test_pause_blocks_mint_and_transfer() {
         "rare".to_string(),
         None,
         false,
+        None,
     )
     .unwrap_err();
-    assert_eq!(err, ContractError::Paused);
+    assert_eq!(err, ContractError::Admin(AdminError::Paused));
 
     // Transfer fails
     let info = message_info(&player, &[]);
@@ -517,7 +1143,7 @@ fn test_pause_blocks_mint_and_transfer() {
         "1".to_string(),
     )
     .unwrap_err();
-    assert_eq!(err, ContractError::Paused);
+    assert_eq!(err, ContractError::Admin(AdminError::Paused));
 
     // Unpause
     let info = message_info(&owner, &[]);
@@ -541,16 +1167,168 @@ fn test_pause_blocks_mint_and_transfer() {
 fn test_minter_transfer() {
     let mut deps = setup();
     let owner = a(&deps, "owner");
+    let minter = a(&deps, "minter");
     let new_minter = a(&deps, "new_minter");
+    let raid_server = a(&deps, "raid_server");
+    let player = a(&deps, "player1");
 
     let info = message_info(&owner, &[]);
-    execute_propose_minter(deps.as_mut(), mock_env(), info, new_minter.to_string()).unwrap();
+    execute_add_minter(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        raid_server.to_string(),
+    )
+    .unwrap();
+    let err = execute_propose_minter(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        new_minter.to_string(),
+        Some(player.to_string()),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotMinter {
+            address: player.to_string()
+        }
+    );
+    execute_propose_minter(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        new_minter.to_string(),
+        Some(minter.to_string()),
+    )
+    .unwrap();
 
     let info = message_info(&new_minter, &[]);
     execute_accept_minter(deps.as_mut(), mock_env(), info).unwrap();
 
-    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
-    assert_eq!(config.minter, new_minter);
+    // Only the rotated key is replaced; the raid server keeps its role
+    let minters: MintersResponse =
+        from_json(query_minters(deps.as_ref(), None, None).unwrap()).unwrap();
+    let mut expected = vec![new_minter.to_string(), raid_server.to_string()];
+    expected.sort();
+    assert_eq!(minters.minters, expected);
+
+    // The old minter lost the role
+    let err = execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        player.to_string(),
+        "raid_clear".to_string(),
+        "pve".to_string(),
+        Timestamp::from_seconds(1700000000),
+        "Cleared a raid".to_string(),
+        "epic".to_string(),
+        None,
+        true,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "minter".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_add_and_remove_minter() {
+    let mut deps = setup();
+    let owner = a(&deps, "owner");
+    let raid_server = a(&deps, "raid_server");
+    let player = a(&deps, "player1");
+
+    let mint_from = |deps: &mut Deps, sender: &Addr| {
+        execute_mint(
+            deps.as_mut(),
+            mock_env(),
+            message_info(sender, &[]),
+            player.to_string(),
+            "raid_clear".to_string(),
+            "pve".to_string(),
+            Timestamp::from_seconds(1700000000),
+            "Cleared a raid".to_string(),
+            "epic".to_string(),
+            None,
+            true,
+            None,
+        )
+    };
+
+    let err = execute_add_minter(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&raid_server, &[]),
+        raid_server.to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+
+    execute_add_minter(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        raid_server.to_string(),
+    )
+    .unwrap();
+    let err = execute_add_minter(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        raid_server.to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AlreadyMinter {
+            address: raid_server.to_string()
+        }
+    );
+
+    // Both the original and the new minter can mint
+    let minters: MintersResponse =
+        from_json(query_minters(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert_eq!(minters.minters.len(), 2);
+    mint_from(&mut deps, &raid_server).unwrap();
+
+    execute_remove_minter(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        raid_server.to_string(),
+    )
+    .unwrap();
+    let err = mint_from(&mut deps, &raid_server).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "minter".to_string()
+        }
+    );
+    let err = execute_remove_minter(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        raid_server.to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotMinter {
+            address: raid_server.to_string()
+        }
+    );
 }
 
 #[test]
@@ -561,11 +1339,173 @@ fn test_wrong_address_cannot_accept_minter() {
     let rando = a(&deps, "rando");
 
     let info = message_info(&owner, &[]);
-    execute_propose_minter(deps.as_mut(), mock_env(), info, new_minter.to_string()).unwrap();
+    execute_propose_minter(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        new_minter.to_string(),
+        None,
+    )
+    .unwrap();
 
     let info = message_info(&rando, &[]);
     let err = execute_accept_minter(deps.as_mut(), mock_env(), info).unwrap_err();
-    assert_eq!(err, ContractError::NotPendingMinter);
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::NotPendingRole {
+            role: "minter".to_string()
+        })
+    );
+}
+
+// ─── Contract-Triggered Minting ─────────────────────────────────────────────
+
+#[test]
+fn test_mint_for_authorized_contract() {
+    let mut deps = setup();
+    let owner = a(&deps, "owner");
+    let minter = a(&deps, "minter");
+    let dao = a(&deps, "corporation_dao");
+    let founder = a(&deps, "founder");
+
+    let mint_for = |deps: &mut Deps, sender: &Addr| {
+        execute_mint_for(
+            deps.as_mut(),
+            mock_env(),
+            message_info(sender, &[]),
+            MintRequest {
+                to: founder.to_string(),
+                achievement_id: "corporation_founded".to_string(),
+                category: "corporation".to_string(),
+                earned_at: Timestamp::from_seconds(1700000000),
+                description: "Founded a corporation".to_string(),
+                rarity: "rare".to_string(),
+                token_uri: None,
+                soulbound: true,
+                tier: None,
+            },
+        )
+    };
+
+    // Minters cannot use MintFor, and the DAO is not yet on the list
+    for sender in [&minter, &dao] {
+        let err = mint_for(&mut deps, sender).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Unauthorized {
+                role: "authorized contract".to_string()
+            }
+        );
+    }
+
+    let err = execute_add_authorized_contract(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        dao.to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+    execute_add_authorized_contract(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        dao.to_string(),
+    )
+    .unwrap();
+    let err = execute_add_authorized_contract(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        dao.to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AlreadyAuthorizedContract {
+            address: dao.to_string()
+        }
+    );
+    let contracts: AuthorizedContractsResponse =
+        from_json(query_authorized_contracts(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert_eq!(contracts.contracts, vec![dao.to_string()]);
+
+    let res = mint_for(&mut deps, &dao).unwrap();
+    assert_eq!(res.attributes[0].value, "mint_for");
+    assert_eq!(
+        res.events[0],
+        Event::from(MintEvent {
+            token_id: "1".to_string(),
+            owner: founder.to_string(),
+            minter: dao.to_string(),
+        })
+    );
+    let check: AchievementCheckResponse = from_json(
+        query_has_achievement(
+            deps.as_ref(),
+            founder.to_string(),
+            "corporation_founded".to_string(),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(check.has_achievement);
+
+    // Authorized contracts are not minters
+    let err = execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&dao, &[]),
+        founder.to_string(),
+        "other".to_string(),
+        "corporation".to_string(),
+        Timestamp::from_seconds(1700000000),
+        "desc".to_string(),
+        "rare".to_string(),
+        None,
+        true,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "minter".to_string()
+        }
+    );
+
+    execute_remove_authorized_contract(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        dao.to_string(),
+    )
+    .unwrap();
+    let err = mint_for(&mut deps, &dao).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "authorized contract".to_string()
+        }
+    );
+    let err = execute_remove_authorized_contract(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        dao.to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotAuthorizedContract {
+            address: dao.to_string()
+        }
+    );
 }
 
 // ─── Achievements By Owner Query ────────────────────────────────────────────
@@ -602,8 +1542,445 @@ fn test_achievements_by_owner() {
     assert_eq!(result.achievements.len(), 1);
 }
 
-// ─── Operator Approval Still Works (but soulbound tokens stay put) ──────────
-
+#[test]
+fn test_achievements_by_owner_and_category() {
+    let mut deps = setup();
+    let minter = a(&deps, "minter");
+    let player1 = a(&deps, "player1");
+    let player2 = a(&deps, "player2");
+    mint_achievement(&mut deps, "player1", "ach_a", true);
+    mint_achievement(&mut deps, "player1", "ach_b", true);
+    mint_achievement(&mut deps, "player1", "ach_c", false);
+
+    let query = |deps: &Deps, owner: &Addr, category: &str| -> Vec<String> {
+        let res: AchievementsResponse = from_json(
+            query_achievements_by_owner_and_category(
+                deps.as_ref(),
+                owner.to_string(),
+                category.to_string(),
+                None,
+                None,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        res.achievements.into_iter().map(|a| a.token_id).collect()
+    };
+    assert_eq!(query(&deps, &player1, "combat"), vec!["1", "2", "3"]);
+
+    // Recategorising moves the token between index buckets
+    execute_update_metadata(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        "2".to_string(),
+        None,
+        None,
+        None,
+        Some("social".to_string()),
+    )
+    .unwrap();
+    assert_eq!(query(&deps, &player1, "combat"), vec!["1", "3"]);
+    assert_eq!(query(&deps, &player1, "social"), vec!["2"]);
+
+    // Transfer and burn keep the index in step
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        player2.to_string(),
+        "3".to_string(),
+    )
+    .unwrap();
+    execute_burn(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        "1".to_string(),
+    )
+    .unwrap();
+    assert!(query(&deps, &player1, "combat").is_empty());
+    assert_eq!(query(&deps, &player2, "combat"), vec!["3"]);
+}
+
+#[test]
+fn test_owners_of_achievement() {
+    let mut deps = setup();
+    mint_achievement(&mut deps, "player1", "raid_clear", false);
+    mint_achievement(&mut deps, "player2", "raid_clear", true);
+    mint_achievement(&mut deps, "player2", "other", true);
+
+    let query = |deps: &Deps| -> Vec<String> {
+        let res: OwnersOfAchievementResponse = from_json(
+            query_owners_of_achievement(deps.as_ref(), "raid_clear".to_string(), None, None, None)
+                .unwrap(),
+        )
+        .unwrap();
+        res.owners.into_iter().map(|o| o.owner).collect()
+    };
+    let mut expected = vec![
+        a(&deps, "player1").to_string(),
+        a(&deps, "player2").to_string(),
+    ];
+    expected.sort();
+    assert_eq!(query(&deps), expected);
+
+    // Transfer moves the holder; burn drops it
+    let player1 = a(&deps, "player1");
+    let player3 = a(&deps, "player3");
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        player3.to_string(),
+        "1".to_string(),
+    )
+    .unwrap();
+    let minter = a(&deps, "minter");
+    execute_burn(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        "2".to_string(),
+    )
+    .unwrap();
+    assert_eq!(query(&deps), vec![player3.to_string()]);
+}
+
+// ─── Showcase ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_showcase_order_and_pruning() {
+    let mut deps = setup();
+    let first = mint_achievement(&mut deps, "player1", "first_hack", true);
+    let speed = mint_achievement(&mut deps, "player1", "speed_run", false);
+    let boss = mint_achievement(&mut deps, "player1", "boss_kill", false);
+    let theirs = mint_achievement(&mut deps, "player2", "first_hack", true);
+    let player1 = a(&deps, "player1");
+    let player2 = a(&deps, "player2");
+    let minter = a(&deps, "minter");
+
+    let err = execute_set_showcase(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        vec![first.clone(), theirs],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "token owner".to_string()
+        }
+    );
+    let err = execute_set_showcase(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        vec![first.clone(), first.clone()],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DuplicateShowcaseToken {
+            token_id: first.clone()
+        }
+    );
+    let err = execute_set_showcase(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        (0..13).map(|i| i.to_string()).collect(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ShowcaseTooLarge { max: 12 });
+
+    // Player-chosen order is kept as given
+    execute_set_showcase(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        vec![boss.clone(), first.clone(), speed.clone()],
+    )
+    .unwrap();
+    let showcase: ShowcaseResponse =
+        from_json(query_showcase(deps.as_ref(), player1.to_string()).unwrap()).unwrap();
+    let ids: Vec<_> = showcase.tokens.iter().map(|t| t.token_id.clone()).collect();
+    assert_eq!(ids, vec![boss.clone(), first.clone(), speed.clone()]);
+
+    // Transferred and burned items drop out without the owner touching it
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        player2.to_string(),
+        speed,
+    )
+    .unwrap();
+    execute_burn(deps.as_mut(), mock_env(), message_info(&minter, &[]), boss).unwrap();
+    let showcase: ShowcaseResponse =
+        from_json(query_showcase(deps.as_ref(), player1.to_string()).unwrap()).unwrap();
+    let ids: Vec<_> = showcase.tokens.iter().map(|t| t.token_id.clone()).collect();
+    assert_eq!(ids, vec![first]);
+
+    execute_set_showcase(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        vec![],
+    )
+    .unwrap();
+    let showcase: ShowcaseResponse =
+        from_json(query_showcase(deps.as_ref(), player1.to_string()).unwrap()).unwrap();
+    assert!(showcase.tokens.is_empty());
+}
+
+// ─── Merkle Claims ──────────────────────────────────────────────────────────
+
+fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Sha256::new();
+    hasher.update(lo);
+    hasher.update(hi);
+    hasher.finalize().into()
+}
+
+#[test]
+fn test_merkle_claim() {
+    let mut deps = setup();
+    let owner = a(&deps, "owner");
+    let player1 = a(&deps, "player1");
+    let player2 = a(&deps, "player2");
+
+    // Three leaves: root = H(H(l0, l1), l2)
+    let l0 = claim_leaf(&player1, "season1_veteran");
+    let l1 = claim_leaf(&player2, "season1_veteran");
+    let l2 = claim_leaf(&player1, "beta_tester");
+    let h01 = hash_pair(l0, l1);
+    let root = hash_pair(h01, l2);
+    let hex = |h: [u8; 32]| HexBinary::from(h.to_vec());
+
+    let expiry = mock_env().block.time.plus_seconds(86_400);
+    let metadata = ClaimMetadata {
+        category: "retro".to_string(),
+        description: "Season 1 participant".to_string(),
+        rarity: "rare".to_string(),
+        token_uri: None,
+        soulbound: true,
+    };
+    let err = execute_set_claim_root(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        "s1".to_string(),
+        hex(root),
+        expiry,
+        metadata.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+    execute_set_claim_root(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "s1".to_string(),
+        hex(root),
+        expiry,
+        metadata,
+    )
+    .unwrap();
+
+    // Wrong leaf for the proof
+    let err = execute_claim(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player2, &[]),
+        "s1".to_string(),
+        "beta_tester".to_string(),
+        vec![hex(h01)],
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidProof);
+
+    execute_claim(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        "s1".to_string(),
+        "season1_veteran".to_string(),
+        vec![hex(l1), hex(l2)],
+    )
+    .unwrap();
+    let check: AchievementCheckResponse = from_json(
+        query_has_achievement(
+            deps.as_ref(),
+            player1.to_string(),
+            "season1_veteran".to_string(),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(check.has_achievement);
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), check.token_id.unwrap()).unwrap()).unwrap();
+    assert_eq!(nft.metadata.category, "retro");
+    assert!(nft.soulbound);
+
+    // Burning the token does not re-open the claim
+    let minter = a(&deps, "minter");
+    execute_burn(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        "1".to_string(),
+    )
+    .unwrap();
+    let err = execute_claim(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        "s1".to_string(),
+        "season1_veteran".to_string(),
+        vec![hex(l1), hex(l2)],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AlreadyClaimed {
+            campaign_id: "s1".to_string(),
+            achievement_id: "season1_veteran".to_string()
+        }
+    );
+
+    // Closed after expiry
+    let mut env = mock_env();
+    env.block.time = expiry;
+    let err = execute_claim(
+        deps.as_mut(),
+        env,
+        message_info(&player1, &[]),
+        "s1".to_string(),
+        "beta_tester".to_string(),
+        vec![hex(h01)],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CampaignExpired {
+            campaign_id: "s1".to_string()
+        }
+    );
+}
+
+// ─── Soulbound Unbind ───────────────────────────────────────────────────────
+
+#[test]
+fn test_unbind_requires_holder_request_and_minter_approval() {
+    let mut deps = setup();
+    let minter = a(&deps, "minter");
+    let player1 = a(&deps, "player1");
+    let player2 = a(&deps, "player2");
+    let token_id = mint_achievement(&mut deps, "player1", "legacy_badge", true);
+
+    // Minter cannot unbind without the holder's request
+    let err = execute_approve_unbind(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NoUnbindRequest {
+            token_id: token_id.clone()
+        }
+    );
+
+    // Only the holder can request
+    let err = execute_request_unbind(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player2, &[]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "token owner".to_string()
+        }
+    );
+
+    execute_request_unbind(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        token_id.clone(),
+    )
+    .unwrap();
+    let res: UnbindRequestsResponse =
+        from_json(query_unbind_requests(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert_eq!(res.requests.len(), 1);
+    assert_eq!(res.requests[0].owner, player1.to_string());
+
+    // Holder alone cannot approve
+    let err = execute_approve_unbind(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "minter".to_string()
+        }
+    );
+
+    execute_approve_unbind(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+    )
+    .unwrap();
+    let res: UnbindRequestsResponse =
+        from_json(query_unbind_requests(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert!(res.requests.is_empty());
+
+    // Now transferable
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        player2.to_string(),
+        token_id.clone(),
+    )
+    .unwrap();
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), token_id.clone()).unwrap()).unwrap();
+    assert!(!nft.soulbound);
+    assert_eq!(nft.owner, player2.to_string());
+
+    let err = execute_request_unbind(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player2, &[]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotSoulbound { token_id });
+}
+
+// ─── Operator Approval Still Works (but soulbound tokens stay put) ──────────
+
 #[test]
 fn test_operator_can_transfer_non_soulbound_only() {
     let mut deps = setup();
@@ -654,3 +2031,481 @@ fn test_sequential_token_ids() {
         assert_eq!(token_id, (i + 1).to_string());
     }
 }
+
+// ─── Attestations ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_attestation_flow() {
+    let mut deps = setup();
+    let owner = a(&deps, "owner");
+    let minter = a(&deps, "minter");
+    let player = a(&deps, "player1");
+    let token_id = mint_achievement(&mut deps, "player1", "speed_run", false);
+
+    let sk = SigningKey::from_bytes((&[0x42u8; 32]).into()).unwrap();
+    let pubkey = Binary::from(sk.verifying_key().to_encoded_point(true).as_bytes());
+    let sign = |hash: &Binary| -> Binary {
+        let sig: Signature = sk.sign_prehash(hash.as_slice()).unwrap();
+        Binary::from(sig.to_bytes().as_slice())
+    };
+
+    // Only the holder can request
+    let err = execute_request_attestation(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "token owner".to_string()
+        }
+    );
+    let res = execute_request_attestation(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        token_id.clone(),
+    )
+    .unwrap();
+    let payload: AttestationPayloadResponse =
+        from_json(query_attestation_payload(deps.as_ref(), mock_env(), token_id.clone()).unwrap())
+            .unwrap();
+    assert_eq!(payload.payload_version, 2);
+    assert_eq!(
+        res.attributes[4].value,
+        HexBinary::from(payload.message_hash.as_slice()).to_hex()
+    );
+
+    let err = execute_submit_attestation(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        sign(&payload.message_hash),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AttestationSignerNotSet);
+
+    let err = execute_set_attestation_signer(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Some(Binary::from(vec![2u8; 20])),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidPubkeyLength { length: 20 });
+    execute_set_attestation_signer(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Some(pubkey),
+    )
+    .unwrap();
+
+    // A signature over anything else is rejected
+    let err = execute_submit_attestation(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        sign(&payload.domain_separator),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidAttestationSignature);
+
+    execute_submit_attestation(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        sign(&payload.message_hash),
+    )
+    .unwrap();
+    let res: AttestationResponse =
+        from_json(query_attestation(deps.as_ref(), mock_env(), token_id.clone()).unwrap()).unwrap();
+    assert!(res.current);
+    let attestation = res.attestation.unwrap();
+    assert_eq!(attestation.message_hash, payload.message_hash);
+    assert_eq!(attestation.attested_at, mock_env().block.time);
+
+    // A transfer changes the owner in the payload, so the old proof goes stale
+    let buyer = a(&deps, "buyer");
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        buyer.to_string(),
+        token_id.clone(),
+    )
+    .unwrap();
+    let res: AttestationResponse =
+        from_json(query_attestation(deps.as_ref(), mock_env(), token_id.clone()).unwrap()).unwrap();
+    assert!(!res.current);
+    assert!(res.attestation.is_some());
+
+    execute_revoke_achievement(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        "cheated".to_string(),
+    )
+    .unwrap();
+    let err = execute_request_attestation(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&buyer, &[]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::TokenRevoked { .. }));
+}
+
+// ─── Mint Hooks ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_mint_notifies_hook_contract() {
+    let mut deps = setup();
+    let owner = a(&deps, "owner");
+    let reputation = a(&deps, "reputation");
+    let minter = a(&deps, "minter");
+    let player = a(&deps, "player1");
+
+    let err = execute_set_notify_contract(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        Some(reputation.to_string()),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+    execute_set_notify_contract(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Some(reputation.to_string()),
+    )
+    .unwrap();
+
+    let res = execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        player.to_string(),
+        "first_blood".to_string(),
+        "combat".to_string(),
+        Timestamp::from_seconds(1700000000),
+        "Test achievement".to_string(),
+        "rare".to_string(),
+        None,
+        false,
+        None,
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].id, NOTIFY_REPLY_ID);
+    assert_eq!(res.messages[0].reply_on, ReplyOn::Error);
+    assert_eq!(
+        res.messages[0].msg,
+        WasmMsg::Execute {
+            contract_addr: reputation.to_string(),
+            msg: to_json_binary(&AchievementHookMsg::AchievementMintedHook {
+                owner: player.to_string(),
+                achievement_id: "first_blood".to_string(),
+                token_id: "1".to_string(),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()
+    );
+
+    // A failing hook keeps the mint and switches notifications off
+    #[allow(deprecated)]
+    let failed = Reply {
+        id: NOTIFY_REPLY_ID,
+        payload: Binary::default(),
+        gas_used: 0,
+        result: SubMsgResult::Err("out of gas".to_string()),
+    };
+    let res = reply(deps.as_mut(), mock_env(), failed.clone()).unwrap();
+    assert_eq!(res.attributes[0].value, "notify_disabled");
+    assert_eq!(res.attributes[2].value, "out of gas");
+    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(config.notify_contract, None);
+    // Later failures from the same batch are ignored
+    let res = reply(deps.as_mut(), mock_env(), failed.clone()).unwrap();
+    assert!(res.attributes.is_empty());
+
+    let token_id = mint_achievement(&mut deps, "player1", "second_wind", false);
+    assert_eq!(token_id, "2");
+    let err = reply(deps.as_mut(), mock_env(), Reply { id: 99, ..failed }).unwrap_err();
+    assert_eq!(err, ContractError::UnknownReplyId { id: 99 });
+}
+
+// ─── Collection Stats ───────────────────────────────────────────────────────
+
+fn stat(deps: &Deps) -> CollectionStatsResponse {
+    from_json(query_collection_stats(deps.as_ref()).unwrap()).unwrap()
+}
+
+fn counts(entries: &[StatCount]) -> Vec<(&str, u64)> {
+    entries.iter().map(|e| (e.name.as_str(), e.count)).collect()
+}
+
+#[test]
+fn test_collection_stats() {
+    let mut deps = setup();
+    let minter = a(&deps, "minter");
+    let player1 = a(&deps, "player1");
+    let player2 = a(&deps, "player2");
+    let t1 = mint_achievement(&mut deps, "player1", "first_hack", true);
+    let t2 = mint_achievement(&mut deps, "player1", "speed_run", false);
+    let t3 = mint_achievement(&mut deps, "player2", "first_hack", true);
+    execute_update_metadata(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        t2.clone(),
+        None,
+        Some("legendary".to_string()),
+        None,
+        Some("exploration".to_string()),
+    )
+    .unwrap();
+
+    let stats = stat(&deps);
+    assert_eq!(stats.total, 3);
+    assert_eq!(
+        counts(&stats.by_category),
+        vec![("combat", 2), ("exploration", 1)]
+    );
+    assert_eq!(
+        counts(&stats.by_rarity),
+        vec![("legendary", 1), ("rare", 2)]
+    );
+    assert_eq!((stats.soulbound, stats.transferable), (2, 1));
+    assert_eq!(stats.unique_holders, 2);
+
+    // Handing player1's only transferable token to player2 keeps both as holders
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player1, &[]),
+        player2.to_string(),
+        t2.clone(),
+    )
+    .unwrap();
+    assert_eq!(stat(&deps).unique_holders, 2);
+
+    // Burning player1's last token drops them; unbinding moves a token across
+    execute_burn(deps.as_mut(), mock_env(), message_info(&minter, &[]), t1).unwrap();
+    execute_request_unbind(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player2, &[]),
+        t3.clone(),
+    )
+    .unwrap();
+    execute_approve_unbind(deps.as_mut(), mock_env(), message_info(&minter, &[]), t3).unwrap();
+    let stats = stat(&deps);
+    assert_eq!(stats.total, 2);
+    assert_eq!(
+        counts(&stats.by_category),
+        vec![("combat", 1), ("exploration", 1)]
+    );
+    assert_eq!((stats.soulbound, stats.transferable), (0, 2));
+    assert_eq!(stats.unique_holders, 1);
+    assert!(!HOLDER_BALANCES.has(deps.as_ref().storage, &player1));
+
+    // A store from before the counters existed is counted by the backfill, and
+    // tokens moved ahead of its cursor are counted where they end up
+    COLLECTION_TOTALS.remove(deps.as_mut().storage);
+    CATEGORY_COUNTS.remove(deps.as_mut().storage, "combat");
+    CATEGORY_COUNTS.remove(deps.as_mut().storage, "exploration");
+    RARITY_COUNTS.remove(deps.as_mut().storage, "rare");
+    RARITY_COUNTS.remove(deps.as_mut().storage, "legendary");
+    HOLDER_BALANCES.remove(deps.as_mut().storage, &player2);
+    let backfill = MigrateMsg {
+        backfill: Some(BackfillMsg {
+            start_after: None,
+            limit: Some(1),
+        }),
+        ..MigrateMsg::default()
+    };
+    migrate(deps.as_mut(), mock_env(), backfill.clone()).unwrap();
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player2, &[]),
+        player1.to_string(),
+        "3".to_string(),
+    )
+    .unwrap();
+    migrate(deps.as_mut(), mock_env(), backfill.clone()).unwrap();
+    migrate(deps.as_mut(), mock_env(), backfill).unwrap();
+    let stats = stat(&deps);
+    assert_eq!(
+        counts(&stats.by_category),
+        vec![("combat", 1), ("exploration", 1)]
+    );
+    assert_eq!(
+        counts(&stats.by_rarity),
+        vec![("legendary", 1), ("rare", 1)]
+    );
+    assert_eq!((stats.soulbound, stats.transferable), (0, 2));
+    assert_eq!(stats.unique_holders, 2);
+
+    // A later backfill leaves the live counters alone
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::default()).unwrap();
+    assert_eq!(stat(&deps), stats);
+}
+
+// ─── Migration ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_chunked_backfill_migration() {
+    let mut deps = setup();
+    for i in 0..5 {
+        mint_achievement(&mut deps, "player1", &format!("ach_{}", i), false);
+    }
+    // Simulate a store written before the owner indexes and LAST_TOKEN_ID existed
+    let player = a(&deps, "player1");
+    for i in 1..=5 {
+        let token_id = i.to_string();
+        OWNER_TOKENS.remove(deps.as_mut().storage, (&player, &token_id));
+        OWNER_CATEGORY_TOKENS.remove(deps.as_mut().storage, (&player, "combat", &token_id));
+        ACHIEVEMENT_OWNERS.remove(deps.as_mut().storage, (&format!("ach_{}", i - 1), &player));
+    }
+    LAST_TOKEN_ID.remove(deps.as_mut().storage);
+
+    let status: MigrationStatusResponse =
+        from_json(query_migration_status(deps.as_ref()).unwrap()).unwrap();
+    assert!(status.done);
+
+    let backfill = MigrateMsg {
+        backfill: Some(BackfillMsg {
+            start_after: None,
+            limit: Some(2),
+        }),
+        ..MigrateMsg::default()
+    };
+    let res = migrate(deps.as_mut(), mock_env(), backfill.clone()).unwrap();
+    assert_eq!(res.attributes[2].value, "2");
+    assert_eq!(res.attributes[3].value, "false");
+    let status: MigrationStatusResponse =
+        from_json(query_migration_status(deps.as_ref()).unwrap()).unwrap();
+    assert!(!status.done);
+    assert_eq!(status.processed, 2);
+    assert_eq!(status.total_tokens, 5);
+    assert_eq!(status.cursor, Some("2".to_string()));
+
+    // Ids cannot be issued until LAST_TOKEN_ID is rebuilt
+    let minter = a(&deps, "minter");
+    let err = execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        player.to_string(),
+        "ach_new".to_string(),
+        "combat".to_string(),
+        Timestamp::from_seconds(1700000000),
+        "Test achievement".to_string(),
+        "rare".to_string(),
+        None,
+        false,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::BackfillInProgress);
+
+    // Each chunk resumes where the last one stopped
+    migrate(deps.as_mut(), mock_env(), backfill.clone()).unwrap();
+    let res = migrate(deps.as_mut(), mock_env(), backfill).unwrap();
+    assert_eq!(res.attributes[3].value, "true");
+    let status: MigrationStatusResponse =
+        from_json(query_migration_status(deps.as_ref()).unwrap()).unwrap();
+    assert!(status.done);
+    assert_eq!(status.processed, 5);
+
+    let tokens: TokensResponse =
+        from_json(query_tokens(deps.as_ref(), player.to_string(), None, None).unwrap()).unwrap();
+    assert_eq!(tokens.tokens.len(), 5);
+    let token_id = mint_achievement(&mut deps, "player1", "ach_new", false);
+    assert_eq!(token_id, "6");
+
+    // The plain `{}` payload from before chunking still means a full migration
+    let msg: MigrateMsg = from_json(b"{}").unwrap();
+    assert_eq!(msg, MigrateMsg::default());
+}
+
+#[test]
+fn test_migrate_seeds_category_registry() {
+    let mut deps = setup();
+    let owner = a(&deps, "owner");
+    mint_achievement(&mut deps, "player1", "ach_0", false);
+    // Simulate a store written before the category registry existed, with
+    // the kind of variants the registry is meant to stop
+    for category in SETUP_CATEGORIES {
+        CATEGORIES.remove(deps.as_mut().storage, category);
+    }
+    CATEGORIES_SEEDED.remove(deps.as_mut().storage);
+    for legacy in ["Combat", "combat "] {
+        CATEGORY_COUNTS
+            .save(deps.as_mut().storage, legacy, &1)
+            .unwrap();
+    }
+    let categories = |deps: &Deps| {
+        let res: CategoriesResponse =
+            from_json(query_categories(deps.as_ref(), None, None).unwrap()).unwrap();
+        res.categories
+    };
+
+    let msg = MigrateMsg {
+        categories: vec!["Governance".to_string()],
+        ..MigrateMsg::default()
+    };
+    let err = migrate(deps.as_mut(), mock_env(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidCategory { .. }));
+
+    // Valid categories already in use come back, plus any named in the
+    // message; invalid ones are left out and reported
+    let msg = MigrateMsg {
+        categories: vec!["governance".to_string()],
+        ..MigrateMsg::default()
+    };
+    let res = migrate(deps.as_mut(), mock_env(), msg).unwrap();
+    let rejected = res
+        .attributes
+        .iter()
+        .find(|attr| attr.key == "rejected_categories")
+        .unwrap();
+    assert_eq!(rejected.value, "Combat,combat ");
+    assert_eq!(categories(&deps), vec!["combat", "governance"]);
+    mint_achievement(&mut deps, "player1", "ach_1", false);
+
+    // Later upgrades leave the owner's removals alone, even an empty registry
+    for category in ["combat", "governance"] {
+        execute_remove_category(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            category.to_string(),
+        )
+        .unwrap();
+    }
+    let res = migrate(deps.as_mut(), mock_env(), MigrateMsg::default()).unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .all(|attr| attr.key != "rejected_categories"));
+    assert!(categories(&deps).is_empty());
+}
@@ -0,0 +1,95 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, MessageInfo, Response};
+use cw_storage_plus::Item;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::AdminError;
+
+/// A contract config with a single owner.
+pub trait Owned {
+    fn owner(&self) -> &Addr;
+    fn set_owner(&mut self, owner: Addr);
+}
+
+/// A contract config with a global pause flag.
+pub trait Pausable {
+    fn is_paused(&self) -> bool;
+    fn set_paused(&mut self, paused: bool);
+}
+
+/// Verify the caller is the contract owner.
+pub fn assert_owner<C>(deps: Deps, config: &Item<C>, sender: &Addr) -> Result<(), AdminError>
+where
+    C: Owned + Serialize + DeserializeOwned,
+{
+    if config.load(deps.storage)?.owner() != sender {
+        return Err(AdminError::Unauthorized {
+            role: "owner".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Verify the contract is not paused.
+pub fn assert_not_paused<C>(deps: Deps, config: &Item<C>) -> Result<(), AdminError>
+where
+    C: Pausable + Serialize + DeserializeOwned,
+{
+    if config.load(deps.storage)?.is_paused() {
+        return Err(AdminError::Paused);
+    }
+    Ok(())
+}
+
+// FIX: M-08 — reject unexpected funds
+pub fn reject_funds(info: &MessageInfo) -> Result<(), AdminError> {
+    if !info.funds.is_empty() {
+        return Err(AdminError::UnexpectedFunds);
+    }
+    Ok(())
+}
+
+/// Set the pause flag. The caller is responsible for authorization, since
+/// some contracts let roles other than the owner pause.
+pub fn set_paused<C>(deps: DepsMut, config: &Item<C>, paused: bool) -> Result<Response, AdminError>
+where
+    C: Pausable + Serialize + DeserializeOwned,
+{
+    let mut c = config.load(deps.storage)?;
+    if !paused && !c.is_paused() {
+        return Err(AdminError::NotPaused);
+    }
+    c.set_paused(paused);
+    config.save(deps.storage, &c)?;
+
+    let action = if paused { "pause" } else { "unpause" };
+    Ok(Response::new().add_attribute("action", action))
+}
+
+/// Owner-only pause. Pausing an already paused contract is a no-op.
+pub fn execute_pause<C>(
+    deps: DepsMut,
+    info: &MessageInfo,
+    config: &Item<C>,
+) -> Result<Response, AdminError>
+where
+    C: Owned + Pausable + Serialize + DeserializeOwned,
+{
+    reject_funds(info)?;
+    assert_owner(deps.as_ref(), config, &info.sender)?;
+    set_paused(deps, config, true)
+}
+
+/// Owner-only unpause.
+pub fn execute_unpause<C>(
+    deps: DepsMut,
+    info: &MessageInfo,
+    config: &Item<C>,
+) -> Result<Response, AdminError>
+where
+    C: Owned + Pausable + Serialize + DeserializeOwned,
+{
+    reject_funds(info)?;
+    assert_owner(deps.as_ref(), config, &info.sender)?;
+    set_paused(deps, config, false)
+}
@@ -0,0 +1,31 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+/// Errors raised by the shared admin handlers. Contracts wrap these with
+/// `#[error(transparent)]`, so the messages are identical everywhere.
+#[derive(Error, Debug, PartialEq)]
+pub enum AdminError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("unauthorized: only {role} can perform this action")]
+    Unauthorized { role: String },
+
+    #[error("contract is paused")]
+    Paused,
+
+    #[error("contract is not paused")]
+    NotPaused,
+
+    #[error("no {role} transfer pending")]
+    NoTransferPending { role: String },
+
+    #[error("caller is not the pending {role}")]
+    NotPendingRole { role: String },
+
+    #[error("{role} transfer already pending")]
+    TransferAlreadyPending { role: String },
+
+    #[error("unexpected funds sent with this message")]
+    UnexpectedFunds,
+}
@@ -0,0 +1,15 @@
+//! Admin plumbing shared by the SYSBREAK contracts: owner checks, the global
+//! pause switch, fund rejection and two-step role transfers.
+
+pub mod admin;
+pub mod error;
+pub mod transfer;
+
+pub use admin::{
+    assert_not_paused, assert_owner, execute_pause, execute_unpause, reject_funds, set_paused,
+    Owned, Pausable,
+};
+pub use error::AdminError;
+pub use transfer::{
+    ApplyToConfig, Minted, PendingMinterTransfer, PendingOwnerTransfer, RoleProposal, RoleTransfer,
+};
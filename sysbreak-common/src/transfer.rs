@@ -0,0 +1,184 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, MessageInfo, Response, StdResult, Storage,
+};
+use cw_storage_plus::Item;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::admin::{assert_owner, reject_funds, Owned};
+use crate::error::AdminError;
+
+/// A pending role hand-over.
+pub trait RoleProposal {
+    fn proposed(&self) -> &Addr;
+}
+
+/// How accepting a proposal changes the contract config.
+pub trait ApplyToConfig<C>: RoleProposal {
+    fn apply(self, config: &mut C);
+}
+
+/// A contract config with a minter role.
+pub trait Minted {
+    fn set_minter(&mut self, minter: Addr);
+}
+
+// FIX: H-04 — two-step owner transfer state
+#[cw_serde]
+pub struct PendingOwnerTransfer {
+    pub proposed_owner: Addr,
+}
+
+impl RoleProposal for PendingOwnerTransfer {
+    fn proposed(&self) -> &Addr {
+        &self.proposed_owner
+    }
+}
+
+impl<C: Owned> ApplyToConfig<C> for PendingOwnerTransfer {
+    fn apply(self, config: &mut C) {
+        config.set_owner(self.proposed_owner);
+    }
+}
+
+/// Two-step minter transfer state
+#[cw_serde]
+pub struct PendingMinterTransfer {
+    pub proposed_minter: Addr,
+    /// Existing minter key revoked when the proposal is accepted, for
+    /// contracts that keep a set of minters
+    #[serde(default)]
+    pub replaces: Option<Addr>,
+}
+
+impl RoleProposal for PendingMinterTransfer {
+    fn proposed(&self) -> &Addr {
+        &self.proposed_minter
+    }
+}
+
+impl<C: Minted> ApplyToConfig<C> for PendingMinterTransfer {
+    fn apply(self, config: &mut C) {
+        config.set_minter(self.proposed_minter);
+    }
+}
+
+/// Two-step transfer of a privileged role: the owner proposes, the proposed
+/// address accepts, and the owner can cancel in between. Only one transfer
+/// per role can be pending.
+pub struct RoleTransfer<P> {
+    role: &'static str,
+    pending: Item<P>,
+}
+
+impl<P> RoleTransfer<P>
+where
+    P: RoleProposal + Serialize + DeserializeOwned,
+{
+    pub const fn new(role: &'static str, pending_key: &'static str) -> Self {
+        Self {
+            role,
+            pending: Item::new(pending_key),
+        }
+    }
+
+    pub fn pending(&self, deps: Deps) -> StdResult<Option<P>> {
+        self.pending.may_load(deps.storage)
+    }
+
+    /// Record `proposal` (owner only). The proposed address must already be
+    /// validated.
+    pub fn propose<C>(
+        &self,
+        deps: DepsMut,
+        info: &MessageInfo,
+        config: &Item<C>,
+        proposal: P,
+    ) -> Result<Response, AdminError>
+    where
+        C: Owned + Serialize + DeserializeOwned,
+    {
+        reject_funds(info)?;
+        assert_owner(deps.as_ref(), config, &info.sender)?;
+
+        if self.pending.may_load(deps.storage)?.is_some() {
+            return Err(AdminError::TransferAlreadyPending {
+                role: self.role.to_string(),
+            });
+        }
+        let proposed = proposal.proposed().to_string();
+        self.pending.save(deps.storage, &proposal)?;
+
+        Ok(Response::new()
+            .add_attribute("action", format!("propose_{}", self.role))
+            .add_attribute(format!("proposed_{}", self.role), proposed))
+    }
+
+    /// Remove and return the pending proposal (proposed address only), for
+    /// roles that are not a single config field.
+    pub fn take(&self, storage: &mut dyn Storage, info: &MessageInfo) -> Result<P, AdminError> {
+        reject_funds(info)?;
+        let pending =
+            self.pending
+                .may_load(storage)?
+                .ok_or_else(|| AdminError::NoTransferPending {
+                    role: self.role.to_string(),
+                })?;
+        if info.sender != *pending.proposed() {
+            return Err(AdminError::NotPendingRole {
+                role: self.role.to_string(),
+            });
+        }
+        self.pending.remove(storage);
+        Ok(pending)
+    }
+
+    /// Apply the pending proposal to the config (proposed address only).
+    pub fn accept<C>(
+        &self,
+        deps: DepsMut,
+        info: &MessageInfo,
+        config: &Item<C>,
+    ) -> Result<Response, AdminError>
+    where
+        C: Serialize + DeserializeOwned,
+        P: ApplyToConfig<C>,
+    {
+        let pending = self.take(deps.storage, info)?;
+        let mut c = config.load(deps.storage)?;
+        pending.apply(&mut c);
+        config.save(deps.storage, &c)?;
+
+        Ok(Response::new()
+            .add_attribute("action", format!("accept_{}", self.role))
+            .add_attribute(format!("new_{}", self.role), info.sender.as_str()))
+    }
+
+    /// Drop the pending proposal (owner only).
+    pub fn cancel<C>(
+        &self,
+        deps: DepsMut,
+        info: &MessageInfo,
+        config: &Item<C>,
+    ) -> Result<Response, AdminError>
+    where
+        C: Owned + Serialize + DeserializeOwned,
+    {
+        reject_funds(info)?;
+        assert_owner(deps.as_ref(), config, &info.sender)?;
+
+        if self.pending.may_load(deps.storage)?.is_none() {
+            return Err(AdminError::NoTransferPending {
+                role: self.role.to_string(),
+            });
+        }
+        self.pending.remove(deps.storage);
+
+        Ok(Response::new().add_attribute("action", format!("cancel_{}_transfer", self.role)))
+    }
+
+    pub fn query_pending(&self, deps: Deps) -> StdResult<Binary> {
+        to_json_binary(&self.pending(deps)?)
+    }
+}
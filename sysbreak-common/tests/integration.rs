@@ -0,0 +1,191 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::testing::{message_info, mock_dependencies, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{coin, from_json, Addr, OwnedDeps};
+use cw_storage_plus::Item;
+
+use sysbreak_common::{
+    assert_not_paused, execute_pause, execute_unpause, AdminError, Owned, Pausable,
+    PendingOwnerTransfer, RoleTransfer,
+};
+
+type Deps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+#[cw_serde]
+struct Config {
+    owner: Addr,
+    paused: bool,
+}
+
+impl Owned for Config {
+    fn owner(&self) -> &Addr {
+        &self.owner
+    }
+
+    fn set_owner(&mut self, owner: Addr) {
+        self.owner = owner;
+    }
+}
+
+impl Pausable for Config {
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+}
+
+const CONFIG: Item<Config> = Item::new("config");
+const OWNER_TRANSFER: RoleTransfer<PendingOwnerTransfer> =
+    RoleTransfer::new("owner", "pending_owner");
+
+fn setup() -> Deps {
+    let mut deps = mock_dependencies();
+    let owner = deps.api.addr_make("owner");
+    CONFIG
+        .save(
+            deps.as_mut().storage,
+            &Config {
+                owner,
+                paused: false,
+            },
+        )
+        .unwrap();
+    deps
+}
+
+// ─── Role Transfer ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_owner_transfer_flow() {
+    let mut deps = setup();
+    let owner = deps.api.addr_make("owner");
+    let alice = deps.api.addr_make("alice");
+
+    let res = OWNER_TRANSFER
+        .propose(
+            deps.as_mut(),
+            &message_info(&owner, &[]),
+            &CONFIG,
+            PendingOwnerTransfer {
+                proposed_owner: alice.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.attributes[0].value, "propose_owner");
+    assert_eq!(res.attributes[1].key, "proposed_owner");
+
+    let pending: Option<PendingOwnerTransfer> =
+        from_json(OWNER_TRANSFER.query_pending(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(pending.unwrap().proposed_owner, alice);
+
+    let err = OWNER_TRANSFER
+        .accept(deps.as_mut(), &message_info(&owner, &[]), &CONFIG)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        AdminError::NotPendingRole {
+            role: "owner".to_string()
+        }
+    );
+
+    OWNER_TRANSFER
+        .accept(deps.as_mut(), &message_info(&alice, &[]), &CONFIG)
+        .unwrap();
+    assert_eq!(CONFIG.load(deps.as_ref().storage).unwrap().owner, alice);
+    assert!(OWNER_TRANSFER.pending(deps.as_ref()).unwrap().is_none());
+}
+
+#[test]
+fn test_role_transfer_guards() {
+    let mut deps = setup();
+    let owner = deps.api.addr_make("owner");
+    let alice = deps.api.addr_make("alice");
+    let proposal = PendingOwnerTransfer {
+        proposed_owner: alice.clone(),
+    };
+
+    let err = OWNER_TRANSFER
+        .propose(
+            deps.as_mut(),
+            &message_info(&alice, &[]),
+            &CONFIG,
+            proposal.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        AdminError::Unauthorized {
+            role: "owner".to_string()
+        }
+    );
+    let err = OWNER_TRANSFER
+        .propose(
+            deps.as_mut(),
+            &message_info(&owner, &[coin(1, "ushido")]),
+            &CONFIG,
+            proposal.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, AdminError::UnexpectedFunds);
+
+    OWNER_TRANSFER
+        .propose(
+            deps.as_mut(),
+            &message_info(&owner, &[]),
+            &CONFIG,
+            proposal.clone(),
+        )
+        .unwrap();
+    let err = OWNER_TRANSFER
+        .propose(deps.as_mut(), &message_info(&owner, &[]), &CONFIG, proposal)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        AdminError::TransferAlreadyPending {
+            role: "owner".to_string()
+        }
+    );
+
+    OWNER_TRANSFER
+        .cancel(deps.as_mut(), &message_info(&owner, &[]), &CONFIG)
+        .unwrap();
+    let err = OWNER_TRANSFER
+        .accept(deps.as_mut(), &message_info(&alice, &[]), &CONFIG)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        AdminError::NoTransferPending {
+            role: "owner".to_string()
+        }
+    );
+}
+
+// ─── Pause ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_pause_and_unpause() {
+    let mut deps = setup();
+    let owner = deps.api.addr_make("owner");
+    let alice = deps.api.addr_make("alice");
+
+    let err = execute_pause(deps.as_mut(), &message_info(&alice, &[]), &CONFIG).unwrap_err();
+    assert_eq!(
+        err,
+        AdminError::Unauthorized {
+            role: "owner".to_string()
+        }
+    );
+    let err = execute_unpause(deps.as_mut(), &message_info(&owner, &[]), &CONFIG).unwrap_err();
+    assert_eq!(err, AdminError::NotPaused);
+
+    execute_pause(deps.as_mut(), &message_info(&owner, &[]), &CONFIG).unwrap();
+    // Pausing twice is a no-op
+    execute_pause(deps.as_mut(), &message_info(&owner, &[]), &CONFIG).unwrap();
+    let err = assert_not_paused(deps.as_ref(), &CONFIG).unwrap_err();
+    assert_eq!(err, AdminError::Paused);
+
+    execute_unpause(deps.as_mut(), &message_info(&owner, &[]), &CONFIG).unwrap();
+    assert_not_paused(deps.as_ref(), &CONFIG).unwrap();
+}
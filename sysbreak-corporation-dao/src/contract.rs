@@ -1,31 +1,54 @@
 use cosmwasm_std::{
-    entry_point, to_json_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Response,
-    StdResult, Timestamp, Uint128,
+    entry_point, to_json_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo,
+    Reply, Response, StdResult, Storage, SubMsg, SubMsgResult, Timestamp, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_storage_plus::Bound;
+use sysbreak_common::reject_funds;
+use sysbreak_events::{ClaimEvent, ProposalExecutedEvent};
 
 use crate::error::ContractError;
 use crate::helpers::{
-    assert_active, assert_member, assert_not_dissolved, assert_officer_or_founder,
-    assert_voting_active, assert_voting_ended, check_dissolution_supermajority,
-    check_proposal_passed, load_config, load_corporation, reject_funds, validate_funds,
-    validate_funds_min, validate_quorum_bps, validate_voting_period,
+    assert_active, assert_can_propose, assert_member, assert_not_dissolved,
+    assert_not_suspended, assert_officer_or_founder, assert_voting_active, assert_voting_ended,
+    check_choice_passed, check_dissolution_supermajority, check_proposal_passed, check_votes_passed,
+    count_choice_votes, decayed_reputation, execution_delay, in_good_standing, index_corporation,
+    join_policy_key, load_config, load_corporation, max_spend, name_index_id, name_index_key, member_cap, proposal_deposit,
+    corp_level, even_split, save_corporation, status_key, validate_custom_denom, validate_leveling,
+    validate_branding, validate_dues, validate_funds, validate_funds_min, validate_max_spend_bps,
+    validate_choice_options, validate_proposal_metadata, validate_proposal_rules,
+    spend_limit_bps, validate_quorum_bps, validate_rage_quit, validate_tranches,
+    validate_voting_period,
 };
 use crate::msg::{
-    CorporationResponse, CorporationsListResponse, ExecuteMsg, InstantiateMsg, MemberEntry,
-    MemberInfoResponse, MembersListResponse, MigrateMsg, ProposalResponse, ProposalTypeMsg,
-    ProposalsListResponse, QueryMsg, VoteStatusResponse,
+    AchievementExecuteMsg, AchievementMintRequest, ChoiceTally, ChunkMsg, ChoiceVoteStatusResponse,
+    CorporationFilter, CorporationResponse, CorporationsByMemberResponse, CorporationsListResponse,
+    ExecutableAtResponse, ExecuteMsg, GovernanceBadgeMsg, InstantiateMsg, InterestEntry,
+    InterestsResponse, MemberCorporation, MemberEntry, MemberInfoResponse,
+    MemberSquadResponse, MembersListResponse, MigrateMsg, MigrationStatusResponse,
+    ProposalHookMsg, ProposalResponse, ProposalRulesResponse, ProposalTypeMsg, ProposalsListResponse, QueryMsg,
+    ReputationEntry, ReputationLeaderboardResponse, SquadMembersResponse, SquadsResponse,
+    VoteEntry, VoteStatusResponse, VotesResponse,
 };
 use crate::state::{
-    Config, Corporation, CorporationStatus, JoinPolicy, MemberInfo, MemberRole,
-    PendingOwnerTransfer, Proposal, ProposalStatus, ProposalType, CONFIG, CORPORATIONS,
-    CORP_COUNT, CORP_PROPOSALS, DISSOLUTION_CLAIMS, INVITES, MEMBERS, PENDING_OWNER, PROPOSALS,
-    PROPOSAL_COUNT, VOTES,
+    Branding, Config, Corporation, CustomTreasury, ExecutionDelays, GovernanceBadge, CorporationStatus, JoinPolicy, Leveling, MemberInfo,
+    MemberRole, MemberStanding, MigrationProgress, MilestonePlan, PendingOwnerTransfer, Proposal,
+    ProposalMetadata, ProposalRules, ProposalStatus, ProposalType, RageQuit, Reputation, ShareBasis, Squad,
+    AUTHOR_REPUTATION, BADGE_GAS_LIMIT, CHOICE_VOTES, CONTRIBUTIONS, BADGE_REPLY_ID, CONFIG, CORPORATIONS, CORP_COUNT, CORP_DIRECTORY, CORP_NAME_INDEX, CORP_PROPOSALS, CORP_SIZE_INDEX, DIRECTORY_SCAN_LIMIT, CORP_PROPOSAL_COUNT, CORP_PROPOSAL_SEQS,
+    CUSTOM_DISSOLUTION_CLAIMS, DEFAULT_MAX_SPEND_BPS, DISSOLUTION_CLAIMS, HOOK_GAS_LIMIT, HOOK_REPLY_ID, INTERESTS, INTEREST_TTL, INVITES, MAX_STREAK_BONUS_STEPS, MEMBERS,
+    MEMBER_CORPS, MEMBER_SQUADS, MIGRATION, OPEN_YES_VOTES, OWNER_TRANSFER, PROPOSALS, PROPOSAL_COUNT, REPUTATION,
+    SQUADS, SQUAD_MEMBERS, STREAK_BONUS, TRANCHE_VOTES, TRANCHE_VOTING_PERIOD, TrancheVote, VOTES, VOTE_REPUTATION,
+    MILESTONE_PLANS,
 };
 
 const CONTRACT_NAME: &str = "crates.io:sysbreak-corporation-dao";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+const MAX_SQUADS: usize = 10;
+const MAX_SQUAD_NAME_LEN: usize = 32;
+const MAX_VETO_REASON_LEN: usize = 512;
+const MAX_EXECUTION_DELAY: u64 = 30 * 86_400;
+const DEFAULT_MIGRATION_LIMIT: u32 = 200;
+const MAX_MIGRATION_LIMIT: u32 = 2_000;
 
 // ─── Instantiate ──────────────────────────────────────────────────────
 
@@ -41,6 +64,8 @@ pub fn instantiate(
     // FIX: M-02 — validate governance parameters on instantiation
     validate_quorum_bps(msg.default_quorum_bps)?;
     validate_voting_period(msg.default_voting_period)?;
+    let max_spend_bps = msg.max_spend_bps.unwrap_or(DEFAULT_MAX_SPEND_BPS);
+    validate_max_spend_bps(max_spend_bps, 10_000)?;
 
     let owner = deps.api.addr_validate(&msg.owner)?;
     let config = Config {
@@ -51,6 +76,11 @@ pub fn instantiate(
         default_max_members: msg.default_max_members,
         default_quorum_bps: msg.default_quorum_bps,
         default_voting_period: msg.default_voting_period,
+        max_spend_bps,
+        governance_badge: None,
+        execution_delays: ExecutionDelays::default(),
+        hook_contract: None,
+        leveling: None,
     };
     CONFIG.save(deps.storage, &config)?;
     CORP_COUNT.save(deps.storage, &0u64)?;
@@ -73,7 +103,18 @@ pub fn execute(
             name,
             description,
             join_policy,
-        } => execute_create_corporation(deps, env, info, name, description, join_policy),
+            branding,
+            custom_denom,
+        } => execute_create_corporation(
+            deps,
+            env,
+            info,
+            name,
+            description,
+            join_policy,
+            branding,
+            custom_denom,
+        ),
         ExecuteMsg::JoinCorporation { corp_id } => {
             execute_join_corporation(deps, env, info, corp_id)
         }
@@ -81,22 +122,52 @@ pub fn execute(
             execute_invite_member(deps, info, corp_id, invitee)
         }
         ExecuteMsg::AcceptInvite { corp_id } => execute_accept_invite(deps, env, info, corp_id),
+        ExecuteMsg::ExpressInterest { corp_id } => {
+            execute_express_interest(deps, env, info, corp_id)
+        }
+        ExecuteMsg::ApproveInterest { corp_id, applicant } => {
+            execute_approve_interest(deps, env, info, corp_id, applicant)
+        }
         ExecuteMsg::LeaveCorporation { corp_id } => {
             execute_leave_corporation(deps, info, corp_id)
         }
+        ExecuteMsg::LeaveWithShare { corp_id } => execute_leave_with_share(deps, env, info, corp_id),
         ExecuteMsg::DonateTreasury { corp_id } => {
             execute_donate_treasury(deps, info, corp_id)
         }
+        ExecuteMsg::PayDues { corp_id } => execute_pay_dues(deps, env, info, corp_id),
+        ExecuteMsg::LapseDelinquentMembers {
+            corp_id,
+            start_after,
+            limit,
+        } => execute_lapse_delinquent_members(deps, env, info, corp_id, start_after, limit),
         ExecuteMsg::CreateProposal {
             corp_id,
             proposal_type,
-        } => execute_create_proposal(deps, env, info, corp_id, proposal_type),
+            metadata,
+        } => execute_create_proposal(deps, env, info, corp_id, proposal_type, metadata),
         ExecuteMsg::Vote { proposal_id, vote } => {
             execute_vote(deps, env, info, proposal_id, vote)
         }
+        ExecuteMsg::VoteChoice {
+            proposal_id,
+            ranking,
+        } => execute_vote_choice(deps, env, info, proposal_id, ranking),
         ExecuteMsg::ExecuteProposal { proposal_id } => {
             execute_execute_proposal(deps, env, info, proposal_id)
         }
+        ExecuteMsg::OpenTrancheVote { proposal_id } => {
+            execute_open_tranche_vote(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::VoteTranche { proposal_id, vote } => {
+            execute_vote_tranche(deps, env, info, proposal_id, vote)
+        }
+        ExecuteMsg::ReleaseTranche { proposal_id } => {
+            execute_release_tranche(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::CancelMilestonePlan { proposal_id } => {
+            execute_cancel_milestone_plan(deps, info, proposal_id)
+        }
         ExecuteMsg::ClaimDissolution { corp_id } => {
             execute_claim_dissolution(deps, info, corp_id)
         }
@@ -104,8 +175,38 @@ pub fn execute(
             corp_id,
             description,
         } => execute_update_description(deps, info, corp_id, description),
+        ExecuteMsg::UpdateBranding { corp_id, branding } => {
+            execute_update_branding(deps, info, corp_id, branding)
+        }
+        ExecuteMsg::CreateSquad {
+            corp_id,
+            name,
+            leader,
+        } => execute_create_squad(deps, env, info, corp_id, name, leader),
+        ExecuteMsg::AssignToSquad {
+            corp_id,
+            member,
+            squad,
+        } => execute_assign_to_squad(deps, info, corp_id, member, squad),
+        ExecuteMsg::DisbandSquad { corp_id, name } => {
+            execute_disband_squad(deps, info, corp_id, name)
+        }
+        ExecuteMsg::VetoProposal {
+            proposal_id,
+            reason,
+        } => execute_veto_proposal(deps, env, info, proposal_id, reason),
         // FIX: H-01
         ExecuteMsg::WithdrawFees { amount } => execute_withdraw_fees(deps, env, info, amount),
+        ExecuteMsg::SetGovernanceBadge { badge } => execute_set_governance_badge(deps, info, badge),
+        ExecuteMsg::SetExecutionDelays { delays } => {
+            execute_set_execution_delays(deps, info, delays)
+        }
+        ExecuteMsg::SetMaxSpendBps { max_spend_bps } => {
+            execute_set_max_spend_bps(deps, info, max_spend_bps)
+        }
+        ExecuteMsg::SetLeveling { leveling } => execute_set_leveling(deps, info, leveling),
+        ExecuteMsg::AddHook { address } => execute_add_hook(deps, info, address),
+        ExecuteMsg::RemoveHook {} => execute_remove_hook(deps, info),
         // FIX: H-04
         ExecuteMsg::ProposeOwner { new_owner } => execute_propose_owner(deps, info, new_owner),
         ExecuteMsg::AcceptOwner {} => execute_accept_owner(deps, info),
@@ -122,8 +223,15 @@ fn execute_create_corporation(
     name: String,
     description: String,
     join_policy: JoinPolicy,
+    branding: Option<Branding>,
+    custom_denom: Option<String>,
 ) -> Result<Response, ContractError> {
     let config = load_config(deps.as_ref())?;
+    let branding = branding.unwrap_or_default();
+    validate_branding(&branding)?;
+    if let Some(denom) = &custom_denom {
+        validate_custom_denom(deps.api, &config, denom)?;
+    }
 
     // Validate creation fee
     validate_funds(
@@ -144,20 +252,37 @@ fn execute_create_corporation(
         join_policy,
         quorum_bps: config.default_quorum_bps,
         voting_period: config.default_voting_period,
+        max_spend_bps: config.max_spend_bps.min(DEFAULT_MAX_SPEND_BPS),
         max_members: config.default_max_members,
         member_count: 1,
         treasury_balance: Uint128::zero(),
         created_at: env.block.time,
         status: CorporationStatus::Active,
+        member_epoch: 1,
+        min_officer_reputation: 0,
+        proposal_rules: ProposalRules::default(),
+        branding,
+        dues: None,
+        rage_quit: RageQuit::default(),
+        total_contributions: Uint128::zero(),
+        executed_proposals: 0,
+        custom_treasury: custom_denom.map(|denom| CustomTreasury {
+            denom,
+            balance: Uint128::zero(),
+        }),
     };
-    CORPORATIONS.save(deps.storage, corp_id, &corp)?;
+    save_corporation(deps.storage, &corp)?;
 
     // Add founder as first member
     let member_info = MemberInfo {
         role: MemberRole::Founder,
         joined_at: env.block.time,
+        epoch: corp.member_epoch,
+        dues_paid_until: None,
+        standing: MemberStanding::Good,
     };
     MEMBERS.save(deps.storage, (corp_id, &info.sender), &member_info)?;
+    MEMBER_CORPS.save(deps.storage, (&info.sender, corp_id), &())?;
 
     Ok(Response::new()
         .add_attribute("action", "create_corporation")
@@ -186,21 +311,7 @@ fn execute_join_corporation(
         return Err(ContractError::AlreadyMember { corp_id });
     }
 
-    // Check capacity
-    if corp.member_count >= corp.max_members {
-        return Err(ContractError::CorporationFull {
-            max: corp.max_members,
-        });
-    }
-
-    corp.member_count += 1;
-    CORPORATIONS.save(deps.storage, corp_id, &corp)?;
-
-    let member_info = MemberInfo {
-        role: MemberRole::Member,
-        joined_at: env.block.time,
-    };
-    MEMBERS.save(deps.storage, (corp_id, &info.sender), &member_info)?;
+    admit_member(deps, &env, &mut corp, &info.sender)?;
 
     Ok(Response::new()
         .add_attribute("action", "join_corporation")
@@ -258,29 +369,107 @@ fn execute_accept_invite(
         return Err(ContractError::AlreadyMember { corp_id });
     }
 
-    // Check capacity
-    if corp.member_count >= corp.max_members {
-        return Err(ContractError::CorporationFull {
-            max: corp.max_members,
-        });
+    admit_member(deps, &env, &mut corp, &info.sender)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_invite")
+        .add_attribute("corp_id", corp_id.to_string())
+        .add_attribute("member", info.sender.to_string()))
+}
+
+// ─── Interest (Invite-Only) ───────────────────────────────────────────
+
+fn execute_express_interest(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    corp_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let corp = load_corporation(deps.as_ref(), corp_id)?;
+    assert_active(&corp)?;
+    if corp.join_policy != JoinPolicy::InviteOnly {
+        return Err(ContractError::NotInviteOnly);
+    }
+    if MEMBERS.has(deps.storage, (corp_id, &info.sender)) {
+        return Err(ContractError::AlreadyMember { corp_id });
+    }
+
+    let expires_at = env.block.time.plus_seconds(INTEREST_TTL);
+    INTERESTS.save(deps.storage, (corp_id, &info.sender), &expires_at)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "express_interest")
+        .add_attribute("corp_id", corp_id.to_string())
+        .add_attribute("applicant", info.sender.to_string())
+        .add_attribute("expires_at", expires_at.seconds().to_string()))
+}
+
+fn execute_approve_interest(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    corp_id: u64,
+    applicant: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let mut corp = load_corporation(deps.as_ref(), corp_id)?;
+    assert_active(&corp)?;
+    assert_officer_or_founder(deps.as_ref(), corp_id, &info.sender)?;
+
+    let applicant = deps.api.addr_validate(&applicant)?;
+    let open = INTERESTS
+        .may_load(deps.storage, (corp_id, &applicant))?
+        .is_some_and(|expires_at| env.block.time < expires_at);
+    if !open {
+        return Err(ContractError::NoPendingInterest);
+    }
+    if MEMBERS.has(deps.storage, (corp_id, &applicant)) {
+        return Err(ContractError::AlreadyMember { corp_id });
     }
 
-    // Remove invite
-    INVITES.remove(deps.storage, (corp_id, &info.sender));
+    admit_member(deps, &env, &mut corp, &applicant)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "approve_interest")
+        .add_attribute("corp_id", corp_id.to_string())
+        .add_attribute("member", applicant.to_string())
+        .add_attribute("approved_by", info.sender.to_string()))
+}
+
+/// Add a regular member, within the corporation's member cap (including the
+/// level bonus). Clears any invite or interest they had pending.
+fn admit_member(
+    deps: DepsMut,
+    env: &Env,
+    corp: &mut Corporation,
+    member: &Addr,
+) -> Result<(), ContractError> {
+    let cap = member_cap(&load_config(deps.as_ref())?, corp);
+    if corp.member_count >= cap {
+        return Err(ContractError::CorporationFull { max: cap });
+    }
 
+    INVITES.remove(deps.storage, (corp.id, member));
+    INTERESTS.remove(deps.storage, (corp.id, member));
     corp.member_count += 1;
-    CORPORATIONS.save(deps.storage, corp_id, &corp)?;
+    corp.member_epoch += 1;
+    save_corporation(deps.storage, corp)?;
 
     let member_info = MemberInfo {
         role: MemberRole::Member,
         joined_at: env.block.time,
+        epoch: corp.member_epoch,
+        // New members get their first period free
+        dues_paid_until: corp
+            .dues
+            .as_ref()
+            .map(|dues| env.block.time.plus_seconds(dues.period)),
+        standing: MemberStanding::Good,
     };
-    MEMBERS.save(deps.storage, (corp_id, &info.sender), &member_info)?;
-
-    Ok(Response::new()
-        .add_attribute("action", "accept_invite")
-        .add_attribute("corp_id", corp_id.to_string())
-        .add_attribute("member", info.sender.to_string()))
+    MEMBERS.save(deps.storage, (corp.id, member), &member_info)?;
+    MEMBER_CORPS.save(deps.storage, (member, corp.id), &())?;
+    Ok(())
 }
 
 // ─── Leave Corporation ────────────────────────────────────────────────
@@ -302,6 +491,10 @@ fn execute_leave_corporation(
     }
 
     MEMBERS.remove(deps.storage, (corp_id, &info.sender));
+    MEMBER_CORPS.remove(deps.storage, (&info.sender, corp_id));
+    REPUTATION.remove(deps.storage, (corp_id, &info.sender));
+    drop_from_squad(deps.storage, corp_id, &info.sender)?;
+    forget_stake(deps.storage, &mut corp, &info.sender)?;
     corp.member_count -= 1;
 
     // If founder leaves (last member), dissolve
@@ -309,7 +502,7 @@ fn execute_leave_corporation(
         corp.status = CorporationStatus::Dissolved;
     }
 
-    CORPORATIONS.save(deps.storage, corp_id, &corp)?;
+    save_corporation(deps.storage, &corp)?;
 
     Ok(Response::new()
         .add_attribute("action", "leave_corporation")
@@ -317,6 +510,121 @@ fn execute_leave_corporation(
         .add_attribute("member", info.sender.to_string()))
 }
 
+/// Rage-quit: leave with a share of the treasury sized by the corporation's
+/// basis, less its haircut. The last member out takes whatever is left.
+/// A contribution share never exceeds what the member actually paid in, so
+/// balances from before contributions were tracked stay with the corporation.
+fn execute_leave_with_share(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    corp_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let mut corp = load_corporation(deps.as_ref(), corp_id)?;
+    assert_active(&corp)?;
+    let member = assert_member(deps.as_ref(), corp_id, &info.sender)?;
+    if !corp.rage_quit.enabled {
+        return Err(ContractError::RageQuitDisabled { corp_id });
+    }
+    if member.role == MemberRole::Founder && corp.member_count > 1 {
+        return Err(ContractError::FounderCannotLeave);
+    }
+    // Backing a proposal and leaving before it lands would dodge its outcome
+    if let Some(&proposal_id) = open_yes_votes(deps.storage, corp_id, &info.sender)?.first() {
+        return Err(ContractError::YesVoteInFlight { proposal_id });
+    }
+    // Joining just to walk off with a slice of the treasury is not an exit
+    let eligible_at = member
+        .joined_at
+        .plus_seconds(corp.rage_quit.min_tenure_seconds);
+    if env.block.time < eligible_at {
+        return Err(ContractError::TenureTooShort {
+            eligible_at: eligible_at.seconds(),
+        });
+    }
+
+    let contributed = CONTRIBUTIONS
+        .may_load(deps.storage, (corp_id, &info.sender))?
+        .unwrap_or_default();
+    let custom_balance = corp
+        .custom_treasury
+        .as_ref()
+        .map(|c| c.balance)
+        .unwrap_or_default();
+    // The member's slice of a balance before the haircut
+    let slice = |balance: Uint128| match corp.rage_quit.basis {
+        ShareBasis::Equal => balance.multiply_ratio(1u128, corp.member_count),
+        ShareBasis::Contribution if corp.total_contributions.is_zero() => Uint128::zero(),
+        ShareBasis::Contribution => balance.multiply_ratio(contributed, corp.total_contributions),
+    };
+    let less_haircut = |gross: Uint128| {
+        gross.multiply_ratio(10_000 - corp.rage_quit.haircut_bps as u128, 10_000u128)
+    };
+    let (share, custom_share) = if corp.member_count == 1 {
+        (corp.treasury_balance, custom_balance)
+    } else {
+        let mut gross = slice(corp.treasury_balance);
+        // Contributions are counted in the config denom, so only that share
+        // can be capped at what the member paid in
+        if corp.rage_quit.basis == ShareBasis::Contribution {
+            gross = gross.min(contributed);
+        }
+        (less_haircut(gross), less_haircut(slice(custom_balance)))
+    };
+
+    MEMBERS.remove(deps.storage, (corp_id, &info.sender));
+    MEMBER_CORPS.remove(deps.storage, (&info.sender, corp_id));
+    REPUTATION.remove(deps.storage, (corp_id, &info.sender));
+    drop_from_squad(deps.storage, corp_id, &info.sender)?;
+    forget_stake(deps.storage, &mut corp, &info.sender)?;
+    corp.member_count -= 1;
+    corp.treasury_balance = corp
+        .treasury_balance
+        .checked_sub(share)
+        .map_err(|_| ContractError::Overflow)?;
+    let mut custom_payout = None;
+    if let Some(custom) = corp.custom_treasury.as_mut() {
+        custom.balance = custom
+            .balance
+            .checked_sub(custom_share)
+            .map_err(|_| ContractError::Overflow)?;
+        custom_payout = Some(Coin {
+            denom: custom.denom.clone(),
+            amount: custom_share,
+        });
+    }
+    if corp.member_count == 0 {
+        corp.status = CorporationStatus::Dissolved;
+    }
+    save_corporation(deps.storage, &corp)?;
+
+    let mut resp = Response::new()
+        .add_attribute("action", "leave_with_share")
+        .add_attribute("corp_id", corp_id.to_string())
+        .add_attribute("member", info.sender.to_string())
+        .add_attribute("share", share.to_string());
+    if !share.is_zero() {
+        let config = load_config(deps.as_ref())?;
+        resp = resp.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                denom: config.denom,
+                amount: share,
+            }],
+        });
+    }
+    if let Some(coin) = custom_payout.filter(|c| !c.amount.is_zero()) {
+        resp = resp
+            .add_attribute("custom_share", coin.amount.to_string())
+            .add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![coin],
+            });
+    }
+    Ok(resp)
+}
+
 // ─── Donate Treasury ──────────────────────────────────────────────────
 
 // FIX: I-03 — DonateTreasury intentionally allows non-member donations.
@@ -329,6 +637,30 @@ fn execute_donate_treasury(
     let mut corp = load_corporation(deps.as_ref(), corp_id)?;
     assert_active(&corp)?;
 
+    // A donation in the custom denom goes to its own balance. It is not a
+    // contribution, as those are counted in the config denom.
+    if let (Some(custom), [coin]) = (corp.custom_treasury.as_mut(), info.funds.as_slice()) {
+        if coin.denom == custom.denom {
+            let amount = validate_funds_min(
+                &info,
+                &custom.denom,
+                Uint128::one(),
+                ContractError::ZeroAmount,
+            )?;
+            custom.balance = custom
+                .balance
+                .checked_add(amount)
+                .map_err(|_| ContractError::Overflow)?;
+            save_corporation(deps.storage, &corp)?;
+
+            return Ok(Response::new()
+                .add_attribute("action", "donate_treasury")
+                .add_attribute("corp_id", corp_id.to_string())
+                .add_attribute("amount", amount.to_string())
+                .add_attribute("denom", &coin.denom));
+        }
+    }
+
     let config = load_config(deps.as_ref())?;
     let amount = validate_funds_min(
         &info,
@@ -341,12 +673,142 @@ fn execute_donate_treasury(
         .treasury_balance
         .checked_add(amount)
         .map_err(|_| ContractError::Overflow)?;
-    CORPORATIONS.save(deps.storage, corp_id, &corp)?;
+    if MEMBERS.has(deps.storage, (corp_id, &info.sender)) {
+        record_contribution(deps.storage, &mut corp, &info.sender, amount)?;
+    }
+    save_corporation(deps.storage, &corp)?;
 
     Ok(Response::new()
         .add_attribute("action", "donate_treasury")
         .add_attribute("corp_id", corp_id.to_string())
-        .add_attribute("amount", amount.to_string()))
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("denom", config.denom))
+}
+
+// ─── Membership Dues ──────────────────────────────────────────────────
+
+fn execute_pay_dues(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    corp_id: u64,
+) -> Result<Response, ContractError> {
+    let mut corp = load_corporation(deps.as_ref(), corp_id)?;
+    assert_active(&corp)?;
+    let mut member = assert_member(deps.as_ref(), corp_id, &info.sender)?;
+    let Some(dues) = corp.dues.clone() else {
+        return Err(ContractError::DuesNotEnabled { corp_id });
+    };
+
+    let config = load_config(deps.as_ref())?;
+    let amount = validate_funds(
+        &info,
+        &config.denom,
+        dues.amount,
+        ContractError::InsufficientDues,
+    )?;
+
+    corp.treasury_balance = corp
+        .treasury_balance
+        .checked_add(amount)
+        .map_err(|_| ContractError::Overflow)?;
+    record_contribution(deps.storage, &mut corp, &info.sender, amount)?;
+    save_corporation(deps.storage, &corp)?;
+
+    // Paying early extends the current period; paying late starts a new one now
+    let paid_until = member
+        .dues_paid_until
+        .map_or(env.block.time, |until| until.max(env.block.time))
+        .plus_seconds(dues.period);
+    member.dues_paid_until = Some(paid_until);
+    member.standing = MemberStanding::Good;
+    MEMBERS.save(deps.storage, (corp_id, &info.sender), &member)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "pay_dues")
+        .add_attribute("corp_id", corp_id.to_string())
+        .add_attribute("member", info.sender.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("paid_until", paid_until.seconds().to_string()))
+}
+
+fn execute_lapse_delinquent_members(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    corp_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let corp = load_corporation(deps.as_ref(), corp_id)?;
+    assert_active(&corp)?;
+    assert_officer_or_founder(deps.as_ref(), corp_id, &info.sender)?;
+    let Some(dues) = &corp.dues else {
+        return Err(ContractError::DuesNotEnabled { corp_id });
+    };
+    let standing = if dues.suspend_delinquent {
+        MemberStanding::Suspended
+    } else {
+        MemberStanding::Lapsed
+    };
+
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|s| deps.api.addr_validate(s))
+        .transpose()?;
+    let members: Vec<(Addr, MemberInfo)> = MEMBERS
+        .prefix(corp_id)
+        .range(
+            deps.storage,
+            start.as_ref().map(Bound::exclusive),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+
+    let mut lapsed = 0u32;
+    for (addr, mut member) in members.iter().cloned() {
+        if member.standing == standing || in_good_standing(&corp, &member, env.block.time) {
+            continue;
+        }
+        member.standing = standing.clone();
+        MEMBERS.save(deps.storage, (corp_id, &addr), &member)?;
+        lapsed += 1;
+    }
+
+    let mut resp = Response::new()
+        .add_attribute("action", "lapse_delinquent_members")
+        .add_attribute("corp_id", corp_id.to_string())
+        .add_attribute("lapsed", lapsed.to_string());
+    // Where the next page starts, if this one was full
+    if members.len() == limit {
+        if let Some((last, _)) = members.last() {
+            resp = resp.add_attribute("next_start_after", last.to_string());
+        }
+    }
+    Ok(resp)
+}
+
+/// Restart every member's dues: paid until `paid_until` (None when dues are
+/// switched off) and back in good standing. Bounded by max_members.
+fn reset_dues(
+    storage: &mut dyn Storage,
+    corp_id: u64,
+    paid_until: Option<Timestamp>,
+) -> StdResult<()> {
+    let members: Vec<(Addr, MemberInfo)> = MEMBERS
+        .prefix(corp_id)
+        .range(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (addr, mut member) in members {
+        member.dues_paid_until = paid_until;
+        member.standing = MemberStanding::Good;
+        MEMBERS.save(storage, (corp_id, &addr), &member)?;
+    }
+    Ok(())
 }
 
 // ─── Create Proposal ──────────────────────────────────────────────────
@@ -357,28 +819,57 @@ fn execute_create_proposal(
     info: MessageInfo,
     corp_id: u64,
     proposal_type_msg: ProposalTypeMsg,
+    metadata: Option<ProposalMetadata>,
 ) -> Result<Response, ContractError> {
     let corp = load_corporation(deps.as_ref(), corp_id)?;
     assert_active(&corp)?;
-    assert_member(deps.as_ref(), corp_id, &info.sender)?;
+    let member = assert_member(deps.as_ref(), corp_id, &info.sender)?;
+    assert_can_propose(deps.as_ref(), &env, &corp, &info.sender, &member)?;
+    if let Some(m) = &metadata {
+        validate_proposal_metadata(m)?;
+    }
 
     let config = load_config(deps.as_ref())?;
+    // Old proposals must take their per-corp numbers before new ones do
+    if MIGRATION.may_load(deps.storage)?.is_some_and(|m| !m.done) {
+        return Err(ContractError::MigrationInProgress);
+    }
 
-    // Validate proposal deposit
-    validate_funds(
-        &info,
-        &config.denom,
-        config.proposal_deposit,
-        ContractError::InsufficientProposalDeposit,
-    )?;
+    // Validate proposal deposit, discounted by the corporation's level
+    let deposit = proposal_deposit(&config, &corp);
+    if deposit.is_zero() {
+        reject_funds(&info)?;
+    } else {
+        validate_funds(
+            &info,
+            &config.denom,
+            deposit,
+            ContractError::InsufficientProposalDeposit,
+        )?;
+    }
 
     // Convert msg-level proposal type to state-level (validate addresses)
     let proposal_type = match proposal_type_msg {
-        ProposalTypeMsg::TreasurySpend { recipient, amount } => {
+        ProposalTypeMsg::TreasurySpend {
+            recipient,
+            amount,
+            denom,
+        } => {
             let recipient_addr = deps.api.addr_validate(&recipient)?;
+            // Naming the config denom is the same as leaving it out
+            let denom = denom.filter(|denom| *denom != config.denom);
+            if let Some(denom) = &denom {
+                if corp.custom_treasury.as_ref().map(|c| &c.denom) != Some(denom) {
+                    return Err(ContractError::UnsupportedDenom {
+                        corp_id,
+                        denom: denom.clone(),
+                    });
+                }
+            }
             ProposalType::TreasurySpend {
                 recipient: recipient_addr,
                 amount,
+                denom,
             }
         }
         ProposalTypeMsg::ChangeSettings {
@@ -387,12 +878,24 @@ fn execute_create_proposal(
             join_policy,
             quorum_bps,
             voting_period,
+            max_spend_bps,
+            min_officer_reputation,
+            proposal_rules,
+            branding,
+            dues,
+            rage_quit,
         } => ProposalType::ChangeSettings {
             name,
             description,
             join_policy,
             quorum_bps,
             voting_period,
+            max_spend_bps,
+            min_officer_reputation,
+            proposal_rules,
+            branding,
+            dues,
+            rage_quit,
         },
         ProposalTypeMsg::KickMember { member } => {
             let member_addr = deps.api.addr_validate(&member)?;
@@ -411,16 +914,50 @@ fn execute_create_proposal(
         ProposalTypeMsg::Custom { title, description } => {
             ProposalType::Custom { title, description }
         }
+        ProposalTypeMsg::CreateSquad { name, leader } => {
+            validate_squad_name(&name)?;
+            let leader_addr = deps.api.addr_validate(&leader)?;
+            ProposalType::CreateSquad {
+                name,
+                leader: leader_addr,
+            }
+        }
+        ProposalTypeMsg::AssignToSquad { member, squad } => {
+            let member_addr = deps.api.addr_validate(&member)?;
+            ProposalType::AssignToSquad {
+                member: member_addr,
+                squad,
+            }
+        }
+        ProposalTypeMsg::DisbandSquad { name } => ProposalType::DisbandSquad { name },
+        ProposalTypeMsg::MultiChoice { title, options } => {
+            validate_choice_options(&title, &options)?;
+            ProposalType::MultiChoice { title, options }
+        }
+        ProposalTypeMsg::MilestoneSpend {
+            recipient,
+            tranches,
+        } => {
+            let recipient_addr = deps.api.addr_validate(&recipient)?;
+            validate_tranches(&tranches)?;
+            ProposalType::MilestoneSpend {
+                recipient: recipient_addr,
+                tranches,
+            }
+        }
     };
 
     let proposal_id = PROPOSAL_COUNT.load(deps.storage)? + 1;
     PROPOSAL_COUNT.save(deps.storage, &proposal_id)?;
+    let corp_seq = next_corp_seq(deps.storage, corp_id)?;
+    CORP_PROPOSAL_SEQS.save(deps.storage, (corp_id, corp_seq), &proposal_id)?;
 
     let voting_ends_at = Timestamp::from_seconds(env.block.time.seconds() + corp.voting_period);
 
     let proposal = Proposal {
         id: proposal_id,
         corp_id,
+        corp_seq,
         proposer: info.sender.clone(),
         proposal_type,
         status: ProposalStatus::Active,
@@ -428,21 +965,47 @@ fn execute_create_proposal(
         no_votes: 0,
         created_at: env.block.time,
         voting_ends_at,
-        deposit: config.proposal_deposit,
+        deposit,
         // FIX: H-02 — snapshot member count at creation for quorum evaluation
         member_count_snapshot: corp.member_count,
+        member_epoch_snapshot: Some(corp.member_epoch),
+        veto_reason: None,
+        metadata,
+        executable_at: None,
+        winning_option: None,
     };
     PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
     // FIX: M-07 — insert into secondary index for efficient corp-based queries
     CORP_PROPOSALS.save(deps.storage, (corp_id, proposal_id), &())?;
 
+    let hook = proposal_hook(
+        &config,
+        ProposalHookMsg::ProposalCreatedHook {
+            proposal_id,
+            corp_id,
+            proposer: info.sender.to_string(),
+        },
+    )?;
+
     Ok(Response::new()
+        .add_submessages(hook)
         .add_attribute("action", "create_proposal")
         .add_attribute("proposal_id", proposal_id.to_string())
         .add_attribute("corp_id", corp_id.to_string())
+        .add_attribute("corp_seq", corp_seq.to_string())
         .add_attribute("proposer", info.sender.to_string()))
 }
 
+/// Bump and return the corporation's proposal counter
+fn next_corp_seq(storage: &mut dyn Storage, corp_id: u64) -> StdResult<u64> {
+    let seq = CORP_PROPOSAL_COUNT
+        .may_load(storage, corp_id)?
+        .unwrap_or_default()
+        + 1;
+    CORP_PROPOSAL_COUNT.save(storage, corp_id, &seq)?;
+    Ok(seq)
+}
+
 // ─── Vote ─────────────────────────────────────────────────────────────
 
 fn execute_vote(
@@ -453,18 +1016,9 @@ fn execute_vote(
     vote: bool,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?; // FIX: M-08
-    let mut proposal = PROPOSALS
-        .load(deps.storage, proposal_id)
-        .map_err(|_| ContractError::ProposalNotFound { id: proposal_id })?;
-
-    assert_voting_active(&proposal, &env)?;
-
-    // Must be a member
-    let member = assert_member(deps.as_ref(), proposal.corp_id, &info.sender)?;
-
-    // Flash-join protection: member must have joined BEFORE proposal was created
-    if member.joined_at >= proposal.created_at {
-        return Err(ContractError::JoinedAfterProposal);
+    let mut proposal = load_votable_proposal(deps.as_ref(), &env, proposal_id, &info.sender)?;
+    if matches!(proposal.proposal_type, ProposalType::MultiChoice { .. }) {
+        return Err(ContractError::ChoiceVoteRequired { id: proposal_id });
     }
 
     // Check not already voted
@@ -474,6 +1028,11 @@ fn execute_vote(
 
     // Record vote (final, no changes allowed)
     VOTES.save(deps.storage, (proposal_id, &info.sender), &vote)?;
+    if vote {
+        let mut open = open_yes_votes(deps.storage, proposal.corp_id, &info.sender)?;
+        open.push(proposal_id);
+        OPEN_YES_VOTES.save(deps.storage, (proposal.corp_id, &info.sender), &open)?;
+    }
 
     if vote {
         proposal.yes_votes += 1;
@@ -481,34 +1040,115 @@ fn execute_vote(
         proposal.no_votes += 1;
     }
     PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    let reputation = record_vote_reputation(
+        deps.storage,
+        proposal.corp_id,
+        &info.sender,
+        proposal.corp_seq,
+        env.block.time,
+    )?;
 
     Ok(Response::new()
         .add_attribute("action", "vote")
         .add_attribute("proposal_id", proposal_id.to_string())
         .add_attribute("voter", info.sender.to_string())
-        .add_attribute("vote", vote.to_string()))
+        .add_attribute("vote", vote.to_string())
+        .add_attribute("reputation", reputation.to_string()))
 }
 
-// ─── Execute Proposal ─────────────────────────────────────────────────
-
-// FIX: I-04 — ExecuteProposal is intentionally callable by any address.
-// This is by design: permissionless execution after quorum prevents governance deadlock
-// where no member is online to finalize a passing proposal.
-fn execute_execute_proposal(
+/// Cast a ranked ballot on a MultiChoice proposal. Ballots are final, like
+/// yes/no votes, and are counted when the proposal executes.
+fn execute_vote_choice(
     deps: DepsMut,
     env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     proposal_id: u64,
+    ranking: Vec<u32>,
 ) -> Result<Response, ContractError> {
-    reject_funds(&_info)?; // FIX: M-08
-    let mut proposal = PROPOSALS
-        .load(deps.storage, proposal_id)
-        .map_err(|_| ContractError::ProposalNotFound { id: proposal_id })?;
-
-    if proposal.status == ProposalStatus::Executed {
-        return Err(ContractError::AlreadyExecuted { id: proposal_id });
+    reject_funds(&info)?;
+    let proposal = load_votable_proposal(deps.as_ref(), &env, proposal_id, &info.sender)?;
+    let ProposalType::MultiChoice { options, .. } = &proposal.proposal_type else {
+        return Err(ContractError::NotMultiChoice { id: proposal_id });
+    };
+    let valid = !ranking.is_empty()
+        && ranking.iter().enumerate().all(|(i, choice)| {
+            (*choice as usize) < options.len() && !ranking[..i].contains(choice)
+        });
+    if !valid {
+        return Err(ContractError::InvalidRanking { id: proposal_id });
+    }
+    if CHOICE_VOTES.has(deps.storage, (proposal_id, &info.sender)) {
+        return Err(ContractError::AlreadyVoted { id: proposal_id });
+    }
+
+    CHOICE_VOTES.save(deps.storage, (proposal_id, &info.sender), &ranking)?;
+    let reputation = record_vote_reputation(
+        deps.storage,
+        proposal.corp_id,
+        &info.sender,
+        proposal.corp_seq,
+        env.block.time,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "vote_choice")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", info.sender.to_string())
+        .add_attribute("first_choice", ranking[0].to_string())
+        .add_attribute("reputation", reputation.to_string()))
+}
+
+/// Load a proposal that is open for voting and check `voter` may vote on it
+fn load_votable_proposal(
+    deps: Deps,
+    env: &Env,
+    proposal_id: u64,
+    voter: &Addr,
+) -> Result<Proposal, ContractError> {
+    let proposal = PROPOSALS
+        .load(deps.storage, proposal_id)
+        .map_err(|_| ContractError::ProposalNotFound { id: proposal_id })?;
+
+    assert_voting_active(&proposal, env)?;
+
+    // Must be a member, and not suspended for unpaid dues
+    let member = assert_member(deps, proposal.corp_id, voter)?;
+    let corp = load_corporation(deps, proposal.corp_id)?;
+    assert_not_suspended(&corp, &member)?;
+
+    // Flash-join protection: member must have joined BEFORE proposal was created.
+    // Join order is exact even within a block; older proposals fall back to timestamps.
+    let joined_after = match proposal.member_epoch_snapshot {
+        Some(snapshot) => member.epoch > snapshot,
+        None => member.joined_at >= proposal.created_at,
+    };
+    if joined_after {
+        return Err(ContractError::JoinedAfterProposal);
+    }
+    Ok(proposal)
+}
+
+// ─── Execute Proposal ─────────────────────────────────────────────────
+
+// FIX: I-04 — ExecuteProposal is intentionally callable by any address.
+// This is by design: permissionless execution after quorum prevents governance deadlock
+// where no member is online to finalize a passing proposal.
+fn execute_execute_proposal(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&_info)?; // FIX: M-08
+    let mut proposal = PROPOSALS
+        .load(deps.storage, proposal_id)
+        .map_err(|_| ContractError::ProposalNotFound { id: proposal_id })?;
+
+    if proposal.status == ProposalStatus::Executed {
+        return Err(ContractError::AlreadyExecuted { id: proposal_id });
     }
-    if proposal.status != ProposalStatus::Active {
+    let queued = proposal.status == ProposalStatus::QueuedForExecution;
+    if proposal.status != ProposalStatus::Active && !queued {
         return Err(ContractError::ProposalNotPending { id: proposal_id });
     }
 
@@ -518,19 +1158,68 @@ fn execute_execute_proposal(
     let config = load_config(deps.as_ref())?;
 
     // FIX: H-02 — use snapshot member count, not current, for quorum evaluation
-    let passed = check_proposal_passed(&proposal, proposal.member_count_snapshot, corp.quorum_bps);
+    let passed = queued
+        || match &proposal.proposal_type {
+            ProposalType::MultiChoice { options, .. } => {
+                let count = count_choice_votes(deps.storage, proposal_id, options.len())?;
+                let passed =
+                    check_choice_passed(&count, proposal.member_count_snapshot, corp.quorum_bps);
+                if passed {
+                    proposal.winning_option = count.winner;
+                }
+                passed
+            }
+            _ => check_proposal_passed(&proposal, proposal.member_count_snapshot, corp.quorum_bps),
+        };
 
     let mut msgs: Vec<BankMsg> = vec![];
     let mut resp = Response::new()
         .add_attribute("action", "execute_proposal")
         .add_attribute("proposal_id", proposal_id.to_string());
+    let executed = move |result: &str| ProposalExecutedEvent {
+        proposal_id: proposal_id.to_string(),
+        corp_id: proposal.corp_id.to_string(),
+        result: result.to_string(),
+    };
 
     if !passed {
         // Failed — burn deposit (don't refund)
+        let event = executed("failed");
         proposal.status = ProposalStatus::Failed;
         PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
 
-        return Ok(resp.add_attribute("result", "failed"));
+        return Ok(resp.add_event(event));
+    }
+
+    // Sensitive proposals wait out their delay so members who disagree can leave first
+    let executable_at = proposal.executable_at.unwrap_or_else(|| {
+        let delay = execution_delay(&config, &proposal.proposal_type);
+        proposal.voting_ends_at.plus_seconds(delay)
+    });
+    if !queued {
+        resp = resp.add_submessages(proposal_hook(
+            &config,
+            ProposalHookMsg::ProposalPassedHook {
+                proposal_id,
+                corp_id: proposal.corp_id,
+                executable_at,
+            },
+        )?);
+    }
+    if env.block.time < executable_at {
+        if queued {
+            return Err(ContractError::ExecutionDelayed {
+                id: proposal_id,
+                executable_at,
+            });
+        }
+        proposal.status = ProposalStatus::QueuedForExecution;
+        proposal.executable_at = Some(executable_at);
+        PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+        return Ok(resp
+            .add_attribute("result", "queued")
+            .add_attribute("executable_at", executable_at.seconds().to_string()));
     }
 
     // Mark as executed BEFORE dispatching any bank messages (check-effects-interactions)
@@ -548,35 +1237,48 @@ fn execute_execute_proposal(
         });
     }
 
-    match &proposal.proposal_type {
-        ProposalType::TreasurySpend { recipient, amount } => {
-            // Enforce 25% max spend per proposal
-            let max_spend = corp
-                .treasury_balance
-                .checked_mul(Uint128::new(25))
-                .map_err(|_| ContractError::Overflow)?
-                .checked_div(Uint128::new(100))
-                .map_err(|_| ContractError::Overflow)?;
-
-            if *amount > max_spend {
-                return Err(ContractError::SpendExceedsLimit);
+    let result = match &proposal.proposal_type {
+        ProposalType::TreasurySpend {
+            recipient,
+            amount,
+            denom,
+        } => {
+            let max_spend_bps = spend_limit_bps(&config, &corp);
+            let (balance, spend_denom) = match denom {
+                None => (&mut corp.treasury_balance, config.denom.clone()),
+                Some(denom) => match corp.custom_treasury.as_mut() {
+                    Some(custom) if custom.denom == *denom => {
+                        (&mut custom.balance, custom.denom.clone())
+                    }
+                    _ => {
+                        return Err(ContractError::UnsupportedDenom {
+                            corp_id: proposal.corp_id,
+                            denom: denom.clone(),
+                        })
+                    }
+                },
+            };
+            // Enforce the corporation's max spend per proposal
+            if *amount > balance.multiply_ratio(max_spend_bps, 10_000u128) {
+                return Err(ContractError::SpendExceedsLimit { max_spend_bps });
             }
 
-            corp.treasury_balance = corp
-                .treasury_balance
+            *balance = balance
                 .checked_sub(*amount)
                 .map_err(|_| ContractError::Overflow)?;
-            CORPORATIONS.save(deps.storage, proposal.corp_id, &corp)?;
+            save_corporation(deps.storage, &corp)?;
 
             msgs.push(BankMsg::Send {
                 to_address: recipient.to_string(),
                 amount: vec![Coin {
-                    denom: config.denom.clone(),
+                    denom: spend_denom.clone(),
                     amount: *amount,
                 }],
             });
+            resp = resp.add_attribute("spend_denom", spend_denom);
 
             resp = resp.add_attribute("spend_amount", amount.to_string());
+            "treasury_spent"
         }
 
         ProposalType::ChangeSettings {
@@ -585,6 +1287,12 @@ fn execute_execute_proposal(
             join_policy,
             quorum_bps,
             voting_period,
+            max_spend_bps,
+            min_officer_reputation,
+            proposal_rules,
+            branding,
+            dues,
+            rage_quit,
         } => {
             // FIX: M-02 — validate governance parameters before applying
             if let Some(q) = quorum_bps {
@@ -593,6 +1301,21 @@ fn execute_execute_proposal(
             if let Some(vp) = voting_period {
                 validate_voting_period(*vp)?;
             }
+            if let Some(bps) = max_spend_bps {
+                validate_max_spend_bps(*bps, config.max_spend_bps)?;
+            }
+            if let Some(rules) = proposal_rules {
+                validate_proposal_rules(rules)?;
+            }
+            if let Some(b) = branding {
+                validate_branding(b)?;
+            }
+            if let Some(d) = dues {
+                validate_dues(d)?;
+            }
+            if let Some(r) = rage_quit {
+                validate_rage_quit(r)?;
+            }
 
             if let Some(n) = name {
                 corp.name = n.clone();
@@ -609,9 +1332,38 @@ fn execute_execute_proposal(
             if let Some(vp) = voting_period {
                 corp.voting_period = *vp;
             }
-            CORPORATIONS.save(deps.storage, proposal.corp_id, &corp)?;
+            if let Some(bps) = max_spend_bps {
+                corp.max_spend_bps = *bps;
+            }
+            if let Some(rep) = min_officer_reputation {
+                corp.min_officer_reputation = *rep;
+            }
+            if let Some(rules) = proposal_rules {
+                corp.proposal_rules = rules.clone();
+            }
+            if let Some(b) = branding {
+                corp.branding = b.clone();
+            }
+            if let Some(r) = rage_quit {
+                corp.rage_quit = r.clone();
+            }
+            if let Some(d) = dues {
+                let was_charging = corp.dues.is_some();
+                corp.dues = (!d.amount.is_zero()).then(|| d.clone());
+                // Switching dues on or off restarts everyone's clock
+                match &corp.dues {
+                    Some(d) if !was_charging => reset_dues(
+                        deps.storage,
+                        proposal.corp_id,
+                        Some(env.block.time.plus_seconds(d.period)),
+                    )?,
+                    None if was_charging => reset_dues(deps.storage, proposal.corp_id, None)?,
+                    _ => {}
+                }
+            }
+            save_corporation(deps.storage, &corp)?;
 
-            resp = resp.add_attribute("result", "settings_changed");
+            "settings_changed"
         }
 
         ProposalType::KickMember { member } => {
@@ -626,10 +1378,15 @@ fn execute_execute_proposal(
             }
 
             MEMBERS.remove(deps.storage, (proposal.corp_id, member));
+            MEMBER_CORPS.remove(deps.storage, (member, proposal.corp_id));
+            REPUTATION.remove(deps.storage, (proposal.corp_id, member));
+            drop_from_squad(deps.storage, proposal.corp_id, member)?;
+            forget_stake(deps.storage, &mut corp, member)?;
             corp.member_count -= 1;
-            CORPORATIONS.save(deps.storage, proposal.corp_id, &corp)?;
+            save_corporation(deps.storage, &corp)?;
 
             resp = resp.add_attribute("kicked", member.to_string());
+            "member_kicked"
         }
 
         ProposalType::PromoteMember { member, new_role } => {
@@ -645,10 +1402,24 @@ fn execute_execute_proposal(
                         corp_id: proposal.corp_id,
                     })?;
 
+            if *new_role == MemberRole::Officer {
+                let actual = REPUTATION
+                    .may_load(deps.storage, (proposal.corp_id, member))?
+                    .map(|rep| decayed_reputation(&rep, env.block.time))
+                    .unwrap_or(0);
+                if actual < corp.min_officer_reputation {
+                    return Err(ContractError::InsufficientReputation {
+                        required: corp.min_officer_reputation,
+                        actual,
+                    });
+                }
+            }
+
             member_info.role = new_role.clone();
             MEMBERS.save(deps.storage, (proposal.corp_id, member), &member_info)?;
 
             resp = resp.add_attribute("promoted", member.to_string());
+            "member_promoted"
         }
 
         ProposalType::Dissolution => {
@@ -658,14 +1429,16 @@ fn execute_execute_proposal(
             corp.status = CorporationStatus::Dissolving;
 
             // FIX: L-01 — distribute remainder to founder so no funds are locked
-            if !corp.treasury_balance.is_zero() && corp.member_count > 0 {
-                let member_count_u128 = Uint128::from(corp.member_count);
-                let share = corp
-                    .treasury_balance
-                    .checked_div(member_count_u128)
-                    .map_err(|_| ContractError::Overflow)?;
-                let remainder = corp.treasury_balance.checked_rem(member_count_u128)
-                    .map_err(|_| ContractError::Overflow)?;
+            let custom_balance = corp
+                .custom_treasury
+                .as_ref()
+                .map_or(Uint128::zero(), |custom| custom.balance);
+            if (!corp.treasury_balance.is_zero() || !custom_balance.is_zero())
+                && corp.member_count > 0
+            {
+                let (share, remainder) = even_split(corp.treasury_balance, corp.member_count)?;
+                let (custom_share, custom_remainder) =
+                    even_split(custom_balance, corp.member_count)?;
 
                 // Record claims for all current members
                 let members: Vec<_> = MEMBERS
@@ -674,32 +1447,327 @@ fn execute_execute_proposal(
                     .collect::<StdResult<Vec<_>>>()?;
 
                 for (addr, info) in &members {
-                    let member_share = if info.role == MemberRole::Founder {
-                        share.checked_add(remainder).map_err(|_| ContractError::Overflow)?
+                    let (member_share, member_custom_share) = if info.role == MemberRole::Founder {
+                        (
+                            share
+                                .checked_add(remainder)
+                                .map_err(|_| ContractError::Overflow)?,
+                            custom_share
+                                .checked_add(custom_remainder)
+                                .map_err(|_| ContractError::Overflow)?,
+                        )
                     } else {
-                        share
+                        (share, custom_share)
                     };
                     DISSOLUTION_CLAIMS.save(
                         deps.storage,
                         (proposal.corp_id, addr),
                         &member_share,
                     )?;
+                    if !member_custom_share.is_zero() {
+                        CUSTOM_DISSOLUTION_CLAIMS.save(
+                            deps.storage,
+                            (proposal.corp_id, addr),
+                            &member_custom_share,
+                        )?;
+                    }
                 }
             }
 
-            CORPORATIONS.save(deps.storage, proposal.corp_id, &corp)?;
+            save_corporation(deps.storage, &corp)?;
 
-            resp = resp.add_attribute("result", "dissolution_started");
+            "dissolution_started"
         }
 
         ProposalType::Custom { title, .. } => {
+            resp = resp.add_attribute("custom_title", title);
+            "custom_passed"
+        }
+
+        ProposalType::CreateSquad { name, leader } => {
+            create_squad(deps.storage, proposal.corp_id, name, leader, env.block.time)?;
+            resp = resp.add_attribute("squad", name);
+            "squad_created"
+        }
+
+        ProposalType::AssignToSquad { member, squad } => {
+            assign_to_squad(deps.storage, proposal.corp_id, member, squad.as_deref())?;
+            resp = resp.add_attribute("member", member.to_string());
+            "squad_assigned"
+        }
+
+        ProposalType::DisbandSquad { name } => {
+            disband_squad(deps.storage, proposal.corp_id, name)?;
+            resp = resp.add_attribute("squad", name);
+            "squad_disbanded"
+        }
+
+        ProposalType::MultiChoice { title, options } => {
+            // Set when the ballots were counted above
+            let winner = proposal.winning_option.unwrap_or_default();
             resp = resp
-                .add_attribute("result", "custom_passed")
-                .add_attribute("custom_title", title);
+                .add_attribute("choice_title", title)
+                .add_attribute("winning_option", winner.to_string())
+                .add_attribute("winner", &options[winner as usize]);
+            "choice_decided"
+        }
+
+        ProposalType::MilestoneSpend {
+            recipient,
+            tranches,
+        } => {
+            // Nothing is paid yet; each tranche waits for its confirmation vote
+            let plan = MilestonePlan {
+                corp_id: proposal.corp_id,
+                recipient: recipient.clone(),
+                tranches: tranches.clone(),
+                released: 0,
+                rounds: 0,
+                vote: None,
+            };
+            MILESTONE_PLANS.save(deps.storage, proposal_id, &plan)?;
+            resp = resp.add_attribute("tranches", tranches.len().to_string());
+            "milestones_approved"
+        }
+    };
+    corp.executed_proposals += 1;
+    save_corporation(deps.storage, &corp)?;
+
+    // The author earns reputation for a proposal that passed, if still a member
+    if MEMBERS.has(deps.storage, (proposal.corp_id, &proposal.proposer)) {
+        let mut rep = REPUTATION
+            .may_load(deps.storage, (proposal.corp_id, &proposal.proposer))?
+            .unwrap_or_default();
+        rep.score = decayed_reputation(&rep, env.block.time).saturating_add(AUTHOR_REPUTATION);
+        rep.updated_at = env.block.time;
+        REPUTATION.save(deps.storage, (proposal.corp_id, &proposal.proposer), &rep)?;
+
+        // Failures come back through `reply` instead of reverting the execution
+        if let Some(badge) = &config.governance_badge {
+            let mint = WasmMsg::Execute {
+                contract_addr: badge.achievement_contract.to_string(),
+                msg: to_json_binary(&AchievementExecuteMsg::MintFor {
+                    mint: AchievementMintRequest {
+                        to: proposal.proposer.to_string(),
+                        achievement_id: badge.achievement_id.clone(),
+                        category: "governance".to_string(),
+                        earned_at: env.block.time,
+                        description: format!("Passed proposal #{} in {}", proposal_id, corp.name),
+                        rarity: "common".to_string(),
+                        token_uri: None,
+                        soulbound: true,
+                        tier: None,
+                    },
+                })?,
+                funds: vec![],
+            };
+            resp = resp.add_submessage(
+                SubMsg::reply_on_error(mint, BADGE_REPLY_ID).with_gas_limit(BADGE_GAS_LIMIT),
+            );
         }
     }
 
-    Ok(resp.add_messages(msgs))
+    let hook = proposal_hook(
+        &config,
+        ProposalHookMsg::ProposalExecutedHook {
+            proposal_id,
+            corp_id: proposal.corp_id,
+            result: result.to_string(),
+        },
+    )?;
+
+    Ok(resp
+        .add_messages(msgs)
+        .add_submessages(hook)
+        .add_event(executed(result)))
+}
+
+// ─── Milestone Tranches ───────────────────────────────────────────────
+
+fn load_milestone_plan(
+    storage: &dyn Storage,
+    proposal_id: u64,
+) -> Result<MilestonePlan, ContractError> {
+    MILESTONE_PLANS
+        .may_load(storage, proposal_id)?
+        .ok_or(ContractError::NotMilestonePlan { id: proposal_id })
+}
+
+fn execute_open_tranche_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let mut plan = load_milestone_plan(deps.storage, proposal_id)?;
+    let corp = load_corporation(deps.as_ref(), plan.corp_id)?;
+    assert_active(&corp)?;
+    if info.sender != plan.recipient {
+        assert_member(deps.as_ref(), plan.corp_id, &info.sender)?;
+    }
+    if plan.released as usize >= plan.tranches.len() {
+        return Err(ContractError::AllTranchesReleased { id: proposal_id });
+    }
+    if plan.vote.is_some() {
+        return Err(ContractError::TrancheVoteOpen { id: proposal_id });
+    }
+
+    plan.rounds += 1;
+    let voting_ends_at = env
+        .block
+        .time
+        .plus_seconds(corp.voting_period.min(TRANCHE_VOTING_PERIOD));
+    plan.vote = Some(TrancheVote {
+        round: plan.rounds,
+        yes_votes: 0,
+        no_votes: 0,
+        member_count_snapshot: corp.member_count,
+        member_epoch_snapshot: corp.member_epoch,
+        voting_ends_at,
+    });
+    MILESTONE_PLANS.save(deps.storage, proposal_id, &plan)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "open_tranche_vote")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("tranche", plan.released.to_string())
+        .add_attribute("voting_ends_at", voting_ends_at.seconds().to_string()))
+}
+
+fn execute_vote_tranche(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    vote: bool,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let mut plan = load_milestone_plan(deps.storage, proposal_id)?;
+    let Some(ballot) = plan.vote.as_mut() else {
+        return Err(ContractError::NoTrancheVote { id: proposal_id });
+    };
+    if env.block.time >= ballot.voting_ends_at {
+        return Err(ContractError::VotingEnded { id: proposal_id });
+    }
+    let member = assert_member(deps.as_ref(), plan.corp_id, &info.sender)?;
+    let corp = load_corporation(deps.as_ref(), plan.corp_id)?;
+    assert_not_suspended(&corp, &member)?;
+    // Same flash-join protection as proposal votes
+    if member.epoch > ballot.member_epoch_snapshot {
+        return Err(ContractError::JoinedAfterProposal);
+    }
+    if TRANCHE_VOTES.has(deps.storage, (proposal_id, ballot.round, &info.sender)) {
+        return Err(ContractError::AlreadyVoted { id: proposal_id });
+    }
+
+    TRANCHE_VOTES.save(deps.storage, (proposal_id, ballot.round, &info.sender), &vote)?;
+    if vote {
+        ballot.yes_votes += 1;
+    } else {
+        ballot.no_votes += 1;
+    }
+    MILESTONE_PLANS.save(deps.storage, proposal_id, &plan)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "vote_tranche")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("tranche", plan.released.to_string())
+        .add_attribute("voter", info.sender.to_string())
+        .add_attribute("vote", vote.to_string()))
+}
+
+// Like ExecuteProposal, releasing is permissionless once voting has ended
+fn execute_release_tranche(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let mut plan = load_milestone_plan(deps.storage, proposal_id)?;
+    let Some(ballot) = plan.vote.take() else {
+        return Err(ContractError::NoTrancheVote { id: proposal_id });
+    };
+    if env.block.time < ballot.voting_ends_at {
+        return Err(ContractError::VotingNotEnded { id: proposal_id });
+    }
+
+    let mut corp = load_corporation(deps.as_ref(), plan.corp_id)?;
+    let index = plan.released as usize;
+    let resp = Response::new()
+        .add_attribute("action", "release_tranche")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("tranche", index.to_string());
+    let passed = check_votes_passed(
+        ballot.yes_votes,
+        ballot.no_votes,
+        ballot.member_count_snapshot,
+        corp.quorum_bps,
+    );
+    if !passed {
+        // The milestone can be put to a vote again
+        MILESTONE_PLANS.save(deps.storage, proposal_id, &plan)?;
+        return Ok(resp.add_attribute("result", "rejected"));
+    }
+
+    assert_active(&corp)?;
+    let amount = plan.tranches[index].amount;
+    let config = load_config(deps.as_ref())?;
+    let limit = max_spend(&config, &corp, corp.treasury_balance);
+    if amount > limit {
+        // Close the ballot unpaid rather than fail, which would leave it open
+        // and block the next vote; the tranche can be voted on again once the
+        // treasury has grown
+        MILESTONE_PLANS.save(deps.storage, proposal_id, &plan)?;
+        return Ok(resp
+            .add_attribute("result", "deferred")
+            .add_attribute("max_spend", limit.to_string()));
+    }
+    corp.treasury_balance = corp
+        .treasury_balance
+        .checked_sub(amount)
+        .map_err(|_| ContractError::Overflow)?;
+    save_corporation(deps.storage, &corp)?;
+    plan.released += 1;
+    MILESTONE_PLANS.save(deps.storage, proposal_id, &plan)?;
+
+    let config = load_config(deps.as_ref())?;
+    Ok(resp
+        .add_message(BankMsg::Send {
+            to_address: plan.recipient.to_string(),
+            amount: vec![Coin {
+                denom: config.denom,
+                amount,
+            }],
+        })
+        .add_attribute("result", "released")
+        .add_attribute("recipient", plan.recipient.to_string())
+        .add_attribute("spend_amount", amount.to_string()))
+}
+
+fn execute_cancel_milestone_plan(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let plan = load_milestone_plan(deps.storage, proposal_id)?;
+    // The corporation approved the plan by vote, so no officer may undo it
+    // alone; only the recipient can give up what is still owed to them
+    if info.sender != plan.recipient {
+        return Err(ContractError::Unauthorized {
+            role: "recipient".to_string(),
+        });
+    }
+    MILESTONE_PLANS.remove(deps.storage, proposal_id);
+    let cancelled = plan.tranches.len() - plan.released as usize;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_milestone_plan")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("released", plan.released.to_string())
+        .add_attribute("cancelled", cancelled.to_string()))
 }
 
 // ─── Claim Dissolution ────────────────────────────────────────────────
@@ -719,8 +1787,11 @@ fn execute_claim_dissolution(
     let share = DISSOLUTION_CLAIMS
         .may_load(deps.storage, (corp_id, &info.sender))?
         .unwrap_or(Uint128::zero());
+    let custom_share = CUSTOM_DISSOLUTION_CLAIMS
+        .may_load(deps.storage, (corp_id, &info.sender))?
+        .unwrap_or(Uint128::zero());
 
-    if share.is_zero() {
+    if share.is_zero() && custom_share.is_zero() {
         return Err(ContractError::NothingToClaim);
     }
 
@@ -728,7 +1799,12 @@ fn execute_claim_dissolution(
 
     // Remove claim and member
     DISSOLUTION_CLAIMS.remove(deps.storage, (corp_id, &info.sender));
+    CUSTOM_DISSOLUTION_CLAIMS.remove(deps.storage, (corp_id, &info.sender));
     MEMBERS.remove(deps.storage, (corp_id, &info.sender));
+    MEMBER_CORPS.remove(deps.storage, (&info.sender, corp_id));
+    REPUTATION.remove(deps.storage, (corp_id, &info.sender));
+    drop_from_squad(deps.storage, corp_id, &info.sender)?;
+    forget_stake(deps.storage, &mut corp, &info.sender)?;
 
     corp.member_count -= 1;
     corp.treasury_balance = corp
@@ -741,25 +1817,39 @@ fn execute_claim_dissolution(
         corp.status = CorporationStatus::Dissolved;
     }
 
-    CORPORATIONS.save(deps.storage, corp_id, &corp)?;
+    let mut payouts = vec![(config.denom, share)];
+    if let Some(custom) = corp.custom_treasury.as_mut() {
+        custom.balance = custom
+            .balance
+            .checked_sub(custom_share)
+            .map_err(|_| ContractError::Overflow)?;
+        payouts.push((custom.denom.clone(), custom_share));
+    }
 
-    let msg = BankMsg::Send {
-        to_address: info.sender.to_string(),
-        amount: vec![Coin {
-            denom: config.denom,
-            amount: share,
-        }],
-    };
+    save_corporation(deps.storage, &corp)?;
 
-    Ok(Response::new()
-        .add_message(msg)
+    let mut resp = Response::new()
         .add_attribute("action", "claim_dissolution")
-        .add_attribute("corp_id", corp_id.to_string())
-        .add_attribute("claimant", info.sender.to_string())
-        .add_attribute("amount", share.to_string()))
+        .add_attribute("corp_id", corp_id.to_string());
+    for (denom, amount) in payouts.into_iter().filter(|(_, amount)| !amount.is_zero()) {
+        resp = resp
+            .add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![Coin {
+                    denom: denom.clone(),
+                    amount,
+                }],
+            })
+            .add_event(ClaimEvent {
+                recipient: info.sender.to_string(),
+                amount: amount.to_string(),
+                denom,
+            });
+    }
+    Ok(resp)
 }
 
-// ─── Update Description (Founder only, no proposal) ──────────────────
+// ─── Update Description / Branding (Founder only, no proposal) ───────
 
 fn execute_update_description(
     deps: DepsMut,
@@ -779,142 +1869,673 @@ fn execute_update_description(
     }
 
     corp.description = description;
-    CORPORATIONS.save(deps.storage, corp_id, &corp)?;
+    save_corporation(deps.storage, &corp)?;
 
     Ok(Response::new()
         .add_attribute("action", "update_description")
         .add_attribute("corp_id", corp_id.to_string()))
 }
 
-// ─── Withdraw Fees (H-01) ─────────────────────────────────────────────
-
-// FIX: H-01 — allow owner to withdraw surplus fees/deposits not tracked in any treasury
-fn execute_withdraw_fees(
+fn execute_update_branding(
     deps: DepsMut,
-    env: Env,
     info: MessageInfo,
-    amount: Uint128,
+    corp_id: u64,
+    branding: Branding,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
-    let config = load_config(deps.as_ref())?;
-    if info.sender != config.owner {
-        return Err(ContractError::Unauthorized {
-            role: "owner".to_string(),
-        });
-    }
-    if amount.is_zero() {
-        return Err(ContractError::ZeroAmount);
-    }
-
-    // Query actual contract balance
-    let contract_balance = deps
-        .querier
-        .query_balance(&env.contract.address, &config.denom)?
-        .amount;
-
-    // Sum all tracked treasury balances across corporations
-    let total_tracked: Uint128 = CORPORATIONS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .try_fold(Uint128::zero(), |acc, item| {
-            let (_, corp) = item?;
-            Ok::<_, cosmwasm_std::StdError>(acc.saturating_add(corp.treasury_balance))
-        })?;
+    reject_funds(&info)?;
+    let mut corp = load_corporation(deps.as_ref(), corp_id)?;
+    assert_active(&corp)?;
 
-    let surplus = contract_balance.saturating_sub(total_tracked);
-    if amount > surplus {
-        return Err(ContractError::InsufficientSurplus {
-            requested: amount.to_string(),
-            available: surplus.to_string(),
+    let member = assert_member(deps.as_ref(), corp_id, &info.sender)?;
+    if member.role != MemberRole::Founder {
+        return Err(ContractError::Unauthorized {
+            role: "founder".to_string(),
         });
     }
+    validate_branding(&branding)?;
 
-    let msg = BankMsg::Send {
-        to_address: config.owner.to_string(),
-        amount: vec![Coin {
-            denom: config.denom,
-            amount,
-        }],
-    };
+    corp.branding = branding;
+    save_corporation(deps.storage, &corp)?;
 
     Ok(Response::new()
-        .add_message(msg)
-        .add_attribute("action", "withdraw_fees")
-        .add_attribute("amount", amount.to_string())
-        .add_attribute("surplus", surplus.to_string()))
+        .add_attribute("action", "update_branding")
+        .add_attribute("corp_id", corp_id.to_string()))
 }
 
-// ─── Two-Step Owner Transfer (H-04) ──────────────────────────────────
+// ─── Squads ───────────────────────────────────────────────────────────
 
-fn execute_propose_owner(
+fn execute_create_squad(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    new_owner: String,
+    corp_id: u64,
+    name: String,
+    leader: String,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
-    let config = load_config(deps.as_ref())?;
-    if info.sender != config.owner {
-        return Err(ContractError::Unauthorized {
-            role: "owner".to_string(),
+    reject_funds(&info)?;
+    let corp = load_corporation(deps.as_ref(), corp_id)?;
+    assert_active(&corp)?;
+    assert_officer_or_founder(deps.as_ref(), corp_id, &info.sender)?;
+    validate_squad_name(&name)?;
+
+    let leader = deps.api.addr_validate(&leader)?;
+    create_squad(deps.storage, corp_id, &name, &leader, env.block.time)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_squad")
+        .add_attribute("corp_id", corp_id.to_string())
+        .add_attribute("squad", name)
+        .add_attribute("leader", leader.to_string()))
+}
+
+fn execute_assign_to_squad(
+    deps: DepsMut,
+    info: MessageInfo,
+    corp_id: u64,
+    member: String,
+    squad: Option<String>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let corp = load_corporation(deps.as_ref(), corp_id)?;
+    assert_active(&corp)?;
+    assert_officer_or_founder(deps.as_ref(), corp_id, &info.sender)?;
+
+    let member = deps.api.addr_validate(&member)?;
+    assign_to_squad(deps.storage, corp_id, &member, squad.as_deref())?;
+
+    Ok(Response::new()
+        .add_attribute("action", "assign_to_squad")
+        .add_attribute("corp_id", corp_id.to_string())
+        .add_attribute("member", member.to_string())
+        .add_attribute("squad", squad.unwrap_or_default()))
+}
+
+fn execute_disband_squad(
+    deps: DepsMut,
+    info: MessageInfo,
+    corp_id: u64,
+    name: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let corp = load_corporation(deps.as_ref(), corp_id)?;
+    assert_active(&corp)?;
+    assert_officer_or_founder(deps.as_ref(), corp_id, &info.sender)?;
+
+    disband_squad(deps.storage, corp_id, &name)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "disband_squad")
+        .add_attribute("corp_id", corp_id.to_string())
+        .add_attribute("squad", name))
+}
+
+fn validate_squad_name(name: &str) -> Result<(), ContractError> {
+    if name.is_empty() || name.len() > MAX_SQUAD_NAME_LEN {
+        return Err(ContractError::InvalidSquadName {
+            max: MAX_SQUAD_NAME_LEN,
+        });
+    }
+    Ok(())
+}
+
+fn load_squad(storage: &dyn Storage, corp_id: u64, name: &str) -> Result<Squad, ContractError> {
+    SQUADS
+        .may_load(storage, (corp_id, name))?
+        .ok_or_else(|| ContractError::SquadNotFound {
+            name: name.to_string(),
+        })
+}
+
+/// Create a squad and move its leader into it. Shared by the officer action
+/// and the CreateSquad proposal.
+fn create_squad(
+    storage: &mut dyn Storage,
+    corp_id: u64,
+    name: &str,
+    leader: &Addr,
+    now: Timestamp,
+) -> Result<(), ContractError> {
+    if SQUADS.has(storage, (corp_id, name)) {
+        return Err(ContractError::SquadExists {
+            name: name.to_string(),
         });
     }
-    if PENDING_OWNER.may_load(deps.storage)?.is_some() {
-        return Err(ContractError::OwnerTransferAlreadyPending);
+    let count = SQUADS
+        .prefix(corp_id)
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .count();
+    if count >= MAX_SQUADS {
+        return Err(ContractError::TooManySquads { max: MAX_SQUADS });
     }
 
-    let proposed = deps.api.addr_validate(&new_owner)?;
-    PENDING_OWNER.save(
-        deps.storage,
-        &PendingOwnerTransfer {
-            proposed_owner: proposed.clone(),
+    SQUADS.save(
+        storage,
+        (corp_id, name),
+        &Squad {
+            name: name.to_string(),
+            leader: leader.clone(),
+            member_count: 0,
+            created_at: now,
         },
     )?;
+    assign_to_squad(storage, corp_id, leader, Some(name))
+}
 
-    Ok(Response::new()
-        .add_attribute("action", "propose_owner")
-        .add_attribute("proposed_owner", proposed.as_str()))
+/// Move `member` into `squad`, or out of their current squad when None.
+/// A squad leader cannot be moved; the squad must be disbanded first.
+fn assign_to_squad(
+    storage: &mut dyn Storage,
+    corp_id: u64,
+    member: &Addr,
+    squad: Option<&str>,
+) -> Result<(), ContractError> {
+    if !MEMBERS.has(storage, (corp_id, member)) {
+        return Err(ContractError::NotMember { corp_id });
+    }
+    let mut target = squad
+        .map(|name| load_squad(storage, corp_id, name))
+        .transpose()?;
+
+    match MEMBER_SQUADS.may_load(storage, (corp_id, member))? {
+        Some(current) if Some(current.as_str()) == squad => {
+            return Err(ContractError::AlreadyInSquad { name: current });
+        }
+        Some(current) => {
+            let mut old = load_squad(storage, corp_id, &current)?;
+            if old.leader == *member {
+                return Err(ContractError::SquadLeader { name: current });
+            }
+            old.member_count -= 1;
+            SQUADS.save(storage, (corp_id, &current), &old)?;
+            SQUAD_MEMBERS.remove(storage, (corp_id, &current, member));
+            MEMBER_SQUADS.remove(storage, (corp_id, member));
+        }
+        None if squad.is_none() => return Err(ContractError::NotInSquad),
+        None => {}
+    }
+
+    if let Some(new) = target.as_mut() {
+        new.member_count += 1;
+        SQUADS.save(storage, (corp_id, &new.name), new)?;
+        SQUAD_MEMBERS.save(storage, (corp_id, &new.name, member), &())?;
+        MEMBER_SQUADS.save(storage, (corp_id, member), &new.name)?;
+    }
+    Ok(())
 }
 
-fn execute_accept_owner(
+/// Remove a squad and release all of its members.
+fn disband_squad(storage: &mut dyn Storage, corp_id: u64, name: &str) -> Result<(), ContractError> {
+    load_squad(storage, corp_id, name)?;
+
+    // Bounded by max_members
+    let members: Vec<Addr> = SQUAD_MEMBERS
+        .prefix((corp_id, name))
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for member in &members {
+        SQUAD_MEMBERS.remove(storage, (corp_id, name, member));
+        MEMBER_SQUADS.remove(storage, (corp_id, member));
+    }
+    SQUADS.remove(storage, (corp_id, name));
+    Ok(())
+}
+
+/// Clear squad membership for someone leaving the corporation. A departing
+/// leader takes the squad down with them.
+fn drop_from_squad(
+    storage: &mut dyn Storage,
+    corp_id: u64,
+    member: &Addr,
+) -> Result<(), ContractError> {
+    let Some(name) = MEMBER_SQUADS.may_load(storage, (corp_id, member))? else {
+        return Ok(());
+    };
+    let mut squad = load_squad(storage, corp_id, &name)?;
+    if squad.leader == *member {
+        return disband_squad(storage, corp_id, &name);
+    }
+    squad.member_count -= 1;
+    SQUADS.save(storage, (corp_id, &name), &squad)?;
+    SQUAD_MEMBERS.remove(storage, (corp_id, &name, member));
+    MEMBER_SQUADS.remove(storage, (corp_id, member));
+    Ok(())
+}
+
+/// Count a member's payment into the treasury toward contribution-based shares
+fn record_contribution(
+    storage: &mut dyn Storage,
+    corp: &mut Corporation,
+    member: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let contributed = CONTRIBUTIONS
+        .may_load(storage, (corp.id, member))?
+        .unwrap_or_default();
+    CONTRIBUTIONS.save(storage, (corp.id, member), &(contributed + amount))?;
+    corp.total_contributions = corp
+        .total_contributions
+        .checked_add(amount)
+        .map_err(|_| ContractError::Overflow)?;
+    Ok(())
+}
+
+/// Drop a departing member's contribution and yes-vote tracking. What they
+/// paid in stays in the treasury for the remaining members.
+fn forget_stake(storage: &mut dyn Storage, corp: &mut Corporation, member: &Addr) -> StdResult<()> {
+    if let Some(contributed) = CONTRIBUTIONS.may_load(storage, (corp.id, member))? {
+        corp.total_contributions = corp.total_contributions.saturating_sub(contributed);
+        CONTRIBUTIONS.remove(storage, (corp.id, member));
+    }
+    OPEN_YES_VOTES.remove(storage, (corp.id, member));
+    Ok(())
+}
+
+/// Proposals the member voted yes on that are still being voted on or queued
+fn open_yes_votes(storage: &dyn Storage, corp_id: u64, member: &Addr) -> StdResult<Vec<u64>> {
+    let mut open = vec![];
+    for id in OPEN_YES_VOTES
+        .may_load(storage, (corp_id, member))?
+        .unwrap_or_default()
+    {
+        let status = PROPOSALS.load(storage, id)?.status;
+        if matches!(
+            status,
+            ProposalStatus::Active | ProposalStatus::QueuedForExecution
+        ) {
+            open.push(id);
+        }
+    }
+    Ok(open)
+}
+
+// ─── Reputation ───────────────────────────────────────────────────────
+
+/// Credit `voter` for a vote on the corporation's proposal `corp_seq` and
+/// return their new score. Voting on the next proposal after the last one
+/// voted on extends the streak; skipping one resets it. Late votes on older
+/// proposals leave the streak alone.
+fn record_vote_reputation(
+    storage: &mut dyn Storage,
+    corp_id: u64,
+    voter: &Addr,
+    corp_seq: u64,
+    now: Timestamp,
+) -> StdResult<u64> {
+    let mut rep: Reputation = REPUTATION
+        .may_load(storage, (corp_id, voter))?
+        .unwrap_or_default();
+    if corp_seq == rep.last_voted_seq + 1 {
+        rep.vote_streak += 1;
+    } else if corp_seq > rep.last_voted_seq {
+        rep.vote_streak = 1;
+    }
+    rep.last_voted_seq = rep.last_voted_seq.max(corp_seq);
+
+    let steps = rep
+        .vote_streak
+        .saturating_sub(1)
+        .min(MAX_STREAK_BONUS_STEPS) as u64;
+    rep.score =
+        decayed_reputation(&rep, now).saturating_add(VOTE_REPUTATION + STREAK_BONUS * steps);
+    rep.updated_at = now;
+    REPUTATION.save(storage, (corp_id, voter), &rep)?;
+    Ok(rep.score)
+}
+
+// ─── Emergency Veto ───────────────────────────────────────────────────
+
+/// Kill-switch for a single exploitative proposal without touching any other
+/// corporation. Only possible while voting is open; the proposer gets their
+/// deposit back since the proposal never reached a vote outcome.
+fn execute_veto_proposal(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
+    proposal_id: u64,
+    reason: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let config = load_config(deps.as_ref())?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {
+            role: "owner".to_string(),
+        });
+    }
+    if reason.trim().is_empty() || reason.len() > MAX_VETO_REASON_LEN {
+        return Err(ContractError::InvalidVetoReason {
+            max: MAX_VETO_REASON_LEN,
+        });
+    }
+
+    let mut proposal = PROPOSALS
+        .load(deps.storage, proposal_id)
+        .map_err(|_| ContractError::ProposalNotFound { id: proposal_id })?;
+    assert_voting_active(&proposal, &env)?;
+
+    proposal.status = ProposalStatus::Vetoed;
+    proposal.veto_reason = Some(reason.clone());
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    let mut resp = Response::new()
+        .add_attribute("action", "veto_proposal")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("reason", reason)
+        .add_event(ProposalExecutedEvent {
+            proposal_id: proposal_id.to_string(),
+            corp_id: proposal.corp_id.to_string(),
+            result: "vetoed".to_string(),
+        });
+    if !proposal.deposit.is_zero() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: proposal.proposer.to_string(),
+            amount: vec![Coin {
+                denom: config.denom,
+                amount: proposal.deposit,
+            }],
+        });
+    }
+    Ok(resp)
+}
+
+// ─── Withdraw Fees (H-01) ─────────────────────────────────────────────
+
+// FIX: H-01 — allow owner to withdraw surplus fees/deposits not tracked in any treasury
+fn execute_withdraw_fees(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?; // FIX: M-08
-    let pending = PENDING_OWNER
-        .may_load(deps.storage)?
-        .ok_or(ContractError::NoOwnerTransferPending)?;
+    let config = load_config(deps.as_ref())?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {
+            role: "owner".to_string(),
+        });
+    }
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
 
-    if info.sender != pending.proposed_owner {
-        return Err(ContractError::NotPendingOwner);
+    // Query actual contract balance
+    let contract_balance = deps
+        .querier
+        .query_balance(&env.contract.address, &config.denom)?
+        .amount;
+
+    // Sum all tracked treasury balances across corporations
+    let total_tracked: Uint128 = CORPORATIONS
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .try_fold(Uint128::zero(), |acc, item| {
+            let (_, corp) = item?;
+            Ok::<_, cosmwasm_std::StdError>(acc.saturating_add(corp.treasury_balance))
+        })?;
+
+    let surplus = contract_balance.saturating_sub(total_tracked);
+    if amount > surplus {
+        return Err(ContractError::InsufficientSurplus {
+            requested: amount.to_string(),
+            available: surplus.to_string(),
+        });
     }
 
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.owner = pending.proposed_owner.clone();
-        Ok(c)
-    })?;
-    PENDING_OWNER.remove(deps.storage);
+    let msg = BankMsg::Send {
+        to_address: config.owner.to_string(),
+        amount: vec![Coin {
+            denom: config.denom,
+            amount,
+        }],
+    };
 
     Ok(Response::new()
-        .add_attribute("action", "accept_owner")
-        .add_attribute("new_owner", pending.proposed_owner.as_str()))
+        .add_message(msg)
+        .add_attribute("action", "withdraw_fees")
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("surplus", surplus.to_string()))
 }
 
-fn execute_cancel_owner_transfer(
+fn execute_set_governance_badge(
     deps: DepsMut,
     info: MessageInfo,
+    badge: Option<GovernanceBadgeMsg>,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
-    let config = load_config(deps.as_ref())?;
+    reject_funds(&info)?;
+    let mut config = load_config(deps.as_ref())?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {
+            role: "owner".to_string(),
+        });
+    }
+
+    config.governance_badge = match badge {
+        Some(b) => {
+            if b.achievement_id.is_empty() {
+                return Err(ContractError::EmptyAchievementId);
+            }
+            Some(GovernanceBadge {
+                achievement_contract: deps.api.addr_validate(&b.achievement_contract)?,
+                achievement_id: b.achievement_id,
+            })
+        }
+        None => None,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    let mut resp = Response::new().add_attribute("action", "set_governance_badge");
+    if let Some(b) = &config.governance_badge {
+        resp = resp
+            .add_attribute("achievement_contract", b.achievement_contract.as_str())
+            .add_attribute("achievement_id", &b.achievement_id);
+    }
+    Ok(resp)
+}
+
+fn execute_set_execution_delays(
+    deps: DepsMut,
+    info: MessageInfo,
+    delays: ExecutionDelays,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let mut config = load_config(deps.as_ref())?;
     if info.sender != config.owner {
         return Err(ContractError::Unauthorized {
             role: "owner".to_string(),
         });
     }
-    if PENDING_OWNER.may_load(deps.storage)?.is_none() {
-        return Err(ContractError::NoOwnerTransferPending);
+    for value in [delays.treasury_spend, delays.change_settings] {
+        if value > MAX_EXECUTION_DELAY {
+            return Err(ContractError::InvalidExecutionDelay {
+                value,
+                max: MAX_EXECUTION_DELAY,
+            });
+        }
     }
 
-    PENDING_OWNER.remove(deps.storage);
-    Ok(Response::new().add_attribute("action", "cancel_owner_transfer"))
+    config.execution_delays = delays;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_execution_delays")
+        .add_attribute(
+            "treasury_spend",
+            config.execution_delays.treasury_spend.to_string(),
+        )
+        .add_attribute(
+            "change_settings",
+            config.execution_delays.change_settings.to_string(),
+        ))
+}
+
+fn execute_set_max_spend_bps(
+    deps: DepsMut,
+    info: MessageInfo,
+    max_spend_bps: u16,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let mut config = load_config(deps.as_ref())?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {
+            role: "owner".to_string(),
+        });
+    }
+    validate_max_spend_bps(max_spend_bps, 10_000)?;
+
+    config.max_spend_bps = max_spend_bps;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_max_spend_bps")
+        .add_attribute("max_spend_bps", max_spend_bps.to_string()))
+}
+
+fn execute_set_leveling(
+    deps: DepsMut,
+    info: MessageInfo,
+    leveling: Option<Leveling>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let mut config = load_config(deps.as_ref())?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {
+            role: "owner".to_string(),
+        });
+    }
+    if let Some(leveling) = &leveling {
+        validate_leveling(leveling)?;
+    }
+
+    let levels = leveling.as_ref().map_or(0, |l| l.levels.len());
+    config.leveling = leveling;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_leveling")
+        .add_attribute("levels", levels.to_string()))
+}
+
+fn execute_add_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let mut config = load_config(deps.as_ref())?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {
+            role: "owner".to_string(),
+        });
+    }
+    if let Some(existing) = &config.hook_contract {
+        return Err(ContractError::HookAlreadySet {
+            address: existing.to_string(),
+        });
+    }
+
+    let hook_contract = deps.api.addr_validate(&address)?;
+    config.hook_contract = Some(hook_contract.clone());
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_hook")
+        .add_attribute("hook_contract", hook_contract))
+}
+
+fn execute_remove_hook(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let mut config = load_config(deps.as_ref())?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {
+            role: "owner".to_string(),
+        });
+    }
+    let hook_contract = config
+        .hook_contract
+        .take()
+        .ok_or(ContractError::NoHookContract)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_hook")
+        .add_attribute("hook_contract", hook_contract))
+}
+
+/// Hook message for the configured hook contract, if any. Failures come back
+/// through `reply` instead of reverting the governance action.
+fn proposal_hook(config: &Config, msg: ProposalHookMsg) -> StdResult<Option<SubMsg>> {
+    let Some(hook_contract) = &config.hook_contract else {
+        return Ok(None);
+    };
+    let hook = WasmMsg::Execute {
+        contract_addr: hook_contract.to_string(),
+        msg: to_json_binary(&msg)?,
+        funds: vec![],
+    };
+    Ok(Some(
+        SubMsg::reply_on_error(hook, HOOK_REPLY_ID).with_gas_limit(HOOK_GAS_LIMIT),
+    ))
+}
+
+// ─── Two-Step Owner Transfer (H-04) ──────────────────────────────────
+
+fn execute_propose_owner(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_owner: String,
+) -> Result<Response, ContractError> {
+    let proposed_owner = deps.api.addr_validate(&new_owner)?;
+    Ok(OWNER_TRANSFER.propose(
+        deps,
+        &info,
+        &CONFIG,
+        PendingOwnerTransfer { proposed_owner },
+    )?)
+}
+
+fn execute_accept_owner(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(OWNER_TRANSFER.accept(deps, &info, &CONFIG)?)
+}
+
+fn execute_cancel_owner_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(OWNER_TRANSFER.cancel(deps, &info, &CONFIG)?)
+}
+
+// ─── Reply ────────────────────────────────────────────────────────────
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        BADGE_REPLY_ID => reply_badge(msg),
+        HOOK_REPLY_ID => reply_hook(msg),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+/// A failed badge mint (e.g. the author already holds it) keeps the execution
+fn reply_badge(msg: Reply) -> Result<Response, ContractError> {
+    let error = match msg.result {
+        SubMsgResult::Ok(_) => return Ok(Response::new()),
+        SubMsgResult::Err(err) => err,
+    };
+    Ok(Response::new()
+        .add_attribute("action", "governance_badge_failed")
+        .add_attribute("error", error))
+}
+
+/// A failed proposal hook keeps the governance action that sent it
+fn reply_hook(msg: Reply) -> Result<Response, ContractError> {
+    let error = match msg.result {
+        SubMsgResult::Ok(_) => return Ok(Response::new()),
+        SubMsgResult::Err(err) => err,
+    };
+    Ok(Response::new()
+        .add_attribute("action", "proposal_hook_failed")
+        .add_attribute("error", error))
 }
 
 // ─── Query ────────────────────────────────────────────────────────────
@@ -924,47 +2545,247 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
         QueryMsg::Corporation { corp_id } => query_corporation(deps, corp_id),
-        QueryMsg::ListCorporations { start_after, limit } => {
-            query_list_corporations(deps, start_after, limit)
-        }
+        QueryMsg::ListCorporations {
+            start_after,
+            limit,
+            filter,
+        } => query_list_corporations(deps, start_after, limit, filter),
         QueryMsg::Members {
             corp_id,
             start_after,
             limit,
         } => query_members(deps, corp_id, start_after, limit),
-        QueryMsg::MemberInfo { corp_id, address } => query_member_info(deps, corp_id, address),
+        QueryMsg::Interests {
+            corp_id,
+            start_after,
+            limit,
+        } => query_interests(deps, env, corp_id, start_after, limit),
+        QueryMsg::MemberInfo { corp_id, address } => {
+            query_member_info(deps, env, corp_id, address)
+        }
+        QueryMsg::ProposalRules { corp_id } => query_proposal_rules(deps, corp_id),
+        QueryMsg::ReputationLeaderboard { corp_id, limit } => {
+            query_reputation_leaderboard(deps, env, corp_id, limit)
+        }
+        QueryMsg::CorporationsByMember {
+            address,
+            start_after,
+            limit,
+        } => query_corporations_by_member(deps, address, start_after, limit),
         QueryMsg::Proposal { proposal_id } => query_proposal(deps, proposal_id),
+        QueryMsg::ProposalByCorpSeq { corp_id, seq } => {
+            query_proposal_by_corp_seq(deps, corp_id, seq)
+        }
         QueryMsg::Proposals {
             corp_id,
             start_after,
             limit,
         } => query_proposals(deps, corp_id, start_after, limit),
         QueryMsg::VoteStatus { proposal_id } => query_vote_status(deps, env, proposal_id),
+        QueryMsg::MilestonePlan { proposal_id } => {
+            to_json_binary(&MILESTONE_PLANS.load(deps.storage, proposal_id)?)
+        }
+        QueryMsg::ChoiceVoteStatus { proposal_id } => {
+            query_choice_vote_status(deps, env, proposal_id)
+        }
+        QueryMsg::ExecutableAt { proposal_id } => query_executable_at(deps, env, proposal_id),
+        QueryMsg::Votes {
+            proposal_id,
+            start_after,
+            limit,
+        } => query_votes(deps, proposal_id, start_after, limit),
+        QueryMsg::Squads {
+            corp_id,
+            start_after,
+            limit,
+        } => query_squads(deps, corp_id, start_after, limit),
+        QueryMsg::SquadMembers {
+            corp_id,
+            name,
+            start_after,
+            limit,
+        } => query_squad_members(deps, corp_id, name, start_after, limit),
+        QueryMsg::MemberSquad { corp_id, address } => query_member_squad(deps, corp_id, address),
         // FIX: H-04
-        QueryMsg::PendingOwner {} => to_json_binary(&PENDING_OWNER.may_load(deps.storage)?),
+        QueryMsg::PendingOwner {} => OWNER_TRANSFER.query_pending(deps),
+        QueryMsg::MigrationStatus {} => query_migration_status(deps),
     }
 }
 
 fn query_corporation(deps: Deps, corp_id: u64) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
     let corp = CORPORATIONS.load(deps.storage, corp_id)?;
-    to_json_binary(&CorporationResponse { corporation: corp })
+    let level = corp_level(&config, &corp);
+    to_json_binary(&CorporationResponse {
+        max_spend: max_spend(&config, &corp, corp.treasury_balance),
+        custom_max_spend: corp
+            .custom_treasury
+            .as_ref()
+            .map(|custom| max_spend(&config, &corp, custom.balance)),
+        level: level.level,
+        xp: level.xp,
+        next_level_xp: level.next_level_xp,
+        member_cap: member_cap(&config, &corp),
+        proposal_deposit: proposal_deposit(&config, &corp),
+        corporation: corp,
+    })
 }
 
 fn query_list_corporations(
     deps: Deps,
     start_after: Option<u64>,
     limit: Option<u32>,
+    filter: Option<CorporationFilter>,
 ) -> StdResult<Binary> {
     let limit = limit.unwrap_or(30).min(100) as usize;
-    let start = start_after.map(Bound::exclusive);
+    let Some(filter) = filter else {
+        let corporations: Vec<Corporation> = CORPORATIONS
+            .range(
+                deps.storage,
+                start_after.map(Bound::exclusive),
+                None,
+                cosmwasm_std::Order::Ascending,
+            )
+            .take(limit)
+            .map(|r| r.map(|(_, v)| v))
+            .collect::<StdResult<_>>()?;
+        let next_start_after = if corporations.len() == limit {
+            corporations.last().map(|c| c.id)
+        } else {
+            None
+        };
+        return to_json_binary(&CorporationsListResponse {
+            corporations,
+            next_start_after,
+        });
+    };
 
-    let corporations: Vec<Corporation> = CORPORATIONS
-        .range(deps.storage, start, None, cosmwasm_std::Order::Ascending)
-        .take(limit)
-        .map(|r| r.map(|(_, v)| v))
-        .collect::<StdResult<_>>()?;
+    // A page resumes after the last corporation of the previous one, at its
+    // current place in whichever index serves the filter
+    let cursor = start_after
+        .map(|id| CORPORATIONS.load(deps.storage, id))
+        .transpose()?;
+    let name_prefix = filter.name_prefix.as_deref().map(str::to_lowercase);
+    let min_members = filter.min_members.unwrap_or(0);
+    let max_members = filter.max_members.unwrap_or(u32::MAX);
+
+    // Each bucket yields its first `limit` matches in index order, keyed so that
+    // keys from different buckets compare; the page is the first `limit` of
+    // them all. Only a search on both name and member count checks entries one
+    // by one, and a bucket that hits the scan limit doing so has only been
+    // searched up to where it stopped, so nothing past the lowest such point
+    // can be returned.
+    let mut hits: Vec<(Vec<u8>, u64)> = vec![];
+    let mut frontier: Option<(Vec<u8>, u64)> = None;
+    for status in [
+        CorporationStatus::Active,
+        CorporationStatus::Dissolving,
+        CorporationStatus::Dissolved,
+    ] {
+        if filter.status.as_ref().is_some_and(|s| *s != status) {
+            continue;
+        }
+        for join_policy in [JoinPolicy::Open, JoinPolicy::InviteOnly] {
+            if filter
+                .join_policy
+                .as_ref()
+                .is_some_and(|p| *p != join_policy)
+            {
+                continue;
+            }
+            let bucket = (status_key(&status), join_policy_key(&join_policy));
+
+            if let Some(prefix) = name_prefix.as_deref().map(str::as_bytes) {
+                // Ordered by name
+                let resume = cursor
+                    .as_ref()
+                    .map(|c| name_index_key(&c.name, c.id))
+                    .filter(|key| key.as_slice() >= prefix);
+                let start = match &resume {
+                    Some(key) => Bound::exclusive(key.as_slice()),
+                    None => Bound::inclusive(prefix),
+                };
+                let entries = CORP_NAME_INDEX.prefix(bucket).range(
+                    deps.storage,
+                    Some(start),
+                    None,
+                    cosmwasm_std::Order::Ascending,
+                );
+                let mut found = 0;
+                for (scanned, entry) in entries.enumerate() {
+                    let (key, member_count) = entry?;
+                    if !key.starts_with(prefix) {
+                        break;
+                    }
+                    let corp_id = name_index_id(&key);
+                    if (min_members..=max_members).contains(&member_count) {
+                        hits.push((key.clone(), corp_id));
+                        found += 1;
+                        if found == limit {
+                            break;
+                        }
+                    }
+                    if scanned + 1 == DIRECTORY_SCAN_LIMIT {
+                        if frontier.as_ref().map_or(true, |(f, _)| key < *f) {
+                            frontier = Some((key, corp_id));
+                        }
+                        break;
+                    }
+                }
+            } else if filter.min_members.is_some() || filter.max_members.is_some() {
+                // Ordered by member count
+                let start = match &cursor {
+                    Some(c) if c.member_count >= min_members => {
+                        Bound::exclusive((c.member_count, c.id))
+                    }
+                    _ => Bound::inclusive((min_members, 0)),
+                };
+                let keys = CORP_SIZE_INDEX.prefix(bucket).keys(
+                    deps.storage,
+                    Some(start),
+                    Some(Bound::inclusive((max_members, u64::MAX))),
+                    cosmwasm_std::Order::Ascending,
+                );
+                for key in keys.take(limit) {
+                    let (member_count, corp_id) = key?;
+                    let mut order = member_count.to_be_bytes().to_vec();
+                    order.extend_from_slice(&corp_id.to_be_bytes());
+                    hits.push((order, corp_id));
+                }
+            } else {
+                // Ordered by id
+                let keys = CORP_DIRECTORY.prefix(bucket).keys(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    cosmwasm_std::Order::Ascending,
+                );
+                for corp_id in keys.take(limit) {
+                    let corp_id = corp_id?;
+                    hits.push((corp_id.to_be_bytes().to_vec(), corp_id));
+                }
+            }
+        }
+    }
+    hits.sort_unstable();
+    if let Some((frontier, _)) = &frontier {
+        hits.retain(|(key, _)| key <= frontier);
+    }
+    hits.truncate(limit);
+    let next_start_after = if hits.len() == limit {
+        hits.last().map(|(_, id)| *id)
+    } else {
+        frontier.map(|(_, id)| id)
+    };
 
-    to_json_binary(&CorporationsListResponse { corporations })
+    let corporations = hits
+        .into_iter()
+        .map(|(_, id)| CORPORATIONS.load(deps.storage, id))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&CorporationsListResponse {
+        corporations,
+        next_start_after,
+    })
 }
 
 fn query_members(
@@ -996,21 +2817,142 @@ fn query_members(
     to_json_binary(&MembersListResponse { members })
 }
 
-fn query_member_info(deps: Deps, corp_id: u64, address: String) -> StdResult<Binary> {
+fn query_interests(
+    deps: Deps,
+    env: Env,
+    corp_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|s| deps.api.addr_validate(s))
+        .transpose()?;
+    let start_bound = start.as_ref().map(Bound::exclusive);
+
+    let interests: Vec<InterestEntry> = INTERESTS
+        .prefix(corp_id)
+        .range(deps.storage, start_bound, None, cosmwasm_std::Order::Ascending)
+        .filter(|r| {
+            r.as_ref()
+                .map_or(true, |(_, expires_at)| env.block.time < *expires_at)
+        })
+        .take(limit)
+        .map(|r| {
+            r.map(|(addr, expires_at)| InterestEntry {
+                address: addr.to_string(),
+                expires_at,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&InterestsResponse { interests })
+}
+
+fn query_corporations_by_member(
+    deps: Deps,
+    address: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let addr = deps.api.addr_validate(&address)?;
+    let start = start_after.map(Bound::exclusive);
+
+    let corporations: Vec<MemberCorporation> = MEMBER_CORPS
+        .prefix(&addr)
+        .keys(deps.storage, start, None, cosmwasm_std::Order::Ascending)
+        .take(limit)
+        .map(|corp_id| {
+            let corp_id = corp_id?;
+            let role = MEMBERS.load(deps.storage, (corp_id, &addr))?.role;
+            let status = CORPORATIONS.load(deps.storage, corp_id)?.status;
+            Ok(MemberCorporation {
+                corp_id,
+                role,
+                status,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&CorporationsByMemberResponse { corporations })
+}
+
+fn query_member_info(deps: Deps, env: Env, corp_id: u64, address: String) -> StdResult<Binary> {
     let addr = deps.api.addr_validate(&address)?;
     let info = MEMBERS.may_load(deps.storage, (corp_id, &addr))?;
+    let in_good_standing = match &info {
+        Some(member) => {
+            let corp = CORPORATIONS.load(deps.storage, corp_id)?;
+            in_good_standing(&corp, member, env.block.time)
+        }
+        None => false,
+    };
+    let rep = REPUTATION
+        .may_load(deps.storage, (corp_id, &addr))?
+        .unwrap_or_default();
+
+    let contribution = CONTRIBUTIONS
+        .may_load(deps.storage, (corp_id, &addr))?
+        .unwrap_or_default();
 
     to_json_binary(&MemberInfoResponse {
         is_member: info.is_some(),
+        reputation: decayed_reputation(&rep, env.block.time),
+        vote_streak: rep.vote_streak,
+        in_good_standing,
+        contribution,
         info,
     })
 }
 
+fn query_proposal_rules(deps: Deps, corp_id: u64) -> StdResult<Binary> {
+    let corp = CORPORATIONS.load(deps.storage, corp_id)?;
+    to_json_binary(&ProposalRulesResponse {
+        rules: corp.proposal_rules,
+    })
+}
+
+/// Bounded by max_members: only current members hold reputation
+fn query_reputation_leaderboard(
+    deps: Deps,
+    env: Env,
+    corp_id: u64,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let mut entries: Vec<ReputationEntry> = REPUTATION
+        .prefix(corp_id)
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|r| {
+            r.map(|(addr, rep)| ReputationEntry {
+                address: addr.to_string(),
+                reputation: decayed_reputation(&rep, env.block.time),
+                vote_streak: rep.vote_streak,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+    entries.sort_by(|a, b| {
+        b.reputation
+            .cmp(&a.reputation)
+            .then_with(|| a.address.cmp(&b.address))
+    });
+    entries.truncate(limit);
+
+    to_json_binary(&ReputationLeaderboardResponse { entries })
+}
+
 fn query_proposal(deps: Deps, proposal_id: u64) -> StdResult<Binary> {
     let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
     to_json_binary(&ProposalResponse { proposal })
 }
 
+fn query_proposal_by_corp_seq(deps: Deps, corp_id: u64, seq: u64) -> StdResult<Binary> {
+    let proposal_id = CORP_PROPOSAL_SEQS.load(deps.storage, (corp_id, seq))?;
+    query_proposal(deps, proposal_id)
+}
+
 // FIX: M-07 — use CORP_PROPOSALS secondary index instead of full table scan
 fn query_proposals(
     deps: Deps,
@@ -1034,6 +2976,40 @@ fn query_proposals(
     to_json_binary(&ProposalsListResponse { proposals })
 }
 
+fn query_votes(
+    deps: Deps,
+    proposal_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|s| deps.api.addr_validate(s))
+        .transpose()?;
+    let start_bound = start.as_ref().map(Bound::exclusive);
+
+    let votes: Vec<VoteEntry> = VOTES
+        .prefix(proposal_id)
+        .range(
+            deps.storage,
+            start_bound,
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .take(limit)
+        .map(|r| {
+            r.map(|(voter, vote)| VoteEntry {
+                voter: voter.to_string(),
+                vote,
+                weight: 1,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&VotesResponse { votes })
+}
+
 fn query_vote_status(deps: Deps, env: Env, proposal_id: u64) -> StdResult<Binary> {
     let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
     let corp = CORPORATIONS.load(deps.storage, proposal.corp_id)?;
@@ -1058,29 +3034,233 @@ fn query_vote_status(deps: Deps, env: Env, proposal_id: u64) -> StdResult<Binary
     })
 }
 
+fn query_choice_vote_status(deps: Deps, env: Env, proposal_id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    let ProposalType::MultiChoice { options, .. } = proposal.proposal_type else {
+        return Err(cosmwasm_std::StdError::generic_err(
+            ContractError::NotMultiChoice { id: proposal_id }.to_string(),
+        ));
+    };
+    let corp = CORPORATIONS.load(deps.storage, proposal.corp_id)?;
+    let count = count_choice_votes(deps.storage, proposal_id, options.len())?;
+    let snapshot = proposal.member_count_snapshot;
+
+    to_json_binary(&ChoiceVoteStatusResponse {
+        quorum_reached: (count.ballots as u64) * 10000
+            >= (snapshot as u64) * (corp.quorum_bps as u64),
+        ballots: count.ballots,
+        winner: count.winner,
+        options: options
+            .into_iter()
+            .enumerate()
+            .map(|(i, option)| ChoiceTally {
+                option,
+                first_choices: count.first_choices[i],
+                final_round: count.final_round[i],
+            })
+            .collect(),
+        total_members: snapshot,
+        quorum_bps: corp.quorum_bps,
+        voting_ended: env.block.time >= proposal.voting_ends_at,
+    })
+}
+
+fn query_executable_at(deps: Deps, env: Env, proposal_id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    let executable_at = match proposal.status {
+        ProposalStatus::QueuedForExecution => proposal.executable_at,
+        ProposalStatus::Active => {
+            let config = CONFIG.load(deps.storage)?;
+            let delay = execution_delay(&config, &proposal.proposal_type);
+            Some(proposal.voting_ends_at.plus_seconds(delay))
+        }
+        _ => None,
+    };
+
+    to_json_binary(&ExecutableAtResponse {
+        proposal_id,
+        status: proposal.status,
+        executable: executable_at.is_some_and(|t| env.block.time >= t),
+        executable_at,
+    })
+}
+
+fn query_squads(
+    deps: Deps,
+    corp_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let squads: Vec<Squad> = SQUADS
+        .prefix(corp_id)
+        .range(deps.storage, start, None, cosmwasm_std::Order::Ascending)
+        .take(limit)
+        .map(|r| r.map(|(_, v)| v))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&SquadsResponse { squads })
+}
+
+fn query_squad_members(
+    deps: Deps,
+    corp_id: u64,
+    name: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|s| deps.api.addr_validate(s))
+        .transpose()?;
+    let start_bound = start.as_ref().map(Bound::exclusive);
+
+    let members: Vec<String> = SQUAD_MEMBERS
+        .prefix((corp_id, &name))
+        .keys(
+            deps.storage,
+            start_bound,
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .take(limit)
+        .map(|r| r.map(|addr| addr.to_string()))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&SquadMembersResponse { members })
+}
+
+fn query_migration_status(deps: Deps) -> StdResult<Binary> {
+    // Nothing pending unless a chunked run was started
+    let progress = MIGRATION
+        .may_load(deps.storage)?
+        .unwrap_or(MigrationProgress {
+            proposals_done: true,
+            done: true,
+            ..MigrationProgress::default()
+        });
+    to_json_binary(&MigrationStatusResponse {
+        done: progress.done,
+        proposals_done: progress.proposals_done,
+        processed: progress.processed,
+        proposal_cursor: progress.proposal_cursor,
+    })
+}
+
+fn query_member_squad(deps: Deps, corp_id: u64, address: String) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    let squad = MEMBER_SQUADS.may_load(deps.storage, (corp_id, &addr))?;
+    to_json_binary(&MemberSquadResponse { squad })
+}
+
 // ─── Migrate ──────────────────────────────────────────────────────────
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    // FIX: H-02 + M-07 — backfill member_count_snapshot and CORP_PROPOSALS index
-    // For existing proposals, use current corp member_count as best approximation.
-    let all_proposals: Vec<(u64, Proposal)> = PROPOSALS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .collect::<StdResult<Vec<_>>>()?;
+    let progress = match msg.chunk {
+        None => migrate_chunk(deps.storage, MigrationProgress::default(), None)?,
+        Some(ChunkMsg { limit }) => {
+            let progress = MIGRATION
+                .may_load(deps.storage)?
+                .filter(|m| !m.done)
+                .unwrap_or_default();
+            let limit = limit
+                .unwrap_or(DEFAULT_MIGRATION_LIMIT)
+                .min(MAX_MIGRATION_LIMIT) as usize;
+            migrate_chunk(deps.storage, progress, Some(limit))?
+        }
+    };
 
-    for (id, mut proposal) in all_proposals {
-        // Backfill snapshot if zero (i.e., from pre-migration state)
-        if proposal.member_count_snapshot == 0 {
-            if let Ok(corp) = CORPORATIONS.load(deps.storage, proposal.corp_id) {
-                proposal.member_count_snapshot = corp.member_count;
-                PROPOSALS.save(deps.storage, id, &proposal)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("processed", progress.processed.to_string())
+        .add_attribute("done", progress.done.to_string()))
+}
+
+/// Backfill up to `limit` proposals and memberships (everything when None)
+/// after the stored cursors, and save the progress.
+fn migrate_chunk(
+    storage: &mut dyn Storage,
+    mut progress: MigrationProgress,
+    limit: Option<usize>,
+) -> StdResult<MigrationProgress> {
+    let mut budget = limit.unwrap_or(usize::MAX);
+
+    if !progress.proposals_done {
+        // FIX: H-02 + M-07 — backfill member_count_snapshot and CORP_PROPOSALS index
+        // For existing proposals, use current corp member_count as best approximation.
+        let start = progress.proposal_cursor.map(Bound::exclusive);
+        let proposals: Vec<(u64, Proposal)> = PROPOSALS
+            .range(storage, start, None, cosmwasm_std::Order::Ascending)
+            .take(budget)
+            .collect::<StdResult<Vec<_>>>()?;
+        budget -= proposals.len();
+        progress.processed += proposals.len() as u64;
+        // Fewer than asked for means the scan reached the end
+        progress.proposals_done = budget > 0;
+
+        for (id, mut proposal) in proposals {
+            // Backfill snapshot if zero (i.e., from pre-migration state)
+            if proposal.member_count_snapshot == 0 {
+                if let Ok(corp) = CORPORATIONS.load(storage, proposal.corp_id) {
+                    proposal.member_count_snapshot = corp.member_count;
+                    PROPOSALS.save(storage, id, &proposal)?;
+                }
             }
+            // Number proposals created before per-corp sequences, oldest first
+            if proposal.corp_seq == 0 {
+                proposal.corp_seq = next_corp_seq(storage, proposal.corp_id)?;
+                CORP_PROPOSAL_SEQS.save(storage, (proposal.corp_id, proposal.corp_seq), &id)?;
+                PROPOSALS.save(storage, id, &proposal)?;
+            }
+            // Backfill CORP_PROPOSALS index
+            CORP_PROPOSALS.save(storage, (proposal.corp_id, id), &())?;
+            progress.proposal_cursor = Some(id);
+        }
+    }
+
+    // Backfill the member -> corporations index
+    if progress.proposals_done && !progress.members_done && budget > 0 {
+        let start = progress
+            .member_cursor
+            .as_ref()
+            .map(|(corp_id, member)| Bound::exclusive((*corp_id, member)));
+        let memberships: Vec<(u64, Addr)> = MEMBERS
+            .keys(storage, start, None, cosmwasm_std::Order::Ascending)
+            .take(budget)
+            .collect::<StdResult<_>>()?;
+        budget -= memberships.len();
+        progress.processed += memberships.len() as u64;
+        progress.members_done = budget > 0;
+
+        for (corp_id, member) in memberships {
+            MEMBER_CORPS.save(storage, (&member, corp_id), &())?;
+            progress.member_cursor = Some((corp_id, member));
+        }
+    }
+
+    // Backfill the corporation directory
+    if progress.members_done && budget > 0 {
+        let start = progress.corp_cursor.map(Bound::exclusive);
+        let corps: Vec<Corporation> = CORPORATIONS
+            .range(storage, start, None, cosmwasm_std::Order::Ascending)
+            .take(budget)
+            .map(|r| r.map(|(_, corp)| corp))
+            .collect::<StdResult<_>>()?;
+        progress.processed += corps.len() as u64;
+        progress.done = corps.len() < budget;
+
+        for corp in corps {
+            index_corporation(storage, &corp)?;
+            progress.corp_cursor = Some(corp.id);
         }
-        // Backfill CORP_PROPOSALS index
-        CORP_PROPOSALS.save(deps.storage, (proposal.corp_id, id), &())?;
     }
 
-    Ok(Response::new().add_attribute("action", "migrate"))
+    MIGRATION.save(storage, &progress)?;
+    Ok(progress)
 }
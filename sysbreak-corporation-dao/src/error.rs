@@ -1,4 +1,5 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Timestamp};
+use sysbreak_common::AdminError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -6,6 +7,9 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    Admin(#[from] AdminError),
+
     #[error("unauthorized: only {role} can perform this action")]
     Unauthorized { role: String },
 
@@ -27,6 +31,12 @@ pub enum ContractError {
     #[error("no pending invite for this address")]
     NoPendingInvite,
 
+    #[error("corporation is open; join it directly")]
+    NotInviteOnly,
+
+    #[error("no open interest from this address")]
+    NoPendingInterest,
+
     #[error("corporation is full (max {max} members)")]
     CorporationFull { max: u32 },
 
@@ -51,8 +61,8 @@ pub enum ContractError {
     #[error("member joined after proposal was created (flash-join protection)")]
     JoinedAfterProposal,
 
-    #[error("treasury spend exceeds 25% of corporation treasury")]
-    SpendExceedsLimit,
+    #[error("treasury spend exceeds {max_spend_bps} bps of corporation treasury")]
+    SpendExceedsLimit { max_spend_bps: u16 },
 
     #[error("dissolution requires 75% supermajority, only got {pct}%")]
     DissolutionSupermajorityNotReached { pct: u64 },
@@ -72,6 +82,12 @@ pub enum ContractError {
     #[error("wrong denomination: expected {expected}, got {got}")]
     WrongDenom { expected: String, got: String },
 
+    #[error("invalid custom denom: {reason}")]
+    InvalidCustomDenom { reason: String },
+
+    #[error("corporation {corp_id} has no treasury in {denom}")]
+    UnsupportedDenom { corp_id: u64, denom: String },
+
     #[error("insufficient funds for creation fee")]
     InsufficientCreationFee,
 
@@ -93,6 +109,33 @@ pub enum ContractError {
     #[error("overflow in arithmetic operation")]
     Overflow,
 
+    #[error("squad not found: {name}")]
+    SquadNotFound { name: String },
+
+    #[error("squad {name} already exists")]
+    SquadExists { name: String },
+
+    #[error("squad name must be 1..={max} characters")]
+    InvalidSquadName { max: usize },
+
+    #[error("corporation already has the maximum of {max} squads")]
+    TooManySquads { max: usize },
+
+    #[error("member already belongs to squad {name}")]
+    AlreadyInSquad { name: String },
+
+    #[error("member is not in a squad")]
+    NotInSquad,
+
+    #[error("member leads squad {name} — disband it first")]
+    SquadLeader { name: String },
+
+    #[error("veto reason must be 1..={max} characters")]
+    InvalidVetoReason { max: usize },
+
+    #[error("proposal {field} must be 1..={max} characters")]
+    InvalidProposalMetadata { field: String, max: usize },
+
     // FIX: H-01 — surplus withdrawal error
     #[error("insufficient surplus: requested {requested}, available {available}")]
     InsufficientSurplus { requested: String, available: String },
@@ -101,16 +144,6 @@ pub enum ContractError {
     #[error("cannot promote a member to Founder role")]
     CannotPromoteToFounder,
 
-    // FIX: H-04 — two-step owner transfer errors
-    #[error("no owner transfer pending")]
-    NoOwnerTransferPending,
-
-    #[error("caller is not the pending owner")]
-    NotPendingOwner,
-
-    #[error("owner transfer already pending")]
-    OwnerTransferAlreadyPending,
-
     // FIX: M-01 — exact payment required
     #[error("overpayment not allowed: expected {expected}, got {got}")]
     OverpaymentNotAllowed { expected: String, got: String },
@@ -122,7 +155,99 @@ pub enum ContractError {
     #[error("invalid voting_period: {value} seconds (must be 3600..=2592000)")]
     InvalidVotingPeriod { value: u64 },
 
-    // FIX: M-08 — reject unexpected funds
-    #[error("unexpected funds sent with this message")]
-    UnexpectedFunds,
+    #[error("invalid max_spend_bps: {value} (must be 1..={max})")]
+    InvalidMaxSpendBps { value: u16, max: u16 },
+
+    #[error("migration in progress; proposals can be created once it completes")]
+    MigrationInProgress,
+
+    #[error("insufficient reputation: {actual} (officers need {required})")]
+    InsufficientReputation { required: u64, actual: u64 },
+
+    #[error("membership too recent to create proposals; eligible at {eligible_at}")]
+    TenureTooShort { eligible_at: u64 },
+
+    #[error("invalid proposal tenure: {value} seconds (max {max})")]
+    InvalidProposalTenure { value: u64, max: u64 },
+
+    #[error("{field} must be an https://, ipfs:// or ar:// URI of at most {max} characters")]
+    InvalidBrandingUri { field: String, max: usize },
+
+    #[error("corporation {corp_id} does not charge dues")]
+    DuesNotEnabled { corp_id: u64 },
+
+    #[error("insufficient dues payment")]
+    InsufficientDues,
+
+    #[error("invalid dues period: {value} seconds (must be {min}..={max})")]
+    InvalidDuesPeriod { value: u64, min: u64, max: u64 },
+
+    #[error("member is suspended for unpaid dues")]
+    MemberSuspended,
+
+    #[error("too many tags (max {max})")]
+    TooManyTags { max: usize },
+
+    #[error("proposal {id} cannot execute before {executable_at}")]
+    ExecutionDelayed { id: u64, executable_at: Timestamp },
+
+    #[error("invalid execution delay: {value} seconds (max {max})")]
+    InvalidExecutionDelay { value: u64, max: u64 },
+
+    #[error("invalid leveling config: {reason}")]
+    InvalidLeveling { reason: String },
+
+    #[error("corporation {corp_id} does not allow leaving with a treasury share")]
+    RageQuitDisabled { corp_id: u64 },
+
+    #[error("cannot leave with a share while proposal {proposal_id}, voted yes on, is still open")]
+    YesVoteInFlight { proposal_id: u64 },
+
+    #[error("invalid haircut: {value} bps (max 10000)")]
+    InvalidHaircutBps { value: u16 },
+
+    #[error("achievement id cannot be empty")]
+    EmptyAchievementId,
+
+    #[error("a hook contract is already set: {address}")]
+    HookAlreadySet { address: String },
+
+    #[error("no hook contract is set")]
+    NoHookContract,
+
+    #[error("a multi-choice proposal needs {min} to {max} options")]
+    InvalidChoiceOptions { min: usize, max: usize },
+
+    #[error("invalid option {option:?}: options are 1..={max} characters, listed once")]
+    InvalidChoiceOption { option: String, max: usize },
+
+    #[error("proposal {id} is multi-choice; vote with VoteChoice")]
+    ChoiceVoteRequired { id: u64 },
+
+    #[error("proposal {id} is not multi-choice")]
+    NotMultiChoice { id: u64 },
+
+    #[error("invalid ranking for proposal {id}: list distinct option indexes")]
+    InvalidRanking { id: u64 },
+
+    #[error("invalid tranches: {reason}")]
+    InvalidTranches { reason: String },
+
+    #[error("proposal {id} is not an executed milestone spend")]
+    NotMilestonePlan { id: u64 },
+
+    #[error("a tranche vote is already open on proposal {id}")]
+    TrancheVoteOpen { id: u64 },
+
+    #[error("no tranche vote is open on proposal {id}")]
+    NoTrancheVote { id: u64 },
+
+    #[error("every tranche of proposal {id} has been released")]
+    AllTranchesReleased { id: u64 },
+
+    #[error("unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("invalid tag {tag:?}: tags are 1..={max} lowercase letters, digits or '-', without duplicates")]
+    InvalidTag { tag: String, max: usize },
 }
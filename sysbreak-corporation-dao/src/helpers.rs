@@ -1,9 +1,10 @@
-use cosmwasm_std::{Addr, Deps, Env, MessageInfo, Uint128};
+use cosmwasm_std::{Addr, Api, Deps, Env, MessageInfo, StdResult, Storage, Timestamp, Uint128};
 
 use crate::error::ContractError;
 use crate::state::{
-    CorporationStatus, Config, Corporation, MemberInfo, MemberRole, Proposal,
-    ProposalStatus, CONFIG, CORPORATIONS, MEMBERS,
+    Branding, Config, Corporation, CorporationStatus, Dues, JoinPolicy, MemberInfo,
+    MemberRole, MemberStanding, Proposal, ProposalMetadata, ProposalRules, ProposalStatus, ProposalType, RageQuit, Reputation, CONFIG,
+    CHOICE_VOTES, CORPORATIONS, CORP_DIRECTORY, CORP_NAME_INDEX, CORP_SIZE_INDEX, Leveling, MAX_LEVELS, MAX_TRANCHES, MEMBERS, REPUTATION, REPUTATION_HALF_LIFE, Tranche,
 };
 
 /// Load config or return StdError
@@ -18,6 +19,75 @@ pub fn load_corporation(deps: Deps, corp_id: u64) -> Result<Corporation, Contrac
         .map_err(|_| ContractError::CorporationNotFound { id: corp_id })
 }
 
+pub fn status_key(status: &CorporationStatus) -> &'static str {
+    match status {
+        CorporationStatus::Active => "active",
+        CorporationStatus::Dissolving => "dissolving",
+        CorporationStatus::Dissolved => "dissolved",
+    }
+}
+
+pub fn join_policy_key(join_policy: &JoinPolicy) -> &'static str {
+    match join_policy {
+        JoinPolicy::Open => "open",
+        JoinPolicy::InviteOnly => "invite_only",
+    }
+}
+
+/// Tail of a CORP_NAME_INDEX key: the lowercased name, then the id to keep
+/// corporations of the same name apart.
+pub fn name_index_key(name: &str, corp_id: u64) -> Vec<u8> {
+    let mut key = name.to_lowercase().into_bytes();
+    key.push(0);
+    key.extend_from_slice(&corp_id.to_be_bytes());
+    key
+}
+
+/// The corporation id at the end of a CORP_NAME_INDEX key tail.
+pub fn name_index_id(key: &[u8]) -> u64 {
+    let mut id = [0u8; 8];
+    id.copy_from_slice(&key[key.len() - 8..]);
+    u64::from_be_bytes(id)
+}
+
+/// Write `corp` into the directory and the name and member count indexes, in
+/// the bucket for its status and join policy.
+pub fn index_corporation(storage: &mut dyn Storage, corp: &Corporation) -> StdResult<()> {
+    let status = status_key(&corp.status);
+    let join_policy = join_policy_key(&corp.join_policy);
+    CORP_DIRECTORY.save(storage, (status, join_policy, corp.id), &())?;
+    CORP_NAME_INDEX.save(
+        storage,
+        (status, join_policy, &name_index_key(&corp.name, corp.id)),
+        &corp.member_count,
+    )?;
+    CORP_SIZE_INDEX.save(
+        storage,
+        (status, join_policy, (corp.member_count, corp.id)),
+        &(),
+    )
+}
+
+fn unindex_corporation(storage: &mut dyn Storage, corp: &Corporation) {
+    let status = status_key(&corp.status);
+    let join_policy = join_policy_key(&corp.join_policy);
+    CORP_DIRECTORY.remove(storage, (status, join_policy, corp.id));
+    CORP_NAME_INDEX.remove(
+        storage,
+        (status, join_policy, &name_index_key(&corp.name, corp.id)),
+    );
+    CORP_SIZE_INDEX.remove(storage, (status, join_policy, (corp.member_count, corp.id)));
+}
+
+/// Save `corp`, moving its directory and index entries to match.
+pub fn save_corporation(storage: &mut dyn Storage, corp: &Corporation) -> StdResult<()> {
+    if let Some(old) = CORPORATIONS.may_load(storage, corp.id)? {
+        unindex_corporation(storage, &old);
+    }
+    index_corporation(storage, corp)?;
+    CORPORATIONS.save(storage, corp.id, corp)
+}
+
 /// Assert the corporation is Active
 pub fn assert_active(corp: &Corporation) -> Result<(), ContractError> {
     match corp.status {
@@ -70,6 +140,78 @@ pub fn assert_officer_or_founder(
     }
 }
 
+/// Whether `member` is paid up. Corporations without dues, and founders,
+/// are always in good standing.
+pub fn in_good_standing(corp: &Corporation, member: &MemberInfo, now: Timestamp) -> bool {
+    corp.dues.is_none()
+        || member.role == MemberRole::Founder
+        || member.dues_paid_until.map_or(true, |until| now < until)
+}
+
+/// Suspensions only bite while the corporation still charges dues
+pub fn assert_not_suspended(corp: &Corporation, member: &MemberInfo) -> Result<(), ContractError> {
+    if corp.dues.is_some() && member.standing == MemberStanding::Suspended {
+        return Err(ContractError::MemberSuspended);
+    }
+    Ok(())
+}
+
+/// Rank used by role requirements: Member < Officer < Founder
+fn role_rank(role: &MemberRole) -> u8 {
+    match role {
+        MemberRole::Member => 0,
+        MemberRole::Officer => 1,
+        MemberRole::Founder => 2,
+    }
+}
+
+/// Assert `member` meets the corporation's proposal rules. The founder is
+/// exempt so a corporation can never lock itself out of governance.
+pub fn assert_can_propose(
+    deps: Deps,
+    env: &Env,
+    corp: &Corporation,
+    sender: &Addr,
+    member: &MemberInfo,
+) -> Result<(), ContractError> {
+    if member.role == MemberRole::Founder {
+        return Ok(());
+    }
+    assert_not_suspended(corp, member)?;
+    let rules = &corp.proposal_rules;
+    if let Some(min_role) = &rules.min_role {
+        if role_rank(&member.role) < role_rank(min_role) {
+            return Err(ContractError::Unauthorized {
+                role: match min_role {
+                    MemberRole::Member => "member",
+                    MemberRole::Officer => "officer or founder",
+                    MemberRole::Founder => "founder",
+                }
+                .to_string(),
+            });
+        }
+    }
+    let eligible_at = member.joined_at.plus_seconds(rules.min_tenure_seconds);
+    if env.block.time < eligible_at {
+        return Err(ContractError::TenureTooShort {
+            eligible_at: eligible_at.seconds(),
+        });
+    }
+    if rules.min_reputation > 0 {
+        let actual = REPUTATION
+            .may_load(deps.storage, (corp.id, sender))?
+            .map(|rep| decayed_reputation(&rep, env.block.time))
+            .unwrap_or(0);
+        if actual < rules.min_reputation {
+            return Err(ContractError::InsufficientReputation {
+                required: rules.min_reputation,
+                actual,
+            });
+        }
+    }
+    Ok(())
+}
+
 /// Validate that exactly one coin of the correct denom and exact amount was sent.
 // FIX: M-01 — reject overpayment (changed from >= to == check)
 pub fn validate_funds(
@@ -130,14 +272,6 @@ pub fn validate_funds_min(
     Ok(coin.amount)
 }
 
-// FIX: M-08 — reject unexpected funds on handlers that should not accept any
-pub fn reject_funds(info: &MessageInfo) -> Result<(), ContractError> {
-    if !info.funds.is_empty() {
-        return Err(ContractError::UnexpectedFunds);
-    }
-    Ok(())
-}
-
 // FIX: M-02 — validate governance parameters
 pub fn validate_quorum_bps(bps: u16) -> Result<(), ContractError> {
     if bps == 0 || bps > 10_000 {
@@ -147,12 +281,425 @@ pub fn validate_quorum_bps(bps: u16) -> Result<(), ContractError> {
 }
 
 pub fn validate_voting_period(seconds: u64) -> Result<(), ContractError> {
-    if seconds < 3600 || seconds > 2_592_000 {
+    if !(3600..=2_592_000).contains(&seconds) {
         return Err(ContractError::InvalidVotingPeriod { value: seconds });
     }
     Ok(())
 }
 
+/// Longest membership a corporation can require before proposing (90 days)
+const MAX_PROPOSAL_MIN_TENURE: u64 = 7_776_000;
+
+pub fn validate_proposal_rules(rules: &ProposalRules) -> Result<(), ContractError> {
+    if rules.min_tenure_seconds > MAX_PROPOSAL_MIN_TENURE {
+        return Err(ContractError::InvalidProposalTenure {
+            value: rules.min_tenure_seconds,
+            max: MAX_PROPOSAL_MIN_TENURE,
+        });
+    }
+    Ok(())
+}
+
+const MIN_DUES_PERIOD: u64 = 86_400;
+const MAX_DUES_PERIOD: u64 = 31_536_000;
+
+/// Dues with a zero amount switch dues off, so only charged dues need a period
+/// between a day and a year
+pub fn validate_dues(dues: &Dues) -> Result<(), ContractError> {
+    if !dues.amount.is_zero() && !(MIN_DUES_PERIOD..=MAX_DUES_PERIOD).contains(&dues.period) {
+        return Err(ContractError::InvalidDuesPeriod {
+            value: dues.period,
+            min: MIN_DUES_PERIOD,
+            max: MAX_DUES_PERIOD,
+        });
+    }
+    Ok(())
+}
+
+pub fn validate_rage_quit(rage_quit: &RageQuit) -> Result<(), ContractError> {
+    if rage_quit.haircut_bps > 10_000 {
+        return Err(ContractError::InvalidHaircutBps {
+            value: rage_quit.haircut_bps,
+        });
+    }
+    Ok(())
+}
+
+const MAX_PROPOSAL_TITLE_LEN: usize = 128;
+const MAX_PROPOSAL_DESCRIPTION_LEN: usize = 4096;
+const MAX_METADATA_URI_LEN: usize = 512;
+
+/// Every metadata field that is set must be non-empty and within its length limit
+pub fn validate_proposal_metadata(metadata: &ProposalMetadata) -> Result<(), ContractError> {
+    let fields = [
+        ("title", &metadata.title, MAX_PROPOSAL_TITLE_LEN),
+        (
+            "description",
+            &metadata.description,
+            MAX_PROPOSAL_DESCRIPTION_LEN,
+        ),
+        ("metadata_uri", &metadata.metadata_uri, MAX_METADATA_URI_LEN),
+    ];
+    for (field, value, max) in fields {
+        if let Some(v) = value {
+            if v.is_empty() || v.len() > max {
+                return Err(ContractError::InvalidProposalMetadata {
+                    field: field.to_string(),
+                    max,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+const MIN_CHOICE_OPTIONS: usize = 2;
+const MAX_CHOICE_OPTIONS: usize = 10;
+const MAX_CHOICE_OPTION_LEN: usize = 64;
+
+/// A MultiChoice title follows the metadata title rules; options are short,
+/// non-empty and listed once each
+pub fn validate_choice_options(title: &str, options: &[String]) -> Result<(), ContractError> {
+    if title.is_empty() || title.len() > MAX_PROPOSAL_TITLE_LEN {
+        return Err(ContractError::InvalidProposalMetadata {
+            field: "title".to_string(),
+            max: MAX_PROPOSAL_TITLE_LEN,
+        });
+    }
+    if !(MIN_CHOICE_OPTIONS..=MAX_CHOICE_OPTIONS).contains(&options.len()) {
+        return Err(ContractError::InvalidChoiceOptions {
+            min: MIN_CHOICE_OPTIONS,
+            max: MAX_CHOICE_OPTIONS,
+        });
+    }
+    for (i, option) in options.iter().enumerate() {
+        if option.is_empty()
+            || option.len() > MAX_CHOICE_OPTION_LEN
+            || options[..i].contains(option)
+        {
+            return Err(ContractError::InvalidChoiceOption {
+                option: option.clone(),
+                max: MAX_CHOICE_OPTION_LEN,
+            });
+        }
+    }
+    Ok(())
+}
+
+const MAX_TRANCHE_DESCRIPTION_LEN: usize = 256;
+
+/// A MilestoneSpend needs 1..=MAX_TRANCHES non-zero tranches, each with a
+/// description of its milestone
+pub fn validate_tranches(tranches: &[Tranche]) -> Result<(), ContractError> {
+    let invalid = |reason: String| ContractError::InvalidTranches { reason };
+    if !(1..=MAX_TRANCHES).contains(&tranches.len()) {
+        return Err(invalid(format!("need 1 to {} tranches", MAX_TRANCHES)));
+    }
+    let mut total = Uint128::zero();
+    for tranche in tranches {
+        if tranche.amount.is_zero() {
+            return Err(invalid("tranche amounts must be non-zero".to_string()));
+        }
+        if tranche.description.is_empty() || tranche.description.len() > MAX_TRANCHE_DESCRIPTION_LEN
+        {
+            return Err(invalid(format!(
+                "descriptions are 1..={} characters",
+                MAX_TRANCHE_DESCRIPTION_LEN
+            )));
+        }
+        total = total
+            .checked_add(tranche.amount)
+            .map_err(|_| ContractError::Overflow)?;
+    }
+    Ok(())
+}
+
+/// Result of an instant-runoff count over MultiChoice ballots
+pub struct ChoiceCount {
+    pub ballots: u32,
+    /// Ballots ranking each option first
+    pub first_choices: Vec<u32>,
+    /// Ballots held by each option in the deciding round; 0 once eliminated
+    pub final_round: Vec<u32>,
+    pub winner: Option<u32>,
+}
+
+/// Instant runoff: the option with the fewest ballots is eliminated and its
+/// ballots move to their next surviving choice, until one option holds a
+/// majority of the unexhausted ballots. Ties favour the earlier-listed option.
+pub fn count_choice_votes(
+    storage: &dyn Storage,
+    proposal_id: u64,
+    options: usize,
+) -> StdResult<ChoiceCount> {
+    let ballots = CHOICE_VOTES
+        .prefix(proposal_id)
+        .range(storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|r| r.map(|(_, ranking)| ranking))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut eliminated = vec![false; options];
+    let round = |eliminated: &[bool]| {
+        let mut counts = vec![0u32; options];
+        for ranking in &ballots {
+            if let Some(&choice) = ranking.iter().find(|&&c| !eliminated[c as usize]) {
+                counts[choice as usize] += 1;
+            }
+        }
+        counts
+    };
+    let first_choices = round(&eliminated);
+    let mut counts = first_choices.clone();
+    let winner = loop {
+        let active: u32 = counts.iter().sum();
+        let remaining: Vec<usize> = (0..options).filter(|&i| !eliminated[i]).collect();
+        // Most ballots wins; on a tie the earlier option comes first
+        let leader = remaining
+            .iter()
+            .copied()
+            .max_by_key(|&i| (counts[i], std::cmp::Reverse(i)));
+        let Some(leader) = leader.filter(|_| active > 0) else {
+            break None;
+        };
+        if counts[leader] * 2 > active || remaining.len() == 1 {
+            break Some(leader as u32);
+        }
+        let last = remaining
+            .iter()
+            .copied()
+            .min_by_key(|&i| (counts[i], std::cmp::Reverse(i)))
+            .unwrap_or(leader);
+        eliminated[last] = true;
+        counts = round(&eliminated);
+    };
+
+    Ok(ChoiceCount {
+        ballots: ballots.len() as u32,
+        first_choices,
+        final_round: counts,
+        winner,
+    })
+}
+
+/// A MultiChoice proposal passes when enough members cast a ballot and the
+/// count produced a winner
+pub fn check_choice_passed(count: &ChoiceCount, total_members: u32, quorum_bps: u16) -> bool {
+    total_members > 0
+        && (count.ballots as u64) * 10000 >= (total_members as u64) * (quorum_bps as u64)
+        && count.winner.is_some()
+}
+
+const MAX_BRANDING_TAGS: usize = 8;
+const MAX_TAG_LEN: usize = 24;
+const BRANDING_URI_SCHEMES: [&str; 3] = ["https://", "ipfs://", "ar://"];
+
+/// URIs need a known scheme, something after it and no whitespace; tags are
+/// short lowercase slugs, listed once each
+pub fn validate_branding(branding: &Branding) -> Result<(), ContractError> {
+    let uris = [
+        ("emblem_uri", &branding.emblem_uri),
+        ("banner_uri", &branding.banner_uri),
+    ];
+    for (field, uri) in uris {
+        if let Some(uri) = uri {
+            let valid = uri.len() <= MAX_METADATA_URI_LEN
+                && !uri.chars().any(|c| c.is_whitespace() || c.is_control())
+                && BRANDING_URI_SCHEMES
+                    .iter()
+                    .any(|scheme| uri.len() > scheme.len() && uri.starts_with(scheme));
+            if !valid {
+                return Err(ContractError::InvalidBrandingUri {
+                    field: field.to_string(),
+                    max: MAX_METADATA_URI_LEN,
+                });
+            }
+        }
+    }
+
+    if branding.tags.len() > MAX_BRANDING_TAGS {
+        return Err(ContractError::TooManyTags {
+            max: MAX_BRANDING_TAGS,
+        });
+    }
+    for (i, tag) in branding.tags.iter().enumerate() {
+        let valid = !tag.is_empty()
+            && tag.len() <= MAX_TAG_LEN
+            && tag
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-');
+        if !valid || branding.tags[..i].contains(tag) {
+            return Err(ContractError::InvalidTag {
+                tag: tag.clone(),
+                max: MAX_TAG_LEN,
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn validate_max_spend_bps(bps: u16, max: u16) -> Result<(), ContractError> {
+    if bps == 0 || bps > max {
+        return Err(ContractError::InvalidMaxSpendBps { value: bps, max });
+    }
+    Ok(())
+}
+
+/// The corporation's `max_spend_bps`, capped by the global ceiling. A ceiling
+/// lowered by the owner applies at once, not just to later ChangeSettings.
+pub fn spend_limit_bps(config: &Config, corp: &Corporation) -> u16 {
+    corp.max_spend_bps.min(config.max_spend_bps)
+}
+
+/// The most a single TreasurySpend proposal may pay out of a treasury of
+/// `balance`. `max_spend_bps` never exceeds 10000, so this cannot overflow.
+pub fn max_spend(config: &Config, corp: &Corporation, balance: Uint128) -> Uint128 {
+    balance.multiply_ratio(spend_limit_bps(config, corp), 10_000u128)
+}
+
+/// A custom treasury denom must be a plausible bank denom (3-128 characters,
+/// starting with a letter; letters, digits and `/:._-`) other than the
+/// config denom
+pub fn validate_custom_denom(
+    api: &dyn Api,
+    config: &Config,
+    denom: &str,
+) -> Result<(), ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidCustomDenom {
+        reason: reason.to_string(),
+    };
+    if denom == config.denom {
+        return Err(invalid("already the config denom"));
+    }
+    // Treasuries only hold bank coins; a CW20 would need a receive hook and
+    // its own transfer messages on every payout path
+    if api.addr_validate(denom).is_ok() {
+        return Err(invalid("CW20 tokens are not supported"));
+    }
+    if !(3..=128).contains(&denom.len()) {
+        return Err(invalid("must be 3-128 characters"));
+    }
+    if !denom.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err(invalid("must start with a letter"));
+    }
+    if !denom
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c))
+    {
+        return Err(invalid("only letters, digits and /:._- are allowed"));
+    }
+    Ok(())
+}
+
+/// Split `total` evenly over `count` members: (each share, remainder)
+pub fn even_split(total: Uint128, count: u32) -> Result<(Uint128, Uint128), ContractError> {
+    let count = Uint128::from(count);
+    let share = total
+        .checked_div(count)
+        .map_err(|_| ContractError::Overflow)?;
+    let remainder = total
+        .checked_rem(count)
+        .map_err(|_| ContractError::Overflow)?;
+    Ok((share, remainder))
+}
+
+pub fn validate_leveling(leveling: &Leveling) -> Result<(), ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidLeveling {
+        reason: reason.to_string(),
+    };
+    if leveling.treasury_unit.is_zero() {
+        return Err(invalid("treasury_unit must be non-zero"));
+    }
+    if leveling.levels.is_empty() || leveling.levels.len() > MAX_LEVELS {
+        return Err(ContractError::InvalidLeveling {
+            reason: format!("must define 1 to {} levels", MAX_LEVELS),
+        });
+    }
+    if leveling
+        .levels
+        .windows(2)
+        .any(|w| w[0].min_xp >= w[1].min_xp)
+    {
+        return Err(invalid("min_xp must be strictly ascending"));
+    }
+    if leveling
+        .levels
+        .iter()
+        .any(|l| l.deposit_discount_bps > 10_000)
+    {
+        return Err(invalid("deposit_discount_bps above 10000"));
+    }
+    Ok(())
+}
+
+/// Where a corporation stands: level 0 has no perks
+pub struct CorpLevel {
+    pub level: u32,
+    pub xp: u64,
+    pub next_level_xp: Option<u64>,
+    pub bonus_members: u32,
+    pub deposit_discount_bps: u16,
+}
+
+pub fn corp_level(config: &Config, corp: &Corporation) -> CorpLevel {
+    let Some(leveling) = &config.leveling else {
+        return CorpLevel {
+            level: 0,
+            xp: 0,
+            next_level_xp: None,
+            bonus_members: 0,
+            deposit_discount_bps: 0,
+        };
+    };
+    let treasury_units = (corp.treasury_balance / leveling.treasury_unit).u128();
+    let xp = leveling
+        .member_xp
+        .saturating_mul(corp.member_count.into())
+        .saturating_add(
+            leveling
+                .treasury_xp
+                .saturating_mul(u64::try_from(treasury_units).unwrap_or(u64::MAX)),
+        )
+        .saturating_add(leveling.proposal_xp.saturating_mul(corp.executed_proposals));
+    let reached = leveling
+        .levels
+        .iter()
+        .take_while(|l| l.min_xp <= xp)
+        .count();
+    let perks = reached.checked_sub(1).map(|i| &leveling.levels[i]);
+    CorpLevel {
+        level: reached as u32,
+        xp,
+        next_level_xp: leveling.levels.get(reached).map(|l| l.min_xp),
+        bonus_members: perks.map_or(0, |p| p.bonus_members),
+        deposit_discount_bps: perks.map_or(0, |p| p.deposit_discount_bps),
+    }
+}
+
+/// Member cap with the corporation's level bonus
+pub fn member_cap(config: &Config, corp: &Corporation) -> u32 {
+    corp.max_members
+        .saturating_add(corp_level(config, corp).bonus_members)
+}
+
+/// Proposal deposit after the corporation's level discount
+pub fn proposal_deposit(config: &Config, corp: &Corporation) -> Uint128 {
+    let discount = corp_level(config, corp).deposit_discount_bps;
+    config
+        .proposal_deposit
+        .multiply_ratio(10_000 - discount as u128, 10_000u128)
+}
+
+/// Seconds a passed proposal of this type waits after voting ends
+pub fn execution_delay(config: &Config, proposal_type: &ProposalType) -> u64 {
+    match proposal_type {
+        ProposalType::TreasurySpend { .. } | ProposalType::MilestoneSpend { .. } => {
+            config.execution_delays.treasury_spend
+        }
+        ProposalType::ChangeSettings { .. } => config.execution_delays.change_settings,
+        _ => 0,
+    }
+}
+
 /// Check that a proposal's voting period has ended
 pub fn assert_voting_ended(proposal: &Proposal, env: &Env) -> Result<(), ContractError> {
     if env.block.time < proposal.voting_ends_at {
@@ -177,16 +724,31 @@ pub fn check_proposal_passed(
     proposal: &Proposal,
     total_members: u32,
     quorum_bps: u16,
+) -> bool {
+    check_votes_passed(
+        proposal.yes_votes,
+        proposal.no_votes,
+        total_members,
+        quorum_bps,
+    )
+}
+
+/// Quorum of `total_members` reached and more yes than no votes
+pub fn check_votes_passed(
+    yes_votes: u32,
+    no_votes: u32,
+    total_members: u32,
+    quorum_bps: u16,
 ) -> bool {
     if total_members == 0 {
         return false;
     }
-    let total_votes = proposal.yes_votes + proposal.no_votes;
+    let total_votes = yes_votes + no_votes;
     // Quorum check: total_votes * 10000 >= total_members * quorum_bps
     let quorum_reached =
         (total_votes as u64) * 10000 >= (total_members as u64) * (quorum_bps as u64);
     // Majority check: yes > no
-    quorum_reached && proposal.yes_votes > proposal.no_votes
+    quorum_reached && yes_votes > no_votes
 }
 
 /// Check dissolution supermajority (75%)
@@ -204,3 +766,16 @@ pub fn check_dissolution_supermajority(
     }
     Ok(())
 }
+
+/// `rep.score` decayed to `now`: halved once per `REPUTATION_HALF_LIFE`, and
+/// linearly between halvings so it falls smoothly rather than in steps.
+pub fn decayed_reputation(rep: &Reputation, now: Timestamp) -> u64 {
+    let elapsed = now.seconds().saturating_sub(rep.updated_at.seconds());
+    let halvings = elapsed / REPUTATION_HALF_LIFE;
+    if halvings >= 64 {
+        return 0;
+    }
+    let base = (rep.score >> halvings) as u128;
+    let into_period = (elapsed % REPUTATION_HALF_LIFE) as u128;
+    (base - base * into_period / (2 * REPUTATION_HALF_LIFE as u128)) as u64
+}
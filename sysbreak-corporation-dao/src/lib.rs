@@ -3,3 +3,6 @@ pub mod error;
 pub mod helpers;
 pub mod msg;
 pub mod state;
+
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -1,7 +1,7 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Timestamp, Uint128};
 
-use crate::state::{JoinPolicy, MemberRole};
+use crate::state::{JoinPolicy, MemberRole, ProposalMetadata};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -14,6 +14,8 @@ pub struct InstantiateMsg {
     pub default_quorum_bps: u16,
     /// Default voting period in seconds
     pub default_voting_period: u64,
+    /// Ceiling for each corporation's per-proposal spend cap in basis points (default 2500)
+    pub max_spend_bps: Option<u16>,
 }
 
 #[cw_serde]
@@ -23,6 +25,12 @@ pub enum ExecuteMsg {
         name: String,
         description: String,
         join_policy: JoinPolicy,
+        /// Optional emblem, banner and tags
+        branding: Option<crate::state::Branding>,
+        /// Native or IBC denom the treasury accepts besides the config denom;
+        /// CW20 tokens are not supported. Cannot be changed later.
+        #[serde(default)]
+        custom_denom: Option<String>,
     },
 
     /// Join an open corporation
@@ -34,16 +42,43 @@ pub enum ExecuteMsg {
     /// Accept a pending invite
     AcceptInvite { corp_id: u64 },
 
+    /// Ask to join an invite-only corporation. Stays open for INTEREST_TTL;
+    /// asking again restarts the clock.
+    ExpressInterest { corp_id: u64 },
+
+    /// Admit a player who expressed interest, in one step (officer or founder only)
+    ApproveInterest { corp_id: u64, applicant: String },
+
     /// Leave a corporation voluntarily
     LeaveCorporation { corp_id: u64 },
 
+    /// Leave and take a pro-rata share of the treasury, less the corporation's
+    /// haircut. Only where enabled, and not while a proposal the member voted
+    /// yes on is still open.
+    LeaveWithShare { corp_id: u64 },
+
     /// Donate native tokens to corporation treasury
     DonateTreasury { corp_id: u64 },
 
+    /// Pay one period of membership dues into the treasury (members only,
+    /// exact amount). Restores good standing.
+    PayDues { corp_id: u64 },
+
+    /// Flag members whose dues have run out as Lapsed, or Suspended if the
+    /// corporation's dues say so (officer or founder only). Checks up to
+    /// `limit` members after `start_after`; the founder is exempt.
+    LapseDelinquentMembers {
+        corp_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
     /// Create a proposal (any member, requires deposit)
     CreateProposal {
         corp_id: u64,
         proposal_type: ProposalTypeMsg,
+        /// Optional title, description and link shown alongside the proposal
+        metadata: Option<ProposalMetadata>,
     },
 
     /// Vote on an active proposal
@@ -52,17 +87,98 @@ pub enum ExecuteMsg {
         vote: bool,
     },
 
+    /// Vote on an active MultiChoice proposal with option indexes, most
+    /// preferred first. A single index is a plain vote; later ones count
+    /// only once the earlier options are eliminated.
+    VoteChoice {
+        proposal_id: u64,
+        ranking: Vec<u32>,
+    },
+
     /// Execute a passed proposal after voting period ends
     ExecuteProposal { proposal_id: u64 },
 
+    /// Open the confirmation vote on the next tranche of an executed
+    /// MilestoneSpend (members or the recipient). It runs for the corporation's
+    /// voting period, capped at one day, and needs no deposit.
+    OpenTrancheVote { proposal_id: u64 },
+
+    /// Vote on the open tranche confirmation
+    VoteTranche { proposal_id: u64, vote: bool },
+
+    /// Close a tranche confirmation once voting ends (anyone). A passed vote
+    /// pays the tranche, within the corporation's spend cap; a failed one, or
+    /// a passed one the cap cannot cover yet ("deferred"), can be opened again.
+    ReleaseTranche { proposal_id: u64 },
+
+    /// Give up a milestone plan's unreleased tranches, e.g. when abandoning
+    /// the project (recipient only). Released tranches stay paid.
+    CancelMilestonePlan { proposal_id: u64 },
+
     /// Claim dissolution share (when corporation is dissolving)
     ClaimDissolution { corp_id: u64 },
 
     /// Founder can update description without a proposal
     UpdateDescription { corp_id: u64, description: String },
 
+    /// Founder can replace the emblem, banner and tags without a proposal
+    UpdateBranding {
+        corp_id: u64,
+        branding: crate::state::Branding,
+    },
+
+    /// Create a squad led by an existing member (officer or founder only)
+    CreateSquad {
+        corp_id: u64,
+        name: String,
+        leader: String,
+    },
+
+    /// Move a member into a squad, or out of their squad with `squad: None`
+    /// (officer or founder only)
+    AssignToSquad {
+        corp_id: u64,
+        member: String,
+        squad: Option<String>,
+    },
+
+    /// Disband a squad, releasing its members (officer or founder only)
+    DisbandSquad {
+        corp_id: u64,
+        name: String,
+    },
+
+    /// Emergency veto of a proposal that is still being voted on (contract
+    /// owner only). The reason is stored on the proposal.
+    VetoProposal {
+        proposal_id: u64,
+        reason: String,
+    },
+
     // FIX: H-01 — withdraw surplus fees/deposits not tracked in any treasury
     WithdrawFees { amount: Uint128 },
+    /// Mint an achievement to the author of each proposal that passes and
+    /// executes; None stops minting (owner only)
+    SetGovernanceBadge {
+        badge: Option<GovernanceBadgeMsg>,
+    },
+    /// Set how long passed TreasurySpend and ChangeSettings proposals wait
+    /// after voting ends before they can execute (owner only)
+    SetExecutionDelays {
+        delays: crate::state::ExecutionDelays,
+    },
+    /// Set the global ceiling on max_spend_bps, checked when ChangeSettings
+    /// proposals execute and capping corporations already above it (owner only)
+    SetMaxSpendBps { max_spend_bps: u16 },
+    /// Set the XP weights and level perks, or None to switch levels off (owner only)
+    SetLeveling {
+        leveling: Option<crate::state::Leveling>,
+    },
+    /// Send a ProposalHookMsg to `address` whenever a proposal is created,
+    /// passes or executes (owner only)
+    AddHook { address: String },
+    /// Stop sending proposal hooks (owner only)
+    RemoveHook {},
 
     // FIX: H-04 — two-step owner transfer
     ProposeOwner { new_owner: String },
@@ -73,18 +189,57 @@ pub enum ExecuteMsg {
 /// Message-level proposal type (uses String for addresses)
 #[cw_serde]
 pub enum ProposalTypeMsg {
-    TreasurySpend { recipient: String, amount: Uint128 },
+    TreasurySpend {
+        recipient: String,
+        amount: Uint128,
+        /// Spend the corporation's custom denom instead of the config denom
+        #[serde(default)]
+        denom: Option<String>,
+    },
     ChangeSettings {
         name: Option<String>,
         description: Option<String>,
         join_policy: Option<JoinPolicy>,
         quorum_bps: Option<u16>,
         voting_period: Option<u64>,
+        max_spend_bps: Option<u16>,
+        min_officer_reputation: Option<u64>,
+        proposal_rules: Option<crate::state::ProposalRules>,
+        /// Replaces the whole branding when set
+        branding: Option<crate::state::Branding>,
+        /// A zero amount stops charging dues
+        dues: Option<crate::state::Dues>,
+        rage_quit: Option<crate::state::RageQuit>,
     },
     KickMember { member: String },
     PromoteMember { member: String, new_role: MemberRole },
     Dissolution,
-    Custom { title: String, description: String },
+    Custom {
+        title: String,
+        description: String,
+    },
+    CreateSquad {
+        name: String,
+        leader: String,
+    },
+    AssignToSquad {
+        member: String,
+        squad: Option<String>,
+    },
+    DisbandSquad {
+        name: String,
+    },
+    /// Choose between 2 to 10 options by ranked-choice vote
+    MultiChoice {
+        title: String,
+        options: Vec<String>,
+    },
+    /// Fund a project in up to 12 tranches, each paid out by a confirmation
+    /// vote once its milestone is met
+    MilestoneSpend {
+        recipient: String,
+        tranches: Vec<crate::state::Tranche>,
+    },
 }
 
 #[cw_serde]
@@ -96,10 +251,14 @@ pub enum QueryMsg {
     #[returns(CorporationResponse)]
     Corporation { corp_id: u64 },
 
+    /// Corporations by id, optionally only those matching every field of
+    /// `filter`. A name prefix lists matches by name, otherwise a member count
+    /// bound lists them by member count; ties go by id.
     #[returns(CorporationsListResponse)]
     ListCorporations {
         start_after: Option<u64>,
         limit: Option<u32>,
+        filter: Option<CorporationFilter>,
     },
 
     #[returns(MembersListResponse)]
@@ -112,9 +271,37 @@ pub enum QueryMsg {
     #[returns(MemberInfoResponse)]
     MemberInfo { corp_id: u64, address: String },
 
+    /// Unexpired interests in joining a corporation, paginated by address
+    #[returns(InterestsResponse)]
+    Interests {
+        corp_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Requirements for creating proposals in a corporation
+    #[returns(ProposalRulesResponse)]
+    ProposalRules { corp_id: u64 },
+
+    /// Members of a corporation ranked by current (decayed) reputation
+    #[returns(ReputationLeaderboardResponse)]
+    ReputationLeaderboard { corp_id: u64, limit: Option<u32> },
+
+    /// Corporations `address` belongs to, paginated by corp_id
+    #[returns(CorporationsByMemberResponse)]
+    CorporationsByMember {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
     #[returns(ProposalResponse)]
     Proposal { proposal_id: u64 },
 
+    /// Look up a proposal by its number within the corporation
+    #[returns(ProposalResponse)]
+    ProposalByCorpSeq { corp_id: u64, seq: u64 },
+
     #[returns(ProposalsListResponse)]
     Proposals {
         corp_id: u64,
@@ -125,19 +312,90 @@ pub enum QueryMsg {
     #[returns(VoteStatusResponse)]
     VoteStatus { proposal_id: u64 },
 
+    /// Tranches, progress and open confirmation vote of an executed MilestoneSpend
+    #[returns(crate::state::MilestonePlan)]
+    MilestonePlan { proposal_id: u64 },
+
+    /// Per-option tallies and the current winner of a MultiChoice proposal
+    #[returns(ChoiceVoteStatusResponse)]
+    ChoiceVoteStatus { proposal_id: u64 },
+
+    /// When a proposal can be executed, accounting for its execution delay
+    #[returns(ExecutableAtResponse)]
+    ExecutableAt { proposal_id: u64 },
+
+    /// Individual votes cast on a proposal, ordered by voter address
+    #[returns(VotesResponse)]
+    Votes {
+        proposal_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    #[returns(SquadsResponse)]
+    Squads {
+        corp_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    #[returns(SquadMembersResponse)]
+    SquadMembers {
+        corp_id: u64,
+        name: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// The squad a member belongs to, if any
+    #[returns(MemberSquadResponse)]
+    MemberSquad { corp_id: u64, address: String },
+
     // FIX: H-04 — query pending owner transfer
     #[returns(Option<crate::state::PendingOwnerTransfer>)]
     PendingOwner {},
+
+    /// Progress of a chunked migration
+    #[returns(MigrationStatusResponse)]
+    MigrationStatus {},
 }
 
 #[cw_serde]
 pub struct CorporationResponse {
     pub corporation: crate::state::Corporation,
+    /// Largest amount a TreasurySpend proposal could pay out right now
+    pub max_spend: Uint128,
+    /// The same in the custom denom, if the corporation has one
+    pub custom_max_spend: Option<Uint128>,
+    pub level: u32,
+    pub xp: u64,
+    /// XP the next level needs; None at the top level or with levels off
+    pub next_level_xp: Option<u64>,
+    /// Member cap including the level bonus
+    pub member_cap: u32,
+    /// Deposit a new proposal costs after the level discount
+    pub proposal_deposit: Uint128,
+}
+
+/// Directory search for ListCorporations; unset fields match everything
+#[cw_serde]
+#[derive(Default)]
+pub struct CorporationFilter {
+    pub status: Option<crate::state::CorporationStatus>,
+    pub join_policy: Option<JoinPolicy>,
+    pub min_members: Option<u32>,
+    pub max_members: Option<u32>,
+    /// Case-insensitive
+    pub name_prefix: Option<String>,
 }
 
 #[cw_serde]
 pub struct CorporationsListResponse {
     pub corporations: Vec<crate::state::Corporation>,
+    /// Where to resume; set when the page is full or a search on both name and
+    /// member count stopped at the scan limit before finding enough matches
+    #[serde(default)]
+    pub next_start_after: Option<u64>,
 }
 
 #[cw_serde]
@@ -145,6 +403,17 @@ pub struct MembersListResponse {
     pub members: Vec<MemberEntry>,
 }
 
+#[cw_serde]
+pub struct InterestsResponse {
+    pub interests: Vec<InterestEntry>,
+}
+
+#[cw_serde]
+pub struct InterestEntry {
+    pub address: String,
+    pub expires_at: Timestamp,
+}
+
 #[cw_serde]
 pub struct MemberEntry {
     pub address: String,
@@ -156,6 +425,42 @@ pub struct MemberEntry {
 pub struct MemberInfoResponse {
     pub is_member: bool,
     pub info: Option<crate::state::MemberInfo>,
+    /// Governance reputation, decayed to the current block time
+    pub reputation: u64,
+    pub vote_streak: u32,
+    /// False once dues have run out; always true without dues and for the founder
+    pub in_good_standing: bool,
+    /// Paid into the treasury through donations and dues, for contribution-based exit shares
+    pub contribution: Uint128,
+}
+
+#[cw_serde]
+pub struct ProposalRulesResponse {
+    pub rules: crate::state::ProposalRules,
+}
+
+#[cw_serde]
+pub struct ReputationLeaderboardResponse {
+    pub entries: Vec<ReputationEntry>,
+}
+
+#[cw_serde]
+pub struct ReputationEntry {
+    pub address: String,
+    pub reputation: u64,
+    pub vote_streak: u32,
+}
+
+#[cw_serde]
+pub struct MemberCorporation {
+    pub corp_id: u64,
+    pub role: MemberRole,
+    pub status: crate::state::CorporationStatus,
+}
+
+#[cw_serde]
+pub struct CorporationsByMemberResponse {
+    pub corporations: Vec<MemberCorporation>,
 }
 
 #[cw_serde]
@@ -163,11 +468,35 @@ pub struct ProposalResponse {
     pub proposal: crate::state::Proposal,
 }
 
+#[cw_serde]
+pub struct ExecutableAtResponse {
+    pub proposal_id: u64,
+    pub status: crate::state::ProposalStatus,
+    /// Stored time for a queued proposal, end of voting plus the type's delay
+    /// while voting is open, None once the proposal is finished
+    pub executable_at: Option<Timestamp>,
+    pub executable: bool,
+}
+
 #[cw_serde]
 pub struct ProposalsListResponse {
     pub proposals: Vec<crate::state::Proposal>,
 }
 
+#[cw_serde]
+pub struct VotesResponse {
+    pub votes: Vec<VoteEntry>,
+}
+
+#[cw_serde]
+pub struct VoteEntry {
+    pub voter: String,
+    /// true = yes, false = no
+    pub vote: bool,
+    /// Each member's vote counts once
+    pub weight: u32,
+}
+
 #[cw_serde]
 pub struct VoteStatusResponse {
     pub yes_votes: u32,
@@ -180,4 +509,114 @@ pub struct VoteStatusResponse {
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct ChoiceVoteStatusResponse {
+    pub options: Vec<ChoiceTally>,
+    pub ballots: u32,
+    pub total_members: u32,
+    pub quorum_bps: u16,
+    pub quorum_reached: bool,
+    /// Instant-runoff winner over the ballots cast so far
+    pub winner: Option<u32>,
+    pub voting_ended: bool,
+}
+
+#[cw_serde]
+pub struct ChoiceTally {
+    pub option: String,
+    /// Ballots ranking this option first
+    pub first_choices: u32,
+    /// Ballots counting for this option in the last runoff round; 0 once eliminated
+    pub final_round: u32,
+}
+
+#[cw_serde]
+pub struct SquadsResponse {
+    pub squads: Vec<crate::state::Squad>,
+}
+
+#[cw_serde]
+pub struct SquadMembersResponse {
+    pub members: Vec<String>,
+}
+
+#[cw_serde]
+pub struct MemberSquadResponse {
+    pub squad: Option<String>,
+}
+
+#[cw_serde]
+pub struct MigrationStatusResponse {
+    /// False while a chunked migration is unfinished
+    pub done: bool,
+    pub proposals_done: bool,
+    pub processed: u64,
+    pub proposal_cursor: Option<u64>,
+}
+
+#[cw_serde]
+pub struct GovernanceBadgeMsg {
+    /// Achievement NFT contract that lists this DAO as an authorized contract
+    pub achievement_contract: String,
+    pub achievement_id: String,
+}
+
+// ─── Messages sent to the achievement NFT contract ───────────────────────────
+
+#[cw_serde]
+pub enum AchievementExecuteMsg {
+    MintFor { mint: AchievementMintRequest },
+}
+
+#[cw_serde]
+pub struct AchievementMintRequest {
+    pub to: String,
+    pub achievement_id: String,
+    pub category: String,
+    pub earned_at: Timestamp,
+    pub description: String,
+    pub rarity: String,
+    pub token_uri: Option<String>,
+    pub soulbound: bool,
+    pub tier: Option<u8>,
+}
+
+// ─── Messages sent to the hook contract ──────────────────────────────────────
+
+/// Sent to Config::hook_contract as proposals move through governance. A
+/// failing hook is reported in the reply and never reverts the governance action.
+#[cw_serde]
+pub enum ProposalHookMsg {
+    ProposalCreatedHook {
+        proposal_id: u64,
+        corp_id: u64,
+        proposer: String,
+    },
+    /// Voting succeeded; `executable_at` is later than now for timelocked proposals
+    ProposalPassedHook {
+        proposal_id: u64,
+        corp_id: u64,
+        executable_at: Timestamp,
+    },
+    ProposalExecutedHook {
+        proposal_id: u64,
+        corp_id: u64,
+        result: String,
+    },
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct MigrateMsg {
+    /// Unset (the plain `{}` payload) upgrades and backfills every proposal,
+    /// membership and directory entry in one transaction
+    #[serde(default)]
+    pub chunk: Option<ChunkMsg>,
+}
+
+/// Upgrade and backfill at most `limit` proposals, memberships and
+/// corporations after where the previous chunk stopped, starting a new
+/// run if the last one finished. Repeat until MigrationStatus reports `done`.
+#[cw_serde]
+pub struct ChunkMsg {
+    pub limit: Option<u32>,
+}
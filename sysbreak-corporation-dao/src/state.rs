@@ -1,12 +1,8 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
-
-// FIX: H-04 — two-step owner transfer state
-#[cw_serde]
-pub struct PendingOwnerTransfer {
-    pub proposed_owner: Addr,
-}
+pub use sysbreak_common::PendingOwnerTransfer;
+use sysbreak_common::{Owned, RoleTransfer};
 
 /// Global contract configuration
 #[cw_serde]
@@ -23,6 +19,87 @@ pub struct Config {
     pub default_quorum_bps: u16,
     /// Default voting period in seconds (3 days = 259200)
     pub default_voting_period: u64,
+    /// Ceiling on each corporation's per-proposal treasury spend, in basis points
+    #[serde(default = "default_max_spend_bps")]
+    pub max_spend_bps: u16,
+    /// Achievement minted to the author of each executed proposal; None disables it
+    #[serde(default)]
+    pub governance_badge: Option<GovernanceBadge>,
+    /// Wait between the end of voting and execution for sensitive proposal types
+    #[serde(default)]
+    pub execution_delays: ExecutionDelays,
+    /// Contract sent a ProposalHookMsg on proposal creation, passage and
+    /// execution; None disables the hooks
+    #[serde(default)]
+    pub hook_contract: Option<Addr>,
+    /// XP weights and level perks; None keeps every corporation at level 0
+    #[serde(default)]
+    pub leveling: Option<Leveling>,
+}
+
+impl Owned for Config {
+    fn owner(&self) -> &Addr {
+        &self.owner
+    }
+
+    fn set_owner(&mut self, owner: Addr) {
+        self.owner = owner;
+    }
+}
+
+/// Achievement the DAO mints through the achievement contract's `MintFor`
+/// when a proposal passes and executes. The DAO must be an authorized
+/// contract there; a failed mint (including a repeat badge) never blocks execution.
+#[cw_serde]
+pub struct GovernanceBadge {
+    pub achievement_contract: Addr,
+    pub achievement_id: String,
+}
+
+/// Seconds a passed proposal waits after voting ends before it can execute,
+/// giving members who voted against it time to leave. 0 executes at once.
+#[cw_serde]
+#[derive(Default)]
+pub struct ExecutionDelays {
+    pub treasury_spend: u64,
+    pub change_settings: u64,
+}
+
+/// How corporations earn XP and what each level unlocks. XP is worked out
+/// from the corporation's current state, so losing members or spending the
+/// treasury can drop a level.
+#[cw_serde]
+pub struct Leveling {
+    /// XP per member
+    pub member_xp: u64,
+    /// XP per whole `treasury_unit` held in the treasury
+    pub treasury_xp: u64,
+    pub treasury_unit: Uint128,
+    /// XP per executed proposal
+    pub proposal_xp: u64,
+    /// `levels[n - 1]` describes level n; `min_xp` strictly ascending
+    pub levels: Vec<LevelPerks>,
+}
+
+/// XP needed for a level and the perks it grants. Perks are not cumulative:
+/// a corporation gets those of the level it is at.
+#[cw_serde]
+pub struct LevelPerks {
+    pub min_xp: u64,
+    /// Added to the corporation's max_members
+    pub bonus_members: u32,
+    /// Taken off the proposal deposit, in basis points
+    pub deposit_discount_bps: u16,
+}
+
+/// Most levels a Leveling config can define
+pub const MAX_LEVELS: usize = 20;
+
+/// Per-proposal spend cap for corporations created before it was configurable
+pub const DEFAULT_MAX_SPEND_BPS: u16 = 2500;
+
+fn default_max_spend_bps() -> u16 {
+    DEFAULT_MAX_SPEND_BPS
 }
 
 /// A corporation (guild)
@@ -35,12 +112,109 @@ pub struct Corporation {
     pub join_policy: JoinPolicy,
     pub quorum_bps: u16,
     pub voting_period: u64,
+    /// Largest share of the treasury a single TreasurySpend may pay out, in basis points
+    #[serde(default = "default_max_spend_bps")]
+    pub max_spend_bps: u16,
     pub max_members: u32,
     pub member_count: u32,
     pub treasury_balance: Uint128,
     pub created_at: Timestamp,
     /// Once set to Dissolving, no new proposals; once Dissolved, nothing works
     pub status: CorporationStatus,
+    /// Incremented on every join, so members can be ordered against proposals
+    #[serde(default)]
+    pub member_epoch: u64,
+    /// Reputation a member needs before they can be promoted to Officer
+    #[serde(default)]
+    pub min_officer_reputation: u64,
+    /// Who may create proposals; the founder is always allowed
+    #[serde(default)]
+    pub proposal_rules: ProposalRules,
+    #[serde(default)]
+    pub branding: Branding,
+    /// Recurring membership fee paid into the treasury; None charges nothing
+    #[serde(default)]
+    pub dues: Option<Dues>,
+    /// Whether and how members may leave with a share of the treasury
+    #[serde(default)]
+    pub rage_quit: RageQuit,
+    /// Donations and dues paid in by current members since contributions
+    /// were tracked; the denominator of contribution-based shares
+    #[serde(default)]
+    pub total_contributions: Uint128,
+    /// Proposals executed since levels were introduced; earns XP
+    #[serde(default)]
+    pub executed_proposals: u64,
+    /// Second denom the treasury accepts, chosen at creation
+    #[serde(default)]
+    pub custom_treasury: Option<CustomTreasury>,
+}
+
+/// A corporation's own treasury denom (a corp-branded or IBC asset), kept
+/// apart from `treasury_balance`, which is always in the config denom
+#[cw_serde]
+pub struct CustomTreasury {
+    pub denom: String,
+    pub balance: Uint128,
+}
+
+/// Terms for `LeaveWithShare`. Off by default, in which case leaving forfeits
+/// everything.
+#[cw_serde]
+#[derive(Default)]
+pub struct RageQuit {
+    pub enabled: bool,
+    pub basis: ShareBasis,
+    /// Part of the share that stays in the treasury, in basis points
+    pub haircut_bps: u16,
+    /// How long a member must have been in before leaving with a share
+    #[serde(default)]
+    pub min_tenure_seconds: u64,
+}
+
+/// How a leaving member's share of the treasury is sized
+#[cw_serde]
+#[derive(Default)]
+pub enum ShareBasis {
+    /// Treasury split evenly across current members
+    #[default]
+    Equal,
+    /// Treasury split by what each current member paid in through
+    /// DonateTreasury and PayDues
+    Contribution,
+}
+
+/// Membership dues: `amount` buys `period` seconds of good standing
+#[cw_serde]
+pub struct Dues {
+    pub amount: Uint128,
+    pub period: u64,
+    /// LapseDelinquentMembers suspends non-payers instead of only flagging them
+    pub suspend_delinquent: bool,
+}
+
+/// Emblem, banner and search tags shown by game clients. Every field is optional.
+#[cw_serde]
+#[derive(Default)]
+pub struct Branding {
+    /// https://, ipfs:// or ar:// link to the corporation emblem
+    pub emblem_uri: Option<String>,
+    pub banner_uri: Option<String>,
+    /// Lowercase tags such as "pvp" or "eu-west"
+    pub tags: Vec<String>,
+}
+
+/// Requirements a member must meet to create a proposal. The defaults let
+/// any member propose.
+#[cw_serde]
+#[derive(Default)]
+pub struct ProposalRules {
+    /// Seconds a member must have belonged to the corporation
+    pub min_tenure_seconds: u64,
+    /// Current (decayed) reputation required
+    pub min_reputation: u64,
+    /// Lowest role allowed to propose; None allows every member
+    pub min_role: Option<MemberRole>,
 }
 
 #[cw_serde]
@@ -69,6 +243,62 @@ pub enum MemberRole {
 pub struct MemberInfo {
     pub role: MemberRole,
     pub joined_at: Timestamp,
+    /// The corporation's `member_epoch` when this member joined; 0 for members
+    /// who joined before epochs were tracked
+    #[serde(default)]
+    pub epoch: u64,
+    /// When this member's paid dues run out; None while the corporation
+    /// charges no dues
+    #[serde(default)]
+    pub dues_paid_until: Option<Timestamp>,
+    #[serde(default)]
+    pub standing: MemberStanding,
+}
+
+/// Set by LapseDelinquentMembers and cleared by paying dues
+#[cw_serde]
+#[derive(Default)]
+pub enum MemberStanding {
+    #[default]
+    Good,
+    /// Behind on dues; informational only
+    Lapsed,
+    /// Behind on dues and barred from voting and proposing while dues are charged
+    Suspended,
+}
+
+/// Governance reputation within one corporation. `score` is as of
+/// `updated_at`; readers apply decay with `helpers::decayed_reputation`.
+#[cw_serde]
+#[derive(Default)]
+pub struct Reputation {
+    pub score: u64,
+    /// Consecutive corporation proposals (by `corp_seq`) voted on
+    pub vote_streak: u32,
+    /// `corp_seq` of the last proposal voted on
+    pub last_voted_seq: u64,
+    pub updated_at: Timestamp,
+}
+
+/// Reputation halves every 30 days without new participation
+pub const REPUTATION_HALF_LIFE: u64 = 2_592_000;
+/// Reputation for casting a vote
+pub const VOTE_REPUTATION: u64 = 10;
+/// Extra reputation per consecutive vote in a streak
+pub const STREAK_BONUS: u64 = 2;
+/// Streak length beyond which the bonus stops growing
+pub const MAX_STREAK_BONUS_STEPS: u32 = 5;
+/// Reputation for authoring a proposal that passes and is executed
+pub const AUTHOR_REPUTATION: u64 = 25;
+
+/// A named group within a corporation, led by one of its members. Each
+/// member belongs to at most one squad.
+#[cw_serde]
+pub struct Squad {
+    pub name: String,
+    pub leader: Addr,
+    pub member_count: u32,
+    pub created_at: Timestamp,
 }
 
 /// Proposal types
@@ -77,6 +307,9 @@ pub enum ProposalType {
     TreasurySpend {
         recipient: Addr,
         amount: Uint128,
+        /// The corporation's custom denom; None spends the config denom
+        #[serde(default)]
+        denom: Option<String>,
     },
     ChangeSettings {
         name: Option<String>,
@@ -84,6 +317,20 @@ pub enum ProposalType {
         join_policy: Option<JoinPolicy>,
         quorum_bps: Option<u16>,
         voting_period: Option<u64>,
+        #[serde(default)]
+        max_spend_bps: Option<u16>,
+        #[serde(default)]
+        min_officer_reputation: Option<u64>,
+        #[serde(default)]
+        proposal_rules: Option<ProposalRules>,
+        /// Replaces the whole branding when set
+        #[serde(default)]
+        branding: Option<Branding>,
+        /// A zero amount stops charging dues
+        #[serde(default)]
+        dues: Option<Dues>,
+        #[serde(default)]
+        rage_quit: Option<RageQuit>,
     },
     KickMember {
         member: Addr,
@@ -97,8 +344,72 @@ pub enum ProposalType {
         title: String,
         description: String,
     },
+    CreateSquad {
+        name: String,
+        leader: Addr,
+    },
+    /// Move a member into `squad`, or out of their squad when None
+    AssignToSquad {
+        member: Addr,
+        squad: Option<String>,
+    },
+    DisbandSquad {
+        name: String,
+    },
+    /// Members rank `options` instead of voting yes/no; the instant-runoff
+    /// winner is recorded when the proposal executes
+    MultiChoice {
+        title: String,
+        options: Vec<String>,
+    },
+    /// Pays `recipient` in installments. Passing approves the plan; each
+    /// tranche is then paid out by its own short confirmation vote.
+    MilestoneSpend {
+        recipient: Addr,
+        tranches: Vec<Tranche>,
+    },
+}
+
+/// One installment of a MilestoneSpend, in the config denom
+#[cw_serde]
+pub struct Tranche {
+    pub amount: Uint128,
+    /// The milestone that earns it
+    pub description: String,
+}
+
+/// An executed MilestoneSpend. Tranches are released in order; the first
+/// `released` have been paid.
+#[cw_serde]
+pub struct MilestonePlan {
+    pub corp_id: u64,
+    pub recipient: Addr,
+    pub tranches: Vec<Tranche>,
+    pub released: u32,
+    /// Confirmation votes opened so far, including failed ones
+    pub rounds: u32,
+    /// The confirmation vote on the next tranche, while one is open
+    pub vote: Option<TrancheVote>,
+}
+
+/// Confirmation vote on the next tranche of a MilestonePlan. It passes on
+/// the corporation's quorum and a simple majority, like a proposal.
+#[cw_serde]
+pub struct TrancheVote {
+    /// Keys the ballots in TRANCHE_VOTES, so a failed tranche can be voted on again
+    pub round: u32,
+    pub yes_votes: u32,
+    pub no_votes: u32,
+    pub member_count_snapshot: u32,
+    pub member_epoch_snapshot: u64,
+    pub voting_ends_at: Timestamp,
 }
 
+/// Most tranches a MilestoneSpend may have
+pub const MAX_TRANCHES: usize = 12;
+/// Longest a tranche confirmation vote runs; a shorter corporation voting period is used instead
+pub const TRANCHE_VOTING_PERIOD: u64 = 86_400;
+
 #[cw_serde]
 pub enum ProposalStatus {
     /// Voting is open
@@ -107,14 +418,30 @@ pub enum ProposalStatus {
     Passed,
     /// Quorum not reached or more "no" than "yes"
     Failed,
+    /// Passed and waiting out its execution delay
+    QueuedForExecution,
     /// Passed and executed
     Executed,
+    /// Stopped by the contract owner during voting; the deposit is refunded
+    Vetoed,
+}
+
+/// Free-form rationale attached to any proposal, for display only
+#[cw_serde]
+pub struct ProposalMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// Link to a longer write-up (forum post, IPFS document)
+    pub metadata_uri: Option<String>,
 }
 
 #[cw_serde]
 pub struct Proposal {
     pub id: u64,
     pub corp_id: u64,
+    /// Position among the corporation's own proposals, starting at 1
+    #[serde(default)]
+    pub corp_seq: u64,
     pub proposer: Addr,
     pub proposal_type: ProposalType,
     pub status: ProposalStatus,
@@ -126,33 +453,139 @@ pub struct Proposal {
     pub deposit: Uint128,
     // FIX: H-02 — snapshot member count at proposal creation for quorum evaluation
     pub member_count_snapshot: u32,
+    /// Corporation `member_epoch` at creation: only members with an epoch at
+    /// or below it may vote. None for proposals created before epochs existed.
+    #[serde(default)]
+    pub member_epoch_snapshot: Option<u64>,
+    /// Why the contract owner vetoed this proposal
+    #[serde(default)]
+    pub veto_reason: Option<String>,
+    #[serde(default)]
+    pub metadata: Option<ProposalMetadata>,
+    /// Earliest execution time, set when the proposal is queued
+    #[serde(default)]
+    pub executable_at: Option<Timestamp>,
+    /// Index of the option that won a MultiChoice proposal, set on execution
+    #[serde(default)]
+    pub winning_option: Option<u32>,
+}
+
+/// Cursor for a migration run over several transactions. Proposals are
+/// backfilled first (in id order, so per-corp numbering stays oldest first),
+/// then the member -> corporations index, then the corporation directory.
+#[cw_serde]
+#[derive(Default)]
+pub struct MigrationProgress {
+    /// Last proposal id backfilled
+    pub proposal_cursor: Option<u64>,
+    pub proposals_done: bool,
+    /// Last (corp_id, member) indexed
+    pub member_cursor: Option<(u64, Addr)>,
+    #[serde(default)]
+    pub members_done: bool,
+    /// Last corporation added to CORP_DIRECTORY and its indexes
+    #[serde(default)]
+    pub corp_cursor: Option<u64>,
+    pub done: bool,
+    /// Proposals, memberships and corporations processed so far in this run
+    pub processed: u64,
 }
 
 pub const CONFIG: Item<Config> = Item::new("dao_config");
+/// Reply id for the governance badge mint submessage
+pub const BADGE_REPLY_ID: u64 = 1;
+/// Gas the badge mint may use, so a misbehaving contract cannot stall execution
+pub const BADGE_GAS_LIMIT: u64 = 500_000;
+/// Reply id for proposal hooks sent to Config::hook_contract
+pub const HOOK_REPLY_ID: u64 = 2;
+/// Gas each proposal hook may use, so a misbehaving contract cannot stall governance
+pub const HOOK_GAS_LIMIT: u64 = 500_000;
 pub const CORP_COUNT: Item<u64> = Item::new("corp_count");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("prop_count");
 
 /// corp_id -> Corporation
 pub const CORPORATIONS: Map<u64, Corporation> = Map::new("corps");
 
+/// (status, join_policy, corp_id) -> () — lets ListCorporations walk one
+/// status and join policy bucket at a time without loading every corporation
+pub const CORP_DIRECTORY: Map<(&str, &str, u64), ()> = Map::new("corp_directory");
+/// (status, join_policy, lowercased name ++ 0x00 ++ big-endian corp_id) ->
+/// member_count. The name is the unprefixed tail of the key, so the names
+/// under a prefix are one contiguous range
+pub const CORP_NAME_INDEX: Map<(&str, &str, &[u8]), u32> = Map::new("corp_name_index");
+/// (status, join_policy, (member_count, corp_id)) -> () — a member count range
+/// is one contiguous range
+pub const CORP_SIZE_INDEX: Map<(&str, &str, (u32, u64)), ()> = Map::new("corp_size_index");
+/// Most name index entries a ListCorporations filtering on both name and member
+/// count reads from one bucket, so a filter that matches nothing cannot exhaust
+/// the query gas limit
+pub const DIRECTORY_SCAN_LIMIT: usize = 200;
+
 /// (corp_id, member_addr) -> MemberInfo
 pub const MEMBERS: Map<(u64, &Addr), MemberInfo> = Map::new("members");
 
+/// (member_addr, corp_id) -> () — reverse of MEMBERS, lists an address's corporations
+pub const MEMBER_CORPS: Map<(&Addr, u64), ()> = Map::new("member_corps");
+
 /// (corp_id, invited_addr) -> bool (pending invites for invite-only corps)
 pub const INVITES: Map<(u64, &Addr), bool> = Map::new("invites");
 
+/// (corp_id, applicant) -> when the interest lapses, for invite-only corps
+pub const INTERESTS: Map<(u64, &Addr), Timestamp> = Map::new("interests");
+/// How long an ExpressInterest stays open for officers to approve (14 days)
+pub const INTEREST_TTL: u64 = 14 * 86_400;
+
 /// proposal_id -> Proposal
 pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
 
 /// (proposal_id, voter_addr) -> bool (vote tracking — true=yes, false=no)
 pub const VOTES: Map<(u64, &Addr), bool> = Map::new("votes");
+/// Ballots on MultiChoice proposals, option indexes most preferred first:
+/// (proposal_id, voter) -> ranking
+pub const CHOICE_VOTES: Map<(u64, &Addr), Vec<u32>> = Map::new("choice_votes");
+
+/// MilestoneSpend proposal_id -> MilestonePlan, saved when the proposal executes
+pub const MILESTONE_PLANS: Map<u64, MilestonePlan> = Map::new("milestone_plans");
+/// Tranche confirmation ballots: (proposal_id, round, voter) -> yes
+pub const TRANCHE_VOTES: Map<(u64, u32, &Addr), bool> = Map::new("tranche_votes");
 
 /// (corp_id, member_addr) -> Uint128 (claimable share during dissolution)
 pub const DISSOLUTION_CLAIMS: Map<(u64, &Addr), Uint128> = Map::new("diss_claims");
+/// (corp_id, member_addr) -> Uint128 (claimable share of the custom treasury)
+pub const CUSTOM_DISSOLUTION_CLAIMS: Map<(u64, &Addr), Uint128> = Map::new("custom_diss_claims");
 
 // FIX: H-04 — pending owner transfer storage
-pub const PENDING_OWNER: Item<PendingOwnerTransfer> = Item::new("pending_owner");
+pub const OWNER_TRANSFER: RoleTransfer<PendingOwnerTransfer> =
+    RoleTransfer::new("owner", "pending_owner");
 
 // FIX: M-07 — secondary index for efficient proposal queries by corporation
 /// (corp_id, proposal_id) -> () — allows prefix scan by corp_id
 pub const CORP_PROPOSALS: Map<(u64, u64), ()> = Map::new("corp_props");
+
+/// corp_id -> number of proposals created in that corporation
+pub const CORP_PROPOSAL_COUNT: Map<u64, u64> = Map::new("corp_prop_count");
+
+/// (corp_id, corp_seq) -> global proposal id
+pub const CORP_PROPOSAL_SEQS: Map<(u64, u64), u64> = Map::new("corp_prop_seqs");
+
+/// (corp_id, squad_name) -> Squad
+pub const SQUADS: Map<(u64, &str), Squad> = Map::new("squads");
+
+/// (corp_id, squad_name, member_addr) -> () — lists a squad's members
+pub const SQUAD_MEMBERS: Map<(u64, &str, &Addr), ()> = Map::new("squad_members");
+
+/// (corp_id, member_addr) -> squad_name
+pub const MEMBER_SQUADS: Map<(u64, &Addr), String> = Map::new("member_squads");
+
+/// (corp_id, member_addr) -> Reputation; removed when the member leaves
+pub const REPUTATION: Map<(u64, &Addr), Reputation> = Map::new("reputation");
+
+/// Progress of the last chunked migration; proposals cannot be created while it is unfinished
+pub const MIGRATION: Item<MigrationProgress> = Item::new("migration");
+
+/// Treasury contributions per current member: (corp_id, member) -> amount
+pub const CONTRIBUTIONS: Map<(u64, &Addr), Uint128> = Map::new("contributions");
+
+/// Proposals a member voted yes on that may still be open: (corp_id, member)
+/// -> proposal ids. Pruned as they finish.
+pub const OPEN_YES_VOTES: Map<(u64, &Addr), Vec<u64>> = Map::new("open_yes_votes");
@@ -0,0 +1,15 @@
+//! cw-multi-test wrapper, for tests that run this contract alongside others.
+
+use cosmwasm_std::Empty;
+use cw_multi_test::{Contract, ContractWrapper};
+
+use crate::contract;
+
+/// The corporation DAO, ready for `App::store_code`.
+pub fn contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new(contract::execute, contract::instantiate, contract::query)
+            .with_reply(contract::reply)
+            .with_migrate(contract::migrate),
+    )
+}
@@ -1,12 +1,14 @@
 use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
-use cosmwasm_std::{coin, from_json, Addr, BankMsg, Timestamp, Uint128};
+use cosmwasm_std::{coin, from_json, Addr, BankMsg, Response, Timestamp, Uint128};
 
-use sysbreak_corporation_dao::contract::{execute, instantiate, query};
+use sysbreak_corporation_dao::contract::{execute, instantiate, migrate, query, reply};
 use sysbreak_corporation_dao::error::ContractError;
 use sysbreak_corporation_dao::msg::*;
 use sysbreak_corporation_dao::state::*;
+use sysbreak_events::ProposalExecutedEvent;
 
 const DENOM: &str = "ushido";
+const GOLD: &str = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
 
 fn setup_deps() -> cosmwasm_std::OwnedDeps<
     cosmwasm_std::MemoryStorage,
@@ -16,6 +18,16 @@ fn setup_deps() -> cosmwasm_std::OwnedDeps<
     mock_dependencies()
 }
 
+/// The `result` attribute of the proposal-executed event
+fn proposal_result(res: &Response) -> String {
+    let event = res
+        .events
+        .iter()
+        .find(|e| e.ty == ProposalExecutedEvent::TYPE)
+        .unwrap();
+    event.attributes[2].value.clone()
+}
+
 fn addr(deps: &cosmwasm_std::OwnedDeps<cosmwasm_std::MemoryStorage, cosmwasm_std::testing::MockApi, cosmwasm_std::testing::MockQuerier>, name: &str) -> Addr {
     deps.api.addr_make(name)
 }
@@ -29,6 +41,7 @@ fn default_instantiate_msg(owner: &Addr) -> InstantiateMsg {
         default_max_members: 50,
         default_quorum_bps: 5100, // 51%
         default_voting_period: 259200, // 3 days
+        max_spend_bps: None,
     }
 }
 
@@ -53,6 +66,8 @@ fn create_corporation(
         name: name.to_string(),
         description: format!("{} description", name),
         join_policy,
+        branding: None,
+        custom_denom: None,
     };
     let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
     // Extract corp_id from attributes
@@ -86,6 +101,7 @@ fn create_proposal(
     let msg = ExecuteMsg::CreateProposal {
         corp_id,
         proposal_type,
+        metadata: None,
     };
     let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
     res.attributes
@@ -138,6 +154,8 @@ fn test_create_corporation_insufficient_fee() {
         name: "TestCorp".to_string(),
         description: "desc".to_string(),
         join_policy: JoinPolicy::Open,
+        branding: None,
+        custom_denom: None,
     };
     let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
     assert_eq!(err, ContractError::InsufficientCreationFee);
@@ -251,6 +269,50 @@ fn test_leave_corporation() {
     assert_eq!(resp.corporation.member_count, 1);
 }
 
+#[test]
+fn test_corporations_by_member() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let member = addr(&deps, "member1");
+    let alpha = create_corporation(&mut deps, &founder, "Alpha", JoinPolicy::Open);
+    let beta = create_corporation(&mut deps, &member, "Beta", JoinPolicy::Open);
+    join_corporation(&mut deps, &member, alpha);
+
+    let corps_of = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, who: &Addr| {
+        let msg = QueryMsg::CorporationsByMember {
+            address: who.to_string(),
+            start_after: None,
+            limit: None,
+        };
+        let resp: CorporationsByMemberResponse =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        resp.corporations
+    };
+    assert_eq!(
+        corps_of(&deps, &member),
+        vec![
+            MemberCorporation {
+                corp_id: alpha,
+                role: MemberRole::Member,
+                status: CorporationStatus::Active,
+            },
+            MemberCorporation {
+                corp_id: beta,
+                role: MemberRole::Founder,
+                status: CorporationStatus::Active,
+            },
+        ]
+    );
+
+    let msg = ExecuteMsg::LeaveCorporation { corp_id: alpha };
+    execute(deps.as_mut(), mock_env(), message_info(&member, &[]), msg).unwrap();
+    let corps = corps_of(&deps, &member);
+    assert_eq!(corps.len(), 1);
+    assert_eq!(corps[0].corp_id, beta);
+}
+
 #[test]
 fn test_founder_cannot_leave_with_members() {
     let mut deps = setup_deps();
@@ -301,6 +363,8 @@ fn test_create_and_vote_proposal() {
             name: "Corp".to_string(),
             description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
         res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
@@ -370,6 +434,8 @@ fn test_flash_join_voting_protection() {
             name: "Corp".to_string(),
             description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
         res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
@@ -421,6 +487,8 @@ fn test_cannot_vote_twice() {
             name: "Corp".to_string(),
             description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
         res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
@@ -456,6 +524,109 @@ fn test_cannot_vote_twice() {
     assert_eq!(err, ContractError::AlreadyVoted { id: proposal_id });
 }
 
+#[test]
+fn test_vote_eligibility_follows_join_order() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let early = addr(&deps, "early");
+    let late = addr(&deps, "late");
+    let env = mock_env();
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    join_corporation(&mut deps, &early, corp_id);
+
+    // Same block as the joins: order decides, not timestamps
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::Custom {
+            title: "Test".to_string(),
+            description: "desc".to_string(),
+        },
+    );
+    join_corporation(&mut deps, &late, corp_id);
+
+    let vote = |deps: &mut cosmwasm_std::OwnedDeps<
+        cosmwasm_std::MemoryStorage,
+        cosmwasm_std::testing::MockApi,
+        cosmwasm_std::testing::MockQuerier,
+    >,
+                voter: &Addr| {
+        let msg = ExecuteMsg::Vote {
+            proposal_id,
+            vote: true,
+        };
+        execute(deps.as_mut(), mock_env(), message_info(voter, &[]), msg)
+    };
+    vote(&mut deps, &founder).unwrap();
+    let err = vote(&mut deps, &late).unwrap_err();
+    assert_eq!(err, ContractError::JoinedAfterProposal);
+
+    // Leaving and rejoining forfeits the vote on proposals already open
+    let msg = ExecuteMsg::LeaveCorporation { corp_id };
+    execute(deps.as_mut(), mock_env(), message_info(&early, &[]), msg).unwrap();
+    join_corporation(&mut deps, &early, corp_id);
+    let err = vote(&mut deps, &early).unwrap_err();
+    assert_eq!(err, ContractError::JoinedAfterProposal);
+}
+
+#[test]
+fn test_list_votes() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let member = addr(&deps, "member1");
+    let mut env = mock_env();
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    join_corporation(&mut deps, &member, corp_id);
+    env.block.time = env.block.time.plus_seconds(10);
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::Custom {
+            title: "Vote".to_string(),
+            description: "desc".to_string(),
+        },
+    );
+    for (voter, vote) in [(&founder, true), (&member, false)] {
+        let msg = ExecuteMsg::Vote { proposal_id, vote };
+        execute(deps.as_mut(), env.clone(), message_info(voter, &[]), msg).unwrap();
+    }
+
+    let votes = |start_after: Option<String>, limit: Option<u32>| {
+        let msg = QueryMsg::Votes {
+            proposal_id,
+            start_after,
+            limit,
+        };
+        let res = query(deps.as_ref(), mock_env(), msg).unwrap();
+        from_json::<VotesResponse>(res).unwrap().votes
+    };
+    let all = votes(None, None);
+    assert_eq!(all.len(), 2);
+    let founder_vote = all.iter().find(|v| v.voter == founder.as_str()).unwrap();
+    assert_eq!(
+        founder_vote,
+        &VoteEntry {
+            voter: founder.to_string(),
+            vote: true,
+            weight: 1,
+        }
+    );
+    assert!(all.iter().any(|v| v.voter == member.as_str() && !v.vote));
+
+    let first = votes(None, Some(1));
+    assert_eq!(first, all[..1]);
+    let rest = votes(Some(first[0].voter.clone()), None);
+    assert_eq!(rest, all[1..]);
+}
+
 #[test]
 fn test_execute_passed_custom_proposal() {
     let mut deps = setup_deps();
@@ -471,6 +642,8 @@ fn test_execute_passed_custom_proposal() {
             name: "Corp".to_string(),
             description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
         res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
@@ -513,7 +686,7 @@ fn test_execute_passed_custom_proposal() {
     let msg = ExecuteMsg::ExecuteProposal { proposal_id };
     let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
-    assert!(res.attributes.iter().any(|a| a.key == "result" && a.value == "custom_passed"));
+    assert_eq!(proposal_result(&res), "custom_passed");
 
     // Check proposal status
     let res = query(deps.as_ref(), env, QueryMsg::Proposal { proposal_id }).unwrap();
@@ -536,6 +709,8 @@ fn test_execute_failed_proposal() {
             name: "Corp".to_string(),
             description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
         res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
@@ -576,85 +751,397 @@ fn test_execute_failed_proposal() {
     let info = message_info(&founder, &[]);
     let msg = ExecuteMsg::ExecuteProposal { proposal_id };
     let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-    assert!(res.attributes.iter().any(|a| a.key == "result" && a.value == "failed"));
+    assert_eq!(proposal_result(&res), "failed");
 }
 
 #[test]
-fn test_treasury_spend_proposal() {
+fn test_governance_badge_minted_on_execution() {
     let mut deps = setup_deps();
-    do_instantiate(&mut deps);
-
+    let owner = do_instantiate(&mut deps);
     let founder = addr(&deps, "founder");
+    let achievements = addr(&deps, "achievements");
+
+    let set_badge = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>,
+                     sender: &Addr,
+                     badge: Option<GovernanceBadgeMsg>| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(sender, &[]),
+            ExecuteMsg::SetGovernanceBadge { badge },
+        )
+    };
+    let badge = GovernanceBadgeMsg {
+        achievement_contract: achievements.to_string(),
+        achievement_id: "governance".to_string(),
+    };
+    let err = set_badge(&mut deps, &founder, Some(badge.clone())).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized { .. }));
+    let err = set_badge(
+        &mut deps,
+        &owner,
+        Some(GovernanceBadgeMsg {
+            achievement_id: String::new(),
+            ..badge.clone()
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::EmptyAchievementId);
+    set_badge(&mut deps, &owner, Some(badge)).unwrap();
+
     let mut env = mock_env();
     env.block.time = Timestamp::from_seconds(1000);
-
     let corp_id = {
         let info = message_info(&founder, &[coin(1000, DENOM)]);
         let msg = ExecuteMsg::CreateCorporation {
             name: "Corp".to_string(),
             description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
         res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
     };
 
-    // Donate to treasury
-    {
-        let info = message_info(&founder, &[coin(10000, DENOM)]);
-        let msg = ExecuteMsg::DonateTreasury { corp_id };
-        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-    }
+    let mut pass_proposal = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, title: &str| {
+        env.block.time = env.block.time.plus_seconds(10);
+        let proposal_id = create_proposal(
+            deps,
+            &env,
+            &founder,
+            corp_id,
+            ProposalTypeMsg::Custom {
+                title: title.to_string(),
+                description: "desc".to_string(),
+            },
+        );
+        let msg = ExecuteMsg::Vote {
+            proposal_id,
+            vote: true,
+        };
+        execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+        env.block.time = env.block.time.plus_seconds(259200 + 1);
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+        let res = execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+        (proposal_id, env.block.time, res)
+    };
 
-    // Add member
-    let member = addr(&deps, "member1");
-    {
-        let info = message_info(&member, &[]);
-        let msg = ExecuteMsg::JoinCorporation { corp_id };
-        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-    }
+    // The author is minted the badge, tolerating a failed mint
+    let (proposal_id, executed_at, res) = pass_proposal(&mut deps, "Alliance");
+    assert_eq!(proposal_result(&res), "custom_passed");
+    let mint = res.messages.iter().find(|m| m.id == BADGE_REPLY_ID).unwrap();
+    assert_eq!(mint.reply_on, cosmwasm_std::ReplyOn::Error);
+    assert_eq!(mint.gas_limit, Some(BADGE_GAS_LIMIT));
+    assert_eq!(
+        mint.msg,
+        cosmwasm_std::CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
+            contract_addr: achievements.to_string(),
+            msg: cosmwasm_std::to_json_binary(&AchievementExecuteMsg::MintFor {
+                mint: AchievementMintRequest {
+                    to: founder.to_string(),
+                    achievement_id: "governance".to_string(),
+                    category: "governance".to_string(),
+                    earned_at: executed_at,
+                    description: format!("Passed proposal #{} in Corp", proposal_id),
+                    rarity: "common".to_string(),
+                    token_uri: None,
+                    soulbound: true,
+                    tier: None,
+                },
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
 
-    let recipient = addr(&deps, "recipient");
+    #[allow(deprecated)]
+    let failed = cosmwasm_std::Reply {
+        id: BADGE_REPLY_ID,
+        payload: cosmwasm_std::Binary::default(),
+        gas_used: 0,
+        result: cosmwasm_std::SubMsgResult::Err("already holds achievement".to_string()),
+    };
+    let res = reply(deps.as_mut(), mock_env(), failed).unwrap();
+    assert_eq!(res.attributes[0].value, "governance_badge_failed");
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Proposal { proposal_id }).unwrap();
+    let resp: ProposalResponse = from_json(res).unwrap();
+    assert_eq!(resp.proposal.status, ProposalStatus::Executed);
+
+    // Cleared: nothing is minted
+    set_badge(&mut deps, &owner, None).unwrap();
+    let (_, _, res) = pass_proposal(&mut deps, "Second");
+    assert!(res.messages.iter().all(|m| m.id != BADGE_REPLY_ID));
+}
+
+#[test]
+fn test_proposal_hooks() {
+    let mut deps = setup_deps();
+    let owner = do_instantiate(&mut deps);
+    let founder = addr(&deps, "founder");
+    let relay = addr(&deps, "relay");
 
+    let admin = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, sender: &Addr, msg: ExecuteMsg| {
+        execute(deps.as_mut(), mock_env(), message_info(sender, &[]), msg)
+    };
+    let add_hook = ExecuteMsg::AddHook {
+        address: relay.to_string(),
+    };
+    let err = admin(&mut deps, &founder, add_hook.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized { .. }));
+    admin(&mut deps, &owner, add_hook.clone()).unwrap();
+    let err = admin(&mut deps, &owner, add_hook).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::HookAlreadySet {
+            address: relay.to_string()
+        }
+    );
+
+    let hooks = |res: &Response| -> Vec<ProposalHookMsg> {
+        res.messages
+            .iter()
+            .filter(|m| m.id == HOOK_REPLY_ID)
+            .map(|m| {
+                assert_eq!(m.reply_on, cosmwasm_std::ReplyOn::Error);
+                assert_eq!(m.gas_limit, Some(HOOK_GAS_LIMIT));
+                match &m.msg {
+                    cosmwasm_std::CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
+                        contract_addr,
+                        msg,
+                        ..
+                    }) => {
+                        assert_eq!(contract_addr, relay.as_str());
+                        from_json(msg).unwrap()
+                    }
+                    other => panic!("unexpected hook message {:?}", other),
+                }
+            })
+            .collect()
+    };
+
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(1000);
+    let corp_id = {
+        let info = message_info(&founder, &[coin(1000, DENOM)]);
+        let msg = ExecuteMsg::CreateCorporation {
+            name: "Corp".to_string(),
+            description: "desc".to_string(),
+            join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
+        };
+        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
+    };
+
+    // Creation, passage and execution each notify the hook contract
     env.block.time = Timestamp::from_seconds(2000);
+    let info = message_info(&founder, &[coin(500, DENOM)]);
+    let msg = ExecuteMsg::CreateProposal {
+        corp_id,
+        proposal_type: ProposalTypeMsg::Custom {
+            title: "Alliance".to_string(),
+            description: "desc".to_string(),
+        },
+        metadata: None,
+    };
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let proposal_id = 1;
+    assert_eq!(
+        hooks(&res),
+        vec![ProposalHookMsg::ProposalCreatedHook {
+            proposal_id,
+            corp_id,
+            proposer: founder.to_string(),
+        }]
+    );
+
+    let msg = ExecuteMsg::Vote {
+        proposal_id,
+        vote: true,
+    };
+    execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    let res = execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+    assert_eq!(
+        hooks(&res),
+        vec![
+            ProposalHookMsg::ProposalPassedHook {
+                proposal_id,
+                corp_id,
+                executable_at: Timestamp::from_seconds(2000 + 259200),
+            },
+            ProposalHookMsg::ProposalExecutedHook {
+                proposal_id,
+                corp_id,
+                result: "custom_passed".to_string(),
+            },
+        ]
+    );
+
+    // A failing hook leaves governance untouched
+    #[allow(deprecated)]
+    let failed = cosmwasm_std::Reply {
+        id: HOOK_REPLY_ID,
+        payload: cosmwasm_std::Binary::default(),
+        gas_used: 0,
+        result: cosmwasm_std::SubMsgResult::Err("relay out of gas".to_string()),
+    };
+    let res = reply(deps.as_mut(), mock_env(), failed).unwrap();
+    assert_eq!(res.attributes[0].value, "proposal_hook_failed");
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Proposal { proposal_id }).unwrap();
+    let resp: ProposalResponse = from_json(res).unwrap();
+    assert_eq!(resp.proposal.status, ProposalStatus::Executed);
+
+    // Removed: nothing is sent
+    let remove = ExecuteMsg::RemoveHook {};
+    admin(&mut deps, &owner, remove.clone()).unwrap();
+    let err = admin(&mut deps, &owner, remove).unwrap_err();
+    assert_eq!(err, ContractError::NoHookContract);
     let proposal_id = create_proposal(
         &mut deps,
         &env,
         &founder,
         corp_id,
-        ProposalTypeMsg::TreasurySpend {
-            recipient: recipient.to_string(),
-            amount: Uint128::new(2500), // exactly 25%
+        ProposalTypeMsg::Custom {
+            title: "Second".to_string(),
+            description: "desc".to_string(),
         },
     );
+    assert_eq!(proposal_id, 2);
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    let config: Config = from_json(res).unwrap();
+    assert_eq!(config.hook_contract, None);
+}
 
-    // Both vote yes
-    for voter in [&founder, &member] {
-        let info = message_info(voter, &[]);
-        let msg = ExecuteMsg::Vote {
-            proposal_id,
-            vote: true,
-        };
-        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+#[test]
+fn test_multi_choice_proposal() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+    let founder = addr(&deps, "founder");
+    let corp_id = create_corporation(&mut deps, &founder, "Raiders", JoinPolicy::Open);
+    let members: Vec<Addr> = (1..=4)
+        .map(|i| addr(&deps, &format!("member{}", i)))
+        .collect();
+    for member in &members {
+        join_corporation(&mut deps, member, corp_id);
     }
+    let env = mock_env();
 
-    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
+    let propose = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, options: &[&str]| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&founder, &[coin(500, DENOM)]),
+            ExecuteMsg::CreateProposal {
+                corp_id,
+                proposal_type: ProposalTypeMsg::MultiChoice {
+                    title: "Raid target".to_string(),
+                    options: options.iter().map(|o| o.to_string()).collect(),
+                },
+                metadata: None,
+            },
+        )
+    };
+    let err = propose(&mut deps, &["Vault"]).unwrap_err();
+    assert_eq!(err, ContractError::InvalidChoiceOptions { min: 2, max: 10 });
+    let err = propose(&mut deps, &["Vault", "Vault"]).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidChoiceOption { .. }));
+    propose(&mut deps, &["Vault", "Foundry", "Spire"]).unwrap();
+    let proposal_id = 1;
+
+    let vote_choice =
+        |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, voter: &Addr, ranking: Vec<u32>| {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(voter, &[]),
+                ExecuteMsg::VoteChoice {
+                    proposal_id,
+                    ranking,
+                },
+            )
+        };
+    for ranking in [vec![], vec![3], vec![1, 1]] {
+        let err = vote_choice(&mut deps, &founder, ranking).unwrap_err();
+        assert_eq!(err, ContractError::InvalidRanking { id: proposal_id });
+    }
+    let msg = ExecuteMsg::Vote {
+        proposal_id,
+        vote: true,
+    };
+    let err = execute(deps.as_mut(), mock_env(), message_info(&founder, &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::ChoiceVoteRequired { id: proposal_id });
+
+    // Vault and Foundry tie on first choices; Spire's voter falls back to Foundry
+    vote_choice(&mut deps, &founder, vec![0]).unwrap();
+    vote_choice(&mut deps, &members[0], vec![0]).unwrap();
+    vote_choice(&mut deps, &members[1], vec![1]).unwrap();
+    vote_choice(&mut deps, &members[2], vec![1, 0]).unwrap();
+    vote_choice(&mut deps, &members[3], vec![2, 1]).unwrap();
+    let err = vote_choice(&mut deps, &members[3], vec![0]).unwrap_err();
+    assert_eq!(err, ContractError::AlreadyVoted { id: proposal_id });
 
-    let info = message_info(&founder, &[]);
-    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
-    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ChoiceVoteStatus { proposal_id },
+    )
+    .unwrap();
+    let status: ChoiceVoteStatusResponse = from_json(res).unwrap();
+    assert_eq!(status.ballots, 5);
+    assert!(status.quorum_reached);
+    assert_eq!(status.winner, Some(1));
+    let tallies: Vec<(u32, u32)> = status
+        .options
+        .iter()
+        .map(|t| (t.first_choices, t.final_round))
+        .collect();
+    assert_eq!(tallies, vec![(2, 2), (2, 3), (1, 0)]);
 
-    // Should have bank messages (deposit refund + treasury spend)
-    assert_eq!(res.messages.len(), 2);
+    // Yes/no proposals take no ballots and report no choice status
+    let custom_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::Custom {
+            title: "Alliance".to_string(),
+            description: "desc".to_string(),
+        },
+    );
+    let msg = ExecuteMsg::VoteChoice {
+        proposal_id: custom_id,
+        ranking: vec![0],
+    };
+    let err = execute(deps.as_mut(), mock_env(), message_info(&founder, &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::NotMultiChoice { id: custom_id });
+    query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ChoiceVoteStatus {
+            proposal_id: custom_id,
+        },
+    )
+    .unwrap_err();
 
-    // Check treasury decreased
-    let res = query(deps.as_ref(), env, QueryMsg::Corporation { corp_id }).unwrap();
-    let resp: CorporationResponse = from_json(res).unwrap();
-    assert_eq!(resp.corporation.treasury_balance, Uint128::new(7500));
+    let mut later = env.clone();
+    later.block.time = later.block.time.plus_seconds(259200 + 1);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    let res = execute(deps.as_mut(), later, message_info(&founder, &[]), msg).unwrap();
+    assert_eq!(proposal_result(&res), "choice_decided");
+    let winner = res.attributes.iter().find(|a| a.key == "winner").unwrap();
+    assert_eq!(winner.value, "Foundry");
+    let res = query(deps.as_ref(), env, QueryMsg::Proposal { proposal_id }).unwrap();
+    let resp: ProposalResponse = from_json(res).unwrap();
+    assert_eq!(resp.proposal.status, ProposalStatus::Executed);
+    assert_eq!(resp.proposal.winning_option, Some(1));
 }
 
 #[test]
-fn test_treasury_spend_exceeds_25_percent() {
+fn test_treasury_spend_proposal() {
     let mut deps = setup_deps();
     do_instantiate(&mut deps);
 
@@ -668,6 +1155,8 @@ fn test_treasury_spend_exceeds_25_percent() {
             name: "Corp".to_string(),
             description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
         res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
@@ -680,6 +1169,7 @@ fn test_treasury_spend_exceeds_25_percent() {
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
     }
 
+    // Add member
     let member = addr(&deps, "member1");
     {
         let info = message_info(&member, &[]);
@@ -697,10 +1187,12 @@ fn test_treasury_spend_exceeds_25_percent() {
         corp_id,
         ProposalTypeMsg::TreasurySpend {
             recipient: recipient.to_string(),
-            amount: Uint128::new(2501), // over 25%
+            amount: Uint128::new(2500), // exactly 25%
+            denom: None,
         },
     );
 
+    // Both vote yes
     for voter in [&founder, &member] {
         let info = message_info(voter, &[]);
         let msg = ExecuteMsg::Vote {
@@ -714,76 +1206,127 @@ fn test_treasury_spend_exceeds_25_percent() {
 
     let info = message_info(&founder, &[]);
     let msg = ExecuteMsg::ExecuteProposal { proposal_id };
-    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
-    assert_eq!(err, ContractError::SpendExceedsLimit);
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // Should have bank messages (deposit refund + treasury spend)
+    assert_eq!(res.messages.len(), 2);
+
+    // Check treasury decreased
+    let res = query(deps.as_ref(), env, QueryMsg::Corporation { corp_id }).unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.treasury_balance, Uint128::new(7500));
 }
 
 #[test]
-fn test_change_settings_proposal() {
+fn test_treasury_spend_execution_delay() {
     let mut deps = setup_deps();
-    do_instantiate(&mut deps);
-
+    let owner = do_instantiate(&mut deps);
     let founder = addr(&deps, "founder");
-    let mut env = mock_env();
-    env.block.time = Timestamp::from_seconds(1000);
+    let member = addr(&deps, "member1");
+    let recipient = addr(&deps, "recipient");
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    join_corporation(&mut deps, &member, corp_id);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[coin(10000, DENOM)]),
+        ExecuteMsg::DonateTreasury { corp_id },
+    )
+    .unwrap();
 
-    let corp_id = {
-        let info = message_info(&founder, &[coin(1000, DENOM)]);
-        let msg = ExecuteMsg::CreateCorporation {
-            name: "Corp".to_string(),
-            description: "desc".to_string(),
-            join_policy: JoinPolicy::Open,
-        };
-        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-        res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
+    let delays = ExecutionDelays {
+        treasury_spend: 86_400,
+        change_settings: 0,
     };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[]),
+        ExecuteMsg::SetExecutionDelays {
+            delays: delays.clone(),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized { .. }));
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        ExecuteMsg::SetExecutionDelays { delays },
+    )
+    .unwrap();
 
-    let member = addr(&deps, "member1");
-    {
-        let info = message_info(&member, &[]);
-        let msg = ExecuteMsg::JoinCorporation { corp_id };
-        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-    }
-
-    env.block.time = Timestamp::from_seconds(2000);
+    let mut env = mock_env();
     let proposal_id = create_proposal(
         &mut deps,
         &env,
         &founder,
         corp_id,
-        ProposalTypeMsg::ChangeSettings {
-            name: Some("NewName".to_string()),
-            description: None,
-            join_policy: Some(JoinPolicy::InviteOnly),
-            quorum_bps: Some(6000),
-            voting_period: None,
+        ProposalTypeMsg::TreasurySpend {
+            recipient: recipient.to_string(),
+            amount: Uint128::new(2000),
+            denom: None,
         },
     );
-
     for voter in [&founder, &member] {
-        let info = message_info(voter, &[]);
         let msg = ExecuteMsg::Vote {
             proposal_id,
             vote: true,
         };
-        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        execute(deps.as_mut(), env.clone(), message_info(voter, &[]), msg).unwrap();
     }
+    let voting_ends_at = Timestamp::from_seconds(env.block.time.seconds() + 259200);
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ExecutableAt { proposal_id },
+    )
+    .unwrap();
+    let resp: ExecutableAtResponse = from_json(res).unwrap();
+    assert_eq!(
+        resp.executable_at,
+        Some(voting_ends_at.plus_seconds(86_400))
+    );
+    assert!(!resp.executable);
 
-    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
-
-    let info = message_info(&founder, &[]);
+    // Once voting ends the spend is queued instead of paid
+    env.block.time = voting_ends_at;
     let msg = ExecuteMsg::ExecuteProposal { proposal_id };
-    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let res = execute(deps.as_mut(), env.clone(), message_info(&member, &[]), msg.clone()).unwrap();
+    assert!(res.messages.is_empty());
+    let res = query(deps.as_ref(), env.clone(), QueryMsg::Proposal { proposal_id }).unwrap();
+    let resp: ProposalResponse = from_json(res).unwrap();
+    assert_eq!(resp.proposal.status, ProposalStatus::QueuedForExecution);
+
+    env.block.time = voting_ends_at.plus_seconds(86_399);
+    let err = execute(deps.as_mut(), env.clone(), message_info(&member, &[]), msg.clone())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ExecutionDelayed {
+            id: proposal_id,
+            executable_at: voting_ends_at.plus_seconds(86_400),
+        }
+    );
 
+    env.block.time = voting_ends_at.plus_seconds(86_400);
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ExecutableAt { proposal_id },
+    )
+    .unwrap();
+    let resp: ExecutableAtResponse = from_json(res).unwrap();
+    assert!(resp.executable);
+    let res = execute(deps.as_mut(), env.clone(), message_info(&member, &[]), msg).unwrap();
+    assert_eq!(proposal_result(&res), "treasury_spent");
     let res = query(deps.as_ref(), env, QueryMsg::Corporation { corp_id }).unwrap();
     let resp: CorporationResponse = from_json(res).unwrap();
-    assert_eq!(resp.corporation.name, "NewName");
-    assert_eq!(resp.corporation.join_policy, JoinPolicy::InviteOnly);
-    assert_eq!(resp.corporation.quorum_bps, 6000);
+    assert_eq!(resp.corporation.treasury_balance, Uint128::new(8000));
 }
 
 #[test]
-fn test_kick_member_proposal() {
+fn test_treasury_spend_exceeds_25_percent() {
     let mut deps = setup_deps();
     do_instantiate(&mut deps);
 
@@ -797,33 +1340,39 @@ fn test_kick_member_proposal() {
             name: "Corp".to_string(),
             description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
         res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
     };
 
-    let member = addr(&deps, "member1");
+    // Donate to treasury
     {
-        let info = message_info(&member, &[]);
-        let msg = ExecuteMsg::JoinCorporation { corp_id };
+        let info = message_info(&founder, &[coin(10000, DENOM)]);
+        let msg = ExecuteMsg::DonateTreasury { corp_id };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
     }
 
-    let bad_member = addr(&deps, "badmember");
+    let member = addr(&deps, "member1");
     {
-        let info = message_info(&bad_member, &[]);
+        let info = message_info(&member, &[]);
         let msg = ExecuteMsg::JoinCorporation { corp_id };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
     }
 
+    let recipient = addr(&deps, "recipient");
+
     env.block.time = Timestamp::from_seconds(2000);
     let proposal_id = create_proposal(
         &mut deps,
         &env,
         &founder,
         corp_id,
-        ProposalTypeMsg::KickMember {
-            member: bad_member.to_string(),
+        ProposalTypeMsg::TreasurySpend {
+            recipient: recipient.to_string(),
+            amount: Uint128::new(2501), // over 25%
+            denom: None,
         },
     );
 
@@ -840,138 +1389,429 @@ fn test_kick_member_proposal() {
 
     let info = message_info(&founder, &[]);
     let msg = ExecuteMsg::ExecuteProposal { proposal_id };
-    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SpendExceedsLimit {
+            max_spend_bps: 2500
+        }
+    );
+}
 
-    // Verify kicked
+#[test]
+fn test_configurable_spend_cap() {
+    let mut deps = setup_deps();
+    let owner = deps.api.addr_make("owner");
+    let msg = InstantiateMsg {
+        max_spend_bps: Some(4000),
+        ..default_instantiate_msg(&owner)
+    };
+    instantiate(deps.as_mut(), mock_env(), message_info(&owner, &[]), msg).unwrap();
+
+    let founder = addr(&deps, "founder");
+    let member = addr(&deps, "member1");
+    let recipient = addr(&deps, "recipient");
+    let mut env = mock_env();
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    join_corporation(&mut deps, &member, corp_id);
+    let msg = ExecuteMsg::DonateTreasury { corp_id };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&founder, &[coin(10000, DENOM)]),
+        msg,
+    )
+    .unwrap();
+
+    // New corporations start at the old 25% cap
     let res = query(
         deps.as_ref(),
-        env,
-        QueryMsg::MemberInfo {
-            corp_id,
-            address: bad_member.to_string(),
-        },
+        env.clone(),
+        QueryMsg::Corporation { corp_id },
     )
     .unwrap();
-    let resp: MemberInfoResponse = from_json(res).unwrap();
-    assert!(!resp.is_member);
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.max_spend_bps, 2500);
+    assert_eq!(resp.max_spend, Uint128::new(2500));
+
+    let mut pass = |env: &mut cosmwasm_std::Env, proposal_type: ProposalTypeMsg| {
+        env.block.time = env.block.time.plus_seconds(10);
+        let proposal_id = create_proposal(&mut deps, env, &founder, corp_id, proposal_type);
+        for voter in [&founder, &member] {
+            let msg = ExecuteMsg::Vote {
+                proposal_id,
+                vote: true,
+            };
+            execute(deps.as_mut(), env.clone(), message_info(voter, &[]), msg).unwrap();
+        }
+        env.block.time = env.block.time.plus_seconds(259200 + 1);
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+        execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg)
+    };
+    let change_cap = |bps: u16| ProposalTypeMsg::ChangeSettings {
+        name: None,
+        description: None,
+        join_policy: None,
+        quorum_bps: None,
+        voting_period: None,
+        max_spend_bps: Some(bps),
+        min_officer_reputation: None,
+        proposal_rules: None,
+        branding: None,
+        dues: None,
+        rage_quit: None,
+    };
+
+    // Cannot go above the global ceiling
+    let err = pass(&mut env, change_cap(5000)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidMaxSpendBps {
+            value: 5000,
+            max: 4000
+        }
+    );
+
+    pass(&mut env, change_cap(4000)).unwrap();
+    let spend = ProposalTypeMsg::TreasurySpend {
+        recipient: recipient.to_string(),
+        amount: Uint128::new(4000),
+        denom: None,
+    };
+    let res = pass(&mut env, spend).unwrap();
+    assert_eq!(proposal_result(&res), "treasury_spent");
+
+    let res = query(deps.as_ref(), env, QueryMsg::Corporation { corp_id }).unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.max_spend_bps, 4000);
+    assert_eq!(resp.corporation.treasury_balance, Uint128::new(6000));
+    assert_eq!(resp.max_spend, Uint128::new(2400));
 }
 
 #[test]
-fn test_promote_member_proposal() {
+fn test_set_max_spend_bps() {
     let mut deps = setup_deps();
-    do_instantiate(&mut deps);
-
+    let owner = do_instantiate(&mut deps);
     let founder = addr(&deps, "founder");
-    let mut env = mock_env();
-    env.block.time = Timestamp::from_seconds(1000);
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
 
-    let corp_id = {
-        let info = message_info(&founder, &[coin(1000, DENOM)]);
-        let msg = ExecuteMsg::CreateCorporation {
-            name: "Corp".to_string(),
-            description: "desc".to_string(),
-            join_policy: JoinPolicy::Open,
-        };
-        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-        res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
+    let set = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, sender: &Addr, max_spend_bps| {
+        let msg = ExecuteMsg::SetMaxSpendBps { max_spend_bps };
+        execute(deps.as_mut(), mock_env(), message_info(sender, &[]), msg)
     };
-
-    let member = addr(&deps, "member1");
-    {
-        let info = message_info(&member, &[]);
-        let msg = ExecuteMsg::JoinCorporation { corp_id };
-        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let err = set(&mut deps, &founder, 5000).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized { .. }));
+    for value in [0, 10_001] {
+        let err = set(&mut deps, &owner, value).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidMaxSpendBps { value, max: 10_000 }
+        );
     }
+    set(&mut deps, &owner, 5000).unwrap();
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    let config: Config = from_json(res).unwrap();
+    assert_eq!(config.max_spend_bps, 5000);
 
-    env.block.time = Timestamp::from_seconds(2000);
+    // Corporations can now vote their cap up to the new ceiling
+    let mut env = mock_env();
     let proposal_id = create_proposal(
         &mut deps,
         &env,
         &founder,
         corp_id,
-        ProposalTypeMsg::PromoteMember {
-            member: member.to_string(),
-            new_role: MemberRole::Officer,
+        ProposalTypeMsg::ChangeSettings {
+            name: None,
+            description: None,
+            join_policy: None,
+            quorum_bps: None,
+            voting_period: None,
+            max_spend_bps: Some(5000),
+            min_officer_reputation: None,
+            proposal_rules: None,
+            branding: None,
+            dues: None,
+            rage_quit: None,
         },
     );
-
-    // Only founder can vote (member joined at same time as corp creation, which is before proposal)
-    let info = message_info(&founder, &[]);
     let msg = ExecuteMsg::Vote {
         proposal_id,
         vote: true,
     };
+    execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+    env.block.time = env.block.time.plus_seconds(259200 + 1);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::Corporation { corp_id },
+    )
+    .unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.max_spend_bps, 5000);
+
+    // Lowering the ceiling caps the corporation without another vote
+    let info = message_info(&founder, &[coin(10000, DENOM)]);
+    let msg = ExecuteMsg::DonateTreasury { corp_id };
     execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    set(&mut deps, &owner, 1000).unwrap();
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::Corporation { corp_id },
+    )
+    .unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.max_spend_bps, 5000);
+    assert_eq!(resp.max_spend, Uint128::new(1000));
 
-    let info = message_info(&member, &[]);
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::TreasurySpend {
+            recipient: founder.to_string(),
+            amount: Uint128::new(2000),
+            denom: None,
+        },
+    );
     let msg = ExecuteMsg::Vote {
         proposal_id,
         vote: true,
     };
-    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-
-    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
-
-    let info = message_info(&founder, &[]);
+    execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+    env.block.time = env.block.time.plus_seconds(259200 + 1);
     let msg = ExecuteMsg::ExecuteProposal { proposal_id };
-    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-
-    let res = query(
-        deps.as_ref(),
-        env,
-        QueryMsg::MemberInfo {
-            corp_id,
-            address: member.to_string(),
-        },
-    )
-    .unwrap();
-    let resp: MemberInfoResponse = from_json(res).unwrap();
-    assert_eq!(resp.info.unwrap().role, MemberRole::Officer);
+    let err = execute(deps.as_mut(), env, message_info(&founder, &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SpendExceedsLimit {
+            max_spend_bps: 1000
+        }
+    );
 }
 
 #[test]
-fn test_dissolution_proposal() {
+fn test_milestone_spend_tranches() {
     let mut deps = setup_deps();
     do_instantiate(&mut deps);
-
     let founder = addr(&deps, "founder");
-    let mut env = mock_env();
-    env.block.time = Timestamp::from_seconds(1000);
+    let member = addr(&deps, "member1");
+    let recipient = addr(&deps, "recipient");
+    let corp_id = create_corporation(&mut deps, &founder, "Builders", JoinPolicy::Open);
+    join_corporation(&mut deps, &member, corp_id);
+    let donate = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, amount: u128| {
+        let info = message_info(&founder, &[coin(amount, DENOM)]);
+        let msg = ExecuteMsg::DonateTreasury { corp_id };
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    };
+    donate(&mut deps, 10_000);
+    let treasury = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| {
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Corporation { corp_id }).unwrap();
+        let resp: CorporationResponse = from_json(res).unwrap();
+        resp.corporation.treasury_balance
+    };
+    let tranche = |amount: u128, description: &str| Tranche {
+        amount: Uint128::new(amount),
+        description: description.to_string(),
+    };
 
-    let corp_id = {
-        let info = message_info(&founder, &[coin(1000, DENOM)]);
-        let msg = ExecuteMsg::CreateCorporation {
-            name: "Corp".to_string(),
-            description: "desc".to_string(),
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[coin(500, DENOM)]),
+        ExecuteMsg::CreateProposal {
+            corp_id,
+            proposal_type: ProposalTypeMsg::MilestoneSpend {
+                recipient: recipient.to_string(),
+                tranches: vec![tranche(0, "design")],
+            },
+            metadata: None,
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidTranches { .. }));
+
+    // 45% of the treasury in total, more than a single spend may take
+    let mut env = mock_env();
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::MilestoneSpend {
+            recipient: recipient.to_string(),
+            tranches: vec![tranche(2_500, "design"), tranche(2_000, "launch")],
+        },
+    );
+    for voter in [&founder, &member] {
+        let msg = ExecuteMsg::Vote {
+            proposal_id,
+            vote: true,
+        };
+        execute(deps.as_mut(), env.clone(), message_info(voter, &[]), msg).unwrap();
+    }
+    env.block.time = env.block.time.plus_seconds(259_200);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    let res = execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+    assert_eq!(proposal_result(&res), "milestones_approved");
+    assert_eq!(res.messages.len(), 1); // deposit refund only
+    assert_eq!(treasury(&deps), Uint128::new(10_000));
+
+    let run = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>,
+               env: &cosmwasm_std::Env,
+               sender: &Addr,
+               msg: ExecuteMsg| {
+        execute(deps.as_mut(), env.clone(), message_info(sender, &[]), msg)
+    };
+    let vote_both = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, env: &cosmwasm_std::Env, vote| {
+        for voter in [&founder, &member] {
+            let msg = ExecuteMsg::VoteTranche { proposal_id, vote };
+            run(deps, env, voter, msg).unwrap();
+        }
+    };
+    let open = ExecuteMsg::OpenTrancheVote { proposal_id };
+    let release = ExecuteMsg::ReleaseTranche { proposal_id };
+
+    let outsider = addr(&deps, "outsider");
+    let err = run(&mut deps, &env, &outsider, open.clone()).unwrap_err();
+    assert_eq!(err, ContractError::NotMember { corp_id });
+    // The recipient may ask for a tranche; the vote is shorter than a proposal's
+    let res = run(&mut deps, &env, &recipient, open.clone()).unwrap();
+    let ends_at = env.block.time.plus_seconds(TRANCHE_VOTING_PERIOD);
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "voting_ends_at" && a.value == ends_at.seconds().to_string()));
+    let err = run(&mut deps, &env, &member, open.clone()).unwrap_err();
+    assert_eq!(err, ContractError::TrancheVoteOpen { id: proposal_id });
+
+    // Rejected: nothing paid, and the milestone can be voted on again
+    vote_both(&mut deps, &env, false);
+    let err = run(&mut deps, &env, &founder, release.clone()).unwrap_err();
+    assert_eq!(err, ContractError::VotingNotEnded { id: proposal_id });
+    env.block.time = ends_at;
+    let res = run(&mut deps, &env, &outsider, release.clone()).unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(treasury(&deps), Uint128::new(10_000));
+
+    run(&mut deps, &env, &member, open.clone()).unwrap();
+    vote_both(&mut deps, &env, true);
+    env.block.time = env.block.time.plus_seconds(TRANCHE_VOTING_PERIOD);
+    let res = run(&mut deps, &env, &outsider, release.clone()).unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        cosmwasm_std::CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![coin(2_500, DENOM)],
+        })
+    );
+    assert_eq!(treasury(&deps), Uint128::new(7_500));
+
+    // Each tranche still respects the spend cap: 2000 > 25% of 7500. The
+    // ballot closes unpaid and the tranche goes back to a vote
+    run(&mut deps, &env, &member, open.clone()).unwrap();
+    vote_both(&mut deps, &env, true);
+    env.block.time = env.block.time.plus_seconds(TRANCHE_VOTING_PERIOD);
+    let res = run(&mut deps, &env, &outsider, release.clone()).unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "result" && a.value == "deferred"));
+    assert!(res.messages.is_empty());
+    assert_eq!(treasury(&deps), Uint128::new(7_500));
+    donate(&mut deps, 1_000);
+    run(&mut deps, &env, &member, open.clone()).unwrap();
+    vote_both(&mut deps, &env, true);
+    env.block.time = env.block.time.plus_seconds(TRANCHE_VOTING_PERIOD);
+    run(&mut deps, &env, &outsider, release).unwrap();
+    assert_eq!(treasury(&deps), Uint128::new(6_500));
+
+    let err = run(&mut deps, &env, &recipient, open.clone()).unwrap_err();
+    assert_eq!(err, ContractError::AllTranchesReleased { id: proposal_id });
+    let plan_query = QueryMsg::MilestonePlan { proposal_id };
+    let res = query(deps.as_ref(), env.clone(), plan_query).unwrap();
+    let plan: MilestonePlan = from_json(res).unwrap();
+    assert_eq!(plan.released, 2);
+    assert_eq!(plan.rounds, 4);
+    assert!(plan.vote.is_none());
+
+    // Only the recipient can give up the rest of a plan, not even the founder
+    let cancel = ExecuteMsg::CancelMilestonePlan { proposal_id };
+    for sender in [&founder, &member] {
+        let err = run(&mut deps, &env, sender, cancel.clone()).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Unauthorized {
+                role: "recipient".to_string()
+            }
+        );
+    }
+    run(&mut deps, &env, &recipient, cancel).unwrap();
+    let err = run(&mut deps, &env, &recipient, open).unwrap_err();
+    assert_eq!(err, ContractError::NotMilestonePlan { id: proposal_id });
+}
+
+#[test]
+fn test_change_settings_proposal() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(1000);
+
+    let corp_id = {
+        let info = message_info(&founder, &[coin(1000, DENOM)]);
+        let msg = ExecuteMsg::CreateCorporation {
+            name: "Corp".to_string(),
+            description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
         res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
     };
 
-    // Donate treasury
+    let member = addr(&deps, "member1");
     {
-        let info = message_info(&founder, &[coin(10000, DENOM)]);
-        let msg = ExecuteMsg::DonateTreasury { corp_id };
+        let info = message_info(&member, &[]);
+        let msg = ExecuteMsg::JoinCorporation { corp_id };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
     }
 
-    // Need 75% supermajority — with 1 member, founder's vote = 100%
     env.block.time = Timestamp::from_seconds(2000);
     let proposal_id = create_proposal(
         &mut deps,
         &env,
         &founder,
         corp_id,
-        ProposalTypeMsg::Dissolution,
+        ProposalTypeMsg::ChangeSettings {
+            name: Some("NewName".to_string()),
+            description: None,
+            join_policy: Some(JoinPolicy::InviteOnly),
+            quorum_bps: Some(6000),
+            voting_period: None,
+            max_spend_bps: None,
+            min_officer_reputation: None,
+            proposal_rules: None,
+            branding: None,
+            dues: None,
+            rage_quit: None,
+        },
     );
 
-    let info = message_info(&founder, &[]);
-    let msg = ExecuteMsg::Vote {
-        proposal_id,
-        vote: true,
-    };
-    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    for voter in [&founder, &member] {
+        let info = message_info(voter, &[]);
+        let msg = ExecuteMsg::Vote {
+            proposal_id,
+            vote: true,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    }
 
     env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
 
@@ -979,34 +1819,15 @@ fn test_dissolution_proposal() {
     let msg = ExecuteMsg::ExecuteProposal { proposal_id };
     execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
-    // Corp should be dissolving
-    let res = query(deps.as_ref(), env.clone(), QueryMsg::Corporation { corp_id }).unwrap();
-    let resp: CorporationResponse = from_json(res).unwrap();
-    assert_eq!(resp.corporation.status, CorporationStatus::Dissolving);
-
-    // Claim dissolution share
-    let info = message_info(&founder, &[]);
-    let msg = ExecuteMsg::ClaimDissolution { corp_id };
-    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-
-    // Should have bank send message with share
-    assert_eq!(res.messages.len(), 1);
-    let bank_msg = &res.messages[0].msg;
-    match bank_msg {
-        cosmwasm_std::CosmosMsg::Bank(BankMsg::Send { amount, .. }) => {
-            assert_eq!(amount[0].amount, Uint128::new(10000));
-        }
-        _ => panic!("Expected BankMsg::Send"),
-    }
-
-    // Corp should be dissolved (last member claimed)
     let res = query(deps.as_ref(), env, QueryMsg::Corporation { corp_id }).unwrap();
     let resp: CorporationResponse = from_json(res).unwrap();
-    assert_eq!(resp.corporation.status, CorporationStatus::Dissolved);
+    assert_eq!(resp.corporation.name, "NewName");
+    assert_eq!(resp.corporation.join_policy, JoinPolicy::InviteOnly);
+    assert_eq!(resp.corporation.quorum_bps, 6000);
 }
 
 #[test]
-fn test_dissolution_requires_supermajority() {
+fn test_kick_member_proposal() {
     let mut deps = setup_deps();
     do_instantiate(&mut deps);
 
@@ -1020,18 +1841,23 @@ fn test_dissolution_requires_supermajority() {
             name: "Corp".to_string(),
             description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
         res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
     };
 
-    // Add 3 more members (total 4) — need 3 yes votes for 75%
-    let m1 = addr(&deps, "m1");
-    let m2 = addr(&deps, "m2");
-    let m3 = addr(&deps, "m3");
+    let member = addr(&deps, "member1");
+    {
+        let info = message_info(&member, &[]);
+        let msg = ExecuteMsg::JoinCorporation { corp_id };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    }
 
-    for m in [&m1, &m2, &m3] {
-        let info = message_info(m, &[]);
+    let bad_member = addr(&deps, "badmember");
+    {
+        let info = message_info(&bad_member, &[]);
         let msg = ExecuteMsg::JoinCorporation { corp_id };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
     }
@@ -1042,11 +1868,12 @@ fn test_dissolution_requires_supermajority() {
         &env,
         &founder,
         corp_id,
-        ProposalTypeMsg::Dissolution,
+        ProposalTypeMsg::KickMember {
+            member: bad_member.to_string(),
+        },
     );
 
-    // Only 2 out of 4 vote yes (50%, need 75%)
-    for voter in [&founder, &m1] {
+    for voter in [&founder, &member] {
         let info = message_info(voter, &[]);
         let msg = ExecuteMsg::Vote {
             proposal_id,
@@ -1054,28 +1881,29 @@ fn test_dissolution_requires_supermajority() {
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
     }
-    for voter in [&m2, &m3] {
-        let info = message_info(voter, &[]);
-        let msg = ExecuteMsg::Vote {
-            proposal_id,
-            vote: false,
-        };
-        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-    }
 
     env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
 
     let info = message_info(&founder, &[]);
     let msg = ExecuteMsg::ExecuteProposal { proposal_id };
-    // This should fail because even though quorum (51%) is met, dissolution needs 75% supermajority
-    // But first the general pass check happens: 2 yes vs 2 no => not passed (yes must be > no)
-    // So it fails as "failed" proposal
-    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-    assert!(res.attributes.iter().any(|a| a.key == "result" && a.value == "failed"));
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // Verify kicked
+    let res = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::MemberInfo {
+            corp_id,
+            address: bad_member.to_string(),
+        },
+    )
+    .unwrap();
+    let resp: MemberInfoResponse = from_json(res).unwrap();
+    assert!(!resp.is_member);
 }
 
 #[test]
-fn test_voting_not_ended() {
+fn test_promote_member_proposal() {
     let mut deps = setup_deps();
     do_instantiate(&mut deps);
 
@@ -1089,341 +1917,2451 @@ fn test_voting_not_ended() {
             name: "Corp".to_string(),
             description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
         };
         let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
         res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
     };
 
+    let member = addr(&deps, "member1");
+    {
+        let info = message_info(&member, &[]);
+        let msg = ExecuteMsg::JoinCorporation { corp_id };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    }
+
     env.block.time = Timestamp::from_seconds(2000);
     let proposal_id = create_proposal(
         &mut deps,
         &env,
         &founder,
         corp_id,
-        ProposalTypeMsg::Custom {
-            title: "Test".to_string(),
-            description: "desc".to_string(),
+        ProposalTypeMsg::PromoteMember {
+            member: member.to_string(),
+            new_role: MemberRole::Officer,
         },
     );
 
-    // Try to execute before voting ends
-    let info = message_info(&founder, &[]);
-    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
-    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
-    assert_eq!(err, ContractError::VotingNotEnded { id: proposal_id });
-}
-
-#[test]
-fn test_update_description_founder_only() {
-    let mut deps = setup_deps();
-    do_instantiate(&mut deps);
-
-    let founder = addr(&deps, "founder");
-    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
-
-    let member = addr(&deps, "member1");
-    join_corporation(&mut deps, &member, corp_id);
-
-    // Founder updates description
+    // Only founder can vote (member joined at same time as corp creation, which is before proposal)
     let info = message_info(&founder, &[]);
-    let msg = ExecuteMsg::UpdateDescription {
-        corp_id,
-        description: "Updated description".to_string(),
+    let msg = ExecuteMsg::Vote {
+        proposal_id,
+        vote: true,
     };
-    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
-
-    let res = query(deps.as_ref(), mock_env(), QueryMsg::Corporation { corp_id }).unwrap();
-    let resp: CorporationResponse = from_json(res).unwrap();
-    assert_eq!(resp.corporation.description, "Updated description");
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
-    // Member cannot update
     let info = message_info(&member, &[]);
-    let msg = ExecuteMsg::UpdateDescription {
-        corp_id,
-        description: "Hacked!".to_string(),
+    let msg = ExecuteMsg::Vote {
+        proposal_id,
+        vote: true,
     };
-    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
-    assert_eq!(
-        err,
-        ContractError::Unauthorized {
-            role: "founder".to_string()
-        }
-    );
-}
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
-#[test]
-fn test_list_corporations() {
-    let mut deps = setup_deps();
-    do_instantiate(&mut deps);
+    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
 
-    let founder = addr(&deps, "founder");
-    create_corporation(&mut deps, &founder, "Corp1", JoinPolicy::Open);
-    create_corporation(&mut deps, &founder, "Corp2", JoinPolicy::InviteOnly);
-    create_corporation(&mut deps, &founder, "Corp3", JoinPolicy::Open);
+    let info = message_info(&founder, &[]);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
     let res = query(
         deps.as_ref(),
-        mock_env(),
-        QueryMsg::ListCorporations {
-            start_after: None,
-            limit: Some(2),
+        env,
+        QueryMsg::MemberInfo {
+            corp_id,
+            address: member.to_string(),
         },
     )
     .unwrap();
-    let resp: CorporationsListResponse = from_json(res).unwrap();
-    assert_eq!(resp.corporations.len(), 2);
-    assert_eq!(resp.corporations[0].name, "Corp1");
-    assert_eq!(resp.corporations[1].name, "Corp2");
+    let resp: MemberInfoResponse = from_json(res).unwrap();
+    assert_eq!(resp.info.unwrap().role, MemberRole::Officer);
+}
 
-    // Pagination
-    let res = query(
-        deps.as_ref(),
-        mock_env(),
-        QueryMsg::ListCorporations {
-            start_after: Some(2),
-            limit: None,
-        },
+#[test]
+fn test_reputation_and_officer_gate() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let alice = addr(&deps, "alice");
+    let bob = addr(&deps, "bob");
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(1000);
+    let corp_id = {
+        let info = message_info(&founder, &[coin(1000, DENOM)]);
+        let msg = ExecuteMsg::CreateCorporation {
+            name: "Corp".to_string(),
+            description: "desc".to_string(),
+            join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
+        };
+        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        res.attributes
+            .iter()
+            .find(|a| a.key == "corp_id")
+            .unwrap()
+            .value
+            .parse::<u64>()
+            .unwrap()
+    };
+    for member in [&alice, &bob] {
+        let msg = ExecuteMsg::JoinCorporation { corp_id };
+        execute(deps.as_mut(), env.clone(), message_info(member, &[]), msg).unwrap();
+    }
+
+    let vote = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>,
+                env: &cosmwasm_std::Env,
+                voter: &Addr,
+                proposal_id: u64| {
+        let msg = ExecuteMsg::Vote {
+            proposal_id,
+            vote: true,
+        };
+        execute(deps.as_mut(), env.clone(), message_info(voter, &[]), msg)
+            .unwrap()
+            .attributes
+            .iter()
+            .find(|a| a.key == "reputation")
+            .unwrap()
+            .value
+            .parse::<u64>()
+            .unwrap()
+    };
+    let member_rep =
+        |deps: &cosmwasm_std::OwnedDeps<_, _, _>, env: &cosmwasm_std::Env, member: &Addr| {
+            let res = query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::MemberInfo {
+                    corp_id,
+                    address: member.to_string(),
+                },
+            )
+            .unwrap();
+            let resp: MemberInfoResponse = from_json(res).unwrap();
+            (resp.reputation, resp.vote_streak)
+        };
+    let voting_period = 259_200;
+
+    // Proposal 1: require 20 reputation for officers. Bob sits it out.
+    env.block.time = Timestamp::from_seconds(2000);
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::ChangeSettings {
+            name: None,
+            description: None,
+            join_policy: None,
+            quorum_bps: None,
+            voting_period: None,
+            max_spend_bps: None,
+            min_officer_reputation: Some(20),
+            proposal_rules: None,
+            branding: None,
+            dues: None,
+            rage_quit: None,
+        },
+    );
+    assert_eq!(
+        vote(&mut deps, &env, &founder, proposal_id),
+        VOTE_REPUTATION
+    );
+    assert_eq!(vote(&mut deps, &env, &alice, proposal_id), VOTE_REPUTATION);
+    env.block.time = env.block.time.plus_seconds(voting_period + 1);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+    // The author is credited for the executed proposal
+    assert_eq!(
+        member_rep(&deps, &env, &founder),
+        (VOTE_REPUTATION + AUTHOR_REPUTATION, 1)
+    );
+
+    // Proposal 2: promote Bob, who has no reputation. Voting again extends streaks.
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::PromoteMember {
+            member: bob.to_string(),
+            new_role: MemberRole::Officer,
+        },
+    );
+    assert_eq!(
+        vote(&mut deps, &env, &founder, proposal_id),
+        VOTE_REPUTATION + AUTHOR_REPUTATION + VOTE_REPUTATION + STREAK_BONUS
+    );
+    vote(&mut deps, &env, &alice, proposal_id);
+    assert_eq!(member_rep(&deps, &env, &alice), (22, 2));
+    env.block.time = env.block.time.plus_seconds(voting_period + 1);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    let err = execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientReputation {
+            required: 20,
+            actual: 0
+        }
+    );
+
+    // Proposal 3: promote Alice. Bob's first vote starts a fresh streak.
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::PromoteMember {
+            member: alice.to_string(),
+            new_role: MemberRole::Officer,
+        },
+    );
+    vote(&mut deps, &env, &founder, proposal_id);
+    vote(&mut deps, &env, &alice, proposal_id);
+    assert_eq!(vote(&mut deps, &env, &bob, proposal_id), VOTE_REPUTATION);
+    assert_eq!(member_rep(&deps, &env, &alice).1, 3);
+    assert_eq!(member_rep(&deps, &env, &bob), (VOTE_REPUTATION, 1));
+    let voted_at = env.block.time;
+    env.block.time = env.block.time.plus_seconds(voting_period + 1);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::MemberInfo {
+            corp_id,
+            address: alice.to_string(),
+        },
+    )
+    .unwrap();
+    let resp: MemberInfoResponse = from_json(res).unwrap();
+    assert_eq!(resp.info.unwrap().role, MemberRole::Officer);
+
+    // Ranked by reputation, highest first
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ReputationLeaderboard {
+            corp_id,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let resp: ReputationLeaderboardResponse = from_json(res).unwrap();
+    let ranked: Vec<_> = resp.entries.iter().map(|e| e.address.clone()).collect();
+    assert_eq!(
+        ranked,
+        vec![founder.to_string(), alice.to_string(), bob.to_string()]
+    );
+
+    // Reputation halves over each half-life without participation
+    env.block.time = voted_at.plus_seconds(REPUTATION_HALF_LIFE);
+    assert_eq!(member_rep(&deps, &env, &bob), (VOTE_REPUTATION / 2, 1));
+    env.block.time = voted_at.plus_seconds(2 * REPUTATION_HALF_LIFE);
+    assert_eq!(member_rep(&deps, &env, &bob), (VOTE_REPUTATION / 4, 1));
+
+    // Leaving forfeits reputation
+    let msg = ExecuteMsg::LeaveCorporation { corp_id };
+    execute(deps.as_mut(), env.clone(), message_info(&bob, &[]), msg).unwrap();
+    assert_eq!(member_rep(&deps, &env, &bob), (0, 0));
+}
+
+#[test]
+fn test_proposal_eligibility_rules() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let alice = addr(&deps, "alice");
+    let carol = addr(&deps, "carol");
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(1000);
+    let corp_id = {
+        let info = message_info(&founder, &[coin(1000, DENOM)]);
+        let msg = ExecuteMsg::CreateCorporation {
+            name: "Corp".to_string(),
+            description: "desc".to_string(),
+            join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
+        };
+        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        res.attributes
+            .iter()
+            .find(|a| a.key == "corp_id")
+            .unwrap()
+            .value
+            .parse::<u64>()
+            .unwrap()
+    };
+    let msg = ExecuteMsg::JoinCorporation { corp_id };
+    execute(deps.as_mut(), env.clone(), message_info(&alice, &[]), msg).unwrap();
+
+    // Any member may propose by default
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ProposalRules { corp_id },
+    )
+    .unwrap();
+    let resp: ProposalRulesResponse = from_json(res).unwrap();
+    assert_eq!(resp.rules, ProposalRules::default());
+
+    let pass_rules = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>,
+                      env: &mut cosmwasm_std::Env,
+                      rules: ProposalRules| {
+        let proposal_id = create_proposal(
+            deps,
+            env,
+            &founder,
+            corp_id,
+            ProposalTypeMsg::ChangeSettings {
+                name: None,
+                description: None,
+                join_policy: None,
+                quorum_bps: None,
+                voting_period: None,
+                max_spend_bps: None,
+                min_officer_reputation: None,
+                proposal_rules: Some(rules),
+                branding: None,
+                dues: None,
+                rage_quit: None,
+            },
+        );
+        for voter in [&founder, &alice] {
+            let msg = ExecuteMsg::Vote {
+                proposal_id,
+                vote: true,
+            };
+            execute(deps.as_mut(), env.clone(), message_info(voter, &[]), msg).unwrap();
+        }
+        env.block.time = env.block.time.plus_seconds(259_200 + 1);
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+        execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg)
+    };
+
+    // Tenure is capped at 90 days
+    env.block.time = Timestamp::from_seconds(2000);
+    let err = pass_rules(
+        &mut deps,
+        &mut env,
+        ProposalRules {
+            min_tenure_seconds: 7_776_001,
+            ..ProposalRules::default()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidProposalTenure {
+            value: 7_776_001,
+            max: 7_776_000
+        }
+    );
+
+    let rules = ProposalRules {
+        min_tenure_seconds: 86_400,
+        min_reputation: 0,
+        min_role: None,
+    };
+    pass_rules(&mut deps, &mut env, rules.clone()).unwrap();
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ProposalRules { corp_id },
+    )
+    .unwrap();
+    let resp: ProposalRulesResponse = from_json(res).unwrap();
+    assert_eq!(resp.rules, rules);
+
+    // A new member must wait out the tenure before proposing
+    let joined_at = env.block.time;
+    let msg = ExecuteMsg::JoinCorporation { corp_id };
+    execute(deps.as_mut(), env.clone(), message_info(&carol, &[]), msg).unwrap();
+    let custom = || ProposalTypeMsg::Custom {
+        title: "Raid".to_string(),
+        description: "Friday".to_string(),
+    };
+    let msg = ExecuteMsg::CreateProposal {
+        corp_id,
+        proposal_type: custom(),
+        metadata: None,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&carol, &[coin(500, DENOM)]),
+        msg,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TenureTooShort {
+            eligible_at: joined_at.seconds() + 86_400
+        }
+    );
+    env.block.time = joined_at.plus_seconds(86_400);
+    create_proposal(&mut deps, &env, &carol, corp_id, custom());
+
+    // Officers only, with some reputation: Alice is still a Member
+    pass_rules(
+        &mut deps,
+        &mut env,
+        ProposalRules {
+            min_tenure_seconds: 0,
+            min_reputation: 15,
+            min_role: Some(MemberRole::Officer),
+        },
+    )
+    .unwrap();
+    let msg = ExecuteMsg::CreateProposal {
+        corp_id,
+        proposal_type: custom(),
+        metadata: None,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&alice, &[coin(500, DENOM)]),
+        msg,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "officer or founder".to_string()
+        }
+    );
+
+    // The founder is exempt from every rule
+    create_proposal(&mut deps, &env, &founder, corp_id, custom());
+}
+
+#[test]
+fn test_membership_dues() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let alice = addr(&deps, "alice");
+    let bob = addr(&deps, "bob");
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    join_corporation(&mut deps, &alice, corp_id);
+
+    let mut env = mock_env();
+    let pay = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>,
+               env: &cosmwasm_std::Env,
+               who: &Addr,
+               amount: u128| {
+        let info = message_info(who, &[coin(amount, DENOM)]);
+        execute(deps.as_mut(), env.clone(), info, ExecuteMsg::PayDues { corp_id })
+    };
+    let standing = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, env: &cosmwasm_std::Env, who: &Addr| {
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::MemberInfo {
+                corp_id,
+                address: who.to_string(),
+            },
+        )
+        .unwrap();
+        let resp: MemberInfoResponse = from_json(res).unwrap();
+        (resp.in_good_standing, resp.info.unwrap().standing)
+    };
+
+    let err = pay(&mut deps, &env, &alice, 100).unwrap_err();
+    assert_eq!(err, ContractError::DuesNotEnabled { corp_id });
+
+    // Weekly dues of 100, suspending non-payers
+    let dues = Dues {
+        amount: Uint128::new(100),
+        period: 604_800,
+        suspend_delinquent: true,
+    };
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::ChangeSettings {
+            name: None,
+            description: None,
+            join_policy: None,
+            quorum_bps: None,
+            voting_period: None,
+            max_spend_bps: None,
+            min_officer_reputation: None,
+            proposal_rules: None,
+            branding: None,
+            dues: Some(dues.clone()),
+            rage_quit: None,
+        },
+    );
+    for voter in [&founder, &alice] {
+        let msg = ExecuteMsg::Vote {
+            proposal_id,
+            vote: true,
+        };
+        execute(deps.as_mut(), env.clone(), message_info(voter, &[]), msg).unwrap();
+    }
+    env.block.time = env.block.time.plus_seconds(259_200 + 1);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+    let enabled_at = env.block.time;
+
+    // Everyone starts with one period paid; late joiners get theirs from joining
+    join_corporation(&mut deps, &bob, corp_id);
+    assert_eq!(standing(&deps, &env, &alice), (true, MemberStanding::Good));
+
+    let err = pay(&mut deps, &env, &bob, 50).unwrap_err();
+    assert_eq!(err, ContractError::InsufficientDues);
+    pay(&mut deps, &env, &bob, 100).unwrap();
+    let res = query(deps.as_ref(), env.clone(), QueryMsg::Corporation { corp_id }).unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.treasury_balance, Uint128::new(100));
+
+    // A week later alice has run out; bob paid ahead and the founder is exempt
+    env.block.time = enabled_at.plus_seconds(604_800);
+    assert_eq!(standing(&deps, &env, &alice), (false, MemberStanding::Good));
+    assert_eq!(standing(&deps, &env, &bob), (true, MemberStanding::Good));
+
+    let lapse = ExecuteMsg::LapseDelinquentMembers {
+        corp_id,
+        start_after: None,
+        limit: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), message_info(&bob, &[]), lapse.clone())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "officer or founder".to_string()
+        }
+    );
+    let res = execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), lapse.clone())
+        .unwrap();
+    let lapsed = res.attributes.iter().find(|a| a.key == "lapsed").unwrap();
+    assert_eq!(lapsed.value, "1");
+    assert_eq!(standing(&deps, &env, &alice), (false, MemberStanding::Suspended));
+    assert_eq!(standing(&deps, &env, &founder), (true, MemberStanding::Good));
+
+    // Suspended members can neither vote nor propose
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::Custom {
+            title: "Raid night".to_string(),
+            description: "Friday".to_string(),
+        },
+    );
+    let vote = ExecuteMsg::Vote {
+        proposal_id,
+        vote: true,
+    };
+    let err = execute(deps.as_mut(), env.clone(), message_info(&alice, &[]), vote.clone())
+        .unwrap_err();
+    assert_eq!(err, ContractError::MemberSuspended);
+    let msg = ExecuteMsg::CreateProposal {
+        corp_id,
+        proposal_type: ProposalTypeMsg::Dissolution,
+        metadata: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), message_info(&alice, &[coin(500, DENOM)]), msg)
+        .unwrap_err();
+    assert_eq!(err, ContractError::MemberSuspended);
+
+    // Paying late restores standing for a full period from now
+    let res = pay(&mut deps, &env, &alice, 100).unwrap();
+    let paid_until = res.attributes.iter().find(|a| a.key == "paid_until").unwrap();
+    assert_eq!(
+        paid_until.value,
+        env.block.time.plus_seconds(604_800).seconds().to_string()
+    );
+    assert_eq!(standing(&deps, &env, &alice), (true, MemberStanding::Good));
+    execute(deps.as_mut(), env.clone(), message_info(&alice, &[]), vote).unwrap();
+}
+
+#[test]
+fn test_leave_with_share() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let backer = addr(&deps, "backer");
+    let lurker = addr(&deps, "lurker");
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(1000);
+
+    let corp_id = {
+        let info = message_info(&founder, &[coin(1000, DENOM)]);
+        let msg = ExecuteMsg::CreateCorporation {
+            name: "Corp".to_string(),
+            description: "desc".to_string(),
+            join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: Some(GOLD.to_string()),
+        };
+        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
+    };
+    for member in [&backer, &lurker] {
+        let info = message_info(member, &[]);
+        execute(deps.as_mut(), env.clone(), info, ExecuteMsg::JoinCorporation { corp_id }).unwrap();
+    }
+    // The sponsor is not a member, so its 3000 is nobody's contribution
+    let sponsor = addr(&deps, "sponsor");
+    for (donor, amount) in [(&founder, 6000), (&backer, 3000), (&sponsor, 3000)] {
+        let info = message_info(donor, &[coin(amount, DENOM)]);
+        execute(deps.as_mut(), env.clone(), info, ExecuteMsg::DonateTreasury { corp_id }).unwrap();
+    }
+    let info = message_info(&sponsor, &[coin(900, GOLD)]);
+    execute(deps.as_mut(), env.clone(), info, ExecuteMsg::DonateTreasury { corp_id }).unwrap();
+
+    // Off by default
+    let leave = ExecuteMsg::LeaveWithShare { corp_id };
+    let err = execute(deps.as_mut(), env.clone(), message_info(&backer, &[]), leave.clone()).unwrap_err();
+    assert_eq!(err, ContractError::RageQuitDisabled { corp_id });
+
+    env.block.time = Timestamp::from_seconds(2000);
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::ChangeSettings {
+            name: None,
+            description: None,
+            join_policy: None,
+            quorum_bps: None,
+            voting_period: None,
+            max_spend_bps: None,
+            min_officer_reputation: None,
+            proposal_rules: None,
+            branding: None,
+            dues: None,
+            rage_quit: Some(RageQuit {
+                enabled: true,
+                basis: ShareBasis::Contribution,
+                haircut_bps: 1000,
+                min_tenure_seconds: 604_800,
+            }),
+        },
+    );
+    for voter in [&founder, &backer] {
+        let info = message_info(voter, &[]);
+        let msg = ExecuteMsg::Vote { proposal_id, vote: true };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    }
+
+    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
+    let info = message_info(&founder, &[]);
+    execute(deps.as_mut(), env.clone(), info, ExecuteMsg::ExecuteProposal { proposal_id }).unwrap();
+
+    // A yes vote on a proposal still being decided pins the voter in place
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::Custom { title: "Raid".to_string(), description: "desc".to_string() },
+    );
+    let info = message_info(&backer, &[]);
+    execute(deps.as_mut(), env.clone(), info, ExecuteMsg::Vote { proposal_id, vote: true }).unwrap();
+    let err = execute(deps.as_mut(), env.clone(), message_info(&backer, &[]), leave.clone()).unwrap_err();
+    assert_eq!(err, ContractError::YesVoteInFlight { proposal_id });
+
+    env.block.time = env.block.time.plus_seconds(259200 + 1);
+    let info = message_info(&founder, &[]);
+    execute(deps.as_mut(), env.clone(), info, ExecuteMsg::ExecuteProposal { proposal_id }).unwrap();
+
+    // Joined at 1000, so a week in is the earliest exit
+    let err = execute(deps.as_mut(), env.clone(), message_info(&backer, &[]), leave.clone()).unwrap_err();
+    assert_eq!(err, ContractError::TenureTooShort { eligible_at: 605_800 });
+    env.block.time = Timestamp::from_seconds(605_800);
+
+    // 3000 of 9000 contributed, a third of 12000 capped at the 3000 paid in,
+    // less a 10% haircut. The custom treasury is split the same way, uncapped:
+    // a third of 900 less 10%
+    let res = execute(deps.as_mut(), env.clone(), message_info(&backer, &[]), leave.clone()).unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        BankMsg::Send {
+            to_address: backer.to_string(),
+            amount: vec![coin(2700, DENOM)],
+        }
+        .into()
+    );
+    assert_eq!(
+        res.messages[1].msg,
+        BankMsg::Send {
+            to_address: backer.to_string(),
+            amount: vec![coin(270, GOLD)],
+        }
+        .into()
+    );
+
+    // Never paid in, walks away with nothing
+    let res = execute(deps.as_mut(), env.clone(), message_info(&lurker, &[]), leave.clone()).unwrap();
+    assert!(res.messages.is_empty());
+
+    let res = query(deps.as_ref(), env.clone(), QueryMsg::Corporation { corp_id }).unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.treasury_balance, Uint128::new(9300));
+    assert_eq!(resp.corporation.custom_treasury.unwrap().balance, Uint128::new(630));
+    assert_eq!(resp.corporation.total_contributions, Uint128::new(6000));
+    assert_eq!(resp.corporation.member_count, 1);
+
+    // The last member takes both treasuries in full, leaving nothing locked
+    let res = execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), leave).unwrap();
+    assert_eq!(res.messages.len(), 2);
+    assert_eq!(
+        res.messages[1].msg,
+        BankMsg::Send {
+            to_address: founder.to_string(),
+            amount: vec![coin(630, GOLD)],
+        }
+        .into()
+    );
+    let res = query(deps.as_ref(), env, QueryMsg::Corporation { corp_id }).unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.status, CorporationStatus::Dissolved);
+    assert_eq!(resp.corporation.custom_treasury.unwrap().balance, Uint128::zero());
+}
+
+#[test]
+fn test_dissolution_proposal() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(1000);
+
+    let corp_id = {
+        let info = message_info(&founder, &[coin(1000, DENOM)]);
+        let msg = ExecuteMsg::CreateCorporation {
+            name: "Corp".to_string(),
+            description: "desc".to_string(),
+            join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
+        };
+        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
+    };
+
+    // Donate treasury
+    {
+        let info = message_info(&founder, &[coin(10000, DENOM)]);
+        let msg = ExecuteMsg::DonateTreasury { corp_id };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    }
+
+    // Need 75% supermajority — with 1 member, founder's vote = 100%
+    env.block.time = Timestamp::from_seconds(2000);
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::Dissolution,
+    );
+
+    let info = message_info(&founder, &[]);
+    let msg = ExecuteMsg::Vote {
+        proposal_id,
+        vote: true,
+    };
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
+
+    let info = message_info(&founder, &[]);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // Corp should be dissolving
+    let res = query(deps.as_ref(), env.clone(), QueryMsg::Corporation { corp_id }).unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.status, CorporationStatus::Dissolving);
+
+    // Claim dissolution share
+    let info = message_info(&founder, &[]);
+    let msg = ExecuteMsg::ClaimDissolution { corp_id };
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // Should have bank send message with share
+    assert_eq!(res.messages.len(), 1);
+    let bank_msg = &res.messages[0].msg;
+    match bank_msg {
+        cosmwasm_std::CosmosMsg::Bank(BankMsg::Send { amount, .. }) => {
+            assert_eq!(amount[0].amount, Uint128::new(10000));
+        }
+        _ => panic!("Expected BankMsg::Send"),
+    }
+
+    // Corp should be dissolved (last member claimed)
+    let res = query(deps.as_ref(), env, QueryMsg::Corporation { corp_id }).unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.status, CorporationStatus::Dissolved);
+}
+
+#[test]
+fn test_dissolution_requires_supermajority() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(1000);
+
+    let corp_id = {
+        let info = message_info(&founder, &[coin(1000, DENOM)]);
+        let msg = ExecuteMsg::CreateCorporation {
+            name: "Corp".to_string(),
+            description: "desc".to_string(),
+            join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
+        };
+        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
+    };
+
+    // Add 3 more members (total 4) — need 3 yes votes for 75%
+    let m1 = addr(&deps, "m1");
+    let m2 = addr(&deps, "m2");
+    let m3 = addr(&deps, "m3");
+
+    for m in [&m1, &m2, &m3] {
+        let info = message_info(m, &[]);
+        let msg = ExecuteMsg::JoinCorporation { corp_id };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    }
+
+    env.block.time = Timestamp::from_seconds(2000);
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::Dissolution,
+    );
+
+    // Only 2 out of 4 vote yes (50%, need 75%)
+    for voter in [&founder, &m1] {
+        let info = message_info(voter, &[]);
+        let msg = ExecuteMsg::Vote {
+            proposal_id,
+            vote: true,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    }
+    for voter in [&m2, &m3] {
+        let info = message_info(voter, &[]);
+        let msg = ExecuteMsg::Vote {
+            proposal_id,
+            vote: false,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    }
+
+    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
+
+    let info = message_info(&founder, &[]);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    // This should fail because even though quorum (51%) is met, dissolution needs 75% supermajority
+    // But first the general pass check happens: 2 yes vs 2 no => not passed (yes must be > no)
+    // So it fails as "failed" proposal
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    assert_eq!(proposal_result(&res), "failed");
+}
+
+#[test]
+fn test_voting_not_ended() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(1000);
+
+    let corp_id = {
+        let info = message_info(&founder, &[coin(1000, DENOM)]);
+        let msg = ExecuteMsg::CreateCorporation {
+            name: "Corp".to_string(),
+            description: "desc".to_string(),
+            join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
+        };
+        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
+    };
+
+    env.block.time = Timestamp::from_seconds(2000);
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::Custom {
+            title: "Test".to_string(),
+            description: "desc".to_string(),
+        },
+    );
+
+    // Try to execute before voting ends
+    let info = message_info(&founder, &[]);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::VotingNotEnded { id: proposal_id });
+}
+
+#[test]
+fn test_update_description_founder_only() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+
+    let member = addr(&deps, "member1");
+    join_corporation(&mut deps, &member, corp_id);
+
+    // Founder updates description
+    let info = message_info(&founder, &[]);
+    let msg = ExecuteMsg::UpdateDescription {
+        corp_id,
+        description: "Updated description".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Corporation { corp_id }).unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.description, "Updated description");
+
+    // Member cannot update
+    let info = message_info(&member, &[]);
+    let msg = ExecuteMsg::UpdateDescription {
+        corp_id,
+        description: "Hacked!".to_string(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "founder".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_corporation_branding() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let member = addr(&deps, "member1");
+    let create = |branding: Branding| ExecuteMsg::CreateCorporation {
+        name: "Corp".to_string(),
+        description: "desc".to_string(),
+        join_policy: JoinPolicy::Open,
+        branding: Some(branding),
+        custom_denom: None,
+    };
+    let fee = message_info(&founder, &[coin(1000, DENOM)]);
+
+    let bad_uri = Branding {
+        emblem_uri: Some("javascript:alert(1)".to_string()),
+        ..Branding::default()
+    };
+    let err = execute(deps.as_mut(), mock_env(), fee.clone(), create(bad_uri)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidBrandingUri {
+            field: "emblem_uri".to_string(),
+            max: 512
+        }
+    );
+
+    let branding = Branding {
+        emblem_uri: Some("ipfs://bafyemblem".to_string()),
+        banner_uri: Some("https://cdn.example.com/banner.png".to_string()),
+        tags: vec!["pvp".to_string(), "eu-west".to_string()],
+    };
+    execute(deps.as_mut(), mock_env(), fee, create(branding.clone())).unwrap();
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::ListCorporations {
+            start_after: None,
+            limit: None,
+            filter: None,
+        },
+    )
+    .unwrap();
+    let resp: CorporationsListResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporations[0].branding, branding);
+    join_corporation(&mut deps, &member, 1);
+
+    let update = |tags: &[&str]| ExecuteMsg::UpdateBranding {
+        corp_id: 1,
+        branding: Branding {
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..branding.clone()
+        },
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&member, &[]),
+        update(&["pvp"]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "founder".to_string()
+        }
+    );
+
+    let info = message_info(&founder, &[]);
+    for (tags, bad) in [(&["pvp", "PvE"][..], "PvE"), (&["pvp", "pvp"][..], "pvp")] {
+        let err = execute(deps.as_mut(), mock_env(), info.clone(), update(tags)).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidTag {
+                tag: bad.to_string(),
+                max: 24
+            }
+        );
+    }
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), update(&["t"; 9])).unwrap_err();
+    assert_eq!(err, ContractError::TooManyTags { max: 8 });
+
+    execute(deps.as_mut(), mock_env(), info, update(&["pve", "casual"])).unwrap();
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::Corporation { corp_id: 1 },
+    )
+    .unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.branding.tags, vec!["pve", "casual"]);
+    assert_eq!(resp.corporation.branding.emblem_uri, branding.emblem_uri);
+}
+
+#[test]
+fn test_proposal_numbering_per_corporation() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let alice = addr(&deps, "alice");
+    let bob = addr(&deps, "bob");
+    let env = mock_env();
+    let corp_a = create_corporation(&mut deps, &alice, "Alpha", JoinPolicy::Open);
+    let corp_b = create_corporation(&mut deps, &bob, "Beta", JoinPolicy::Open);
+    let custom = |title: &str| ProposalTypeMsg::Custom {
+        title: title.to_string(),
+        description: "desc".to_string(),
+    };
+
+    create_proposal(&mut deps, &env, &alice, corp_a, custom("a1"));
+    let b1 = create_proposal(&mut deps, &env, &bob, corp_b, custom("b1"));
+    let b2 = create_proposal(&mut deps, &env, &bob, corp_b, custom("b2"));
+    assert_eq!((b1, b2), (2, 3));
+
+    let by_seq = |corp_id: u64, seq: u64| {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ProposalByCorpSeq { corp_id, seq },
+        )
+        .unwrap();
+        from_json::<ProposalResponse>(res).unwrap().proposal
+    };
+    let proposal = by_seq(corp_b, 2);
+    assert_eq!(proposal.id, b2);
+    assert_eq!(proposal.corp_seq, 2);
+    assert_eq!(by_seq(corp_b, 1).id, b1);
+    assert_eq!(by_seq(corp_a, 1).corp_seq, 1);
+
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::ProposalByCorpSeq {
+            corp_id: corp_a,
+            seq: 2,
+        },
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_list_corporations() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    create_corporation(&mut deps, &founder, "Corp1", JoinPolicy::Open);
+    create_corporation(&mut deps, &founder, "Corp2", JoinPolicy::InviteOnly);
+    create_corporation(&mut deps, &founder, "Corp3", JoinPolicy::Open);
+
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::ListCorporations {
+            start_after: None,
+            limit: Some(2),
+            filter: None,
+        },
+    )
+    .unwrap();
+    let resp: CorporationsListResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporations.len(), 2);
+    assert_eq!(resp.corporations[0].name, "Corp1");
+    assert_eq!(resp.corporations[1].name, "Corp2");
+
+    // Pagination
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::ListCorporations {
+            start_after: Some(2),
+            limit: None,
+            filter: None,
+        },
+    )
+    .unwrap();
+    let resp: CorporationsListResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporations.len(), 1);
+    assert_eq!(resp.corporations[0].name, "Corp3");
+}
+
+#[test]
+fn test_list_corporations_filter() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let alpha = create_corporation(&mut deps, &founder, "Alpha Wolves", JoinPolicy::Open);
+    create_corporation(&mut deps, &founder, "Beta Guild", JoinPolicy::InviteOnly);
+    let wolfpack = create_corporation(&mut deps, &founder, "alpha pack", JoinPolicy::Open);
+    create_corporation(&mut deps, &founder, "Gamma", JoinPolicy::Open);
+    let m1 = addr(&deps, "member1");
+    let m2 = addr(&deps, "member2");
+    join_corporation(&mut deps, &m1, alpha);
+    join_corporation(&mut deps, &m2, alpha);
+
+    let list = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, start_after, filter| {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ListCorporations {
+                start_after,
+                limit: None,
+                filter: Some(filter),
+            },
+        )
+        .unwrap();
+        from_json::<CorporationsListResponse>(res)
+            .unwrap()
+            .corporations
+            .into_iter()
+            .map(|c| c.id)
+            .collect::<Vec<_>>()
+    };
+
+    // Name prefix is case-insensitive
+    let by_name = CorporationFilter {
+        name_prefix: Some("ALPHA".to_string()),
+        ..CorporationFilter::default()
+    };
+    assert_eq!(list(&deps, None, by_name.clone()), vec![wolfpack, alpha]);
+    assert_eq!(list(&deps, Some(wolfpack), by_name), vec![alpha]);
+
+    let open = CorporationFilter {
+        join_policy: Some(JoinPolicy::Open),
+        ..CorporationFilter::default()
+    };
+    assert_eq!(list(&deps, None, open), vec![alpha, wolfpack, 4]);
+
+    let big = CorporationFilter {
+        min_members: Some(2),
+        ..CorporationFilter::default()
+    };
+    assert_eq!(list(&deps, None, big), vec![alpha]);
+
+    // A member count bound lists by member count, then id
+    let any_size = CorporationFilter {
+        min_members: Some(1),
+        ..CorporationFilter::default()
+    };
+    assert_eq!(
+        list(&deps, None, any_size.clone()),
+        vec![2, wolfpack, 4, alpha]
+    );
+    assert_eq!(list(&deps, Some(4), any_size), vec![alpha]);
+
+    let small_invite_only = CorporationFilter {
+        join_policy: Some(JoinPolicy::InviteOnly),
+        max_members: Some(1),
+        ..CorporationFilter::default()
+    };
+    assert_eq!(list(&deps, None, small_invite_only), vec![2]);
+
+    // Leaving moves the corporation to the Dissolved bucket
+    let info = message_info(&founder, &[]);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::LeaveCorporation { corp_id: wolfpack },
+    )
+    .unwrap();
+    let active = CorporationFilter {
+        status: Some(CorporationStatus::Active),
+        join_policy: Some(JoinPolicy::Open),
+        ..CorporationFilter::default()
+    };
+    assert_eq!(list(&deps, None, active), vec![alpha, 4]);
+    let dissolved = CorporationFilter {
+        status: Some(CorporationStatus::Dissolved),
+        ..CorporationFilter::default()
+    };
+    assert_eq!(list(&deps, None, dissolved), vec![wolfpack]);
+}
+
+#[test]
+fn test_list_corporations_filter_scan_limit() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    for i in 0..DIRECTORY_SCAN_LIMIT {
+        let name = format!("Guild {i:03}");
+        create_corporation(&mut deps, &founder, &name, JoinPolicy::Open);
+    }
+    let needle = create_corporation(&mut deps, &founder, "Needle", JoinPolicy::Open);
+    let big = create_corporation(&mut deps, &founder, "Guild zz", JoinPolicy::Open);
+    let member = addr(&deps, "member1");
+    join_corporation(&mut deps, &member, big);
+
+    let search = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, start_after, filter| {
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ListCorporations {
+                start_after,
+                limit: None,
+                filter: Some(filter),
+            },
+        )
+        .unwrap();
+        from_json::<CorporationsListResponse>(res).unwrap()
+    };
+    let ids = |resp: &CorporationsListResponse| {
+        resp.corporations.iter().map(|c| c.id).collect::<Vec<_>>()
+    };
+
+    // Names and member counts are each one index range, however many
+    // corporations sort before the match
+    let by_name = CorporationFilter {
+        name_prefix: Some("needle".to_string()),
+        ..CorporationFilter::default()
+    };
+    let resp = search(&deps, None, by_name);
+    assert_eq!(ids(&resp), vec![needle]);
+    assert_eq!(resp.next_start_after, None);
+    let by_size = CorporationFilter {
+        min_members: Some(2),
+        ..CorporationFilter::default()
+    };
+    let resp = search(&deps, None, by_size);
+    assert_eq!(ids(&resp), vec![big]);
+    assert_eq!(resp.next_start_after, None);
+
+    // Both at once check member counts along the name range, so that search
+    // gives up at the scan limit and says where to pick up
+    let both = CorporationFilter {
+        name_prefix: Some("guild".to_string()),
+        min_members: Some(2),
+        ..CorporationFilter::default()
+    };
+    let resp = search(&deps, None, both.clone());
+    assert!(resp.corporations.is_empty());
+    assert_eq!(resp.next_start_after, Some(DIRECTORY_SCAN_LIMIT as u64));
+
+    let resp = search(&deps, resp.next_start_after, both);
+    assert_eq!(ids(&resp), vec![big]);
+    assert_eq!(resp.next_start_after, None);
+}
+
+#[test]
+fn test_list_members() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+
+    let m1 = addr(&deps, "member1");
+    let m2 = addr(&deps, "member2");
+    join_corporation(&mut deps, &m1, corp_id);
+    join_corporation(&mut deps, &m2, corp_id);
+
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::Members {
+            corp_id,
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let resp: MembersListResponse = from_json(res).unwrap();
+    assert_eq!(resp.members.len(), 3); // founder + 2 members
+}
+
+#[test]
+fn test_corporation_full() {
+    let mut deps = setup_deps();
+
+    // Create with max_members = 2
+    let owner = deps.api.addr_make("owner");
+    let msg = InstantiateMsg {
+        owner: owner.to_string(),
+        denom: DENOM.to_string(),
+        creation_fee: Uint128::new(1000),
+        proposal_deposit: Uint128::new(500),
+        default_max_members: 2,
+        default_quorum_bps: 5100,
+        default_voting_period: 259200,
+        max_spend_bps: None,
+    };
+    let info = message_info(&owner, &[]);
+    instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let founder = addr(&deps, "founder");
+    let corp_id = create_corporation(&mut deps, &founder, "SmallCorp", JoinPolicy::Open);
+
+    let m1 = addr(&deps, "m1");
+    join_corporation(&mut deps, &m1, corp_id);
+
+    // 3rd member should fail
+    let m2 = addr(&deps, "m2");
+    let info = message_info(&m2, &[]);
+    let msg = ExecuteMsg::JoinCorporation { corp_id };
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::CorporationFull { max: 2 });
+}
+
+#[test]
+fn test_corporation_levels() {
+    let mut deps = setup_deps();
+    let owner = deps.api.addr_make("owner");
+    let mut msg = default_instantiate_msg(&owner);
+    msg.default_max_members = 2;
+    instantiate(deps.as_mut(), mock_env(), message_info(&owner, &[]), msg).unwrap();
+
+    let perks = |min_xp, bonus_members, deposit_discount_bps| LevelPerks {
+        min_xp,
+        bonus_members,
+        deposit_discount_bps,
+    };
+    let mut leveling = Leveling {
+        member_xp: 10,
+        treasury_xp: 5,
+        treasury_unit: Uint128::new(1000),
+        proposal_xp: 20,
+        levels: vec![perks(30, 1, 2000), perks(30, 3, 5000)],
+    };
+    let set_leveling = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, sender: &Addr, leveling| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(sender, &[]),
+            ExecuteMsg::SetLeveling { leveling },
+        )
+    };
+    let founder = addr(&deps, "founder");
+    let err = set_leveling(&mut deps, &founder, Some(leveling.clone())).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized { .. }));
+    let err = set_leveling(&mut deps, &owner, Some(leveling.clone())).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidLeveling {
+            reason: "min_xp must be strictly ascending".to_string()
+        }
+    );
+    leveling.levels[1].min_xp = 60;
+    set_leveling(&mut deps, &owner, Some(leveling)).unwrap();
+
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    let m1 = addr(&deps, "m1");
+    join_corporation(&mut deps, &m1, corp_id);
+    let status = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| -> CorporationResponse {
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Corporation { corp_id }).unwrap())
+            .unwrap()
+    };
+    let corp = status(&deps);
+    assert_eq!((corp.level, corp.xp, corp.next_level_xp), (0, 20, Some(30)));
+    assert_eq!(corp.member_cap, 2);
+    assert_eq!(corp.proposal_deposit, Uint128::new(500));
+
+    let m2 = addr(&deps, "m2");
+    let join = ExecuteMsg::JoinCorporation { corp_id };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&m2, &[]),
+        join.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::CorporationFull { max: 2 });
+
+    // A 2000 treasury adds 10 XP and reaches level 1
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[coin(2000, DENOM)]),
+        ExecuteMsg::DonateTreasury { corp_id },
+    )
+    .unwrap();
+    execute(deps.as_mut(), mock_env(), message_info(&m2, &[]), join).unwrap();
+    let corp = status(&deps);
+    assert_eq!((corp.level, corp.xp, corp.next_level_xp), (1, 40, Some(60)));
+    assert_eq!(corp.member_cap, 3);
+    assert_eq!(corp.proposal_deposit, Uint128::new(400));
+
+    // The discounted deposit is what gets paid, and refunded on execution
+    let mut env = mock_env();
+    let create = ExecuteMsg::CreateProposal {
+        corp_id,
+        proposal_type: ProposalTypeMsg::Custom {
+            title: "Level up".to_string(),
+            description: "Execute something".to_string(),
+        },
+        metadata: None,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&founder, &[coin(500, DENOM)]),
+        create.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::OverpaymentNotAllowed { .. }));
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&founder, &[coin(400, DENOM)]),
+        create,
+    )
+    .unwrap();
+    for voter in [&founder, &m1, &m2] {
+        let vote = ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: true,
+        };
+        execute(deps.as_mut(), env.clone(), message_info(voter, &[]), vote).unwrap();
+    }
+    env.block.time = env.block.time.plus_seconds(259_200 + 1);
+    let res = execute(
+        deps.as_mut(),
+        env,
+        message_info(&founder, &[]),
+        ExecuteMsg::ExecuteProposal { proposal_id: 1 },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        BankMsg::Send {
+            to_address: founder.to_string(),
+            amount: vec![coin(400, DENOM)],
+        }
+        .into()
+    );
+
+    // An executed proposal adds 20 XP: top level, no next requirement
+    let corp = status(&deps);
+    assert_eq!(corp.corporation.executed_proposals, 1);
+    assert_eq!((corp.level, corp.xp, corp.next_level_xp), (2, 60, None));
+    assert_eq!(corp.member_cap, 5);
+    assert_eq!(corp.proposal_deposit, Uint128::new(250));
+}
+
+#[test]
+fn test_already_member() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+
+    let m1 = addr(&deps, "m1");
+    join_corporation(&mut deps, &m1, corp_id);
+
+    // Try to join again
+    let info = message_info(&m1, &[]);
+    let msg = ExecuteMsg::JoinCorporation { corp_id };
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::AlreadyMember { corp_id });
+}
+
+#[test]
+fn test_non_member_cannot_create_proposal() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+
+    let outsider = addr(&deps, "outsider");
+    let info = message_info(&outsider, &[coin(500, DENOM)]);
+    let msg = ExecuteMsg::CreateProposal {
+        corp_id,
+        proposal_type: ProposalTypeMsg::Custom {
+            title: "Hack".to_string(),
+            description: "desc".to_string(),
+        },
+        metadata: None,
+    };
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::NotMember { corp_id });
+}
+
+#[test]
+fn test_dissolving_blocks_new_proposals() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(1000);
+
+    let corp_id = {
+        let info = message_info(&founder, &[coin(1000, DENOM)]);
+        let msg = ExecuteMsg::CreateCorporation {
+            name: "Corp".to_string(),
+            description: "desc".to_string(),
+            join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
+        };
+        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
+    };
+
+    // Create and pass dissolution
+    env.block.time = Timestamp::from_seconds(2000);
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::Dissolution,
+    );
+
+    let info = message_info(&founder, &[]);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::Vote {
+            proposal_id,
+            vote: true,
+        },
+    )
+    .unwrap();
+
+    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
+    let info = message_info(&founder, &[]);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::ExecuteProposal { proposal_id },
+    )
+    .unwrap();
+
+    // Try to create new proposal — should fail
+    let info = message_info(&founder, &[coin(500, DENOM)]);
+    let msg = ExecuteMsg::CreateProposal {
+        corp_id,
+        proposal_type: ProposalTypeMsg::Custom {
+            title: "Blocked".to_string(),
+            description: "desc".to_string(),
+        },
+        metadata: None,
+    };
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(err, ContractError::Dissolving);
+}
+
+#[test]
+fn test_already_executed_proposal() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(1000);
+
+    let corp_id = {
+        let info = message_info(&founder, &[coin(1000, DENOM)]);
+        let msg = ExecuteMsg::CreateCorporation {
+            name: "Corp".to_string(),
+            description: "desc".to_string(),
+            join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: None,
+        };
+        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
+    };
+
+    let member = addr(&deps, "m1");
+    {
+        let info = message_info(&member, &[]);
+        execute(deps.as_mut(), env.clone(), info, ExecuteMsg::JoinCorporation { corp_id }).unwrap();
+    }
+
+    env.block.time = Timestamp::from_seconds(2000);
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::Custom {
+            title: "Test".to_string(),
+            description: "desc".to_string(),
+        },
+    );
+
+    for voter in [&founder, &member] {
+        let info = message_info(voter, &[]);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::Vote {
+                proposal_id,
+                vote: true,
+            },
+        )
+        .unwrap();
+    }
+
+    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
+
+    let info = message_info(&founder, &[]);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::ExecuteProposal { proposal_id },
     )
     .unwrap();
-    let resp: CorporationsListResponse = from_json(res).unwrap();
-    assert_eq!(resp.corporations.len(), 1);
-    assert_eq!(resp.corporations[0].name, "Corp3");
+
+    // Try to execute again
+    let info = message_info(&founder, &[]);
+    let err = execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::ExecuteProposal { proposal_id },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AlreadyExecuted { id: proposal_id });
 }
 
 #[test]
-fn test_list_members() {
+fn test_proposal_metadata() {
     let mut deps = setup_deps();
     do_instantiate(&mut deps);
 
     let founder = addr(&deps, "founder");
+    let recipient = addr(&deps, "recipient");
+    let env = mock_env();
     let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    let msg = |metadata: ProposalMetadata| ExecuteMsg::CreateProposal {
+        corp_id,
+        proposal_type: ProposalTypeMsg::TreasurySpend {
+            recipient: recipient.to_string(),
+            amount: Uint128::new(100),
+            denom: None,
+        },
+        metadata: Some(metadata),
+    };
 
-    let m1 = addr(&deps, "member1");
-    let m2 = addr(&deps, "member2");
-    join_corporation(&mut deps, &m1, corp_id);
-    join_corporation(&mut deps, &m2, corp_id);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&founder, &[coin(500, DENOM)]),
+        msg(ProposalMetadata {
+            title: Some(String::new()),
+            description: None,
+            metadata_uri: None,
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidProposalMetadata {
+            field: "title".to_string(),
+            max: 128
+        }
+    );
+
+    let metadata = ProposalMetadata {
+        title: Some("Pay the event winners".to_string()),
+        description: Some("Prize pool for the season finale".to_string()),
+        metadata_uri: Some("ipfs://bafy-finale".to_string()),
+    };
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&founder, &[coin(500, DENOM)]),
+        msg(metadata.clone()),
+    )
+    .unwrap();
+    let proposal_id = res
+        .attributes
+        .iter()
+        .find(|a| a.key == "proposal_id")
+        .unwrap()
+        .value
+        .parse()
+        .unwrap();
+    let res = query(deps.as_ref(), env, QueryMsg::Proposal { proposal_id }).unwrap();
+    let resp: ProposalResponse = from_json(res).unwrap();
+    assert_eq!(resp.proposal.metadata, Some(metadata));
+}
+
+#[test]
+fn test_owner_veto_proposal() {
+    let mut deps = setup_deps();
+    let owner = do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let mut env = mock_env();
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    env.block.time = env.block.time.plus_seconds(10);
+    let proposal_id = create_proposal(
+        &mut deps,
+        &env,
+        &founder,
+        corp_id,
+        ProposalTypeMsg::Custom {
+            title: "Exploit".to_string(),
+            description: "desc".to_string(),
+        },
+    );
+    let veto = |reason: &str| ExecuteMsg::VetoProposal {
+        proposal_id,
+        reason: reason.to_string(),
+    };
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&founder, &[]),
+        veto("no"),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "owner".to_string()
+        }
+    );
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        veto(" "),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidVetoReason { max: 512 });
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        veto("targets the crafting dupe bug"),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        cosmwasm_std::CosmosMsg::Bank(BankMsg::Send {
+            to_address: founder.to_string(),
+            amount: vec![coin(500, DENOM)],
+        })
+    );
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::Proposal { proposal_id },
+    )
+    .unwrap();
+    let resp: ProposalResponse = from_json(res).unwrap();
+    assert_eq!(resp.proposal.status, ProposalStatus::Vetoed);
+    assert_eq!(
+        resp.proposal.veto_reason.as_deref(),
+        Some("targets the crafting dupe bug")
+    );
+
+    // A vetoed proposal can no longer be voted on, vetoed again or executed
+    let msg = ExecuteMsg::Vote {
+        proposal_id,
+        vote: true,
+    };
+    let err = execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::ProposalNotPending { id: proposal_id });
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        veto("again"),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ProposalNotPending { id: proposal_id });
+    env.block.time = env.block.time.plus_seconds(259200 + 1);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+    let err = execute(deps.as_mut(), env, message_info(&founder, &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::ProposalNotPending { id: proposal_id });
+}
+
+// ─── Squads ───────────────────────────────────────────────────────────
+
+fn squad_of(
+    deps: &cosmwasm_std::OwnedDeps<
+        cosmwasm_std::MemoryStorage,
+        cosmwasm_std::testing::MockApi,
+        cosmwasm_std::testing::MockQuerier,
+    >,
+    corp_id: u64,
+    member: &Addr,
+) -> Option<String> {
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::MemberSquad {
+            corp_id,
+            address: member.to_string(),
+        },
+    )
+    .unwrap();
+    from_json::<MemberSquadResponse>(res).unwrap().squad
+}
+
+#[test]
+fn test_officer_manages_squads() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let lead = addr(&deps, "lead");
+    let grunt = addr(&deps, "grunt");
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    join_corporation(&mut deps, &lead, corp_id);
+    join_corporation(&mut deps, &grunt, corp_id);
+
+    let create = ExecuteMsg::CreateSquad {
+        corp_id,
+        name: "red".to_string(),
+        leader: lead.to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&grunt, &[]),
+        create.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "officer or founder".to_string()
+        }
+    );
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[]),
+        create.clone(),
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[]),
+        create,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SquadExists {
+            name: "red".to_string()
+        }
+    );
+    assert_eq!(squad_of(&deps, corp_id, &lead), Some("red".to_string()));
+
+    let assign = |squad: Option<&str>, member: &Addr| ExecuteMsg::AssignToSquad {
+        corp_id,
+        member: member.to_string(),
+        squad: squad.map(str::to_string),
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[]),
+        assign(Some("red"), &grunt),
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[]),
+        assign(Some("red"), &grunt),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AlreadyInSquad {
+            name: "red".to_string()
+        }
+    );
+    // Leaders stay put until their squad is disbanded
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[]),
+        assign(None, &lead),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SquadLeader {
+            name: "red".to_string()
+        }
+    );
+
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::Squads {
+            corp_id,
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let squads: SquadsResponse = from_json(res).unwrap();
+    assert_eq!(squads.squads.len(), 1);
+    assert_eq!(squads.squads[0].leader, lead);
+    assert_eq!(squads.squads[0].member_count, 2);
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::SquadMembers {
+            corp_id,
+            name: "red".to_string(),
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let members: SquadMembersResponse = from_json(res).unwrap();
+    assert_eq!(members.members.len(), 2);
+    assert!(members.members.contains(&grunt.to_string()));
+
+    // Leaving the corporation leaves the squad
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&grunt, &[]),
+        ExecuteMsg::LeaveCorporation { corp_id },
+    )
+    .unwrap();
+    assert_eq!(squad_of(&deps, corp_id, &grunt), None);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[]),
+        ExecuteMsg::DisbandSquad {
+            corp_id,
+            name: "red".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(squad_of(&deps, corp_id, &lead), None);
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[]),
+        assign(Some("red"), &lead),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SquadNotFound {
+            name: "red".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_squad_proposals() {
+    let mut deps = setup_deps();
+    do_instantiate(&mut deps);
+
+    let founder = addr(&deps, "founder");
+    let lead = addr(&deps, "lead");
+    let grunt = addr(&deps, "grunt");
+    let mut env = mock_env();
+    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    join_corporation(&mut deps, &lead, corp_id);
+    join_corporation(&mut deps, &grunt, corp_id);
+
+    let pass = |deps: &mut cosmwasm_std::OwnedDeps<
+        cosmwasm_std::MemoryStorage,
+        cosmwasm_std::testing::MockApi,
+        cosmwasm_std::testing::MockQuerier,
+    >,
+                env: &mut cosmwasm_std::Env,
+                proposal_type: ProposalTypeMsg| {
+        env.block.time = env.block.time.plus_seconds(10);
+        let proposal_id = create_proposal(deps, env, &founder, corp_id, proposal_type);
+        for voter in [&founder, &lead] {
+            let msg = ExecuteMsg::Vote {
+                proposal_id,
+                vote: true,
+            };
+            execute(deps.as_mut(), env.clone(), message_info(voter, &[]), msg).unwrap();
+        }
+        env.block.time = env.block.time.plus_seconds(259200 + 1);
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+        let res = execute(deps.as_mut(), env.clone(), message_info(&founder, &[]), msg).unwrap();
+        proposal_result(&res)
+    };
+
+    let result = pass(
+        &mut deps,
+        &mut env,
+        ProposalTypeMsg::CreateSquad {
+            name: "blue".to_string(),
+            leader: lead.to_string(),
+        },
+    );
+    assert_eq!(result, "squad_created");
+    let result = pass(
+        &mut deps,
+        &mut env,
+        ProposalTypeMsg::AssignToSquad {
+            member: grunt.to_string(),
+            squad: Some("blue".to_string()),
+        },
+    );
+    assert_eq!(result, "squad_assigned");
+    assert_eq!(squad_of(&deps, corp_id, &grunt), Some("blue".to_string()));
 
+    // Kicking the leader disbands their squad
+    let result = pass(
+        &mut deps,
+        &mut env,
+        ProposalTypeMsg::KickMember {
+            member: lead.to_string(),
+        },
+    );
+    assert_eq!(result, "member_kicked");
+    assert_eq!(squad_of(&deps, corp_id, &grunt), None);
     let res = query(
         deps.as_ref(),
         mock_env(),
-        QueryMsg::Members {
+        QueryMsg::Squads {
             corp_id,
             start_after: None,
             limit: None,
         },
     )
     .unwrap();
-    let resp: MembersListResponse = from_json(res).unwrap();
-    assert_eq!(resp.members.len(), 3); // founder + 2 members
+    let squads: SquadsResponse = from_json(res).unwrap();
+    assert!(squads.squads.is_empty());
 }
 
-#[test]
-fn test_corporation_full() {
-    let mut deps = setup_deps();
-
-    // Create with max_members = 2
-    let owner = deps.api.addr_make("owner");
-    let msg = InstantiateMsg {
-        owner: owner.to_string(),
-        denom: DENOM.to_string(),
-        creation_fee: Uint128::new(1000),
-        proposal_deposit: Uint128::new(500),
-        default_max_members: 2,
-        default_quorum_bps: 5100,
-        default_voting_period: 259200,
-    };
-    let info = message_info(&owner, &[]);
-    instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
-
-    let founder = addr(&deps, "founder");
-    let corp_id = create_corporation(&mut deps, &founder, "SmallCorp", JoinPolicy::Open);
-
-    let m1 = addr(&deps, "m1");
-    join_corporation(&mut deps, &m1, corp_id);
-
-    // 3rd member should fail
-    let m2 = addr(&deps, "m2");
-    let info = message_info(&m2, &[]);
-    let msg = ExecuteMsg::JoinCorporation { corp_id };
-    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
-    assert_eq!(err, ContractError::CorporationFull { max: 2 });
-}
+// ─── Migration ────────────────────────────────────────────────────────
 
 #[test]
-fn test_already_member() {
+fn test_chunked_migration() {
     let mut deps = setup_deps();
     do_instantiate(&mut deps);
-
     let founder = addr(&deps, "founder");
-    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    let alice = addr(&deps, "alice");
+    let bob = addr(&deps, "bob");
+    let corp_id = create_corporation(&mut deps, &founder, "Alpha", JoinPolicy::Open);
+    join_corporation(&mut deps, &alice, corp_id);
+    join_corporation(&mut deps, &bob, corp_id);
+    for i in 0..3 {
+        create_proposal(
+            &mut deps,
+            &mock_env(),
+            &founder,
+            corp_id,
+            ProposalTypeMsg::Custom {
+                title: format!("Proposal {}", i),
+                description: "A test proposal".to_string(),
+            },
+        );
+    }
 
-    let m1 = addr(&deps, "m1");
-    join_corporation(&mut deps, &m1, corp_id);
+    // Simulate a store written before snapshots, per-corp numbering and the indexes
+    for id in 1..=3u64 {
+        let mut proposal = PROPOSALS.load(&deps.storage, id).unwrap();
+        proposal.corp_seq = 0;
+        proposal.member_count_snapshot = 0;
+        PROPOSALS.save(&mut deps.storage, id, &proposal).unwrap();
+        CORP_PROPOSALS.remove(&mut deps.storage, (corp_id, id));
+        CORP_PROPOSAL_SEQS.remove(&mut deps.storage, (corp_id, id));
+    }
+    CORP_PROPOSAL_COUNT.remove(&mut deps.storage, corp_id);
+    for member in [&founder, &alice, &bob] {
+        MEMBER_CORPS.remove(&mut deps.storage, (member, corp_id));
+    }
 
-    // Try to join again
-    let info = message_info(&m1, &[]);
-    let msg = ExecuteMsg::JoinCorporation { corp_id };
-    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
-    assert_eq!(err, ContractError::AlreadyMember { corp_id });
-}
+    let status: MigrationStatusResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::MigrationStatus {}).unwrap()).unwrap();
+    assert!(status.done);
 
-#[test]
-fn test_non_member_cannot_create_proposal() {
-    let mut deps = setup_deps();
-    do_instantiate(&mut deps);
+    // The plain `{}` payload from before chunking still means a full migration
+    let msg: MigrateMsg = from_json(b"{}").unwrap();
+    assert_eq!(msg, MigrateMsg::default());
 
-    let founder = addr(&deps, "founder");
-    let corp_id = create_corporation(&mut deps, &founder, "Corp", JoinPolicy::Open);
+    let chunk = MigrateMsg {
+        chunk: Some(ChunkMsg { limit: Some(2) }),
+    };
+    let res = migrate(deps.as_mut(), mock_env(), chunk.clone()).unwrap();
+    assert_eq!(res.attributes[2].value, "false");
+    let status: MigrationStatusResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::MigrationStatus {}).unwrap()).unwrap();
+    assert!(!status.proposals_done);
+    assert_eq!(status.proposal_cursor, Some(2));
+
+    // New proposals wait until the old ones are numbered
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&founder, &[coin(500, DENOM)]),
+        ExecuteMsg::CreateProposal {
+            corp_id,
+            proposal_type: ProposalTypeMsg::Custom {
+                title: "Too early".to_string(),
+                description: "A test proposal".to_string(),
+            },
+            metadata: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::MigrationInProgress);
 
-    let outsider = addr(&deps, "outsider");
-    let info = message_info(&outsider, &[coin(500, DENOM)]);
-    let msg = ExecuteMsg::CreateProposal {
+    // Proposal 3 and the first membership, then the last two memberships,
+    // then the corporation directory
+    for done in ["false", "false", "true"] {
+        let res = migrate(deps.as_mut(), mock_env(), chunk.clone()).unwrap();
+        assert_eq!(res.attributes[2].value, done);
+    }
+    let status: MigrationStatusResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::MigrationStatus {}).unwrap()).unwrap();
+    assert!(status.done);
+    assert_eq!(status.processed, 7);
+
+    let res: ProposalResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ProposalByCorpSeq { corp_id, seq: 3 },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.proposal.id, 3);
+    assert_eq!(res.proposal.member_count_snapshot, 3);
+    let res: ProposalsListResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Proposals {
+                corp_id,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.proposals.len(), 3);
+    let res: CorporationsByMemberResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::CorporationsByMember {
+                address: bob.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.corporations.len(), 1);
+
+    create_proposal(
+        &mut deps,
+        &mock_env(),
+        &founder,
         corp_id,
-        proposal_type: ProposalTypeMsg::Custom {
-            title: "Hack".to_string(),
-            description: "desc".to_string(),
+        ProposalTypeMsg::Custom {
+            title: "After".to_string(),
+            description: "A test proposal".to_string(),
         },
-    };
-    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
-    assert_eq!(err, ContractError::NotMember { corp_id });
+    );
 }
 
 #[test]
-fn test_dissolving_blocks_new_proposals() {
+fn test_express_interest_and_approve() {
     let mut deps = setup_deps();
     do_instantiate(&mut deps);
 
     let founder = addr(&deps, "founder");
-    let mut env = mock_env();
-    env.block.time = Timestamp::from_seconds(1000);
+    let open_id = create_corporation(&mut deps, &founder, "OpenCorp", JoinPolicy::Open);
+    let corp_id = create_corporation(&mut deps, &founder, "PrivateCorp", JoinPolicy::InviteOnly);
+    let alice = addr(&deps, "alice");
+    let bob = addr(&deps, "bob");
 
-    let corp_id = {
-        let info = message_info(&founder, &[coin(1000, DENOM)]);
-        let msg = ExecuteMsg::CreateCorporation {
-            name: "Corp".to_string(),
-            description: "desc".to_string(),
-            join_policy: JoinPolicy::Open,
-        };
-        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-        res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
-    };
+    // Open corporations are joined directly
+    let info = message_info(&alice, &[]);
+    let msg = ExecuteMsg::ExpressInterest { corp_id: open_id };
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::NotInviteOnly);
+
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(86_400);
+    for applicant in [&alice, &bob] {
+        let info = message_info(applicant, &[]);
+        let msg = ExecuteMsg::ExpressInterest { corp_id };
+        execute(deps.as_mut(), later.clone(), info, msg).unwrap();
+    }
 
-    // Create and pass dissolution
-    env.block.time = Timestamp::from_seconds(2000);
-    let proposal_id = create_proposal(
-        &mut deps,
-        &env,
-        &founder,
+    let msg = QueryMsg::Interests {
         corp_id,
-        ProposalTypeMsg::Dissolution,
+        start_after: None,
+        limit: Some(1),
+    };
+    let res = query(deps.as_ref(), mock_env(), msg).unwrap();
+    let page: InterestsResponse = from_json(res).unwrap();
+    assert_eq!(page.interests.len(), 1);
+    let first = page.interests[0].address.clone();
+    let msg = QueryMsg::Interests {
+        corp_id,
+        start_after: Some(first.clone()),
+        limit: None,
+    };
+    let res = query(deps.as_ref(), mock_env(), msg).unwrap();
+    let page: InterestsResponse = from_json(res).unwrap();
+    assert_eq!(page.interests.len(), 1);
+    assert_ne!(page.interests[0].address, first);
+    assert_eq!(
+        page.interests[0].expires_at,
+        later.block.time.plus_seconds(INTEREST_TTL)
     );
 
-    let info = message_info(&founder, &[]);
-    execute(
-        deps.as_mut(),
-        env.clone(),
-        info,
-        ExecuteMsg::Vote {
-            proposal_id,
-            vote: true,
-        },
-    )
-    .unwrap();
+    // Only officers and the founder can approve
+    let info = message_info(&bob, &[]);
+    let msg = ExecuteMsg::ApproveInterest {
+        corp_id,
+        applicant: alice.to_string(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert!(matches!(err, ContractError::NotMember { .. }));
 
-    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
+    // One step: no invite to accept
     let info = message_info(&founder, &[]);
-    execute(
-        deps.as_mut(),
-        env.clone(),
-        info,
-        ExecuteMsg::ExecuteProposal { proposal_id },
-    )
-    .unwrap();
+    let msg = ExecuteMsg::ApproveInterest {
+        corp_id,
+        applicant: alice.to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Corporation { corp_id }).unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.member_count, 2);
+    let msg = QueryMsg::Interests {
+        corp_id,
+        start_after: None,
+        limit: None,
+    };
+    let res = query(deps.as_ref(), mock_env(), msg).unwrap();
+    let page: InterestsResponse = from_json(res).unwrap();
+    assert_eq!(page.interests.len(), 1);
+    assert_eq!(page.interests[0].address, bob.to_string());
+
+    // Members can't register interest in their own corporation
+    let info = message_info(&alice, &[]);
+    let msg = ExecuteMsg::ExpressInterest { corp_id };
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::AlreadyMember { corp_id });
 
-    // Try to create new proposal — should fail
-    let info = message_info(&founder, &[coin(500, DENOM)]);
-    let msg = ExecuteMsg::CreateProposal {
+    // Bob's interest lapses after the TTL
+    let mut expired = later.clone();
+    expired.block.time = expired.block.time.plus_seconds(INTEREST_TTL);
+    let msg = QueryMsg::Interests {
         corp_id,
-        proposal_type: ProposalTypeMsg::Custom {
-            title: "Blocked".to_string(),
-            description: "desc".to_string(),
-        },
+        start_after: None,
+        limit: None,
     };
-    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
-    assert_eq!(err, ContractError::Dissolving);
+    let res = query(deps.as_ref(), expired.clone(), msg).unwrap();
+    let page: InterestsResponse = from_json(res).unwrap();
+    assert!(page.interests.is_empty());
+    let info = message_info(&founder, &[]);
+    let msg = ExecuteMsg::ApproveInterest {
+        corp_id,
+        applicant: bob.to_string(),
+    };
+    let err = execute(deps.as_mut(), expired, info, msg).unwrap_err();
+    assert_eq!(err, ContractError::NoPendingInterest);
 }
 
 #[test]
-fn test_already_executed_proposal() {
+fn test_custom_denom_treasury() {
     let mut deps = setup_deps();
     do_instantiate(&mut deps);
 
     let founder = addr(&deps, "founder");
+    let recipient = addr(&deps, "recipient");
     let mut env = mock_env();
     env.block.time = Timestamp::from_seconds(1000);
-
-    let corp_id = {
+    type Deps = cosmwasm_std::OwnedDeps<
+        cosmwasm_std::MemoryStorage,
+        cosmwasm_std::testing::MockApi,
+        cosmwasm_std::testing::MockQuerier,
+    >;
+    let create = |deps: &mut Deps, custom_denom: &str| {
         let info = message_info(&founder, &[coin(1000, DENOM)]);
         let msg = ExecuteMsg::CreateCorporation {
             name: "Corp".to_string(),
             description: "desc".to_string(),
             join_policy: JoinPolicy::Open,
+            branding: None,
+            custom_denom: Some(custom_denom.to_string()),
         };
-        let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-        res.attributes.iter().find(|a| a.key == "corp_id").unwrap().value.parse::<u64>().unwrap()
+        execute(deps.as_mut(), mock_env(), info, msg)
     };
 
-    let member = addr(&deps, "m1");
-    {
-        let info = message_info(&member, &[]);
-        execute(deps.as_mut(), env.clone(), info, ExecuteMsg::JoinCorporation { corp_id }).unwrap();
+    let err = create(&mut deps, DENOM).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidCustomDenom { .. }));
+    let err = create(&mut deps, "ibc/gold!").unwrap_err();
+    assert!(matches!(err, ContractError::InvalidCustomDenom { .. }));
+    // Bank coins only: a CW20 contract is not a denom
+    let cw20 = addr(&deps, "gold_token");
+    let err = create(&mut deps, cw20.as_str()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidCustomDenom {
+            reason: "CW20 tokens are not supported".to_string()
+        }
+    );
+    create(&mut deps, GOLD).unwrap();
+    let corp_id = 1;
+
+    // Both denoms can be donated and are tracked apart
+    for funds in [coin(1000, GOLD), coin(400, DENOM)] {
+        let info = message_info(&founder, &[funds]);
+        let msg = ExecuteMsg::DonateTreasury { corp_id };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
     }
+    let info = message_info(&founder, &[coin(10, "uother")]);
+    let msg = ExecuteMsg::DonateTreasury { corp_id };
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+    let info = message_info(&founder, &[coin(0, GOLD)]);
+    let msg = ExecuteMsg::DonateTreasury { corp_id };
+    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::ZeroAmount);
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Corporation { corp_id }).unwrap();
+    let resp: CorporationResponse = from_json(res).unwrap();
+    assert_eq!(resp.corporation.treasury_balance, Uint128::new(400));
+    let custom = resp.corporation.custom_treasury.unwrap();
+    assert_eq!(custom.denom, GOLD);
+    assert_eq!(custom.balance, Uint128::new(1000));
+    assert_eq!(resp.custom_max_spend, Some(Uint128::new(250)));
 
+    // Spends name the custom denom; any other denom is refused
     env.block.time = Timestamp::from_seconds(2000);
-    let proposal_id = create_proposal(
-        &mut deps,
-        &env,
-        &founder,
+    let info = message_info(&founder, &[coin(500, DENOM)]);
+    let msg = ExecuteMsg::CreateProposal {
         corp_id,
-        ProposalTypeMsg::Custom {
-            title: "Test".to_string(),
-            description: "desc".to_string(),
+        proposal_type: ProposalTypeMsg::TreasurySpend {
+            recipient: recipient.to_string(),
+            amount: Uint128::new(10),
+            denom: Some("uother".to_string()),
         },
-    );
-
-    for voter in [&founder, &member] {
-        let info = message_info(voter, &[]);
-        execute(
-            deps.as_mut(),
-            env.clone(),
-            info,
-            ExecuteMsg::Vote {
-                proposal_id,
-                vote: true,
-            },
-        )
-        .unwrap();
-    }
+        metadata: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+    assert!(matches!(err, ContractError::UnsupportedDenom { .. }));
 
-    env.block.time = Timestamp::from_seconds(2000 + 259200 + 1);
+    let pass = |deps: &mut Deps, env: &mut cosmwasm_std::Env, proposal_type| {
+        let proposal_id = create_proposal(deps, env, &founder, corp_id, proposal_type);
+        let info = message_info(&founder, &[]);
+        let msg = ExecuteMsg::Vote {
+            proposal_id,
+            vote: true,
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        env.block.time = env.block.time.plus_seconds(259200 + 1);
+        let info = message_info(&founder, &[]);
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap()
+    };
 
-    let info = message_info(&founder, &[]);
-    execute(
-        deps.as_mut(),
-        env.clone(),
-        info,
-        ExecuteMsg::ExecuteProposal { proposal_id },
-    )
-    .unwrap();
+    let res = pass(
+        &mut deps,
+        &mut env,
+        ProposalTypeMsg::TreasurySpend {
+            recipient: recipient.to_string(),
+            amount: Uint128::new(250),
+            denom: Some(GOLD.to_string()),
+        },
+    );
+    assert_eq!(
+        res.messages[1].msg,
+        cosmwasm_std::CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![coin(250, GOLD)],
+        })
+    );
 
-    // Try to execute again
+    // Dissolution pays out both treasuries
+    pass(&mut deps, &mut env, ProposalTypeMsg::Dissolution);
     let info = message_info(&founder, &[]);
-    let err = execute(
-        deps.as_mut(),
-        env,
-        info,
-        ExecuteMsg::ExecuteProposal { proposal_id },
-    )
-    .unwrap_err();
-    assert_eq!(err, ContractError::AlreadyExecuted { id: proposal_id });
+    let msg = ExecuteMsg::ClaimDissolution { corp_id };
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let paid: Vec<_> = res
+        .messages
+        .iter()
+        .map(|m| match &m.msg {
+            cosmwasm_std::CosmosMsg::Bank(BankMsg::Send { amount, .. }) => amount[0].clone(),
+            _ => panic!("Expected BankMsg::Send"),
+        })
+        .collect();
+    assert_eq!(paid, vec![coin(400, DENOM), coin(750, GOLD)]);
 }
@@ -1,8 +1,12 @@
 use cosmwasm_std::{
-    to_json_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
-    Uint128,
+    from_json, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env,
+    IbcMsg, IbcTimeout, MessageInfo, Order, Reply, Response, StdResult, SubMsg, SubMsgResult,
+    Timestamp, Uint128,
 };
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::Bound;
+use sysbreak_common::AdminError;
+use sysbreak_events::{ClaimEvent, DepositEvent, WithdrawEvent};
 
 use crate::error::ContractError;
 use crate::helpers::*;
@@ -12,11 +16,14 @@ use crate::state::*;
 const CONTRACT_NAME: &str = "crates.io:sysbreak-credit-bridge";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const DEFAULT_QUERY_LIMIT: u32 = 30;
+const MAX_QUERY_LIMIT: u32 = 100;
+
 // ─── Instantiate ────────────────────────────────────────────────────────────
 
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     _info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
@@ -35,11 +42,15 @@ pub fn instantiate(
     let owner = deps.api.addr_validate(&msg.owner)?;
     let oracle = deps.api.addr_validate(&msg.oracle)?;
     let treasury = deps.api.addr_validate(&msg.treasury)?;
+    let pauser = msg.pauser.map(|p| deps.api.addr_validate(&p)).transpose()?;
+    let peak_window_seconds = msg.peak_window_seconds.unwrap_or(DEFAULT_PEAK_WINDOW);
+    validate_peak_window(peak_window_seconds)?;
 
     let config = Config {
         owner,
         oracle,
-        paused: false,
+        withdrawals_paused: false,
+        deposits_paused: false,
         denom: msg.denom,
         rate_credits: msg.rate_credits,
         rate_tokens: msg.rate_tokens,
@@ -52,13 +63,34 @@ pub fn instantiate(
         min_reserve: msg.min_reserve,
         oracle_pubkey: msg.oracle_pubkey,
         chain_id: msg.chain_id,
+        pauser,
+        peak_window_seconds,
+        limit_window_seconds: DEFAULT_LIMIT_WINDOW,
+        maintenance_mode: false,
+        achievement_discount: None,
+        dao_fee_share: None,
+        player_lifetime_cap: None,
+        kyc_lifetime_cap: None,
+        oracle_key_version: 1,
+        oracle_key_grace_seconds: DEFAULT_ORACLE_KEY_GRACE,
+        referral_bps: 0,
+        price_feed: None,
+        withdrawal_queue: false,
+        strict_telemetry: false,
+        fee_rounding: FeeRounding::Floor,
+        min_fee: Uint128::zero(),
     };
 
     CONFIG.save(deps.storage, &config)?;
-    PEAK_BALANCE.save(deps.storage, &Uint128::zero())?;
-    // FIX: M-04 — initialize Map-based global withdrawal counters
-    GLOBAL_WD_COUNTER.save(deps.storage, &0u64)?;
-    GLOBAL_WD_OLDEST.save(deps.storage, &0u64)?;
+    record_config_change(
+        deps.storage,
+        &env,
+        &config.owner,
+        "instantiate",
+        &config.denom,
+    )?;
+    // New deployments only take sequenced nonces
+    LEGACY_NONCES_UNTIL.save(deps.storage, &env.block.time)?;
 
     Ok(Response::new()
         .add_attribute("action", "instantiate")
@@ -71,8 +103,14 @@ pub fn execute_deposit(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    referrer: Option<String>,
 ) -> Result<Response, ContractError> {
-    assert_not_paused(deps.as_ref())?;
+    assert_deposits_open(deps.as_ref())?;
+    assert_not_blocked(deps.as_ref(), &info.sender)?;
+    let referrer = referrer.map(|r| deps.api.addr_validate(&r)).transpose()?;
+    if referrer.as_ref() == Some(&info.sender) {
+        return Err(ContractError::SelfReferral);
+    }
 
     let config = CONFIG.load(deps.storage)?;
 
@@ -83,39 +121,98 @@ pub fn execute_deposit(
         return Err(ContractError::MultipleDenomsSent);
     }
 
+    // Denom is inferred from the funds; it must be the primary or a configured denom
     let sent = &info.funds[0];
-    if sent.denom != config.denom {
-        return Err(ContractError::WrongDenom {
-            expected: config.denom,
-            got: sent.denom.clone(),
-        });
-    }
-    if sent.amount < config.min_deposit {
+    let params = load_priced_denom_config(deps.as_ref(), &env, &config, &sent.denom)?;
+    if sent.amount < params.min_deposit {
         return Err(ContractError::DepositBelowMinimum {
-            min: config.min_deposit.to_string(),
+            min: params.min_deposit.to_string(),
         });
     }
 
     // Calculate credit amount (before fee — fee is on withdrawal, not deposit)
-    let credit_amount = tokens_to_credits(sent.amount, &config)?;
+    let mut credit_amount = tokens_to_credits(sent.amount, &params)?;
+
+    // A running bonus campaign tops up the credits, out of its budget
+    let mut bonus = None;
+    if let Some(mut campaign) = active_campaign(deps.storage, env.block.time)? {
+        let bonus_credits = campaign_bonus(&campaign, credit_amount);
+        if !bonus_credits.is_zero() {
+            campaign.spent += bonus_credits;
+            BONUS_CAMPAIGNS.save(deps.storage, campaign.id, &campaign)?;
+            credit_amount += bonus_credits;
+            bonus = Some((campaign.id, bonus_credits));
+        }
+    }
 
-    // Update peak balance tracking
+    // Update high-water mark tracking
     let contract_balance = deps
         .querier
-        .query_balance(&env.contract.address, &config.denom)?
+        .query_balance(&env.contract.address, &sent.denom)?
         .amount;
-    let mut peak = PEAK_BALANCE.load(deps.storage)?;
-    if contract_balance > peak {
-        peak = contract_balance;
-        PEAK_BALANCE.save(deps.storage, &peak)?;
+    record_balance_sample(
+        deps.storage,
+        &config,
+        &sent.denom,
+        env.block.time,
+        contract_balance,
+    )?;
+
+    // Receipt of exactly what was recorded, for resolving support disputes
+    let id = RECEIPT_COUNTER.may_load(deps.storage)?.unwrap_or_default() + 1;
+    RECEIPT_COUNTER.save(deps.storage, &id)?;
+    let hash = build_receipt_hash(
+        &config.chain_id,
+        env.contract.address.as_str(),
+        id,
+        info.sender.as_str(),
+        &sent.denom,
+        sent.amount,
+        credit_amount,
+        env.block.height,
+        env.block.time,
+    );
+    let receipt = DepositReceipt {
+        id,
+        player: info.sender.clone(),
+        denom: sent.denom.clone(),
+        token_amount: sent.amount,
+        credit_amount,
+        block_height: env.block.height,
+        timestamp: env.block.time,
+        hash: Binary::from(hash),
+    };
+    RECEIPTS.save(deps.storage, id, &receipt)?;
+
+    let mut res = Response::new()
+        .add_attribute("action", "deposit")
+        .add_attribute("receipt_id", id.to_string())
+        .add_attribute("receipt_hash", receipt.hash.to_base64());
+    if let Some((campaign_id, bonus_credits)) = bonus {
+        res = res
+            .add_attribute("campaign_id", campaign_id.to_string())
+            .add_attribute("bonus_credits", bonus_credits);
+    }
+
+    // Referrals are recorded on the first deposit only
+    if !DEPOSITORS.has(deps.storage, &info.sender) {
+        DEPOSITORS.save(deps.storage, &info.sender, &())?;
+        if let Some(referrer) = referrer {
+            REFERRERS.save(deps.storage, &info.sender, &referrer)?;
+            REFERRAL_COUNTS.update(deps.storage, &referrer, |n| -> StdResult<_> {
+                Ok(n.unwrap_or_default() + 1)
+            })?;
+            res = res.add_attribute("referrer", referrer);
+        }
     }
 
     // Backend observes this event and credits the player's in-game account
-    Ok(Response::new()
-        .add_attribute("action", "deposit")
-        .add_attribute("sender", info.sender.as_str())
-        .add_attribute("token_amount", sent.amount.to_string())
-        .add_attribute("credit_amount", credit_amount.to_string()))
+    Ok(res.add_event(DepositEvent {
+            sender: info.sender.to_string(),
+            denom: sent.denom.clone(),
+            token_amount: sent.amount.to_string(),
+            credit_amount: credit_amount.to_string(),
+        }))
 }
 
 // ─── Execute: Withdraw ──────────────────────────────────────────────────────
@@ -128,398 +225,1822 @@ pub fn execute_withdraw(
     credit_amount: Uint128,
     token_amount: Uint128,
     signature: Binary,
+    ibc_transfer: Option<IbcTransferTarget>,
+    denom: Option<String>,
+    payload_version: Option<u8>,
+    recipient: Option<String>,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?; // FIX: M-08
-    assert_not_paused(deps.as_ref())?;
+    let recipient = recipient.map(|r| deps.api.addr_validate(&r)).transpose()?;
+    withdraw(
+        deps,
+        env,
+        info.sender,
+        None,
+        nonce,
+        credit_amount,
+        token_amount,
+        signature,
+        ibc_transfer,
+        denom,
+        payload_version,
+        recipient,
+    )
+}
+
+/// Relayed withdrawal: the session key stands in for the player's own
+/// transaction signature, the oracle signature is checked as usual.
+pub fn execute_withdraw_with_session_key(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    player: String,
+    nonce: String,
+    credit_amount: Uint128,
+    token_amount: Uint128,
+    signature: Binary,
+    session_signature: Binary,
+    denom: Option<String>,
+    payload_version: Option<u8>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let player = deps.api.addr_validate(&player)?;
+    let session = SESSION_KEYS
+        .may_load(deps.storage, &player)?
+        .ok_or_else(|| ContractError::NoSessionKey {
+            player: player.to_string(),
+        })?;
+    if env.block.time >= session.expires_at {
+        return Err(ContractError::SessionKeyExpired {
+            expired_at: session.expires_at.seconds(),
+        });
+    }
+
+    let res = withdraw(
+        deps,
+        env,
+        player,
+        Some((session.pubkey, session_signature)),
+        nonce,
+        credit_amount,
+        token_amount,
+        signature,
+        None,
+        denom,
+        payload_version,
+        None,
+    )?;
+    Ok(res.add_attribute("relayer", info.sender))
+}
+
+/// Shared withdrawal path. `session` holds the player's session pubkey and
+/// their signature when a relayer submits on the player's behalf; `recipient`
+/// is a signed payout address other than the player.
+fn withdraw(
+    deps: DepsMut,
+    env: Env,
+    player: Addr,
+    session: Option<(Binary, Binary)>,
+    nonce: String,
+    credit_amount: Uint128,
+    token_amount: Uint128,
+    signature: Binary,
+    ibc_transfer: Option<IbcTransferTarget>,
+    denom: Option<String>,
+    payload_version: Option<u8>,
+    recipient: Option<Addr>,
+) -> Result<Response, ContractError> {
+    assert_withdrawals_open(deps.as_ref(), &player)?;
+    assert_not_blocked(deps.as_ref(), &player)?;
+    let version = payload_version.unwrap_or(PAYLOAD_VERSION_LEGACY);
+    if let Some(recipient) = &recipient {
+        if ibc_transfer.is_some() {
+            return Err(ContractError::InvalidRecipient {
+                reason: "cannot be combined with an IBC transfer".to_string(),
+            });
+        }
+        if version != PAYLOAD_VERSION_STRUCTURED {
+            return Err(ContractError::InvalidRecipient {
+                reason: "requires payload version 2".to_string(),
+            });
+        }
+        assert_not_blocked(deps.as_ref(), recipient)?;
+    }
 
     if credit_amount.is_zero() || token_amount.is_zero() {
         return Err(ContractError::ZeroAmount);
     }
 
     let config = CONFIG.load(deps.storage)?;
-    let player = info.sender.clone();
-
-    // FIX: M-03 — validate nonce timestamp before replay check
-    validate_nonce_timestamp(&nonce, env.block.time)?;
-
-    // 1. Nonce replay check
-    if USED_NONCES
-        .may_load(deps.storage, &nonce)?
-        .unwrap_or(false)
-    {
-        return Err(ContractError::NonceAlreadyUsed {
-            nonce: nonce.clone(),
-        });
-    }
+    // Non-primary denoms are bound into the signed payload
+    let signed_denom = denom.filter(|d| *d != config.denom);
+    let denom = signed_denom.clone().unwrap_or_else(|| config.denom.clone());
+    let params = load_priced_denom_config(deps.as_ref(), &env, &config, &denom)?;
+
+    let ibc_channel = match &ibc_transfer {
+        Some(target) => Some(
+            IBC_CHANNELS
+                .may_load(deps.storage, &target.channel_id)?
+                .ok_or_else(|| ContractError::IbcChannelNotAllowed {
+                    channel_id: target.channel_id.clone(),
+                })?,
+        ),
+        None => None,
+    };
 
-    // 2. Verify credit ↔ token conversion matches the current rate (minus fees)
-    let gross_tokens = credits_to_tokens(credit_amount, &config)?;
-    let fee = calculate_fee(gross_tokens, config.fee_bps)?;
-    let net_tokens = gross_tokens.checked_sub(fee).map_err(|_| ContractError::Overflow)?;
-
-    if token_amount != net_tokens {
-        return Err(ContractError::AmountMismatch {
-            credits: credit_amount.to_string(),
-            expected_tokens: net_tokens.to_string(),
-            provided_tokens: token_amount.to_string(),
-        });
-    }
+    // 1. Nonce format, expiry and replay check
+    check_nonce(deps.as_ref(), &env, &nonce)?;
 
-    // 3. Verify oracle signature
-    let message_hash = build_withdrawal_message(
-        &config.chain_id,
-        env.contract.address.as_str(),
-        &nonce,
-        player.as_str(),
+    // 2. Verify credit ↔ token conversion matches the current rate (minus fees)
+    let (fee, net_tokens) = check_withdrawal_amount(
+        deps.as_ref(),
+        &config,
+        &params,
+        &denom,
+        &player,
         credit_amount,
         token_amount,
-    );
+    )?;
 
-    let valid = deps
-        .api
-        .secp256k1_verify(&message_hash, &signature, &config.oracle_pubkey)
-        .map_err(|_| ContractError::SignatureVerificationFailed)?;
+    // 3. Verify oracle signature
+    let message_hashes = match version {
+        PAYLOAD_VERSION_LEGACY => vec![build_withdrawal_message(
+            &config.chain_id,
+            env.contract.address.as_str(),
+            &nonce,
+            player.as_str(),
+            credit_amount,
+            token_amount,
+            signed_denom.as_deref(),
+        )],
+        PAYLOAD_VERSION_STRUCTURED => signing_domains(deps.as_ref(), &env, &config)?
+            .iter()
+            .map(|domain| {
+                build_structured_withdrawal_message(
+                    domain,
+                    &nonce,
+                    player.as_str(),
+                    credit_amount,
+                    token_amount,
+                    &denom,
+                    recipient.as_ref().map(Addr::as_str),
+                )
+            })
+            .collect(),
+        version => return Err(ContractError::UnsupportedPayloadVersion { version }),
+    };
 
-    if !valid {
-        return Err(ContractError::InvalidSignature);
+    // Signatures issued before a key rotation stay valid through the grace window
+    let mut signed = None;
+    'keys: for (key_version, pubkey) in oracle_keys(deps.storage, &env, &config)? {
+        for message_hash in &message_hashes {
+            if verify_oracle_signature(deps.api, message_hash, &signature, &pubkey)? {
+                signed = Some((key_version, message_hash));
+                break 'keys;
+            }
+        }
     }
+    let (key_version, signed_hash) = signed.ok_or(ContractError::InvalidSignature)?;
 
-    // 4. Check player daily limit and cooldown
-    check_player_limits(deps.as_ref(), &env, &player, credit_amount, &config)?;
+    // The player's session key must have signed the same payload
+    if let Some((session_pubkey, session_signature)) = &session {
+        if !verify_oracle_signature(deps.api, signed_hash, session_signature, session_pubkey)? {
+            return Err(ContractError::InvalidSessionSignature);
+        }
+    }
 
-    // 5. Check global daily limit
-    check_global_limit(deps.as_ref(), &env, credit_amount, &config)?;
+    // The signed amount checks out; pay what the credits are worth now
+    let token_amount = net_tokens;
 
-    // 6. Check treasury has enough balance (respecting min reserve)
-    let contract_balance = deps
-        .querier
-        .query_balance(&env.contract.address, &config.denom)?
-        .amount;
+    // 4. Check player daily limit and cooldown
+    // 5. Check lifetime cap and global daily limit
+    let limits = check_player_limits(
+        deps.as_ref(),
+        &env,
+        &player,
+        credit_amount,
+        &config,
+        &denom,
+        &params,
+    )
+    .and_then(|_| check_lifetime_cap(deps.as_ref(), &player, credit_amount, &config))
+    .and_then(|_| check_global_limit(deps.as_ref(), &env, credit_amount, &config, &denom, &params));
+    if let Err(err) = limits {
+        return fail_withdrawal(deps, &env, &config, &player, &nonce, err);
+    }
 
+    // 6. Check treasury has enough balance (respecting min reserve)
     // Total outgoing: token_amount (to player) + fee (to treasury, but that's internal if treasury is external)
     // If treasury is a different address, we send fee there too
-    let total_outgoing = token_amount.checked_add(fee).map_err(|_| ContractError::Overflow)?;
-    let remaining = contract_balance
-        .checked_sub(total_outgoing)
-        .map_err(|_| ContractError::InsufficientTreasury {
-            needed: total_outgoing.to_string(),
-            available: contract_balance.to_string(),
-            reserve_min: config.min_reserve.to_string(),
-        })?;
-
-    if remaining < config.min_reserve {
-        return Err(ContractError::InsufficientTreasury {
-            needed: total_outgoing.to_string(),
-            available: contract_balance.to_string(),
-            reserve_min: config.min_reserve.to_string(),
-        });
-    }
+    let total_outgoing = token_amount
+        .checked_add(fee)
+        .map_err(|_| ContractError::Overflow)?;
+    // With the queue enabled, a local withdrawal the treasury cannot cover yet
+    // (or one behind already queued withdrawals) waits its turn; None queues it
+    let queueable = config.withdrawal_queue && ibc_transfer.is_none();
+    let queue_ahead = queueable
+        && QUEUED_TOTALS
+            .may_load(deps.storage, &denom)?
+            .is_some_and(|t| t.count > 0);
+    let reserve = check_reserve(deps.as_ref(), &env, &denom, &params, total_outgoing);
+    let contract_balance = match reserve {
+        Ok(_) if queue_ahead => None,
+        Ok(balance) => Some(balance),
+        Err(ContractError::InsufficientTreasury { .. }) if queueable => None,
+        Err(e) => return fail_withdrawal(deps, &env, &config, &player, &nonce, e),
+    };
 
     // 7. ALL CHECKS PASSED — mutate state BEFORE dispatching bank messages
 
-    // Mark nonce as used
-    USED_NONCES.save(deps.storage, &nonce, &true)?;
+    // Mark nonce as used, clearing a few expired ones along the way
+    set_nonce_used(deps.storage, &nonce, true)?;
+    prune_nonces(deps.storage, env.block.time, NONCE_PRUNE_BATCH)?;
 
-    // Record player withdrawal
+    // Record withdrawal against the player and global limit buckets
     let now = env.block.time;
-    let record = WithdrawalRecord {
-        amount_credits: credit_amount,
-        timestamp: now,
+    record_withdrawal(deps.storage, &player, &denom, now, credit_amount)?;
+    let previous_withdrawal = PLAYER_LAST_WITHDRAWAL.may_load(deps.storage, &player)?;
+    PLAYER_LAST_WITHDRAWAL.save(deps.storage, &player, &now)?;
+
+    let Some(contract_balance) = contract_balance else {
+        let id = WITHDRAWAL_QUEUE_COUNT
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            + 1;
+        WITHDRAWAL_QUEUE_COUNT.save(deps.storage, &id)?;
+        WITHDRAWAL_QUEUE.save(
+            deps.storage,
+            id,
+            &QueuedWithdrawal {
+                id,
+                player: player.clone(),
+                recipient: recipient.clone(),
+                denom: denom.clone(),
+                nonce: nonce.clone(),
+                credit_amount,
+                token_amount,
+                fee,
+                queued_at: now,
+            },
+        )?;
+        QUEUED_TOTALS.update(deps.storage, &denom, |t| -> StdResult<_> {
+            let mut totals = t.unwrap_or_default();
+            totals.count += 1;
+            totals.amount = totals.amount.saturating_add(total_outgoing);
+            Ok(totals)
+        })?;
+        return Ok(Response::new()
+            .add_attribute("action", "withdraw_queued")
+            .add_attribute("nonce", &nonce)
+            .add_attribute("queue_id", id.to_string())
+            .add_attribute("player", player.as_str())
+            .add_attribute("oracle_key_version", key_version.to_string()));
     };
 
-    let player_records = PLAYER_WITHDRAWALS
-        .may_load(deps.storage, &player)?
-        .unwrap_or_default();
-    // Prune expired entries while we're at it
-    let (mut pruned, _) = sum_rolling_window(player_records, now, 86_400);
-    pruned.push(record.clone());
-    PLAYER_WITHDRAWALS.save(deps.storage, &player, &pruned)?;
-    PLAYER_LAST_WITHDRAWAL.save(deps.storage, &player, &now)?;
+    // Treasury health tracking
+    record_balance_sample(deps.storage, &config, &denom, now, contract_balance)?;
+    let payee = recipient.clone().unwrap_or_else(|| player.clone());
+    record_outflow(deps.storage, &denom, now, total_outgoing)?;
 
-    // FIX: M-04 — record global withdrawal in Map-based storage and prune expired
-    let mut counter = GLOBAL_WD_COUNTER.load(deps.storage)?;
-    counter += 1;
-    GLOBAL_WITHDRAWAL_RECORDS.save(deps.storage, counter, &record)?;
-    GLOBAL_WD_COUNTER.save(deps.storage, &counter)?;
-
-    // Prune a batch of old entries (up to 10 per tx for gas efficiency)
-    let cutoff = now.minus_seconds(86_400);
-    let mut oldest = GLOBAL_WD_OLDEST.load(deps.storage)?;
-    let mut pruned = 0u32;
-    while oldest < counter && pruned < 10 {
-        if let Some(old_record) = GLOBAL_WITHDRAWAL_RECORDS.may_load(deps.storage, oldest)? {
-            if old_record.timestamp < cutoff {
-                GLOBAL_WITHDRAWAL_RECORDS.remove(deps.storage, oldest);
-                oldest += 1;
-                pruned += 1;
-            } else {
-                break;
+    // 8. Build payout messages: local bank send, or IBC transfer to the remote chain
+    let payout = Coin {
+        denom: denom.clone(),
+        amount: token_amount,
+    };
+    let mut response = Response::new();
+    match (ibc_transfer, ibc_channel) {
+        (Some(target), Some(channel)) => {
+            let referral = accrue_referral_reward(deps.storage, &config, &player, &denom, fee)?;
+            PENDING_IBC_TRANSFER.save(
+                deps.storage,
+                &IbcTransferRecord {
+                    player: player.clone(),
+                    channel_id: target.channel_id.clone(),
+                    remote_address: target.remote_address.clone(),
+                    amount: token_amount,
+                    denom: denom.clone(),
+                    nonce: nonce.clone(),
+                },
+            )?;
+            // ibc-hooks delivers the ack/timeout to the contract named in the memo
+            let memo = format!(r#"{{"ibc_callback":"{}"}}"#, env.contract.address);
+            let transfer = IbcMsg::Transfer {
+                channel_id: target.channel_id.clone(),
+                to_address: target.remote_address.clone(),
+                amount: payout,
+                timeout: IbcTimeout::with_timestamp(now.plus_seconds(channel.timeout_seconds)),
+                memo: Some(memo),
+            };
+            response = response
+                .add_submessage(SubMsg::reply_on_success(
+                    CosmosMsg::Ibc(transfer),
+                    IBC_TRANSFER_REPLY_ID,
+                ))
+                .add_attribute("ibc_channel", target.channel_id)
+                .add_attribute("remote_address", target.remote_address)
+                .add_submessages(fee_payments(
+                    deps.as_ref(),
+                    &config,
+                    &player,
+                    &denom,
+                    fee - referral,
+                )?);
+        }
+        _ => {
+            // A failed send (e.g. a blocked recipient) is rolled back in `reply`
+            // instead of consuming the nonce; the fee follows a successful send.
+            PENDING_WITHDRAWAL.save(
+                deps.storage,
+                &PendingWithdrawal {
+                    player: player.clone(),
+                    recipient: recipient.clone(),
+                    denom: denom.clone(),
+                    nonce: nonce.clone(),
+                    credit_amount,
+                    token_amount,
+                    fee,
+                    withdrawn_at: now,
+                    previous_withdrawal,
+                },
+            )?;
+            response = response.add_submessage(SubMsg::reply_always(
+                BankMsg::Send {
+                    to_address: payee.to_string(),
+                    amount: vec![payout],
+                },
+                WITHDRAW_SEND_REPLY_ID,
+            ));
+            if payee != player {
+                response = response.add_attribute("recipient", payee.as_str());
             }
-        } else {
-            oldest += 1;
-            pruned += 1;
         }
     }
-    GLOBAL_WD_OLDEST.save(deps.storage, &oldest)?;
-
-    // 8. Build bank messages
-    let mut messages = vec![BankMsg::Send {
-        to_address: player.to_string(),
-        amount: vec![Coin {
-            denom: config.denom.clone(),
-            amount: token_amount,
-        }],
-    }];
-
-    // Send fee to treasury (only if fee > 0 and treasury != contract)
-    if !fee.is_zero() {
-        messages.push(BankMsg::Send {
-            to_address: config.treasury.to_string(),
-            amount: vec![Coin {
-                denom: config.denom,
-                amount: fee,
-            }],
-        });
-    }
 
-    Ok(Response::new()
-        .add_messages(messages)
+    Ok(response
         .add_attribute("action", "withdraw")
-        .add_attribute("player", player.as_str())
         .add_attribute("nonce", &nonce)
-        .add_attribute("credit_amount", credit_amount.to_string())
-        .add_attribute("token_amount", token_amount.to_string())
-        .add_attribute("fee_amount", fee.to_string()))
+        .add_attribute("oracle_key_version", key_version.to_string())
+        .add_event(WithdrawEvent {
+            recipient: payee.to_string(),
+            denom,
+            token_amount: token_amount.to_string(),
+            credit_amount: credit_amount.to_string(),
+            fee_amount: fee.to_string(),
+        }))
 }
 
-// ─── Execute: Treasury Management ───────────────────────────────────────────
-
-pub fn execute_fund_treasury(
+/// A signed withdrawal that failed its limit or treasury checks. Strict
+/// telemetry logs it and returns success so the record survives; the nonce
+/// stays unused so the same authorization can be retried. A retry that fails
+/// again is not logged twice and reverts with the error instead.
+fn fail_withdrawal(
     deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
+    env: &Env,
+    config: &Config,
+    player: &Addr,
+    nonce: &str,
+    err: ContractError,
 ) -> Result<Response, ContractError> {
-    assert_owner(deps.as_ref(), &info.sender)?;
-
-    let config = CONFIG.load(deps.storage)?;
-
-    if info.funds.is_empty() {
-        return Err(ContractError::NoFundsSent);
+    if !config.strict_telemetry {
+        return Err(err);
     }
-    if info.funds.len() > 1 {
-        return Err(ContractError::MultipleDenomsSent);
+    let reason = failure_reason(&err);
+    if !mark_nonce_failed(deps.storage, nonce)? {
+        return Err(err);
     }
-    let sent = &info.funds[0];
-    if sent.denom != config.denom {
-        return Err(ContractError::WrongDenom {
-            expected: config.denom,
-            got: sent.denom.clone(),
-        });
+    record_failure(deps.storage, player, reason.clone(), env.block.time)?;
+    Ok(Response::new()
+        .add_attribute("action", "withdraw_failed")
+        .add_attribute("nonce", nonce)
+        .add_attribute("player", player.as_str())
+        .add_attribute("reason", reason.as_str())
+        .add_attribute("error", err.to_string()))
+}
+
+// ─── Reply / Sudo: Withdrawal Payouts ───────────────────────────────────────
+
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        IBC_TRANSFER_REPLY_ID => reply_ibc_transfer(deps, msg),
+        WITHDRAW_SEND_REPLY_ID => reply_withdraw_send(deps, env, msg),
+        QUEUE_PAYOUT_REPLY_ID => reply_queue_payout(deps, env, msg),
+        DAO_DONATION_REPLY_ID => reply_dao_donation(deps.as_ref(), msg),
+        id => Err(ContractError::UnknownReplyId { id }),
     }
+}
 
-    // Update peak balance
-    let contract_balance = deps
-        .querier
-        .query_balance(&env.contract.address, &config.denom)?
-        .amount;
-    let mut peak = PEAK_BALANCE.load(deps.storage)?;
-    if contract_balance > peak {
-        peak = contract_balance;
-        PEAK_BALANCE.save(deps.storage, &peak)?;
+/// Settle a local withdrawal: pay the fee once the player has been paid, or
+/// undo the nonce, limits and outflow so the same authorization can be retried.
+fn reply_withdraw_send(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let pending = PENDING_WITHDRAWAL.load(deps.storage)?;
+    PENDING_WITHDRAWAL.remove(deps.storage);
+
+    let error = match msg.result {
+        SubMsgResult::Ok(_) => {
+            let config = CONFIG.load(deps.storage)?;
+            let referral = accrue_referral_reward(
+                deps.storage,
+                &config,
+                &pending.player,
+                &pending.denom,
+                pending.fee,
+            )?;
+            let fees = fee_payments(
+                deps.as_ref(),
+                &config,
+                &pending.player,
+                &pending.denom,
+                pending.fee - referral,
+            )?;
+            return Ok(Response::new()
+                .add_submessages(fees)
+                .add_attribute("action", "withdraw_sent")
+                .add_attribute("nonce", pending.nonce));
+        }
+        SubMsgResult::Err(err) => err,
+    };
+
+    set_nonce_used(deps.storage, &pending.nonce, false)?;
+    unrecord_withdrawal(
+        deps.storage,
+        &pending.player,
+        &pending.denom,
+        pending.withdrawn_at,
+        pending.credit_amount,
+    )?;
+    unrecord_outflow(
+        deps.storage,
+        &pending.denom,
+        pending.withdrawn_at,
+        pending.token_amount.saturating_add(pending.fee),
+    )?;
+    match pending.previous_withdrawal {
+        Some(t) => PLAYER_LAST_WITHDRAWAL.save(deps.storage, &pending.player, &t)?,
+        None => PLAYER_LAST_WITHDRAWAL.remove(deps.storage, &pending.player),
+    }
+    if CONFIG.load(deps.storage)?.strict_telemetry {
+        record_failure(
+            deps.storage,
+            &pending.player,
+            FailureReason::SendFailed,
+            env.block.time,
+        )?;
     }
 
     Ok(Response::new()
-        .add_attribute("action", "fund_treasury")
-        .add_attribute("amount", sent.amount.to_string())
-        .add_attribute("new_balance", contract_balance.to_string()))
+        .add_attribute("action", "withdraw_rolled_back")
+        .add_attribute("player", pending.player.as_str())
+        .add_attribute("nonce", pending.nonce)
+        .add_attribute("error", error))
 }
 
-pub fn execute_withdraw_treasury(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    amount: Uint128,
-) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
-    assert_owner(deps.as_ref(), &info.sender)?;
-
-    if amount.is_zero() {
-        return Err(ContractError::ZeroAmount);
+/// Settle a queued withdrawal paid out by `ProcessQueue`: pay the fee, or undo
+/// the nonce and limits so the authorization can be retried. Either way the
+/// entry leaves the queue.
+fn reply_queue_payout(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let id: u64 = from_json(&msg.payload)?;
+    let queued = dequeue_withdrawal(deps.storage, id)?;
+    let total_outgoing = queued.token_amount.saturating_add(queued.fee);
+
+    if let SubMsgResult::Err(error) = msg.result {
+        roll_back_queued(deps.storage, &queued)?;
+        if CONFIG.load(deps.storage)?.strict_telemetry {
+            record_failure(
+                deps.storage,
+                &queued.player,
+                FailureReason::SendFailed,
+                env.block.time,
+            )?;
+        }
+        return Ok(Response::new()
+            .add_attribute("action", "queued_withdraw_rolled_back")
+            .add_attribute("queue_id", id.to_string())
+            .add_attribute("player", queued.player.as_str())
+            .add_attribute("nonce", queued.nonce)
+            .add_attribute("error", error));
     }
 
     let config = CONFIG.load(deps.storage)?;
+    record_outflow(deps.storage, &queued.denom, env.block.time, total_outgoing)?;
+    let referral = accrue_referral_reward(
+        deps.storage,
+        &config,
+        &queued.player,
+        &queued.denom,
+        queued.fee,
+    )?;
+    let fees = fee_payments(
+        deps.as_ref(),
+        &config,
+        &queued.player,
+        &queued.denom,
+        queued.fee - referral,
+    )?;
+    Ok(Response::new()
+        .add_submessages(fees)
+        .add_attribute("action", "queued_withdraw_sent")
+        .add_attribute("queue_id", id.to_string())
+        .add_attribute("nonce", queued.nonce)
+        .add_event(WithdrawEvent {
+            recipient: queued.recipient.unwrap_or(queued.player).to_string(),
+            denom: queued.denom,
+            token_amount: queued.token_amount.to_string(),
+            credit_amount: queued.credit_amount.to_string(),
+            fee_amount: queued.fee.to_string(),
+        }))
+}
+
+/// A fee share the DAO rejected (e.g. the corporation was dissolved since the
+/// lookup) goes to the treasury instead.
+fn reply_dao_donation(deps: Deps, msg: Reply) -> Result<Response, ContractError> {
+    let SubMsgResult::Err(error) = msg.result else {
+        return Ok(Response::new());
+    };
+    let donation: Coin = from_json(&msg.payload)?;
+    let config = CONFIG.load(deps.storage)?;
+    Ok(Response::new()
+        .add_messages(fee_payment(
+            &config.treasury,
+            &donation.denom,
+            donation.amount,
+        ))
+        .add_attribute("action", "dao_donation_failed")
+        .add_attribute("denom", donation.denom)
+        .add_attribute("error", error))
+}
 
-    let contract_balance = deps
-        .querier
-        .query_balance(&env.contract.address, &config.denom)?
-        .amount;
+/// Record the packet sequence of a just-dispatched IBC withdrawal so the
+/// lifecycle callback can match it.
+fn reply_ibc_transfer(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let response = match msg.result {
+        SubMsgResult::Ok(response) => response,
+        SubMsgResult::Err(err) => return Err(cosmwasm_std::StdError::generic_err(err).into()),
+    };
+    #[allow(deprecated)]
+    let data = response
+        .msg_responses
+        .first()
+        .map(|r| r.value.clone())
+        .or(response.data)
+        .ok_or(ContractError::MissingIbcSequence)?;
+    let sequence = parse_transfer_sequence(&data).ok_or(ContractError::MissingIbcSequence)?;
+
+    let record = PENDING_IBC_TRANSFER.load(deps.storage)?;
+    PENDING_IBC_TRANSFER.remove(deps.storage);
+    IBC_TRANSFERS.save(deps.storage, (&record.channel_id, sequence), &record)?;
+    IBC_PENDING_TOTALS.update(deps.storage, &record.denom, |t| -> StdResult<_> {
+        let mut totals = t.unwrap_or_default();
+        totals.count += 1;
+        totals.amount = totals.amount.saturating_add(record.amount);
+        Ok(totals)
+    })?;
 
-    let remaining = contract_balance
-        .checked_sub(amount)
-        .map_err(|_| ContractError::ReserveBreached {
-            reserve_min: config.min_reserve.to_string(),
-        })?;
+    Ok(Response::new()
+        .add_attribute("action", "ibc_transfer_sent")
+        .add_attribute("channel_id", &record.channel_id)
+        .add_attribute("sequence", sequence.to_string()))
+}
 
-    if remaining < config.min_reserve {
-        return Err(ContractError::ReserveBreached {
-            reserve_min: config.min_reserve.to_string(),
-        });
-    }
+/// ibc-hooks lifecycle callback. On timeout or error ack the transfer module has
+/// already returned the tokens to this contract, so they are refunded locally.
+pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    let SudoMsg::IbcLifecycleComplete(lifecycle) = msg;
+    let (channel, sequence, refund) = match lifecycle {
+        IbcLifecycleComplete::IbcAck {
+            channel,
+            sequence,
+            success,
+            ..
+        } => (channel, sequence, !success),
+        IbcLifecycleComplete::IbcTimeout { channel, sequence } => (channel, sequence, true),
+    };
 
-    let msg = BankMsg::Send {
-        to_address: info.sender.to_string(),
-        amount: vec![Coin {
-            denom: config.denom,
-            amount,
-        }],
+    // Unknown packets (e.g. sent before an upgrade) are ignored rather than failing the hook
+    let Some(record) = IBC_TRANSFERS.may_load(deps.storage, (&channel, sequence))? else {
+        return Ok(Response::new()
+            .add_attribute("action", "ibc_lifecycle_ignored")
+            .add_attribute("channel_id", channel)
+            .add_attribute("sequence", sequence.to_string()));
     };
+    IBC_TRANSFERS.remove(deps.storage, (&channel, sequence));
+    IBC_PENDING_TOTALS.update(deps.storage, &record.denom, |t| -> StdResult<_> {
+        let mut totals = t.unwrap_or_default();
+        totals.count = totals.count.saturating_sub(1);
+        totals.amount = totals.amount.saturating_sub(record.amount);
+        Ok(totals)
+    })?;
+
+    if !refund {
+        return Ok(Response::new()
+            .add_attribute("action", "ibc_transfer_complete")
+            .add_attribute("channel_id", channel)
+            .add_attribute("sequence", sequence.to_string()));
+    }
 
     Ok(Response::new()
-        .add_message(msg)
-        .add_attribute("action", "withdraw_treasury")
-        .add_attribute("amount", amount.to_string())
-        .add_attribute("remaining", remaining.to_string()))
+        .add_message(BankMsg::Send {
+            to_address: record.player.to_string(),
+            amount: vec![Coin {
+                denom: record.denom,
+                amount: record.amount,
+            }],
+        })
+        .add_attribute("action", "ibc_transfer_refund")
+        .add_attribute("player", record.player.as_str())
+        .add_attribute("nonce", record.nonce)
+        .add_attribute("amount", record.amount.to_string())
+        .add_attribute("channel_id", channel)
+        .add_attribute("sequence", sequence.to_string()))
 }
 
-// ─── Execute: Oracle Transfer (two-step) ────────────────────────────────────
+// ─── Execute: Withdrawal Queue ──────────────────────────────────────────────
 
-pub fn execute_propose_oracle(
+/// Pay queued withdrawals in queue order. A denom stops at its first entry the
+/// treasury cannot cover so later withdrawals never overtake it; entries of
+/// frozen players, or outside the whitelist during maintenance, are passed over.
+/// The scan resumes after the entries it passed over last time, so they cannot
+/// hide the rest of the queue, and starts from the head again once it reaches
+/// the end.
+pub fn execute_process_queue(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
-    new_oracle: String,
-    new_pubkey: Binary,
+    limit: Option<u32>,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
-    assert_owner(deps.as_ref(), &info.sender)?;
-    // FIX: L-03 — validate public key
-    validate_pubkey(&new_pubkey)?;
+    reject_funds(&info)?;
 
-    if PENDING_ORACLE.may_load(deps.storage)?.is_some() {
-        return Err(ContractError::OracleTransferAlreadyPending);
+    let config = CONFIG.load(deps.storage)?;
+    if config.withdrawals_paused {
+        return Err(ContractError::WithdrawalsPaused);
     }
+    let limit = limit.unwrap_or(DEFAULT_QUEUE_BATCH).min(MAX_QUEUE_BATCH) as usize;
+    let cursor = WITHDRAWAL_QUEUE_CURSOR.may_load(deps.storage)?;
+    let queued = WITHDRAWAL_QUEUE
+        .range(
+            deps.storage,
+            cursor.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let reached_end = queued.len() < limit;
+
+    // Balance left per denom after this call's payouts; None once the denom has to wait
+    let mut balances: Vec<(String, Option<Uint128>)> = vec![];
+    let mut response = Response::new();
+    let mut dispatched = 0u32;
+    // The cursor only moves past entries that were paid or passed over; it
+    // stops at the first one left waiting on the treasury
+    let mut resume_after = cursor;
+    let mut waiting = false;
+    for (id, entry) in queued {
+        let payee = entry.recipient.as_ref().unwrap_or(&entry.player);
+        if assert_withdrawals_open(deps.as_ref(), &entry.player).is_err()
+            || assert_not_blocked(deps.as_ref(), &entry.player).is_err()
+            || assert_not_blocked(deps.as_ref(), payee).is_err()
+        {
+            if !waiting {
+                resume_after = Some(id);
+            }
+            continue;
+        }
+        let i = match balances.iter().position(|(d, _)| *d == entry.denom) {
+            Some(i) => i,
+            None => {
+                let balance = deps
+                    .querier
+                    .query_balance(&env.contract.address, &entry.denom)?
+                    .amount;
+                record_balance_sample(
+                    deps.storage,
+                    &config,
+                    &entry.denom,
+                    env.block.time,
+                    balance,
+                )?;
+                // Rewards owed to referrers are not the queue's to pay out
+                let spendable =
+                    balance.saturating_sub(referral_outstanding(deps.storage, &entry.denom)?);
+                balances.push((entry.denom.clone(), Some(spendable)));
+                balances.len() - 1
+            }
+        };
+        let Some(balance) = balances[i].1 else {
+            waiting = true;
+            continue;
+        };
+        // A removed denom has no reserve left to protect
+        let min_reserve = load_denom_config(deps.storage, &config, &entry.denom)
+            .map(|p| p.min_reserve)
+            .unwrap_or_default();
+        let remaining = balance
+            .checked_sub(entry.token_amount.saturating_add(entry.fee))
+            .ok()
+            .filter(|r| *r >= min_reserve);
+        balances[i].1 = remaining;
+        if remaining.is_none() {
+            waiting = true;
+            continue;
+        }
 
-    let proposed = deps.api.addr_validate(&new_oracle)?;
-    PENDING_ORACLE.save(
-        deps.storage,
-        &PendingOracleTransfer {
-            proposed_oracle: proposed.clone(),
-            proposed_pubkey: new_pubkey,
-        },
-    )?;
+        response = response.add_submessage(
+            SubMsg::reply_always(
+                BankMsg::Send {
+                    to_address: payee.to_string(),
+                    amount: vec![Coin {
+                        denom: entry.denom,
+                        amount: entry.token_amount,
+                    }],
+                },
+                QUEUE_PAYOUT_REPLY_ID,
+            )
+            .with_payload(to_json_binary(&id)?),
+        );
+        dispatched += 1;
+        if !waiting {
+            resume_after = Some(id);
+        }
+    }
+    match resume_after {
+        Some(id) if !reached_end => WITHDRAWAL_QUEUE_CURSOR.save(deps.storage, &id)?,
+        _ => WITHDRAWAL_QUEUE_CURSOR.remove(deps.storage),
+    }
 
-    Ok(Response::new()
-        .add_attribute("action", "propose_oracle")
-        .add_attribute("proposed_oracle", proposed.as_str()))
+    Ok(response
+        .add_attribute("action", "process_queue")
+        .add_attribute("dispatched", dispatched.to_string()))
 }
 
-pub fn execute_accept_oracle(
+pub fn execute_set_withdrawal_queue(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    enabled: bool,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
-    let pending = PENDING_ORACLE
-        .may_load(deps.storage)?
-        .ok_or(ContractError::NoOracleTransferPending)?;
-
-    if info.sender != pending.proposed_oracle {
-        return Err(ContractError::NotPendingOracle);
-    }
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
 
     CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.oracle = pending.proposed_oracle.clone();
-        c.oracle_pubkey = pending.proposed_pubkey.clone();
+        c.withdrawal_queue = enabled;
         Ok(c)
     })?;
-    PENDING_ORACLE.remove(deps.storage);
 
     Ok(Response::new()
-        .add_attribute("action", "accept_oracle")
-        .add_attribute("new_oracle", pending.proposed_oracle.as_str()))
+        .add_attribute("action", "set_withdrawal_queue")
+        .add_attribute("enabled", enabled.to_string()))
 }
 
-pub fn execute_cancel_oracle_transfer(
+/// Drop a queued withdrawal the queue cannot pay, releasing its nonce, limit
+/// usage and share of the held-back treasury.
+pub fn execute_cancel_queued_withdrawal(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    queue_id: u64,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
+    reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
 
-    if PENDING_ORACLE.may_load(deps.storage)?.is_none() {
-        return Err(ContractError::NoOracleTransferPending);
-    }
+    let queued = dequeue_withdrawal(deps.storage, queue_id)?;
+    roll_back_queued(deps.storage, &queued)?;
 
-    PENDING_ORACLE.remove(deps.storage);
-    Ok(Response::new().add_attribute("action", "cancel_oracle_transfer"))
+    Ok(Response::new()
+        .add_attribute("action", "cancel_queued_withdrawal")
+        .add_attribute("queue_id", queue_id.to_string())
+        .add_attribute("player", queued.player.as_str())
+        .add_attribute("nonce", queued.nonce))
 }
 
-// ─── Execute: Admin Config Updates ──────────────────────────────────────────
-
-pub fn execute_update_rate(
+pub fn execute_set_strict_telemetry(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    rate_credits: Uint128,
-    rate_tokens: Uint128,
+    enabled: bool,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
+    reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
 
-    if rate_credits.is_zero() || rate_tokens.is_zero() {
-        return Err(ContractError::ZeroAmount);
-    }
-
     CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.rate_credits = rate_credits;
-        c.rate_tokens = rate_tokens;
+        c.strict_telemetry = enabled;
         Ok(c)
     })?;
 
     Ok(Response::new()
-        .add_attribute("action", "update_rate")
-        .add_attribute("rate_credits", rate_credits.to_string())
-        .add_attribute("rate_tokens", rate_tokens.to_string()))
+        .add_attribute("action", "set_strict_telemetry")
+        .add_attribute("enabled", enabled.to_string()))
 }
 
-pub fn execute_update_fee(
+// ─── Execute: IBC Channel Allowlist ─────────────────────────────────────────
+
+pub fn execute_set_ibc_channel(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    fee_bps: u16,
+    channel_id: String,
+    timeout_seconds: u64,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
+    reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
 
-    if fee_bps > 10_000 {
-        return Err(ContractError::Overflow);
+    if timeout_seconds == 0 {
+        return Err(ContractError::InvalidIbcTimeout);
     }
 
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.fee_bps = fee_bps;
-        Ok(c)
-    })?;
+    IBC_CHANNELS.save(
+        deps.storage,
+        &channel_id,
+        &IbcChannelConfig { timeout_seconds },
+    )?;
 
     Ok(Response::new()
-        .add_attribute("action", "update_fee")
-        .add_attribute("fee_bps", fee_bps.to_string()))
+        .add_attribute("action", "set_ibc_channel")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("timeout_seconds", timeout_seconds.to_string()))
 }
 
-pub fn execute_update_limits(
+pub fn execute_remove_ibc_channel(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    player_daily_limit: Option<Uint128>,
-    global_daily_limit: Option<Uint128>,
+    channel_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if !IBC_CHANNELS.has(deps.storage, &channel_id) {
+        return Err(ContractError::IbcChannelNotAllowed { channel_id });
+    }
+    IBC_CHANNELS.remove(deps.storage, &channel_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_ibc_channel")
+        .add_attribute("channel_id", channel_id))
+}
+
+// ─── Execute: Session Keys ──────────────────────────────────────────────────
+
+pub fn execute_register_session_key(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pubkey: Binary,
+    expires_at: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    validate_pubkey(&pubkey)?;
+    let now = env.block.time.seconds();
+    if expires_at <= now || expires_at > now.saturating_add(MAX_SESSION_KEY_TTL) {
+        return Err(ContractError::InvalidSessionKeyExpiry {
+            max_ttl: MAX_SESSION_KEY_TTL,
+        });
+    }
+
+    SESSION_KEYS.save(
+        deps.storage,
+        &info.sender,
+        &SessionKey {
+            pubkey,
+            expires_at: Timestamp::from_seconds(expires_at),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_session_key")
+        .add_attribute("player", info.sender)
+        .add_attribute("expires_at", expires_at.to_string()))
+}
+
+pub fn execute_revoke_session_key(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    if !SESSION_KEYS.has(deps.storage, &info.sender) {
+        return Err(ContractError::NoSessionKey {
+            player: info.sender.to_string(),
+        });
+    }
+    SESSION_KEYS.remove(deps.storage, &info.sender);
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_session_key")
+        .add_attribute("player", info.sender))
+}
+
+// ─── Execute: Treasury Management ───────────────────────────────────────────
+
+pub fn execute_fund_treasury(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.funds.is_empty() {
+        return Err(ContractError::NoFundsSent);
+    }
+    if info.funds.len() > 1 {
+        return Err(ContractError::MultipleDenomsSent);
+    }
+    let sent = &info.funds[0];
+    load_denom_config(deps.storage, &config, &sent.denom)?;
+
+    // Update high-water mark
+    let contract_balance = deps
+        .querier
+        .query_balance(&env.contract.address, &sent.denom)?
+        .amount;
+    record_balance_sample(
+        deps.storage,
+        &config,
+        &sent.denom,
+        env.block.time,
+        contract_balance,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "fund_treasury")
+        .add_attribute("amount", sent.amount.to_string())
+        .add_attribute("new_balance", contract_balance.to_string())
+        .add_attribute("denom", &sent.denom))
+}
+
+pub fn execute_withdraw_treasury(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?; // FIX: M-08
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let denom = denom.unwrap_or_else(|| config.denom.clone());
+    let params = load_denom_config(deps.storage, &config, &denom)?;
+
+    let contract_balance = deps
+        .querier
+        .query_balance(&env.contract.address, &denom)?
+        .amount;
+
+    let remaining =
+        contract_balance
+            .checked_sub(amount)
+            .map_err(|_| ContractError::ReserveBreached {
+                reserve_min: params.min_reserve.to_string(),
+            })?;
+
+    // Unclaimed referral rewards belong to the referrers, queued tokens to the players
+    let outstanding = referral_outstanding(deps.storage, &denom)?;
+    let queued = QUEUED_TOTALS
+        .may_load(deps.storage, &denom)?
+        .unwrap_or_default()
+        .amount;
+    if remaining < params.min_reserve + outstanding + queued {
+        return Err(ContractError::ReserveBreached {
+            reserve_min: params.min_reserve.to_string(),
+        });
+    }
+
+    let msg = BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: vec![Coin {
+            denom: denom.clone(),
+            amount,
+        }],
+    };
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "withdraw_treasury")
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("remaining", remaining.to_string())
+        .add_attribute("denom", denom))
+}
+
+pub fn execute_sweep_foreign_funds(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    denom: String,
+    amount: Uint128,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+
+    // Bridged denoms, and removed ones still owed to referrers or queued
+    // players, are backed by reserves that a sweep would bypass
+    let config = CONFIG.load(deps.storage)?;
+    let owed = REFERRAL_OUTSTANDING
+        .may_load(deps.storage, &denom)?
+        .is_some_and(|outstanding| !outstanding.is_zero())
+        || QUEUED_TOTALS
+            .may_load(deps.storage, &denom)?
+            .is_some_and(|queued| !queued.amount.is_zero());
+    if denom == config.denom || DENOM_CONFIGS.has(deps.storage, &denom) || owed {
+        return Err(ContractError::BridgedDenom { denom });
+    }
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let msg = BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: vec![Coin {
+            denom: denom.clone(),
+            amount,
+        }],
+    };
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "sweep_foreign_funds")
+        .add_attribute("denom", denom)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("recipient", recipient))
+}
+
+// ─── Execute: Additional Denoms ─────────────────────────────────────────────
+
+pub fn execute_set_denom_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    denom_config: DenomConfig,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    if denom == config.denom {
+        return Err(ContractError::PrimaryDenomConfig { denom });
+    }
+    if denom_config.rate_credits.is_zero() || denom_config.rate_tokens.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+    if denom_config.fee_bps > 10_000 {
+        return Err(ContractError::Overflow);
+    }
+    // Only a new denom may set its rate directly; changes go through the rate timelock
+    if let Some(existing) = DENOM_CONFIGS.may_load(deps.storage, &denom)? {
+        if existing.rate_credits != denom_config.rate_credits
+            || existing.rate_tokens != denom_config.rate_tokens
+        {
+            return Err(ContractError::RateChangeRequiresTimelock { denom });
+        }
+    }
+
+    DENOM_CONFIGS.save(deps.storage, &denom, &denom_config)?;
+    record_config_change(deps.storage, &env, &info.sender, "set_denom_config", &denom)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_denom_config")
+        .add_attribute("denom", denom)
+        .add_attribute("rate_credits", denom_config.rate_credits.to_string())
+        .add_attribute("rate_tokens", denom_config.rate_tokens.to_string())
+        .add_attribute("fee_bps", denom_config.fee_bps.to_string()))
+}
+
+pub fn execute_remove_denom(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if !DENOM_CONFIGS.has(deps.storage, &denom) {
+        return Err(ContractError::UnsupportedDenom { denom });
+    }
+    DENOM_CONFIGS.remove(deps.storage, &denom);
+    record_config_change(deps.storage, &env, &info.sender, "remove_denom", &denom)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_denom")
+        .add_attribute("denom", denom))
+}
+
+// ─── Execute: Oracle Transfer (two-step) ────────────────────────────────────
+
+pub fn execute_propose_oracle(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    new_oracle: String,
+    new_pubkey: Binary,
+) -> Result<Response, ContractError> {
+    // FIX: L-03 — validate public key
+    validate_pubkey(&new_pubkey)?;
+    let proposed_oracle = deps.api.addr_validate(&new_oracle)?;
+    Ok(ORACLE_TRANSFER.propose(
+        deps,
+        &info,
+        &CONFIG,
+        PendingOracleTransfer {
+            proposed_oracle,
+            proposed_pubkey: new_pubkey,
+        },
+    )?)
+}
+
+pub fn execute_accept_oracle(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let outgoing = CONFIG.load(deps.storage)?;
+    let res = ORACLE_TRANSFER.accept(deps.branch(), &info, &CONFIG)?;
+
+    let mut config = CONFIG.load(deps.storage)?;
+    if config.oracle_pubkey == outgoing.oracle_pubkey {
+        return Ok(res);
+    }
+    // Keep the outgoing key for signatures already handed to players
+    PREVIOUS_ORACLE_KEY.save(
+        deps.storage,
+        &PreviousOracleKey {
+            version: outgoing.oracle_key_version,
+            pubkey: outgoing.oracle_pubkey,
+            rotated_at: env.block.time,
+        },
+    )?;
+    config.oracle_key_version = outgoing.oracle_key_version + 1;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(res.add_attribute("oracle_key_version", config.oracle_key_version.to_string()))
+}
+
+pub fn execute_set_oracle_key_grace(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    seconds: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+    validate_oracle_key_grace(seconds)?;
+
+    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+        c.oracle_key_grace_seconds = seconds;
+        Ok(c)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_oracle_key_grace")
+        .add_attribute("seconds", seconds.to_string()))
+}
+
+pub fn execute_cancel_oracle_transfer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(ORACLE_TRANSFER.cancel(deps, &info, &CONFIG)?)
+}
+
+// ─── Execute: Referrals ─────────────────────────────────────────────────────
+
+pub fn execute_claim_referral_rewards(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    let denom = denom.unwrap_or(config.denom);
+
+    let mut reward = REFERRAL_REWARDS
+        .may_load(deps.storage, (&info.sender, &denom))?
+        .unwrap_or_default();
+    let amount = reward.claimable;
+    if amount.is_zero() {
+        return Err(ContractError::NoReferralRewards);
+    }
+    reward.claimable = Uint128::zero();
+    REFERRAL_REWARDS.save(deps.storage, (&info.sender, &denom), &reward)?;
+    REFERRAL_OUTSTANDING.update(deps.storage, &denom, |o| -> StdResult<_> {
+        Ok(o.unwrap_or_default().saturating_sub(amount))
+    })?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                denom: denom.clone(),
+                amount,
+            }],
+        })
+        .add_attribute("action", "claim_referral_rewards")
+        .add_event(ClaimEvent {
+            recipient: info.sender.to_string(),
+            amount: amount.to_string(),
+            denom,
+        }))
+}
+
+pub fn execute_set_referral_bps(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bps: u16,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+    if bps > 10_000 {
+        return Err(ContractError::InvalidBps { bps });
+    }
+
+    let config = CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+        c.referral_bps = bps;
+        Ok(c)
+    })?;
+    record_config_change(
+        deps.storage,
+        &env,
+        &info.sender,
+        "set_referral_bps",
+        &config.denom,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_referral_bps")
+        .add_attribute("referral_bps", bps.to_string()))
+}
+
+// ─── Execute: Bonus Campaigns ───────────────────────────────────────────────
+
+pub fn execute_create_campaign(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start: u64,
+    end: u64,
+    bonus_bps: u16,
+    budget: Uint128,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if start >= end || end <= env.block.time.seconds() || end - start > MAX_CAMPAIGN_DURATION {
+        return Err(ContractError::InvalidCampaignWindow);
+    }
+    if bonus_bps == 0 || bonus_bps > 10_000 {
+        return Err(ContractError::InvalidBonusBps { value: bonus_bps });
+    }
+    if budget.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+    // Campaigns are kept in start order, so only the latest can overlap
+    let latest = BONUS_CAMPAIGNS
+        .range(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+    if let Some((id, latest)) = latest {
+        if start < latest.end.seconds() {
+            return Err(ContractError::CampaignOverlap { id });
+        }
+    }
+
+    let id = BONUS_CAMPAIGN_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    BONUS_CAMPAIGN_COUNT.save(deps.storage, &id)?;
+    BONUS_CAMPAIGNS.save(
+        deps.storage,
+        id,
+        &BonusCampaign {
+            id,
+            start: Timestamp::from_seconds(start),
+            end: Timestamp::from_seconds(end),
+            bonus_bps,
+            budget,
+            spent: Uint128::zero(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_campaign")
+        .add_attribute("campaign_id", id.to_string())
+        .add_attribute("bonus_bps", bonus_bps.to_string())
+        .add_attribute("budget", budget))
+}
+
+pub fn execute_end_campaign(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let mut campaign = BONUS_CAMPAIGNS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::CampaignNotFound { id })?;
+    if campaign.end <= env.block.time {
+        return Err(ContractError::CampaignEnded { id });
+    }
+    // Shortening keeps windows from overlapping; a scheduled campaign ends up empty
+    campaign.end = env.block.time.max(campaign.start);
+    BONUS_CAMPAIGNS.save(deps.storage, id, &campaign)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "end_campaign")
+        .add_attribute("campaign_id", id.to_string())
+        .add_attribute("spent", campaign.spent))
+}
+
+// ─── Execute: Scheduled Rate Updates ────────────────────────────────────────
+
+pub fn execute_schedule_rate_update(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: Option<String>,
+    rate_credits: Uint128,
+    rate_tokens: Uint128,
+    effective_at: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if rate_credits.is_zero() || rate_tokens.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let denom = denom.filter(|d| *d != config.denom);
+    if let Some(denom) = &denom {
+        load_denom_config(deps.storage, &config, denom)?;
+    }
+    if PENDING_RATE.may_load(deps.storage)?.is_some() {
+        return Err(ContractError::RateUpdateAlreadyPending);
+    }
+
+    let earliest = env.block.time.plus_seconds(MIN_RATE_TIMELOCK).seconds();
+    if effective_at < earliest {
+        return Err(ContractError::RateTimelockTooShort { earliest });
+    }
+
+    PENDING_RATE.save(
+        deps.storage,
+        &PendingRateUpdate {
+            denom: denom.clone(),
+            rate_credits,
+            rate_tokens,
+            effective_at: Timestamp::from_seconds(effective_at),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "schedule_rate_update")
+        .add_attribute("denom", denom.unwrap_or(config.denom))
+        .add_attribute("rate_credits", rate_credits.to_string())
+        .add_attribute("rate_tokens", rate_tokens.to_string())
+        .add_attribute("effective_at", effective_at.to_string()))
+}
+
+/// Permissionless: once the timelock has elapsed anyone may apply the new rate.
+pub fn execute_apply_scheduled_rate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let pending = PENDING_RATE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoRateUpdatePending)?;
+
+    if env.block.time < pending.effective_at {
+        return Err(ContractError::RateUpdateNotReady {
+            effective_at: pending.effective_at.seconds(),
+        });
+    }
+
+    match &pending.denom {
+        Some(denom) => {
+            // The denom may have been removed while the update was pending
+            let mut params = DENOM_CONFIGS
+                .may_load(deps.storage, denom)?
+                .ok_or_else(|| ContractError::UnsupportedDenom {
+                    denom: denom.clone(),
+                })?;
+            params.rate_credits = pending.rate_credits;
+            params.rate_tokens = pending.rate_tokens;
+            DENOM_CONFIGS.save(deps.storage, denom, &params)?;
+        }
+        None => {
+            CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+                c.rate_credits = pending.rate_credits;
+                c.rate_tokens = pending.rate_tokens;
+                Ok(c)
+            })?;
+        }
+    }
+    PENDING_RATE.remove(deps.storage);
+    let denom = match pending.denom {
+        Some(denom) => denom,
+        None => CONFIG.load(deps.storage)?.denom,
+    };
+    record_config_change(
+        deps.storage,
+        &env,
+        &info.sender,
+        "apply_scheduled_rate",
+        &denom,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "apply_scheduled_rate")
+        .add_attribute("rate_credits", pending.rate_credits.to_string())
+        .add_attribute("rate_tokens", pending.rate_tokens.to_string()))
+}
+
+pub fn execute_cancel_scheduled_rate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if PENDING_RATE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoRateUpdatePending);
+    }
+
+    PENDING_RATE.remove(deps.storage);
+    Ok(Response::new().add_attribute("action", "cancel_scheduled_rate"))
+}
+
+// ─── Execute: Player Denylist ───────────────────────────────────────────────
+
+pub fn execute_set_player_blocked(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    blocked: bool,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner_or_oracle(deps.as_ref(), &info.sender)?;
+
+    let player = deps.api.addr_validate(&address)?;
+    let reason = reason.unwrap_or_default();
+    if blocked {
+        BLOCKED_PLAYERS.save(
+            deps.storage,
+            &player,
+            &BlockedPlayer {
+                reason: reason.clone(),
+                blocked_by: info.sender.clone(),
+                blocked_at: env.block.time,
+            },
+        )?;
+    } else {
+        BLOCKED_PLAYERS.remove(deps.storage, &player);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_player_blocked")
+        .add_attribute("player", player.as_str())
+        .add_attribute("blocked", blocked.to_string())
+        .add_attribute("reason", reason)
+        .add_attribute("by", info.sender.as_str()))
+}
+
+// ─── Execute: Lifetime Caps ─────────────────────────────────────────────────
+
+pub fn execute_set_kyc_verified(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+    verified: bool,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner_or_oracle(deps.as_ref(), &info.sender)?;
+
+    let player = deps.api.addr_validate(&address)?;
+    if verified {
+        KYC_VERIFIED.save(deps.storage, &player, &true)?;
+    } else {
+        KYC_VERIFIED.remove(deps.storage, &player);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_kyc_verified")
+        .add_attribute("player", player.as_str())
+        .add_attribute("verified", verified.to_string())
+        .add_attribute("by", info.sender.as_str()))
+}
+
+pub fn execute_set_lifetime_caps(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    player_cap: Option<Uint128>,
+    kyc_cap: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // KYC may only raise the cap
+    if let Some(kyc) = kyc_cap {
+        if player_cap.map_or(true, |p| kyc < p) {
+            return Err(ContractError::InvalidLifetimeCaps);
+        }
+    }
+
+    let config = CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+        c.player_lifetime_cap = player_cap;
+        c.kyc_lifetime_cap = kyc_cap;
+        Ok(c)
+    })?;
+    record_config_change(
+        deps.storage,
+        &env,
+        &info.sender,
+        "set_lifetime_caps",
+        &config.denom,
+    )?;
+
+    let cap_attr = |cap: Option<Uint128>| cap.map_or("unlimited".to_string(), |c| c.to_string());
+    Ok(Response::new()
+        .add_attribute("action", "set_lifetime_caps")
+        .add_attribute("player_cap", cap_attr(player_cap))
+        .add_attribute("kyc_cap", cap_attr(kyc_cap)))
+}
+
+// ─── Execute: Admin Config Updates ──────────────────────────────────────────
+
+pub fn execute_update_fee(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    fee_bps: u16,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?; // FIX: M-08
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if fee_bps > 10_000 {
+        return Err(ContractError::Overflow);
+    }
+
+    let config = CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+        c.fee_bps = fee_bps;
+        Ok(c)
+    })?;
+    record_config_change(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_fee",
+        &config.denom,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_fee")
+        .add_attribute("fee_bps", fee_bps.to_string()))
+}
+
+/// Set how the primary denom's withdrawal fee is rounded and the least it
+/// may be. Additional denoms carry their own policy in their DenomConfig.
+pub fn execute_set_fee_policy(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    rounding: FeeRounding,
+    min_fee: Uint128,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let config = CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+        c.fee_rounding = rounding;
+        c.min_fee = min_fee;
+        Ok(c)
+    })?;
+    record_config_change(
+        deps.storage,
+        &env,
+        &info.sender,
+        "set_fee_policy",
+        &config.denom,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_fee_policy")
+        .add_attribute("rounding", config.fee_rounding.as_str())
+        .add_attribute("min_fee", min_fee.to_string()))
+}
+
+pub fn execute_set_achievement_discount(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    discount: Option<AchievementDiscountMsg>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let discount = match discount {
+        Some(d) => {
+            if d.discount_bps > 10_000 {
+                return Err(ContractError::InvalidBps {
+                    bps: d.discount_bps,
+                });
+            }
+            Some(AchievementDiscount {
+                achievement_contract: deps.api.addr_validate(&d.achievement_contract)?,
+                achievement_id: d.achievement_id,
+                discount_bps: d.discount_bps,
+            })
+        }
+        None => None,
+    };
+
+    let mut resp = Response::new().add_attribute("action", "set_achievement_discount");
+    if let Some(d) = &discount {
+        resp = resp
+            .add_attribute("achievement_contract", d.achievement_contract.as_str())
+            .add_attribute("achievement_id", &d.achievement_id)
+            .add_attribute("discount_bps", d.discount_bps.to_string());
+    }
+    let config = CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+        c.achievement_discount = discount;
+        Ok(c)
+    })?;
+    record_config_change(
+        deps.storage,
+        &env,
+        &info.sender,
+        "set_achievement_discount",
+        &config.denom,
+    )?;
+
+    Ok(resp)
+}
+
+/// Follow a price oracle for the primary denom. The feed must answer once
+/// here, so a misconfigured contract is caught before it silently falls back.
+pub fn execute_set_price_feed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    feed: Option<PriceFeedMsg>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let feed = match feed {
+        Some(f) => {
+            if !(1..=MAX_PRICE_STALENESS).contains(&f.max_staleness_seconds)
+                || !(1..10_000).contains(&f.max_deviation_bps)
+            {
+                return Err(ContractError::InvalidPriceFeed {
+                    max_staleness: MAX_PRICE_STALENESS,
+                });
+            }
+            let config = CONFIG.load(deps.storage)?;
+            let contract = deps.api.addr_validate(&f.contract)?;
+            let _: PriceFeedResponse = deps.querier.query_wasm_smart(
+                &contract,
+                &PriceFeedQueryMsg::Price {
+                    denom: config.denom,
+                },
+            )?;
+            Some(PriceFeed {
+                contract,
+                max_staleness_seconds: f.max_staleness_seconds,
+                max_deviation_bps: f.max_deviation_bps,
+            })
+        }
+        None => None,
+    };
+
+    let mut resp = Response::new().add_attribute("action", "set_price_feed");
+    if let Some(f) = &feed {
+        resp = resp
+            .add_attribute("contract", f.contract.as_str())
+            .add_attribute("max_staleness_seconds", f.max_staleness_seconds.to_string())
+            .add_attribute("max_deviation_bps", f.max_deviation_bps.to_string());
+    }
+    let config = CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+        c.price_feed = feed;
+        Ok(c)
+    })?;
+    record_config_change(
+        deps.storage,
+        &env,
+        &info.sender,
+        "set_price_feed",
+        &config.denom,
+    )?;
+    let rate = current_rate(
+        deps.as_ref(),
+        &env,
+        &config,
+        &config.denom,
+        &load_denom_config(deps.storage, &config, &config.denom)?,
+    );
+
+    Ok(resp
+        .add_attribute("rate_credits", rate.rate_credits.to_string())
+        .add_attribute("rate_tokens", rate.rate_tokens.to_string()))
+}
+
+/// Point the fee share at a corporation DAO. The DAO's treasury denom is read
+/// once here, so withdrawals in other denoms keep paying the treasury in full.
+pub fn execute_set_dao_fee_share(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    fee_share: Option<DaoFeeShareMsg>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let fee_share = match fee_share {
+        Some(s) => {
+            if s.dao_fee_share_bps > 10_000 {
+                return Err(ContractError::InvalidBps {
+                    bps: s.dao_fee_share_bps,
+                });
+            }
+            let dao_contract = deps.api.addr_validate(&s.dao_contract)?;
+            let dao_config: DaoConfigResponse = deps
+                .querier
+                .query_wasm_smart(&dao_contract, &DaoQueryMsg::Config {})?;
+            Some(DaoFeeShare {
+                dao_contract,
+                denom: dao_config.denom,
+                dao_fee_share_bps: s.dao_fee_share_bps,
+            })
+        }
+        None => None,
+    };
+
+    let mut resp = Response::new().add_attribute("action", "set_dao_fee_share");
+    if let Some(s) = &fee_share {
+        resp = resp
+            .add_attribute("dao_contract", s.dao_contract.as_str())
+            .add_attribute("denom", &s.denom)
+            .add_attribute("dao_fee_share_bps", s.dao_fee_share_bps.to_string());
+    }
+    // Recorded against the denom whose fees are (or were) shared
+    let previous = CONFIG.load(deps.storage)?.dao_fee_share;
+    let config = CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+        c.dao_fee_share = fee_share;
+        Ok(c)
+    })?;
+    let denom = config
+        .dao_fee_share
+        .as_ref()
+        .or(previous.as_ref())
+        .map_or(config.denom.as_str(), |s| s.denom.as_str());
+    record_config_change(deps.storage, &env, &info.sender, "set_dao_fee_share", denom)?;
+
+    Ok(resp)
+}
+
+pub fn execute_update_limits(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    player_daily_limit: Option<Uint128>,
+    global_daily_limit: Option<Uint128>,
     cooldown_seconds: Option<u64>,
     min_deposit: Option<Uint128>,
     min_reserve: Option<Uint128>,
+    peak_window_seconds: Option<u64>,
+    limit_window_seconds: Option<u64>,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?; // FIX: M-08
     assert_owner(deps.as_ref(), &info.sender)?;
+    if let Some(v) = peak_window_seconds {
+        validate_peak_window(v)?;
+    }
+    if let Some(v) = limit_window_seconds {
+        validate_limit_window(v)?;
+    }
 
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+    let config = CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
         if let Some(v) = player_daily_limit {
             c.player_daily_limit = v;
         }
@@ -535,26 +2056,82 @@ pub fn execute_update_limits(
         if let Some(v) = min_reserve {
             c.min_reserve = v;
         }
+        if let Some(v) = peak_window_seconds {
+            c.peak_window_seconds = v;
+        }
+        if let Some(v) = limit_window_seconds {
+            c.limit_window_seconds = v;
+        }
         Ok(c)
     })?;
+    record_config_change(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_limits",
+        &config.denom,
+    )?;
 
     Ok(Response::new().add_attribute("action", "update_limits"))
 }
 
+/// Set the pause flag of each selected direction. Pausing is idempotent;
+/// unpausing fails if none of the selected directions is paused.
+fn set_paused(
+    deps: DepsMut,
+    deposits: bool,
+    withdrawals: bool,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let was_paused =
+        (deposits && config.deposits_paused) || (withdrawals && config.withdrawals_paused);
+    if !paused && !was_paused {
+        return Err(AdminError::NotPaused.into());
+    }
+    if deposits {
+        config.deposits_paused = paused;
+    }
+    if withdrawals {
+        config.withdrawals_paused = paused;
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    let action = if paused { "pause" } else { "unpause" };
+    Ok(Response::new()
+        .add_attribute("action", action)
+        .add_attribute("deposits_paused", config.deposits_paused.to_string())
+        .add_attribute("withdrawals_paused", config.withdrawals_paused.to_string()))
+}
+
 pub fn execute_pause(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?; // FIX: M-08
-    assert_owner(deps.as_ref(), &info.sender)?;
+    assert_owner_or_pauser(deps.as_ref(), &info.sender)?;
+    set_paused(deps, true, true, true)
+}
 
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.paused = true;
-        Ok(c)
-    })?;
+pub fn execute_pause_deposits(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner_or_pauser(deps.as_ref(), &info.sender)?;
+    set_paused(deps, true, false, true)
+}
 
-    Ok(Response::new().add_attribute("action", "pause"))
+pub fn execute_pause_withdrawals(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner_or_pauser(deps.as_ref(), &info.sender)?;
+    set_paused(deps, false, true, true)
 }
 
 pub fn execute_unpause(
@@ -562,67 +2139,129 @@ pub fn execute_unpause(
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
+    reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
+    set_paused(deps, true, true, false)
+}
 
-    let config = CONFIG.load(deps.storage)?;
-    if !config.paused {
-        return Err(ContractError::NotPaused);
-    }
+pub fn execute_unpause_deposits(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+    set_paused(deps, true, false, false)
+}
+
+pub fn execute_unpause_withdrawals(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+    set_paused(deps, false, true, false)
+}
+
+pub fn execute_set_pauser(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    pauser: Option<String>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
 
+    let pauser = pauser.map(|p| deps.api.addr_validate(&p)).transpose()?;
     CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.paused = false;
+        c.pauser = pauser.clone();
         Ok(c)
     })?;
 
-    Ok(Response::new().add_attribute("action", "unpause"))
+    Ok(Response::new()
+        .add_attribute("action", "set_pauser")
+        .add_attribute(
+            "pauser",
+            pauser.as_ref().map(|p| p.as_str()).unwrap_or("none"),
+        ))
 }
 
-// ─── Two-Step Owner Transfer (H-04) ─────────────────────────────────────────
-
-pub fn execute_propose_owner(
+pub fn execute_set_maintenance_mode(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    new_owner: String,
+    enabled: bool,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
-    if PENDING_OWNER.may_load(deps.storage)?.is_some() {
-        return Err(ContractError::OwnerTransferAlreadyPending);
+
+    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+        c.maintenance_mode = enabled;
+        Ok(c)
+    })?;
+
+    // The whitelist only applies to the incident it was built for
+    if !enabled {
+        let whitelisted: Vec<Addr> = MAINTENANCE_WHITELIST
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for addr in whitelisted {
+            MAINTENANCE_WHITELIST.remove(deps.storage, &addr);
+        }
     }
-    let proposed = deps.api.addr_validate(&new_owner)?;
-    PENDING_OWNER.save(
-        deps.storage,
-        &PendingOwnerTransfer {
-            proposed_owner: proposed.clone(),
-        },
-    )?;
+
     Ok(Response::new()
-        .add_attribute("action", "propose_owner")
-        .add_attribute("proposed_owner", proposed.as_str()))
+        .add_attribute("action", "set_maintenance_mode")
+        .add_attribute("enabled", enabled.to_string()))
 }
 
-pub fn execute_accept_owner(
+pub fn execute_set_maintenance_whitelist(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    address: String,
+    allowed: bool,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?;
-    let pending = PENDING_OWNER
-        .may_load(deps.storage)?
-        .ok_or(ContractError::NoOwnerTransferPending)?;
-    if info.sender != pending.proposed_owner {
-        return Err(ContractError::NotPendingOwner);
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let addr = deps.api.addr_validate(&address)?;
+    if allowed {
+        MAINTENANCE_WHITELIST.save(deps.storage, &addr, &true)?;
+    } else {
+        MAINTENANCE_WHITELIST.remove(deps.storage, &addr);
     }
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.owner = pending.proposed_owner.clone();
-        Ok(c)
-    })?;
-    PENDING_OWNER.remove(deps.storage);
+
     Ok(Response::new()
-        .add_attribute("action", "accept_owner")
-        .add_attribute("new_owner", pending.proposed_owner.as_str()))
+        .add_attribute("action", "set_maintenance_whitelist")
+        .add_attribute("address", addr.as_str())
+        .add_attribute("allowed", allowed.to_string()))
+}
+
+// ─── Two-Step Owner Transfer (H-04) ─────────────────────────────────────────
+
+pub fn execute_propose_owner(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    new_owner: String,
+) -> Result<Response, ContractError> {
+    let proposed_owner = deps.api.addr_validate(&new_owner)?;
+    Ok(OWNER_TRANSFER.propose(
+        deps,
+        &info,
+        &CONFIG,
+        PendingOwnerTransfer { proposed_owner },
+    )?)
+}
+
+pub fn execute_accept_owner(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(OWNER_TRANSFER.accept(deps, &info, &CONFIG)?)
 }
 
 pub fn execute_cancel_owner_transfer(
@@ -630,13 +2269,7 @@ pub fn execute_cancel_owner_transfer(
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?;
-    assert_owner(deps.as_ref(), &info.sender)?;
-    if PENDING_OWNER.may_load(deps.storage)?.is_none() {
-        return Err(ContractError::NoOwnerTransferPending);
-    }
-    PENDING_OWNER.remove(deps.storage);
-    Ok(Response::new().add_attribute("action", "cancel_owner_transfer"))
+    Ok(OWNER_TRANSFER.cancel(deps, &info, &CONFIG)?)
 }
 
 // ─── Queries ────────────────────────────────────────────────────────────────
@@ -645,33 +2278,94 @@ pub fn query_config(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&CONFIG.load(deps.storage)?)
 }
 
-pub fn query_treasury_info(deps: Deps, env: Env) -> StdResult<Binary> {
+pub fn query_treasury_info(deps: Deps, env: Env, denom: Option<String>) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
+    let denom = denom.unwrap_or_else(|| config.denom.clone());
+    let params = load_denom_config(deps.storage, &config, &denom)
+        .map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
     let balance = deps
         .querier
-        .query_balance(&env.contract.address, &config.denom)?
+        .query_balance(&env.contract.address, &denom)?
         .amount;
-    let peak = PEAK_BALANCE.load(deps.storage)?;
-    let available = balance.saturating_sub(config.min_reserve);
+    let peak = rolling_peak_balance(deps.storage, &config, &denom, env.block.time, balance)?;
+    let queued = QUEUED_TOTALS
+        .may_load(deps.storage, &denom)?
+        .unwrap_or_default();
+    let available = balance
+        .saturating_sub(params.min_reserve)
+        .saturating_sub(queued.amount)
+        .saturating_sub(referral_outstanding(deps.storage, &denom)?);
 
     to_json_binary(&TreasuryInfoResponse {
+        denom,
         balance,
-        min_reserve: config.min_reserve,
+        min_reserve: params.min_reserve,
         peak_balance: peak,
         available_for_withdrawal: available,
     })
 }
 
-pub fn query_player_info(deps: Deps, env: Env, address: String) -> StdResult<Binary> {
-    let addr = deps.api.addr_validate(&address)?;
+pub fn query_solvency(deps: Deps, env: Env, denom: Option<String>) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
+    let denom = denom.unwrap_or_else(|| config.denom.clone());
+    let params = load_denom_config(deps.storage, &config, &denom)
+        .map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
     let now = env.block.time;
+    let balance = deps
+        .querier
+        .query_balance(&env.contract.address, &denom)?
+        .amount;
 
-    let records = PLAYER_WITHDRAWALS
-        .may_load(deps.storage, &addr)?
+    let peak_balance = rolling_peak_balance(deps.storage, &config, &denom, now, balance)?;
+    let outflow_24h = outflow_24h(deps.storage, &denom, now)?;
+    let solvency_ratio = if outflow_24h.is_zero() {
+        None
+    } else {
+        Decimal::checked_from_ratio(balance, outflow_24h).ok()
+    };
+    let pending = IBC_PENDING_TOTALS
+        .may_load(deps.storage, &denom)?
+        .unwrap_or_default();
+    let queued = QUEUED_TOTALS
+        .may_load(deps.storage, &denom)?
         .unwrap_or_default();
-    let (_active, used) = sum_rolling_window(records, now, 86_400);
-    let remaining = config.player_daily_limit.saturating_sub(used);
+
+    to_json_binary(&SolvencyResponse {
+        denom,
+        balance,
+        min_reserve: params.min_reserve,
+        peak_balance,
+        outflow_24h,
+        solvency_ratio,
+        pending_ibc_transfers: pending.count,
+        pending_ibc_amount: pending.amount,
+        queued_withdrawals: queued.count,
+        queued_amount: queued.amount,
+        reserve_breached: balance < params.min_reserve,
+    })
+}
+
+pub fn query_player_info(
+    deps: Deps,
+    env: Env,
+    address: String,
+    denom: Option<String>,
+) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+    let denom = denom.unwrap_or_else(|| config.denom.clone());
+    let params = load_denom_config(deps.storage, &config, &denom)
+        .map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
+    let now = env.block.time;
+
+    let used = player_window_usage(
+        deps.storage,
+        &addr,
+        &denom,
+        now,
+        config.limit_window_seconds,
+    )?;
+    let remaining = params.player_daily_limit.saturating_sub(used);
 
     let cooldown_until = PLAYER_LAST_WITHDRAWAL
         .may_load(deps.storage, &addr)?
@@ -679,24 +2373,233 @@ pub fn query_player_info(deps: Deps, env: Env, address: String) -> StdResult<Bin
 
     to_json_binary(&PlayerInfoResponse {
         withdrawals_24h: used,
-        daily_limit: config.player_daily_limit,
+        daily_limit: params.player_daily_limit,
         remaining_limit: remaining,
         cooldown_until,
+        limit_window_seconds: config.limit_window_seconds,
+        lifetime_withdrawn: PLAYER_LIFETIME_WITHDRAWN
+            .may_load(deps.storage, &addr)?
+            .unwrap_or_default(),
+        lifetime_cap: lifetime_cap(deps.storage, &config, &addr),
+        kyc_verified: KYC_VERIFIED.has(deps.storage, &addr),
+    })
+}
+
+pub fn query_player_blocked(deps: Deps, address: String) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    let info = BLOCKED_PLAYERS.may_load(deps.storage, &addr)?;
+    to_json_binary(&PlayerBlockedResponse {
+        blocked: info.is_some(),
+        info,
     })
 }
 
+pub fn query_blocked_players(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let players = BLOCKED_PLAYERS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(addr, info)| BlockedPlayerEntry {
+                address: addr.to_string(),
+                info,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&BlockedPlayersResponse { players })
+}
+
+pub fn query_maintenance_whitelist(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let addresses = MAINTENANCE_WHITELIST
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|addr| addr.to_string()))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&MaintenanceWhitelistResponse { addresses })
+}
+
 pub fn query_nonce_used(deps: Deps, nonce: String) -> StdResult<Binary> {
-    let used = USED_NONCES
-        .may_load(deps.storage, &nonce)?
-        .unwrap_or(false);
+    let used = nonce_used(deps.storage, &nonce)?;
     to_json_binary(&NonceUsedResponse { used })
 }
 
-pub fn query_convert_credits_to_tokens(deps: Deps, credit_amount: Uint128) -> StdResult<Binary> {
+pub fn query_validate_withdrawal(
+    deps: Deps,
+    env: Env,
+    player: String,
+    nonce: String,
+    credit_amount: Uint128,
+    token_amount: Uint128,
+    denom: Option<String>,
+) -> StdResult<Binary> {
+    let player = deps.api.addr_validate(&player)?;
+    let config = CONFIG.load(deps.storage)?;
+    let denom = denom.unwrap_or_else(|| config.denom.clone());
+
+    let mut checks = vec![
+        withdrawal_check("withdrawals_open", assert_withdrawals_open(deps, &player)),
+        withdrawal_check("not_blocked", assert_not_blocked(deps, &player)),
+    ];
+    let params = load_priced_denom_config(deps, &env, &config, &denom);
+    checks.push(withdrawal_check("denom", params.as_ref()));
+    checks.push(withdrawal_check("nonce", check_nonce(deps, &env, &nonce)));
+
+    let mut quoted = None;
+    let mut would_queue = false;
+    if let Ok(params) = &params {
+        let amount = if credit_amount.is_zero() || token_amount.is_zero() {
+            Err(ContractError::ZeroAmount)
+        } else {
+            check_withdrawal_amount(
+                deps,
+                &config,
+                params,
+                &denom,
+                &player,
+                credit_amount,
+                token_amount,
+            )
+        };
+        quoted = amount.as_ref().ok().copied();
+        checks.push(withdrawal_check("amount", amount));
+    }
+
+    checks.push(withdrawal_check(
+        "cooldown",
+        check_cooldown(deps, &env, &player, &config),
+    ));
+
+    if let Ok(params) = &params {
+        checks.push(withdrawal_check(
+            "player_limit",
+            check_player_daily_limit(deps, &env, &player, credit_amount, &config, &denom, params),
+        ));
+        checks.push(withdrawal_check(
+            "lifetime_cap",
+            check_lifetime_cap(deps, &player, credit_amount, &config),
+        ));
+        checks.push(withdrawal_check(
+            "global_limit",
+            check_global_limit(deps, &env, credit_amount, &config, &denom, params),
+        ));
+        if let Some((fee, net_tokens)) = quoted {
+            let mut reserve = net_tokens
+                .checked_add(fee)
+                .map_err(|_| ContractError::Overflow)
+                .and_then(|total| check_reserve(deps, &env, &denom, params, total))
+                .map(|_| ());
+            let queue_ahead = QUEUED_TOTALS
+                .may_load(deps.storage, &denom)?
+                .is_some_and(|t| t.count > 0);
+            if config.withdrawal_queue
+                && (queue_ahead
+                    || matches!(reserve, Err(ContractError::InsufficientTreasury { .. })))
+            {
+                would_queue = true;
+                reserve = Ok(());
+            }
+            checks.push(withdrawal_check("reserve", reserve));
+        }
+    }
+
+    to_json_binary(&ValidateWithdrawalResponse {
+        valid: checks.iter().all(|c| c.error.is_none()),
+        would_queue,
+        checks,
+    })
+}
+
+fn withdrawal_check<T, E: ToString>(check: &str, result: Result<T, E>) -> WithdrawalCheck {
+    WithdrawalCheck {
+        check: check.to_string(),
+        error: result.err().map(|e| e.to_string()),
+    }
+}
+
+pub fn query_withdrawal_queue(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let withdrawals = WITHDRAWAL_QUEUE
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, w)| w))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&WithdrawalQueueResponse { withdrawals })
+}
+
+pub fn query_config_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let changes = CONFIG_HISTORY
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, change)| change))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&ConfigHistoryResponse { changes })
+}
+
+/// Failure log entries, newest first
+pub fn query_recent_failures(deps: Deps, limit: Option<u32>) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as u64;
+    let count = FAILURE_COUNT.may_load(deps.storage)?.unwrap_or_default();
+    let failures = (1..=limit.min(count).min(FAILURE_LOG_SIZE))
+        .map(|back| FAILURE_LOG.load(deps.storage, (count - back) % FAILURE_LOG_SIZE))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&RecentFailuresResponse { failures })
+}
+
+pub fn query_session_key(deps: Deps, player: String) -> StdResult<Binary> {
+    let player = deps.api.addr_validate(&player)?;
+    to_json_binary(&SESSION_KEYS.may_load(deps.storage, &player)?)
+}
+
+pub fn query_convert_credits_to_tokens(
+    deps: Deps,
+    env: Env,
+    credit_amount: Uint128,
+    denom: Option<String>,
+    player: Option<String>,
+) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
-    let gross = credits_to_tokens(credit_amount, &config)
+    let denom = denom.unwrap_or_else(|| config.denom.clone());
+    let params = load_priced_denom_config(deps, &env, &config, &denom)
+        .map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
+    let fee_bps = match player {
+        Some(p) => withdrawal_fee_bps(deps, &config, &params, &deps.api.addr_validate(&p)?)?,
+        None => params.fee_bps,
+    };
+    let gross = credits_to_tokens(credit_amount, &params)
         .map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
-    let fee = calculate_fee(gross, config.fee_bps)
+    let fee = calculate_fee(gross, fee_bps, &params)
         .map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
     let net = gross.saturating_sub(fee);
 
@@ -707,9 +2610,17 @@ pub fn query_convert_credits_to_tokens(deps: Deps, credit_amount: Uint128) -> St
     })
 }
 
-pub fn query_convert_tokens_to_credits(deps: Deps, token_amount: Uint128) -> StdResult<Binary> {
+pub fn query_convert_tokens_to_credits(
+    deps: Deps,
+    env: Env,
+    token_amount: Uint128,
+    denom: Option<String>,
+) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
-    let credits = tokens_to_credits(token_amount, &config)
+    let denom = denom.unwrap_or_else(|| config.denom.clone());
+    let params = load_priced_denom_config(deps, &env, &config, &denom)
+        .map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
+    let credits = tokens_to_credits(token_amount, &params)
         .map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
 
     to_json_binary(&ConversionResponse {
@@ -719,38 +2630,328 @@ pub fn query_convert_tokens_to_credits(deps: Deps, token_amount: Uint128) -> Std
     })
 }
 
+pub fn query_denom_config(deps: Deps, denom: String) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let params = load_denom_config(deps.storage, &config, &denom)
+        .map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
+    to_json_binary(&params)
+}
+
+pub fn query_denoms(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let denoms = DENOM_CONFIGS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(denom, config)| DenomInfo { denom, config }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&DenomsResponse { denoms })
+}
+
 pub fn query_pending_oracle(deps: Deps) -> StdResult<Binary> {
-    to_json_binary(&PENDING_ORACLE.may_load(deps.storage)?)
+    ORACLE_TRANSFER.query_pending(deps)
+}
+
+pub fn query_oracle_keys(deps: Deps, env: Env) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let previous = PREVIOUS_ORACLE_KEY
+        .may_load(deps.storage)?
+        .map(|previous| OracleKeyInfo {
+            valid_until: Some(previous_key_expiry(&previous, &config)),
+            version: previous.version,
+            pubkey: previous.pubkey,
+        })
+        .filter(|previous| previous.valid_until > Some(env.block.time));
+    to_json_binary(&OracleKeysResponse {
+        current: OracleKeyInfo {
+            version: config.oracle_key_version,
+            pubkey: config.oracle_pubkey,
+            valid_until: None,
+        },
+        previous,
+        grace_seconds: config.oracle_key_grace_seconds,
+    })
+}
+
+pub fn query_pending_rate(deps: Deps) -> StdResult<Binary> {
+    to_json_binary(&PENDING_RATE.may_load(deps.storage)?)
+}
+
+pub fn query_exchange_rate(deps: Deps, env: Env, denom: Option<String>) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let denom = denom.unwrap_or_else(|| config.denom.clone());
+    let params = load_denom_config(deps.storage, &config, &denom)
+        .map_err(|e| cosmwasm_std::StdError::generic_err(e.to_string()))?;
+    let rate = current_rate(deps, &env, &config, &denom, &params);
+
+    to_json_binary(&ExchangeRateResponse {
+        denom,
+        rate_credits: rate.rate_credits,
+        rate_tokens: rate.rate_tokens,
+        source: rate.source,
+        feed_updated_at: rate.feed_updated_at,
+    })
+}
+
+pub fn query_ibc_channels(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let channels = IBC_CHANNELS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(channel_id, cfg)| IbcChannelInfo {
+                channel_id,
+                timeout_seconds: cfg.timeout_seconds,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&IbcChannelsResponse { channels })
+}
+
+pub fn query_ibc_transfer(deps: Deps, channel_id: String, sequence: u64) -> StdResult<Binary> {
+    to_json_binary(&IBC_TRANSFERS.may_load(deps.storage, (&channel_id, sequence))?)
+}
+
+pub fn query_deposit_receipt(deps: Deps, id: u64) -> StdResult<Binary> {
+    to_json_binary(&RECEIPTS.may_load(deps.storage, id)?)
+}
+
+pub fn query_referral_stats(deps: Deps, address: String) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    // One entry per bridged denom the referrer has earned in
+    let rewards = REFERRAL_REWARDS
+        .prefix(&addr)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            item.map(|(denom, r)| ReferralRewardEntry {
+                denom,
+                claimable: r.claimable,
+                total_earned: r.total_earned,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&ReferralStatsResponse {
+        referrer: REFERRERS
+            .may_load(deps.storage, &addr)?
+            .map(|r| r.to_string()),
+        referred_count: REFERRAL_COUNTS
+            .may_load(deps.storage, &addr)?
+            .unwrap_or_default(),
+        rewards,
+    })
+}
+
+pub fn query_bonus_campaign(deps: Deps, id: u64) -> StdResult<Binary> {
+    to_json_binary(&BONUS_CAMPAIGNS.may_load(deps.storage, id)?)
+}
+
+pub fn query_bonus_campaigns(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let campaigns = BONUS_CAMPAIGNS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, campaign)| campaign))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&BonusCampaignsResponse { campaigns })
+}
+
+pub fn query_active_bonus_campaign(deps: Deps, env: Env) -> StdResult<Binary> {
+    to_json_binary(&active_campaign(deps.storage, env.block.time)?)
 }
 
 // FIX: H-04
 pub fn query_pending_owner(deps: Deps) -> StdResult<Binary> {
-    to_json_binary(&PENDING_OWNER.may_load(deps.storage)?)
+    OWNER_TRANSFER.query_pending(deps)
+}
+
+/// Domain separators accepted for structured payloads: the current contract version,
+/// then the version before the last migration (so in-flight signatures survive it).
+fn signing_domains(deps: Deps, env: &Env, config: &Config) -> StdResult<Vec<Vec<u8>>> {
+    let mut versions = vec![CONTRACT_VERSION.to_string()];
+    if let Some(previous) = PREVIOUS_CONTRACT_VERSION.may_load(deps.storage)? {
+        if previous != CONTRACT_VERSION {
+            versions.push(previous);
+        }
+    }
+    Ok(versions
+        .iter()
+        .map(|version| {
+            domain_separator(
+                CONTRACT_NAME,
+                version,
+                &config.chain_id,
+                env.contract.address.as_str(),
+            )
+        })
+        .collect())
+}
+
+pub fn query_signing_domains(deps: Deps, env: Env) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let domain_separators = signing_domains(deps, &env, &config)?
+        .into_iter()
+        .map(Binary::from)
+        .collect();
+    to_json_binary(&SigningDomainsResponse {
+        payload_versions: vec![PAYLOAD_VERSION_LEGACY, PAYLOAD_VERSION_STRUCTURED],
+        domain_separators,
+    })
 }
 
 // ─── Migrate ────────────────────────────────────────────────────────────────
 
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    // Keep accepting structured payloads signed against the outgoing version's domain
+    let previous = get_contract_version(deps.storage)?.version;
+    if previous != CONTRACT_VERSION {
+        PREVIOUS_CONTRACT_VERSION.save(deps.storage, &previous)?;
+    }
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    // FIX: M-04 — migrate GLOBAL_WITHDRAWALS Vec to GLOBAL_WITHDRAWAL_RECORDS Map
+    // All-time peak is superseded by the rolling high-water mark in BALANCE_SAMPLES
+    PEAK_BALANCE.remove(deps.storage);
+
+    // Nonces the oracle signed before the switch to sequenced nonces stay
+    // redeemable for one expiry window
+    if !LEGACY_NONCES_UNTIL.exists(deps.storage) {
+        let until = env.block.time.plus_seconds(NONCE_EXPIRY_WINDOW);
+        LEGACY_NONCES_UNTIL.save(deps.storage, &until)?;
+    }
+
+    // A contract paused before the split stays paused in both directions
+    if LEGACY_PAUSE.load(deps.storage)?.paused == Some(true) {
+        CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
+            c.deposits_paused = true;
+            c.withdrawals_paused = true;
+            Ok(c)
+        })?;
+    }
+
     // FIX: I-02 — migrate() should be updated for future state changes
-    if let Some(old_records) = GLOBAL_WITHDRAWALS.may_load(deps.storage)? {
-        let mut counter = 0u64;
-        for record in old_records {
-            counter += 1;
-            GLOBAL_WITHDRAWAL_RECORDS.save(deps.storage, counter, &record)?;
-        }
-        GLOBAL_WD_COUNTER.save(deps.storage, &counter)?;
-        GLOBAL_WD_OLDEST.save(deps.storage, &1u64)?;
-        GLOBAL_WITHDRAWALS.remove(deps.storage);
-    } else {
-        // Ensure counters exist
-        if GLOBAL_WD_COUNTER.may_load(deps.storage)?.is_none() {
-            GLOBAL_WD_COUNTER.save(deps.storage, &0u64)?;
+    let config = CONFIG.load(deps.storage)?;
+    let now_hour = bucket_of(env.block.time);
+    let window = config.limit_window_seconds;
+
+    // Limit buckets used to live in 24-slot rings. Move them to their slot in the
+    // larger ring and drop the ones outside the window; removing everything that
+    // moves before saving keeps a moved bucket from landing on one not yet read.
+    let player_buckets: Vec<((Addr, String, u64), WithdrawalBucket)> = PLAYER_WD_BUCKETS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    let mut moved = vec![];
+    for ((player, denom, slot), bucket) in player_buckets {
+        let in_window = in_limit_window(&bucket, now_hour, window);
+        if slot != bucket.hour % LIMIT_RING_SLOTS || !in_window {
+            PLAYER_WD_BUCKETS.remove(deps.storage, (&player, &denom, slot));
+            if in_window {
+                moved.push((player, denom, bucket));
+            }
+        }
+    }
+    for (player, denom, bucket) in moved {
+        let slot = bucket.hour % LIMIT_RING_SLOTS;
+        PLAYER_WD_BUCKETS.save(deps.storage, (&player, &denom, slot), &bucket)?;
+    }
+    let global_buckets: Vec<((String, u64), WithdrawalBucket)> = GLOBAL_WD_BUCKETS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    let mut moved = vec![];
+    for ((denom, slot), bucket) in global_buckets {
+        let in_window = in_limit_window(&bucket, now_hour, window);
+        if slot != bucket.hour % LIMIT_RING_SLOTS || !in_window {
+            GLOBAL_WD_BUCKETS.remove(deps.storage, (&denom, slot));
+            if in_window {
+                moved.push((denom, bucket));
+            }
+        }
+    }
+    for (denom, bucket) in moved {
+        let slot = bucket.hour % LIMIT_RING_SLOTS;
+        GLOBAL_WD_BUCKETS.save(deps.storage, (&denom, slot), &bucket)?;
+    }
+
+    // Fold per-withdrawal records into hourly limit buckets; records outside the
+    // limit window no longer count and are dropped.
+    let as_bucket = |r: &WithdrawalRecord| WithdrawalBucket {
+        hour: bucket_of(r.timestamp),
+        amount_credits: r.amount_credits,
+    };
+
+    let players: Vec<(Addr, Vec<WithdrawalRecord>)> = PLAYER_WITHDRAWALS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (player, records) in players {
+        for r in &records {
+            // Records without a denom predate multi-denom support (primary denom)
+            let bucket = as_bucket(r);
+            if !in_limit_window(&bucket, now_hour, window) {
+                continue;
+            }
+            let denom = r.denom.as_deref().unwrap_or(&config.denom);
+            let slot = bucket.hour % LIMIT_RING_SLOTS;
+            PLAYER_WD_BUCKETS.update(
+                deps.storage,
+                (&player, denom, slot),
+                |b| -> StdResult<_> { Ok(accumulate(b, bucket.hour, bucket.amount_credits)) },
+            )?;
         }
-        if GLOBAL_WD_OLDEST.may_load(deps.storage)?.is_none() {
-            GLOBAL_WD_OLDEST.save(deps.storage, &0u64)?;
+        PLAYER_WITHDRAWALS.remove(deps.storage, &player);
+    }
+
+    // FIX: M-04 — both the original Vec and the later Map-based global records
+    let mut global = GLOBAL_WITHDRAWALS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let indexed: Vec<(u64, WithdrawalRecord)> = GLOBAL_WITHDRAWAL_RECORDS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (idx, record) in indexed {
+        GLOBAL_WITHDRAWAL_RECORDS.remove(deps.storage, idx);
+        global.push(record);
+    }
+    for r in &global {
+        let bucket = as_bucket(r);
+        if !in_limit_window(&bucket, now_hour, window) {
+            continue;
+        }
+        let denom = r.denom.as_deref().unwrap_or(&config.denom);
+        let slot = bucket.hour % LIMIT_RING_SLOTS;
+        GLOBAL_WD_BUCKETS.update(deps.storage, (denom, slot), |b| -> StdResult<_> {
+            Ok(accumulate(b, bucket.hour, bucket.amount_credits))
+        })?;
+    }
+    GLOBAL_WITHDRAWALS.remove(deps.storage);
+    GLOBAL_WD_COUNTER.remove(deps.storage);
+    GLOBAL_WD_OLDEST.remove(deps.storage);
+
+    // Start the parameter history from what is in force today
+    if !CONFIG_HISTORY_COUNT.exists(deps.storage) {
+        let denoms = DENOM_CONFIGS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for denom in std::iter::once(config.denom.clone()).chain(denoms) {
+            record_config_change(deps.storage, &env, &config.owner, "migrate", &denom)?;
         }
     }
 
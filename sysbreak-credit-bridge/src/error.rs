@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use sysbreak_common::AdminError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -6,23 +7,23 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    Admin(#[from] AdminError),
+
     #[error("unauthorized: only {role} can perform this action")]
     Unauthorized { role: String },
 
-    #[error("contract is paused")]
-    Paused,
-
-    #[error("contract is not paused")]
-    NotPaused,
+    #[error("player {address} is blocked: {reason}")]
+    PlayerBlocked { address: String, reason: String },
 
-    #[error("no oracle transfer pending")]
-    NoOracleTransferPending,
+    #[error("contract is in maintenance mode")]
+    MaintenanceMode,
 
-    #[error("caller is not the pending oracle")]
-    NotPendingOracle,
+    #[error("deposits are paused")]
+    DepositsPaused,
 
-    #[error("oracle transfer already pending")]
-    OracleTransferAlreadyPending,
+    #[error("withdrawals are paused")]
+    WithdrawalsPaused,
 
     #[error("deposit amount below minimum of {min} ushido")]
     DepositBelowMinimum { min: String },
@@ -36,6 +37,18 @@ pub enum ContractError {
     #[error("wrong denomination: expected {expected}, got {got}")]
     WrongDenom { expected: String, got: String },
 
+    #[error("denom {denom} is not supported by the bridge")]
+    UnsupportedDenom { denom: String },
+
+    #[error("{denom} is the primary denom; use the primary config messages")]
+    PrimaryDenomConfig { denom: String },
+
+    #[error("{denom} is held by the bridge; withdraw it with WithdrawTreasury")]
+    BridgedDenom { denom: String },
+
+    #[error("rate changes for {denom} must go through ScheduleRateUpdate")]
+    RateChangeRequiresTimelock { denom: String },
+
     #[error("withdrawal nonce {nonce} has already been used")]
     NonceAlreadyUsed { nonce: String },
 
@@ -66,6 +79,16 @@ pub enum ContractError {
         limit: String,
     },
 
+    #[error("withdrawal exceeds player lifetime cap: {used} + {requested} > {cap} credits")]
+    LifetimeCapExceeded {
+        used: String,
+        requested: String,
+        cap: String,
+    },
+
+    #[error("KYC lifetime cap must not be below the cap for players without KYC")]
+    InvalidLifetimeCaps,
+
     #[error("withdrawal cooldown active: next withdrawal available at {available_at}")]
     CooldownActive { available_at: String },
 
@@ -79,21 +102,88 @@ pub enum ContractError {
     #[error("treasury withdrawal would breach minimum reserve of {reserve_min}")]
     ReserveBreached { reserve_min: String },
 
+    #[error("invalid peak window: {value} seconds (must be between 1 hour and 30 days)")]
+    InvalidPeakWindow { value: u64 },
+
+    #[error(
+        "invalid limit window: {value} seconds (must be whole hours between 1 hour and 7 days)"
+    )]
+    InvalidLimitWindow { value: u64 },
+
+    #[error("invalid oracle key grace: {value} seconds (must be at most 7 days)")]
+    InvalidOracleKeyGrace { value: u64 },
+
+    #[error("invalid price feed: staleness must be 1..={max_staleness} seconds and deviation 1..10000 bps")]
+    InvalidPriceFeed { max_staleness: u64 },
+
+    #[error("invalid campaign window: must end after it starts, in the future, within 30 days")]
+    InvalidCampaignWindow,
+
+    #[error("invalid bonus: {value} bps (must be 1..=10000)")]
+    InvalidBonusBps { value: u16 },
+
+    #[error("invalid bps: {bps} (must be at most 10000)")]
+    InvalidBps { bps: u16 },
+
+    #[error("campaign overlaps campaign {id}")]
+    CampaignOverlap { id: u64 },
+
+    #[error("campaign {id} not found")]
+    CampaignNotFound { id: u64 },
+
+    #[error("campaign {id} has already ended")]
+    CampaignEnded { id: u64 },
+
+    #[error("queued withdrawal {id} not found")]
+    QueuedWithdrawalNotFound { id: u64 },
+
+    #[error("a player cannot refer themselves")]
+    SelfReferral,
+
+    #[error("no referral rewards to claim")]
+    NoReferralRewards,
+
     #[error("zero amount not allowed")]
     ZeroAmount,
 
     #[error("overflow in arithmetic operation")]
     Overflow,
 
-    // FIX: H-04 — two-step owner transfer errors
-    #[error("no owner transfer pending")]
-    NoOwnerTransferPending,
+    #[error("no rate update scheduled")]
+    NoRateUpdatePending,
+
+    #[error("rate update already scheduled")]
+    RateUpdateAlreadyPending,
+
+    #[error("rate update must take effect at or after {earliest}")]
+    RateTimelockTooShort { earliest: u64 },
+
+    #[error("scheduled rate not effective until {effective_at}")]
+    RateUpdateNotReady { effective_at: u64 },
 
-    #[error("caller is not the pending owner")]
-    NotPendingOwner,
+    #[error("IBC channel {channel_id} is not allowed")]
+    IbcChannelNotAllowed { channel_id: String },
 
-    #[error("owner transfer already pending")]
-    OwnerTransferAlreadyPending,
+    #[error("invalid IBC timeout: must be greater than zero")]
+    InvalidIbcTimeout,
+
+    #[error("unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("IBC transfer response did not contain a packet sequence")]
+    MissingIbcSequence,
+
+    #[error("no session key registered for {player}")]
+    NoSessionKey { player: String },
+
+    #[error("session key expired at {expired_at}")]
+    SessionKeyExpired { expired_at: u64 },
+
+    #[error("session key expiry must be in the future and at most {max_ttl} seconds ahead")]
+    InvalidSessionKeyExpiry { max_ttl: u64 },
+
+    #[error("invalid session key signature")]
+    InvalidSessionSignature,
 
     // FIX: L-03 — invalid public key length
     #[error("invalid public key length: {length} bytes (expected 33 compressed or 65 uncompressed)")]
@@ -103,10 +193,15 @@ pub enum ContractError {
     #[error("nonce has expired (older than {window} seconds)")]
     NonceExpired { window: u64 },
 
-    #[error("invalid nonce format: expected 'timestamp:random'")]
+    #[error("invalid nonce format: expected 'seq:bucket:sequence'")]
     InvalidNonceFormat,
 
-    // FIX: M-08 — reject unexpected funds
-    #[error("unexpected funds sent with this message")]
-    UnexpectedFunds,
+    #[error("legacy 'timestamp:random' nonces are no longer accepted")]
+    LegacyNonceRetired,
+
+    #[error("unsupported withdrawal payload version {version}")]
+    UnsupportedPayloadVersion { version: u8 },
+
+    #[error("invalid withdrawal recipient: {reason}")]
+    InvalidRecipient { reason: String },
 }
@@ -1,64 +1,506 @@
-use cosmwasm_std::{Addr, Binary, Deps, Env, MessageInfo, Timestamp, Uint128};
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, BankMsg, Binary, Coin, Decimal, Deps, Env, Order, StdResult,
+    Storage, SubMsg, Timestamp, Uint128, WasmMsg,
+};
+use cw_storage_plus::Bound;
 use sha2::{Digest, Sha256};
+use std::ops::RangeInclusive;
 
 use crate::error::ContractError;
+use crate::msg::{
+    AchievementQueryMsg, DaoCorporationsResponse, DaoExecuteMsg, DaoQueryMsg,
+    HasAchievementResponse, PriceFeedQueryMsg, PriceFeedResponse, RateSource,
+};
 use crate::state::{
-    Config, WithdrawalRecord, CONFIG, GLOBAL_WITHDRAWAL_RECORDS, GLOBAL_WD_COUNTER,
-    GLOBAL_WD_OLDEST, NONCE_EXPIRY_WINDOW, PLAYER_LAST_WITHDRAWAL, PLAYER_WITHDRAWALS,
+    BonusCampaign, Config, ConfigChange, DenomConfig, FailureReason, FailureRecord, FeeRounding,
+    PreviousOracleKey, QueuedWithdrawal, WithdrawalBucket, BALANCE_SAMPLES, BLOCKED_PLAYERS,
+    BONUS_CAMPAIGNS, BUCKET_SECONDS, CONFIG, CONFIG_HISTORY, CONFIG_HISTORY_COUNT,
+    DAO_DONATION_REPLY_ID, DENOM_CONFIGS, FAILED_NONCES, FAILURE_COUNT, FAILURE_LOG,
+    FAILURE_LOG_SIZE, GLOBAL_WD_BUCKETS, KYC_VERIFIED, LEGACY_NONCES_UNTIL, LIMIT_RING_SLOTS,
+    MAINTENANCE_WHITELIST, MAX_LIMIT_WINDOW, MAX_ORACLE_KEY_GRACE, MAX_PEAK_WINDOW, NONCE_BITMAPS,
+    NONCE_BUCKET_SECONDS, NONCE_EXPIRY_WINDOW, OUTFLOW_BUCKETS, PLAYER_LAST_WITHDRAWAL,
+    PLAYER_LIFETIME_WITHDRAWN, PLAYER_WD_BUCKETS, PREVIOUS_ORACLE_KEY, QUEUED_TOTALS,
+    REFERRAL_OUTSTANDING, REFERRAL_REWARDS, REFERRERS, USED_NONCES, WITHDRAWAL_QUEUE,
 };
 
+/// Re-exported so handlers keep importing it alongside the other guards.
+pub use sysbreak_common::reject_funds;
+
 pub fn assert_owner(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    Ok(sysbreak_common::assert_owner(deps, &CONFIG, sender)?)
+}
+
+pub fn assert_owner_or_oracle(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if *sender != config.owner {
+    if *sender != config.owner && *sender != config.oracle {
         return Err(ContractError::Unauthorized {
-            role: "owner".to_string(),
+            role: "owner or oracle".to_string(),
         });
     }
     Ok(())
 }
 
-pub fn assert_not_paused(deps: Deps) -> Result<(), ContractError> {
+pub fn assert_owner_or_pauser(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if config.paused {
-        return Err(ContractError::Paused);
+    if *sender != config.owner && config.pauser.as_ref() != Some(sender) {
+        return Err(ContractError::Unauthorized {
+            role: "owner or pauser".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Rejects when deposits are paused or in maintenance mode.
+pub fn assert_deposits_open(deps: Deps) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.deposits_paused {
+        return Err(ContractError::DepositsPaused);
+    }
+    if config.maintenance_mode {
+        return Err(ContractError::MaintenanceMode);
+    }
+    Ok(())
+}
+
+/// Rejects when withdrawals are paused; in maintenance mode only whitelisted
+/// addresses may withdraw.
+pub fn assert_withdrawals_open(deps: Deps, player: &Addr) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.withdrawals_paused {
+        return Err(ContractError::WithdrawalsPaused);
+    }
+    if config.maintenance_mode && !MAINTENANCE_WHITELIST.has(deps.storage, player) {
+        return Err(ContractError::MaintenanceMode);
+    }
+    Ok(())
+}
+
+pub fn assert_not_blocked(deps: Deps, player: &Addr) -> Result<(), ContractError> {
+    if let Some(blocked) = BLOCKED_PLAYERS.may_load(deps.storage, player)? {
+        return Err(ContractError::PlayerBlocked {
+            address: player.to_string(),
+            reason: blocked.reason,
+        });
+    }
+    Ok(())
+}
+
+/// Resolve the bridging parameters for `denom`: the primary denom from Config,
+/// anything else from DENOM_CONFIGS.
+pub fn load_denom_config(
+    storage: &dyn Storage,
+    config: &Config,
+    denom: &str,
+) -> Result<DenomConfig, ContractError> {
+    if denom == config.denom {
+        return Ok(DenomConfig {
+            rate_credits: config.rate_credits,
+            rate_tokens: config.rate_tokens,
+            fee_bps: config.fee_bps,
+            min_deposit: config.min_deposit,
+            player_daily_limit: config.player_daily_limit,
+            global_daily_limit: config.global_daily_limit,
+            min_reserve: config.min_reserve,
+            fee_rounding: config.fee_rounding.clone(),
+            min_fee: config.min_fee,
+        });
+    }
+    DENOM_CONFIGS
+        .may_load(storage, denom)?
+        .ok_or_else(|| ContractError::UnsupportedDenom {
+            denom: denom.to_string(),
+        })
+}
+
+/// Append the parameters now in force for `denom` to CONFIG_HISTORY. Call
+/// after the change is saved.
+pub fn record_config_change(
+    storage: &mut dyn Storage,
+    env: &Env,
+    changed_by: &Addr,
+    action: &str,
+    denom: &str,
+) -> Result<(), ContractError> {
+    let config = CONFIG.load(storage)?;
+    let params = match load_denom_config(storage, &config, denom) {
+        Ok(params) => Some(params),
+        Err(ContractError::UnsupportedDenom { .. }) => None,
+        Err(e) => return Err(e),
+    };
+    let id = CONFIG_HISTORY_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    CONFIG_HISTORY_COUNT.save(storage, &id)?;
+    let change = ConfigChange {
+        id,
+        changed_at: env.block.time,
+        changed_by: changed_by.clone(),
+        action: action.to_string(),
+        denom: denom.to_string(),
+        params,
+        cooldown_seconds: config.cooldown_seconds,
+        limit_window_seconds: config.limit_window_seconds,
+        player_lifetime_cap: config.player_lifetime_cap,
+        kyc_lifetime_cap: config.kyc_lifetime_cap,
+        achievement_discount: config.achievement_discount,
+        referral_bps: config.referral_bps,
+        dao_fee_share: config.dao_fee_share,
+        price_feed: config.price_feed,
+    };
+    CONFIG_HISTORY.save(storage, id, &change)?;
+    Ok(())
+}
+
+/// `load_denom_config` with the rate deposits and withdrawals use right now
+pub fn load_priced_denom_config(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    denom: &str,
+) -> Result<DenomConfig, ContractError> {
+    let mut params = load_denom_config(deps.storage, config, denom)?;
+    let rate = current_rate(deps, env, config, denom, &params);
+    params.rate_credits = rate.rate_credits;
+    params.rate_tokens = rate.rate_tokens;
+    Ok(params)
+}
+
+/// Whether `denom`'s rate follows the configured price feed
+pub fn follows_price_feed(config: &Config, denom: &str) -> bool {
+    config.price_feed.is_some() && denom == config.denom
+}
+
+/// A denom's conversion rate at the current block
+pub struct CurrentRate {
+    pub rate_credits: Uint128,
+    pub rate_tokens: Uint128,
+    pub source: RateSource,
+    pub feed_updated_at: Option<u64>,
+}
+
+/// The primary denom follows the price feed while its reading is fresh,
+/// clamped to `max_deviation_bps` either side of the static rate. Anything
+/// else, or a feed that is stale, fails to answer or reports zero, uses the
+/// static rate in `params`.
+pub fn current_rate(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    denom: &str,
+    params: &DenomConfig,
+) -> CurrentRate {
+    let static_rate = CurrentRate {
+        rate_credits: params.rate_credits,
+        rate_tokens: params.rate_tokens,
+        source: RateSource::Static,
+        feed_updated_at: None,
+    };
+    let Some(feed) = config.price_feed.as_ref().filter(|_| denom == config.denom) else {
+        return static_rate;
+    };
+    let Ok(reading) = deps.querier.query_wasm_smart::<PriceFeedResponse>(
+        &feed.contract,
+        &PriceFeedQueryMsg::Price {
+            denom: denom.to_string(),
+        },
+    ) else {
+        return static_rate;
+    };
+    let age = env.block.time.seconds().saturating_sub(reading.updated_at);
+    if reading.price.is_zero() || age > feed.max_staleness_seconds {
+        return static_rate;
+    }
+
+    // Band of max_deviation_bps around the static price (tokens per credit)
+    let Ok(reference) = Decimal::checked_from_ratio(params.rate_tokens, params.rate_credits) else {
+        return static_rate;
+    };
+    let band = Decimal::from_ratio(feed.max_deviation_bps, 10_000u16);
+    let lower = reference * (Decimal::one() - band);
+    let Ok(upper) = reference.checked_mul(Decimal::one() + band) else {
+        return static_rate;
+    };
+    let price = reading.price.clamp(lower, upper);
+    if price.is_zero() {
+        return static_rate;
+    }
+
+    CurrentRate {
+        rate_credits: Decimal::one().atomics(),
+        rate_tokens: price.atomics(),
+        source: if price == reading.price {
+            RateSource::Feed
+        } else {
+            RateSource::FeedClamped
+        },
+        feed_updated_at: Some(reading.updated_at),
+    }
+}
+
+/// Withdrawal fee for `player`: the denom's fee_bps, less the achievement
+/// discount if one is configured and the player holds the achievement.
+pub fn withdrawal_fee_bps(
+    deps: Deps,
+    config: &Config,
+    params: &DenomConfig,
+    player: &Addr,
+) -> StdResult<u16> {
+    let Some(discount) = &config.achievement_discount else {
+        return Ok(params.fee_bps);
+    };
+    let res: HasAchievementResponse = deps.querier.query_wasm_smart(
+        &discount.achievement_contract,
+        &AchievementQueryMsg::HasAchievement {
+            owner: player.to_string(),
+            achievement_id: discount.achievement_id.clone(),
+        },
+    )?;
+    if res.has_achievement {
+        Ok(params.fee_bps.saturating_sub(discount.discount_bps))
+    } else {
+        Ok(params.fee_bps)
+    }
+}
+
+pub fn bucket_of(time: Timestamp) -> u64 {
+    time.seconds() / BUCKET_SECONDS
+}
+
+/// First bucket that still falls inside a window of `window_seconds` ending at `now`.
+fn window_start_bucket(now: Timestamp, window_seconds: u64) -> u64 {
+    bucket_of(now.minus_seconds(window_seconds))
+}
+
+pub fn validate_peak_window(seconds: u64) -> Result<(), ContractError> {
+    if !(BUCKET_SECONDS..=MAX_PEAK_WINDOW).contains(&seconds) {
+        return Err(ContractError::InvalidPeakWindow { value: seconds });
+    }
+    Ok(())
+}
+
+pub fn validate_oracle_key_grace(seconds: u64) -> Result<(), ContractError> {
+    if seconds > MAX_ORACLE_KEY_GRACE {
+        return Err(ContractError::InvalidOracleKeyGrace { value: seconds });
+    }
+    Ok(())
+}
+
+/// The campaign whose window contains `now`, if any. Campaigns are stored in
+/// start order, so only those scheduled after `now` are skipped.
+pub fn active_campaign(storage: &dyn Storage, now: Timestamp) -> StdResult<Option<BonusCampaign>> {
+    for item in BONUS_CAMPAIGNS.range(storage, None, None, Order::Descending) {
+        let (_, campaign) = item?;
+        if campaign.start <= now {
+            return Ok((now < campaign.end).then_some(campaign));
+        }
+    }
+    Ok(None)
+}
+
+/// Bonus credits `campaign` grants on a deposit worth `credits`, limited to
+/// what is left of its budget
+pub fn campaign_bonus(campaign: &BonusCampaign, credits: Uint128) -> Uint128 {
+    credits
+        .multiply_ratio(campaign.bonus_bps, 10_000u128)
+        .min(campaign.budget.saturating_sub(campaign.spent))
+}
+
+/// Oracle keys that may sign a withdrawal now, as (version, pubkey): the
+/// current key, then the previous one while its grace window is open.
+pub fn oracle_keys(
+    storage: &dyn Storage,
+    env: &Env,
+    config: &Config,
+) -> StdResult<Vec<(u32, Binary)>> {
+    let mut keys = vec![(config.oracle_key_version, config.oracle_pubkey.clone())];
+    if let Some(previous) = PREVIOUS_ORACLE_KEY.may_load(storage)? {
+        if env.block.time < previous_key_expiry(&previous, config) {
+            keys.push((previous.version, previous.pubkey));
+        }
+    }
+    Ok(keys)
+}
+
+pub fn previous_key_expiry(previous: &PreviousOracleKey, config: &Config) -> Timestamp {
+    previous
+        .rotated_at
+        .plus_seconds(config.oracle_key_grace_seconds)
+}
+
+pub fn validate_limit_window(seconds: u64) -> Result<(), ContractError> {
+    if !(BUCKET_SECONDS..=MAX_LIMIT_WINDOW).contains(&seconds) || seconds % BUCKET_SECONDS != 0 {
+        return Err(ContractError::InvalidLimitWindow { value: seconds });
     }
     Ok(())
 }
 
+/// Record an observed treasury balance in the current hourly bucket and prune
+/// up to 10 buckets that have fallen out of the high-water mark window.
+pub fn record_balance_sample(
+    storage: &mut dyn Storage,
+    config: &Config,
+    denom: &str,
+    now: Timestamp,
+    balance: Uint128,
+) -> Result<(), ContractError> {
+    let bucket = bucket_of(now);
+    let current = BALANCE_SAMPLES
+        .may_load(storage, (denom, bucket))?
+        .unwrap_or_default();
+    if balance > current {
+        BALANCE_SAMPLES.save(storage, (denom, bucket), &balance)?;
+    }
+
+    let cutoff = window_start_bucket(now, config.peak_window_seconds);
+    let expired: Vec<u64> = BALANCE_SAMPLES
+        .prefix(denom)
+        .keys(
+            storage,
+            None,
+            Some(Bound::exclusive(cutoff)),
+            Order::Ascending,
+        )
+        .take(10)
+        .collect::<StdResult<_>>()?;
+    for old in expired {
+        BALANCE_SAMPLES.remove(storage, (denom, old));
+    }
+    Ok(())
+}
+
+/// Rolling high-water mark: the highest sampled balance within the configured
+/// window, never lower than the current balance.
+pub fn rolling_peak_balance(
+    storage: &dyn Storage,
+    config: &Config,
+    denom: &str,
+    now: Timestamp,
+    current_balance: Uint128,
+) -> StdResult<Uint128> {
+    let start = window_start_bucket(now, config.peak_window_seconds);
+    BALANCE_SAMPLES
+        .prefix(denom)
+        .range(
+            storage,
+            Some(Bound::inclusive(start)),
+            None,
+            Order::Ascending,
+        )
+        .try_fold(current_balance, |peak, item| {
+            item.map(|(_, sample)| peak.max(sample))
+        })
+}
+
+/// Add tokens paid out of the treasury to the current hourly outflow bucket.
+pub fn record_outflow(
+    storage: &mut dyn Storage,
+    denom: &str,
+    now: Timestamp,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let bucket = bucket_of(now);
+    let current = OUTFLOW_BUCKETS
+        .may_load(storage, (denom, bucket))?
+        .unwrap_or_default();
+    OUTFLOW_BUCKETS.save(storage, (denom, bucket), &current.saturating_add(amount))?;
+
+    let cutoff = window_start_bucket(now, 86_400);
+    let expired: Vec<u64> = OUTFLOW_BUCKETS
+        .prefix(denom)
+        .keys(
+            storage,
+            None,
+            Some(Bound::exclusive(cutoff)),
+            Order::Ascending,
+        )
+        .take(10)
+        .collect::<StdResult<_>>()?;
+    for old in expired {
+        OUTFLOW_BUCKETS.remove(storage, (denom, old));
+    }
+    Ok(())
+}
+
+/// Take a rolled-back payout back out of the current hourly outflow bucket.
+pub fn unrecord_outflow(
+    storage: &mut dyn Storage,
+    denom: &str,
+    time: Timestamp,
+    amount: Uint128,
+) -> StdResult<()> {
+    let bucket = bucket_of(time);
+    if let Some(current) = OUTFLOW_BUCKETS.may_load(storage, (denom, bucket))? {
+        OUTFLOW_BUCKETS.save(storage, (denom, bucket), &current.saturating_sub(amount))?;
+    }
+    Ok(())
+}
+
+/// Tokens paid out of the treasury over the last 24 hours (hourly granularity).
+pub fn outflow_24h(storage: &dyn Storage, denom: &str, now: Timestamp) -> StdResult<Uint128> {
+    let start = window_start_bucket(now, 86_400);
+    OUTFLOW_BUCKETS
+        .prefix(denom)
+        .range(
+            storage,
+            Some(Bound::inclusive(start)),
+            None,
+            Order::Ascending,
+        )
+        .try_fold(Uint128::zero(), |total, item| {
+            item.map(|(_, amount)| total.saturating_add(amount))
+        })
+}
+
 /// Convert credit amount to gross token amount (before fees) using the stored rate.
 /// credits / rate_credits * rate_tokens = tokens
 /// We use: tokens = credits * rate_tokens / rate_credits (checked math)
-pub fn credits_to_tokens(credits: Uint128, config: &Config) -> Result<Uint128, ContractError> {
+pub fn credits_to_tokens(credits: Uint128, params: &DenomConfig) -> Result<Uint128, ContractError> {
     credits
-        .checked_mul(config.rate_tokens)
+        .checked_mul(params.rate_tokens)
         .map_err(|_| ContractError::Overflow)?
-        .checked_div(config.rate_credits)
+        .checked_div(params.rate_credits)
         .map_err(|_| ContractError::Overflow)
 }
 
 /// Convert token amount to credit amount using the stored rate.
 /// tokens / rate_tokens * rate_credits = credits
-pub fn tokens_to_credits(tokens: Uint128, config: &Config) -> Result<Uint128, ContractError> {
+pub fn tokens_to_credits(tokens: Uint128, params: &DenomConfig) -> Result<Uint128, ContractError> {
     tokens
-        .checked_mul(config.rate_credits)
+        .checked_mul(params.rate_credits)
         .map_err(|_| ContractError::Overflow)?
-        .checked_div(config.rate_tokens)
+        .checked_div(params.rate_tokens)
         .map_err(|_| ContractError::Overflow)
 }
 
 /// Calculate fee amount in tokens from a gross token amount.
-/// fee = amount * fee_bps / 10_000
-pub fn calculate_fee(amount: Uint128, fee_bps: u16) -> Result<Uint128, ContractError> {
-    amount
+/// fee = amount * fee_bps / 10_000, rounded per the denom's policy and raised
+/// to its min_fee, but never more than `amount`. A zero fee_bps stays free.
+pub fn calculate_fee(
+    amount: Uint128,
+    fee_bps: u16,
+    params: &DenomConfig,
+) -> Result<Uint128, ContractError> {
+    if fee_bps == 0 {
+        return Ok(Uint128::zero());
+    }
+    let scaled = amount
         .checked_mul(Uint128::from(fee_bps as u128))
+        .map_err(|_| ContractError::Overflow)?;
+    let bias = match params.fee_rounding {
+        FeeRounding::Floor => 0u128,
+        FeeRounding::Ceil => 9_999,
+        FeeRounding::HalfUp => 5_000,
+    };
+    let fee = scaled
+        .checked_add(Uint128::from(bias))
         .map_err(|_| ContractError::Overflow)?
         .checked_div(Uint128::from(10_000u128))
-        .map_err(|_| ContractError::Overflow)
+        .map_err(|_| ContractError::Overflow)?;
+    Ok(fee.max(params.min_fee).min(amount))
 }
 
 /// Build the canonical message that the oracle must sign for a withdrawal.
 /// Format: "withdraw:{chain_id}:{contract_addr}:{nonce}:{player}:{credit_amount}:{token_amount}"
-/// This prevents replay across chains, contracts, and nonces.
+/// Withdrawals of a non-primary denom append ":{denom}".
+/// This prevents replay across chains, contracts, nonces, and denoms.
 pub fn build_withdrawal_message(
     chain_id: &str,
     contract_addr: &str,
@@ -66,37 +508,385 @@ pub fn build_withdrawal_message(
     player: &str,
     credit_amount: Uint128,
     token_amount: Uint128,
+    denom: Option<&str>,
 ) -> Vec<u8> {
-    let msg = format!(
+    let mut msg = format!(
         "withdraw:{}:{}:{}:{}:{}:{}",
         chain_id, contract_addr, nonce, player, credit_amount, token_amount
     );
+    if let Some(denom) = denom {
+        msg.push(':');
+        msg.push_str(denom);
+    }
     // SHA-256 hash — secp256k1_verify expects a 32-byte message hash
     let mut hasher = Sha256::new();
     hasher.update(msg.as_bytes());
     hasher.finalize().to_vec()
 }
 
-/// Sum withdrawal amounts within a rolling 24h window, pruning expired entries.
-/// Returns (pruned_records, total_in_window).
-pub fn sum_rolling_window(
-    records: Vec<WithdrawalRecord>,
+/// SHA-256 over "deposit:{chain_id}:{contract}:{id}:{player}:{denom}:{token_amount}:{credit_amount}:{height}:{time}".
+/// Binding the chain and contract lets a receipt be checked against this deployment only.
+pub fn build_receipt_hash(
+    chain_id: &str,
+    contract_addr: &str,
+    id: u64,
+    player: &str,
+    denom: &str,
+    token_amount: Uint128,
+    credit_amount: Uint128,
+    block_height: u64,
+    timestamp: Timestamp,
+) -> Vec<u8> {
+    let msg = format!(
+        "deposit:{}:{}:{}:{}:{}:{}:{}:{}:{}",
+        chain_id,
+        contract_addr,
+        id,
+        player,
+        denom,
+        token_amount,
+        credit_amount,
+        block_height,
+        timestamp.seconds()
+    );
+    let mut hasher = Sha256::new();
+    hasher.update(msg.as_bytes());
+    hasher.finalize().to_vec()
+}
+
+/// Append `field` with a 4-byte big-endian length prefix, so no two field
+/// sequences share an encoding.
+fn push_field(buf: &mut Vec<u8>, field: &[u8]) {
+    buf.extend_from_slice(&(field.len() as u32).to_be_bytes());
+    buf.extend_from_slice(field);
+}
+
+/// SHA-256 over the length-prefixed fields
+/// "sysbreak-credit-bridge-domain", contract_name, contract_version, chain_id, contract_addr.
+pub fn domain_separator(
+    contract_name: &str,
+    contract_version: &str,
+    chain_id: &str,
+    contract_addr: &str,
+) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for field in [
+        "sysbreak-credit-bridge-domain",
+        contract_name,
+        contract_version,
+        chain_id,
+        contract_addr,
+    ] {
+        push_field(&mut buf, field.as_bytes());
+    }
+    Sha256::digest(&buf).to_vec()
+}
+
+/// Payload version 2: SHA-256(0x19 0x01 || domain_separator || struct_hash), where
+/// struct_hash is SHA-256 over the length-prefixed fields "withdraw", nonce, player,
+/// credit_amount and token_amount (16-byte big-endian), and denom. The denom is always
+/// included, primary or not. A payout to another address is tagged "withdraw_to"
+/// instead and appends the recipient as a final field.
+pub fn build_structured_withdrawal_message(
+    domain_separator: &[u8],
+    nonce: &str,
+    player: &str,
+    credit_amount: Uint128,
+    token_amount: Uint128,
+    denom: &str,
+    recipient: Option<&str>,
+) -> Vec<u8> {
+    let mut fields = Vec::new();
+    match recipient {
+        Some(_) => push_field(&mut fields, b"withdraw_to"),
+        None => push_field(&mut fields, b"withdraw"),
+    }
+    push_field(&mut fields, nonce.as_bytes());
+    push_field(&mut fields, player.as_bytes());
+    push_field(&mut fields, &credit_amount.u128().to_be_bytes());
+    push_field(&mut fields, &token_amount.u128().to_be_bytes());
+    push_field(&mut fields, denom.as_bytes());
+    if let Some(recipient) = recipient {
+        push_field(&mut fields, recipient.as_bytes());
+    }
+    let struct_hash = Sha256::digest(&fields);
+
+    let mut hasher = Sha256::new();
+    hasher.update([0x19, 0x01]);
+    hasher.update(domain_separator);
+    hasher.update(struct_hash);
+    hasher.finalize().to_vec()
+}
+
+/// Hours covered by a limit window of `window_seconds` ending in hour `now_hour`.
+fn limit_window_hours(now_hour: u64, window_seconds: u64) -> RangeInclusive<u64> {
+    (now_hour + 1).saturating_sub(window_seconds / BUCKET_SECONDS)..=now_hour
+}
+
+/// Whether `bucket` is inside a limit window of `window_seconds` ending in hour `now_hour`.
+pub fn in_limit_window(bucket: &WithdrawalBucket, now_hour: u64, window_seconds: u64) -> bool {
+    limit_window_hours(now_hour, window_seconds).contains(&bucket.hour)
+}
+
+/// Add `amount` to the ring slot for `hour`, resetting it if it holds a stale hour.
+pub fn accumulate(
+    existing: Option<WithdrawalBucket>,
+    hour: u64,
+    amount: Uint128,
+) -> WithdrawalBucket {
+    match existing {
+        Some(b) if b.hour == hour => WithdrawalBucket {
+            hour,
+            amount_credits: b.amount_credits.saturating_add(amount),
+        },
+        _ => WithdrawalBucket {
+            hour,
+            amount_credits: amount,
+        },
+    }
+}
+
+/// Credits `player` withdrew in `denom` over the hourly buckets of the last
+/// `window_seconds`. One read per hour of the window regardless of withdrawal
+/// volume; a withdrawal ages out up to an hour before the full window has passed.
+pub fn player_window_usage(
+    storage: &dyn Storage,
+    player: &Addr,
+    denom: &str,
+    now: Timestamp,
+    window_seconds: u64,
+) -> StdResult<Uint128> {
+    let mut used = Uint128::zero();
+    for hour in limit_window_hours(bucket_of(now), window_seconds) {
+        let slot = hour % LIMIT_RING_SLOTS;
+        if let Some(b) = PLAYER_WD_BUCKETS.may_load(storage, (player, denom, slot))? {
+            if b.hour == hour {
+                used = used.saturating_add(b.amount_credits);
+            }
+        }
+    }
+    Ok(used)
+}
+
+/// Credits withdrawn by all players in `denom` over the hourly buckets of the last
+/// `window_seconds`.
+pub fn global_window_usage(
+    storage: &dyn Storage,
+    denom: &str,
     now: Timestamp,
     window_seconds: u64,
-) -> (Vec<WithdrawalRecord>, Uint128) {
-    let cutoff = now.minus_seconds(window_seconds);
-    let mut total = Uint128::zero();
-    let mut active: Vec<WithdrawalRecord> = Vec::new();
-
-    for record in records {
-        if record.timestamp >= cutoff {
-            // Safe: individual amounts are validated Uint128, sum bounded by global limit
-            total = total.saturating_add(record.amount_credits);
-            active.push(record);
+) -> StdResult<Uint128> {
+    let mut used = Uint128::zero();
+    for hour in limit_window_hours(bucket_of(now), window_seconds) {
+        let slot = hour % LIMIT_RING_SLOTS;
+        if let Some(b) = GLOBAL_WD_BUCKETS.may_load(storage, (denom, slot))? {
+            if b.hour == hour {
+                used = used.saturating_add(b.amount_credits);
+            }
         }
     }
+    Ok(used)
+}
+
+/// Count a withdrawal made at `time` against the player's and the global buckets
+/// and the player's lifetime total.
+pub fn record_withdrawal(
+    storage: &mut dyn Storage,
+    player: &Addr,
+    denom: &str,
+    time: Timestamp,
+    amount: Uint128,
+) -> StdResult<()> {
+    let hour = bucket_of(time);
+    let slot = hour % LIMIT_RING_SLOTS;
+    PLAYER_WD_BUCKETS.update(storage, (player, denom, slot), |b| -> StdResult<_> {
+        Ok(accumulate(b, hour, amount))
+    })?;
+    GLOBAL_WD_BUCKETS.update(storage, (denom, slot), |b| -> StdResult<_> {
+        Ok(accumulate(b, hour, amount))
+    })?;
+    PLAYER_LIFETIME_WITHDRAWN.update(storage, player, |total| -> StdResult<_> {
+        Ok(total.unwrap_or_default().saturating_add(amount))
+    })?;
+    Ok(())
+}
 
-    (active, total)
+/// Take a queued withdrawal off the queue and release its share of QUEUED_TOTALS.
+pub fn dequeue_withdrawal(
+    storage: &mut dyn Storage,
+    id: u64,
+) -> Result<QueuedWithdrawal, ContractError> {
+    let queued = WITHDRAWAL_QUEUE
+        .may_load(storage, id)?
+        .ok_or(ContractError::QueuedWithdrawalNotFound { id })?;
+    WITHDRAWAL_QUEUE.remove(storage, id);
+    let total_outgoing = queued.token_amount.saturating_add(queued.fee);
+    QUEUED_TOTALS.update(storage, &queued.denom, |t| -> StdResult<_> {
+        let mut totals = t.unwrap_or_default();
+        totals.count = totals.count.saturating_sub(1);
+        totals.amount = totals.amount.saturating_sub(total_outgoing);
+        Ok(totals)
+    })?;
+    Ok(queued)
+}
+
+/// Free a dequeued withdrawal's nonce and limit usage so the authorization
+/// can be retried.
+pub fn roll_back_queued(
+    storage: &mut dyn Storage,
+    queued: &QueuedWithdrawal,
+) -> Result<(), ContractError> {
+    set_nonce_used(storage, &queued.nonce, false)?;
+    unrecord_withdrawal(
+        storage,
+        &queued.player,
+        &queued.denom,
+        queued.queued_at,
+        queued.credit_amount,
+    )?;
+    Ok(())
+}
+
+/// Undo `record_withdrawal` for a withdrawal made at `time` in this block.
+pub fn unrecord_withdrawal(
+    storage: &mut dyn Storage,
+    player: &Addr,
+    denom: &str,
+    time: Timestamp,
+    amount: Uint128,
+) -> StdResult<()> {
+    let hour = bucket_of(time);
+    let slot = hour % LIMIT_RING_SLOTS;
+    let deduct = |b: WithdrawalBucket| WithdrawalBucket {
+        hour: b.hour,
+        amount_credits: b.amount_credits.saturating_sub(amount),
+    };
+    if let Some(b) = PLAYER_WD_BUCKETS.may_load(storage, (player, denom, slot))? {
+        if b.hour == hour {
+            PLAYER_WD_BUCKETS.save(storage, (player, denom, slot), &deduct(b))?;
+        }
+    }
+    if let Some(b) = GLOBAL_WD_BUCKETS.may_load(storage, (denom, slot))? {
+        if b.hour == hour {
+            GLOBAL_WD_BUCKETS.save(storage, (denom, slot), &deduct(b))?;
+        }
+    }
+    if let Some(total) = PLAYER_LIFETIME_WITHDRAWN.may_load(storage, player)? {
+        PLAYER_LIFETIME_WITHDRAWN.save(storage, player, &total.saturating_sub(amount))?;
+    }
+    Ok(())
+}
+
+/// Fee transfer to the treasury; nothing when the fee is zero.
+pub fn fee_payment(treasury: &Addr, denom: &str, fee: Uint128) -> Option<BankMsg> {
+    if fee.is_zero() {
+        return None;
+    }
+    Some(BankMsg::Send {
+        to_address: treasury.to_string(),
+        amount: vec![Coin {
+            denom: denom.to_string(),
+            amount: fee,
+        }],
+    })
+}
+
+/// Fee transfers for a withdrawal by `player`: the DAO share to the player's
+/// first active corporation, if a share is configured for this denom, and the
+/// rest to the treasury. A DAO that fails the lookup counts as no corporation,
+/// and a donation it rejects is paid to the treasury in `reply`, so the DAO can
+/// never block a withdrawal.
+pub fn fee_payments(
+    deps: Deps,
+    config: &Config,
+    player: &Addr,
+    denom: &str,
+    fee: Uint128,
+) -> StdResult<Vec<SubMsg>> {
+    let share = config
+        .dao_fee_share
+        .as_ref()
+        .filter(|s| s.denom == denom && s.dao_fee_share_bps > 0 && !fee.is_zero());
+    let Some(share) = share else {
+        return Ok(fee_payment(&config.treasury, denom, fee)
+            .into_iter()
+            .map(SubMsg::new)
+            .collect());
+    };
+
+    let corp_id = deps
+        .querier
+        .query_wasm_smart::<DaoCorporationsResponse>(
+            &share.dao_contract,
+            &DaoQueryMsg::CorporationsByMember {
+                address: player.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .ok()
+        .and_then(|res| {
+            res.corporations
+                .into_iter()
+                .find(|c| c.status == "active")
+                .map(|c| c.corp_id)
+        });
+    let dao_amount = match corp_id {
+        Some(_) => fee.multiply_ratio(share.dao_fee_share_bps, 10_000u128),
+        None => Uint128::zero(),
+    };
+
+    let mut msgs = vec![];
+    if let Some(corp_id) = corp_id.filter(|_| !dao_amount.is_zero()) {
+        let donation = Coin {
+            denom: denom.to_string(),
+            amount: dao_amount,
+        };
+        msgs.push(
+            SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: share.dao_contract.to_string(),
+                    msg: to_json_binary(&DaoExecuteMsg::DonateTreasury { corp_id })?,
+                    funds: vec![donation.clone()],
+                },
+                DAO_DONATION_REPLY_ID,
+            )
+            .with_payload(to_json_binary(&donation)?),
+        );
+    }
+    msgs.extend(fee_payment(&config.treasury, denom, fee - dao_amount).map(SubMsg::new));
+    Ok(msgs)
+}
+
+/// Credit `referral_bps` of a withdrawal fee to `player`'s referrer, if they
+/// have one. Returns the amount held back from the fee for the referrer.
+pub fn accrue_referral_reward(
+    storage: &mut dyn Storage,
+    config: &Config,
+    player: &Addr,
+    denom: &str,
+    fee: Uint128,
+) -> StdResult<Uint128> {
+    if config.referral_bps == 0 {
+        return Ok(Uint128::zero());
+    }
+    let Some(referrer) = REFERRERS.may_load(storage, player)? else {
+        return Ok(Uint128::zero());
+    };
+    let reward = fee.multiply_ratio(config.referral_bps, 10_000u128);
+    if reward.is_zero() {
+        return Ok(reward);
+    }
+    REFERRAL_REWARDS.update(storage, (&referrer, denom), |r| -> StdResult<_> {
+        let mut r = r.unwrap_or_default();
+        r.claimable += reward;
+        r.total_earned += reward;
+        Ok(r)
+    })?;
+    REFERRAL_OUTSTANDING.update(storage, denom, |o| -> StdResult<_> {
+        Ok(o.unwrap_or_default() + reward)
+    })?;
+    Ok(reward)
 }
 
 /// Check player daily limit and cooldown. Returns the current 24h usage.
@@ -106,79 +896,435 @@ pub fn check_player_limits(
     player: &Addr,
     credit_amount: Uint128,
     config: &Config,
+    denom: &str,
+    params: &DenomConfig,
 ) -> Result<Uint128, ContractError> {
-    let now = env.block.time;
+    check_cooldown(deps, env, player, config)?;
+    check_player_daily_limit(deps, env, player, credit_amount, config, denom, params)
+}
 
-    // Cooldown check
+pub fn check_cooldown(
+    deps: Deps,
+    env: &Env,
+    player: &Addr,
+    config: &Config,
+) -> Result<(), ContractError> {
     if let Some(last) = PLAYER_LAST_WITHDRAWAL.may_load(deps.storage, player)? {
         let cooldown_until = last.plus_seconds(config.cooldown_seconds);
-        if now < cooldown_until {
+        if env.block.time < cooldown_until {
             return Err(ContractError::CooldownActive {
                 available_at: cooldown_until.seconds().to_string(),
             });
         }
     }
+    Ok(())
+}
 
-    // Rolling 24h window
-    let records = PLAYER_WITHDRAWALS
-        .may_load(deps.storage, player)?
-        .unwrap_or_default();
-    let (_active, used) = sum_rolling_window(records, now, 86_400);
+/// Check the player's limit over the rolling limit window. Returns the current usage.
+pub fn check_player_daily_limit(
+    deps: Deps,
+    env: &Env,
+    player: &Addr,
+    credit_amount: Uint128,
+    config: &Config,
+    denom: &str,
+    params: &DenomConfig,
+) -> Result<Uint128, ContractError> {
+    let used = player_window_usage(
+        deps.storage,
+        player,
+        denom,
+        env.block.time,
+        config.limit_window_seconds,
+    )?;
 
     let new_total = used.checked_add(credit_amount).map_err(|_| ContractError::Overflow)?;
-    if new_total > config.player_daily_limit {
+    if new_total > params.player_daily_limit {
         return Err(ContractError::PlayerDailyLimitExceeded {
             used: used.to_string(),
             requested: credit_amount.to_string(),
-            limit: config.player_daily_limit.to_string(),
+            limit: params.player_daily_limit.to_string(),
         });
     }
 
     Ok(used)
 }
 
-// FIX: M-04 — Map-based global limit check with pruning
-/// Check global daily limit using the Map-based storage. Returns the current 24h usage.
-pub fn check_global_limit(
+/// Lifetime cap for `player`'s KYC tier; None is unlimited.
+pub fn lifetime_cap(storage: &dyn Storage, config: &Config, player: &Addr) -> Option<Uint128> {
+    if KYC_VERIFIED.has(storage, player) {
+        config.kyc_lifetime_cap
+    } else {
+        config.player_lifetime_cap
+    }
+}
+
+/// Check the player's lifetime cap. Returns the credits withdrawn so far.
+pub fn check_lifetime_cap(
     deps: Deps,
-    env: &Env,
+    player: &Addr,
     credit_amount: Uint128,
     config: &Config,
 ) -> Result<Uint128, ContractError> {
-    let now = env.block.time;
-    let cutoff = now.minus_seconds(86_400);
-    let oldest = GLOBAL_WD_OLDEST.may_load(deps.storage)?.unwrap_or(0);
-    let counter = GLOBAL_WD_COUNTER.may_load(deps.storage)?.unwrap_or(0);
-
-    let mut used = Uint128::zero();
-    for idx in oldest..=counter {
-        if let Some(record) = GLOBAL_WITHDRAWAL_RECORDS.may_load(deps.storage, idx)? {
-            if record.timestamp >= cutoff {
-                used = used.saturating_add(record.amount_credits);
-            }
+    let used = PLAYER_LIFETIME_WITHDRAWN
+        .may_load(deps.storage, player)?
+        .unwrap_or_default();
+    if let Some(cap) = lifetime_cap(deps.storage, config, player) {
+        let new_total = used
+            .checked_add(credit_amount)
+            .map_err(|_| ContractError::Overflow)?;
+        if new_total > cap {
+            return Err(ContractError::LifetimeCapExceeded {
+                used: used.to_string(),
+                requested: credit_amount.to_string(),
+                cap: cap.to_string(),
+            });
         }
     }
+    Ok(used)
+}
+
+/// Check the global limit over the rolling limit window. Returns the current usage.
+pub fn check_global_limit(
+    deps: Deps,
+    env: &Env,
+    credit_amount: Uint128,
+    config: &Config,
+    denom: &str,
+    params: &DenomConfig,
+) -> Result<Uint128, ContractError> {
+    let used = global_window_usage(
+        deps.storage,
+        denom,
+        env.block.time,
+        config.limit_window_seconds,
+    )?;
 
     let new_total = used.checked_add(credit_amount).map_err(|_| ContractError::Overflow)?;
-    if new_total > config.global_daily_limit {
+    if new_total > params.global_daily_limit {
         return Err(ContractError::GlobalDailyLimitExceeded {
             used: used.to_string(),
             requested: credit_amount.to_string(),
-            limit: config.global_daily_limit.to_string(),
+            limit: params.global_daily_limit.to_string(),
         });
     }
 
     Ok(used)
 }
 
-// FIX: M-08 — reject unexpected funds
-pub fn reject_funds(info: &MessageInfo) -> Result<(), ContractError> {
-    if !info.funds.is_empty() {
-        return Err(ContractError::UnexpectedFunds);
+/// A withdrawal nonce as the oracle signs it
+pub enum Nonce<'a> {
+    /// "{unix_timestamp}:{random}", one USED_NONCES entry each
+    Legacy(&'a str),
+    /// "seq:{bucket}:{sequence}", one bit in the bucket's NONCE_BITMAPS word,
+    /// where bucket is the unix timestamp over NONCE_BUCKET_SECONDS
+    Sequenced { bucket: u64, sequence: u64 },
+}
+
+impl Nonce<'_> {
+    pub fn parse(nonce: &str) -> Result<Nonce<'_>, ContractError> {
+        let Some(rest) = nonce.strip_prefix("seq:") else {
+            return Ok(Nonce::Legacy(nonce));
+        };
+        let (bucket, sequence) = rest
+            .split_once(':')
+            .ok_or(ContractError::InvalidNonceFormat)?;
+        Ok(Nonce::Sequenced {
+            bucket: bucket
+                .parse()
+                .map_err(|_| ContractError::InvalidNonceFormat)?,
+            sequence: sequence
+                .parse()
+                .map_err(|_| ContractError::InvalidNonceFormat)?,
+        })
+    }
+
+    /// The timestamp bucket the nonce expires with
+    pub fn bucket(&self) -> Result<u64, ContractError> {
+        match self {
+            Nonce::Legacy(nonce) => nonce
+                .split_once(':')
+                .and_then(|(ts, _)| ts.parse::<u64>().ok())
+                .map(|ts| ts / NONCE_BUCKET_SECONDS)
+                .ok_or(ContractError::InvalidNonceFormat),
+            Nonce::Sequenced { bucket, .. } => Ok(*bucket),
+        }
+    }
+}
+
+/// Oldest sequenced nonce bucket still inside the expiry window
+fn oldest_nonce_bucket(now: Timestamp) -> u64 {
+    now.seconds().saturating_sub(NONCE_EXPIRY_WINDOW) / NONCE_BUCKET_SECONDS
+}
+
+fn legacy_nonces_open(storage: &dyn Storage, now: Timestamp) -> StdResult<bool> {
+    Ok(LEGACY_NONCES_UNTIL
+        .may_load(storage)?
+        .is_some_and(|until| now < until))
+}
+
+/// Reject a malformed, expired or already used nonce.
+pub fn check_nonce(deps: Deps, env: &Env, nonce: &str) -> Result<(), ContractError> {
+    let used = match Nonce::parse(nonce)? {
+        Nonce::Legacy(nonce) => {
+            if !legacy_nonces_open(deps.storage, env.block.time)? {
+                return Err(ContractError::LegacyNonceRetired);
+            }
+            // FIX: M-03 — validate nonce timestamp before replay check
+            validate_nonce_timestamp(nonce, env.block.time)?;
+            USED_NONCES.may_load(deps.storage, nonce)?.unwrap_or(false)
+        }
+        Nonce::Sequenced { bucket, sequence } => {
+            if bucket < oldest_nonce_bucket(env.block.time) {
+                return Err(ContractError::NonceExpired {
+                    window: NONCE_EXPIRY_WINDOW,
+                });
+            }
+            let word = NONCE_BITMAPS
+                .may_load(deps.storage, (bucket, sequence / 64))?
+                .unwrap_or_default();
+            word & (1 << (sequence % 64)) != 0
+        }
+    };
+    if used {
+        return Err(ContractError::NonceAlreadyUsed {
+            nonce: nonce.to_string(),
+        });
     }
     Ok(())
 }
 
+/// Whether a nonce has been consumed; malformed nonces never have been.
+pub fn nonce_used(storage: &dyn Storage, nonce: &str) -> StdResult<bool> {
+    match Nonce::parse(nonce) {
+        Ok(Nonce::Legacy(nonce)) => Ok(USED_NONCES.may_load(storage, nonce)?.unwrap_or(false)),
+        Ok(Nonce::Sequenced { bucket, sequence }) => Ok(NONCE_BITMAPS
+            .may_load(storage, (bucket, sequence / 64))?
+            .is_some_and(|word| word & (1 << (sequence % 64)) != 0)),
+        Err(_) => Ok(false),
+    }
+}
+
+/// Consume a nonce that passed `check_nonce`, or release it again when the
+/// withdrawal it authorized is rolled back.
+pub fn set_nonce_used(
+    storage: &mut dyn Storage,
+    nonce: &str,
+    used: bool,
+) -> Result<(), ContractError> {
+    match Nonce::parse(nonce)? {
+        Nonce::Legacy(nonce) if used => USED_NONCES.save(storage, nonce, &true)?,
+        Nonce::Legacy(nonce) => USED_NONCES.remove(storage, nonce),
+        Nonce::Sequenced { bucket, sequence } => {
+            let key = (bucket, sequence / 64);
+            let bit = 1 << (sequence % 64);
+            let word = NONCE_BITMAPS.may_load(storage, key)?.unwrap_or_default();
+            match if used { word | bit } else { word & !bit } {
+                0 => NONCE_BITMAPS.remove(storage, key),
+                word => NONCE_BITMAPS.save(storage, key, &word)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Drop up to `limit` bitmap words and failure marks from buckets past the
+/// expiry window, then legacy entries once the transition has ended. None of
+/// them can match a nonce that passes `check_nonce` any more.
+pub fn prune_nonces(storage: &mut dyn Storage, now: Timestamp, limit: usize) -> StdResult<usize> {
+    let oldest = oldest_nonce_bucket(now);
+    let end = Bound::exclusive((oldest, 0));
+    let stale: Vec<(u64, u64)> = NONCE_BITMAPS
+        .keys(storage, None, Some(end), Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+    for key in &stale {
+        NONCE_BITMAPS.remove(storage, *key);
+    }
+    let mut pruned = stale.len();
+    let end = Bound::exclusive((oldest, ""));
+    let failed: Vec<(u64, String)> = FAILED_NONCES
+        .keys(storage, None, Some(end), Order::Ascending)
+        .take(limit - pruned)
+        .collect::<StdResult<_>>()?;
+    for (bucket, nonce) in &failed {
+        FAILED_NONCES.remove(storage, (*bucket, nonce));
+    }
+    pruned += failed.len();
+    if pruned < limit && LEGACY_NONCES_UNTIL.exists(storage) && !legacy_nonces_open(storage, now)? {
+        let legacy: Vec<String> = USED_NONCES
+            .keys(storage, None, None, Order::Ascending)
+            .take(limit - pruned)
+            .collect::<StdResult<_>>()?;
+        for nonce in &legacy {
+            USED_NONCES.remove(storage, nonce);
+        }
+        pruned += legacy.len();
+    }
+    Ok(pruned)
+}
+
+/// Verify `token_amount` against what `credit_amount` pays out to `player` at
+/// the current rate, net of their fee. A static rate must match exactly; a rate
+/// that follows the price feed can move after the oracle signs, so there
+/// `token_amount` is the least the player accepts. Returns the fee and the
+/// tokens to pay out.
+pub fn check_withdrawal_amount(
+    deps: Deps,
+    config: &Config,
+    params: &DenomConfig,
+    denom: &str,
+    player: &Addr,
+    credit_amount: Uint128,
+    token_amount: Uint128,
+) -> Result<(Uint128, Uint128), ContractError> {
+    let gross_tokens = credits_to_tokens(credit_amount, params)?;
+    let fee_bps = withdrawal_fee_bps(deps, config, params, player)?;
+    let fee = calculate_fee(gross_tokens, fee_bps, params)?;
+    let net_tokens = gross_tokens
+        .checked_sub(fee)
+        .map_err(|_| ContractError::Overflow)?;
+
+    let accepted = if follows_price_feed(config, denom) {
+        net_tokens >= token_amount
+    } else {
+        net_tokens == token_amount
+    };
+    if !accepted {
+        return Err(ContractError::AmountMismatch {
+            credits: credit_amount.to_string(),
+            expected_tokens: net_tokens.to_string(),
+            provided_tokens: token_amount.to_string(),
+        });
+    }
+    Ok((fee, net_tokens))
+}
+
+/// Unclaimed referral rewards in `denom`. They belong to the referrers, so no
+/// other outflow may spend them.
+pub fn referral_outstanding(storage: &dyn Storage, denom: &str) -> StdResult<Uint128> {
+    Ok(REFERRAL_OUTSTANDING
+        .may_load(storage, denom)?
+        .unwrap_or_default())
+}
+
+/// Check the contract can pay `total_outgoing` and keep its minimum reserve,
+/// after what queued withdrawals and referrers are owed. Returns the current
+/// balance.
+pub fn check_reserve(
+    deps: Deps,
+    env: &Env,
+    denom: &str,
+    params: &DenomConfig,
+    total_outgoing: Uint128,
+) -> Result<Uint128, ContractError> {
+    let contract_balance = deps
+        .querier
+        .query_balance(&env.contract.address, denom)?
+        .amount;
+    // Tokens owed to queued withdrawals or referrers are not available to new ones
+    let queued = QUEUED_TOTALS
+        .may_load(deps.storage, denom)?
+        .unwrap_or_default()
+        .amount;
+    let available = contract_balance
+        .saturating_sub(queued)
+        .saturating_sub(referral_outstanding(deps.storage, denom)?);
+    let insufficient = || ContractError::InsufficientTreasury {
+        needed: total_outgoing.to_string(),
+        available: available.to_string(),
+        reserve_min: params.min_reserve.to_string(),
+    };
+    let remaining = available
+        .checked_sub(total_outgoing)
+        .map_err(|_| insufficient())?;
+    if remaining < params.min_reserve {
+        return Err(insufficient());
+    }
+    Ok(contract_balance)
+}
+
+/// Classify a withdrawal check failure for the failure log.
+pub fn failure_reason(err: &ContractError) -> FailureReason {
+    match err {
+        ContractError::CooldownActive { .. } => FailureReason::Cooldown,
+        ContractError::PlayerDailyLimitExceeded { .. } => FailureReason::PlayerDailyLimit,
+        ContractError::LifetimeCapExceeded { .. } => FailureReason::LifetimeCap,
+        ContractError::GlobalDailyLimitExceeded { .. } => FailureReason::GlobalDailyLimit,
+        ContractError::InsufficientTreasury { .. } => FailureReason::InsufficientTreasury,
+        _ => FailureReason::Other,
+    }
+}
+
+/// Mark `nonce` as having failed its checks. Returns false if it already had,
+/// so a replayed authorization is logged only once.
+pub fn mark_nonce_failed(storage: &mut dyn Storage, nonce: &str) -> Result<bool, ContractError> {
+    let key = (Nonce::parse(nonce)?.bucket()?, nonce);
+    if FAILED_NONCES.has(storage, key) {
+        return Ok(false);
+    }
+    FAILED_NONCES.save(storage, key, &true)?;
+    Ok(true)
+}
+
+/// Append to the failure log, overwriting the oldest record once it is full.
+pub fn record_failure(
+    storage: &mut dyn Storage,
+    player: &Addr,
+    reason: FailureReason,
+    now: Timestamp,
+) -> StdResult<()> {
+    let count = FAILURE_COUNT.may_load(storage)?.unwrap_or_default();
+    FAILURE_LOG.save(
+        storage,
+        count % FAILURE_LOG_SIZE,
+        &FailureRecord {
+            player: player.clone(),
+            reason,
+            failed_at: now,
+        },
+    )?;
+    FAILURE_COUNT.save(storage, &(count + 1))?;
+    Ok(())
+}
+
+/// Extract the packet sequence from a protobuf-encoded MsgTransferResponse
+/// (`uint64 sequence = 1`). Returns None if the field is absent or malformed.
+pub fn parse_transfer_sequence(data: &[u8]) -> Option<u64> {
+    let mut i = 0;
+    while i < data.len() {
+        let key = read_varint(data, &mut i)?;
+        let (field, wire_type) = (key >> 3, key & 0x7);
+        match wire_type {
+            0 => {
+                let value = read_varint(data, &mut i)?;
+                if field == 1 {
+                    return Some(value);
+                }
+            }
+            2 => {
+                let len = read_varint(data, &mut i)? as usize;
+                i = i.checked_add(len)?;
+            }
+            _ => return None,
+        }
+    }
+    None
+}
+
+fn read_varint(data: &[u8], i: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *data.get(*i)?;
+        *i += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
 // FIX: L-03 — validate oracle public key length
 pub fn validate_pubkey(pubkey: &Binary) -> Result<(), ContractError> {
     let len = pubkey.len();
@@ -188,6 +1334,52 @@ pub fn validate_pubkey(pubkey: &Binary) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// Compress a 65-byte uncompressed SEC1 key (0x04 || x || y) to 33 bytes.
+fn compress_pubkey(uncompressed: &[u8]) -> Option<Vec<u8>> {
+    if uncompressed.len() != 65 || uncompressed[0] != 0x04 {
+        return None;
+    }
+    let prefix = if uncompressed[64] & 1 == 0 {
+        0x02
+    } else {
+        0x03
+    };
+    let mut compressed = Vec::with_capacity(33);
+    compressed.push(prefix);
+    compressed.extend_from_slice(&uncompressed[1..33]);
+    Some(compressed)
+}
+
+/// Check `signature` over `message_hash` against the oracle key. Accepts a 64-byte
+/// (r, s) signature, or a 65-byte recoverable (r, s, v) one with v in {0, 1, 27, 28},
+/// whose recovered key must equal `pubkey` (compressed or uncompressed).
+pub fn verify_oracle_signature(
+    api: &dyn Api,
+    message_hash: &[u8],
+    signature: &[u8],
+    pubkey: &[u8],
+) -> Result<bool, ContractError> {
+    if signature.len() != 65 {
+        return api
+            .secp256k1_verify(message_hash, signature, pubkey)
+            .map_err(|_| ContractError::SignatureVerificationFailed);
+    }
+
+    let recovery_param = match signature[64] {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        _ => return Err(ContractError::SignatureVerificationFailed),
+    };
+    let recovered = api
+        .secp256k1_recover_pubkey(message_hash, &signature[..64], recovery_param)
+        .map_err(|_| ContractError::SignatureVerificationFailed)?;
+    if pubkey.len() == 33 {
+        Ok(compress_pubkey(&recovered).as_deref() == Some(pubkey))
+    } else {
+        Ok(recovered == pubkey)
+    }
+}
+
 // FIX: M-03 — parse and validate timestamp-based nonce
 /// Nonce format: "{unix_timestamp}:{random}"
 /// Rejects nonces older than NONCE_EXPIRY_WINDOW.
@@ -4,11 +4,17 @@ pub mod helpers;
 pub mod msg;
 pub mod state;
 
-#[cfg(not(feature = "library"))]
+#[cfg(feature = "testing")]
+pub mod testing;
+
+// Entry points are only referenced by the wasm export shims, which are not
+// generated for native targets, and by the `testing` wrapper.
+#[cfg(any(not(feature = "library"), feature = "testing"))]
+#[allow(dead_code)]
 mod entry {
     use super::*;
-    use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
-    use msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+    use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
+    use msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg};
 
     #[entry_point]
     pub fn instantiate(
@@ -28,38 +34,144 @@ mod entry {
         msg: ExecuteMsg,
     ) -> Result<Response, error::ContractError> {
         match msg {
-            ExecuteMsg::Deposit {} => contract::execute_deposit(deps, env, info),
+            ExecuteMsg::Deposit { referrer } => {
+                contract::execute_deposit(deps, env, info, referrer)
+            }
+            ExecuteMsg::ClaimReferralRewards { denom } => {
+                contract::execute_claim_referral_rewards(deps, env, info, denom)
+            }
             ExecuteMsg::Withdraw {
                 nonce,
                 credit_amount,
                 token_amount,
                 signature,
-            } => contract::execute_withdraw(deps, env, info, nonce, credit_amount, token_amount, signature),
+                ibc_transfer,
+                denom,
+                payload_version,
+                recipient,
+            } => contract::execute_withdraw(
+                deps,
+                env,
+                info,
+                nonce,
+                credit_amount,
+                token_amount,
+                signature,
+                ibc_transfer,
+                denom,
+                payload_version,
+                recipient,
+            ),
+            ExecuteMsg::WithdrawWithSessionKey {
+                player,
+                nonce,
+                credit_amount,
+                token_amount,
+                signature,
+                session_signature,
+                denom,
+                payload_version,
+            } => contract::execute_withdraw_with_session_key(
+                deps,
+                env,
+                info,
+                player,
+                nonce,
+                credit_amount,
+                token_amount,
+                signature,
+                session_signature,
+                denom,
+                payload_version,
+            ),
+            ExecuteMsg::RegisterSessionKey { pubkey, expires_at } => {
+                contract::execute_register_session_key(deps, env, info, pubkey, expires_at)
+            }
+            ExecuteMsg::RevokeSessionKey {} => {
+                contract::execute_revoke_session_key(deps, env, info)
+            }
+            ExecuteMsg::ProcessQueue { limit } => {
+                contract::execute_process_queue(deps, env, info, limit)
+            }
+            ExecuteMsg::CreateCampaign {
+                start,
+                end,
+                bonus_bps,
+                budget,
+            } => contract::execute_create_campaign(deps, env, info, start, end, bonus_bps, budget),
+            ExecuteMsg::EndCampaign { id } => contract::execute_end_campaign(deps, env, info, id),
             ExecuteMsg::FundTreasury {} => contract::execute_fund_treasury(deps, env, info),
-            ExecuteMsg::WithdrawTreasury { amount } => {
-                contract::execute_withdraw_treasury(deps, env, info, amount)
+            ExecuteMsg::WithdrawTreasury { amount, denom } => {
+                contract::execute_withdraw_treasury(deps, env, info, amount, denom)
+            }
+            ExecuteMsg::SweepForeignFunds {
+                denom,
+                amount,
+                recipient,
+            } => contract::execute_sweep_foreign_funds(deps, env, info, denom, amount, recipient),
+            ExecuteMsg::SetDenomConfig { denom, config } => {
+                contract::execute_set_denom_config(deps, env, info, denom, config)
+            }
+            ExecuteMsg::RemoveDenom { denom } => {
+                contract::execute_remove_denom(deps, env, info, denom)
             }
             ExecuteMsg::ProposeOracle {
                 new_oracle,
                 new_pubkey,
             } => contract::execute_propose_oracle(deps, env, info, new_oracle, new_pubkey),
             ExecuteMsg::AcceptOracle {} => contract::execute_accept_oracle(deps, env, info),
+            ExecuteMsg::SetOracleKeyGrace { seconds } => {
+                contract::execute_set_oracle_key_grace(deps, env, info, seconds)
+            }
             ExecuteMsg::CancelOracleTransfer {} => {
                 contract::execute_cancel_oracle_transfer(deps, env, info)
             }
-            ExecuteMsg::UpdateRate {
+            ExecuteMsg::ScheduleRateUpdate {
+                denom,
                 rate_credits,
                 rate_tokens,
-            } => contract::execute_update_rate(deps, env, info, rate_credits, rate_tokens),
+                effective_at,
+            } => contract::execute_schedule_rate_update(
+                deps,
+                env,
+                info,
+                denom,
+                rate_credits,
+                rate_tokens,
+                effective_at,
+            ),
+            ExecuteMsg::ApplyScheduledRate {} => {
+                contract::execute_apply_scheduled_rate(deps, env, info)
+            }
+            ExecuteMsg::CancelScheduledRate {} => {
+                contract::execute_cancel_scheduled_rate(deps, env, info)
+            }
+            ExecuteMsg::SetPriceFeed { feed } => {
+                contract::execute_set_price_feed(deps, env, info, feed)
+            }
             ExecuteMsg::UpdateFee { fee_bps } => {
                 contract::execute_update_fee(deps, env, info, fee_bps)
             }
+            ExecuteMsg::SetFeePolicy { rounding, min_fee } => {
+                contract::execute_set_fee_policy(deps, env, info, rounding, min_fee)
+            }
+            ExecuteMsg::SetAchievementDiscount { discount } => {
+                contract::execute_set_achievement_discount(deps, env, info, discount)
+            }
+            ExecuteMsg::SetDaoFeeShare { fee_share } => {
+                contract::execute_set_dao_fee_share(deps, env, info, fee_share)
+            }
+            ExecuteMsg::SetReferralBps { bps } => {
+                contract::execute_set_referral_bps(deps, env, info, bps)
+            }
             ExecuteMsg::UpdateLimits {
                 player_daily_limit,
                 global_daily_limit,
                 cooldown_seconds,
                 min_deposit,
                 min_reserve,
+                peak_window_seconds,
+                limit_window_seconds,
             } => contract::execute_update_limits(
                 deps,
                 env,
@@ -69,9 +181,54 @@ mod entry {
                 cooldown_seconds,
                 min_deposit,
                 min_reserve,
+                peak_window_seconds,
+                limit_window_seconds,
             ),
+            ExecuteMsg::SetIbcChannel {
+                channel_id,
+                timeout_seconds,
+            } => contract::execute_set_ibc_channel(deps, env, info, channel_id, timeout_seconds),
+            ExecuteMsg::RemoveIbcChannel { channel_id } => {
+                contract::execute_remove_ibc_channel(deps, env, info, channel_id)
+            }
+            ExecuteMsg::SetPlayerBlocked {
+                address,
+                blocked,
+                reason,
+            } => contract::execute_set_player_blocked(deps, env, info, address, blocked, reason),
+            ExecuteMsg::SetKycVerified { address, verified } => {
+                contract::execute_set_kyc_verified(deps, env, info, address, verified)
+            }
+            ExecuteMsg::SetWithdrawalQueue { enabled } => {
+                contract::execute_set_withdrawal_queue(deps, env, info, enabled)
+            }
+            ExecuteMsg::CancelQueuedWithdrawal { queue_id } => {
+                contract::execute_cancel_queued_withdrawal(deps, env, info, queue_id)
+            }
+            ExecuteMsg::SetStrictTelemetry { enabled } => {
+                contract::execute_set_strict_telemetry(deps, env, info, enabled)
+            }
+            ExecuteMsg::SetLifetimeCaps {
+                player_cap,
+                kyc_cap,
+            } => contract::execute_set_lifetime_caps(deps, env, info, player_cap, kyc_cap),
             ExecuteMsg::Pause {} => contract::execute_pause(deps, env, info),
+            ExecuteMsg::PauseDeposits {} => contract::execute_pause_deposits(deps, env, info),
+            ExecuteMsg::PauseWithdrawals {} => contract::execute_pause_withdrawals(deps, env, info),
             ExecuteMsg::Unpause {} => contract::execute_unpause(deps, env, info),
+            ExecuteMsg::UnpauseDeposits {} => contract::execute_unpause_deposits(deps, env, info),
+            ExecuteMsg::UnpauseWithdrawals {} => {
+                contract::execute_unpause_withdrawals(deps, env, info)
+            }
+            ExecuteMsg::SetPauser { pauser } => {
+                contract::execute_set_pauser(deps, env, info, pauser)
+            }
+            ExecuteMsg::SetMaintenanceMode { enabled } => {
+                contract::execute_set_maintenance_mode(deps, env, info, enabled)
+            }
+            ExecuteMsg::SetMaintenanceWhitelist { address, allowed } => {
+                contract::execute_set_maintenance_whitelist(deps, env, info, address, allowed)
+            }
             // FIX: H-04
             ExecuteMsg::ProposeOwner { new_owner } => {
                 contract::execute_propose_owner(deps, env, info, new_owner)
@@ -87,21 +244,91 @@ mod entry {
     pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> cosmwasm_std::StdResult<Binary> {
         match msg {
             QueryMsg::Config {} => contract::query_config(deps),
-            QueryMsg::TreasuryInfo {} => contract::query_treasury_info(deps, env),
-            QueryMsg::PlayerInfo { address } => contract::query_player_info(deps, env, address),
+            QueryMsg::TreasuryInfo { denom } => contract::query_treasury_info(deps, env, denom),
+            QueryMsg::Solvency { denom } => contract::query_solvency(deps, env, denom),
+            QueryMsg::PlayerInfo { address, denom } => {
+                contract::query_player_info(deps, env, address, denom)
+            }
+            QueryMsg::PlayerBlocked { address } => contract::query_player_blocked(deps, address),
+            QueryMsg::BlockedPlayers { start_after, limit } => {
+                contract::query_blocked_players(deps, start_after, limit)
+            }
+            QueryMsg::MaintenanceWhitelist { start_after, limit } => {
+                contract::query_maintenance_whitelist(deps, start_after, limit)
+            }
             QueryMsg::NonceUsed { nonce } => contract::query_nonce_used(deps, nonce),
-            QueryMsg::ConvertCreditsToTokens { credit_amount } => {
-                contract::query_convert_credits_to_tokens(deps, credit_amount)
+            QueryMsg::ValidateWithdrawal {
+                player,
+                nonce,
+                credit_amount,
+                token_amount,
+                denom,
+            } => contract::query_validate_withdrawal(
+                deps,
+                env,
+                player,
+                nonce,
+                credit_amount,
+                token_amount,
+                denom,
+            ),
+            QueryMsg::WithdrawalQueue { start_after, limit } => {
+                contract::query_withdrawal_queue(deps, start_after, limit)
+            }
+            QueryMsg::ConfigHistory { start_after, limit } => {
+                contract::query_config_history(deps, start_after, limit)
+            }
+            QueryMsg::RecentFailures { limit } => contract::query_recent_failures(deps, limit),
+            QueryMsg::SessionKey { player } => contract::query_session_key(deps, player),
+            QueryMsg::SigningDomains {} => contract::query_signing_domains(deps, env),
+            QueryMsg::ConvertCreditsToTokens {
+                credit_amount,
+                denom,
+                player,
+            } => {
+                contract::query_convert_credits_to_tokens(deps, env, credit_amount, denom, player)
             }
-            QueryMsg::ConvertTokensToCredits { token_amount } => {
-                contract::query_convert_tokens_to_credits(deps, token_amount)
+            QueryMsg::ConvertTokensToCredits {
+                token_amount,
+                denom,
+            } => contract::query_convert_tokens_to_credits(deps, env, token_amount, denom),
+            QueryMsg::DenomConfig { denom } => contract::query_denom_config(deps, denom),
+            QueryMsg::Denoms { start_after, limit } => {
+                contract::query_denoms(deps, start_after, limit)
             }
             QueryMsg::PendingOracle {} => contract::query_pending_oracle(deps),
+            QueryMsg::OracleKeys {} => contract::query_oracle_keys(deps, env),
+            QueryMsg::PendingRate {} => contract::query_pending_rate(deps),
+            QueryMsg::ExchangeRate { denom } => contract::query_exchange_rate(deps, env, denom),
+            QueryMsg::IbcChannels { start_after, limit } => {
+                contract::query_ibc_channels(deps, start_after, limit)
+            }
+            QueryMsg::IbcTransfer {
+                channel_id,
+                sequence,
+            } => contract::query_ibc_transfer(deps, channel_id, sequence),
+            QueryMsg::DepositReceipt { id } => contract::query_deposit_receipt(deps, id),
+            QueryMsg::ReferralStats { address } => contract::query_referral_stats(deps, address),
+            QueryMsg::BonusCampaign { id } => contract::query_bonus_campaign(deps, id),
+            QueryMsg::BonusCampaigns { start_after, limit } => {
+                contract::query_bonus_campaigns(deps, start_after, limit)
+            }
+            QueryMsg::ActiveBonusCampaign {} => contract::query_active_bonus_campaign(deps, env),
             // FIX: H-04
             QueryMsg::PendingOwner {} => contract::query_pending_owner(deps),
         }
     }
 
+    #[entry_point]
+    pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, error::ContractError> {
+        contract::reply(deps, env, msg)
+    }
+
+    #[entry_point]
+    pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, error::ContractError> {
+        contract::sudo(deps, env, msg)
+    }
+
     #[entry_point]
     pub fn migrate(
         deps: DepsMut,
@@ -1,5 +1,7 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Uint128};
+use cosmwasm_std::{Binary, Decimal, Timestamp, Uint128};
+
+use crate::state::{BlockedPlayer, DenomConfig, FeeRounding};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -28,33 +30,122 @@ pub struct InstantiateMsg {
     pub min_reserve: Uint128,
     /// Chain ID for signature replay protection
     pub chain_id: String,
+    /// Treasury high-water mark window in seconds (default 7 days)
+    pub peak_window_seconds: Option<u64>,
+    /// Optional address allowed to pause the bridge (in addition to the owner)
+    pub pauser: Option<String>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Deposit native $SHIDO to receive in-game credits.
     /// Credits are granted off-chain by the backend after observing the event.
-    Deposit {},
+    Deposit {
+        /// Player who referred the sender; only recorded on the sender's
+        /// first deposit and ignored afterwards
+        referrer: Option<String>,
+    },
+    /// Pay out the sender's referral rewards in `denom` (defaults to the primary denom)
+    ClaimReferralRewards {
+        denom: Option<String>,
+    },
 
     /// Execute a withdrawal authorized by the oracle/backend.
     /// The oracle signs: (chain_id, contract_addr, nonce, player, credit_amount, token_amount)
     Withdraw {
-        /// Unique nonce to prevent replay
+        /// Unique nonce to prevent replay: "seq:{bucket}:{sequence}", with bucket
+        /// the unix hour it was issued in and sequence unique within that hour
         nonce: String,
         /// Credit amount being withdrawn
         credit_amount: Uint128,
         /// Token amount (ushido) to receive — must match credit_amount at current rate minus fees
         token_amount: Uint128,
-        /// secp256k1 signature over SHA-256 hash of the withdrawal payload
+        /// secp256k1 signature over SHA-256 hash of the withdrawal payload:
+        /// 64-byte (r, s) or 65-byte recoverable (r, s, v)
         signature: Binary,
+        /// Send the net tokens over IBC instead of to the sender on this chain
+        ibc_transfer: Option<IbcTransferTarget>,
+        /// Denom to withdraw; defaults to the primary denom
+        denom: Option<String>,
+        /// Signed payload encoding: 1 (default) legacy string, 2 structured with domain separator
+        payload_version: Option<u8>,
+        /// Pay the net tokens to this address (e.g. the player's cold wallet)
+        /// instead of the sender. Must be bound into a version 2 oracle
+        /// signature; limits still count against the sender.
+        recipient: Option<String>,
+    },
+
+    /// Withdrawal submitted by a relayer for `player`, co-signed with the
+    /// player's session key over the same payload the oracle signed. Always
+    /// paid out to `player` on this chain.
+    WithdrawWithSessionKey {
+        player: String,
+        nonce: String,
+        credit_amount: Uint128,
+        token_amount: Uint128,
+        /// Oracle signature, as in Withdraw
+        signature: Binary,
+        /// Session key signature over the same payload hash
+        session_signature: Binary,
+        denom: Option<String>,
+        payload_version: Option<u8>,
+    },
+    /// Register or replace the sender's session key. `expires_at` is a unix
+    /// timestamp at most MAX_SESSION_KEY_TTL seconds ahead.
+    RegisterSessionKey {
+        pubkey: Binary,
+        expires_at: u64,
+    },
+    /// Remove the sender's session key
+    RevokeSessionKey {},
+    /// Pay out queued withdrawals in queue order while the treasury covers
+    /// them, looking at up to `limit` entries (anyone)
+    ProcessQueue {
+        limit: Option<u32>,
+    },
+
+    /// Schedule a deposit bonus campaign between unix timestamps `start` and
+    /// `end` (owner only). Deposits in the window earn `bonus_bps` extra
+    /// credits until `budget` bonus credits are used up. Campaigns cannot overlap.
+    CreateCampaign {
+        start: u64,
+        end: u64,
+        bonus_bps: u16,
+        budget: Uint128,
+    },
+    /// End a running campaign now, or drop a scheduled one (owner only)
+    EndCampaign {
+        id: u64,
     },
 
-    /// Owner deposits additional $SHIDO to fund the bridge treasury
+    /// Owner deposits any supported denom to fund the bridge treasury
     FundTreasury {},
 
-    /// Owner withdraws excess treasury (cannot go below min_reserve)
+    /// Owner withdraws excess treasury (cannot go below the denom's min_reserve)
     WithdrawTreasury {
         amount: Uint128,
+        /// Defaults to the primary denom
+        denom: Option<String>,
+    },
+
+    /// Owner recovers tokens of a denom the bridge does not handle, e.g. sent
+    /// by mistake. Bridged denoms go through WithdrawTreasury so their
+    /// reserves hold.
+    SweepForeignFunds {
+        denom: String,
+        amount: Uint128,
+        recipient: String,
+    },
+
+    /// Add or update an additional bridged denom (owner only).
+    /// Rate changes for an existing denom must be scheduled instead.
+    SetDenomConfig {
+        denom: String,
+        config: DenomConfig,
+    },
+    /// Stop bridging an additional denom (owner only)
+    RemoveDenom {
+        denom: String,
     },
 
     /// Step 1: propose new oracle (owner only)
@@ -66,16 +157,56 @@ pub enum ExecuteMsg {
     AcceptOracle {},
     /// Cancel pending oracle transfer (owner only)
     CancelOracleTransfer {},
+    /// How long withdrawals signed by the previous oracle key stay valid after
+    /// a rotation, at most 7 days; 0 retires it at once (owner only). Also
+    /// applies to the key replaced by the last rotation.
+    SetOracleKeyGrace {
+        seconds: u64,
+    },
 
-    /// Update conversion rate (owner only)
-    UpdateRate {
+    /// Schedule a conversion rate change (owner only).
+    /// `effective_at` is a unix timestamp at least MIN_RATE_TIMELOCK seconds in the future.
+    ScheduleRateUpdate {
+        /// Defaults to the primary denom
+        denom: Option<String>,
         rate_credits: Uint128,
         rate_tokens: Uint128,
+        effective_at: u64,
+    },
+    /// Apply the scheduled rate once its effective time has passed (anyone)
+    ApplyScheduledRate {},
+    /// Cancel the scheduled rate change (owner only)
+    CancelScheduledRate {},
+    /// Follow a price oracle for the primary denom's rate, within a band
+    /// around the static rate; None returns to the static rate (owner only)
+    SetPriceFeed {
+        feed: Option<PriceFeedMsg>,
     },
     /// Update fee (owner only)
     UpdateFee {
         fee_bps: u16,
     },
+    /// Set how the primary denom's withdrawal fee is rounded and its minimum,
+    /// in micro-units (owner only). Other denoms set these in SetDenomConfig.
+    SetFeePolicy {
+        rounding: FeeRounding,
+        min_fee: Uint128,
+    },
+    /// Reduce the withdrawal fee for holders of an achievement; None removes
+    /// the discount (owner only)
+    SetAchievementDiscount {
+        discount: Option<AchievementDiscountMsg>,
+    },
+    /// Forward part of each withdrawal fee to the player's corporation treasury;
+    /// None sends the whole fee to the treasury again (owner only)
+    SetDaoFeeShare {
+        fee_share: Option<DaoFeeShareMsg>,
+    },
+    /// Share of referred players' withdrawal fees paid to their referrers,
+    /// in basis points of the fee; 0 disables new rewards (owner only)
+    SetReferralBps {
+        bps: u16,
+    },
     /// Update limits (owner only)
     UpdateLimits {
         player_daily_limit: Option<Uint128>,
@@ -83,12 +214,81 @@ pub enum ExecuteMsg {
         cooldown_seconds: Option<u64>,
         min_deposit: Option<Uint128>,
         min_reserve: Option<Uint128>,
+        peak_window_seconds: Option<u64>,
+        /// Rolling window the daily limits apply to, in whole hours (default 24h, max 7 days)
+        limit_window_seconds: Option<u64>,
+    },
+    /// Queue local withdrawals the treasury cannot cover instead of rejecting
+    /// them (owner only). Already queued withdrawals stay payable when disabled.
+    SetWithdrawalQueue {
+        enabled: bool,
+    },
+    /// Drop a queued withdrawal that cannot be paid, e.g. for a blocked player
+    /// (owner only). Its nonce and limit usage are released like a failed send.
+    CancelQueuedWithdrawal {
+        queue_id: u64,
+    },
+    /// Log withdrawals that pass the oracle signature check but fail a limit,
+    /// cap or treasury check to RecentFailures, returning success instead of
+    /// an error so the record is kept (owner only)
+    SetStrictTelemetry {
+        enabled: bool,
+    },
+    /// Set the lifetime withdrawal caps in credits; None leaves that tier
+    /// unlimited (owner only)
+    SetLifetimeCaps {
+        player_cap: Option<Uint128>,
+        kyc_cap: Option<Uint128>,
+    },
+
+    /// Allow (or update) an IBC channel for cross-chain withdrawals (owner only)
+    SetIbcChannel {
+        channel_id: String,
+        timeout_seconds: u64,
+    },
+    /// Disallow an IBC channel (owner only)
+    RemoveIbcChannel {
+        channel_id: String,
     },
 
-    /// Emergency pause (owner only)
+    /// Freeze or unfreeze a player's deposits and withdrawals (owner or oracle)
+    SetPlayerBlocked {
+        address: String,
+        blocked: bool,
+        reason: Option<String>,
+    },
+    /// Mark a player as KYC-verified, moving them to the KYC lifetime cap (owner or oracle)
+    SetKycVerified {
+        address: String,
+        verified: bool,
+    },
+
+    /// Emergency pause of deposits and withdrawals (owner or pauser)
     Pause {},
-    /// Unpause (owner only)
+    /// Halt deposits only, e.g. during backend maintenance (owner or pauser)
+    PauseDeposits {},
+    /// Halt withdrawals and queue payouts only, e.g. during an incident (owner or pauser)
+    PauseWithdrawals {},
+    /// Resume deposits and withdrawals (owner only)
     Unpause {},
+    /// Resume deposits (owner only)
+    UnpauseDeposits {},
+    /// Resume withdrawals (owner only)
+    UnpauseWithdrawals {},
+    /// Set or clear the pauser role (owner only)
+    SetPauser {
+        pauser: Option<String>,
+    },
+    /// Halt deposits and restrict withdrawals to the maintenance whitelist (owner only).
+    /// Disabling clears the whitelist.
+    SetMaintenanceMode {
+        enabled: bool,
+    },
+    /// Add or remove an address allowed to withdraw during maintenance (owner only)
+    SetMaintenanceWhitelist {
+        address: String,
+        allowed: bool,
+    },
 
     // FIX: H-04 — two-step owner transfer
     ProposeOwner { new_owner: String },
@@ -96,6 +296,62 @@ pub enum ExecuteMsg {
     CancelOwnerTransfer {},
 }
 
+#[cw_serde]
+pub struct AchievementDiscountMsg {
+    /// Achievement NFT contract queried with HasAchievement on each withdrawal
+    pub achievement_contract: String,
+    pub achievement_id: String,
+    /// Subtracted from the denom's fee_bps (max 10000)
+    pub discount_bps: u16,
+}
+
+#[cw_serde]
+pub struct DaoFeeShareMsg {
+    /// Corporation DAO queried with CorporationsByMember on each withdrawal
+    pub dao_contract: String,
+    /// Share of the fee donated to the corporation (max 10000)
+    pub dao_fee_share_bps: u16,
+}
+
+#[cw_serde]
+pub struct PriceFeedMsg {
+    /// Oracle contract queried with Price on each conversion
+    pub contract: String,
+    /// Readings older than this fall back to the static rate (1s..=24h)
+    pub max_staleness_seconds: u64,
+    /// Furthest the feed may move the rate from the static rate (1..10000)
+    pub max_deviation_bps: u16,
+}
+
+#[cw_serde]
+pub struct IbcTransferTarget {
+    /// Must be on the owner-managed channel allowlist
+    pub channel_id: String,
+    /// Player's address on the remote chain
+    pub remote_address: String,
+}
+
+/// Lifecycle callbacks delivered by the chain's ibc-hooks module for transfers
+/// sent with an `ibc_callback` memo.
+#[cw_serde]
+pub enum SudoMsg {
+    IbcLifecycleComplete(IbcLifecycleComplete),
+}
+
+#[cw_serde]
+pub enum IbcLifecycleComplete {
+    IbcAck {
+        channel: String,
+        sequence: u64,
+        ack: String,
+        success: bool,
+    },
+    IbcTimeout {
+        channel: String,
+        sequence: u64,
+    },
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -103,42 +359,284 @@ pub enum QueryMsg {
     Config {},
 
     #[returns(TreasuryInfoResponse)]
-    TreasuryInfo {},
+    TreasuryInfo { denom: Option<String> },
+
+    /// Treasury health: balance against 24h withdrawal outflow and pending IBC transfers
+    #[returns(SolvencyResponse)]
+    Solvency { denom: Option<String> },
 
     #[returns(PlayerInfoResponse)]
-    PlayerInfo { address: String },
+    PlayerInfo {
+        address: String,
+        denom: Option<String>,
+    },
+
+    #[returns(PlayerBlockedResponse)]
+    PlayerBlocked { address: String },
+
+    #[returns(BlockedPlayersResponse)]
+    BlockedPlayers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    #[returns(MaintenanceWhitelistResponse)]
+    MaintenanceWhitelist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 
     #[returns(NonceUsedResponse)]
     NonceUsed { nonce: String },
 
+    /// Dry-run a withdrawal: every check except the signatures, without
+    /// changing state. Lists the error each failing check would raise.
+    #[returns(ValidateWithdrawalResponse)]
+    ValidateWithdrawal {
+        player: String,
+        nonce: String,
+        credit_amount: Uint128,
+        token_amount: Uint128,
+        /// Defaults to the primary denom
+        denom: Option<String>,
+    },
+
+    /// Queued withdrawals in payout order
+    #[returns(WithdrawalQueueResponse)]
+    WithdrawalQueue {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    /// Rate, fee and limit changes in the order they were made, starting
+    /// with the parameters at instantiation (or at the upgrade that added
+    /// the history)
+    #[returns(ConfigHistoryResponse)]
+    ConfigHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    /// Latest withdrawals logged by strict telemetry, newest first
+    #[returns(RecentFailuresResponse)]
+    RecentFailures { limit: Option<u32> },
+
+    #[returns(Option<crate::state::SessionKey>)]
+    SessionKey { player: String },
+
+    /// Payload versions and the domain separators currently accepted for version 2
+    #[returns(SigningDomainsResponse)]
+    SigningDomains {},
+
+    /// Withdrawal quote; pass `player` to apply their achievement discount
     #[returns(ConversionResponse)]
-    ConvertCreditsToTokens { credit_amount: Uint128 },
+    ConvertCreditsToTokens {
+        credit_amount: Uint128,
+        denom: Option<String>,
+        player: Option<String>,
+    },
 
     #[returns(ConversionResponse)]
-    ConvertTokensToCredits { token_amount: Uint128 },
+    ConvertTokensToCredits {
+        token_amount: Uint128,
+        denom: Option<String>,
+    },
+
+    /// Effective parameters for a denom (primary or additional)
+    #[returns(DenomConfig)]
+    DenomConfig { denom: String },
+
+    /// Additional denoms configured via SetDenomConfig
+    #[returns(DenomsResponse)]
+    Denoms {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 
     #[returns(Option<crate::state::PendingOracleTransfer>)]
     PendingOracle {},
 
+    /// Oracle keys currently accepted on withdrawals
+    #[returns(OracleKeysResponse)]
+    OracleKeys {},
+
+    #[returns(Option<crate::state::PendingRateUpdate>)]
+    PendingRate {},
+
+    /// Rate deposits and withdrawals use right now, and where it came from.
+    /// With a price feed the rate moves, so quote withdrawals just before signing.
+    #[returns(ExchangeRateResponse)]
+    ExchangeRate { denom: Option<String> },
+
+    #[returns(IbcChannelsResponse)]
+    IbcChannels {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    #[returns(Option<crate::state::IbcTransferRecord>)]
+    IbcTransfer { channel_id: String, sequence: u64 },
+
+    /// Receipt stored for a deposit, by the id emitted in its `receipt_id` attribute
+    #[returns(Option<crate::state::DepositReceipt>)]
+    DepositReceipt { id: u64 },
+
+    /// Who referred `address`, how many players they referred, and their rewards per denom
+    #[returns(ReferralStatsResponse)]
+    ReferralStats { address: String },
+
+    #[returns(Option<crate::state::BonusCampaign>)]
+    BonusCampaign { id: u64 },
+
+    /// Campaigns in id (and start) order
+    #[returns(BonusCampaignsResponse)]
+    BonusCampaigns {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    /// The campaign whose window contains the current block time
+    #[returns(Option<crate::state::BonusCampaign>)]
+    ActiveBonusCampaign {},
+
     // FIX: H-04
     #[returns(Option<crate::state::PendingOwnerTransfer>)]
     PendingOwner {},
 }
 
+#[cw_serde]
+pub struct ReferralStatsResponse {
+    pub referrer: Option<String>,
+    pub referred_count: u64,
+    pub rewards: Vec<ReferralRewardEntry>,
+}
+
+#[cw_serde]
+pub struct ReferralRewardEntry {
+    pub denom: String,
+    pub claimable: Uint128,
+    pub total_earned: Uint128,
+}
+
+#[cw_serde]
+pub struct BonusCampaignsResponse {
+    pub campaigns: Vec<crate::state::BonusCampaign>,
+}
+
+#[cw_serde]
+pub struct WithdrawalQueueResponse {
+    pub withdrawals: Vec<crate::state::QueuedWithdrawal>,
+}
+
+#[cw_serde]
+pub struct ConfigHistoryResponse {
+    pub changes: Vec<crate::state::ConfigChange>,
+}
+
+#[cw_serde]
+pub struct RecentFailuresResponse {
+    pub failures: Vec<crate::state::FailureRecord>,
+}
+
+#[cw_serde]
+pub struct DenomsResponse {
+    pub denoms: Vec<DenomInfo>,
+}
+
+#[cw_serde]
+pub struct DenomInfo {
+    pub denom: String,
+    pub config: DenomConfig,
+}
+
 #[cw_serde]
 pub struct TreasuryInfoResponse {
+    pub denom: String,
     pub balance: Uint128,
     pub min_reserve: Uint128,
     pub peak_balance: Uint128,
+    /// Balance above the reserve that queued withdrawals have not claimed
     pub available_for_withdrawal: Uint128,
 }
 
+#[cw_serde]
+pub struct SolvencyResponse {
+    pub denom: String,
+    pub balance: Uint128,
+    pub min_reserve: Uint128,
+    /// Rolling high-water mark over the configured peak window
+    pub peak_balance: Uint128,
+    /// Tokens paid out by withdrawals (net + fee) over the last 24 hours
+    pub outflow_24h: Uint128,
+    /// balance / outflow_24h; None when nothing was withdrawn in the last 24 hours
+    pub solvency_ratio: Option<Decimal>,
+    /// IBC withdrawals dispatched but not yet acknowledged or timed out
+    pub pending_ibc_transfers: u64,
+    pub pending_ibc_amount: Uint128,
+    /// Withdrawals waiting in the queue for the treasury, and what they will pay out (net + fee)
+    pub queued_withdrawals: u64,
+    pub queued_amount: Uint128,
+    pub reserve_breached: bool,
+}
+
 #[cw_serde]
 pub struct PlayerInfoResponse {
+    /// Credits withdrawn within the limit window (24h unless reconfigured)
     pub withdrawals_24h: Uint128,
     pub daily_limit: Uint128,
     pub remaining_limit: Uint128,
     pub cooldown_until: Option<u64>,
+    pub limit_window_seconds: u64,
+    /// Credits withdrawn so far, all denoms combined
+    pub lifetime_withdrawn: Uint128,
+    /// Lifetime cap for the player's KYC tier; None is unlimited
+    pub lifetime_cap: Option<Uint128>,
+    pub kyc_verified: bool,
+}
+
+#[cw_serde]
+pub struct PlayerBlockedResponse {
+    pub blocked: bool,
+    pub info: Option<BlockedPlayer>,
+}
+
+#[cw_serde]
+pub struct BlockedPlayersResponse {
+    pub players: Vec<BlockedPlayerEntry>,
+}
+
+#[cw_serde]
+pub struct BlockedPlayerEntry {
+    pub address: String,
+    pub info: BlockedPlayer,
+}
+
+#[cw_serde]
+pub struct SigningDomainsResponse {
+    pub payload_versions: Vec<u8>,
+    /// Current contract version first, then the pre-migration version if any
+    pub domain_separators: Vec<Binary>,
+}
+
+#[cw_serde]
+pub struct OracleKeyInfo {
+    pub version: u32,
+    pub pubkey: Binary,
+    /// End of the grace window; None for the current key
+    pub valid_until: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct OracleKeysResponse {
+    pub current: OracleKeyInfo,
+    /// The key replaced by the last rotation, while its grace window is open
+    pub previous: Option<OracleKeyInfo>,
+    pub grace_seconds: u64,
+}
+
+#[cw_serde]
+pub struct MaintenanceWhitelistResponse {
+    pub addresses: Vec<String>,
 }
 
 #[cw_serde]
@@ -146,6 +644,28 @@ pub struct NonceUsedResponse {
     pub used: bool,
 }
 
+#[cw_serde]
+pub struct ValidateWithdrawalResponse {
+    /// True when every check passed; signatures are not verified
+    pub valid: bool,
+    /// The treasury cannot cover it yet, or withdrawals are already queued
+    /// for the denom, so the withdrawal queue would hold it (local payouts only)
+    pub would_queue: bool,
+    /// In the order Withdraw runs them. Checks that need an earlier one to
+    /// pass (the amount, limit and reserve checks need a supported denom,
+    /// the reserve check a matching amount) are left out when it fails.
+    pub checks: Vec<WithdrawalCheck>,
+}
+
+#[cw_serde]
+pub struct WithdrawalCheck {
+    /// "withdrawals_open", "not_blocked", "denom", "nonce", "amount",
+    /// "cooldown", "player_limit", "lifetime_cap", "global_limit" or "reserve"
+    pub check: String,
+    /// Error Withdraw would fail with; None if the check passed
+    pub error: Option<String>,
+}
+
 #[cw_serde]
 pub struct ConversionResponse {
     pub credit_amount: Uint128,
@@ -153,5 +673,101 @@ pub struct ConversionResponse {
     pub fee_amount: Uint128,
 }
 
+#[cw_serde]
+pub struct ExchangeRateResponse {
+    pub denom: String,
+    pub rate_credits: Uint128,
+    pub rate_tokens: Uint128,
+    pub source: RateSource,
+    /// Time of the feed reading in use, if any
+    pub feed_updated_at: Option<u64>,
+}
+
+#[cw_serde]
+pub enum RateSource {
+    /// The owner-set rate: no feed configured, or its reading was stale or unavailable
+    Static,
+    Feed,
+    /// The feed reading, limited to the configured deviation from the static rate
+    FeedClamped,
+}
+
+#[cw_serde]
+pub struct IbcChannelsResponse {
+    pub channels: Vec<IbcChannelInfo>,
+}
+
+#[cw_serde]
+pub struct IbcChannelInfo {
+    pub channel_id: String,
+    pub timeout_seconds: u64,
+}
+
 #[cw_serde]
 pub struct MigrateMsg {}
+
+// ─── Queries sent to the achievement NFT contract ───────────────────────────
+
+#[cw_serde]
+pub enum AchievementQueryMsg {
+    HasAchievement {
+        owner: String,
+        achievement_id: String,
+    },
+}
+
+/// The only HasAchievement field the bridge reads; other fields are ignored.
+#[derive(serde::Deserialize)]
+pub struct HasAchievementResponse {
+    pub has_achievement: bool,
+}
+
+// ─── Queries sent to the price feed ─────────────────────────────────────────
+
+#[cw_serde]
+pub enum PriceFeedQueryMsg {
+    Price { denom: String },
+}
+
+#[derive(serde::Deserialize)]
+pub struct PriceFeedResponse {
+    /// Token micro-units one credit is worth
+    pub price: Decimal,
+    /// Unix timestamp of the reading
+    pub updated_at: u64,
+}
+
+// ─── Messages sent to the corporation DAO ───────────────────────────────────
+
+#[cw_serde]
+pub enum DaoQueryMsg {
+    Config {},
+    CorporationsByMember {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub enum DaoExecuteMsg {
+    DonateTreasury { corp_id: u64 },
+}
+
+/// The only DAO config field the bridge reads.
+#[derive(serde::Deserialize)]
+pub struct DaoConfigResponse {
+    pub denom: String,
+}
+
+#[derive(serde::Deserialize)]
+pub struct DaoCorporationsResponse {
+    pub corporations: Vec<DaoMembership>,
+}
+
+#[derive(serde::Deserialize)]
+pub struct DaoMembership {
+    pub corp_id: u64,
+    /// "active", "dissolving" or "dissolved"
+    pub status: String,
+}
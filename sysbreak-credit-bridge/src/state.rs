@@ -1,13 +1,21 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Binary, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
+pub use sysbreak_common::PendingOwnerTransfer;
+use sysbreak_common::{ApplyToConfig, Owned, RoleProposal, RoleTransfer};
 
 #[cw_serde]
 pub struct Config {
     pub owner: Addr,
     /// Backend oracle wallet that signs withdrawal authorizations
     pub oracle: Addr,
-    pub paused: bool,
+    /// Halts withdrawals and queue payouts. Stored as `paused` before deposits
+    /// could be paused separately.
+    #[serde(alias = "paused")]
+    pub withdrawals_paused: bool,
+    /// Halts deposits
+    #[serde(default)]
+    pub deposits_paused: bool,
     /// Native token denomination (e.g. "ushido")
     pub denom: String,
     /// Credits per token micro-unit (e.g. 10_000 credits = 1_000_000 ushido means rate = 10_000 / 1_000_000)
@@ -34,6 +42,181 @@ pub struct Config {
     pub oracle_pubkey: cosmwasm_std::Binary,
     /// Chain ID included in signed payloads to prevent cross-chain replay
     pub chain_id: String,
+    /// Hot key (e.g. a monitoring bot) allowed to pause but not unpause or reconfigure
+    pub pauser: Option<Addr>,
+    /// Window over which the treasury high-water mark is tracked
+    #[serde(default = "default_peak_window_seconds")]
+    pub peak_window_seconds: u64,
+    /// Rolling window the player and global withdrawal limits apply to
+    #[serde(default = "default_limit_window_seconds")]
+    pub limit_window_seconds: u64,
+    /// Incident recovery: deposits halted, withdrawals limited to MAINTENANCE_WHITELIST
+    #[serde(default)]
+    pub maintenance_mode: bool,
+    /// Withdrawal fee reduction for holders of an achievement; None disables it
+    #[serde(default)]
+    pub achievement_discount: Option<AchievementDiscount>,
+    /// Part of each withdrawal fee paid into the player's corporation treasury; None disables it
+    #[serde(default)]
+    pub dao_fee_share: Option<DaoFeeShare>,
+    /// Credits a player without KYC may withdraw over the bridge's lifetime; None is unlimited
+    #[serde(default)]
+    pub player_lifetime_cap: Option<Uint128>,
+    /// Credits a KYC-verified player may withdraw over the bridge's lifetime; None is unlimited
+    #[serde(default)]
+    pub kyc_lifetime_cap: Option<Uint128>,
+    /// Bumped each time the oracle key is rotated
+    #[serde(default = "default_oracle_key_version")]
+    pub oracle_key_version: u32,
+    /// How long the previous oracle key still signs withdrawals after a rotation
+    #[serde(default = "default_oracle_key_grace_seconds")]
+    pub oracle_key_grace_seconds: u64,
+    /// Share of a referred player's withdrawal fees credited to their referrer; 0 disables it
+    #[serde(default)]
+    pub referral_bps: u16,
+    /// Oracle the primary denom's rate follows; None keeps the static rate
+    #[serde(default)]
+    pub price_feed: Option<PriceFeed>,
+    /// Queue local withdrawals the treasury cannot cover yet instead of rejecting them
+    #[serde(default)]
+    pub withdrawal_queue: bool,
+    /// Log signed withdrawals that fail their limit or treasury checks to
+    /// FAILURE_LOG and return success, so the record is not reverted with them
+    #[serde(default)]
+    pub strict_telemetry: bool,
+    /// How the primary denom's withdrawal fee is rounded to whole micro-units
+    #[serde(default)]
+    pub fee_rounding: FeeRounding,
+    /// Smallest withdrawal fee charged in the primary denom, in micro-units
+    #[serde(default)]
+    pub min_fee: Uint128,
+}
+
+impl Owned for Config {
+    fn owner(&self) -> &Addr {
+        &self.owner
+    }
+
+    fn set_owner(&mut self, owner: Addr) {
+        self.owner = owner;
+    }
+}
+
+fn default_peak_window_seconds() -> u64 {
+    DEFAULT_PEAK_WINDOW
+}
+
+fn default_limit_window_seconds() -> u64 {
+    DEFAULT_LIMIT_WINDOW
+}
+
+fn default_oracle_key_version() -> u32 {
+    1
+}
+
+fn default_oracle_key_grace_seconds() -> u64 {
+    DEFAULT_ORACLE_KEY_GRACE
+}
+
+/// Bridging parameters for a denom. The primary denom's parameters live on
+/// `Config`; additional denoms are stored in `DENOM_CONFIGS`.
+#[cw_serde]
+pub struct DenomConfig {
+    pub rate_credits: Uint128,
+    pub rate_tokens: Uint128,
+    pub fee_bps: u16,
+    pub min_deposit: Uint128,
+    pub player_daily_limit: Uint128,
+    pub global_daily_limit: Uint128,
+    pub min_reserve: Uint128,
+    #[serde(default)]
+    pub fee_rounding: FeeRounding,
+    #[serde(default)]
+    pub min_fee: Uint128,
+}
+
+/// How `amount * fee_bps / 10_000` is rounded to whole micro-units
+#[cw_serde]
+#[derive(Default)]
+pub enum FeeRounding {
+    /// Truncate, the behaviour before rounding was configurable
+    #[default]
+    Floor,
+    Ceil,
+    /// Round to nearest, halves up
+    HalfUp,
+}
+
+impl FeeRounding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeeRounding::Floor => "floor",
+            FeeRounding::Ceil => "ceil",
+            FeeRounding::HalfUp => "half_up",
+        }
+    }
+}
+
+/// One entry of the parameter history: what a denom's rate, fee and limits
+/// were from `changed_at` until the next entry for that denom
+#[cw_serde]
+pub struct ConfigChange {
+    pub id: u64,
+    pub changed_at: Timestamp,
+    /// The admin who made the change, or whoever applied a scheduled rate
+    pub changed_by: Addr,
+    /// The action that made the change, e.g. `update_fee`
+    pub action: String,
+    pub denom: String,
+    /// None once the denom is no longer bridged
+    pub params: Option<DenomConfig>,
+    /// Limits shared by all denoms at the time
+    pub cooldown_seconds: u64,
+    pub limit_window_seconds: u64,
+    pub player_lifetime_cap: Option<Uint128>,
+    pub kyc_lifetime_cap: Option<Uint128>,
+    /// Fee adjustments in force at the time
+    #[serde(default)]
+    pub achievement_discount: Option<AchievementDiscount>,
+    #[serde(default)]
+    pub referral_bps: u16,
+    #[serde(default)]
+    pub dao_fee_share: Option<DaoFeeShare>,
+    /// Oracle the primary denom's rate followed at the time
+    #[serde(default)]
+    pub price_feed: Option<PriceFeed>,
+}
+
+/// Players holding `achievement_id` on the achievement NFT contract pay
+/// `discount_bps` less withdrawal fee (floored at zero), on every denom.
+#[cw_serde]
+pub struct AchievementDiscount {
+    pub achievement_contract: Addr,
+    pub achievement_id: String,
+    pub discount_bps: u16,
+}
+
+/// `dao_fee_share_bps` of each withdrawal fee goes to the withdrawing player's
+/// corporation treasury on the DAO instead of the bridge treasury. Only fees
+/// in the DAO's treasury denom are shared, and players outside an active
+/// corporation pay the full fee to the treasury.
+#[cw_serde]
+pub struct DaoFeeShare {
+    pub dao_contract: Addr,
+    /// The DAO's treasury denom, read from its config when the share is set
+    pub denom: String,
+    pub dao_fee_share_bps: u16,
+}
+
+/// Price oracle contract for the primary denom. Readings older than
+/// `max_staleness_seconds` fall back to the static rate; fresh ones are
+/// clamped to within `max_deviation_bps` of it, so the static rate also
+/// bounds how far a faulty feed can move prices.
+#[cw_serde]
+pub struct PriceFeed {
+    pub contract: Addr,
+    pub max_staleness_seconds: u64,
+    pub max_deviation_bps: u16,
 }
 
 #[cw_serde]
@@ -42,48 +225,384 @@ pub struct PendingOracleTransfer {
     pub proposed_pubkey: cosmwasm_std::Binary,
 }
 
-// FIX: H-04 — two-step owner transfer state
+impl RoleProposal for PendingOracleTransfer {
+    fn proposed(&self) -> &Addr {
+        &self.proposed_oracle
+    }
+}
+
+impl ApplyToConfig<Config> for PendingOracleTransfer {
+    fn apply(self, config: &mut Config) {
+        config.oracle = self.proposed_oracle;
+        config.oracle_pubkey = self.proposed_pubkey;
+    }
+}
+
+/// Oracle key replaced by the last rotation. Withdrawals it signed are still
+/// accepted until `rotated_at` + `Config::oracle_key_grace_seconds`.
+#[cw_serde]
+pub struct PreviousOracleKey {
+    pub version: u32,
+    pub pubkey: cosmwasm_std::Binary,
+    pub rotated_at: Timestamp,
+}
+
+/// Rate change announced ahead of time; applied once `effective_at` has passed
+#[cw_serde]
+pub struct PendingRateUpdate {
+    /// Target denom; None for the primary denom
+    pub denom: Option<String>,
+    pub rate_credits: Uint128,
+    pub rate_tokens: Uint128,
+    pub effective_at: Timestamp,
+}
+
+/// Owner-approved IBC channel for cross-chain withdrawals
+#[cw_serde]
+pub struct IbcChannelConfig {
+    /// Packet timeout, measured on the remote chain
+    pub timeout_seconds: u64,
+}
+
+/// Net withdrawal tokens in flight over IBC, refunded to the player on timeout or failed ack
+#[cw_serde]
+pub struct IbcTransferRecord {
+    pub player: Addr,
+    pub channel_id: String,
+    pub remote_address: String,
+    pub amount: Uint128,
+    pub denom: String,
+    pub nonce: String,
+}
+
+/// Local withdrawal whose bank send is in flight; undone in `reply` if the send fails
+#[cw_serde]
+pub struct PendingWithdrawal {
+    pub player: Addr,
+    /// Signed payout address when it is not the player
+    #[serde(default)]
+    pub recipient: Option<Addr>,
+    pub denom: String,
+    pub nonce: String,
+    pub credit_amount: Uint128,
+    pub token_amount: Uint128,
+    /// Paid to the treasury only once the player's send succeeds
+    pub fee: Uint128,
+    pub withdrawn_at: Timestamp,
+    /// Player's previous cooldown anchor, restored on rollback
+    pub previous_withdrawal: Option<Timestamp>,
+}
+
+/// Authorized local withdrawal waiting for the treasury to be refilled. The
+/// nonce and the player's limits were consumed when it was queued; `ProcessQueue`
+/// pays it out in queue order, or rolls it back like a failed send.
 #[cw_serde]
-pub struct PendingOwnerTransfer {
-    pub proposed_owner: Addr,
+pub struct QueuedWithdrawal {
+    pub id: u64,
+    pub player: Addr,
+    /// Signed payout address when it is not the player
+    #[serde(default)]
+    pub recipient: Option<Addr>,
+    pub denom: String,
+    pub nonce: String,
+    pub credit_amount: Uint128,
+    pub token_amount: Uint128,
+    pub fee: Uint128,
+    pub queued_at: Timestamp,
+}
+
+/// Outstanding IBC withdrawals for a denom
+#[cw_serde]
+#[derive(Default)]
+pub struct PendingTotals {
+    pub count: u64,
+    pub amount: Uint128,
+}
+
+/// What the contract recorded for a deposit. `hash` commits to every other field
+/// (see `build_receipt_hash`) so the backend can prove a receipt was not altered.
+#[cw_serde]
+pub struct DepositReceipt {
+    pub id: u64,
+    pub player: Addr,
+    pub denom: String,
+    pub token_amount: Uint128,
+    pub credit_amount: Uint128,
+    pub block_height: u64,
+    pub timestamp: Timestamp,
+    pub hash: Binary,
 }
 
-/// Per-player withdrawal tracking for rolling 24h window
+/// Owner-run deposit promotion: deposits in [start, end) earn `bonus_bps`
+/// extra credits until the campaign has granted `budget` bonus credits.
+#[cw_serde]
+pub struct BonusCampaign {
+    pub id: u64,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    pub bonus_bps: u16,
+    /// Bonus credits the campaign may grant in total
+    pub budget: Uint128,
+    /// Bonus credits granted so far
+    pub spent: Uint128,
+}
+
+/// A referrer's rewards in one denom
+#[cw_serde]
+#[derive(Default)]
+pub struct ReferralReward {
+    pub claimable: Uint128,
+    pub total_earned: Uint128,
+}
+
+/// Compliance freeze on a player address
+#[cw_serde]
+pub struct BlockedPlayer {
+    pub reason: String,
+    pub blocked_by: Addr,
+    pub blocked_at: Timestamp,
+}
+
+/// Temporary key a player registered so relayers can submit withdrawals for them
+#[cw_serde]
+pub struct SessionKey {
+    /// secp256k1 public key (33 bytes compressed or 65 uncompressed)
+    pub pubkey: cosmwasm_std::Binary,
+    pub expires_at: Timestamp,
+}
+
+/// Legacy per-withdrawal record, folded into WithdrawalBucket on migrate
 #[cw_serde]
 pub struct WithdrawalRecord {
     pub amount_credits: Uint128,
     pub timestamp: Timestamp,
+    /// Denom withdrawn; None on records written before multi-denom support (primary denom)
+    pub denom: Option<String>,
+}
+
+/// Credits withdrawn during one hour. Stored in a ring of LIMIT_RING_SLOTS slots
+/// (slot = hour % LIMIT_RING_SLOTS); a slot holding an older hour is stale.
+#[cw_serde]
+pub struct WithdrawalBucket {
+    pub hour: u64,
+    pub amount_credits: Uint128,
+}
+
+/// Why a withdrawal with a valid oracle signature did not pay out
+#[cw_serde]
+pub enum FailureReason {
+    Cooldown,
+    PlayerDailyLimit,
+    LifetimeCap,
+    GlobalDailyLimit,
+    InsufficientTreasury,
+    /// The bank send reverted, e.g. to a blocked recipient
+    SendFailed,
+    Other,
+}
+
+impl FailureReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FailureReason::Cooldown => "cooldown",
+            FailureReason::PlayerDailyLimit => "player_daily_limit",
+            FailureReason::LifetimeCap => "lifetime_cap",
+            FailureReason::GlobalDailyLimit => "global_daily_limit",
+            FailureReason::InsufficientTreasury => "insufficient_treasury",
+            FailureReason::SendFailed => "send_failed",
+            FailureReason::Other => "other",
+        }
+    }
+}
+
+/// One entry of the strict telemetry failure log
+#[cw_serde]
+pub struct FailureRecord {
+    pub player: Addr,
+    pub reason: FailureReason,
+    pub failed_at: Timestamp,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
-pub const PENDING_ORACLE: Item<PendingOracleTransfer> = Item::new("pending_oracle");
+/// The single pause flag of the pre-split layout; read once by migrate to
+/// pause deposits as well
+pub const LEGACY_PAUSE: Item<LegacyPause> = Item::new("config");
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct LegacyPause {
+    #[serde(default)]
+    pub paused: Option<bool>,
+}
+pub const ORACLE_TRANSFER: RoleTransfer<PendingOracleTransfer> =
+    RoleTransfer::new("oracle", "pending_oracle");
+pub const PENDING_RATE: Item<PendingRateUpdate> = Item::new("pending_rate");
 
-/// Nonce replay protection: nonce_string -> true
+/// Nonce replay protection for legacy "{timestamp}:{random}" nonces: nonce_string -> true
 pub const USED_NONCES: Map<&str, bool> = Map::new("used_nonces");
+/// Legacy nonces are accepted until this time; afterwards USED_NONCES is only pruned.
+/// Set one expiry window out by the migration that introduced sequenced nonces.
+pub const LEGACY_NONCES_UNTIL: Item<Timestamp> = Item::new("legacy_nonces_until");
+/// Used sequenced nonces: (bucket, sequence / 64) -> bitmap of sequence % 64
+pub const NONCE_BITMAPS: Map<(u64, u64), u64> = Map::new("nonce_bitmaps");
 
-/// Per-player withdrawal history: player_addr -> Vec<WithdrawalRecord>
-/// We store recent withdrawal records for rolling window calculation
+/// Legacy per-player withdrawal history, replaced by PLAYER_WD_BUCKETS (removed on migrate)
 pub const PLAYER_WITHDRAWALS: Map<&Addr, Vec<WithdrawalRecord>> = Map::new("player_wd");
 
 /// Per-player last withdrawal timestamp for cooldown
 pub const PLAYER_LAST_WITHDRAWAL: Map<&Addr, Timestamp> = Map::new("player_last_wd");
 
-/// Global withdrawal records for rolling 24h window
+/// Legacy global withdrawal history, replaced by GLOBAL_WD_BUCKETS (removed on migrate)
 pub const GLOBAL_WITHDRAWALS: Item<Vec<WithdrawalRecord>> = Item::new("global_wd");
 
-/// Peak treasury balance tracking for reserve ratio calculation
+/// Legacy all-time peak balance, replaced by BALANCE_SAMPLES (removed on migrate)
 pub const PEAK_BALANCE: Item<Uint128> = Item::new("peak_balance");
 
 // FIX: H-04 — pending owner transfer storage
-pub const PENDING_OWNER: Item<PendingOwnerTransfer> = Item::new("pending_owner");
+pub const OWNER_TRANSFER: RoleTransfer<PendingOwnerTransfer> =
+    RoleTransfer::new("owner", "pending_owner");
 
 // FIX: M-04 — Map-based global withdrawals for scalability
-/// Global withdrawal records: counter -> WithdrawalRecord
+/// Legacy global withdrawal records: counter -> WithdrawalRecord (removed on migrate)
 pub const GLOBAL_WITHDRAWAL_RECORDS: Map<u64, WithdrawalRecord> = Map::new("global_wd_map");
-/// Counter for global withdrawal record IDs
+/// Legacy counter for global withdrawal record IDs (removed on migrate)
 pub const GLOBAL_WD_COUNTER: Item<u64> = Item::new("global_wd_counter");
-/// Oldest un-pruned entry index for efficient iteration
+/// Legacy oldest un-pruned entry index (removed on migrate)
 pub const GLOBAL_WD_OLDEST: Item<u64> = Item::new("global_wd_oldest");
 
+/// Default withdrawal limit window (24 hours)
+pub const DEFAULT_LIMIT_WINDOW: u64 = 86_400;
+/// Longest configurable withdrawal limit window (7 days)
+pub const MAX_LIMIT_WINDOW: u64 = 604_800;
+/// Hourly slots in the withdrawal rings, enough to cover MAX_LIMIT_WINDOW
+pub const LIMIT_RING_SLOTS: u64 = MAX_LIMIT_WINDOW / BUCKET_SECONDS;
+/// Per-player withdrawal ring: (player, denom, slot) -> WithdrawalBucket
+pub const PLAYER_WD_BUCKETS: Map<(&Addr, &str, u64), WithdrawalBucket> =
+    Map::new("player_wd_buckets");
+/// Global withdrawal ring: (denom, slot) -> WithdrawalBucket
+pub const GLOBAL_WD_BUCKETS: Map<(&str, u64), WithdrawalBucket> = Map::new("global_wd_buckets");
+
+/// Granularity of the balance high-water mark and outflow tracking (1 hour)
+pub const BUCKET_SECONDS: u64 = 3_600;
+/// Default high-water mark window (7 days)
+pub const DEFAULT_PEAK_WINDOW: u64 = 604_800;
+/// Longest configurable high-water mark window (30 days)
+pub const MAX_PEAK_WINDOW: u64 = 2_592_000;
+
+/// Contract version before the last migration. Structured (v2) withdrawal payloads
+/// signed against this version's domain separator stay valid until the next migration.
+pub const PREVIOUS_CONTRACT_VERSION: Item<String> = Item::new("prev_contract_version");
+
+/// Key replaced by the last oracle rotation
+pub const PREVIOUS_ORACLE_KEY: Item<PreviousOracleKey> = Item::new("prev_oracle_key");
+/// Default grace window for the previous oracle key (24 hours)
+pub const DEFAULT_ORACLE_KEY_GRACE: u64 = 86_400;
+/// Longest configurable grace window for the previous oracle key (7 days)
+pub const MAX_ORACLE_KEY_GRACE: u64 = 604_800;
+
+/// Legacy "withdraw:..." string payload
+pub const PAYLOAD_VERSION_LEGACY: u8 = 1;
+/// Length-prefixed structured payload bound to a versioned domain separator
+pub const PAYLOAD_VERSION_STRUCTURED: u8 = 2;
+
+/// Minimum delay between scheduling a rate change and it taking effect (24 hours)
+pub const MIN_RATE_TIMELOCK: u64 = 86_400;
+/// Oldest price feed reading that may still be trusted (24 hours)
+pub const MAX_PRICE_STALENESS: u64 = 86_400;
+
+/// Additional bridged denoms: denom -> DenomConfig
+pub const DENOM_CONFIGS: Map<&str, DenomConfig> = Map::new("denom_configs");
+/// Highest observed treasury balance per hourly bucket: (denom, bucket) -> balance
+pub const BALANCE_SAMPLES: Map<(&str, u64), Uint128> = Map::new("balance_samples");
+/// Tokens paid out (net + fee) per hourly bucket: (denom, bucket) -> amount
+pub const OUTFLOW_BUCKETS: Map<(&str, u64), Uint128> = Map::new("outflow_buckets");
+
+/// Bonus campaigns by id. Windows never overlap and ids follow start time.
+pub const BONUS_CAMPAIGNS: Map<u64, BonusCampaign> = Map::new("bonus_campaigns");
+pub const BONUS_CAMPAIGN_COUNT: Item<u64> = Item::new("bonus_campaign_count");
+/// Longest a bonus campaign may run (30 days)
+pub const MAX_CAMPAIGN_DURATION: u64 = 2_592_000;
+
+/// Players who have deposited since referrals were introduced; a referrer
+/// can only be recorded on the first deposit
+pub const DEPOSITORS: Map<&Addr, ()> = Map::new("depositors");
+/// Referred player -> referrer
+pub const REFERRERS: Map<&Addr, Addr> = Map::new("referrers");
+/// Referrer -> number of players referred
+pub const REFERRAL_COUNTS: Map<&Addr, u64> = Map::new("referral_counts");
+/// (referrer, denom) -> rewards
+pub const REFERRAL_REWARDS: Map<(&Addr, &str), ReferralReward> = Map::new("referral_rewards");
+/// Unclaimed referral rewards per denom, held back from treasury withdrawals
+pub const REFERRAL_OUTSTANDING: Map<&str, Uint128> = Map::new("referral_outstanding");
+
+/// Deposit receipts: sequence id -> DepositReceipt
+pub const RECEIPTS: Map<u64, DepositReceipt> = Map::new("receipts");
+/// Last issued receipt id
+pub const RECEIPT_COUNTER: Item<u64> = Item::new("receipt_counter");
+
+/// Players frozen from depositing and withdrawing: player_addr -> BlockedPlayer
+pub const BLOCKED_PLAYERS: Map<&Addr, BlockedPlayer> = Map::new("blocked_players");
+/// Registered session keys: player_addr -> SessionKey
+pub const SESSION_KEYS: Map<&Addr, SessionKey> = Map::new("session_keys");
+/// Longest session key lifetime (30 days)
+pub const MAX_SESSION_KEY_TTL: u64 = 2_592_000;
+/// Addresses allowed to withdraw in maintenance mode; cleared when maintenance ends
+pub const MAINTENANCE_WHITELIST: Map<&Addr, bool> = Map::new("maintenance_whitelist");
+/// Players whose KYC the oracle has confirmed: player_addr -> true
+pub const KYC_VERIFIED: Map<&Addr, bool> = Map::new("kyc_verified");
+/// Credits withdrawn by each player, all denoms combined: player_addr -> credits
+pub const PLAYER_LIFETIME_WITHDRAWN: Map<&Addr, Uint128> = Map::new("player_lifetime_wd");
+
+/// Allowed IBC channels: channel_id -> IbcChannelConfig
+pub const IBC_CHANNELS: Map<&str, IbcChannelConfig> = Map::new("ibc_channels");
+/// Transfer dispatched in the current tx, waiting for its packet sequence in `reply`
+pub const PENDING_IBC_TRANSFER: Item<IbcTransferRecord> = Item::new("pending_ibc");
+/// In-flight IBC transfers: (channel_id, packet sequence) -> IbcTransferRecord
+pub const IBC_TRANSFERS: Map<(&str, u64), IbcTransferRecord> = Map::new("ibc_transfers");
+
+/// Aggregate of IBC_TRANSFERS per denom: denom -> PendingTotals
+pub const IBC_PENDING_TOTALS: Map<&str, PendingTotals> = Map::new("ibc_pending_totals");
+
+/// Reply id for the IbcMsg::Transfer submessage
+pub const IBC_TRANSFER_REPLY_ID: u64 = 1;
+/// Reply id for the local withdrawal BankMsg::Send submessage
+pub const WITHDRAW_SEND_REPLY_ID: u64 = 2;
+/// Local withdrawal dispatched in the current tx, settled or rolled back in `reply`
+pub const PENDING_WITHDRAWAL: Item<PendingWithdrawal> = Item::new("pending_withdrawal");
+/// Reply id for a queued withdrawal's BankMsg::Send; the payload carries the queue id
+pub const QUEUE_PAYOUT_REPLY_ID: u64 = 3;
+/// Reply id for a fee share donated to a corporation; the payload carries the coin
+pub const DAO_DONATION_REPLY_ID: u64 = 4;
+
+/// Append-only record of rate, fee and limit changes: id -> ConfigChange
+pub const CONFIG_HISTORY: Map<u64, ConfigChange> = Map::new("config_history");
+/// Last issued history id
+pub const CONFIG_HISTORY_COUNT: Item<u64> = Item::new("config_history_count");
+
+/// Withdrawals waiting on the treasury, in arrival order: id -> QueuedWithdrawal
+pub const WITHDRAWAL_QUEUE: Map<u64, QueuedWithdrawal> = Map::new("withdrawal_queue");
+/// Last issued queue id
+pub const WITHDRAWAL_QUEUE_COUNT: Item<u64> = Item::new("withdrawal_queue_count");
+/// Last queue id ProcessQueue moved past; the next call resumes after it
+pub const WITHDRAWAL_QUEUE_CURSOR: Item<u64> = Item::new("withdrawal_queue_cursor");
+/// Aggregate of WITHDRAWAL_QUEUE per denom (net + fee), held back from the treasury
+pub const QUEUED_TOTALS: Map<&str, PendingTotals> = Map::new("queued_totals");
+/// Default and largest number of queue entries one ProcessQueue call looks at
+pub const DEFAULT_QUEUE_BATCH: u32 = 10;
+pub const MAX_QUEUE_BATCH: u32 = 30;
+
+/// Ring of the last FAILURE_LOG_SIZE failed withdrawals: count % FAILURE_LOG_SIZE -> record
+pub const FAILURE_LOG: Map<u64, FailureRecord> = Map::new("failure_log");
+/// Failures ever logged; the next one lands in slot FAILURE_COUNT % FAILURE_LOG_SIZE
+pub const FAILURE_COUNT: Item<u64> = Item::new("failure_count");
+/// Nonces that failed their limit or treasury checks: (nonce bucket, nonce) -> true.
+/// A replayed authorization is logged only once, so it cannot flush other players'
+/// records out of the ring; entries are pruned with the nonce buckets.
+pub const FAILED_NONCES: Map<(u64, &str), bool> = Map::new("failed_nonces");
+pub const FAILURE_LOG_SIZE: u64 = 100;
+
 // FIX: M-03 — nonce expiry window (7 days)
 pub const NONCE_EXPIRY_WINDOW: u64 = 604_800;
+/// Width of a sequenced nonce's timestamp bucket
+pub const NONCE_BUCKET_SECONDS: u64 = 3_600;
+/// Stale nonce entries each withdrawal clears on its way through
+pub const NONCE_PRUNE_BATCH: usize = 5;
@@ -1,17 +1,35 @@
 use cosmwasm_std::testing::{
-    message_info, mock_dependencies, mock_dependencies_with_balance, mock_env, MockApi,
-    MockQuerier,
+    message_info, mock_dependencies, mock_dependencies_with_balance, mock_env, MockApi, MockQuerier,
+};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, BankMsg, Binary, Coin, ContractResult, CosmosMsg, Decimal,
+    Event, IbcMsg, MemoryStorage, MsgResponse, OwnedDeps, Reply, ReplyOn, Storage, SubMsg,
+    SubMsgResponse, SubMsgResult, SystemError, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use k256::ecdsa::{
+    signature::hazmat::PrehashSigner, RecoveryId, Signature, SigningKey, VerifyingKey,
 };
-use cosmwasm_std::{from_json, Addr, Binary, Coin, MemoryStorage, OwnedDeps, Uint128};
-use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey, VerifyingKey};
 #[allow(unused_imports)]
 use k256::elliptic_curve::sec1::ToEncodedPoint;
 use sha2::{Digest, Sha256};
+use std::str::FromStr;
 
+use sysbreak_common::AdminError;
+use sysbreak_events::{DepositEvent, WithdrawEvent};
 use sysbreak_credit_bridge::contract::*;
 use sysbreak_credit_bridge::error::ContractError;
+use sysbreak_credit_bridge::helpers::{
+    bucket_of, global_window_usage, prune_nonces, record_failure,
+};
 use sysbreak_credit_bridge::msg::*;
-use sysbreak_credit_bridge::state::Config;
+use sysbreak_credit_bridge::state::{
+    BonusCampaign, Config, DenomConfig, DepositReceipt, FailureReason, FailureRecord, FeeRounding,
+    IbcTransferRecord, PendingRateUpdate, SessionKey, WithdrawalBucket, WithdrawalRecord,
+    DAO_DONATION_REPLY_ID, FAILED_NONCES, FAILURE_LOG_SIZE, GLOBAL_WD_COUNTER,
+    GLOBAL_WITHDRAWAL_RECORDS, IBC_TRANSFER_REPLY_ID, LEGACY_NONCES_UNTIL, NONCE_BITMAPS,
+    NONCE_BUCKET_SECONDS, NONCE_EXPIRY_WINDOW, PLAYER_WD_BUCKETS, PLAYER_WITHDRAWALS,
+    QUEUE_PAYOUT_REPLY_ID, USED_NONCES, WITHDRAW_SEND_REPLY_ID,
+};
 
 type TestDeps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
 
@@ -49,6 +67,11 @@ fn sign_withdrawal(
         "withdraw:{}:{}:{}:{}:{}:{}",
         chain_id, contract_addr, nonce, player, credit_amount, token_amount
     );
+    sign_payload(sk, &msg)
+}
+
+/// Sign an arbitrary payload string (SHA-256 prehash) with the test key
+fn sign_payload(sk: &SigningKey, msg: &str) -> Binary {
     let mut hasher = Sha256::new();
     hasher.update(msg.as_bytes());
     let hash = hasher.finalize();
@@ -60,9 +83,9 @@ fn sign_withdrawal(
 const DENOM: &str = "ushido";
 const CHAIN_ID: &str = "shido-testnet-1";
 
-/// mock_env() uses block time 1_571_797_419. Nonces must be "{timestamp}:{random}".
-fn ts_nonce(label: &str) -> String {
-    format!("1571797419:{}", label)
+/// mock_env() uses block time 1_571_797_419, in nonce bucket 436_610.
+fn ts_nonce(sequence: u64) -> String {
+    format!("seq:436610:{}", sequence)
 }
 
 // Rate: 10_000 credits = 1_000_000 ushido (i.e. 100 ushido per credit)
@@ -93,6 +116,8 @@ fn setup() -> (TestDeps, SigningKey) {
         cooldown_seconds: 3600, // 1 hour
         min_reserve: Uint128::from(1_000_000u128), // 1 SHIDO
         chain_id: CHAIN_ID.to_string(),
+        peak_window_seconds: None,
+        pauser: None,
     };
 
     let info = message_info(&owner, &[]);
@@ -125,6 +150,8 @@ fn setup_with_funded_treasury() -> (TestDeps, SigningKey, String) {
         cooldown_seconds: 3600,
         min_reserve: Uint128::from(1_000_000u128),
         chain_id: CHAIN_ID.to_string(),
+        peak_window_seconds: None,
+        pauser: None,
     };
 
     let info = message_info(&owner, &[]);
@@ -142,7 +169,7 @@ fn test_instantiate() {
     let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
     assert_eq!(config.owner, a(&deps, "owner"));
     assert_eq!(config.oracle, a(&deps, "oracle"));
-    assert!(!config.paused);
+    assert!(!config.deposits_paused && !config.withdrawals_paused);
     assert_eq!(config.denom, DENOM);
     assert_eq!(config.rate_credits, Uint128::from(RATE_CREDITS));
     assert_eq!(config.fee_bps, 50);
@@ -173,6 +200,8 @@ fn test_instantiate_zero_rate_fails() {
         cooldown_seconds: 3600,
         min_reserve: Uint128::from(1_000_000u128),
         chain_id: CHAIN_ID.to_string(),
+        peak_window_seconds: None,
+        pauser: None,
     };
 
     let info = message_info(&owner, &[]);
@@ -188,12 +217,152 @@ fn test_deposit() {
     let player = a(&deps, "player1");
 
     let info = message_info(&player, &[Coin::new(1_000_000u128, DENOM)]);
-    let res = execute_deposit(deps.as_mut(), mock_env(), info).unwrap();
+    let res = execute_deposit(deps.as_mut(), mock_env(), info, None).unwrap();
 
     assert_eq!(res.attributes[0].value, "deposit");
     // 1_000_000 ushido * 10_000 / 1_000_000 = 10_000 credits
-    assert_eq!(res.attributes[2].value, "1000000"); // token_amount
-    assert_eq!(res.attributes[3].value, "10000"); // credit_amount
+    assert_eq!(
+        res.events,
+        vec![Event::from(DepositEvent {
+            sender: player.to_string(),
+            denom: DENOM.to_string(),
+            token_amount: "1000000".to_string(),
+            credit_amount: "10000".to_string(),
+        })]
+    );
+}
+
+#[test]
+fn test_deposit_receipt() {
+    let (mut deps, _sk) = setup();
+    let player = a(&deps, "player1");
+    let env = mock_env();
+
+    let info = message_info(&player, &[Coin::new(1_000_000u128, DENOM)]);
+    execute_deposit(deps.as_mut(), env.clone(), info.clone(), None).unwrap();
+    let res = execute_deposit(deps.as_mut(), env.clone(), info, None).unwrap();
+    assert_eq!(res.attributes[1].value, "2"); // receipt_id
+
+    let receipt: Option<DepositReceipt> =
+        from_json(query_deposit_receipt(deps.as_ref(), 2).unwrap()).unwrap();
+    let receipt = receipt.unwrap();
+    assert_eq!(receipt.player, player);
+    assert_eq!(receipt.token_amount, Uint128::from(1_000_000u128));
+    assert_eq!(receipt.credit_amount, Uint128::from(10_000u128));
+    assert_eq!(receipt.block_height, env.block.height);
+    assert_eq!(res.attributes[2].value, receipt.hash.to_base64());
+
+    let payload = format!(
+        "deposit:{}:{}:2:{}:{}:1000000:10000:{}:{}",
+        CHAIN_ID,
+        env.contract.address,
+        player,
+        DENOM,
+        env.block.height,
+        env.block.time.seconds()
+    );
+    assert_eq!(
+        receipt.hash.to_vec(),
+        Sha256::digest(payload.as_bytes()).to_vec()
+    );
+
+    let missing: Option<DepositReceipt> =
+        from_json(query_deposit_receipt(deps.as_ref(), 3).unwrap()).unwrap();
+    assert!(missing.is_none());
+}
+
+#[test]
+fn test_deposit_bonus_campaign() {
+    let (mut deps, _sk) = setup();
+    let owner = a(&deps, "owner");
+    let player = a(&deps, "player1");
+    let mut env = mock_env();
+    let now = env.block.time.seconds();
+    // 20% bonus, up to 3_000 bonus credits
+    let create =
+        |deps: &mut TestDeps, env: &cosmwasm_std::Env, sender: &Addr, start: u64, end: u64| {
+            let info = message_info(sender, &[]);
+            execute_create_campaign(
+                deps.as_mut(),
+                env.clone(),
+                info,
+                start,
+                end,
+                2_000,
+                Uint128::from(3_000u128),
+            )
+        };
+    let deposit = |deps: &mut TestDeps, env: &cosmwasm_std::Env| {
+        let info = message_info(&player, &[Coin::new(1_000_000u128, DENOM)]);
+        execute_deposit(deps.as_mut(), env.clone(), info, None).unwrap()
+    };
+    let credited = |res: &cosmwasm_std::Response| res.events[0].attributes[3].value.clone();
+
+    // Owner only, with a sane window
+    let err = create(&mut deps, &env, &player, now + 3_600, now + 7_200).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized { .. })
+    ));
+    let err = create(
+        &mut deps,
+        &env,
+        &owner,
+        now + 3_600,
+        now + 3_600 + 2_592_001,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidCampaignWindow);
+
+    let start = now + 3_600;
+    let end = start + 172_800;
+    create(&mut deps, &env, &owner, start, end).unwrap();
+    let err = create(&mut deps, &env, &owner, end - 1, end + 3_600).unwrap_err();
+    assert_eq!(err, ContractError::CampaignOverlap { id: 1 });
+
+    // Scheduled but not started yet
+    assert_eq!(credited(&deposit(&mut deps, &env)), "10000");
+    let active: Option<BonusCampaign> =
+        from_json(query_active_bonus_campaign(deps.as_ref(), env.clone()).unwrap()).unwrap();
+    assert!(active.is_none());
+
+    // 20% bonus, then capped by what is left of the budget, then nothing
+    env.block.time = cosmwasm_std::Timestamp::from_seconds(start);
+    let res = deposit(&mut deps, &env);
+    assert_eq!(credited(&res), "12000");
+    assert_eq!(res.attributes[3].value, "1"); // campaign_id
+    assert_eq!(res.attributes[4].value, "2000"); // bonus_credits
+    assert_eq!(credited(&deposit(&mut deps, &env)), "11000");
+    let res = deposit(&mut deps, &env);
+    assert_eq!(credited(&res), "10000");
+    assert_eq!(res.attributes.len(), 3);
+
+    // The receipt records the credits granted, bonus included
+    let receipt: Option<DepositReceipt> =
+        from_json(query_deposit_receipt(deps.as_ref(), 2).unwrap()).unwrap();
+    assert_eq!(receipt.unwrap().credit_amount, Uint128::from(12_000u128));
+
+    let active: Option<BonusCampaign> =
+        from_json(query_active_bonus_campaign(deps.as_ref(), env.clone()).unwrap()).unwrap();
+    assert_eq!(active.unwrap().spent, Uint128::from(3_000u128));
+
+    // Ending early closes the window; the next campaign may start right away
+    execute_end_campaign(deps.as_mut(), env.clone(), message_info(&owner, &[]), 1).unwrap();
+    let err =
+        execute_end_campaign(deps.as_mut(), env.clone(), message_info(&owner, &[]), 1).unwrap_err();
+    assert_eq!(err, ContractError::CampaignEnded { id: 1 });
+    let active: Option<BonusCampaign> =
+        from_json(query_active_bonus_campaign(deps.as_ref(), env.clone()).unwrap()).unwrap();
+    assert!(active.is_none());
+    create(&mut deps, &env, &owner, start, start + 3_600).unwrap();
+
+    let resp: BonusCampaignsResponse =
+        from_json(query_bonus_campaigns(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert_eq!(resp.campaigns.len(), 2);
+    assert_eq!(resp.campaigns[0].end.seconds(), start);
+    let campaign: Option<BonusCampaign> =
+        from_json(query_bonus_campaign(deps.as_ref(), 2).unwrap()).unwrap();
+    assert_eq!(campaign.unwrap().bonus_bps, 2_000);
 }
 
 #[test]
@@ -202,7 +371,7 @@ fn test_deposit_below_minimum_fails() {
     let player = a(&deps, "player1");
 
     let info = message_info(&player, &[Coin::new(50_000u128, DENOM)]); // below 100k min
-    let err = execute_deposit(deps.as_mut(), mock_env(), info).unwrap_err();
+    let err = execute_deposit(deps.as_mut(), mock_env(), info, None).unwrap_err();
     assert!(matches!(err, ContractError::DepositBelowMinimum { .. }));
 }
 
@@ -212,8 +381,13 @@ fn test_deposit_wrong_denom_fails() {
     let player = a(&deps, "player1");
 
     let info = message_info(&player, &[Coin::new(1_000_000u128, "uatom")]);
-    let err = execute_deposit(deps.as_mut(), mock_env(), info).unwrap_err();
-    assert!(matches!(err, ContractError::WrongDenom { .. }));
+    let err = execute_deposit(deps.as_mut(), mock_env(), info, None).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnsupportedDenom {
+            denom: "uatom".to_string()
+        }
+    );
 }
 
 #[test]
@@ -222,7 +396,7 @@ fn test_deposit_no_funds_fails() {
     let player = a(&deps, "player1");
 
     let info = message_info(&player, &[]);
-    let err = execute_deposit(deps.as_mut(), mock_env(), info).unwrap_err();
+    let err = execute_deposit(deps.as_mut(), mock_env(), info, None).unwrap_err();
     assert_eq!(err, ContractError::NoFundsSent);
 }
 
@@ -236,8 +410,8 @@ fn test_deposit_paused_fails() {
     execute_pause(deps.as_mut(), mock_env(), info).unwrap();
 
     let info = message_info(&player, &[Coin::new(1_000_000u128, DENOM)]);
-    let err = execute_deposit(deps.as_mut(), mock_env(), info).unwrap_err();
-    assert_eq!(err, ContractError::Paused);
+    let err = execute_deposit(deps.as_mut(), mock_env(), info, None).unwrap_err();
+    assert_eq!(err, ContractError::DepositsPaused);
 }
 
 // ─── Withdrawal ─────────────────────────────────────────────────────────────
@@ -250,7 +424,7 @@ fn test_withdraw_valid() {
     // 10_000 credits = 1_000_000 ushido gross, fee = 5_000 (0.5%), net = 995_000
     let credit_amount = Uint128::from(10_000u128);
     let token_amount = Uint128::from(995_000u128);
-    let nonce = ts_nonce("001");
+    let nonce = ts_nonce(1);
 
     let sig = sign_withdrawal(
         &sk,
@@ -271,25 +445,62 @@ fn test_withdraw_valid() {
         credit_amount,
         token_amount,
         sig,
+        None,
+        None,
+        None,
+        None,
     )
     .unwrap();
 
     assert_eq!(res.attributes[0].value, "withdraw");
-    assert_eq!(res.attributes[3].value, "10000"); // credit_amount
-    assert_eq!(res.attributes[4].value, "995000"); // token_amount
-    assert_eq!(res.attributes[5].value, "5000"); // fee
-    assert_eq!(res.messages.len(), 2); // player payment + fee payment
+    assert_eq!(
+        res.events,
+        vec![Event::from(WithdrawEvent {
+            recipient: player.to_string(),
+            denom: DENOM.to_string(),
+            token_amount: "995000".to_string(),
+            credit_amount: "10000".to_string(),
+            fee_amount: "5000".to_string(),
+        })]
+    );
+    // Player payment with a reply; the fee follows once it succeeds
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].id, WITHDRAW_SEND_REPLY_ID);
+    assert_eq!(res.messages[0].reply_on, ReplyOn::Always);
+
+    #[allow(deprecated)]
+    let sent = SubMsgResult::Ok(SubMsgResponse {
+        events: vec![],
+        data: None,
+        msg_responses: vec![],
+    });
+    let res = reply(deps.as_mut(), mock_env(), send_reply(sent)).unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: a(&deps, "treasury").to_string(),
+            amount: vec![Coin::new(5_000u128, DENOM)],
+        })
+    );
+}
+
+fn send_reply(result: SubMsgResult) -> Reply {
+    #[allow(deprecated)]
+    Reply {
+        id: WITHDRAW_SEND_REPLY_ID,
+        payload: Binary::default(),
+        gas_used: 0,
+        result,
+    }
 }
 
 #[test]
-fn test_withdraw_nonce_replay_fails() {
+fn test_withdraw_failed_send_rolls_back() {
     let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
     let player = a(&deps, "player1");
-
     let credit_amount = Uint128::from(10_000u128);
     let token_amount = Uint128::from(995_000u128);
-    let nonce = ts_nonce("001");
-
+    let nonce = ts_nonce(1);
     let sig = sign_withdrawal(
         &sk,
         CHAIN_ID,
@@ -300,535 +511,3623 @@ fn test_withdraw_nonce_replay_fails() {
         token_amount,
     );
 
-    let info = message_info(&player, &[]);
-    execute_withdraw(
+    let withdraw = |deps: &mut TestDeps| {
+        execute_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&player, &[]),
+            nonce.clone(),
+            credit_amount,
+            token_amount,
+            sig.clone(),
+            None,
+            None,
+            None,
+            None,
+        )
+    };
+
+    withdraw(&mut deps).unwrap();
+    // The bank module rejects the recipient
+    let res = reply(
         deps.as_mut(),
         mock_env(),
-        info.clone(),
-        nonce.clone(),
-        credit_amount,
-        token_amount,
-        sig.clone(),
+        send_reply(SubMsgResult::Err("blocked address".to_string())),
     )
     .unwrap();
+    assert!(res.messages.is_empty()); // no fee charged
+    assert_eq!(res.attributes[0].value, "withdraw_rolled_back");
+
+    // Nonce, limits, cooldown and outflow are restored
+    let used: NonceUsedResponse =
+        from_json(query_nonce_used(deps.as_ref(), nonce.clone()).unwrap()).unwrap();
+    assert!(!used.used);
+    let info: PlayerInfoResponse =
+        from_json(query_player_info(deps.as_ref(), mock_env(), player.to_string(), None).unwrap())
+            .unwrap();
+    assert_eq!(info.withdrawals_24h, Uint128::zero());
+    assert_eq!(info.cooldown_until, None);
+    assert_eq!(
+        global_window_usage(&deps.storage, DENOM, mock_env().block.time, 86_400).unwrap(),
+        Uint128::zero()
+    );
+    let solvency: SolvencyResponse =
+        from_json(query_solvency(deps.as_ref(), mock_env(), None).unwrap()).unwrap();
+    assert_eq!(solvency.outflow_24h, Uint128::zero());
 
-    // Replay same nonce
-    let mut env2 = mock_env();
-    env2.block.time = env2.block.time.plus_seconds(3601); // past cooldown
-    let err = execute_withdraw(
-        deps.as_mut(),
-        env2,
-        info,
-        nonce.clone(),
-        credit_amount,
-        token_amount,
-        sig,
-    )
-    .unwrap_err();
-
-    assert!(matches!(err, ContractError::NonceAlreadyUsed { .. }));
+    // The same authorization can be retried
+    withdraw(&mut deps).unwrap();
 }
 
-#[test]
-fn test_withdraw_bad_signature_fails() {
-    let (mut deps, _sk, _contract_addr) = setup_with_funded_treasury();
-    let player = a(&deps, "player1");
-
-    let credit_amount = Uint128::from(10_000u128);
-    let token_amount = Uint128::from(995_000u128);
-
-    // Use garbage signature
-    let bad_sig = Binary::from(vec![0u8; 64]);
-
-    let info = message_info(&player, &[]);
-    let err = execute_withdraw(
-        deps.as_mut(),
-        mock_env(),
-        info,
-        ts_nonce("bad"),
-        credit_amount,
-        token_amount,
-        bad_sig,
-    )
-    .unwrap_err();
+/// Independent encoding of the version 2 payload, signed with the test key
+fn sign_structured_withdrawal(
+    sk: &SigningKey,
+    contract_version: &str,
+    contract_addr: &str,
+    nonce: &str,
+    player: &str,
+    credit_amount: Uint128,
+    token_amount: Uint128,
+    denom: &str,
+    recipient: Option<&str>,
+) -> Binary {
+    fn field(buf: &mut Vec<u8>, bytes: &[u8]) {
+        buf.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+        buf.extend_from_slice(bytes);
+    }
+    let mut domain = Vec::new();
+    for f in [
+        "sysbreak-credit-bridge-domain",
+        "crates.io:sysbreak-credit-bridge",
+        contract_version,
+        CHAIN_ID,
+        contract_addr,
+    ] {
+        field(&mut domain, f.as_bytes());
+    }
+    let mut fields = Vec::new();
+    match recipient {
+        Some(_) => field(&mut fields, b"withdraw_to"),
+        None => field(&mut fields, b"withdraw"),
+    }
+    field(&mut fields, nonce.as_bytes());
+    field(&mut fields, player.as_bytes());
+    field(&mut fields, &credit_amount.u128().to_be_bytes());
+    field(&mut fields, &token_amount.u128().to_be_bytes());
+    field(&mut fields, denom.as_bytes());
+    if let Some(recipient) = recipient {
+        field(&mut fields, recipient.as_bytes());
+    }
 
-    assert!(matches!(
-        err,
-        ContractError::InvalidSignature | ContractError::SignatureVerificationFailed
-    ));
+    let mut hasher = Sha256::new();
+    hasher.update([0x19, 0x01]);
+    hasher.update(Sha256::digest(&domain));
+    hasher.update(Sha256::digest(&fields));
+    let (sig, _recid): (Signature, _) = sk.sign_prehash(&hasher.finalize()).unwrap();
+    Binary::from(sig.to_bytes().to_vec())
 }
 
 #[test]
-fn test_withdraw_amount_mismatch_fails() {
+fn test_withdraw_structured_payload() {
     let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
     let player = a(&deps, "player1");
-
     let credit_amount = Uint128::from(10_000u128);
-    let wrong_token_amount = Uint128::from(999_999u128); // wrong amount
+    let token_amount = Uint128::from(995_000u128);
 
-    // Sign with wrong amount — signature will be valid but contract recalculates
-    let sig = sign_withdrawal(
+    // A legacy signature is not valid as a version 2 payload
+    let nonce = ts_nonce(2);
+    let legacy_sig = sign_withdrawal(
         &sk,
         CHAIN_ID,
         &contract_addr,
-        &ts_nonce("mismatch"),
+        &nonce,
         player.as_str(),
         credit_amount,
-        wrong_token_amount,
+        token_amount,
     );
-
-    let info = message_info(&player, &[]);
     let err = execute_withdraw(
         deps.as_mut(),
         mock_env(),
-        info,
-        ts_nonce("mismatch"),
+        message_info(&player, &[]),
+        nonce.clone(),
         credit_amount,
-        wrong_token_amount,
-        sig,
+        token_amount,
+        legacy_sig.clone(),
+        None,
+        None,
+        Some(2),
+        None,
     )
     .unwrap_err();
+    assert_eq!(err, ContractError::InvalidSignature);
 
-    assert!(matches!(err, ContractError::AmountMismatch { .. }));
-}
-
-#[test]
-fn test_withdraw_cooldown_enforced() {
-    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
-    let player = a(&deps, "player1");
-
-    let credit_amount = Uint128::from(1_000u128);
-    let token_amount = Uint128::from(99_500u128);
-
-    // First withdrawal
-    let sig = sign_withdrawal(
-        &sk,
-        CHAIN_ID,
-        &contract_addr,
-        &ts_nonce("1"),
-        player.as_str(),
-        credit_amount,
-        token_amount,
-    );
-    let info = message_info(&player, &[]);
-    execute_withdraw(
+    let err = execute_withdraw(
         deps.as_mut(),
         mock_env(),
-        info.clone(),
-        ts_nonce("1"),
+        message_info(&player, &[]),
+        nonce.clone(),
         credit_amount,
         token_amount,
-        sig,
+        legacy_sig,
+        None,
+        None,
+        Some(3),
+        None,
     )
-    .unwrap();
+    .unwrap_err();
+    assert_eq!(err, ContractError::UnsupportedPayloadVersion { version: 3 });
 
-    // Try again immediately — should fail with cooldown
-    let sig2 = sign_withdrawal(
+    let sig = sign_structured_withdrawal(
         &sk,
-        CHAIN_ID,
+        env!("CARGO_PKG_VERSION"),
         &contract_addr,
-        &ts_nonce("2"),
+        &nonce,
         player.as_str(),
         credit_amount,
         token_amount,
+        DENOM,
+        None,
     );
-    let err = execute_withdraw(
-        deps.as_mut(),
-        mock_env(),
-        info.clone(),
-        ts_nonce("2"),
-        credit_amount,
-        token_amount,
-        sig2.clone(),
-    )
-    .unwrap_err();
-    assert!(matches!(err, ContractError::CooldownActive { .. }));
-
-    // After cooldown period it should work
-    let mut env_later = mock_env();
-    env_later.block.time = env_later.block.time.plus_seconds(3601);
     execute_withdraw(
         deps.as_mut(),
-        env_later,
-        info,
-        ts_nonce("2"),
+        mock_env(),
+        message_info(&player, &[]),
+        nonce,
         credit_amount,
         token_amount,
-        sig2,
+        sig,
+        None,
+        None,
+        Some(2),
+        None,
     )
     .unwrap();
 }
 
 #[test]
-fn test_withdraw_player_daily_limit() {
+fn test_structured_payload_survives_migration() {
     let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
     let player = a(&deps, "player1");
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
 
-    // Player daily limit is 100_000 credits. Try to withdraw 100_001
-    let credit_amount = Uint128::from(100_001u128);
-    let gross_tokens = Uint128::from(100_001u128)
-        .checked_mul(Uint128::from(RATE_TOKENS))
-        .unwrap()
-        .checked_div(Uint128::from(RATE_CREDITS))
-        .unwrap();
-    let fee = gross_tokens
-        .checked_mul(Uint128::from(50u128))
-        .unwrap()
-        .checked_div(Uint128::from(10_000u128))
-        .unwrap();
-    let token_amount = gross_tokens.checked_sub(fee).unwrap();
-
-    let sig = sign_withdrawal(
+    // Signed by the oracle while the old code was live
+    cw2::set_contract_version(
+        deps.as_mut().storage,
+        "crates.io:sysbreak-credit-bridge",
+        "0.0.9",
+    )
+    .unwrap();
+    let nonce = ts_nonce(3);
+    let sig = sign_structured_withdrawal(
         &sk,
-        CHAIN_ID,
+        "0.0.9",
         &contract_addr,
-        &ts_nonce("limit"),
+        &nonce,
         player.as_str(),
         credit_amount,
         token_amount,
+        DENOM,
+        None,
     );
 
-    let info = message_info(&player, &[]);
-    let err = execute_withdraw(
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    let res: SigningDomainsResponse =
+        from_json(query_signing_domains(deps.as_ref(), mock_env()).unwrap()).unwrap();
+    assert_eq!(res.domain_separators.len(), 2);
+
+    execute_withdraw(
         deps.as_mut(),
         mock_env(),
-        info,
-        ts_nonce("limit"),
+        message_info(&player, &[]),
+        nonce,
         credit_amount,
         token_amount,
         sig,
+        None,
+        None,
+        Some(2),
+        None,
     )
-    .unwrap_err();
-
-    assert!(matches!(err, ContractError::PlayerDailyLimitExceeded { .. }));
+    .unwrap();
 }
 
 #[test]
-fn test_withdraw_zero_amount_fails() {
-    let (mut deps, _sk, _contract_addr) = setup_with_funded_treasury();
+fn test_withdraw_to_signed_recipient() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
     let player = a(&deps, "player1");
+    let cold_wallet = a(&deps, "cold_wallet");
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    let nonce = ts_nonce(6);
+    let sign = |recipient: Option<&str>| {
+        sign_structured_withdrawal(
+            &sk,
+            env!("CARGO_PKG_VERSION"),
+            &contract_addr,
+            &nonce,
+            player.as_str(),
+            credit_amount,
+            token_amount,
+            DENOM,
+            recipient,
+        )
+    };
+    let withdraw = |deps: &mut TestDeps, sender: &Addr, sig: Binary, version: Option<u8>| {
+        execute_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(sender, &[]),
+            nonce.clone(),
+            credit_amount,
+            token_amount,
+            sig,
+            None,
+            None,
+            version,
+            Some(cold_wallet.to_string()),
+        )
+    };
 
-    let info = message_info(&player, &[]);
-    let err = execute_withdraw(
-        deps.as_mut(),
-        mock_env(),
-        info,
-        ts_nonce("zero"),
-        Uint128::zero(),
-        Uint128::zero(),
-        Binary::from(vec![0u8; 64]),
-    )
-    .unwrap_err();
+    // The recipient has to be part of the signed payload
+    let err = withdraw(&mut deps, &player, sign(None), Some(2)).unwrap_err();
+    assert_eq!(err, ContractError::InvalidSignature);
+    let signed = sign(Some(cold_wallet.as_str()));
+    let err = withdraw(&mut deps, &player, signed.clone(), None).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidRecipient {
+            reason: "requires payload version 2".to_string()
+        }
+    );
+    // Only the player named in the payload can submit it
+    let err = withdraw(&mut deps, &cold_wallet, signed.clone(), Some(2)).unwrap_err();
+    assert_eq!(err, ContractError::InvalidSignature);
 
-    assert_eq!(err, ContractError::ZeroAmount);
+    let res = withdraw(&mut deps, &player, signed, Some(2)).unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: cold_wallet.to_string(),
+            amount: vec![Coin::new(995_000u128, DENOM)],
+        })
+    );
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "recipient" && attr.value == cold_wallet.as_str()));
+    assert_eq!(
+        res.events,
+        vec![Event::from(WithdrawEvent {
+            recipient: cold_wallet.to_string(),
+            denom: DENOM.to_string(),
+            token_amount: "995000".to_string(),
+            credit_amount: "10000".to_string(),
+            fee_amount: "5000".to_string(),
+        })]
+    );
 }
 
-// ─── Nonce Query ────────────────────────────────────────────────────────────
-
 #[test]
-fn test_nonce_used_query() {
+fn test_withdraw_with_session_key() {
     let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
     let player = a(&deps, "player1");
+    let relayer = a(&deps, "relayer");
+    let session_sk = SigningKey::from_bytes((&[7u8; 32]).into()).unwrap();
+    let session_pubkey = Binary::from(pubkey_bytes(session_sk.verifying_key()));
 
-    // Before use
-    let res: NonceUsedResponse =
-        from_json(query_nonce_used(deps.as_ref(), ts_nonce("q")).unwrap()).unwrap();
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    let nonce = ts_nonce(4);
+    let payload = format!(
+        "withdraw:{}:{}:{}:{}:{}:{}",
+        CHAIN_ID, contract_addr, nonce, player, credit_amount, token_amount
+    );
+    let oracle_sig = sign_payload(&sk, &payload);
+    let relay = |deps: &mut TestDeps, session_signature: Binary| {
+        execute_withdraw_with_session_key(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&relayer, &[]),
+            player.to_string(),
+            nonce.clone(),
+            credit_amount,
+            token_amount,
+            oracle_sig.clone(),
+            session_signature,
+            None,
+            None,
+        )
+    };
+
+    let err = relay(&mut deps, sign_payload(&session_sk, &payload)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NoSessionKey {
+            player: player.to_string()
+        }
+    );
+
+    let now = mock_env().block.time.seconds();
+    let register = |deps: &mut TestDeps, expires_at: u64| {
+        execute_register_session_key(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&player, &[]),
+            session_pubkey.clone(),
+            expires_at,
+        )
+    };
+    let err = register(&mut deps, now + 31 * 86_400).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidSessionKeyExpiry { max_ttl: 2_592_000 }
+    );
+    register(&mut deps, now + 3600).unwrap();
+
+    // The oracle's signature alone is not enough
+    let err = relay(&mut deps, oracle_sig.clone()).unwrap_err();
+    assert_eq!(err, ContractError::InvalidSessionSignature);
+
+    let res = relay(&mut deps, sign_payload(&session_sk, &payload)).unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "relayer" && attr.value == relayer.as_str()));
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: player.to_string(),
+            amount: vec![Coin::new(995_000u128, DENOM)],
+        })
+    );
+
+    // Expired and revoked keys stop working
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(3600);
+    let err = execute_withdraw_with_session_key(
+        deps.as_mut(),
+        later,
+        message_info(&relayer, &[]),
+        player.to_string(),
+        ts_nonce(5),
+        credit_amount,
+        token_amount,
+        oracle_sig.clone(),
+        oracle_sig,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SessionKeyExpired {
+            expired_at: now + 3600
+        }
+    );
+    execute_revoke_session_key(deps.as_mut(), mock_env(), message_info(&player, &[])).unwrap();
+    let res = query_session_key(deps.as_ref(), player.to_string()).unwrap();
+    assert_eq!(from_json::<Option<SessionKey>>(res).unwrap(), None);
+}
+
+#[test]
+fn test_withdraw_recoverable_signature() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    let nonce = ts_nonce(6);
+
+    let payload = format!(
+        "withdraw:{}:{}:{}:{}:{}:{}",
+        CHAIN_ID, contract_addr, nonce, player, credit_amount, token_amount
+    );
+    let hash = Sha256::digest(payload.as_bytes());
+    let (sig, recid): (Signature, RecoveryId) = sk.sign_prehash(&hash).unwrap();
+    let recoverable = |v: u8| {
+        let mut bytes = sig.to_bytes().to_vec();
+        bytes.push(v);
+        Binary::from(bytes)
+    };
+
+    // Wrong recovery id recovers a different key
+    let err = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        nonce.clone(),
+        credit_amount,
+        token_amount,
+        recoverable(28 - recid.to_byte()),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidSignature);
+
+    // Ethereum-style v = 27 + recovery id, as emitted by the HSM
+    execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        nonce,
+        credit_amount,
+        token_amount,
+        recoverable(27 + recid.to_byte()),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_withdraw_nonce_replay_fails() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    let nonce = ts_nonce(1);
+
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &nonce,
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+
+    let info = message_info(&player, &[]);
+    execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        nonce.clone(),
+        credit_amount,
+        token_amount,
+        sig.clone(),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    // Replay same nonce
+    let mut env2 = mock_env();
+    env2.block.time = env2.block.time.plus_seconds(3601); // past cooldown
+    let err = execute_withdraw(
+        deps.as_mut(),
+        env2,
+        info,
+        nonce.clone(),
+        credit_amount,
+        token_amount,
+        sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, ContractError::NonceAlreadyUsed { .. }));
+}
+
+#[test]
+fn test_sequenced_nonces_and_legacy_transition() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+    let credit_amount = Uint128::from(1_000u128);
+    let token_amount = Uint128::from(99_500u128);
+    let withdraw = |deps: &mut TestDeps, nonce: &str, hours: u64| {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(hours * 3600);
+        let sig = sign_withdrawal(
+            &sk,
+            CHAIN_ID,
+            &contract_addr,
+            nonce,
+            player.as_str(),
+            credit_amount,
+            token_amount,
+        );
+        execute_withdraw(
+            deps.as_mut(),
+            env,
+            message_info(&player, &[]),
+            nonce.to_string(),
+            credit_amount,
+            token_amount,
+            sig,
+            None,
+            None,
+            None,
+            None,
+        )
+    };
+
+    // A fresh deployment only takes sequenced nonces
+    let err = withdraw(&mut deps, "1571797419:old", 0).unwrap_err();
+    assert_eq!(err, ContractError::LegacyNonceRetired);
+    let err = withdraw(&mut deps, "seq:436610:x", 0).unwrap_err();
+    assert_eq!(err, ContractError::InvalidNonceFormat);
+
+    withdraw(&mut deps, &ts_nonce(1), 0).unwrap();
+    let err = withdraw(&mut deps, &ts_nonce(1), 1).unwrap_err();
+    assert!(matches!(err, ContractError::NonceAlreadyUsed { .. }));
+    withdraw(&mut deps, &ts_nonce(65), 1).unwrap();
+    let words: Vec<((u64, u64), u64)> = NONCE_BITMAPS
+        .range(deps.as_ref().storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(words, vec![((436_610, 0), 1 << 1), ((436_610, 1), 1 << 1)]);
+
+    // A contract upgraded from string nonces keeps honouring them for one window
+    LEGACY_NONCES_UNTIL.remove(deps.as_mut().storage);
+    USED_NONCES
+        .save(deps.as_mut().storage, "1571790000:spent", &true)
+        .unwrap();
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(3600);
+    migrate(deps.as_mut(), env, MigrateMsg {}).unwrap();
+    let err = withdraw(&mut deps, "1571790000:spent", 2).unwrap_err();
+    assert!(matches!(err, ContractError::NonceAlreadyUsed { .. }));
+    withdraw(&mut deps, "1571797419:fresh", 2).unwrap();
+
+    // Past the window: legacy nonces are retired and the first bucket has expired
+    let later = 1 + 604_800 / 3600;
+    let err = withdraw(&mut deps, "1571797419:late", later).unwrap_err();
+    assert_eq!(err, ContractError::LegacyNonceRetired);
+    let err = withdraw(&mut deps, &ts_nonce(2), later).unwrap_err();
+    assert_eq!(err, ContractError::NonceExpired { window: 604_800 });
+
+    // The next withdrawal clears both stale bitmap words and the legacy entries
+    withdraw(&mut deps, &format!("seq:{}:1", 436_610 + later), later).unwrap();
+    let words: Vec<(u64, u64)> = NONCE_BITMAPS
+        .keys(deps.as_ref().storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(words, vec![(436_610 + later, 0)]);
+    assert!(USED_NONCES.is_empty(deps.as_ref().storage));
+    let res: NonceUsedResponse =
+        from_json(query_nonce_used(deps.as_ref(), ts_nonce(1)).unwrap()).unwrap();
     assert!(!res.used);
+}
+
+#[test]
+fn test_withdraw_bad_signature_fails() {
+    let (mut deps, _sk, _contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+
+    // Use garbage signature
+    let bad_sig = Binary::from(vec![0u8; 64]);
+
+    let info = message_info(&player, &[]);
+    let err = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ts_nonce(7),
+        credit_amount,
+        token_amount,
+        bad_sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(
+        err,
+        ContractError::InvalidSignature | ContractError::SignatureVerificationFailed
+    ));
+}
+
+#[test]
+fn test_withdraw_amount_mismatch_fails() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+
+    let credit_amount = Uint128::from(10_000u128);
+    let wrong_token_amount = Uint128::from(999_999u128); // wrong amount
+
+    // Sign with wrong amount — signature will be valid but contract recalculates
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &ts_nonce(8),
+        player.as_str(),
+        credit_amount,
+        wrong_token_amount,
+    );
+
+    let info = message_info(&player, &[]);
+    let err = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ts_nonce(8),
+        credit_amount,
+        wrong_token_amount,
+        sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, ContractError::AmountMismatch { .. }));
+}
+
+#[test]
+fn test_withdraw_cooldown_enforced() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+
+    let credit_amount = Uint128::from(1_000u128);
+    let token_amount = Uint128::from(99_500u128);
+
+    // First withdrawal
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &ts_nonce(9),
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+    let info = message_info(&player, &[]);
+    execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ts_nonce(9),
+        credit_amount,
+        token_amount,
+        sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    // Try again immediately — should fail with cooldown
+    let sig2 = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &ts_nonce(10),
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+    let err = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ts_nonce(10),
+        credit_amount,
+        token_amount,
+        sig2.clone(),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::CooldownActive { .. }));
+
+    // After cooldown period it should work
+    let mut env_later = mock_env();
+    env_later.block.time = env_later.block.time.plus_seconds(3601);
+    execute_withdraw(
+        deps.as_mut(),
+        env_later,
+        info,
+        ts_nonce(10),
+        credit_amount,
+        token_amount,
+        sig2,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_withdraw_player_daily_limit() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+
+    // Player daily limit is 100_000 credits. Try to withdraw 100_001
+    let credit_amount = Uint128::from(100_001u128);
+    let gross_tokens = Uint128::from(100_001u128)
+        .checked_mul(Uint128::from(RATE_TOKENS))
+        .unwrap()
+        .checked_div(Uint128::from(RATE_CREDITS))
+        .unwrap();
+    let fee = gross_tokens
+        .checked_mul(Uint128::from(50u128))
+        .unwrap()
+        .checked_div(Uint128::from(10_000u128))
+        .unwrap();
+    let token_amount = gross_tokens.checked_sub(fee).unwrap();
+
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &ts_nonce(11),
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+
+    let info = message_info(&player, &[]);
+    let err = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ts_nonce(11),
+        credit_amount,
+        token_amount,
+        sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, ContractError::PlayerDailyLimitExceeded { .. }));
+}
+
+#[test]
+fn test_strict_telemetry_failure_log() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let player = a(&deps, "player1");
+    let credit_amount = Uint128::from(1_000u128);
+    let token_amount = Uint128::from(99_500u128);
+
+    let err =
+        execute_set_strict_telemetry(deps.as_mut(), mock_env(), message_info(&player, &[]), true)
+            .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized { .. })
+    ));
+    execute_set_strict_telemetry(deps.as_mut(), mock_env(), message_info(&owner, &[]), true)
+        .unwrap();
+
+    let withdraw = |deps: &mut TestDeps, sequence: u64, signature: Option<Binary>| {
+        let nonce = ts_nonce(sequence);
+        let sig = signature.unwrap_or_else(|| {
+            sign_withdrawal(
+                &sk,
+                CHAIN_ID,
+                &contract_addr,
+                &nonce,
+                player.as_str(),
+                credit_amount,
+                token_amount,
+            )
+        });
+        execute_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&player, &[]),
+            nonce,
+            credit_amount,
+            token_amount,
+            sig,
+            None,
+            None,
+            None,
+            None,
+        )
+    };
+    let failures = |deps: &TestDeps, limit: Option<u32>| {
+        let resp: RecentFailuresResponse =
+            from_json(query_recent_failures(deps.as_ref(), limit).unwrap()).unwrap();
+        resp.failures
+            .into_iter()
+            .map(|f| f.reason)
+            .collect::<Vec<_>>()
+    };
+
+    // A reverted bank send is logged alongside its rollback, on every retry
+    for _ in 0..2 {
+        withdraw(&mut deps, 1, None).unwrap();
+        reply(
+            deps.as_mut(),
+            mock_env(),
+            send_reply(SubMsgResult::Err("blocked address".to_string())),
+        )
+        .unwrap();
+    }
+    assert_eq!(
+        failures(&deps, None),
+        vec![FailureReason::SendFailed, FailureReason::SendFailed]
+    );
+
+    // A signed withdrawal that hits the cooldown succeeds as a logged no-op
+    withdraw(&mut deps, 1, None).unwrap();
+    let res = withdraw(&mut deps, 2, None).unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(res.attributes[0].value, "withdraw_failed");
+    assert_eq!(res.attributes[3].value, "cooldown");
+    let used: NonceUsedResponse =
+        from_json(query_nonce_used(deps.as_ref(), ts_nonce(2)).unwrap()).unwrap();
+    assert!(!used.used);
+    let resp: RecentFailuresResponse =
+        from_json(query_recent_failures(deps.as_ref(), Some(1)).unwrap()).unwrap();
+    assert_eq!(
+        resp.failures,
+        vec![FailureRecord {
+            player: player.clone(),
+            reason: FailureReason::Cooldown,
+            failed_at: mock_env().block.time,
+        }]
+    );
+
+    // Failures before the oracle signature checks out still revert
+    let other_nonce = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &ts_nonce(4),
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+    let err = withdraw(&mut deps, 3, Some(other_nonce)).unwrap_err();
+    assert_eq!(err, ContractError::InvalidSignature);
+    assert_eq!(failures(&deps, None).len(), 3);
+
+    // Replaying the failed authorization is not logged again and reverts
+    let err = withdraw(&mut deps, 2, None).unwrap_err();
+    assert!(matches!(err, ContractError::CooldownActive { .. }));
+    assert_eq!(failures(&deps, None).len(), 3);
+
+    // The replay guard is pruned with the nonce bitmaps once the nonce expires
+    assert!(!FAILED_NONCES.is_empty(&deps.storage));
+    let expired = mock_env()
+        .block
+        .time
+        .plus_seconds(NONCE_EXPIRY_WINDOW + NONCE_BUCKET_SECONDS);
+    assert_eq!(prune_nonces(&mut deps.storage, expired, 10).unwrap(), 2);
+    assert!(FAILED_NONCES.is_empty(&deps.storage));
+
+    // The log keeps the newest FAILURE_LOG_SIZE entries
+    for _ in 0..FAILURE_LOG_SIZE {
+        record_failure(
+            &mut deps.storage,
+            &player,
+            FailureReason::Other,
+            mock_env().block.time,
+        )
+        .unwrap();
+    }
+    let reasons = failures(&deps, Some(1_000));
+    assert_eq!(reasons.len() as u64, FAILURE_LOG_SIZE);
+    assert!(reasons.iter().all(|r| *r == FailureReason::Other));
+
+    // Disabled: the error reverts as before
+    execute_set_strict_telemetry(deps.as_mut(), mock_env(), message_info(&owner, &[]), false)
+        .unwrap();
+    let err = withdraw(&mut deps, 2, None).unwrap_err();
+    assert!(matches!(err, ContractError::CooldownActive { .. }));
+}
+
+#[test]
+fn test_withdraw_zero_amount_fails() {
+    let (mut deps, _sk, _contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+
+    let info = message_info(&player, &[]);
+    let err = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ts_nonce(12),
+        Uint128::zero(),
+        Uint128::zero(),
+        Binary::from(vec![0u8; 64]),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+
+    assert_eq!(err, ContractError::ZeroAmount);
+}
+
+#[test]
+fn test_withdraw_ibc_channel_not_allowed() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    let nonce = ts_nonce(13);
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &nonce,
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+
+    let err = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        nonce,
+        credit_amount,
+        token_amount,
+        sig,
+        Some(IbcTransferTarget {
+            channel_id: "channel-0".to_string(),
+            remote_address: "osmo1player".to_string(),
+        }),
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::IbcChannelNotAllowed {
+            channel_id: "channel-0".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_withdraw_ibc_timeout_refunds_player() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let player = a(&deps, "player1");
+
+    execute_set_ibc_channel(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "channel-0".to_string(),
+        600,
+    )
+    .unwrap();
+
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    let nonce = ts_nonce(13);
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &nonce,
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+
+    let res = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        nonce.clone(),
+        credit_amount,
+        token_amount,
+        sig,
+        Some(IbcTransferTarget {
+            channel_id: "channel-0".to_string(),
+            remote_address: "osmo1player".to_string(),
+        }),
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    // IBC transfer of the net amount (with reply) + local fee payment
+    assert_eq!(res.messages.len(), 2);
+    assert_eq!(res.messages[0].id, IBC_TRANSFER_REPLY_ID);
+    match &res.messages[0].msg {
+        CosmosMsg::Ibc(IbcMsg::Transfer {
+            channel_id,
+            to_address,
+            amount,
+            ..
+        }) => {
+            assert_eq!(channel_id, "channel-0");
+            assert_eq!(to_address, "osmo1player");
+            assert_eq!(amount.amount, token_amount);
+        }
+        other => panic!("unexpected message: {:?}", other),
+    }
+
+    // Reply carries MsgTransferResponse { sequence: 7 }
+    #[allow(deprecated)]
+    let reply_msg = Reply {
+        id: IBC_TRANSFER_REPLY_ID,
+        payload: Binary::default(),
+        gas_used: 0,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+            msg_responses: vec![MsgResponse {
+                type_url: "/ibc.applications.transfer.v1.MsgTransferResponse".to_string(),
+                value: Binary::from(vec![0x08, 0x07]),
+            }],
+        }),
+    };
+    reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+
+    let record: Option<IbcTransferRecord> =
+        from_json(query_ibc_transfer(deps.as_ref(), "channel-0".to_string(), 7).unwrap()).unwrap();
+    assert_eq!(record.unwrap().player, player);
+    let solvency: SolvencyResponse =
+        from_json(query_solvency(deps.as_ref(), mock_env(), None).unwrap()).unwrap();
+    assert_eq!(solvency.pending_ibc_transfers, 1);
+    assert_eq!(solvency.pending_ibc_amount, token_amount);
+
+    // Timeout: tokens come back to the contract and are forwarded to the player
+    let res = sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::IbcLifecycleComplete(IbcLifecycleComplete::IbcTimeout {
+            channel: "channel-0".to_string(),
+            sequence: 7,
+        }),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: player.to_string(),
+            amount: vec![Coin::new(995_000u128, DENOM)],
+        })
+    );
+
+    let record: Option<IbcTransferRecord> =
+        from_json(query_ibc_transfer(deps.as_ref(), "channel-0".to_string(), 7).unwrap()).unwrap();
+    assert!(record.is_none());
+    let solvency: SolvencyResponse =
+        from_json(query_solvency(deps.as_ref(), mock_env(), None).unwrap()).unwrap();
+    assert_eq!(solvency.pending_ibc_transfers, 0);
+}
+
+// ─── Additional Denoms ──────────────────────────────────────────────────────
+
+const GEM_DENOM: &str = "ugem";
+
+fn gem_config() -> DenomConfig {
+    // 1 credit = 1 ugem, 1% fee
+    DenomConfig {
+        rate_credits: Uint128::from(1u128),
+        rate_tokens: Uint128::from(1u128),
+        fee_bps: 100,
+        min_deposit: Uint128::from(1_000u128),
+        player_daily_limit: Uint128::from(50_000u128),
+        global_daily_limit: Uint128::from(1_000_000u128),
+        min_reserve: Uint128::from(10_000u128),
+        fee_rounding: FeeRounding::Floor,
+        min_fee: Uint128::zero(),
+    }
+}
+
+#[test]
+fn test_secondary_denom_deposit_and_withdraw() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let player = a(&deps, "player1");
+
+    execute_set_denom_config(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        GEM_DENOM.to_string(),
+        gem_config(),
+    )
+    .unwrap();
+
+    // Deposit infers the denom from the funds and uses its rate
+    let res = execute_deposit(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[Coin::new(5_000u128, GEM_DENOM)]),
+        None,
+    )
+    .unwrap();
+    let deposit = &res.events[0].attributes;
+    assert_eq!(deposit[1].value, GEM_DENOM);
+    assert_eq!(deposit[3].value, "5000"); // credit_amount
+
+    deps.querier.bank.update_balance(
+        contract_addr.clone(),
+        vec![
+            Coin::new(100_000_000u128, DENOM),
+            Coin::new(100_000u128, GEM_DENOM),
+        ],
+    );
+
+    // 10_000 credits = 10_000 ugem gross, fee 100, net 9_900
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(9_900u128);
+    let nonce = ts_nonce(14);
+
+    // A primary-denom signature cannot be redeemed against the secondary denom
+    let primary_sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &nonce,
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+    let err = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        nonce.clone(),
+        credit_amount,
+        token_amount,
+        primary_sig,
+        None,
+        Some(GEM_DENOM.to_string()),
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::InvalidSignature | ContractError::SignatureVerificationFailed
+    ));
+
+    let sig = sign_payload(
+        &sk,
+        &format!(
+            "withdraw:{}:{}:{}:{}:{}:{}:{}",
+            CHAIN_ID, contract_addr, nonce, player, credit_amount, token_amount, GEM_DENOM
+        ),
+    );
+    let res = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        nonce,
+        credit_amount,
+        token_amount,
+        sig,
+        None,
+        Some(GEM_DENOM.to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: player.to_string(),
+            amount: vec![Coin::new(9_900u128, GEM_DENOM)],
+        })
+    );
+
+    // Limits are tracked per denom
+    let gem_info: PlayerInfoResponse = from_json(
+        query_player_info(
+            deps.as_ref(),
+            mock_env(),
+            player.to_string(),
+            Some(GEM_DENOM.to_string()),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(gem_info.withdrawals_24h, Uint128::from(10_000u128));
+    assert_eq!(gem_info.daily_limit, Uint128::from(50_000u128));
+    let primary_info: PlayerInfoResponse =
+        from_json(query_player_info(deps.as_ref(), mock_env(), player.to_string(), None).unwrap())
+            .unwrap();
+    assert_eq!(primary_info.withdrawals_24h, Uint128::zero());
+}
+
+#[test]
+fn test_set_denom_config_rate_change_requires_timelock() {
+    let (mut deps, _sk) = setup();
+    let owner = a(&deps, "owner");
+
+    // The primary denom is configured through Config
+    let err = execute_set_denom_config(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        DENOM.to_string(),
+        gem_config(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::PrimaryDenomConfig { .. }));
+
+    execute_set_denom_config(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        GEM_DENOM.to_string(),
+        gem_config(),
+    )
+    .unwrap();
+
+    let mut changed = gem_config();
+    changed.rate_tokens = Uint128::from(2u128);
+    let err = execute_set_denom_config(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        GEM_DENOM.to_string(),
+        changed,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::RateChangeRequiresTimelock { .. }
+    ));
+
+    // Non-rate parameters can still be changed directly
+    let mut changed = gem_config();
+    changed.fee_bps = 25;
+    execute_set_denom_config(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        GEM_DENOM.to_string(),
+        changed,
+    )
+    .unwrap();
+
+    let res: DenomsResponse = from_json(query_denoms(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert_eq!(res.denoms.len(), 1);
+    assert_eq!(res.denoms[0].config.fee_bps, 25);
+}
+
+// ─── Player Denylist ────────────────────────────────────────────────────────
+
+#[test]
+fn test_blocked_player_cannot_deposit_or_withdraw() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let oracle = a(&deps, "oracle");
+    let player = a(&deps, "player1");
+    let rando = a(&deps, "rando");
+
+    let err = execute_set_player_blocked(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&rando, &[]),
+        player.to_string(),
+        true,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "owner or oracle".to_string()
+        }
+    );
+
+    execute_set_player_blocked(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&oracle, &[]),
+        player.to_string(),
+        true,
+        Some("exploit #42".to_string()),
+    )
+    .unwrap();
+
+    let res: PlayerBlockedResponse =
+        from_json(query_player_blocked(deps.as_ref(), player.to_string()).unwrap()).unwrap();
+    assert!(res.blocked);
+    assert_eq!(res.info.unwrap().reason, "exploit #42");
+
+    let err = execute_deposit(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[Coin::new(1_000_000u128, DENOM)]),
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::PlayerBlocked { .. }));
+
+    let credit_amount = Uint128::from(1_000u128);
+    let token_amount = Uint128::from(99_500u128);
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &ts_nonce(15),
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+    let err = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        ts_nonce(15),
+        credit_amount,
+        token_amount,
+        sig.clone(),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::PlayerBlocked { .. }));
+
+    let res: BlockedPlayersResponse =
+        from_json(query_blocked_players(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert_eq!(res.players.len(), 1);
+    assert_eq!(res.players[0].address, player.to_string());
+
+    // Unblocking restores access; the signed authorization is still usable
+    execute_set_player_blocked(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&oracle, &[]),
+        player.to_string(),
+        false,
+        None,
+    )
+    .unwrap();
+    execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        ts_nonce(15),
+        credit_amount,
+        token_amount,
+        sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+}
+
+// ─── Referrals ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_referral_rewards_from_withdrawal_fees() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let player = a(&deps, "player1");
+    let alice = a(&deps, "alice");
+    let bob = a(&deps, "bob");
+    let deposit = |deps: &mut TestDeps, referrer: &Addr| {
+        let info = message_info(&player, &[Coin::new(1_000_000u128, DENOM)]);
+        execute_deposit(deps.as_mut(), mock_env(), info, Some(referrer.to_string()))
+    };
+
+    // Referrers get 20% of their players' withdrawal fees
+    let err =
+        execute_set_referral_bps(deps.as_mut(), mock_env(), message_info(&owner, &[]), 10_001)
+            .unwrap_err();
+    assert_eq!(err, ContractError::InvalidBps { bps: 10_001 });
+    execute_set_referral_bps(deps.as_mut(), mock_env(), message_info(&owner, &[]), 2_000).unwrap();
+
+    let err = deposit(&mut deps, &player).unwrap_err();
+    assert_eq!(err, ContractError::SelfReferral);
+    let res = deposit(&mut deps, &alice).unwrap();
+    assert_eq!(res.attributes[3].key, "referrer");
+    assert_eq!(res.attributes[3].value, alice.to_string());
+    // Only the first deposit records a referrer
+    let res = deposit(&mut deps, &bob).unwrap();
+    assert_eq!(res.attributes.len(), 3);
+
+    let stats: ReferralStatsResponse =
+        from_json(query_referral_stats(deps.as_ref(), player.to_string()).unwrap()).unwrap();
+    assert_eq!(stats.referrer, Some(alice.to_string()));
+
+    // 10_000 credits: fee 5_000, of which 1_000 is kept for Alice
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    let nonce = ts_nonce(16);
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &nonce,
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+    execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        nonce,
+        credit_amount,
+        token_amount,
+        sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    #[allow(deprecated)]
+    let sent = SubMsgResult::Ok(SubMsgResponse {
+        events: vec![],
+        data: None,
+        msg_responses: vec![],
+    });
+    let res = reply(deps.as_mut(), mock_env(), send_reply(sent)).unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: a(&deps, "treasury").to_string(),
+            amount: vec![Coin::new(4_000u128, DENOM)],
+        })
+    );
+
+    let stats: ReferralStatsResponse =
+        from_json(query_referral_stats(deps.as_ref(), alice.to_string()).unwrap()).unwrap();
+    assert_eq!(stats.referrer, None);
+    assert_eq!(stats.referred_count, 1);
+    assert_eq!(
+        stats.rewards,
+        vec![ReferralRewardEntry {
+            denom: DENOM.to_string(),
+            claimable: Uint128::from(1_000u128),
+            total_earned: Uint128::from(1_000u128),
+        }]
+    );
+
+    // Player withdrawals cannot pay them out: 1M reserve + 1M payout
+    // leaves nothing for Alice's 1_000
+    let contract = Addr::unchecked(&contract_addr);
+    deps.querier
+        .bank
+        .update_balance(&contract, vec![Coin::new(2_000_000u128, DENOM)]);
+    let dry_run: ValidateWithdrawalResponse = from_json(
+        query_validate_withdrawal(
+            deps.as_ref(),
+            mock_env(),
+            bob.to_string(),
+            ts_nonce(17),
+            credit_amount,
+            token_amount,
+            None,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let failing = dry_run
+        .checks
+        .iter()
+        .filter(|c| c.error.is_some())
+        .map(|c| c.check.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(failing, vec!["reserve"]);
+    let info: TreasuryInfoResponse =
+        from_json(query_treasury_info(deps.as_ref(), mock_env(), None).unwrap()).unwrap();
+    assert_eq!(info.available_for_withdrawal, Uint128::from(999_000u128));
+    deps.querier
+        .bank
+        .update_balance(&contract, vec![Coin::new(100_000_000u128, DENOM)]);
+
+    // The owner cannot withdraw unclaimed rewards out of the treasury
+    let err = execute_withdraw_treasury(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Uint128::from(99_000_000u128),
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::ReserveBreached { .. }));
+
+    let res =
+        execute_claim_referral_rewards(deps.as_mut(), mock_env(), message_info(&alice, &[]), None)
+            .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: alice.to_string(),
+            amount: vec![Coin::new(1_000u128, DENOM)],
+        })
+    );
+    let err =
+        execute_claim_referral_rewards(deps.as_mut(), mock_env(), message_info(&alice, &[]), None)
+            .unwrap_err();
+    assert_eq!(err, ContractError::NoReferralRewards);
+    execute_withdraw_treasury(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Uint128::from(99_000_000u128),
+        None,
+    )
+    .unwrap();
+}
+
+// ─── Nonce Query ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_nonce_used_query() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+
+    // Before use
+    let res: NonceUsedResponse =
+        from_json(query_nonce_used(deps.as_ref(), ts_nonce(17)).unwrap()).unwrap();
+    assert!(!res.used);
+
+    // Use it
+    let credit_amount = Uint128::from(1_000u128);
+    let token_amount = Uint128::from(99_500u128);
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &ts_nonce(17),
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+    let info = message_info(&player, &[]);
+    execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ts_nonce(17),
+        credit_amount,
+        token_amount,
+        sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    // After use
+    let res: NonceUsedResponse =
+        from_json(query_nonce_used(deps.as_ref(), ts_nonce(17)).unwrap()).unwrap();
+    assert!(res.used);
+}
+
+#[test]
+fn test_validate_withdrawal_dry_run() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+    let credit_amount = Uint128::from(1_000u128);
+    let token_amount = Uint128::from(99_500u128);
+    let validate = |deps: &TestDeps, nonce: &str, token_amount: Uint128| {
+        let res: ValidateWithdrawalResponse = from_json(
+            query_validate_withdrawal(
+                deps.as_ref(),
+                mock_env(),
+                player.to_string(),
+                nonce.to_string(),
+                credit_amount,
+                token_amount,
+                None,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        res
+    };
+    let failing = |res: &ValidateWithdrawalResponse| {
+        res.checks
+            .iter()
+            .filter(|c| c.error.is_some())
+            .map(|c| c.check.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let res = validate(&deps, &ts_nonce(9), token_amount);
+    assert!(res.valid);
+    assert_eq!(res.checks.len(), 10);
+
+    // Wrong amount: the error Withdraw would return is reported
+    let res = validate(&deps, &ts_nonce(9), Uint128::from(99_000u128));
+    assert!(!res.valid);
+    assert_eq!(failing(&res), vec!["amount"]);
+    let amount = res.checks.iter().find(|c| c.check == "amount").unwrap();
+    assert!(amount.error.as_ref().unwrap().contains("mismatch"));
+
+    // The query does not consume the nonce
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &ts_nonce(9),
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+    execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        ts_nonce(9),
+        credit_amount,
+        token_amount,
+        sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let res = validate(&deps, &ts_nonce(9), token_amount);
+    assert_eq!(failing(&res), vec!["nonce", "cooldown"]);
+    let res = validate(&deps, &ts_nonce(10), token_amount);
+    assert_eq!(failing(&res), vec!["cooldown"]);
+}
+
+// ─── Achievement Discount ───────────────────────────────────────────────────
+
+/// Achievement contract's HasAchievement response
+#[cosmwasm_schema::cw_serde]
+struct AchievementCheck {
+    has_achievement: bool,
+    token_id: Option<String>,
+}
+
+#[test]
+fn test_achievement_holder_withdrawal_discount() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let veteran = a(&deps, "veteran");
+    let rookie = a(&deps, "rookie");
+    let achievements = a(&deps, "achievement_nft");
+
+    let holder = veteran.to_string();
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { msg, .. } => {
+            let AchievementQueryMsg::HasAchievement {
+                owner,
+                achievement_id,
+            } = from_json(msg).unwrap();
+            let has_achievement = owner == holder && achievement_id == "bridge_veteran";
+            let res = to_json_binary(&AchievementCheck {
+                has_achievement,
+                token_id: None,
+            });
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        }
+        _ => SystemResult::Err(SystemError::Unknown {}),
+    });
+
+    let discount = AchievementDiscountMsg {
+        achievement_contract: achievements.to_string(),
+        achievement_id: "bridge_veteran".to_string(),
+        discount_bps: 30,
+    };
+    let err = execute_set_achievement_discount(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&veteran, &[]),
+        Some(discount.clone()),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+    let err = execute_set_achievement_discount(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Some(AchievementDiscountMsg {
+            discount_bps: 10_001,
+            ..discount.clone()
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidBps { bps: 10_001 });
+    execute_set_achievement_discount(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Some(discount),
+    )
+    .unwrap();
+
+    // 10_000 credits = 1_000_000 ushido gross; holders pay 20 bps instead of 50
+    let credit_amount = Uint128::from(10_000u128);
+    let quote: ConversionResponse = from_json(
+        query_convert_credits_to_tokens(
+            deps.as_ref(),
+            mock_env(),
+            credit_amount,
+            None,
+            Some(veteran.to_string()),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(quote.token_amount, Uint128::from(998_000u128));
+    assert_eq!(quote.fee_amount, Uint128::from(2_000u128));
+
+    let withdraw = |deps: &mut TestDeps, player: &Addr, sequence: u64, token_amount: u128| {
+        let nonce = ts_nonce(sequence);
+        let token_amount = Uint128::from(token_amount);
+        let sig = sign_withdrawal(
+            &sk,
+            CHAIN_ID,
+            &contract_addr,
+            &nonce,
+            player.as_str(),
+            credit_amount,
+            token_amount,
+        );
+        execute_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(player, &[]),
+            nonce,
+            credit_amount,
+            token_amount,
+            sig,
+            None,
+            None,
+            None,
+            None,
+        )
+    };
+
+    let res = withdraw(&mut deps, &veteran, 1, 998_000).unwrap();
+    assert_eq!(
+        res.events,
+        vec![Event::from(WithdrawEvent {
+            recipient: veteran.to_string(),
+            denom: DENOM.to_string(),
+            token_amount: "998000".to_string(),
+            credit_amount: "10000".to_string(),
+            fee_amount: "2000".to_string(),
+        })]
+    );
+
+    // Players without the achievement still pay the full fee
+    let err = withdraw(&mut deps, &rookie, 2, 998_000).unwrap_err();
+    assert!(matches!(err, ContractError::AmountMismatch { .. }));
+    withdraw(&mut deps, &rookie, 3, 995_000).unwrap();
+
+    execute_set_achievement_discount(deps.as_mut(), mock_env(), message_info(&owner, &[]), None)
+        .unwrap();
+    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert!(config.achievement_discount.is_none());
+}
+
+/// Corporation DAO responses, trimmed to what the bridge reads
+#[cosmwasm_schema::cw_serde]
+struct DaoConfig {
+    denom: String,
+}
+
+#[cosmwasm_schema::cw_serde]
+struct DaoCorporations {
+    corporations: Vec<DaoCorporation>,
+}
+
+#[cosmwasm_schema::cw_serde]
+struct DaoCorporation {
+    corp_id: u64,
+    role: String,
+    status: String,
+}
+
+#[test]
+fn test_withdrawal_fee_shared_with_corporation() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let member = a(&deps, "member");
+    let recruit = a(&deps, "recruit");
+    let loner = a(&deps, "loner");
+    let dao = a(&deps, "dao");
+
+    let guild = [member.to_string(), recruit.to_string()];
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { msg, .. } => {
+            let res = match from_json(msg).unwrap() {
+                DaoQueryMsg::Config {} => to_json_binary(&DaoConfig {
+                    denom: DENOM.to_string(),
+                }),
+                DaoQueryMsg::CorporationsByMember { address, .. } => {
+                    let corporations = if guild.contains(&address) {
+                        vec![
+                            DaoCorporation {
+                                corp_id: 3,
+                                role: "member".to_string(),
+                                status: "dissolving".to_string(),
+                            },
+                            DaoCorporation {
+                                corp_id: 7,
+                                role: "officer".to_string(),
+                                status: "active".to_string(),
+                            },
+                        ]
+                    } else {
+                        vec![]
+                    };
+                    to_json_binary(&DaoCorporations { corporations })
+                }
+            };
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        }
+        _ => SystemResult::Err(SystemError::Unknown {}),
+    });
+
+    let fee_share = DaoFeeShareMsg {
+        dao_contract: dao.to_string(),
+        dao_fee_share_bps: 2_000,
+    };
+    let err = execute_set_dao_fee_share(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&member, &[]),
+        Some(fee_share.clone()),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+    let err = execute_set_dao_fee_share(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Some(DaoFeeShareMsg {
+            dao_fee_share_bps: 10_001,
+            ..fee_share.clone()
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidBps { bps: 10_001 });
+    execute_set_dao_fee_share(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Some(fee_share),
+    )
+    .unwrap();
+    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(config.dao_fee_share.unwrap().denom, DENOM);
+
+    let treasury = a(&deps, "treasury").to_string();
+
+    // 10_000 credits: 995_000 to the player, 5_000 fee
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    let withdraw_and_settle = |deps: &mut TestDeps, player: &Addr, sequence: u64| {
+        let nonce = ts_nonce(sequence);
+        let sig = sign_withdrawal(
+            &sk,
+            CHAIN_ID,
+            &contract_addr,
+            &nonce,
+            player.as_str(),
+            credit_amount,
+            token_amount,
+        );
+        execute_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(player, &[]),
+            nonce,
+            credit_amount,
+            token_amount,
+            sig,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        #[allow(deprecated)]
+        let sent = SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+            msg_responses: vec![],
+        });
+        reply(deps.as_mut(), mock_env(), send_reply(sent))
+            .unwrap()
+            .messages
+    };
+    let treasury_send = |amount: u128| {
+        SubMsg::new(BankMsg::Send {
+            to_address: treasury.clone(),
+            amount: vec![Coin::new(amount, DENOM)],
+        })
+    };
+
+    // A fifth of the member's fee goes to their active corporation
+    let donation = Coin::new(1_000u128, DENOM);
+    let msgs = withdraw_and_settle(&mut deps, &member, 1);
+    assert_eq!(
+        msgs,
+        vec![
+            SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: dao.to_string(),
+                    msg: to_json_binary(&DaoExecuteMsg::DonateTreasury { corp_id: 7 }).unwrap(),
+                    funds: vec![donation.clone()],
+                },
+                DAO_DONATION_REPLY_ID,
+            )
+            .with_payload(to_json_binary(&donation).unwrap()),
+            treasury_send(4_000),
+        ]
+    );
+
+    // A donation the DAO rejects is paid to the treasury instead
+    #[allow(deprecated)]
+    let rejected = Reply {
+        id: DAO_DONATION_REPLY_ID,
+        payload: to_json_binary(&donation).unwrap(),
+        gas_used: 0,
+        result: SubMsgResult::Err("corporation not active".to_string()),
+    };
+    let res = reply(deps.as_mut(), mock_env(), rejected).unwrap();
+    assert_eq!(res.messages, vec![treasury_send(1_000)]);
+
+    // Players outside a corporation pay the treasury in full
+    let msgs = withdraw_and_settle(&mut deps, &loner, 2);
+    assert_eq!(msgs, vec![treasury_send(5_000)]);
+
+    // So do members while the DAO query fails
+    deps.querier
+        .update_wasm(|_| SystemResult::Err(SystemError::Unknown {}));
+    let msgs = withdraw_and_settle(&mut deps, &recruit, 3);
+    assert_eq!(msgs, vec![treasury_send(5_000)]);
+}
+
+// ─── Conversion Queries ─────────────────────────────────────────────────────
+
+#[test]
+fn test_conversion_credits_to_tokens() {
+    let (deps, _sk) = setup();
+
+    let res: ConversionResponse = from_json(
+        query_convert_credits_to_tokens(
+            deps.as_ref(),
+            mock_env(),
+            Uint128::from(10_000u128),
+            None,
+            None,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    // 10_000 credits * 1_000_000 / 10_000 = 1_000_000 gross
+    // fee = 1_000_000 * 50 / 10_000 = 5_000
+    // net = 995_000
+    assert_eq!(res.credit_amount, Uint128::from(10_000u128));
+    assert_eq!(res.token_amount, Uint128::from(995_000u128));
+    assert_eq!(res.fee_amount, Uint128::from(5_000u128));
+}
+
+#[test]
+fn test_conversion_tokens_to_credits() {
+    let (deps, _sk) = setup();
+
+    let res: ConversionResponse = from_json(
+        query_convert_tokens_to_credits(deps.as_ref(), mock_env(), Uint128::from(1_000_000u128), None).unwrap(),
+    )
+    .unwrap();
+
+    // 1_000_000 ushido * 10_000 / 1_000_000 = 10_000 credits (no fee on deposit direction)
+    assert_eq!(res.credit_amount, Uint128::from(10_000u128));
+    assert_eq!(res.fee_amount, Uint128::zero());
+}
+
+// ─── Arithmetic Edge Cases ──────────────────────────────────────────────────
+
+#[test]
+fn test_conversion_small_amount() {
+    let (deps, _sk) = setup();
+
+    // 1 credit = 100 ushido gross, fee = 0 (100 * 50 / 10000 = 0.5 rounds to 0)
+    let res: ConversionResponse = from_json(
+        query_convert_credits_to_tokens(deps.as_ref(), mock_env(), Uint128::from(1u128), None, None).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(res.token_amount, Uint128::from(100u128)); // net = gross when fee rounds to 0
+    assert_eq!(res.fee_amount, Uint128::zero());
+}
+
+#[test]
+fn test_fee_rounding_policy() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let player = a(&deps, "player1");
+    let set_policy = |deps: &mut TestDeps, sender: &Addr, rounding: FeeRounding, min_fee: u128| {
+        execute_set_fee_policy(
+            deps.as_mut(),
+            mock_env(),
+            message_info(sender, &[]),
+            rounding,
+            Uint128::from(min_fee),
+        )
+    };
+    // 1 credit = 100 ushido gross; 0.5% of that is half a micro-unit
+    let quote_fee = |deps: &TestDeps| -> Uint128 {
+        let res: ConversionResponse = from_json(
+            query_convert_credits_to_tokens(deps.as_ref(), mock_env(), Uint128::one(), None, None)
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(res.token_amount + res.fee_amount, Uint128::from(100u128));
+        res.fee_amount
+    };
+
+    let err = set_policy(&mut deps, &player, FeeRounding::Ceil, 0).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized { .. })
+    ));
+
+    set_policy(&mut deps, &owner, FeeRounding::Ceil, 0).unwrap();
+    assert_eq!(quote_fee(&deps), Uint128::one());
+    set_policy(&mut deps, &owner, FeeRounding::HalfUp, 0).unwrap();
+    assert_eq!(quote_fee(&deps), Uint128::one());
+    set_policy(&mut deps, &owner, FeeRounding::Floor, 10).unwrap();
+    assert_eq!(quote_fee(&deps), Uint128::from(10u128));
+    // The minimum never takes more than the withdrawal is worth
+    set_policy(&mut deps, &owner, FeeRounding::Floor, 1_000).unwrap();
+    assert_eq!(quote_fee(&deps), Uint128::from(100u128));
+    let config: DenomConfig =
+        from_json(query_denom_config(deps.as_ref(), DENOM.to_string()).unwrap()).unwrap();
+    assert_eq!(config.min_fee, Uint128::from(1_000u128));
+
+    // Withdrawals are checked against the same fee as the quote
+    set_policy(&mut deps, &owner, FeeRounding::Ceil, 0).unwrap();
+    let withdraw = |deps: &mut TestDeps, token_amount: u128| {
+        let sig = sign_withdrawal(
+            &sk,
+            CHAIN_ID,
+            &contract_addr,
+            &ts_nonce(1),
+            player.as_str(),
+            Uint128::one(),
+            Uint128::from(token_amount),
+        );
+        execute_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&player, &[]),
+            ts_nonce(1),
+            Uint128::one(),
+            Uint128::from(token_amount),
+            sig,
+            None,
+            None,
+            None,
+            None,
+        )
+    };
+    let err = withdraw(&mut deps, 100).unwrap_err();
+    assert!(matches!(err, ContractError::AmountMismatch { .. }));
+    let res = withdraw(&mut deps, 99).unwrap();
+    assert_eq!(
+        res.events,
+        vec![Event::from(WithdrawEvent {
+            recipient: player.to_string(),
+            denom: DENOM.to_string(),
+            token_amount: "99".to_string(),
+            credit_amount: "1".to_string(),
+            fee_amount: "1".to_string(),
+        })]
+    );
+}
+
+#[test]
+fn test_conversion_large_amount() {
+    let (deps, _sk) = setup();
+
+    // 1_000_000_000 credits (1B) = 100_000_000_000 ushido gross
+    let res: ConversionResponse = from_json(
+        query_convert_credits_to_tokens(
+            deps.as_ref(),
+            mock_env(),
+            Uint128::from(1_000_000_000u128),
+            None,
+            None,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    let expected_gross = Uint128::from(100_000_000_000u128);
+    let expected_fee = Uint128::from(500_000_000u128); // 0.5%
+    let expected_net = expected_gross - expected_fee;
+
+    assert_eq!(res.token_amount, expected_net);
+    assert_eq!(res.fee_amount, expected_fee);
+}
+
+// ─── Treasury Management ────────────────────────────────────────────────────
+
+#[test]
+fn test_withdraw_treasury_respects_reserve() {
+    let (mut deps, _sk, _contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+
+    // Contract has 100_000_000 ushido, min_reserve is 1_000_000
+    // Try to withdraw too much
+    let info = message_info(&owner, &[]);
+    let err = execute_withdraw_treasury(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        Uint128::from(99_500_000u128), // would leave only 500k, below 1M reserve,
+        None,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, ContractError::ReserveBreached { .. }));
+
+    // Withdraw an allowed amount
+    let info = message_info(&owner, &[]);
+    execute_withdraw_treasury(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        Uint128::from(99_000_000u128), // leaves exactly 1M,
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_withdrawal_queue() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let contract = Addr::unchecked(&contract_addr);
+    deps.querier.bank.update_balance(&contract, vec![]);
+
+    // 10_000 credits = 995_000 net + 5_000 fee
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    let withdraw = |deps: &mut TestDeps, name: &str, sequence: u64| {
+        let player = a(deps, name);
+        let nonce = ts_nonce(sequence);
+        let sig = sign_withdrawal(
+            &sk,
+            CHAIN_ID,
+            &contract_addr,
+            &nonce,
+            player.as_str(),
+            credit_amount,
+            token_amount,
+        );
+        execute_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&player, &[]),
+            nonce,
+            credit_amount,
+            token_amount,
+            sig,
+            None,
+            None,
+            None,
+            None,
+        )
+    };
+    let queue_reply = |id: u64, result: SubMsgResult| {
+        #[allow(deprecated)]
+        Reply {
+            id: QUEUE_PAYOUT_REPLY_ID,
+            payload: to_json_binary(&id).unwrap(),
+            gas_used: 0,
+            result,
+        }
+    };
+
+    // Off by default: an empty treasury rejects the withdrawal
+    let err = withdraw(&mut deps, "player1", 18).unwrap_err();
+    assert!(matches!(err, ContractError::InsufficientTreasury { .. }));
+
+    let player1 = a(&deps, "player1");
+    let err =
+        execute_set_withdrawal_queue(deps.as_mut(), mock_env(), message_info(&player1, &[]), true)
+            .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized { .. })
+    ));
+    execute_set_withdrawal_queue(deps.as_mut(), mock_env(), message_info(&owner, &[]), true)
+        .unwrap();
+
+    // Both authorizations are kept: nonce consumed, nothing paid yet
+    let res = withdraw(&mut deps, "player1", 18).unwrap();
+    assert_eq!(res.attributes[0].value, "withdraw_queued");
+    assert!(res.messages.is_empty());
+    withdraw(&mut deps, "player2", 19).unwrap();
+    let used: NonceUsedResponse =
+        from_json(query_nonce_used(deps.as_ref(), ts_nonce(18)).unwrap()).unwrap();
+    assert!(used.used);
+    let solvency: SolvencyResponse =
+        from_json(query_solvency(deps.as_ref(), mock_env(), None).unwrap()).unwrap();
+    assert_eq!(solvency.queued_withdrawals, 2);
+    assert_eq!(solvency.queued_amount, Uint128::from(2_000_000u128));
+
+    // Nothing to pay from an empty treasury
+    let anyone = a(&deps, "anyone");
+    let process = |deps: &mut TestDeps, limit: Option<u32>| {
+        execute_process_queue(deps.as_mut(), mock_env(), message_info(&anyone, &[]), limit).unwrap()
+    };
+    assert!(process(&mut deps, None).messages.is_empty());
+
+    // 2.5M covers the first payout and keeps the 1M reserve; the second waits
+    deps.querier
+        .bank
+        .update_balance(&contract, vec![Coin::new(2_500_000u128, DENOM)]);
+    let res = process(&mut deps, None);
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].payload, to_json_binary(&1u64).unwrap());
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: player1.to_string(),
+            amount: vec![Coin::new(995_000u128, DENOM)],
+        })
+    );
+    #[allow(deprecated)]
+    let sent = SubMsgResult::Ok(SubMsgResponse {
+        events: vec![],
+        data: None,
+        msg_responses: vec![],
+    });
+    let res = reply(deps.as_mut(), mock_env(), queue_reply(1, sent)).unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: a(&deps, "treasury").to_string(),
+            amount: vec![Coin::new(5_000u128, DENOM)],
+        })
+    );
+    assert_eq!(res.events.len(), 1);
+
+    // A funded treasury still cannot let a new withdrawal overtake the queue
+    deps.querier
+        .bank
+        .update_balance(&contract, vec![Coin::new(10_000_000u128, DENOM)]);
+    let res = withdraw(&mut deps, "player3", 20).unwrap();
+    assert_eq!(res.attributes[0].value, "withdraw_queued");
+
+    // A failed send drops the entry and frees the nonce for a retry
+    let res = process(&mut deps, Some(1));
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].payload, to_json_binary(&2u64).unwrap());
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        queue_reply(2, SubMsgResult::Err("blocked address".to_string())),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(res.attributes[0].value, "queued_withdraw_rolled_back");
+    let used: NonceUsedResponse =
+        from_json(query_nonce_used(deps.as_ref(), ts_nonce(19)).unwrap()).unwrap();
+    assert!(!used.used);
+
+    let queue: WithdrawalQueueResponse =
+        from_json(query_withdrawal_queue(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert_eq!(queue.withdrawals.len(), 1);
+    assert_eq!(queue.withdrawals[0].player, a(&deps, "player3"));
+
+    // Queued tokens are held back from the owner along with the reserve
+    let err = execute_withdraw_treasury(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Uint128::from(8_000_001u128),
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::ReserveBreached { .. }));
+}
+
+#[test]
+fn test_withdrawal_queue_moves_past_stuck_entries() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let contract = Addr::unchecked(&contract_addr);
+    deps.querier.bank.update_balance(&contract, vec![]);
+    execute_set_withdrawal_queue(deps.as_mut(), mock_env(), message_info(&owner, &[]), true)
+        .unwrap();
+
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    for (name, sequence) in [("player1", 18), ("player2", 19), ("player3", 20)] {
+        let player = a(&deps, name);
+        let nonce = ts_nonce(sequence);
+        let sig = sign_withdrawal(
+            &sk,
+            CHAIN_ID,
+            &contract_addr,
+            &nonce,
+            player.as_str(),
+            credit_amount,
+            token_amount,
+        );
+        execute_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&player, &[]),
+            nonce,
+            credit_amount,
+            token_amount,
+            sig,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    }
+    for name in ["player1", "player2"] {
+        let player = a(&deps, name);
+        execute_set_player_blocked(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            player.to_string(),
+            true,
+            None,
+        )
+        .unwrap();
+    }
+    deps.querier
+        .bank
+        .update_balance(&contract, vec![Coin::new(2_500_000u128, DENOM)]);
+
+    // Two frozen players fill the first batch; the next one picks up behind them
+    let anyone = a(&deps, "anyone");
+    let process = |deps: &mut TestDeps| {
+        execute_process_queue(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&anyone, &[]),
+            Some(2),
+        )
+        .unwrap()
+    };
+    assert!(process(&mut deps).messages.is_empty());
+    let res = process(&mut deps);
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].payload, to_json_binary(&3u64).unwrap());
+
+    // The owner can drop an entry that will never be paid
+    let player1 = a(&deps, "player1");
+    let err =
+        execute_cancel_queued_withdrawal(deps.as_mut(), mock_env(), message_info(&player1, &[]), 1)
+            .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized { .. })
+    ));
+    let res =
+        execute_cancel_queued_withdrawal(deps.as_mut(), mock_env(), message_info(&owner, &[]), 1)
+            .unwrap();
+    assert_eq!(res.attributes[0].value, "cancel_queued_withdrawal");
+
+    let used: NonceUsedResponse =
+        from_json(query_nonce_used(deps.as_ref(), ts_nonce(18)).unwrap()).unwrap();
+    assert!(!used.used);
+    let info: PlayerInfoResponse =
+        from_json(query_player_info(deps.as_ref(), mock_env(), player1.to_string(), None).unwrap())
+            .unwrap();
+    assert_eq!(info.withdrawals_24h, Uint128::zero());
+    let solvency: SolvencyResponse =
+        from_json(query_solvency(deps.as_ref(), mock_env(), None).unwrap()).unwrap();
+    assert_eq!(solvency.queued_withdrawals, 2);
+    assert_eq!(solvency.queued_amount, Uint128::from(2_000_000u128));
+
+    let err =
+        execute_cancel_queued_withdrawal(deps.as_mut(), mock_env(), message_info(&owner, &[]), 1)
+            .unwrap_err();
+    assert_eq!(err, ContractError::QueuedWithdrawalNotFound { id: 1 });
+}
+
+#[test]
+fn test_peak_balance_decays_after_window() {
+    let (mut deps, _sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+
+    // Funding samples the current 100 SHIDO balance
+    let info = message_info(&owner, &[Coin::new(1_000_000u128, DENOM)]);
+    execute_fund_treasury(deps.as_mut(), mock_env(), info).unwrap();
+
+    deps.querier
+        .bank
+        .update_balance(&contract_addr, vec![Coin::new(50_000_000u128, DENOM)]);
+
+    let info: TreasuryInfoResponse =
+        from_json(query_treasury_info(deps.as_ref(), mock_env(), None).unwrap()).unwrap();
+    assert_eq!(info.peak_balance, Uint128::from(100_000_000u128));
+
+    // Default window is 7 days; after that the high-water mark follows the balance
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(8 * 86_400);
+    let info: TreasuryInfoResponse =
+        from_json(query_treasury_info(deps.as_ref(), env, None).unwrap()).unwrap();
+    assert_eq!(info.peak_balance, Uint128::from(50_000_000u128));
+}
+
+#[test]
+fn test_solvency_query() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let player = a(&deps, "player1");
+
+    let res: SolvencyResponse =
+        from_json(query_solvency(deps.as_ref(), mock_env(), None).unwrap()).unwrap();
+    assert_eq!(res.outflow_24h, Uint128::zero());
+    assert_eq!(res.solvency_ratio, None);
+    assert!(!res.reserve_breached);
+
+    // 10_000 credits: 995_000 to player + 5_000 fee = 1_000_000 outgoing
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    let nonce = ts_nonce(20);
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &nonce,
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+    execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        nonce,
+        credit_amount,
+        token_amount,
+        sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let res: SolvencyResponse =
+        from_json(query_solvency(deps.as_ref(), mock_env(), None).unwrap()).unwrap();
+    assert_eq!(res.outflow_24h, Uint128::from(1_000_000u128));
+    assert_eq!(
+        res.solvency_ratio,
+        Some(Decimal::from_ratio(100u128, 1u128))
+    );
+    assert_eq!(res.pending_ibc_transfers, 0);
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(25 * 3600);
+    let res: SolvencyResponse =
+        from_json(query_solvency(deps.as_ref(), env, None).unwrap()).unwrap();
+    assert_eq!(res.outflow_24h, Uint128::zero());
+    assert_eq!(res.solvency_ratio, None);
+}
+
+#[test]
+fn test_sweep_foreign_funds() {
+    let (mut deps, _sk, _contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let rando = a(&deps, "rando");
+    let recipient = a(&deps, "recipient");
+    let sweep = |deps: &mut TestDeps, sender: &Addr, denom: &str| {
+        execute_sweep_foreign_funds(
+            deps.as_mut(),
+            mock_env(),
+            message_info(sender, &[]),
+            denom.to_string(),
+            Uint128::from(700u128),
+            recipient.to_string(),
+        )
+    };
+
+    let err = sweep(&mut deps, &rando, "uatom").unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized { .. })
+    ));
+
+    // The primary denom and additional bridged denoms keep their reserve logic
+    let err = sweep(&mut deps, &owner, DENOM).unwrap_err();
+    assert!(matches!(err, ContractError::BridgedDenom { .. }));
+    execute_set_denom_config(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        GEM_DENOM.to_string(),
+        gem_config(),
+    )
+    .unwrap();
+    let err = sweep(&mut deps, &owner, GEM_DENOM).unwrap_err();
+    assert!(matches!(err, ContractError::BridgedDenom { .. }));
+
+    let res = sweep(&mut deps, &owner, "uatom").unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin::new(700u128, "uatom")],
+        })
+    );
+}
+
+#[test]
+fn test_non_owner_cannot_withdraw_treasury() {
+    let (mut deps, _sk, _contract_addr) = setup_with_funded_treasury();
+    let rando = a(&deps, "rando");
+
+    let info = message_info(&rando, &[]);
+    let err = execute_withdraw_treasury(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        Uint128::from(1_000u128),
+        None,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+}
+
+// ─── Oracle Two-Step Transfer ───────────────────────────────────────────────
+
+#[test]
+fn test_oracle_transfer() {
+    let (mut deps, _sk) = setup();
+    let owner = a(&deps, "owner");
+    let new_oracle = a(&deps, "new_oracle");
+    let new_pubkey = Binary::from(vec![0x02; 33]); // dummy compressed pubkey
+
+    let info = message_info(&owner, &[]);
+    execute_propose_oracle(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        new_oracle.to_string(),
+        new_pubkey.clone(),
+    )
+    .unwrap();
+
+    let pending: Option<sysbreak_credit_bridge::state::PendingOracleTransfer> =
+        from_json(query_pending_oracle(deps.as_ref()).unwrap()).unwrap();
+    assert!(pending.is_some());
+
+    let info = message_info(&new_oracle, &[]);
+    execute_accept_oracle(deps.as_mut(), mock_env(), info).unwrap();
+
+    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(config.oracle, new_oracle);
+    assert_eq!(config.oracle_pubkey, new_pubkey);
+}
+
+#[test]
+fn test_wrong_address_cannot_accept_oracle() {
+    let (mut deps, _sk) = setup();
+    let owner = a(&deps, "owner");
+    let new_oracle = a(&deps, "new_oracle");
+    let rando = a(&deps, "rando");
+
+    let info = message_info(&owner, &[]);
+    execute_propose_oracle(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        new_oracle.to_string(),
+        Binary::from(vec![0x02; 33]),
+    )
+    .unwrap();
 
-    // Use it
+    let info = message_info(&rando, &[]);
+    let err = execute_accept_oracle(deps.as_mut(), mock_env(), info).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::NotPendingRole {
+            role: "oracle".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_previous_oracle_key_grace_window() {
+    let (mut deps, old_sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let new_oracle = a(&deps, "new_oracle");
+    let new_sk = SigningKey::from_bytes((&[0x42u8; 32]).into()).unwrap();
+    let credit_amount = Uint128::from(1_000u128);
+    let token_amount = Uint128::from(99_500u128);
+
+    // Signatures handed out before the rotation
+    let sign = |sk: &SigningKey, player: &Addr, nonce: &str| {
+        sign_withdrawal(
+            sk,
+            CHAIN_ID,
+            &contract_addr,
+            nonce,
+            player.as_str(),
+            credit_amount,
+            token_amount,
+        )
+    };
+    let alice = a(&deps, "alice");
+    let bob = a(&deps, "bob");
+    let alice_sig = sign(&old_sk, &alice, &ts_nonce(21));
+    let bob_sig = sign(&old_sk, &bob, &ts_nonce(22));
+
+    let err = execute_set_oracle_key_grace(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        604_801,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidOracleKeyGrace { value: 604_801 });
+
+    execute_propose_oracle(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        new_oracle.to_string(),
+        Binary::from(pubkey_bytes(new_sk.verifying_key())),
+    )
+    .unwrap();
+    let res =
+        execute_accept_oracle(deps.as_mut(), mock_env(), message_info(&new_oracle, &[])).unwrap();
+    assert_eq!(res.attributes[2].value, "2");
+
+    let keys: OracleKeysResponse =
+        from_json(query_oracle_keys(deps.as_ref(), mock_env()).unwrap()).unwrap();
+    assert_eq!(keys.current.version, 2);
+    let previous = keys.previous.unwrap();
+    assert_eq!(previous.version, 1);
+    assert_eq!(
+        previous.valid_until,
+        Some(mock_env().block.time.plus_seconds(86_400))
+    );
+
+    // The old key still signs during the grace window
+    let res = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &[]),
+        ts_nonce(21),
+        credit_amount,
+        token_amount,
+        alice_sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(res.attributes[2].value, "1");
+    let carol = a(&deps, "carol");
+    let res = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&carol, &[]),
+        ts_nonce(23),
+        credit_amount,
+        token_amount,
+        sign(&new_sk, &carol, &ts_nonce(23)),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(res.attributes[2].value, "2");
+
+    // Shortening the grace retires the old key at once
+    execute_set_oracle_key_grace(deps.as_mut(), mock_env(), message_info(&owner, &[]), 0).unwrap();
+    let keys: OracleKeysResponse =
+        from_json(query_oracle_keys(deps.as_ref(), mock_env()).unwrap()).unwrap();
+    assert!(keys.previous.is_none());
+    let err = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&bob, &[]),
+        ts_nonce(22),
+        credit_amount,
+        token_amount,
+        bob_sig,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidSignature);
+}
+
+// ─── Pause ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_pause_blocks_deposits_and_withdrawals() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let player = a(&deps, "player1");
+
+    // Pause
+    let info = message_info(&owner, &[]);
+    execute_pause(deps.as_mut(), mock_env(), info).unwrap();
+
+    // Deposit fails
+    let info = message_info(&player, &[Coin::new(1_000_000u128, DENOM)]);
+    let err = execute_deposit(deps.as_mut(), mock_env(), info, None).unwrap_err();
+    assert_eq!(err, ContractError::DepositsPaused);
+
+    // Withdrawal fails
     let credit_amount = Uint128::from(1_000u128);
     let token_amount = Uint128::from(99_500u128);
     let sig = sign_withdrawal(
         &sk,
         CHAIN_ID,
         &contract_addr,
-        &ts_nonce("q"),
+        &ts_nonce(24),
         player.as_str(),
         credit_amount,
         token_amount,
     );
     let info = message_info(&player, &[]);
-    execute_withdraw(
+    let err = execute_withdraw(
         deps.as_mut(),
         mock_env(),
         info,
-        ts_nonce("q"),
+        ts_nonce(24),
         credit_amount,
         token_amount,
         sig,
+        None,
+        None,
+        None,
+        None,
     )
-    .unwrap();
+    .unwrap_err();
+    assert_eq!(err, ContractError::WithdrawalsPaused);
 
-    // After use
-    let res: NonceUsedResponse =
-        from_json(query_nonce_used(deps.as_ref(), ts_nonce("q")).unwrap()).unwrap();
-    assert!(res.used);
+    // Unpause
+    let info = message_info(&owner, &[]);
+    execute_unpause(deps.as_mut(), mock_env(), info).unwrap();
 }
 
-// ─── Conversion Queries ─────────────────────────────────────────────────────
+#[test]
+fn test_pauser_can_pause_but_not_unpause() {
+    let (mut deps, _sk) = setup();
+    let owner = a(&deps, "owner");
+    let pauser = a(&deps, "pauser");
+
+    // Not a pauser yet
+    let err = execute_pause(deps.as_mut(), mock_env(), message_info(&pauser, &[])).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "owner or pauser".to_string()
+        }
+    );
+
+    execute_set_pauser(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Some(pauser.to_string()),
+    )
+    .unwrap();
+
+    execute_pause(deps.as_mut(), mock_env(), message_info(&pauser, &[])).unwrap();
+    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert!(config.deposits_paused && config.withdrawals_paused);
+
+    // Pauser cannot unpause or change config
+    let err = execute_unpause(deps.as_mut(), mock_env(), message_info(&pauser, &[])).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+    let err =
+        execute_update_fee(deps.as_mut(), mock_env(), message_info(&pauser, &[]), 0).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+    let err = execute_set_pauser(deps.as_mut(), mock_env(), message_info(&pauser, &[]), None)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+
+    execute_unpause(deps.as_mut(), mock_env(), message_info(&owner, &[])).unwrap();
+}
 
 #[test]
-fn test_conversion_credits_to_tokens() {
-    let (deps, _sk) = setup();
+fn test_pause_deposits_and_withdrawals_separately() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let player = a(&deps, "player1");
+    let credit_amount = Uint128::from(1_000u128);
+    let token_amount = Uint128::from(99_500u128);
 
-    let res: ConversionResponse = from_json(
-        query_convert_credits_to_tokens(deps.as_ref(), Uint128::from(10_000u128)).unwrap(),
+    // Incident: outflows stop, deposits keep flowing
+    execute_pause_withdrawals(deps.as_mut(), mock_env(), message_info(&owner, &[])).unwrap();
+    let info = message_info(&player, &[Coin::new(1_000_000u128, DENOM)]);
+    execute_deposit(deps.as_mut(), mock_env(), info, None).unwrap();
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &ts_nonce(25),
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+    let err = execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        ts_nonce(25),
+        credit_amount,
+        token_amount,
+        sig.clone(),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::WithdrawalsPaused);
+    let err = execute_unpause_deposits(deps.as_mut(), mock_env(), message_info(&owner, &[]))
+        .unwrap_err();
+    assert_eq!(err, ContractError::Admin(AdminError::NotPaused));
+
+    // Backend maintenance: deposits stop, withdrawals resume
+    execute_pause_deposits(deps.as_mut(), mock_env(), message_info(&owner, &[])).unwrap();
+    execute_unpause_withdrawals(deps.as_mut(), mock_env(), message_info(&owner, &[])).unwrap();
+    let info = message_info(&player, &[Coin::new(1_000_000u128, DENOM)]);
+    let err = execute_deposit(deps.as_mut(), mock_env(), info, None).unwrap_err();
+    assert_eq!(err, ContractError::DepositsPaused);
+    execute_withdraw(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&player, &[]),
+        ts_nonce(25),
+        credit_amount,
+        token_amount,
+        sig,
+        None,
+        None,
+        None,
+        None,
     )
     .unwrap();
 
-    // 10_000 credits * 1_000_000 / 10_000 = 1_000_000 gross
-    // fee = 1_000_000 * 50 / 10_000 = 5_000
-    // net = 995_000
-    assert_eq!(res.credit_amount, Uint128::from(10_000u128));
-    assert_eq!(res.token_amount, Uint128::from(995_000u128));
-    assert_eq!(res.fee_amount, Uint128::from(5_000u128));
+    // A config stored with the single flag comes out of migrate paused both ways
+    execute_pause(deps.as_mut(), mock_env(), message_info(&owner, &[])).unwrap();
+    let raw = String::from_utf8(deps.storage.get(b"config").unwrap()).unwrap();
+    let legacy = raw.replace(
+        "\"withdrawals_paused\":true,\"deposits_paused\":true",
+        "\"paused\":true",
+    );
+    assert_ne!(raw, legacy);
+    deps.storage.set(b"config", legacy.as_bytes());
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert!(config.deposits_paused && config.withdrawals_paused);
 }
 
 #[test]
-fn test_conversion_tokens_to_credits() {
-    let (deps, _sk) = setup();
+fn test_maintenance_mode_whitelist() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let player = a(&deps, "player1");
+    let market_maker = a(&deps, "market_maker");
 
-    let res: ConversionResponse = from_json(
-        query_convert_tokens_to_credits(deps.as_ref(), Uint128::from(1_000_000u128)).unwrap(),
+    execute_set_maintenance_mode(deps.as_mut(), mock_env(), message_info(&owner, &[]), true)
+        .unwrap();
+    execute_set_maintenance_whitelist(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        market_maker.to_string(),
+        true,
     )
     .unwrap();
 
-    // 1_000_000 ushido * 10_000 / 1_000_000 = 10_000 credits (no fee on deposit direction)
-    assert_eq!(res.credit_amount, Uint128::from(10_000u128));
-    assert_eq!(res.fee_amount, Uint128::zero());
+    // Deposits are halted for everyone
+    let info = message_info(&market_maker, &[Coin::new(1_000_000u128, DENOM)]);
+    let err = execute_deposit(deps.as_mut(), mock_env(), info, None).unwrap_err();
+    assert_eq!(err, ContractError::MaintenanceMode);
+
+    let credit_amount = Uint128::from(10_000u128);
+    let token_amount = Uint128::from(995_000u128);
+    let withdraw = |deps: &mut TestDeps, who: &Addr, sequence: u64| {
+        let nonce = ts_nonce(sequence);
+        let sig = sign_withdrawal(
+            &sk,
+            CHAIN_ID,
+            &contract_addr,
+            &nonce,
+            who.as_str(),
+            credit_amount,
+            token_amount,
+        );
+        execute_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(who, &[]),
+            nonce,
+            credit_amount,
+            token_amount,
+            sig,
+            None,
+            None,
+            None,
+            None,
+        )
+    };
+
+    let err = withdraw(&mut deps, &player, 1).unwrap_err();
+    assert_eq!(err, ContractError::MaintenanceMode);
+    withdraw(&mut deps, &market_maker, 2).unwrap();
+
+    // Ending maintenance clears the whitelist
+    execute_set_maintenance_mode(deps.as_mut(), mock_env(), message_info(&owner, &[]), false)
+        .unwrap();
+    let res: MaintenanceWhitelistResponse =
+        from_json(query_maintenance_whitelist(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert!(res.addresses.is_empty());
+    withdraw(&mut deps, &player, 3).unwrap();
 }
 
-// ─── Arithmetic Edge Cases ──────────────────────────────────────────────────
+// ─── Admin Updates ──────────────────────────────────────────────────────────
 
 #[test]
-fn test_conversion_small_amount() {
-    let (deps, _sk) = setup();
+fn test_scheduled_rate_update() {
+    let (mut deps, _sk) = setup();
+    let owner = a(&deps, "owner");
+    let now = mock_env().block.time.seconds();
 
-    // 1 credit = 100 ushido gross, fee = 0 (100 * 50 / 10000 = 0.5 rounds to 0)
-    let res: ConversionResponse = from_json(
-        query_convert_credits_to_tokens(deps.as_ref(), Uint128::from(1u128)).unwrap(),
+    // Timelock shorter than the minimum is rejected
+    let info = message_info(&owner, &[]);
+    let err = execute_schedule_rate_update(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        None,
+        Uint128::from(20_000u128),
+        Uint128::from(1_000_000u128),
+        now + 60,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::RateTimelockTooShort { .. }));
+
+    let effective_at = now + 86_400;
+    let info = message_info(&owner, &[]);
+    execute_schedule_rate_update(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        None,
+        Uint128::from(20_000u128),
+        Uint128::from(1_000_000u128),
+        effective_at,
     )
     .unwrap();
 
-    assert_eq!(res.token_amount, Uint128::from(100u128)); // net = gross when fee rounds to 0
-    assert_eq!(res.fee_amount, Uint128::zero());
+    let pending: Option<PendingRateUpdate> =
+        from_json(query_pending_rate(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(pending.unwrap().effective_at.seconds(), effective_at);
+
+    // Not applicable before the effective time; rate unchanged
+    let rando = a(&deps, "rando");
+    let err = execute_apply_scheduled_rate(deps.as_mut(), mock_env(), message_info(&rando, &[]))
+        .unwrap_err();
+    assert!(matches!(err, ContractError::RateUpdateNotReady { .. }));
+    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(config.rate_credits, Uint128::from(RATE_CREDITS));
+
+    // Anyone can apply once the timelock has elapsed
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(86_400);
+    execute_apply_scheduled_rate(deps.as_mut(), env, message_info(&rando, &[])).unwrap();
+
+    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(config.rate_credits, Uint128::from(20_000u128));
+    let pending: Option<PendingRateUpdate> =
+        from_json(query_pending_rate(deps.as_ref()).unwrap()).unwrap();
+    assert!(pending.is_none());
 }
 
 #[test]
-fn test_conversion_large_amount() {
-    let (deps, _sk) = setup();
+fn test_cancel_scheduled_rate() {
+    let (mut deps, _sk) = setup();
+    let owner = a(&deps, "owner");
+    let effective_at = mock_env().block.time.seconds() + 86_400;
 
-    // 1_000_000_000 credits (1B) = 100_000_000_000 ushido gross
-    let res: ConversionResponse = from_json(
-        query_convert_credits_to_tokens(deps.as_ref(), Uint128::from(1_000_000_000u128)).unwrap(),
+    let info = message_info(&owner, &[]);
+    execute_schedule_rate_update(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        None,
+        Uint128::from(20_000u128),
+        Uint128::from(1_000_000u128),
+        effective_at,
     )
     .unwrap();
 
-    let expected_gross = Uint128::from(100_000_000_000u128);
-    let expected_fee = Uint128::from(500_000_000u128); // 0.5%
-    let expected_net = expected_gross - expected_fee;
+    let rando = a(&deps, "rando");
+    let err = execute_cancel_scheduled_rate(deps.as_mut(), mock_env(), message_info(&rando, &[]))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
 
-    assert_eq!(res.token_amount, expected_net);
-    assert_eq!(res.fee_amount, expected_fee);
+    execute_cancel_scheduled_rate(deps.as_mut(), mock_env(), message_info(&owner, &[])).unwrap();
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(86_400);
+    let err =
+        execute_apply_scheduled_rate(deps.as_mut(), env, message_info(&owner, &[])).unwrap_err();
+    assert_eq!(err, ContractError::NoRateUpdatePending);
 }
 
-// ─── Treasury Management ────────────────────────────────────────────────────
+/// Price feed contract's Price response
+#[cosmwasm_schema::cw_serde]
+struct FeedPrice {
+    price: Decimal,
+    updated_at: u64,
+}
+
+fn set_feed_price(deps: &mut TestDeps, price: &str, updated_at: u64) {
+    let price = Decimal::from_str(price).unwrap();
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { msg, .. } => {
+            let PriceFeedQueryMsg::Price { denom } = from_json(msg).unwrap();
+            assert_eq!(denom, DENOM);
+            let res = to_json_binary(&FeedPrice { price, updated_at });
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        }
+        _ => SystemResult::Err(SystemError::Unknown {}),
+    });
+}
 
 #[test]
-fn test_withdraw_treasury_respects_reserve() {
-    let (mut deps, _sk, _contract_addr) = setup_with_funded_treasury();
+fn test_price_feed_rate() {
+    let (mut deps, _sk) = setup();
     let owner = a(&deps, "owner");
+    let feed_contract = a(&deps, "price_feed");
+    let now = mock_env().block.time.seconds();
 
-    // Contract has 100_000_000 ushido, min_reserve is 1_000_000
-    // Try to withdraw too much
-    let info = message_info(&owner, &[]);
-    let err = execute_withdraw_treasury(
+    let feed = |max_staleness_seconds, max_deviation_bps| PriceFeedMsg {
+        contract: feed_contract.to_string(),
+        max_staleness_seconds,
+        max_deviation_bps,
+    };
+    let rate = |deps: &TestDeps| -> ExchangeRateResponse {
+        from_json(query_exchange_rate(deps.as_ref(), mock_env(), None).unwrap()).unwrap()
+    };
+    let quote = |deps: &TestDeps| -> Uint128 {
+        let res: ConversionResponse = from_json(
+            query_convert_credits_to_tokens(
+                deps.as_ref(),
+                mock_env(),
+                Uint128::from(10_000u128),
+                None,
+                None,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        res.token_amount + res.fee_amount
+    };
+
+    // Static rate: 100 ushido per credit
+    assert_eq!(rate(&deps).source, RateSource::Static);
+    assert_eq!(quote(&deps), Uint128::from(1_000_000u128));
+
+    let rando = a(&deps, "rando");
+    let err = execute_set_price_feed(
         deps.as_mut(),
         mock_env(),
-        info,
-        Uint128::from(99_500_000u128), // would leave only 500k, below 1M reserve
+        message_info(&rando, &[]),
+        Some(feed(600, 2_000)),
     )
     .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+    for (staleness, deviation) in [(0, 2_000), (86_401, 2_000), (600, 0), (600, 10_000)] {
+        let err = execute_set_price_feed(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            Some(feed(staleness, deviation)),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidPriceFeed {
+                max_staleness: 86_400
+            }
+        );
+    }
+
+    // A fresh reading within 20% of the static rate is used as is
+    set_feed_price(&mut deps, "110", now - 60);
+    execute_set_price_feed(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Some(feed(600, 2_000)),
+    )
+    .unwrap();
+    let current = rate(&deps);
+    assert_eq!(current.source, RateSource::Feed);
+    assert_eq!(current.feed_updated_at, Some(now - 60));
+    let history: ConfigHistoryResponse =
+        from_json(query_config_history(deps.as_ref(), Some(1), None).unwrap()).unwrap();
+    let switch = history.changes.last().unwrap();
+    assert_eq!(switch.action, "set_price_feed");
+    assert_eq!(switch.price_feed.as_ref().unwrap().max_deviation_bps, 2_000);
+    assert_eq!(quote(&deps), Uint128::from(1_100_000u128));
+    let res: ConversionResponse = from_json(
+        query_convert_tokens_to_credits(
+            deps.as_ref(),
+            mock_env(),
+            Uint128::from(1_100_000u128),
+            None,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.credit_amount, Uint128::from(10_000u128));
 
-    assert!(matches!(err, ContractError::ReserveBreached { .. }));
-
-    // Withdraw an allowed amount
-    let info = message_info(&owner, &[]);
-    execute_withdraw_treasury(
+    // Deposits convert at the feed rate too
+    let player = a(&deps, "player");
+    let res = execute_deposit(
         deps.as_mut(),
         mock_env(),
-        info,
-        Uint128::from(99_000_000u128), // leaves exactly 1M
+        message_info(&player, &[Coin::new(1_100_000u128, DENOM)]),
+        None,
     )
     .unwrap();
+    assert_eq!(
+        res.events,
+        vec![Event::from(DepositEvent {
+            sender: player.to_string(),
+            denom: DENOM.to_string(),
+            token_amount: "1100000".to_string(),
+            credit_amount: "10000".to_string(),
+        })]
+    );
+
+    // Readings outside the band are clamped to it
+    set_feed_price(&mut deps, "200", now);
+    assert_eq!(rate(&deps).source, RateSource::FeedClamped);
+    assert_eq!(quote(&deps), Uint128::from(1_200_000u128));
+    set_feed_price(&mut deps, "10", now);
+    assert_eq!(quote(&deps), Uint128::from(800_000u128));
+
+    // Stale readings fall back to the static rate
+    set_feed_price(&mut deps, "110", now - 601);
+    assert_eq!(rate(&deps).source, RateSource::Static);
+    assert_eq!(quote(&deps), Uint128::from(1_000_000u128));
+
+    // So does a feed that stops answering
+    deps.querier
+        .update_wasm(|_| SystemResult::Err(SystemError::Unknown {}));
+    assert_eq!(rate(&deps).source, RateSource::Static);
+
+    execute_set_price_feed(deps.as_mut(), mock_env(), message_info(&owner, &[]), None).unwrap();
+    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
+    assert!(config.price_feed.is_none());
 }
 
 #[test]
-fn test_non_owner_cannot_withdraw_treasury() {
-    let (mut deps, _sk, _contract_addr) = setup_with_funded_treasury();
-    let rando = a(&deps, "rando");
+fn test_price_feed_withdrawal_after_feed_moves() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
+    let owner = a(&deps, "owner");
+    let player = a(&deps, "player1");
+    let feed_contract = a(&deps, "price_feed");
+    let now = mock_env().block.time.seconds();
 
-    let info = message_info(&rando, &[]);
-    let err = execute_withdraw_treasury(
+    set_feed_price(&mut deps, "100", now);
+    execute_set_price_feed(
         deps.as_mut(),
         mock_env(),
-        info,
-        Uint128::from(1_000u128),
+        message_info(&owner, &[]),
+        Some(PriceFeedMsg {
+            contract: feed_contract.to_string(),
+            max_staleness_seconds: 600,
+            max_deviation_bps: 2_000,
+        }),
     )
-    .unwrap_err();
+    .unwrap();
+
+    // Signed at 100 ushido per credit: 500_000 less the 0.5% fee
+    let credit_amount = Uint128::from(5_000u128);
+    let token_amount = Uint128::from(497_500u128);
+    let sig = sign_withdrawal(
+        &sk,
+        CHAIN_ID,
+        &contract_addr,
+        &ts_nonce(1),
+        player.as_str(),
+        credit_amount,
+        token_amount,
+    );
+    let withdraw = |deps: &mut TestDeps| {
+        execute_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&player, &[]),
+            ts_nonce(1),
+            credit_amount,
+            token_amount,
+            sig.clone(),
+            None,
+            None,
+            None,
+            None,
+        )
+    };
 
+    // The feed falling below the signed amount rejects the withdrawal
+    set_feed_price(&mut deps, "98", now);
+    let err = withdraw(&mut deps).unwrap_err();
     assert_eq!(
         err,
-        ContractError::Unauthorized {
-            role: "owner".to_string()
+        ContractError::AmountMismatch {
+            credits: "5000".to_string(),
+            expected_tokens: "487550".to_string(),
+            provided_tokens: "497500".to_string(),
         }
     );
-}
 
-// ─── Oracle Two-Step Transfer ───────────────────────────────────────────────
+    // A feed that rose since signing pays out at the current rate
+    set_feed_price(&mut deps, "102", now);
+    let res = withdraw(&mut deps).unwrap();
+    assert_eq!(
+        res.events,
+        vec![Event::from(WithdrawEvent {
+            recipient: player.to_string(),
+            denom: DENOM.to_string(),
+            token_amount: "507450".to_string(),
+            credit_amount: "5000".to_string(),
+            fee_amount: "2550".to_string(),
+        })]
+    );
+}
 
 #[test]
-fn test_oracle_transfer() {
+fn test_update_limits() {
     let (mut deps, _sk) = setup();
     let owner = a(&deps, "owner");
-    let new_oracle = a(&deps, "new_oracle");
-    let new_pubkey = Binary::from(vec![0x02; 33]); // dummy compressed pubkey
 
     let info = message_info(&owner, &[]);
-    execute_propose_oracle(
+    execute_update_limits(
         deps.as_mut(),
         mock_env(),
         info,
-        new_oracle.to_string(),
-        new_pubkey.clone(),
+        Some(Uint128::from(200_000u128)),
+        None,
+        Some(1800),
+        None,
+        None,
+        None,
+        None,
     )
     .unwrap();
 
-    let pending: Option<sysbreak_credit_bridge::state::PendingOracleTransfer> =
-        from_json(query_pending_oracle(deps.as_ref()).unwrap()).unwrap();
-    assert!(pending.is_some());
-
-    let info = message_info(&new_oracle, &[]);
-    execute_accept_oracle(deps.as_mut(), mock_env(), info).unwrap();
-
     let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
-    assert_eq!(config.oracle, new_oracle);
-    assert_eq!(config.oracle_pubkey, new_pubkey);
+    assert_eq!(config.player_daily_limit, Uint128::from(200_000u128));
+    assert_eq!(config.cooldown_seconds, 1800);
+    // Unchanged values
+    assert_eq!(config.global_daily_limit, Uint128::from(10_000_000u128));
 }
 
 #[test]
-fn test_wrong_address_cannot_accept_oracle() {
+fn test_migrate_folds_withdrawal_records_into_buckets() {
     let (mut deps, _sk) = setup();
-    let owner = a(&deps, "owner");
-    let new_oracle = a(&deps, "new_oracle");
-    let rando = a(&deps, "rando");
+    let player = a(&deps, "player1");
+    let now = mock_env().block.time;
 
-    let info = message_info(&owner, &[]);
-    execute_propose_oracle(
-        deps.as_mut(),
-        mock_env(),
-        info,
-        new_oracle.to_string(),
-        Binary::from(vec![0x02; 33]),
-    )
-    .unwrap();
+    let recent = WithdrawalRecord {
+        amount_credits: Uint128::from(30_000u128),
+        timestamp: now.minus_seconds(2 * 3600),
+        denom: None,
+    };
+    let expired = WithdrawalRecord {
+        amount_credits: Uint128::from(50_000u128),
+        timestamp: now.minus_seconds(2 * 86_400),
+        denom: None,
+    };
+    PLAYER_WITHDRAWALS
+        .save(
+            deps.as_mut().storage,
+            &player,
+            &vec![expired.clone(), recent.clone()],
+        )
+        .unwrap();
+    GLOBAL_WITHDRAWAL_RECORDS
+        .save(deps.as_mut().storage, 1, &expired)
+        .unwrap();
+    GLOBAL_WITHDRAWAL_RECORDS
+        .save(deps.as_mut().storage, 2, &recent)
+        .unwrap();
+    GLOBAL_WD_COUNTER.save(deps.as_mut().storage, &2).unwrap();
+    // A bucket written to the old 24-slot ring
+    let hour = bucket_of(now.minus_seconds(5 * 3600));
+    PLAYER_WD_BUCKETS
+        .save(
+            deps.as_mut().storage,
+            (&player, DENOM, hour % 24),
+            &WithdrawalBucket {
+                hour,
+                amount_credits: Uint128::from(7_000u128),
+            },
+        )
+        .unwrap();
 
-    let info = message_info(&rando, &[]);
-    let err = execute_accept_oracle(deps.as_mut(), mock_env(), info).unwrap_err();
-    assert_eq!(err, ContractError::NotPendingOracle);
-}
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
 
-// ─── Pause ──────────────────────────────────────────────────────────────────
+    assert!(PLAYER_WITHDRAWALS
+        .may_load(deps.as_ref().storage, &player)
+        .unwrap()
+        .is_none());
+    assert!(GLOBAL_WITHDRAWAL_RECORDS
+        .may_load(deps.as_ref().storage, 2)
+        .unwrap()
+        .is_none());
+
+    let info: PlayerInfoResponse =
+        from_json(query_player_info(deps.as_ref(), mock_env(), player.to_string(), None).unwrap())
+            .unwrap();
+    assert_eq!(info.withdrawals_24h, Uint128::from(37_000u128));
+    assert_eq!(
+        global_window_usage(deps.as_ref().storage, DENOM, now, 86_400).unwrap(),
+        Uint128::from(30_000u128)
+    );
+
+    // The migrated bucket ages out with the rest of the window
+    let mut env = mock_env();
+    env.block.time = now.plus_seconds(23 * 3600);
+    let info: PlayerInfoResponse =
+        from_json(query_player_info(deps.as_ref(), env, player.to_string(), None).unwrap())
+            .unwrap();
+    assert_eq!(info.withdrawals_24h, Uint128::zero());
+}
 
 #[test]
-fn test_pause_blocks_deposits_and_withdrawals() {
+fn test_limit_window_configurable() {
     let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
     let owner = a(&deps, "owner");
     let player = a(&deps, "player1");
+    let update_window = |deps: &mut TestDeps, seconds: u64| {
+        execute_update_limits(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(seconds),
+        )
+    };
+    let player_info = |deps: &TestDeps, env| -> PlayerInfoResponse {
+        from_json(query_player_info(deps.as_ref(), env, player.to_string(), None).unwrap()).unwrap()
+    };
 
-    // Pause
-    let info = message_info(&owner, &[]);
-    execute_pause(deps.as_mut(), mock_env(), info).unwrap();
-
-    // Deposit fails
-    let info = message_info(&player, &[Coin::new(1_000_000u128, DENOM)]);
-    let err = execute_deposit(deps.as_mut(), mock_env(), info).unwrap_err();
-    assert_eq!(err, ContractError::Paused);
+    for bad in [0, 1_800, 5_400, 8 * 86_400] {
+        let err = update_window(&mut deps, bad).unwrap_err();
+        assert_eq!(err, ContractError::InvalidLimitWindow { value: bad });
+    }
+    update_window(&mut deps, 2 * 3600).unwrap();
 
-    // Withdrawal fails
-    let credit_amount = Uint128::from(1_000u128);
-    let token_amount = Uint128::from(99_500u128);
+    let credit_amount = Uint128::from(5_000u128);
+    let token_amount = Uint128::from(497_500u128);
     let sig = sign_withdrawal(
         &sk,
         CHAIN_ID,
         &contract_addr,
-        &ts_nonce("paused"),
+        &ts_nonce(26),
         player.as_str(),
         credit_amount,
         token_amount,
     );
-    let info = message_info(&player, &[]);
-    let err = execute_withdraw(
+    execute_withdraw(
         deps.as_mut(),
         mock_env(),
-        info,
-        ts_nonce("paused"),
+        message_info(&player, &[]),
+        ts_nonce(26),
         credit_amount,
         token_amount,
         sig,
+        None,
+        None,
+        None,
+        None,
     )
-    .unwrap_err();
-    assert_eq!(err, ContractError::Paused);
+    .unwrap();
 
-    // Unpause
-    let info = message_info(&owner, &[]);
-    execute_unpause(deps.as_mut(), mock_env(), info).unwrap();
-}
+    let info = player_info(&deps, mock_env());
+    assert_eq!(info.withdrawals_24h, credit_amount);
+    assert_eq!(info.limit_window_seconds, 7_200);
 
-// ─── Admin Updates ──────────────────────────────────────────────────────────
+    // Out of a 2h window after two hours
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(2 * 3600);
+    assert_eq!(
+        player_info(&deps, later.clone()).withdrawals_24h,
+        Uint128::zero()
+    );
+
+    // Widening the window counts the withdrawal again
+    update_window(&mut deps, 3 * 86_400).unwrap();
+    assert_eq!(player_info(&deps, later).withdrawals_24h, credit_amount);
+}
 
 #[test]
-fn test_update_rate() {
-    let (mut deps, _sk) = setup();
+fn test_lifetime_caps_and_kyc() {
+    let (mut deps, sk, contract_addr) = setup_with_funded_treasury();
     let owner = a(&deps, "owner");
+    let oracle = a(&deps, "oracle");
+    let player = a(&deps, "player1");
+    let credit_amount = Uint128::from(5_000u128);
+    let token_amount = Uint128::from(497_500u128);
+    let withdraw = |deps: &mut TestDeps, label: &str, hours: u64| {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(hours * 3600);
+        let nonce = format!("seq:{}:{}", env.block.time.seconds() / 3600, label);
+        let sig = sign_withdrawal(
+            &sk,
+            CHAIN_ID,
+            &contract_addr,
+            &nonce,
+            player.as_str(),
+            credit_amount,
+            token_amount,
+        );
+        execute_withdraw(
+            deps.as_mut(),
+            env,
+            message_info(&player, &[]),
+            nonce,
+            credit_amount,
+            token_amount,
+            sig,
+            None,
+            None,
+            None,
+            None,
+        )
+    };
 
-    let info = message_info(&owner, &[]);
-    execute_update_rate(
+    // KYC may not lower the cap
+    let err = execute_set_lifetime_caps(
         deps.as_mut(),
         mock_env(),
-        info,
-        Uint128::from(20_000u128),
-        Uint128::from(1_000_000u128),
+        message_info(&owner, &[]),
+        None,
+        Some(Uint128::from(20_000u128)),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidLifetimeCaps);
+    execute_set_lifetime_caps(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Some(Uint128::from(8_000u128)),
+        Some(Uint128::from(10_000u128)),
     )
     .unwrap();
 
-    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
-    assert_eq!(config.rate_credits, Uint128::from(20_000u128));
-}
-
-#[test]
-fn test_update_limits() {
-    let (mut deps, _sk) = setup();
-    let owner = a(&deps, "owner");
+    withdraw(&mut deps, "1", 0).unwrap();
+    let err = withdraw(&mut deps, "2", 2).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::LifetimeCapExceeded {
+            used: "5000".to_string(),
+            requested: "5000".to_string(),
+            cap: "8000".to_string(),
+        }
+    );
 
-    let info = message_info(&owner, &[]);
-    execute_update_limits(
+    // Only the owner or oracle can verify
+    let err = execute_set_kyc_verified(
         deps.as_mut(),
         mock_env(),
-        info,
-        Some(Uint128::from(200_000u128)),
-        None,
-        Some(1800),
-        None,
-        None,
+        message_info(&player, &[]),
+        player.to_string(),
+        true,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized { .. }));
+    execute_set_kyc_verified(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&oracle, &[]),
+        player.to_string(),
+        true,
     )
     .unwrap();
 
-    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
-    assert_eq!(config.player_daily_limit, Uint128::from(200_000u128));
-    assert_eq!(config.cooldown_seconds, 1800);
-    // Unchanged values
-    assert_eq!(config.global_daily_limit, Uint128::from(10_000_000u128));
+    withdraw(&mut deps, "2", 2).unwrap();
+    let info: PlayerInfoResponse =
+        from_json(query_player_info(deps.as_ref(), mock_env(), player.to_string(), None).unwrap())
+            .unwrap();
+    assert!(info.kyc_verified);
+    assert_eq!(info.lifetime_withdrawn, Uint128::from(10_000u128));
+    assert_eq!(info.lifetime_cap, Some(Uint128::from(10_000u128)));
+
+    // Daily limits reset, the lifetime total does not
+    let err = withdraw(&mut deps, "3", 48).unwrap_err();
+    assert!(matches!(err, ContractError::LifetimeCapExceeded { .. }));
 }
 
 // ─── Player Info Query ──────────────────────────────────────────────────────
@@ -839,10 +4138,9 @@ fn test_player_info_query() {
     let player = a(&deps, "player1");
 
     // Before any withdrawal
-    let res: PlayerInfoResponse = from_json(
-        query_player_info(deps.as_ref(), mock_env(), player.to_string()).unwrap(),
-    )
-    .unwrap();
+    let res: PlayerInfoResponse =
+        from_json(query_player_info(deps.as_ref(), mock_env(), player.to_string(), None).unwrap())
+            .unwrap();
     assert_eq!(res.withdrawals_24h, Uint128::zero());
     assert_eq!(res.remaining_limit, Uint128::from(100_000u128));
 
@@ -853,7 +4151,7 @@ fn test_player_info_query() {
         &sk,
         CHAIN_ID,
         &contract_addr,
-        &ts_nonce("info"),
+        &ts_nonce(27),
         player.as_str(),
         credit_amount,
         token_amount,
@@ -863,17 +4161,112 @@ fn test_player_info_query() {
         deps.as_mut(),
         mock_env(),
         info,
-        ts_nonce("info"),
+        ts_nonce(27),
         credit_amount,
         token_amount,
         sig,
+        None,
+        None,
+        None,
+        None,
     )
     .unwrap();
 
-    let res: PlayerInfoResponse = from_json(
-        query_player_info(deps.as_ref(), mock_env(), player.to_string()).unwrap(),
-    )
-    .unwrap();
+    let res: PlayerInfoResponse =
+        from_json(query_player_info(deps.as_ref(), mock_env(), player.to_string(), None).unwrap())
+            .unwrap();
     assert_eq!(res.withdrawals_24h, Uint128::from(5_000u128));
     assert_eq!(res.remaining_limit, Uint128::from(95_000u128));
 }
+
+#[test]
+fn test_config_history() {
+    let (mut deps, _sk) = setup();
+    let owner = a(&deps, "owner");
+    let mut env = mock_env();
+
+    env.block.time = env.block.time.plus_seconds(100);
+    execute_update_fee(deps.as_mut(), env.clone(), message_info(&owner, &[]), 75).unwrap();
+    execute_set_denom_config(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        GEM_DENOM.to_string(),
+        gem_config(),
+    )
+    .unwrap();
+    env.block.time = env.block.time.plus_seconds(100);
+    execute_update_limits(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        None,
+        None,
+        Some(1800),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    execute_remove_denom(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        GEM_DENOM.to_string(),
+    )
+    .unwrap();
+    execute_set_referral_bps(deps.as_mut(), env.clone(), message_info(&owner, &[]), 1_000).unwrap();
+    let discount = AchievementDiscountMsg {
+        achievement_contract: a(&deps, "achievements").to_string(),
+        achievement_id: "veteran".to_string(),
+        discount_bps: 3_000,
+    };
+    execute_set_achievement_discount(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        Some(discount),
+    )
+    .unwrap();
+
+    let page: ConfigHistoryResponse =
+        from_json(query_config_history(deps.as_ref(), None, Some(3)).unwrap()).unwrap();
+    let actions: Vec<_> = page.changes.iter().map(|c| c.action.as_str()).collect();
+    assert_eq!(actions, ["instantiate", "update_fee", "set_denom_config"]);
+    let initial = &page.changes[0];
+    assert_eq!(initial.changed_by, owner);
+    assert_eq!(initial.params.as_ref().unwrap().fee_bps, 50);
+    let fee_change = &page.changes[1];
+    assert_eq!(
+        fee_change.changed_at,
+        mock_env().block.time.plus_seconds(100)
+    );
+    assert_eq!(fee_change.params.as_ref().unwrap().fee_bps, 75);
+    assert_eq!(page.changes[2].denom, GEM_DENOM);
+    assert_eq!(page.changes[2].params, Some(gem_config()));
+
+    let page: ConfigHistoryResponse =
+        from_json(query_config_history(deps.as_ref(), Some(3), None).unwrap()).unwrap();
+    assert_eq!(page.changes.len(), 4);
+    let limits = &page.changes[0];
+    assert_eq!((limits.id, limits.denom.as_str()), (4, DENOM));
+    assert_eq!(limits.cooldown_seconds, 1800);
+    assert_eq!(limits.params.as_ref().unwrap().fee_bps, 75);
+    // A removed denom is recorded without parameters
+    assert_eq!(page.changes[1].denom, GEM_DENOM);
+    assert_eq!(page.changes[1].params, None);
+
+    // Fee adjustments outside the denom parameters are recorded too
+    let referral = &page.changes[2];
+    assert_eq!(referral.action, "set_referral_bps");
+    assert_eq!(referral.referral_bps, 1_000);
+    assert_eq!(referral.achievement_discount, None);
+    let discount = &page.changes[3];
+    assert_eq!(discount.action, "set_achievement_discount");
+    assert_eq!(discount.referral_bps, 1_000);
+    assert_eq!(
+        discount.achievement_discount.as_ref().unwrap().discount_bps,
+        3_000
+    );
+}
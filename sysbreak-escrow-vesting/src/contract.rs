@@ -0,0 +1,262 @@
+use cosmwasm_std::{
+    to_json_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdResult,
+};
+use cw2::set_contract_version;
+use sysbreak_common::reject_funds;
+use sysbreak_events::ClaimEvent;
+
+use crate::error::ContractError;
+use crate::helpers::{assert_owner, validate_schedule, vested_amount};
+use crate::msg::{InstantiateMsg, MigrateMsg, VestingInfo, VestingsResponse};
+use crate::state::{
+    Config, PendingOwnerTransfer, Vesting, VestingSchedule, BENEFICIARY_VESTINGS, CONFIG,
+    LAST_VESTING_ID, OWNER_TRANSFER, VESTINGS,
+};
+
+const CONTRACT_NAME: &str = "crates.io:sysbreak-escrow-vesting";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+const DEFAULT_QUERY_LIMIT: u32 = 30;
+const MAX_QUERY_LIMIT: u32 = 100;
+
+// ─── Instantiate ────────────────────────────────────────────────────────────
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        owner: deps.api.addr_validate(&msg.owner)?,
+        denom: msg.denom,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("contract", CONTRACT_NAME)
+        .add_attribute("owner", config.owner.as_str())
+        .add_attribute("denom", &config.denom))
+}
+
+// ─── Vesting ────────────────────────────────────────────────────────────────
+
+/// Escrow the attached funds under a new schedule. The funds stay in this
+/// contract until claimed or revoked.
+pub fn execute_create_vesting(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    beneficiary: String,
+    schedule: VestingSchedule,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.as_ref(), &info.sender)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let total = match info.funds.as_slice() {
+        [coin] if coin.denom == config.denom && !coin.amount.is_zero() => coin.amount,
+        _ => {
+            return Err(ContractError::IncorrectPayment {
+                denom: config.denom,
+            })
+        }
+    };
+    validate_schedule(&schedule, env.block.time)?;
+    let beneficiary = deps.api.addr_validate(&beneficiary)?;
+
+    let vesting_id = LAST_VESTING_ID.may_load(deps.storage)?.unwrap_or_default() + 1;
+    LAST_VESTING_ID.save(deps.storage, &vesting_id)?;
+    VESTINGS.save(
+        deps.storage,
+        vesting_id,
+        &Vesting {
+            beneficiary: beneficiary.clone(),
+            total,
+            claimed: Default::default(),
+            schedule,
+            revoked_at: None,
+        },
+    )?;
+    BENEFICIARY_VESTINGS.save(deps.storage, (&beneficiary, vesting_id), &true)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_vesting")
+        .add_attribute("vesting_id", vesting_id.to_string())
+        .add_attribute("beneficiary", beneficiary.as_str())
+        .add_attribute("total", total.to_string()))
+}
+
+pub fn execute_claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vesting_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut vesting = load_vesting(deps.as_ref(), vesting_id)?;
+    if info.sender != vesting.beneficiary {
+        return Err(ContractError::Unauthorized {
+            role: "beneficiary".to_string(),
+        });
+    }
+    let amount = vested_amount(&vesting, env.block.time) - vesting.claimed;
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim);
+    }
+    vesting.claimed += amount;
+    VESTINGS.save(deps.storage, vesting_id, &vesting)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: vesting.beneficiary.to_string(),
+            amount: vec![Coin::new(amount, &config.denom)],
+        })
+        .add_attribute("action", "claim")
+        .add_attribute("vesting_id", vesting_id.to_string())
+        .add_event(ClaimEvent {
+            recipient: vesting.beneficiary.to_string(),
+            amount: amount.to_string(),
+            denom: config.denom,
+        }))
+}
+
+/// Cut a vesting down to what has vested so far and return the rest to the
+/// owner. The beneficiary can still claim the vested remainder.
+pub fn execute_revoke_vesting(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vesting_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut vesting = load_vesting(deps.as_ref(), vesting_id)?;
+    if vesting.revoked_at.is_some() {
+        return Err(ContractError::AlreadyRevoked { vesting_id });
+    }
+    let vested = vested_amount(&vesting, env.block.time);
+    if vested == vesting.total {
+        return Err(ContractError::FullyVested { vesting_id });
+    }
+    let refund = vesting.total - vested;
+    vesting.total = vested;
+    vesting.revoked_at = Some(env.block.time);
+    VESTINGS.save(deps.storage, vesting_id, &vesting)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin::new(refund, &config.denom)],
+        })
+        .add_attribute("action", "revoke_vesting")
+        .add_attribute("vesting_id", vesting_id.to_string())
+        .add_attribute("vested", vested.to_string())
+        .add_attribute("refunded", refund.to_string()))
+}
+
+fn load_vesting(deps: Deps, vesting_id: u64) -> Result<Vesting, ContractError> {
+    VESTINGS
+        .may_load(deps.storage, vesting_id)?
+        .ok_or(ContractError::VestingNotFound { vesting_id })
+}
+
+// ─── Admin ──────────────────────────────────────────────────────────────────
+
+pub fn execute_propose_owner(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    new_owner: String,
+) -> Result<Response, ContractError> {
+    let proposed_owner = deps.api.addr_validate(&new_owner)?;
+    Ok(OWNER_TRANSFER.propose(
+        deps,
+        &info,
+        &CONFIG,
+        PendingOwnerTransfer { proposed_owner },
+    )?)
+}
+
+pub fn execute_accept_owner(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(OWNER_TRANSFER.accept(deps, &info, &CONFIG)?)
+}
+
+pub fn execute_cancel_owner_transfer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(OWNER_TRANSFER.cancel(deps, &info, &CONFIG)?)
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+pub fn query_config(deps: Deps) -> StdResult<Binary> {
+    to_json_binary(&CONFIG.load(deps.storage)?)
+}
+
+fn vesting_info(vesting_id: u64, vesting: Vesting, env: &Env) -> VestingInfo {
+    let vested = vested_amount(&vesting, env.block.time);
+    VestingInfo {
+        vesting_id,
+        claimable: vested - vesting.claimed,
+        vested,
+        vesting,
+    }
+}
+
+pub fn query_vesting(deps: Deps, env: Env, vesting_id: u64) -> StdResult<Binary> {
+    let info = VESTINGS
+        .may_load(deps.storage, vesting_id)?
+        .map(|vesting| vesting_info(vesting_id, vesting, &env));
+    to_json_binary(&info)
+}
+
+pub fn query_vestings(
+    deps: Deps,
+    env: Env,
+    beneficiary: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let beneficiary = deps.api.addr_validate(&beneficiary)?;
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.map(cw_storage_plus::Bound::exclusive);
+
+    let vestings: Vec<VestingInfo> = BENEFICIARY_VESTINGS
+        .prefix(&beneficiary)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|vesting_id| {
+            let vesting_id = vesting_id?;
+            let vesting = VESTINGS.load(deps.storage, vesting_id)?;
+            Ok(vesting_info(vesting_id, vesting, &env))
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&VestingsResponse { vestings })
+}
+
+pub fn query_pending_owner(deps: Deps) -> StdResult<Binary> {
+    OWNER_TRANSFER.query_pending(deps)
+}
+
+// ─── Migrate ────────────────────────────────────────────────────────────────
+
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("version", CONTRACT_VERSION))
+}
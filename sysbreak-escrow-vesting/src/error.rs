@@ -0,0 +1,33 @@
+use cosmwasm_std::StdError;
+use sysbreak_common::AdminError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Admin(#[from] AdminError),
+
+    #[error("unauthorized: only {role} can perform this action")]
+    Unauthorized { role: String },
+
+    #[error("vesting not found: {vesting_id}")]
+    VestingNotFound { vesting_id: u64 },
+
+    #[error("invalid vesting schedule: {reason}")]
+    InvalidSchedule { reason: String },
+
+    #[error("payment must be a single non-zero {denom} coin")]
+    IncorrectPayment { denom: String },
+
+    #[error("nothing to claim yet")]
+    NothingToClaim,
+
+    #[error("vesting {vesting_id} is already revoked")]
+    AlreadyRevoked { vesting_id: u64 },
+
+    #[error("vesting {vesting_id} is fully vested and cannot be revoked")]
+    FullyVested { vesting_id: u64 },
+}
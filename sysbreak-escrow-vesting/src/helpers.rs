@@ -0,0 +1,75 @@
+use cosmwasm_std::{Addr, Deps, Timestamp, Uint128};
+
+use crate::error::ContractError;
+use crate::state::{Vesting, VestingSchedule, CONFIG};
+
+/// Verify the caller is the contract owner.
+pub fn assert_owner(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    Ok(sysbreak_common::assert_owner(deps, &CONFIG, sender)?)
+}
+
+/// Reject schedules that could never vest or vest backwards.
+pub fn validate_schedule(schedule: &VestingSchedule, now: Timestamp) -> Result<(), ContractError> {
+    let invalid = |reason: &str| {
+        Err(ContractError::InvalidSchedule {
+            reason: reason.to_string(),
+        })
+    };
+    match schedule {
+        VestingSchedule::Linear {
+            start_time,
+            end_time,
+            cliff_time,
+        } => {
+            if end_time <= start_time {
+                return invalid("end_time must be after start_time");
+            }
+            if *end_time <= now {
+                return invalid("end_time must be in the future");
+            }
+            if let Some(cliff) = cliff_time {
+                if cliff < start_time || cliff > end_time {
+                    return invalid("cliff_time must be between start_time and end_time");
+                }
+            }
+        }
+        VestingSchedule::Cliff { release_time } => {
+            if *release_time <= now {
+                return invalid("release_time must be in the future");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Amount of `vesting.total` vested at `now`, including claimed funds.
+pub fn vested_amount(vesting: &Vesting, now: Timestamp) -> Uint128 {
+    if vesting.revoked_at.is_some() {
+        return vesting.total;
+    }
+    match &vesting.schedule {
+        VestingSchedule::Linear {
+            start_time,
+            end_time,
+            cliff_time,
+        } => {
+            if now < cliff_time.unwrap_or(*start_time) || now <= *start_time {
+                Uint128::zero()
+            } else if now >= *end_time {
+                vesting.total
+            } else {
+                vesting.total.multiply_ratio(
+                    now.seconds() - start_time.seconds(),
+                    end_time.seconds() - start_time.seconds(),
+                )
+            }
+        }
+        VestingSchedule::Cliff { release_time } => {
+            if now >= *release_time {
+                vesting.total
+            } else {
+                Uint128::zero()
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+pub mod contract;
+pub mod error;
+pub mod helpers;
+pub mod msg;
+pub mod state;
+
+// Entry points are only referenced by the wasm export shims, which are not
+// generated for native targets.
+#[cfg(not(feature = "library"))]
+#[allow(dead_code)]
+mod entry {
+    use super::*;
+    use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+    use msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+    #[entry_point]
+    pub fn instantiate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response, error::ContractError> {
+        contract::instantiate(deps, env, info, msg)
+    }
+
+    #[entry_point]
+    pub fn execute(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, error::ContractError> {
+        match msg {
+            ExecuteMsg::CreateVesting {
+                beneficiary,
+                schedule,
+            } => contract::execute_create_vesting(deps, env, info, beneficiary, schedule),
+            ExecuteMsg::Claim { vesting_id } => {
+                contract::execute_claim(deps, env, info, vesting_id)
+            }
+            ExecuteMsg::RevokeVesting { vesting_id } => {
+                contract::execute_revoke_vesting(deps, env, info, vesting_id)
+            }
+            ExecuteMsg::ProposeOwner { new_owner } => {
+                contract::execute_propose_owner(deps, env, info, new_owner)
+            }
+            ExecuteMsg::AcceptOwner {} => contract::execute_accept_owner(deps, env, info),
+            ExecuteMsg::CancelOwnerTransfer {} => {
+                contract::execute_cancel_owner_transfer(deps, env, info)
+            }
+        }
+    }
+
+    #[entry_point]
+    pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> cosmwasm_std::StdResult<Binary> {
+        match msg {
+            QueryMsg::Config {} => contract::query_config(deps),
+            QueryMsg::Vesting { vesting_id } => contract::query_vesting(deps, env, vesting_id),
+            QueryMsg::Vestings {
+                beneficiary,
+                start_after,
+                limit,
+            } => contract::query_vestings(deps, env, beneficiary, start_after, limit),
+            QueryMsg::PendingOwner {} => contract::query_pending_owner(deps),
+        }
+    }
+
+    #[entry_point]
+    pub fn migrate(
+        deps: DepsMut,
+        env: Env,
+        msg: MigrateMsg,
+    ) -> Result<Response, error::ContractError> {
+        contract::migrate(deps, env, msg)
+    }
+}
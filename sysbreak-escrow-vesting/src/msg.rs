@@ -0,0 +1,73 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+
+use crate::state::{Vesting, VestingSchedule};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Contract owner address
+    pub owner: String,
+    /// Native denom being vested
+    pub denom: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Escrow the attached funds for `beneficiary` under `schedule` (owner only)
+    CreateVesting {
+        beneficiary: String,
+        schedule: VestingSchedule,
+    },
+    /// Withdraw everything vested so far (beneficiary only)
+    Claim {
+        vesting_id: u64,
+    },
+    /// Stop a vesting and return the unvested portion to the owner (owner
+    /// only). Already vested funds stay claimable by the beneficiary.
+    RevokeVesting {
+        vesting_id: u64,
+    },
+    ProposeOwner {
+        new_owner: String,
+    },
+    AcceptOwner {},
+    CancelOwnerTransfer {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Get contract configuration
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Get a vesting with its current vested and claimable amounts
+    #[returns(Option<VestingInfo>)]
+    Vesting { vesting_id: u64 },
+    /// List a beneficiary's vestings, paginated by vesting_id
+    #[returns(VestingsResponse)]
+    Vestings {
+        beneficiary: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Option<crate::state::PendingOwnerTransfer>)]
+    PendingOwner {},
+}
+
+#[cw_serde]
+pub struct VestingInfo {
+    pub vesting_id: u64,
+    pub vesting: Vesting,
+    /// Vested as of the current block, including claimed funds
+    pub vested: Uint128,
+    /// vested - claimed
+    pub claimable: Uint128,
+}
+
+#[cw_serde]
+pub struct VestingsResponse {
+    pub vestings: Vec<VestingInfo>,
+}
@@ -0,0 +1,60 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+pub use sysbreak_common::PendingOwnerTransfer;
+use sysbreak_common::{Owned, RoleTransfer};
+
+/// Contract-level configuration
+#[cw_serde]
+pub struct Config {
+    /// Contract owner — funds and revokes vestings
+    pub owner: Addr,
+    /// Native denom being vested (e.g. "ushido")
+    pub denom: String,
+}
+
+impl Owned for Config {
+    fn owner(&self) -> &Addr {
+        &self.owner
+    }
+
+    fn set_owner(&mut self, owner: Addr) {
+        self.owner = owner;
+    }
+}
+
+#[cw_serde]
+pub enum VestingSchedule {
+    /// Vests linearly from `start_time` to `end_time`. Nothing can be claimed
+    /// before `cliff_time`, after which the linear amount so far unlocks at once.
+    Linear {
+        start_time: Timestamp,
+        end_time: Timestamp,
+        cliff_time: Option<Timestamp>,
+    },
+    /// Everything vests at `release_time`
+    Cliff { release_time: Timestamp },
+}
+
+/// An allocation escrowed for a beneficiary
+#[cw_serde]
+pub struct Vesting {
+    pub beneficiary: Addr,
+    /// Amount covered by the schedule; cut to the vested amount on revoke
+    pub total: Uint128,
+    pub claimed: Uint128,
+    pub schedule: VestingSchedule,
+    /// Set by RevokeVesting; `total` is fully vested from then on
+    pub revoked_at: Option<Timestamp>,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const OWNER_TRANSFER: RoleTransfer<PendingOwnerTransfer> =
+    RoleTransfer::new("owner", "pending_owner");
+
+/// vesting_id -> Vesting
+pub const VESTINGS: Map<u64, Vesting> = Map::new("vestings");
+/// Last issued vesting id; ids are never reused
+pub const LAST_VESTING_ID: Item<u64> = Item::new("last_vesting_id");
+/// Per-beneficiary index: (beneficiary, vesting_id) -> bool
+pub const BENEFICIARY_VESTINGS: Map<(&Addr, u64), bool> = Map::new("beneficiary_vestings");
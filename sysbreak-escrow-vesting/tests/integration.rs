@@ -0,0 +1,272 @@
+use cosmwasm_std::testing::{
+    message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{coin, from_json, Addr, BankMsg, CosmosMsg, Env, OwnedDeps, Response, Uint128};
+
+use sysbreak_common::AdminError;
+use sysbreak_escrow_vesting::contract::*;
+use sysbreak_escrow_vesting::error::ContractError;
+use sysbreak_escrow_vesting::msg::*;
+use sysbreak_escrow_vesting::state::VestingSchedule;
+
+type Deps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+const DENOM: &str = "ushido";
+
+fn addr(deps: &Deps, name: &str) -> Addr {
+    deps.api.addr_make(name)
+}
+
+fn setup_contract() -> Deps {
+    let mut deps = mock_dependencies();
+    let owner = deps.api.addr_make("owner");
+    let msg = InstantiateMsg {
+        owner: owner.to_string(),
+        denom: DENOM.to_string(),
+    };
+    instantiate(deps.as_mut(), mock_env(), message_info(&owner, &[]), msg).unwrap();
+    deps
+}
+
+fn env_after(seconds: u64) -> Env {
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(seconds);
+    env
+}
+
+/// 1000 ushido vesting linearly over 1000 seconds from now, with a cliff at 250
+fn create_linear(deps: &mut Deps, beneficiary: &Addr) -> u64 {
+    let owner = addr(deps, "owner");
+    let now = mock_env().block.time;
+    let res = execute_create_vesting(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[coin(1_000, DENOM)]),
+        beneficiary.to_string(),
+        VestingSchedule::Linear {
+            start_time: now,
+            end_time: now.plus_seconds(1_000),
+            cliff_time: Some(now.plus_seconds(250)),
+        },
+    )
+    .unwrap();
+    res.attributes[1].value.parse().unwrap()
+}
+
+fn claim(
+    deps: &mut Deps,
+    env: Env,
+    sender: &Addr,
+    vesting_id: u64,
+) -> Result<Response, ContractError> {
+    execute_claim(deps.as_mut(), env, message_info(sender, &[]), vesting_id)
+}
+
+fn bank_msg(to: &Addr, amount: u128) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: to.to_string(),
+        amount: vec![coin(amount, DENOM)],
+    }
+    .into()
+}
+
+fn messages(res: &Response) -> Vec<CosmosMsg> {
+    res.messages.iter().map(|m| m.msg.clone()).collect()
+}
+
+fn vesting(deps: &Deps, env: Env, vesting_id: u64) -> VestingInfo {
+    let info: Option<VestingInfo> =
+        from_json(query_vesting(deps.as_ref(), env, vesting_id).unwrap()).unwrap();
+    info.unwrap()
+}
+
+// ─── Schedules ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_linear_vesting_with_cliff() {
+    let mut deps = setup_contract();
+    let alice = addr(&deps, "alice");
+    let id = create_linear(&mut deps, &alice);
+
+    let err = claim(&mut deps, env_after(249), &alice, id).unwrap_err();
+    assert_eq!(err, ContractError::NothingToClaim);
+
+    // The linear amount accumulated before the cliff unlocks at once
+    let res = claim(&mut deps, env_after(250), &alice, id).unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&alice, 250)]);
+    let res = claim(&mut deps, env_after(600), &alice, id).unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&alice, 350)]);
+
+    let info = vesting(&deps, env_after(5_000), id);
+    assert_eq!(info.vested, Uint128::new(1_000));
+    assert_eq!(info.claimable, Uint128::new(400));
+    let res = claim(&mut deps, env_after(5_000), &alice, id).unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&alice, 400)]);
+    let err = claim(&mut deps, env_after(6_000), &alice, id).unwrap_err();
+    assert_eq!(err, ContractError::NothingToClaim);
+}
+
+#[test]
+fn test_cliff_vesting_releases_at_once() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let alice = addr(&deps, "alice");
+    execute_create_vesting(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[coin(500, DENOM)]),
+        alice.to_string(),
+        VestingSchedule::Cliff {
+            release_time: mock_env().block.time.plus_seconds(100),
+        },
+    )
+    .unwrap();
+
+    let err = claim(&mut deps, env_after(99), &alice, 1).unwrap_err();
+    assert_eq!(err, ContractError::NothingToClaim);
+    let res = claim(&mut deps, env_after(100), &alice, 1).unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&alice, 500)]);
+}
+
+#[test]
+fn test_create_vesting_validation() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let alice = addr(&deps, "alice");
+    let now = mock_env().block.time;
+    let schedule = VestingSchedule::Cliff {
+        release_time: now.plus_seconds(100),
+    };
+
+    let err = execute_create_vesting(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &[coin(500, DENOM)]),
+        alice.to_string(),
+        schedule.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+    let err = execute_create_vesting(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[coin(500, "uatom")]),
+        alice.to_string(),
+        schedule,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::IncorrectPayment {
+            denom: DENOM.to_string()
+        }
+    );
+    let err = execute_create_vesting(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[coin(500, DENOM)]),
+        alice.to_string(),
+        VestingSchedule::Linear {
+            start_time: now,
+            end_time: now.plus_seconds(100),
+            cliff_time: Some(now.plus_seconds(101)),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidSchedule {
+            reason: "cliff_time must be between start_time and end_time".to_string()
+        }
+    );
+}
+
+// ─── Claims & Revocation ────────────────────────────────────────────────────
+
+#[test]
+fn test_claim_beneficiary_only() {
+    let mut deps = setup_contract();
+    let alice = addr(&deps, "alice");
+    let bob = addr(&deps, "bob");
+    let id = create_linear(&mut deps, &alice);
+
+    let err = claim(&mut deps, env_after(500), &bob, id).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "beneficiary".to_string()
+        }
+    );
+    let err = claim(&mut deps, env_after(500), &alice, 7).unwrap_err();
+    assert_eq!(err, ContractError::VestingNotFound { vesting_id: 7 });
+}
+
+#[test]
+fn test_revoke_returns_unvested_portion() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let alice = addr(&deps, "alice");
+    let id = create_linear(&mut deps, &alice);
+    claim(&mut deps, env_after(300), &alice, id).unwrap();
+
+    let res = execute_revoke_vesting(deps.as_mut(), env_after(400), message_info(&owner, &[]), id)
+        .unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&owner, 600)]);
+    let err = execute_revoke_vesting(deps.as_mut(), env_after(500), message_info(&owner, &[]), id)
+        .unwrap_err();
+    assert_eq!(err, ContractError::AlreadyRevoked { vesting_id: id });
+
+    // Vesting stops at revocation; what had vested stays claimable
+    let info = vesting(&deps, env_after(5_000), id);
+    assert_eq!(info.vested, Uint128::new(400));
+    assert_eq!(info.claimable, Uint128::new(100));
+    let res = claim(&mut deps, env_after(5_000), &alice, id).unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&alice, 100)]);
+}
+
+#[test]
+fn test_fully_vested_cannot_be_revoked() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let alice = addr(&deps, "alice");
+    let id = create_linear(&mut deps, &alice);
+
+    let err = execute_revoke_vesting(
+        deps.as_mut(),
+        env_after(1_000),
+        message_info(&owner, &[]),
+        id,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::FullyVested { vesting_id: id });
+}
+
+#[test]
+fn test_vestings_query_by_beneficiary() {
+    let mut deps = setup_contract();
+    let alice = addr(&deps, "alice");
+    let bob = addr(&deps, "bob");
+    create_linear(&mut deps, &alice);
+    create_linear(&mut deps, &bob);
+    create_linear(&mut deps, &alice);
+
+    let res: VestingsResponse = from_json(
+        query_vestings(deps.as_ref(), env_after(500), alice.to_string(), None, None).unwrap(),
+    )
+    .unwrap();
+    let ids: Vec<u64> = res.vestings.iter().map(|v| v.vesting_id).collect();
+    assert_eq!(ids, vec![1, 3]);
+    assert_eq!(res.vestings[0].claimable, Uint128::new(500));
+
+    let res: VestingsResponse = from_json(
+        query_vestings(deps.as_ref(), mock_env(), alice.to_string(), Some(1), None).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.vestings.len(), 1);
+    assert_eq!(res.vestings[0].vesting_id, 3);
+}
@@ -0,0 +1,111 @@
+//! Typed events emitted by the SYSBREAK contracts.
+//!
+//! Every event has a fixed `sysbreak_*` type and its attributes appear in
+//! field order, so indexers can decode the same event the same way no matter
+//! which contract emitted it. Handlers keep their `action` attribute and any
+//! contract-specific ids on the main wasm event; the shared payload goes here.
+
+use cosmwasm_std::Event;
+
+/// Define an event struct whose fields become attributes, in declaration order.
+macro_rules! sysbreak_event {
+    ($(#[$meta:meta])* $name:ident = $ty:literal { $($(#[$fmeta:meta])* $field:ident),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $name {
+            $($(#[$fmeta])* pub $field: String,)+
+        }
+
+        impl $name {
+            pub const TYPE: &'static str = $ty;
+        }
+
+        impl From<$name> for Event {
+            fn from(e: $name) -> Self {
+                Event::new($ty)$(.add_attribute(stringify!($field), e.$field))+
+            }
+        }
+    };
+}
+
+sysbreak_event! {
+    /// A token came into existence (single, batch, claimed and crafted mints).
+    MintEvent = "sysbreak_mint" {
+        token_id,
+        owner,
+        /// Address that triggered the mint (the minter, or the claimer)
+        minter,
+    }
+}
+
+sysbreak_event! {
+    /// A token was destroyed.
+    BurnEvent = "sysbreak_burn" {
+        token_id,
+        owner,
+        /// Address that sent the burn (owner, approved spender or minter)
+        sender,
+    }
+}
+
+sysbreak_event! {
+    /// A token changed hands, including sends to contracts.
+    TransferEvent = "sysbreak_transfer" {
+        token_id,
+        from,
+        to,
+    }
+}
+
+sysbreak_event! {
+    /// Native funds bridged in for game credits.
+    DepositEvent = "sysbreak_deposit" {
+        sender,
+        denom,
+        token_amount,
+        credit_amount,
+    }
+}
+
+sysbreak_event! {
+    /// Game credits bridged out for native funds. `token_amount` is net of fees.
+    WithdrawEvent = "sysbreak_withdraw" {
+        recipient,
+        denom,
+        token_amount,
+        credit_amount,
+        fee_amount,
+    }
+}
+
+sysbreak_event! {
+    /// A governance proposal was resolved.
+    ProposalExecutedEvent = "sysbreak_proposal_executed" {
+        proposal_id,
+        corp_id,
+        /// "passed", "failed", or the kind-specific outcome
+        result,
+    }
+}
+
+sysbreak_event! {
+    /// A listing settled with a buyer.
+    SaleEvent = "sysbreak_sale" {
+        listing_id,
+        collection,
+        token_id,
+        seller,
+        buyer,
+        price,
+        denom,
+    }
+}
+
+sysbreak_event! {
+    /// Funds paid out to an entitled address (rewards, vestings, dissolution shares).
+    ClaimEvent = "sysbreak_claim" {
+        recipient,
+        amount,
+        denom,
+    }
+}
@@ -0,0 +1,36 @@
+use cosmwasm_std::{Attribute, Event};
+
+use sysbreak_events::{MintEvent, WithdrawEvent};
+
+#[test]
+fn test_attributes_follow_field_order() {
+    let event = Event::from(WithdrawEvent {
+        recipient: "player".to_string(),
+        denom: "ushido".to_string(),
+        token_amount: "995000".to_string(),
+        credit_amount: "10000".to_string(),
+        fee_amount: "5000".to_string(),
+    });
+
+    assert_eq!(event.ty, WithdrawEvent::TYPE);
+    assert_eq!(
+        event.attributes,
+        vec![
+            Attribute::new("recipient", "player"),
+            Attribute::new("denom", "ushido"),
+            Attribute::new("token_amount", "995000"),
+            Attribute::new("credit_amount", "10000"),
+            Attribute::new("fee_amount", "5000"),
+        ]
+    );
+}
+
+#[test]
+fn test_event_types_share_prefix() {
+    let event = Event::from(MintEvent {
+        token_id: "1".to_string(),
+        owner: "player".to_string(),
+        minter: "game".to_string(),
+    });
+    assert_eq!(event.ty, "sysbreak_mint");
+}
@@ -0,0 +1,372 @@
+//! Cross-contract test harness: the credit bridge, corporation DAO, item NFT
+//! and marketplace deployed together in one cw-multi-test `App`, so scenarios
+//! exercise the real messages and queries the contracts send each other.
+
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{coins, Addr, Binary, Coin, Uint128};
+use cw_multi_test::{App, AppResponse, Executor};
+use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+use sha2::{Digest, Sha256};
+
+use sysbreak_corporation_dao::msg as dao;
+use sysbreak_corporation_dao::state::JoinPolicy;
+use sysbreak_credit_bridge::msg as bridge;
+use sysbreak_credit_bridge::state::NONCE_BUCKET_SECONDS;
+use sysbreak_item_nft::msg as items;
+use sysbreak_marketplace::msg as marketplace;
+
+pub const DENOM: &str = "ushido";
+pub const CHAIN_ID: &str = "shido-testnet-1";
+
+// Bridge rate: 10_000 credits = 1_000_000 ushido (100 ushido per credit)
+pub const RATE_CREDITS: u128 = 10_000;
+pub const RATE_TOKENS: u128 = 1_000_000;
+/// Bridge withdrawal fee (0.5%)
+pub const BRIDGE_FEE_BPS: u16 = 50;
+/// Minimum balance the bridge keeps back from withdrawals
+pub const BRIDGE_MIN_RESERVE: u128 = 1_000_000;
+pub const CORP_CREATION_FEE: u128 = 1_000;
+pub const PROPOSAL_DEPOSIT: u128 = 500;
+pub const VOTING_PERIOD: u64 = 259_200;
+/// Item NFT royalty (5%)
+pub const ROYALTY_BPS: u16 = 500;
+
+/// Oracle key the bridge is instantiated with (a fixed test key)
+const ORACLE_KEY: [u8; 32] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10,
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20,
+];
+
+/// Every contract instantiated by `owner`, who is also the item minter.
+pub struct Stack {
+    pub app: App,
+    pub owner: Addr,
+    /// Signs bridge withdrawals; the bridge holds its public key
+    pub oracle_key: SigningKey,
+    /// Receives the bridge's withdrawal fees
+    pub treasury: Addr,
+    /// Receives item NFT royalties
+    pub royalty_recipient: Addr,
+    pub bridge: Addr,
+    pub dao: Addr,
+    pub items: Addr,
+    pub marketplace: Addr,
+}
+
+/// Sets up a `Stack`. Balances are given to `addr_make` labels at genesis.
+#[derive(Default)]
+pub struct StackBuilder {
+    balances: Vec<(String, u128)>,
+    bridge_liquidity: u128,
+}
+
+impl StackBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give `name` `amount` of DENOM at genesis.
+    pub fn with_balance(mut self, name: &str, amount: u128) -> Self {
+        self.balances.push((name.to_string(), amount));
+        self
+    }
+
+    /// Native tokens the owner sends the bridge after instantiation, on top of
+    /// player deposits.
+    pub fn with_bridge_liquidity(mut self, amount: u128) -> Self {
+        self.bridge_liquidity = amount;
+        self
+    }
+
+    pub fn build(self) -> Stack {
+        let mut app = App::new(|router, api, storage| {
+            let owner = api.addr_make("owner");
+            let mut funded = vec![(owner, self.bridge_liquidity)];
+            for (name, amount) in &self.balances {
+                funded.push((api.addr_make(name), *amount));
+            }
+            for (addr, amount) in funded {
+                router
+                    .bank
+                    .init_balance(storage, &addr, coins(amount, DENOM))
+                    .unwrap();
+            }
+        });
+        let owner = app.api().addr_make("owner");
+        let oracle = app.api().addr_make("oracle");
+        let treasury = app.api().addr_make("treasury");
+        let royalty_recipient = app.api().addr_make("royalties");
+        let oracle_key = SigningKey::from_bytes((&ORACLE_KEY).into()).unwrap();
+        let oracle_pubkey = oracle_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec();
+
+        let code_id = app.store_code(sysbreak_credit_bridge::testing::contract());
+        let bridge = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &bridge::InstantiateMsg {
+                    owner: owner.to_string(),
+                    oracle: oracle.to_string(),
+                    oracle_pubkey: Binary::from(oracle_pubkey),
+                    denom: DENOM.to_string(),
+                    rate_credits: Uint128::new(RATE_CREDITS),
+                    rate_tokens: Uint128::new(RATE_TOKENS),
+                    fee_bps: BRIDGE_FEE_BPS,
+                    treasury: treasury.to_string(),
+                    min_deposit: Uint128::new(100_000),
+                    player_daily_limit: Uint128::new(100_000),
+                    global_daily_limit: Uint128::new(10_000_000),
+                    cooldown_seconds: 3_600,
+                    min_reserve: Uint128::new(BRIDGE_MIN_RESERVE),
+                    chain_id: CHAIN_ID.to_string(),
+                    peak_window_seconds: None,
+                    pauser: None,
+                },
+                &[],
+                "credit-bridge",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+        if self.bridge_liquidity > 0 {
+            app.send_tokens(
+                owner.clone(),
+                bridge.clone(),
+                &coins(self.bridge_liquidity, DENOM),
+            )
+            .unwrap();
+        }
+
+        let code_id = app.store_code(sysbreak_corporation_dao::testing::contract());
+        let dao = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &dao::InstantiateMsg {
+                    owner: owner.to_string(),
+                    denom: DENOM.to_string(),
+                    creation_fee: Uint128::new(CORP_CREATION_FEE),
+                    proposal_deposit: Uint128::new(PROPOSAL_DEPOSIT),
+                    default_max_members: 50,
+                    default_quorum_bps: 5_100,
+                    default_voting_period: VOTING_PERIOD,
+                    max_spend_bps: None,
+                },
+                &[],
+                "corporation-dao",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+
+        let code_id = app.store_code(sysbreak_item_nft::testing::contract());
+        let items = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &items::InstantiateMsg {
+                    owner: owner.to_string(),
+                    minter: owner.to_string(),
+                    royalty_bps: ROYALTY_BPS,
+                    royalty_recipient: royalty_recipient.to_string(),
+                    name: "SYSBREAK Items".to_string(),
+                    symbol: "SBITEM".to_string(),
+                },
+                &[],
+                "item-nft",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+
+        let code_id = app.store_code(sysbreak_marketplace::testing::contract());
+        let marketplace = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &marketplace::InstantiateMsg {
+                    owner: owner.to_string(),
+                    denom: DENOM.to_string(),
+                    collections: vec![items.to_string()],
+                },
+                &[],
+                "marketplace",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+        // Items the marketplace holds must stay as they were listed
+        app.execute_contract(
+            owner.clone(),
+            items.clone(),
+            &items::ExecuteMsg::AddEscrowContract {
+                address: marketplace.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        Stack {
+            app,
+            owner,
+            oracle_key,
+            treasury,
+            royalty_recipient,
+            bridge,
+            dao,
+            items,
+            marketplace,
+        }
+    }
+}
+
+impl Stack {
+    pub fn addr(&self, name: &str) -> Addr {
+        self.app.api().addr_make(name)
+    }
+
+    pub fn balance(&self, addr: &Addr) -> Uint128 {
+        self.app.wrap().query_balance(addr, DENOM).unwrap().amount
+    }
+
+    pub fn advance_time(&mut self, seconds: u64) {
+        self.app.update_block(|block| {
+            block.time = block.time.plus_seconds(seconds);
+            block.height += seconds / 5;
+        });
+    }
+
+    /// A sequenced withdrawal nonce in the current block's nonce bucket.
+    pub fn nonce(&self, sequence: u64) -> String {
+        let bucket = self.app.block_info().time.seconds() / NONCE_BUCKET_SECONDS;
+        format!("seq:{}:{}", bucket, sequence)
+    }
+
+    /// Oracle signature over a legacy (v1) withdrawal payload in the primary denom.
+    pub fn sign_withdrawal(
+        &self,
+        nonce: &str,
+        player: &Addr,
+        credit_amount: Uint128,
+        token_amount: Uint128,
+    ) -> Binary {
+        let payload = format!(
+            "withdraw:{}:{}:{}:{}:{}:{}",
+            CHAIN_ID, self.bridge, nonce, player, credit_amount, token_amount
+        );
+        let hash = Sha256::digest(payload.as_bytes());
+        let (sig, _): (Signature, _) = self.oracle_key.sign_prehash(&hash).unwrap();
+        Binary::from(sig.to_bytes().to_vec())
+    }
+
+    /// Withdraw `credit_amount` for `player`, signing for the exact net amount.
+    pub fn withdraw(&mut self, player: &Addr, sequence: u64, credit_amount: u128) -> AppResponse {
+        let credit_amount = Uint128::new(credit_amount);
+        let gross = credit_amount.multiply_ratio(RATE_TOKENS, RATE_CREDITS);
+        let token_amount = gross - gross.multiply_ratio(BRIDGE_FEE_BPS, 10_000u128);
+        let nonce = self.nonce(sequence);
+        let signature = self.sign_withdrawal(&nonce, player, credit_amount, token_amount);
+        self.app
+            .execute_contract(
+                player.clone(),
+                self.bridge.clone(),
+                &bridge::ExecuteMsg::Withdraw {
+                    nonce,
+                    credit_amount,
+                    token_amount,
+                    signature,
+                    ibc_transfer: None,
+                    denom: None,
+                    payload_version: None,
+                    recipient: None,
+                },
+                &[],
+            )
+            .unwrap()
+    }
+
+    /// Mint a plain common item to `to` and return its token id.
+    pub fn mint_item(&mut self, to: &Addr, item_type: &str) -> String {
+        let res = self
+            .app
+            .execute_contract(
+                self.owner.clone(),
+                self.items.clone(),
+                &items::ExecuteMsg::Mint {
+                    to: to.to_string(),
+                    item_type: item_type.to_string(),
+                    rarity: "common".to_string(),
+                    level: 1,
+                    stats: BTreeMap::new(),
+                    origin: "integration".to_string(),
+                    token_uri: None,
+                    max_durability: None,
+                    soulbound: false,
+                    charges: None,
+                    metadata_hash: None,
+                },
+                &[],
+            )
+            .unwrap();
+        event_attr(&res, "wasm-sysbreak_mint", "token_id")
+    }
+
+    /// Create an open corporation founded by `founder` and return its id.
+    pub fn create_corporation(&mut self, founder: &Addr, name: &str) -> u64 {
+        let res = self
+            .app
+            .execute_contract(
+                founder.clone(),
+                self.dao.clone(),
+                &dao::ExecuteMsg::CreateCorporation {
+                    name: name.to_string(),
+                    description: format!("{} description", name),
+                    join_policy: JoinPolicy::Open,
+                    branding: None,
+                    custom_denom: None,
+                },
+                &coins(CORP_CREATION_FEE, DENOM),
+            )
+            .unwrap();
+        event_attr(&res, "wasm", "corp_id").parse().unwrap()
+    }
+
+    pub fn corporation(&self, corp_id: u64) -> dao::CorporationResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.dao, &dao::QueryMsg::Corporation { corp_id })
+            .unwrap()
+    }
+
+    pub fn item_owner(&self, token_id: &str) -> String {
+        let res: items::OwnerOfResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.items,
+                &items::QueryMsg::OwnerOf {
+                    token_id: token_id.to_string(),
+                },
+            )
+            .unwrap();
+        res.owner
+    }
+}
+
+/// First value of `key` on an event of type `ty` (contract events are prefixed "wasm-").
+pub fn event_attr(res: &AppResponse, ty: &str, key: &str) -> String {
+    res.events
+        .iter()
+        .filter(|e| e.ty == ty)
+        .flat_map(|e| &e.attributes)
+        .find(|a| a.key == key)
+        .unwrap_or_else(|| panic!("no {} attribute on a {} event", key, ty))
+        .value
+        .clone()
+}
+
+/// `amount` of DENOM, for message funds.
+pub fn funds(amount: u128) -> Vec<Coin> {
+    coins(amount, DENOM)
+}
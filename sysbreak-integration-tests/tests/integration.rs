@@ -0,0 +1,209 @@
+use cosmwasm_std::{to_json_binary, Uint128};
+use cw_multi_test::Executor;
+
+use sysbreak_corporation_dao::msg as dao;
+use sysbreak_credit_bridge::msg as bridge;
+use sysbreak_integration_tests::{
+    event_attr, funds, StackBuilder, PROPOSAL_DEPOSIT, VOTING_PERIOD,
+};
+use sysbreak_item_nft::msg as items;
+use sysbreak_marketplace::msg as marketplace;
+
+// ─── Credit Bridge ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_deposit_then_withdraw() {
+    let mut stack = StackBuilder::new()
+        .with_balance("player", 10_000_000)
+        .with_bridge_liquidity(5_000_000)
+        .build();
+    let player = stack.addr("player");
+
+    let res = stack
+        .app
+        .execute_contract(
+            player.clone(),
+            stack.bridge.clone(),
+            &bridge::ExecuteMsg::Deposit { referrer: None },
+            &funds(2_000_000),
+        )
+        .unwrap();
+    assert_eq!(
+        event_attr(&res, "wasm-sysbreak_deposit", "credit_amount"),
+        "20000"
+    );
+
+    // 10_000 credits = 1_000_000 ushido gross, 0.5% fee
+    stack.withdraw(&player, 1, 10_000);
+    assert_eq!(stack.balance(&player), Uint128::new(8_995_000));
+    assert_eq!(stack.balance(&stack.treasury), Uint128::new(5_000));
+    assert_eq!(stack.balance(&stack.bridge), Uint128::new(6_000_000));
+}
+
+#[test]
+fn test_withdrawal_fee_shared_with_corporation() {
+    let mut stack = StackBuilder::new()
+        .with_balance("player", 10_000)
+        .with_bridge_liquidity(5_000_000)
+        .build();
+    let player = stack.addr("player");
+    let corp_id = stack.create_corporation(&player, "Netrunners");
+
+    let owner = stack.owner.clone();
+    stack
+        .app
+        .execute_contract(
+            owner,
+            stack.bridge.clone(),
+            &bridge::ExecuteMsg::SetDaoFeeShare {
+                fee_share: Some(bridge::DaoFeeShareMsg {
+                    dao_contract: stack.dao.to_string(),
+                    dao_fee_share_bps: 5_000,
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // The bridge looks the player up on the DAO and donates half the fee
+    stack.withdraw(&player, 1, 10_000);
+    let corp = stack.corporation(corp_id);
+    assert_eq!(corp.corporation.treasury_balance, Uint128::new(2_500));
+    assert_eq!(stack.balance(&stack.treasury), Uint128::new(2_500));
+}
+
+// ─── Corporation DAO ────────────────────────────────────────────────────────
+
+#[test]
+fn test_proposal_spends_treasury() {
+    let mut stack = StackBuilder::new()
+        .with_balance("founder", 100_000)
+        .with_balance("member", 10_000)
+        .build();
+    let founder = stack.addr("founder");
+    let member = stack.addr("member");
+    let recipient = stack.addr("recipient");
+    let dao_addr = stack.dao.clone();
+
+    let corp_id = stack.create_corporation(&founder, "Corp");
+    stack
+        .app
+        .execute_contract(
+            founder.clone(),
+            dao_addr.clone(),
+            &dao::ExecuteMsg::DonateTreasury { corp_id },
+            &funds(10_000),
+        )
+        .unwrap();
+    stack
+        .app
+        .execute_contract(
+            member.clone(),
+            dao_addr.clone(),
+            &dao::ExecuteMsg::JoinCorporation { corp_id },
+            &[],
+        )
+        .unwrap();
+
+    let res = stack
+        .app
+        .execute_contract(
+            founder.clone(),
+            dao_addr.clone(),
+            &dao::ExecuteMsg::CreateProposal {
+                corp_id,
+                proposal_type: dao::ProposalTypeMsg::TreasurySpend {
+                    recipient: recipient.to_string(),
+                    amount: Uint128::new(2_500),
+                    denom: None,
+                },
+                metadata: None,
+            },
+            &funds(PROPOSAL_DEPOSIT),
+        )
+        .unwrap();
+    let proposal_id: u64 = event_attr(&res, "wasm", "proposal_id").parse().unwrap();
+    for voter in [&founder, &member] {
+        stack
+            .app
+            .execute_contract(
+                voter.clone(),
+                dao_addr.clone(),
+                &dao::ExecuteMsg::Vote {
+                    proposal_id,
+                    vote: true,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    stack.advance_time(VOTING_PERIOD + 1);
+    let founder_before = stack.balance(&founder);
+    stack
+        .app
+        .execute_contract(
+            founder.clone(),
+            dao_addr,
+            &dao::ExecuteMsg::ExecuteProposal { proposal_id },
+            &[],
+        )
+        .unwrap();
+
+    assert_eq!(stack.balance(&recipient), Uint128::new(2_500));
+    assert_eq!(
+        stack.balance(&founder),
+        founder_before + Uint128::new(PROPOSAL_DEPOSIT)
+    );
+    let corp = stack.corporation(corp_id);
+    assert_eq!(corp.corporation.treasury_balance, Uint128::new(7_500));
+}
+
+// ─── Item NFT + Marketplace ─────────────────────────────────────────────────
+
+#[test]
+fn test_mint_list_and_buy() {
+    let mut stack = StackBuilder::new().with_balance("buyer", 5_000_000).build();
+    let seller = stack.addr("seller");
+    let buyer = stack.addr("buyer");
+    let token_id = stack.mint_item(&seller, "weapon");
+
+    // SendNft moves the token into escrow and lists it through the receive hook
+    let res = stack
+        .app
+        .execute_contract(
+            seller.clone(),
+            stack.items.clone(),
+            &items::ExecuteMsg::SendNft {
+                contract: stack.marketplace.to_string(),
+                token_id: token_id.clone(),
+                msg: to_json_binary(&marketplace::ListingMsg::FixedPrice {
+                    price: Uint128::new(1_000_000),
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+    let listing_id: u64 = event_attr(&res, "wasm", "listing_id").parse().unwrap();
+    assert_eq!(stack.item_owner(&token_id), stack.marketplace.to_string());
+
+    stack
+        .app
+        .execute_contract(
+            buyer.clone(),
+            stack.marketplace.clone(),
+            &marketplace::ExecuteMsg::Buy { listing_id },
+            &funds(1_000_000),
+        )
+        .unwrap();
+
+    assert_eq!(stack.item_owner(&token_id), buyer.to_string());
+    assert_eq!(stack.balance(&buyer), Uint128::new(4_000_000));
+    // 5% royalty from the item contract's cw2981 extension
+    assert_eq!(
+        stack.balance(&stack.royalty_recipient),
+        Uint128::new(50_000)
+    );
+    assert_eq!(stack.balance(&seller), Uint128::new(950_000));
+}
@@ -1,28 +1,50 @@
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
-    WasmMsg,
+    to_json_binary, Addr, BankMsg, Binary, BlockInfo, Coin, Deps, DepsMut, Env, Event, HexBinary,
+    MessageInfo, Order, Response, StdResult, Storage, Timestamp, WasmMsg,
 };
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
+use cw721::Expiration;
+use sysbreak_common::reject_funds;
+use sysbreak_events::{BurnEvent, MintEvent, SaleEvent, TransferEvent};
 
 use crate::error::ContractError;
 use crate::helpers::{
-    assert_minter, assert_not_paused, assert_owner, is_authorized, reject_funds,
-    validate_royalty_bps,
+    approval_expiry, assert_minter, assert_not_broken, assert_not_escrowed, assert_not_listed,
+    assert_not_locked, assert_not_paused, assert_not_soulbound, assert_owner, clear_approvals,
+    consume_mint_quota, effective_royalty, is_authorized, is_metadata_frozen, live_approvals,
+    minted_in_window, record_transfer, remove_listing, validate_metadata_hash,
+    validate_royalty_bps, validate_stats,
 };
 use crate::msg::{
-    ApprovalResponse, CollectionInfoResponse, InstantiateMsg, MigrateMsg, MintRequest,
-    NftInfoResponse, NumTokensResponse, OperatorResponse, OwnerOfResponse, RoyaltyInfoResponse,
-    TokensResponse,
+    ApprovalResponse, ApprovalsResponse, BackfillMsg, CheckRoyaltiesResponse, ChildrenResponse,
+    CollectionInfoResponse, Cw2981QueryMsg, EscrowContractsResponse, InstantiateMsg, ListingInfo,
+    ListingsResponse, MetadataFrozenResponse, MetadataHashResponse, MigrateMsg,
+    MigrationStatusResponse, MintRequest, MinterEntry, MintersResponse, NftInfoResponse,
+    NumTokensResponse, OperatorResponse, OwnerOfResponse, RecipeInfo, RecipesResponse,
+    RedemptionsResponse, RoyaltiesInfoResponse, RoyaltyInfoResponse, SpenderApproval,
+    TokenHistoryResponse, TokensResponse, TokensWithInfoResponse, TradeInfo, TradesResponse,
+    TransferRequest, TypeSupplyResponse,
 };
 use crate::state::{
-    Config, ItemMetadata, PendingMinterTransfer, PendingOwnerTransfer, TokenData, CONFIG,
-    OPERATOR_APPROVALS, OWNER_TOKENS, PENDING_MINTER, PENDING_OWNER, TOKENS, TOKEN_APPROVALS,
-    TOKEN_COUNT, TOKEN_OWNERS,
+    BackfillProgress, Config, ItemMetadata, Listing, MinterInfo, PendingMinterTransfer,
+    PendingOwnerTransfer, Recipe, Redemption, StatRange, TokenData, Trade, TransferRecord,
+    TypeRoyalty, ALL_METADATA_FROZEN, BACKFILL, CONFIG, CONFIG_V1, ESCROW_CONTRACTS, ITEM_CHILDREN,
+    ITEM_PARENT, LAST_REDEMPTION_ID, LAST_TOKEN_ID, LAST_TRADE_ID, LEGACY_TOKEN_APPROVALS,
+    LISTINGS, MAX_REDEMPTION_CODE_LEN, MAX_SCHEMA_STATS, MAX_TOKEN_APPROVALS, MINTERS,
+    MINTER_TRANSFER, OPERATOR_APPROVALS, OWNER_TOKENS, OWNER_TRANSFER, PENDING_REDEMPTIONS,
+    RARITY_TOKENS, RECIPES, REDEMPTIONS, REDEMPTION_CODES, REPAIR_FEES, SELLER_LISTINGS,
+    STAT_SCHEMAS, TOKENS, TOKEN_APPROVALS, TOKEN_COUNT, TOKEN_HISTORY, TOKEN_OWNERS, TRADES,
+    TYPE_MAX_SUPPLY, TYPE_MINTED, TYPE_ROYALTIES, TYPE_TOKENS,
 };
 
 const CONTRACT_NAME: &str = "crates.io:sysbreak-item-nft";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const MAX_BATCH_SIZE: u32 = 50;
+const MAX_RECIPE_INPUTS: usize = 10;
+const MAX_TRADE_TOKENS: usize = 10;
+const MAX_ATTACHMENTS: usize = 10;
+const DEFAULT_BACKFILL_LIMIT: u32 = 500;
+const MAX_BACKFILL_LIMIT: u32 = 5_000;
 const DEFAULT_QUERY_LIMIT: u32 = 30;
 const MAX_QUERY_LIMIT: u32 = 100;
 
@@ -43,7 +65,6 @@ pub fn instantiate(
 
     let config = Config {
         owner,
-        minter,
         paused: false,
         royalty_bps: msg.royalty_bps,
         royalty_recipient,
@@ -52,20 +73,21 @@ pub fn instantiate(
         symbol: msg.symbol,
     };
     CONFIG.save(deps.storage, &config)?;
+    MINTERS.save(deps.storage, &minter, &MinterInfo { daily_quota: None })?;
     TOKEN_COUNT.save(deps.storage, &0u64)?;
 
     Ok(Response::new()
         .add_attribute("action", "instantiate")
         .add_attribute("contract", CONTRACT_NAME)
         .add_attribute("owner", config.owner.as_str())
-        .add_attribute("minter", config.minter.as_str()))
+        .add_attribute("minter", minter.as_str()))
 }
 
 // ─── Execute ────────────────────────────────────────────────────────────────
 
 pub fn execute_mint(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     to: String,
     item_type: String,
@@ -74,27 +96,48 @@ pub fn execute_mint(
     stats: std::collections::BTreeMap<String, u64>,
     origin: String,
     token_uri: Option<String>,
+    max_durability: Option<u32>,
+    soulbound: bool,
+    charges: Option<u32>,
+    metadata_hash: Option<HexBinary>,
 ) -> Result<Response, ContractError> {
     assert_not_paused(deps.as_ref())?;
-    assert_minter(deps.as_ref(), &info.sender)?;
+    let minter = assert_minter(deps.as_ref(), &info.sender)?;
+    consume_mint_quota(deps.storage, &env, &info.sender, &minter, 1)?;
 
     let recipient = deps.api.addr_validate(&to)?;
-    let token_id = mint_single(deps, &recipient, item_type, rarity, level, stats, origin, token_uri)?;
+    let token_id = mint_single(
+        deps,
+        &recipient,
+        item_type,
+        rarity,
+        level,
+        stats,
+        origin,
+        token_uri,
+        max_durability,
+        soulbound,
+        charges,
+        metadata_hash,
+    )?;
 
     Ok(Response::new()
         .add_attribute("action", "mint")
-        .add_attribute("token_id", &token_id)
-        .add_attribute("to", recipient.as_str()))
+        .add_event(MintEvent {
+            token_id,
+            owner: recipient.to_string(),
+            minter: info.sender.to_string(),
+        }))
 }
 
 pub fn execute_batch_mint(
     mut deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     mints: Vec<MintRequest>,
 ) -> Result<Response, ContractError> {
     assert_not_paused(deps.as_ref())?;
-    assert_minter(deps.as_ref(), &info.sender)?;
+    let minter = assert_minter(deps.as_ref(), &info.sender)?;
 
     if mints.is_empty() {
         return Err(ContractError::EmptyBatch);
@@ -104,6 +147,13 @@ pub fn execute_batch_mint(
             max: MAX_BATCH_SIZE,
         });
     }
+    consume_mint_quota(
+        deps.storage,
+        &env,
+        &info.sender,
+        &minter,
+        mints.len() as u32,
+    )?;
 
     // Validate all recipients upfront before mutating any state
     let validated: Vec<(Addr, &MintRequest)> = mints
@@ -111,7 +161,7 @@ pub fn execute_batch_mint(
         .map(|m| Ok((deps.api.addr_validate(&m.to)?, m)))
         .collect::<Result<Vec<_>, ContractError>>()?;
 
-    let mut token_ids = Vec::with_capacity(validated.len());
+    let mut events = Vec::with_capacity(validated.len());
     for (recipient, req) in validated {
         let token_id = mint_single(
             deps.branch(),
@@ -122,18 +172,25 @@ pub fn execute_batch_mint(
             req.stats.clone(),
             req.origin.clone(),
             req.token_uri.clone(),
+            req.max_durability,
+            req.soulbound,
+            req.charges,
+            req.metadata_hash.clone(),
         )?;
-        token_ids.push(token_id);
+        events.push(MintEvent {
+            token_id,
+            owner: recipient.to_string(),
+            minter: info.sender.to_string(),
+        });
     }
 
     Ok(Response::new()
         .add_attribute("action", "batch_mint")
-        .add_attribute("count", token_ids.len().to_string())
-        .add_attribute("first_token_id", &token_ids[0])
-        .add_attribute("last_token_id", &token_ids[token_ids.len() - 1]))
+        .add_attribute("count", events.len().to_string())
+        .add_events(events))
 }
 
-/// Internal helper: mint a single token, increment counter, store data + owner.
+/// Internal helper: mint a single token, bump the id and live count, store data + owner.
 fn mint_single(
     deps: DepsMut,
     recipient: &Addr,
@@ -143,10 +200,45 @@ fn mint_single(
     stats: std::collections::BTreeMap<String, u64>,
     origin: String,
     token_uri: Option<String>,
+    max_durability: Option<u32>,
+    soulbound: bool,
+    charges: Option<u32>,
+    metadata_hash: Option<HexBinary>,
 ) -> Result<String, ContractError> {
-    let mut count = TOKEN_COUNT.load(deps.storage)?;
-    count += 1;
-    let token_id = count.to_string();
+    // Ids and supply caps are only safe once LAST_TOKEN_ID and TYPE_MINTED are rebuilt
+    if BACKFILL.may_load(deps.storage)?.is_some_and(|p| !p.done) {
+        return Err(ContractError::BackfillInProgress);
+    }
+    if max_durability == Some(0) {
+        return Err(ContractError::InvalidDurability);
+    }
+    if charges == Some(0) {
+        return Err(ContractError::InvalidCharges);
+    }
+    if let Some(hash) = &metadata_hash {
+        if token_uri.is_none() {
+            return Err(ContractError::InvalidMetadataHash {
+                reason: "a metadata hash needs a token_uri".to_string(),
+            });
+        }
+        validate_metadata_hash(hash)?;
+    }
+    validate_stats(deps.storage, &item_type, &stats)?;
+    let minted = TYPE_MINTED
+        .may_load(deps.storage, &item_type)?
+        .unwrap_or_default()
+        + 1;
+    if let Some(max_supply) = TYPE_MAX_SUPPLY.may_load(deps.storage, &item_type)? {
+        if minted > max_supply {
+            return Err(ContractError::SupplyCapReached {
+                item_type,
+                max_supply,
+            });
+        }
+    }
+    let count = TOKEN_COUNT.load(deps.storage)? + 1;
+    let id = LAST_TOKEN_ID.may_load(deps.storage)?.unwrap_or_default() + 1;
+    let token_id = id.to_string();
 
     let data = TokenData {
         metadata: ItemMetadata {
@@ -155,22 +247,33 @@ fn mint_single(
             level,
             stats,
             origin,
+            durability: max_durability,
+            max_durability,
+            charges,
         },
         token_uri,
+        locked: false,
+        soulbound,
+        metadata_frozen: false,
+        metadata_hash,
     };
 
     TOKENS.save(deps.storage, &token_id, &data)?;
     TOKEN_OWNERS.save(deps.storage, &token_id, recipient)?;
     // FIX: M-06 — maintain owner index for efficient queries
     OWNER_TOKENS.save(deps.storage, (recipient, &token_id), &true)?;
+    TYPE_TOKENS.save(deps.storage, (&data.metadata.item_type, &token_id), &true)?;
+    RARITY_TOKENS.save(deps.storage, (&data.metadata.rarity, &token_id), &true)?;
+    TYPE_MINTED.save(deps.storage, &data.metadata.item_type, &minted)?;
     TOKEN_COUNT.save(deps.storage, &count)?;
+    LAST_TOKEN_ID.save(deps.storage, &id)?;
 
     Ok(token_id)
 }
 
 pub fn execute_transfer_nft(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     recipient: String,
     token_id: String,
@@ -178,31 +281,174 @@ pub fn execute_transfer_nft(
     reject_funds(&info)?; // FIX: M-08
     assert_not_paused(deps.as_ref())?;
 
-    if !is_authorized(deps.as_ref(), &token_id, &info.sender)? {
+    let new_owner = deps.api.addr_validate(&recipient)?;
+    let old_owner = assert_can_transfer(deps.as_ref(), &env.block, &info.sender, &token_id)?;
+    let children = move_token(deps, &env, &old_owner, &new_owner, &token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "transfer_nft")
+        .add_event(TransferEvent {
+            token_id,
+            from: old_owner.to_string(),
+            to: new_owner.to_string(),
+        })
+        .add_events(children))
+}
+
+/// Move up to 50 tokens in one message. Every token is authorized and checked
+/// before any of them moves, so one bad entry fails the whole batch.
+pub fn execute_batch_transfer(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    transfers: Vec<TransferRequest>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    if transfers.is_empty() {
+        return Err(ContractError::EmptyBatch);
+    }
+    if transfers.len() as u32 > MAX_BATCH_SIZE {
+        return Err(ContractError::BatchTooLarge {
+            max: MAX_BATCH_SIZE,
+        });
+    }
+
+    // Validate every entry upfront before mutating any state
+    let mut validated = Vec::with_capacity(transfers.len());
+    for (i, t) in transfers.iter().enumerate() {
+        if transfers[..i]
+            .iter()
+            .any(|prev| prev.token_id == t.token_id)
+        {
+            return Err(ContractError::DuplicateToken {
+                token_id: t.token_id.clone(),
+            });
+        }
+        let new_owner = deps.api.addr_validate(&t.recipient)?;
+        let old_owner = assert_can_transfer(deps.as_ref(), &env.block, &info.sender, &t.token_id)?;
+        validated.push((old_owner, new_owner, &t.token_id));
+    }
+    let mut events = Vec::with_capacity(validated.len());
+    for (old_owner, new_owner, token_id) in validated {
+        let children = move_token(deps.branch(), &env, &old_owner, &new_owner, token_id)?;
+        events.push(TransferEvent {
+            token_id: token_id.clone(),
+            from: old_owner.to_string(),
+            to: new_owner.to_string(),
+        });
+        events.extend(children);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "batch_transfer")
+        .add_attribute("count", events.len().to_string())
+        .add_events(events))
+}
+
+/// Internal helper: check lock, durability and authorization for a transfer.
+/// Returns the current owner.
+fn assert_can_transfer(
+    deps: Deps,
+    block: &BlockInfo,
+    sender: &Addr,
+    token_id: &str,
+) -> Result<Addr, ContractError> {
+    assert_not_soulbound(deps, token_id)?;
+    assert_not_locked(deps, token_id)?;
+    assert_not_broken(deps, token_id)?;
+
+    if !is_authorized(deps, block, token_id, sender)? {
         return Err(ContractError::Unauthorized {
             role: "owner or approved".to_string(),
         });
     }
+    Ok(TOKEN_OWNERS.load(deps.storage, token_id)?)
+}
 
-    let old_owner = TOKEN_OWNERS.load(deps.storage, &token_id)?;
-    let new_owner = deps.api.addr_validate(&recipient)?;
+/// Internal helper: reassign ownership, clear the token's approval and listing,
+/// and record the move in its history. Attached items move along; their
+/// transfer events are returned. Fails on a token that no longer exists rather
+/// than writing index entries for it.
+fn move_token(
+    deps: DepsMut,
+    env: &Env,
+    old_owner: &Addr,
+    new_owner: &Addr,
+    token_id: &str,
+) -> Result<Vec<TransferEvent>, ContractError> {
+    if !TOKENS.has(deps.storage, token_id) {
+        return Err(ContractError::TokenNotFound {
+            token_id: token_id.to_string(),
+        });
+    }
     // FIX: M-06 — update owner index
-    OWNER_TOKENS.remove(deps.storage, (&old_owner, &token_id));
-    OWNER_TOKENS.save(deps.storage, (&new_owner, &token_id), &true)?;
-    // Clear approval on transfer
-    TOKEN_APPROVALS.remove(deps.storage, &token_id);
-    TOKEN_OWNERS.save(deps.storage, &token_id, &new_owner)?;
+    OWNER_TOKENS.remove(deps.storage, (old_owner, token_id));
+    OWNER_TOKENS.save(deps.storage, (new_owner, token_id), &true)?;
+    // Clear approval and any sale listing on transfer
+    clear_approvals(deps.storage, token_id)?;
+    remove_listing(deps.storage, token_id);
+    TOKEN_OWNERS.save(deps.storage, token_id, new_owner)?;
+    record_transfer(
+        deps.storage,
+        token_id,
+        TransferRecord {
+            from: old_owner.clone(),
+            to: new_owner.clone(),
+            timestamp: env.block.time,
+            price: None,
+        },
+    )?;
+    Ok(move_children(
+        deps.storage,
+        env,
+        old_owner,
+        new_owner,
+        token_id,
+    )?)
+}
 
-    Ok(Response::new()
-        .add_attribute("action", "transfer_nft")
-        .add_attribute("token_id", &token_id)
-        .add_attribute("from", info.sender.as_str())
-        .add_attribute("to", new_owner.as_str()))
+/// Internal helper: hand a parent's attached items to its new owner. Children
+/// carry no approvals or listings, so only ownership and history change.
+fn move_children(
+    storage: &mut dyn Storage,
+    env: &Env,
+    old_owner: &Addr,
+    new_owner: &Addr,
+    parent_id: &str,
+) -> StdResult<Vec<TransferEvent>> {
+    let children: Vec<String> = ITEM_CHILDREN
+        .prefix(parent_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    let mut events = Vec::with_capacity(children.len());
+    for child_id in children {
+        OWNER_TOKENS.remove(storage, (old_owner, &child_id));
+        OWNER_TOKENS.save(storage, (new_owner, &child_id), &true)?;
+        TOKEN_OWNERS.save(storage, &child_id, new_owner)?;
+        record_transfer(
+            storage,
+            &child_id,
+            TransferRecord {
+                from: old_owner.clone(),
+                to: new_owner.clone(),
+                timestamp: env.block.time,
+                price: None,
+            },
+        )?;
+        events.push(TransferEvent {
+            token_id: child_id,
+            from: old_owner.to_string(),
+            to: new_owner.to_string(),
+        });
+    }
+    Ok(events)
 }
 
 pub fn execute_send_nft(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     contract: String,
     token_id: String,
@@ -210,8 +456,11 @@ pub fn execute_send_nft(
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?; // FIX: M-08
     assert_not_paused(deps.as_ref())?;
+    assert_not_soulbound(deps.as_ref(), &token_id)?;
+    assert_not_locked(deps.as_ref(), &token_id)?;
+    assert_not_broken(deps.as_ref(), &token_id)?;
 
-    if !is_authorized(deps.as_ref(), &token_id, &info.sender)? {
+    if !is_authorized(deps.as_ref(), &env.block, &token_id, &info.sender)? {
         return Err(ContractError::Unauthorized {
             role: "owner or approved".to_string(),
         });
@@ -224,8 +473,26 @@ pub fn execute_send_nft(
     OWNER_TOKENS.remove(deps.storage, (&previous_owner, &token_id));
     OWNER_TOKENS.save(deps.storage, (&contract_addr, &token_id), &true)?;
     // State mutation before sub-message dispatch (check-effects-interactions)
-    TOKEN_APPROVALS.remove(deps.storage, &token_id);
+    clear_approvals(deps.storage, &token_id)?;
+    remove_listing(deps.storage, &token_id);
     TOKEN_OWNERS.save(deps.storage, &token_id, &contract_addr)?;
+    record_transfer(
+        deps.storage,
+        &token_id,
+        TransferRecord {
+            from: previous_owner.clone(),
+            to: contract_addr.clone(),
+            timestamp: env.block.time,
+            price: None,
+        },
+    )?;
+    let children = move_children(
+        deps.storage,
+        &env,
+        &previous_owner,
+        &contract_addr,
+        &token_id,
+    )?;
 
     // CW-721 receiver callback
     let callback = cw721::receiver::Cw721ReceiveMsg {
@@ -235,24 +502,29 @@ pub fn execute_send_nft(
     };
     let callback_msg = WasmMsg::Execute {
         contract_addr: contract_addr.to_string(),
-        msg: to_json_binary(&callback)?,
+        // Wrapped as `{"receive_nft": ...}` so standard cw721 receivers can parse it
+        msg: callback.into_json_binary()?,
         funds: vec![],
     };
 
     Ok(Response::new()
         .add_message(callback_msg)
         .add_attribute("action", "send_nft")
-        .add_attribute("token_id", &token_id)
-        .add_attribute("from", previous_owner.as_str())
-        .add_attribute("to", contract_addr.as_str()))
+        .add_event(TransferEvent {
+            token_id,
+            from: previous_owner.to_string(),
+            to: contract_addr.to_string(),
+        })
+        .add_events(children))
 }
 
 pub fn execute_approve(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     spender: String,
     token_id: String,
+    expires: Option<Expiration>,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?; // FIX: M-08
     assert_not_paused(deps.as_ref())?;
@@ -267,20 +539,50 @@ pub fn execute_approve(
             role: "token owner".to_string(),
         });
     }
+    assert_not_soulbound(deps.as_ref(), &token_id)?;
+    assert_not_locked(deps.as_ref(), &token_id)?;
 
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::ApprovalExpired);
+    }
     let spender_addr = deps.api.addr_validate(&spender)?;
-    TOKEN_APPROVALS.save(deps.storage, &token_id, &spender_addr)?;
+
+    // Lapsed approvals make room before the cap is checked
+    let stored: Vec<(Addr, Expiration)> = TOKEN_APPROVALS
+        .prefix(&token_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    let mut held = 0;
+    for (spender, expiry) in &stored {
+        if expiry.is_expired(&env.block) {
+            TOKEN_APPROVALS.remove(deps.storage, (&token_id, spender));
+        } else if *spender != spender_addr {
+            held += 1;
+        }
+    }
+    if held >= MAX_TOKEN_APPROVALS {
+        return Err(ContractError::TooManyApprovals {
+            token_id,
+            max: MAX_TOKEN_APPROVALS,
+        });
+    }
+    TOKEN_APPROVALS.save(deps.storage, (&token_id, &spender_addr), &expires)?;
 
     Ok(Response::new()
         .add_attribute("action", "approve")
         .add_attribute("token_id", &token_id)
-        .add_attribute("spender", spender_addr.as_str()))
+        .add_attribute("spender", spender_addr.as_str())
+        .add_attribute("expires", expires.to_string()))
 }
 
+/// Revoke one spender's approval, or every approval on the token when
+/// `spender` is omitted.
 pub fn execute_revoke(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    spender: Option<String>,
     token_id: String,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?; // FIX: M-08
@@ -295,11 +597,22 @@ pub fn execute_revoke(
         });
     }
 
-    TOKEN_APPROVALS.remove(deps.storage, &token_id);
-
-    Ok(Response::new()
+    let mut res = Response::new()
         .add_attribute("action", "revoke")
-        .add_attribute("token_id", &token_id))
+        .add_attribute("token_id", &token_id);
+    match spender {
+        Some(spender) => {
+            let spender_addr = deps.api.addr_validate(&spender)?;
+            TOKEN_APPROVALS.remove(deps.storage, (&token_id, &spender_addr));
+            let legacy = LEGACY_TOKEN_APPROVALS.may_load(deps.storage, &token_id)?;
+            if legacy.as_ref() == Some(&spender_addr) {
+                LEGACY_TOKEN_APPROVALS.remove(deps.storage, &token_id);
+            }
+            res = res.add_attribute("spender", spender_addr.as_str());
+        }
+        None => clear_approvals(deps.storage, &token_id)?,
+    }
+    Ok(res)
 }
 
 pub fn execute_approve_all(
@@ -341,104 +654,196 @@ pub fn execute_propose_minter(
     _env: Env,
     info: MessageInfo,
     new_minter: String,
+    replaces: Option<String>,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
-    assert_owner(deps.as_ref(), &info.sender)?;
+    let proposed_minter = deps.api.addr_validate(&new_minter)?;
+    let replaces = match replaces {
+        Some(address) => {
+            let minter = deps.api.addr_validate(&address)?;
+            if !MINTERS.has(deps.storage, &minter) {
+                return Err(ContractError::NotMinter { address });
+            }
+            Some(minter)
+        }
+        None => None,
+    };
+    Ok(MINTER_TRANSFER.propose(
+        deps,
+        &info,
+        &CONFIG,
+        PendingMinterTransfer {
+            proposed_minter,
+            replaces,
+        },
+    )?)
+}
 
-    if PENDING_MINTER.may_load(deps.storage)?.is_some() {
-        return Err(ContractError::MinterTransferAlreadyPending);
+pub fn execute_accept_minter(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    // Accepting adds to the minter set rather than replacing a config field.
+    // A rotated-out key is revoked and its quota carries over, so rotation
+    // never lifts a limit the owner had set.
+    let pending = MINTER_TRANSFER.take(deps.storage, &info)?;
+    let mut res = Response::new()
+        .add_attribute("action", "accept_minter")
+        .add_attribute("new_minter", pending.proposed_minter.as_str());
+    let mut daily_quota = None;
+    if let Some(old) = &pending.replaces {
+        let Some(replaced) = MINTERS.may_load(deps.storage, old)? else {
+            return Err(ContractError::NotMinter {
+                address: old.to_string(),
+            });
+        };
+        MINTERS.remove(deps.storage, old);
+        daily_quota = replaced.daily_quota;
+        res = res.add_attribute("replaced", old.as_str());
     }
-
-    let proposed = deps.api.addr_validate(&new_minter)?;
-    PENDING_MINTER.save(
+    MINTERS.save(
         deps.storage,
-        &PendingMinterTransfer {
-            proposed_minter: proposed.clone(),
-        },
+        &pending.proposed_minter,
+        &MinterInfo { daily_quota },
     )?;
 
-    Ok(Response::new()
-        .add_attribute("action", "propose_minter")
-        .add_attribute("proposed_minter", proposed.as_str()))
+    Ok(res)
 }
 
-pub fn execute_accept_minter(
+pub fn execute_cancel_minter_transfer(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
-    let pending = PENDING_MINTER
-        .may_load(deps.storage)?
-        .ok_or(ContractError::NoMinterTransferPending)?;
+    Ok(MINTER_TRANSFER.cancel(deps, &info, &CONFIG)?)
+}
+
+/// Authorize another minter key directly (owner only)
+pub fn execute_add_minter(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+    daily_quota: Option<u32>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
 
-    if info.sender != pending.proposed_minter {
-        return Err(ContractError::NotPendingMinter);
+    let minter = deps.api.addr_validate(&address)?;
+    if MINTERS.has(deps.storage, &minter) {
+        return Err(ContractError::AlreadyMinter { address });
     }
+    MINTERS.save(deps.storage, &minter, &MinterInfo { daily_quota })?;
 
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.minter = pending.proposed_minter.clone();
-        Ok(c)
-    })?;
-    PENDING_MINTER.remove(deps.storage);
+    Ok(Response::new()
+        .add_attribute("action", "add_minter")
+        .add_attribute("minter", minter.as_str())
+        .add_attribute("daily_quota", quota_attr(daily_quota)))
+}
+
+pub fn execute_remove_minter(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let minter = deps.api.addr_validate(&address)?;
+    if !MINTERS.has(deps.storage, &minter) {
+        return Err(ContractError::NotMinter { address });
+    }
+    MINTERS.remove(deps.storage, &minter);
 
     Ok(Response::new()
-        .add_attribute("action", "accept_minter")
-        .add_attribute("new_minter", pending.proposed_minter.as_str()))
+        .add_attribute("action", "remove_minter")
+        .add_attribute("minter", minter.as_str()))
 }
 
-pub fn execute_cancel_minter_transfer(
+/// Usage already counted in the window still applies under the new quota
+pub fn execute_set_minter_quota(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    address: String,
+    daily_quota: Option<u32>,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
+    reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
 
-    if PENDING_MINTER.may_load(deps.storage)?.is_none() {
-        return Err(ContractError::NoMinterTransferPending);
+    let minter = deps.api.addr_validate(&address)?;
+    if !MINTERS.has(deps.storage, &minter) {
+        return Err(ContractError::NotMinter { address });
     }
+    MINTERS.save(deps.storage, &minter, &MinterInfo { daily_quota })?;
 
-    PENDING_MINTER.remove(deps.storage);
+    Ok(Response::new()
+        .add_attribute("action", "set_minter_quota")
+        .add_attribute("minter", minter.as_str())
+        .add_attribute("daily_quota", quota_attr(daily_quota)))
+}
 
-    Ok(Response::new().add_attribute("action", "cancel_minter_transfer"))
+fn quota_attr(daily_quota: Option<u32>) -> String {
+    daily_quota.map_or("unlimited".to_string(), |q| q.to_string())
 }
 
-pub fn execute_pause(
+/// Register a contract that holds items for players, e.g. the marketplace or
+/// staking contract (owner only)
+pub fn execute_add_escrow_contract(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    address: String,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
+    reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
 
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.paused = true;
-        Ok(c)
-    })?;
+    let contract = deps.api.addr_validate(&address)?;
+    if ESCROW_CONTRACTS.has(deps.storage, &contract) {
+        return Err(ContractError::AlreadyEscrowContract { address });
+    }
+    ESCROW_CONTRACTS.save(deps.storage, &contract, &true)?;
 
-    Ok(Response::new().add_attribute("action", "pause"))
+    Ok(Response::new()
+        .add_attribute("action", "add_escrow_contract")
+        .add_attribute("contract", contract.as_str()))
 }
 
-pub fn execute_unpause(
+pub fn execute_remove_escrow_contract(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    address: String,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?; // FIX: M-08
+    reject_funds(&info)?;
     assert_owner(deps.as_ref(), &info.sender)?;
 
-    let config = CONFIG.load(deps.storage)?;
-    if !config.paused {
-        return Err(ContractError::NotPaused);
+    let contract = deps.api.addr_validate(&address)?;
+    if !ESCROW_CONTRACTS.has(deps.storage, &contract) {
+        return Err(ContractError::NotEscrowContract { address });
     }
+    ESCROW_CONTRACTS.remove(deps.storage, &contract);
 
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.paused = false;
-        Ok(c)
-    })?;
+    Ok(Response::new()
+        .add_attribute("action", "remove_escrow_contract")
+        .add_attribute("contract", contract.as_str()))
+}
+
+pub fn execute_pause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(sysbreak_common::execute_pause(deps, &info, &CONFIG)?)
+}
 
-    Ok(Response::new().add_attribute("action", "unpause"))
+pub fn execute_unpause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(sysbreak_common::execute_unpause(deps, &info, &CONFIG)?)
 }
 
 pub fn execute_update_royalty(
@@ -466,34 +871,108 @@ pub fn execute_update_royalty(
         .add_attribute("royalty_recipient", recipient.as_str()))
 }
 
+pub fn execute_set_type_royalty(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    item_type: String,
+    royalty_bps: u16,
+    royalty_recipient: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+    validate_royalty_bps(royalty_bps)?;
+
+    let recipient = deps.api.addr_validate(&royalty_recipient)?;
+    TYPE_ROYALTIES.save(
+        deps.storage,
+        &item_type,
+        &TypeRoyalty {
+            royalty_bps,
+            royalty_recipient: recipient.clone(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_type_royalty")
+        .add_attribute("item_type", &item_type)
+        .add_attribute("royalty_bps", royalty_bps.to_string())
+        .add_attribute("royalty_recipient", recipient.as_str()))
+}
+
+pub fn execute_remove_type_royalty(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    item_type: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    TYPE_ROYALTIES.remove(deps.storage, &item_type);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_type_royalty")
+        .add_attribute("item_type", &item_type))
+}
+
 // FIX: L-02 — burn function (minter only)
 pub fn execute_burn(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     token_id: String,
 ) -> Result<Response, ContractError> {
     reject_funds(&info)?;
     assert_minter(deps.as_ref(), &info.sender)?;
 
-    let owner = TOKEN_OWNERS.load(deps.storage, &token_id).map_err(|_| {
+    // An item sitting in a trade has to stay until the trade settles or is cancelled
+    if let Some(owner) = TOKEN_OWNERS.may_load(deps.storage, &token_id)? {
+        assert_not_escrowed(deps.as_ref(), &env, &owner, &token_id)?;
+    }
+    let owner = burn_single(deps, &token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "burn")
+        .add_event(BurnEvent {
+            token_id,
+            owner: owner.to_string(),
+            sender: info.sender.to_string(),
+        }))
+}
+
+/// Internal helper: remove a token and every index entry pointing at it.
+/// Returns the last owner.
+fn burn_single(deps: DepsMut, token_id: &str) -> Result<Addr, ContractError> {
+    let owner = TOKEN_OWNERS.load(deps.storage, token_id).map_err(|_| {
         ContractError::TokenNotFound {
-            token_id: token_id.clone(),
+            token_id: token_id.to_string(),
         }
     })?;
 
-    TOKENS.remove(deps.storage, &token_id);
-    TOKEN_OWNERS.remove(deps.storage, &token_id);
-    TOKEN_APPROVALS.remove(deps.storage, &token_id);
-    OWNER_TOKENS.remove(deps.storage, (&owner, &token_id));
+    let data = TOKENS.load(deps.storage, token_id)?;
+    TYPE_TOKENS.remove(deps.storage, (&data.metadata.item_type, token_id));
+    RARITY_TOKENS.remove(deps.storage, (&data.metadata.rarity, token_id));
+    TOKENS.remove(deps.storage, token_id);
+    TOKEN_OWNERS.remove(deps.storage, token_id);
+    clear_approvals(deps.storage, token_id)?;
+    remove_listing(deps.storage, token_id);
+    TOKEN_HISTORY.remove(deps.storage, token_id);
+    OWNER_TOKENS.remove(deps.storage, (&owner, token_id));
+    // Attached items fall out to the owner; a burned child leaves its socket empty
+    for child_id in children_of(deps.as_ref(), token_id)? {
+        ITEM_PARENT.remove(deps.storage, &child_id);
+        ITEM_CHILDREN.remove(deps.storage, (token_id, &child_id));
+    }
+    if let Some(parent_id) = ITEM_PARENT.may_load(deps.storage, token_id)? {
+        ITEM_PARENT.remove(deps.storage, token_id);
+        ITEM_CHILDREN.remove(deps.storage, (&parent_id, token_id));
+    }
 
     let mut count = TOKEN_COUNT.load(deps.storage)?;
     count = count.saturating_sub(1);
     TOKEN_COUNT.save(deps.storage, &count)?;
-
-    Ok(Response::new()
-        .add_attribute("action", "burn")
-        .add_attribute("token_id", &token_id))
+    Ok(owner)
 }
 
 // FIX: H-04 — two-step owner transfer
@@ -503,21 +982,13 @@ pub fn execute_propose_owner(
     info: MessageInfo,
     new_owner: String,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?;
-    assert_owner(deps.as_ref(), &info.sender)?;
-    if PENDING_OWNER.may_load(deps.storage)?.is_some() {
-        return Err(ContractError::OwnerTransferAlreadyPending);
-    }
-    let proposed = deps.api.addr_validate(&new_owner)?;
-    PENDING_OWNER.save(
-        deps.storage,
-        &PendingOwnerTransfer {
-            proposed_owner: proposed.clone(),
-        },
-    )?;
-    Ok(Response::new()
-        .add_attribute("action", "propose_owner")
-        .add_attribute("proposed_owner", proposed.as_str()))
+    let proposed_owner = deps.api.addr_validate(&new_owner)?;
+    Ok(OWNER_TRANSFER.propose(
+        deps,
+        &info,
+        &CONFIG,
+        PendingOwnerTransfer { proposed_owner },
+    )?)
 }
 
 pub fn execute_accept_owner(
@@ -525,21 +996,7 @@ pub fn execute_accept_owner(
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?;
-    let pending = PENDING_OWNER
-        .may_load(deps.storage)?
-        .ok_or(ContractError::NoOwnerTransferPending)?;
-    if info.sender != pending.proposed_owner {
-        return Err(ContractError::NotPendingOwner);
-    }
-    CONFIG.update(deps.storage, |mut c| -> StdResult<_> {
-        c.owner = pending.proposed_owner.clone();
-        Ok(c)
-    })?;
-    PENDING_OWNER.remove(deps.storage);
-    Ok(Response::new()
-        .add_attribute("action", "accept_owner")
-        .add_attribute("new_owner", pending.proposed_owner.as_str()))
+    Ok(OWNER_TRANSFER.accept(deps, &info, &CONFIG)?)
 }
 
 pub fn execute_cancel_owner_transfer(
@@ -547,13 +1004,7 @@ pub fn execute_cancel_owner_transfer(
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    reject_funds(&info)?;
-    assert_owner(deps.as_ref(), &info.sender)?;
-    if PENDING_OWNER.may_load(deps.storage)?.is_none() {
-        return Err(ContractError::NoOwnerTransferPending);
-    }
-    PENDING_OWNER.remove(deps.storage);
-    Ok(Response::new().add_attribute("action", "cancel_owner_transfer"))
+    Ok(OWNER_TRANSFER.cancel(deps, &info, &CONFIG)?)
 }
 
 // FIX: I-01 — emergency fund sweep
@@ -579,35 +1030,1205 @@ pub fn execute_sweep_funds(
         .add_attribute("recipient", recipient_addr.as_str()))
 }
 
-// ─── Queries ────────────────────────────────────────────────────────────────
-
-pub fn query_config(deps: Deps) -> StdResult<Binary> {
-    let config = CONFIG.load(deps.storage)?;
-    to_json_binary(&config)
-}
+/// Lock an equipped item in place (minter only). Clears any pending approval and
+/// sale listing so the item cannot be moved the moment it is unlocked. An
+/// escrowed item cannot be locked, or its holder could never hand it back.
+pub fn execute_lock(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
 
-pub fn query_nft_info(deps: Deps, token_id: String) -> StdResult<Binary> {
-    let data = TOKENS.load(deps.storage, &token_id)?;
-    let owner = TOKEN_OWNERS.load(deps.storage, &token_id)?;
-    let approval = TOKEN_APPROVALS
-        .may_load(deps.storage, &token_id)?
-        .map(|a| a.to_string());
+    if let Some(owner) = TOKEN_OWNERS.may_load(deps.storage, &token_id)? {
+        assert_not_escrowed(deps.as_ref(), &env, &owner, &token_id)?;
+    }
+    let mut data = TOKENS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if data.locked {
+        return Err(ContractError::TokenLocked { token_id });
+    }
+    data.locked = true;
+    TOKENS.save(deps.storage, &token_id, &data)?;
+    clear_approvals(deps.storage, &token_id)?;
+    remove_listing(deps.storage, &token_id);
 
-    to_json_binary(&NftInfoResponse {
-        token_id,
-        owner: owner.to_string(),
-        metadata: data.metadata,
-        token_uri: data.token_uri,
-        approval,
-    })
+    Ok(Response::new()
+        .add_attribute("action", "lock")
+        .add_attribute("token_id", &token_id))
 }
 
-pub fn query_owner_of(deps: Deps, token_id: String) -> StdResult<Binary> {
+pub fn execute_unlock(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
+
+    let mut data = TOKENS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if !data.locked {
+        return Err(ContractError::TokenNotLocked { token_id });
+    }
+    data.locked = false;
+    TOKENS.save(deps.storage, &token_id, &data)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unlock")
+        .add_attribute("token_id", &token_id))
+}
+
+/// Mutate level and stats in place (minter only). Every delta is applied with
+/// checked arithmetic before anything is saved; before/after values are emitted.
+pub fn execute_upgrade_item(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+    new_level: u32,
+    stat_changes: std::collections::BTreeMap<String, i64>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
+
+    let mut data = TOKENS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if is_metadata_frozen(deps.as_ref(), &token_id)? {
+        return Err(ContractError::MetadataFrozen { token_id });
+    }
+    let old_level = data.metadata.level;
+    if new_level < old_level {
+        return Err(ContractError::LevelDowngrade {
+            current: old_level,
+            requested: new_level,
+        });
+    }
+
+    let mut res = Response::new()
+        .add_attribute("action", "upgrade_item")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("old_level", old_level.to_string())
+        .add_attribute("new_level", new_level.to_string());
+    for (stat, delta) in stat_changes {
+        let old = data.metadata.stats.get(&stat).copied().unwrap_or_default();
+        let new = if delta >= 0 {
+            old.checked_add(delta as u64)
+        } else {
+            old.checked_sub(delta.unsigned_abs())
+        }
+        .ok_or_else(|| ContractError::StatOutOfRange {
+            stat: stat.clone(),
+            delta,
+        })?;
+        res = res
+            .add_attribute(format!("old_stat_{}", stat), old.to_string())
+            .add_attribute(format!("new_stat_{}", stat), new.to_string());
+        data.metadata.stats.insert(stat, new);
+    }
+    validate_stats(deps.storage, &data.metadata.item_type, &data.metadata.stats)?;
+    data.metadata.level = new_level;
+    TOKENS.save(deps.storage, &token_id, &data)?;
+
+    Ok(res)
+}
+
+/// Replace a token's metadata URI. The hash of the new document must be
+/// supplied, and the previous URI and hash are emitted so indexers can follow
+/// the chain of commitments.
+pub fn execute_update_metadata(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+    token_uri: String,
+    metadata_hash: HexBinary,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
+
+    let mut data = TOKENS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if is_metadata_frozen(deps.as_ref(), &token_id)? {
+        return Err(ContractError::MetadataFrozen { token_id });
+    }
+    validate_metadata_hash(&metadata_hash)?;
+
+    let old_uri = data.token_uri.replace(token_uri.clone());
+    let old_hash = data.metadata_hash.replace(metadata_hash.clone());
+    TOKENS.save(deps.storage, &token_id, &data)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_metadata")
+        .add_attribute("token_id", token_id)
+        .add_attribute("old_token_uri", old_uri.unwrap_or_default())
+        .add_attribute("new_token_uri", token_uri)
+        .add_attribute(
+            "old_metadata_hash",
+            old_hash.map(|h| h.to_hex()).unwrap_or_default(),
+        )
+        .add_attribute("new_metadata_hash", metadata_hash.to_hex()))
+}
+
+/// Commit to never changing a token's level or stats again (owner only).
+/// Durability wear and repair are gameplay state and stay enabled.
+pub fn execute_freeze_token_metadata(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let mut data = TOKENS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if is_metadata_frozen(deps.as_ref(), &token_id)? {
+        return Err(ContractError::MetadataFrozen { token_id });
+    }
+    data.metadata_frozen = true;
+    TOKENS.save(deps.storage, &token_id, &data)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "freeze_token_metadata")
+        .add_attribute("token_id", &token_id))
+}
+
+/// Freeze level and stats collection-wide, including tokens minted later (owner only).
+pub fn execute_freeze_all_metadata(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    ALL_METADATA_FROZEN.save(deps.storage, &true)?;
+
+    Ok(Response::new().add_attribute("action", "freeze_all_metadata"))
+}
+
+/// Wear an item down (minter only). Durability saturates at zero; a broken item
+/// stays frozen until the owner repairs it. Listed and escrowed items are left
+/// alone so a buyer or escrow holder gets the item as it was.
+pub fn execute_apply_wear(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    amount: u32,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
+
+    let owner = TOKEN_OWNERS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    assert_not_escrowed(deps.as_ref(), &env, &owner, &token_id)?;
+    assert_not_listed(deps.as_ref(), &token_id)?;
+    let mut data = TOKENS.load(deps.storage, &token_id)?;
+    let old = data
+        .metadata
+        .durability
+        .ok_or_else(|| ContractError::NoDurability {
+            token_id: token_id.clone(),
+        })?;
+    let new = old.saturating_sub(amount);
+    data.metadata.durability = Some(new);
+    TOKENS.save(deps.storage, &token_id, &data)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "apply_wear")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("old_durability", old.to_string())
+        .add_attribute("new_durability", new.to_string()))
+}
+
+/// Restore full durability (token owner). The rarity's repair fee, if any, must
+/// be paid exactly and is forwarded to the collection royalty recipient.
+pub fn execute_repair(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    assert_not_paused(deps.as_ref())?;
+
+    let owner = TOKEN_OWNERS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if info.sender != owner {
+        return Err(ContractError::Unauthorized {
+            role: "token owner".to_string(),
+        });
+    }
+    let mut data = TOKENS.load(deps.storage, &token_id)?;
+    let max = data
+        .metadata
+        .max_durability
+        .ok_or_else(|| ContractError::NoDurability {
+            token_id: token_id.clone(),
+        })?;
+    if data.metadata.durability == Some(max) {
+        return Err(ContractError::ItemNotDamaged { token_id });
+    }
+
+    let fee = REPAIR_FEES.may_load(deps.storage, &data.metadata.rarity)?;
+    let mut res = Response::new();
+    match &fee {
+        Some(fee) => {
+            if info.funds.len() != 1 || info.funds[0] != *fee {
+                return Err(ContractError::IncorrectPayment {
+                    expected: fee.to_string(),
+                });
+            }
+            let config = CONFIG.load(deps.storage)?;
+            res = res.add_message(BankMsg::Send {
+                to_address: config.royalty_recipient.to_string(),
+                amount: vec![fee.clone()],
+            });
+        }
+        None => reject_funds(&info)?,
+    }
+
+    data.metadata.durability = Some(max);
+    TOKENS.save(deps.storage, &token_id, &data)?;
+
+    Ok(res
+        .add_attribute("action", "repair")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("durability", max.to_string())
+        .add_attribute(
+            "fee",
+            fee.map_or_else(|| "0".to_string(), |f| f.to_string()),
+        ))
+}
+
+/// Use up `amount` charges of a consumable (minter or token owner). The game
+/// backend reacts to the emitted attributes; at zero charges the item is burned.
+pub fn execute_consume(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    amount: u32,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    let owner = TOKEN_OWNERS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if info.sender != owner && !MINTERS.has(deps.storage, &info.sender) {
+        return Err(ContractError::Unauthorized {
+            role: "minter or token owner".to_string(),
+        });
+    }
+    // Burning an escrowed item would leave its holder unable to hand it back,
+    // and a listed one would sell with fewer charges than advertised
+    assert_not_escrowed(deps.as_ref(), &env, &owner, &token_id)?;
+    assert_not_listed(deps.as_ref(), &token_id)?;
+    if amount == 0 {
+        return Err(ContractError::InvalidCharges);
+    }
+
+    let mut data = TOKENS.load(deps.storage, &token_id)?;
+    let remaining = data
+        .metadata
+        .charges
+        .ok_or_else(|| ContractError::NotConsumable {
+            token_id: token_id.clone(),
+        })?;
+    if amount > remaining {
+        return Err(ContractError::InsufficientCharges {
+            token_id,
+            remaining,
+        });
+    }
+    let left = remaining - amount;
+
+    let mut res = Response::new()
+        .add_attribute("action", "consume")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("owner", owner.as_str())
+        .add_attribute("item_type", &data.metadata.item_type)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("charges_left", left.to_string());
+    if left == 0 {
+        burn_single(deps, &token_id)?;
+        res = res.add_event(BurnEvent {
+            token_id,
+            owner: owner.to_string(),
+            sender: info.sender.to_string(),
+        });
+    } else {
+        data.metadata.charges = Some(left);
+        TOKENS.save(deps.storage, &token_id, &data)?;
+    }
+
+    Ok(res)
+}
+
+/// Burn the sender's item and record a redemption receipt the game backend
+/// grants an off-chain reward against. Approved spenders and operators cannot
+/// redeem on the owner's behalf.
+pub fn execute_redeem(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    redemption_code: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    let valid_code = !redemption_code.is_empty()
+        && redemption_code.len() <= MAX_REDEMPTION_CODE_LEN
+        && redemption_code.bytes().all(|b| b.is_ascii_graphic());
+    if !valid_code {
+        return Err(ContractError::InvalidRedemptionCode {
+            max: MAX_REDEMPTION_CODE_LEN,
+        });
+    }
+    if REDEMPTION_CODES.has(deps.storage, &redemption_code) {
+        return Err(ContractError::RedemptionCodeUsed {
+            code: redemption_code,
+        });
+    }
+
+    let owner = TOKEN_OWNERS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if owner != info.sender {
+        return Err(ContractError::Unauthorized {
+            role: "token owner".to_string(),
+        });
+    }
+    assert_not_locked(deps.as_ref(), &token_id)?;
+
+    let item = TOKENS.load(deps.storage, &token_id)?.metadata;
+    let redemption_id = LAST_REDEMPTION_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    LAST_REDEMPTION_ID.save(deps.storage, &redemption_id)?;
+    REDEMPTIONS.save(
+        deps.storage,
+        redemption_id,
+        &Redemption {
+            id: redemption_id,
+            token_id: token_id.clone(),
+            owner: owner.clone(),
+            item_type: item.item_type.clone(),
+            rarity: item.rarity,
+            redemption_code: redemption_code.clone(),
+            redeemed_at: env.block.time,
+            acknowledged_at: None,
+            acknowledged_by: None,
+        },
+    )?;
+    PENDING_REDEMPTIONS.save(deps.storage, redemption_id, &())?;
+    REDEMPTION_CODES.save(deps.storage, &redemption_code, &redemption_id)?;
+    burn_single(deps, &token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "redeem")
+        .add_attribute("redemption_id", redemption_id.to_string())
+        .add_attribute("redemption_code", redemption_code)
+        .add_attribute("item_type", item.item_type)
+        .add_event(BurnEvent {
+            token_id,
+            owner: owner.to_string(),
+            sender: info.sender.to_string(),
+        }))
+}
+
+/// Record that the backend granted a redemption's reward (minter only)
+pub fn execute_ack_redemption(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    redemption_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_minter(deps.as_ref(), &info.sender)?;
+
+    let mut redemption = REDEMPTIONS
+        .may_load(deps.storage, redemption_id)?
+        .ok_or(ContractError::RedemptionNotFound { redemption_id })?;
+    if redemption.acknowledged_at.is_some() {
+        return Err(ContractError::RedemptionAcknowledged { redemption_id });
+    }
+    redemption.acknowledged_at = Some(env.block.time);
+    redemption.acknowledged_by = Some(info.sender.clone());
+    REDEMPTIONS.save(deps.storage, redemption_id, &redemption)?;
+    PENDING_REDEMPTIONS.remove(deps.storage, redemption_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "ack_redemption")
+        .add_attribute("redemption_id", redemption_id.to_string())
+        .add_attribute("redemption_code", redemption.redemption_code))
+}
+
+pub fn execute_set_max_supply(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    item_type: String,
+    max_supply: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if let Some(current) = TYPE_MAX_SUPPLY.may_load(deps.storage, &item_type)? {
+        if max_supply > current {
+            return Err(ContractError::InvalidSupplyCap {
+                reason: format!("cannot raise cap above {}", current),
+            });
+        }
+    }
+    let minted = TYPE_MINTED
+        .may_load(deps.storage, &item_type)?
+        .unwrap_or_default();
+    if max_supply < minted {
+        return Err(ContractError::InvalidSupplyCap {
+            reason: format!("{} already minted", minted),
+        });
+    }
+    TYPE_MAX_SUPPLY.save(deps.storage, &item_type, &max_supply)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_max_supply")
+        .add_attribute("item_type", &item_type)
+        .add_attribute("max_supply", max_supply.to_string()))
+}
+
+pub fn execute_set_stat_schema(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    item_type: String,
+    stats: std::collections::BTreeMap<String, StatRange>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if stats.len() > MAX_SCHEMA_STATS {
+        return Err(ContractError::InvalidStatSchema {
+            reason: format!("at most {} stats", MAX_SCHEMA_STATS),
+        });
+    }
+    if let Some((stat, _)) = stats.iter().find(|(_, range)| range.min > range.max) {
+        return Err(ContractError::InvalidStatSchema {
+            reason: format!("{} has min above max", stat),
+        });
+    }
+    STAT_SCHEMAS.save(deps.storage, &item_type, &stats)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_stat_schema")
+        .add_attribute("item_type", &item_type)
+        .add_attribute("stats", stats.len().to_string()))
+}
+
+pub fn execute_remove_stat_schema(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    item_type: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    STAT_SCHEMAS.remove(deps.storage, &item_type);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_stat_schema")
+        .add_attribute("item_type", &item_type))
+}
+
+pub fn execute_set_repair_fee(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    rarity: String,
+    fee: Option<Coin>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    match &fee {
+        Some(fee) if fee.amount.is_zero() => return Err(ContractError::InvalidPrice),
+        Some(fee) => REPAIR_FEES.save(deps.storage, &rarity, fee)?,
+        None => REPAIR_FEES.remove(deps.storage, &rarity),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_repair_fee")
+        .add_attribute("rarity", &rarity)
+        .add_attribute(
+            "fee",
+            fee.map_or_else(|| "none".to_string(), |f| f.to_string()),
+        ))
+}
+
+// ─── Marketplace ────────────────────────────────────────────────────────────
+
+pub fn execute_list_for_sale(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+    price: Coin,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    let owner = TOKEN_OWNERS.load(deps.storage, &token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.clone(),
+        }
+    })?;
+    if info.sender != owner {
+        return Err(ContractError::Unauthorized {
+            role: "token owner".to_string(),
+        });
+    }
+    assert_not_soulbound(deps.as_ref(), &token_id)?;
+    assert_not_locked(deps.as_ref(), &token_id)?;
+    assert_not_broken(deps.as_ref(), &token_id)?;
+    if price.amount.is_zero() {
+        return Err(ContractError::InvalidPrice);
+    }
+
+    LISTINGS.save(
+        deps.storage,
+        &token_id,
+        &Listing {
+            seller: owner.clone(),
+            price: price.clone(),
+        },
+    )?;
+    SELLER_LISTINGS.save(deps.storage, (&owner, &token_id), &true)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "list_for_sale")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("seller", owner.as_str())
+        .add_attribute("price", price.to_string()))
+}
+
+pub fn execute_cancel_listing(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let listing =
+        LISTINGS
+            .may_load(deps.storage, &token_id)?
+            .ok_or_else(|| ContractError::NotListed {
+                token_id: token_id.clone(),
+            })?;
+    if info.sender != listing.seller {
+        return Err(ContractError::Unauthorized {
+            role: "seller".to_string(),
+        });
+    }
+    remove_listing(deps.storage, &token_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_listing")
+        .add_attribute("token_id", &token_id))
+}
+
+/// Settle a listing: the token moves to the buyer before payouts are dispatched.
+/// The royalty follows the item_type override when one is set.
+pub fn execute_buy(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    assert_not_paused(deps.as_ref())?;
+
+    let listing =
+        LISTINGS
+            .may_load(deps.storage, &token_id)?
+            .ok_or_else(|| ContractError::NotListed {
+                token_id: token_id.clone(),
+            })?;
+    if info.sender == listing.seller {
+        return Err(ContractError::SelfPurchase);
+    }
+    if info.funds.len() != 1 || info.funds[0] != listing.price {
+        return Err(ContractError::IncorrectPayment {
+            expected: listing.price.to_string(),
+        });
+    }
+    assert_not_locked(deps.as_ref(), &token_id)?;
+    assert_not_broken(deps.as_ref(), &token_id)?;
+
+    let item_type = TOKENS.load(deps.storage, &token_id)?.metadata.item_type;
+    let royalty_info = effective_royalty(deps.as_ref(), &item_type)?;
+    let price = listing.price.amount;
+    let royalty = price.multiply_ratio(royalty_info.royalty_bps as u128, 10_000u128);
+    let proceeds = price - royalty;
+
+    // Effects before interactions
+    OWNER_TOKENS.remove(deps.storage, (&listing.seller, &token_id));
+    OWNER_TOKENS.save(deps.storage, (&info.sender, &token_id), &true)?;
+    clear_approvals(deps.storage, &token_id)?;
+    remove_listing(deps.storage, &token_id);
+    TOKEN_OWNERS.save(deps.storage, &token_id, &info.sender)?;
+    record_transfer(
+        deps.storage,
+        &token_id,
+        TransferRecord {
+            from: listing.seller.clone(),
+            to: info.sender.clone(),
+            timestamp: env.block.time,
+            price: Some(listing.price.clone()),
+        },
+    )?;
+    let children = move_children(deps.storage, &env, &listing.seller, &info.sender, &token_id)?;
+
+    let denom = listing.price.denom;
+    let mut res = Response::new();
+    if !royalty.is_zero() {
+        res = res.add_message(BankMsg::Send {
+            to_address: royalty_info.royalty_recipient.to_string(),
+            amount: vec![Coin::new(royalty, denom.clone())],
+        });
+    }
+    if !proceeds.is_zero() {
+        res = res.add_message(BankMsg::Send {
+            to_address: listing.seller.to_string(),
+            amount: vec![Coin::new(proceeds, denom.clone())],
+        });
+    }
+
+    Ok(res
+        .add_attribute("action", "buy")
+        .add_attribute("royalty", royalty.to_string())
+        .add_event(SaleEvent {
+            listing_id: token_id.clone(),
+            collection: env.contract.address.to_string(),
+            token_id,
+            seller: listing.seller.to_string(),
+            buyer: info.sender.to_string(),
+            price: price.to_string(),
+            denom,
+        })
+        .add_events(children))
+}
+
+// ─── Trading ────────────────────────────────────────────────────────────────
+
+/// Open a swap. The proposer's offered items move into the contract so they
+/// cannot be sold or transferred while the offer stands; the requested items
+/// are only checked when the counterparty accepts.
+pub fn execute_propose_trade(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    counterparty: String,
+    offered_token_ids: Vec<String>,
+    requested_token_ids: Vec<String>,
+    expiry: Timestamp,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    let counterparty = deps.api.addr_validate(&counterparty)?;
+    if counterparty == info.sender {
+        return Err(ContractError::InvalidTrade {
+            reason: "cannot trade with yourself".to_string(),
+        });
+    }
+    if offered_token_ids.is_empty()
+        || offered_token_ids.len() > MAX_TRADE_TOKENS
+        || requested_token_ids.len() > MAX_TRADE_TOKENS
+    {
+        return Err(ContractError::InvalidTrade {
+            reason: format!(
+                "must offer 1 to {} items and request at most {}",
+                MAX_TRADE_TOKENS, MAX_TRADE_TOKENS
+            ),
+        });
+    }
+    if expiry <= env.block.time {
+        return Err(ContractError::InvalidTrade {
+            reason: "expiry must be in the future".to_string(),
+        });
+    }
+    let all_ids: Vec<&String> = offered_token_ids
+        .iter()
+        .chain(&requested_token_ids)
+        .collect();
+    for (i, token_id) in all_ids.iter().enumerate() {
+        if all_ids[..i].contains(token_id) {
+            return Err(ContractError::DuplicateToken {
+                token_id: token_id.to_string(),
+            });
+        }
+    }
+
+    // Validate every offered item before escrowing any of them
+    for token_id in &offered_token_ids {
+        let owner = TOKEN_OWNERS.load(deps.storage, token_id).map_err(|_| {
+            ContractError::TokenNotFound {
+                token_id: token_id.clone(),
+            }
+        })?;
+        if owner != info.sender {
+            return Err(ContractError::Unauthorized {
+                role: "token owner".to_string(),
+            });
+        }
+        assert_can_transfer(deps.as_ref(), &env.block, &info.sender, token_id)?;
+    }
+    let escrow = env.contract.address.clone();
+    for token_id in &offered_token_ids {
+        move_token(deps.branch(), &env, &info.sender, &escrow, token_id)?;
+    }
+
+    let trade_id = LAST_TRADE_ID.may_load(deps.storage)?.unwrap_or_default() + 1;
+    LAST_TRADE_ID.save(deps.storage, &trade_id)?;
+    TRADES.save(
+        deps.storage,
+        trade_id,
+        &Trade {
+            proposer: info.sender.clone(),
+            counterparty: counterparty.clone(),
+            offered_token_ids: offered_token_ids.clone(),
+            requested_token_ids,
+            expiry,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_trade")
+        .add_attribute("trade_id", trade_id.to_string())
+        .add_attribute("proposer", info.sender.as_str())
+        .add_attribute("counterparty", counterparty.as_str())
+        .add_attribute("offered", offered_token_ids.join(",")))
+}
+
+/// Complete a swap (counterparty only). Every requested item is checked before
+/// anything moves.
+pub fn execute_accept_trade(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    trade_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    let trade = TRADES
+        .may_load(deps.storage, trade_id)?
+        .ok_or(ContractError::TradeNotFound { trade_id })?;
+    if info.sender != trade.counterparty {
+        return Err(ContractError::Unauthorized {
+            role: "counterparty".to_string(),
+        });
+    }
+    if env.block.time >= trade.expiry {
+        return Err(ContractError::TradeExpired { trade_id });
+    }
+    for token_id in &trade.requested_token_ids {
+        let owner = TOKEN_OWNERS.load(deps.storage, token_id).map_err(|_| {
+            ContractError::TokenNotFound {
+                token_id: token_id.clone(),
+            }
+        })?;
+        if owner != info.sender {
+            return Err(ContractError::Unauthorized {
+                role: "token owner".to_string(),
+            });
+        }
+        assert_can_transfer(deps.as_ref(), &env.block, &info.sender, token_id)?;
+    }
+
+    TRADES.remove(deps.storage, trade_id);
+    let escrow = env.contract.address.clone();
+    let mut events = vec![];
+    for token_id in &trade.offered_token_ids {
+        move_token(deps.branch(), &env, &escrow, &trade.counterparty, token_id)?;
+        events.push(TransferEvent {
+            token_id: token_id.clone(),
+            from: trade.proposer.to_string(),
+            to: trade.counterparty.to_string(),
+        });
+        for child_id in children_of(deps.as_ref(), token_id)? {
+            events.push(TransferEvent {
+                token_id: child_id,
+                from: trade.proposer.to_string(),
+                to: trade.counterparty.to_string(),
+            });
+        }
+    }
+    for token_id in &trade.requested_token_ids {
+        let children = move_token(
+            deps.branch(),
+            &env,
+            &trade.counterparty,
+            &trade.proposer,
+            token_id,
+        )?;
+        events.push(TransferEvent {
+            token_id: token_id.clone(),
+            from: trade.counterparty.to_string(),
+            to: trade.proposer.to_string(),
+        });
+        events.extend(children);
+    }
+
+    Ok(Response::new()
+        .add_events(events)
+        .add_attribute("action", "accept_trade")
+        .add_attribute("trade_id", trade_id.to_string())
+        .add_attribute("proposer", trade.proposer.as_str())
+        .add_attribute("counterparty", trade.counterparty.as_str()))
+}
+
+/// Close a trade and return the escrowed items to the proposer. Either party
+/// may cancel, whether or not the trade has expired. An escrowed item that is
+/// somehow gone is skipped so the rest still go back.
+pub fn execute_cancel_trade(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    trade_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+
+    let trade = TRADES
+        .may_load(deps.storage, trade_id)?
+        .ok_or(ContractError::TradeNotFound { trade_id })?;
+    if info.sender != trade.proposer && info.sender != trade.counterparty {
+        return Err(ContractError::Unauthorized {
+            role: "proposer or counterparty".to_string(),
+        });
+    }
+
+    TRADES.remove(deps.storage, trade_id);
+    let escrow = env.contract.address.clone();
+    let mut returned = vec![];
+    for token_id in &trade.offered_token_ids {
+        if !TOKENS.has(deps.storage, token_id) {
+            continue;
+        }
+        move_token(deps.branch(), &env, &escrow, &trade.proposer, token_id)?;
+        returned.push(token_id.as_str());
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_trade")
+        .add_attribute("trade_id", trade_id.to_string())
+        .add_attribute("cancelled_by", info.sender.as_str())
+        .add_attribute("returned", returned.join(",")))
+}
+
+// ─── Crafting ───────────────────────────────────────────────────────────────
+
+pub fn execute_register_recipe(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    recipe_id: String,
+    recipe: Recipe,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if recipe.inputs.is_empty() || recipe.inputs.len() > MAX_RECIPE_INPUTS {
+        return Err(ContractError::InvalidRecipe {
+            reason: format!("must have 1 to {} inputs", MAX_RECIPE_INPUTS),
+        });
+    }
+    RECIPES.save(deps.storage, &recipe_id, &recipe)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_recipe")
+        .add_attribute("recipe_id", &recipe_id)
+        .add_attribute("inputs", recipe.inputs.len().to_string())
+        .add_attribute("output_item_type", &recipe.output.item_type))
+}
+
+pub fn execute_remove_recipe(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    recipe_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    if !RECIPES.has(deps.storage, &recipe_id) {
+        return Err(ContractError::RecipeNotFound { recipe_id });
+    }
+    RECIPES.remove(deps.storage, &recipe_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_recipe")
+        .add_attribute("recipe_id", &recipe_id))
+}
+
+/// Burn the sender's input items and mint the recipe output to them. Every
+/// input is checked before anything is burned. Approved spenders and operators
+/// cannot craft on the owner's behalf.
+pub fn execute_combine(
+    mut deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_ids: Vec<String>,
+    recipe_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    let recipe = RECIPES
+        .may_load(deps.storage, &recipe_id)?
+        .ok_or_else(|| ContractError::RecipeNotFound {
+            recipe_id: recipe_id.clone(),
+        })?;
+    if token_ids.len() != recipe.inputs.len() {
+        return Err(ContractError::WrongInputCount {
+            expected: recipe.inputs.len() as u32,
+            got: token_ids.len() as u32,
+        });
+    }
+
+    for (slot, (token_id, input)) in token_ids.iter().zip(&recipe.inputs).enumerate() {
+        if token_ids[..slot].contains(token_id) {
+            return Err(ContractError::DuplicateToken {
+                token_id: token_id.clone(),
+            });
+        }
+        let owner = TOKEN_OWNERS.load(deps.storage, token_id).map_err(|_| {
+            ContractError::TokenNotFound {
+                token_id: token_id.clone(),
+            }
+        })?;
+        if owner != info.sender {
+            return Err(ContractError::Unauthorized {
+                role: "token owner".to_string(),
+            });
+        }
+        assert_not_locked(deps.as_ref(), token_id)?;
+        let item = TOKENS.load(deps.storage, token_id)?.metadata;
+        let rarity_ok = input.rarity.as_ref().map_or(true, |r| *r == item.rarity);
+        if item.item_type != input.item_type || !rarity_ok || item.level < input.min_level {
+            return Err(ContractError::RecipeInputMismatch {
+                token_id: token_id.clone(),
+                slot: slot as u32,
+            });
+        }
+    }
+
+    let mut events = Vec::with_capacity(token_ids.len() + 1);
+    for token_id in &token_ids {
+        let owner = burn_single(deps.branch(), token_id)?;
+        events.push(Event::from(BurnEvent {
+            token_id: token_id.clone(),
+            owner: owner.to_string(),
+            sender: info.sender.to_string(),
+        }));
+    }
+    let output = recipe.output;
+    let token_id = mint_single(
+        deps,
+        &info.sender,
+        output.item_type,
+        output.rarity,
+        output.level,
+        output.stats,
+        format!("crafted:{}", recipe_id),
+        output.token_uri,
+        output.max_durability,
+        output.soulbound,
+        output.charges,
+        output.metadata_hash,
+    )?;
+
+    events.push(Event::from(MintEvent {
+        token_id,
+        owner: info.sender.to_string(),
+        minter: info.sender.to_string(),
+    }));
+
+    Ok(Response::new()
+        .add_attribute("action", "combine")
+        .add_attribute("recipe_id", &recipe_id)
+        .add_events(events))
+}
+
+// ─── Attachments ────────────────────────────────────────────────────────────
+
+/// Ids of the items attached to `token_id`.
+fn children_of(deps: Deps, token_id: &str) -> StdResult<Vec<String>> {
+    ITEM_CHILDREN
+        .prefix(token_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect()
+}
+
+/// Socket `child_token_id` into `parent_token_id` (owner of both). Nesting is
+/// one level deep: a parent cannot itself be attached, and an item that holds
+/// attachments cannot be socketed.
+pub fn execute_attach(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    parent_token_id: String,
+    child_token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    if parent_token_id == child_token_id {
+        return Err(ContractError::InvalidAttachment {
+            reason: "cannot attach an item to itself".to_string(),
+        });
+    }
+    for token_id in [&parent_token_id, &child_token_id] {
+        let owner = TOKEN_OWNERS.load(deps.storage, token_id).map_err(|_| {
+            ContractError::TokenNotFound {
+                token_id: token_id.clone(),
+            }
+        })?;
+        if owner != info.sender {
+            return Err(ContractError::Unauthorized {
+                role: "token owner".to_string(),
+            });
+        }
+    }
+    // A soulbound child would otherwise leave with a transferable parent
+    assert_not_soulbound(deps.as_ref(), &child_token_id)?;
+    assert_not_locked(deps.as_ref(), &child_token_id)?;
+    if ITEM_PARENT.has(deps.storage, &parent_token_id) {
+        return Err(ContractError::InvalidAttachment {
+            reason: "parent is itself attached".to_string(),
+        });
+    }
+    if !children_of(deps.as_ref(), &child_token_id)?.is_empty() {
+        return Err(ContractError::InvalidAttachment {
+            reason: "child has attachments of its own".to_string(),
+        });
+    }
+    if children_of(deps.as_ref(), &parent_token_id)?.len() >= MAX_ATTACHMENTS {
+        return Err(ContractError::InvalidAttachment {
+            reason: format!("at most {} items per parent", MAX_ATTACHMENTS),
+        });
+    }
+
+    ITEM_PARENT.save(deps.storage, &child_token_id, &parent_token_id)?;
+    ITEM_CHILDREN.save(deps.storage, (&parent_token_id, &child_token_id), &true)?;
+    clear_approvals(deps.storage, &child_token_id)?;
+    remove_listing(deps.storage, &child_token_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "attach")
+        .add_attribute("parent_token_id", &parent_token_id)
+        .add_attribute("child_token_id", &child_token_id))
+}
+
+pub fn execute_detach(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    child_token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    let parent_token_id = ITEM_PARENT
+        .may_load(deps.storage, &child_token_id)?
+        .ok_or_else(|| ContractError::NotAttached {
+            token_id: child_token_id.clone(),
+        })?;
+    if TOKEN_OWNERS.load(deps.storage, &child_token_id)? != info.sender {
+        return Err(ContractError::Unauthorized {
+            role: "token owner".to_string(),
+        });
+    }
+
+    ITEM_PARENT.remove(deps.storage, &child_token_id);
+    ITEM_CHILDREN.remove(deps.storage, (&parent_token_id, &child_token_id));
+
+    Ok(Response::new()
+        .add_attribute("action", "detach")
+        .add_attribute("parent_token_id", &parent_token_id)
+        .add_attribute("child_token_id", &child_token_id))
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+pub fn query_config(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_json_binary(&config)
+}
+
+/// Spenders holding a live approval on the token
+fn approved_spenders(deps: Deps, env: &Env, token_id: &str) -> StdResult<Vec<String>> {
+    Ok(live_approvals(deps.storage, &env.block, token_id)?
+        .into_iter()
+        .map(|(spender, _)| spender.to_string())
+        .collect())
+}
+
+fn nft_info(deps: Deps, env: &Env, token_id: String, owner: &Addr) -> StdResult<NftInfoResponse> {
+    let data = TOKENS.load(deps.storage, &token_id)?;
+    let approvals = approved_spenders(deps, env, &token_id)?;
+
+    Ok(NftInfoResponse {
+        token_id,
+        owner: owner.to_string(),
+        metadata: data.metadata,
+        token_uri: data.token_uri,
+        approvals,
+        locked: data.locked,
+        soulbound: data.soulbound,
+    })
+}
+
+pub fn query_nft_info(deps: Deps, env: Env, token_id: String) -> StdResult<Binary> {
     let owner = TOKEN_OWNERS.load(deps.storage, &token_id)?;
-    let approval = TOKEN_APPROVALS
-        .may_load(deps.storage, &token_id)?
-        .map(|a| a.to_string());
-    let approvals = approval.into_iter().collect();
+    to_json_binary(&nft_info(deps, &env, token_id, &owner)?)
+}
+
+pub fn query_owner_of(deps: Deps, env: Env, token_id: String) -> StdResult<Binary> {
+    let owner = TOKEN_OWNERS.load(deps.storage, &token_id)?;
+    let approvals = approved_spenders(deps, &env, &token_id)?;
 
     to_json_binary(&OwnerOfResponse {
         owner: owner.to_string(),
@@ -637,6 +2258,86 @@ pub fn query_tokens(
     to_json_binary(&TokensResponse { tokens })
 }
 
+/// Same page as `query_tokens`, with each token's full info instead of just its id.
+pub fn query_tokens_with_info(
+    deps: Deps,
+    env: Env,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let tokens = OWNER_TOKENS
+        .prefix(&owner_addr)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|token_id| nft_info(deps, &env, token_id?, &owner_addr))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&TokensWithInfoResponse { tokens })
+}
+
+pub fn query_tokens_by_type(
+    deps: Deps,
+    item_type: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let tokens: Vec<String> = TYPE_TOKENS
+        .prefix(&item_type)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&TokensResponse { tokens })
+}
+
+pub fn query_tokens_by_rarity(
+    deps: Deps,
+    rarity: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let tokens: Vec<String> = RARITY_TOKENS
+        .prefix(&rarity)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&TokensResponse { tokens })
+}
+
+pub fn query_type_supply(deps: Deps, item_type: String) -> StdResult<Binary> {
+    let minted = TYPE_MINTED
+        .may_load(deps.storage, &item_type)?
+        .unwrap_or_default();
+    let max_supply = TYPE_MAX_SUPPLY.may_load(deps.storage, &item_type)?;
+    to_json_binary(&TypeSupplyResponse {
+        item_type,
+        minted,
+        max_supply,
+    })
+}
+
+pub fn query_stat_schema(deps: Deps, item_type: String) -> StdResult<Binary> {
+    to_json_binary(&STAT_SCHEMAS.may_load(deps.storage, &item_type)?)
+}
+
 pub fn query_all_tokens(
     deps: Deps,
     start_after: Option<String>,
@@ -667,14 +2368,61 @@ pub fn query_royalty_info(deps: Deps) -> StdResult<Binary> {
     })
 }
 
-pub fn query_approval(deps: Deps, token_id: String, spender: String) -> StdResult<Binary> {
+pub fn query_royalty_info_for_token(deps: Deps, token_id: String) -> StdResult<Binary> {
+    let item_type = TOKENS.load(deps.storage, &token_id)?.metadata.item_type;
+    let royalty = effective_royalty(deps, &item_type)?;
+    to_json_binary(&RoyaltyInfoResponse {
+        royalty_bps: royalty.royalty_bps,
+        royalty_recipient: royalty.royalty_recipient.to_string(),
+    })
+}
+
+/// cw2981: the royalty is computed from the token's effective basis points,
+/// matching what the built-in marketplace charges.
+pub fn query_cw2981(deps: Deps, msg: Cw2981QueryMsg) -> StdResult<Binary> {
+    match msg {
+        Cw2981QueryMsg::RoyaltyInfo {
+            token_id,
+            sale_price,
+        } => {
+            let item_type = TOKENS.load(deps.storage, &token_id)?.metadata.item_type;
+            let royalty = effective_royalty(deps, &item_type)?;
+            to_json_binary(&RoyaltiesInfoResponse {
+                address: royalty.royalty_recipient.to_string(),
+                royalty_amount: sale_price.multiply_ratio(royalty.royalty_bps as u128, 10_000u128),
+            })
+        }
+        Cw2981QueryMsg::CheckRoyalties {} => to_json_binary(&CheckRoyaltiesResponse {
+            royalty_payments: true,
+        }),
+    }
+}
+
+pub fn query_approval(
+    deps: Deps,
+    env: Env,
+    token_id: String,
+    spender: String,
+) -> StdResult<Binary> {
     let spender_addr = deps.api.addr_validate(&spender)?;
-    let approved = TOKEN_APPROVALS
-        .may_load(deps.storage, &token_id)?
-        .map(|a| a == spender_addr)
-        .unwrap_or(false);
+    let expires = approval_expiry(deps.storage, &env.block, &token_id, &spender_addr)?;
 
-    to_json_binary(&ApprovalResponse { approved })
+    to_json_binary(&ApprovalResponse {
+        approved: expires.is_some(),
+        expires,
+    })
+}
+
+pub fn query_approvals(deps: Deps, env: Env, token_id: String) -> StdResult<Binary> {
+    let approvals = live_approvals(deps.storage, &env.block, &token_id)?
+        .into_iter()
+        .map(|(spender, expires)| SpenderApproval {
+            spender: spender.to_string(),
+            expires,
+        })
+        .collect();
+
+    to_json_binary(&ApprovalsResponse { approvals })
 }
 
 pub fn query_operator(deps: Deps, owner: String, operator: String) -> StdResult<Binary> {
@@ -688,13 +2436,60 @@ pub fn query_operator(deps: Deps, owner: String, operator: String) -> StdResult<
 }
 
 pub fn query_pending_minter(deps: Deps) -> StdResult<Binary> {
-    let pending = PENDING_MINTER.may_load(deps.storage)?;
-    to_json_binary(&pending)
+    MINTER_TRANSFER.query_pending(deps)
+}
+
+pub fn query_minters(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(cw_storage_plus::Bound::exclusive);
+
+    let minters = MINTERS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|entry| {
+            let (addr, info) = entry?;
+            Ok(MinterEntry {
+                minted_in_window: minted_in_window(deps.storage, &env, &addr)?,
+                address: addr.to_string(),
+                daily_quota: info.daily_quota,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&MintersResponse { minters })
+}
+
+pub fn query_escrow_contracts(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(cw_storage_plus::Bound::exclusive);
+
+    let contracts: Vec<String> = ESCROW_CONTRACTS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|k| k.map(|addr| addr.to_string()))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&EscrowContractsResponse { contracts })
 }
 
 // FIX: H-04
 pub fn query_pending_owner(deps: Deps) -> StdResult<Binary> {
-    to_json_binary(&PENDING_OWNER.may_load(deps.storage)?)
+    OWNER_TRANSFER.query_pending(deps)
 }
 
 // FIX: M-05 — collection info query
@@ -706,22 +2501,272 @@ pub fn query_collection_info(deps: Deps) -> StdResult<Binary> {
     })
 }
 
+pub fn query_listings(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let listings: Vec<ListingInfo> = LISTINGS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(token_id, listing)| ListingInfo {
+                token_id,
+                seller: listing.seller.to_string(),
+                price: listing.price,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&ListingsResponse { listings })
+}
+
+pub fn query_listings_by_seller(
+    deps: Deps,
+    seller: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let seller_addr = deps.api.addr_validate(&seller)?;
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let listings: Vec<ListingInfo> = SELLER_LISTINGS
+        .prefix(&seller_addr)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|token_id| {
+            let token_id = token_id?;
+            let listing = LISTINGS.load(deps.storage, &token_id)?;
+            Ok(ListingInfo {
+                token_id,
+                seller: listing.seller.to_string(),
+                price: listing.price,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&ListingsResponse { listings })
+}
+
+pub fn query_metadata_frozen(deps: Deps, token_id: String) -> StdResult<Binary> {
+    let all_frozen = ALL_METADATA_FROZEN
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let frozen = all_frozen || TOKENS.load(deps.storage, &token_id)?.metadata_frozen;
+    to_json_binary(&MetadataFrozenResponse {
+        token_id,
+        frozen,
+        all_frozen,
+    })
+}
+
+pub fn query_metadata_hash(deps: Deps, token_id: String) -> StdResult<Binary> {
+    let data = TOKENS.load(deps.storage, &token_id)?;
+    to_json_binary(&MetadataHashResponse {
+        token_id,
+        token_uri: data.token_uri,
+        metadata_hash: data.metadata_hash,
+    })
+}
+
+pub fn query_token_history(deps: Deps, token_id: String) -> StdResult<Binary> {
+    let history = TOKEN_HISTORY
+        .may_load(deps.storage, &token_id)?
+        .unwrap_or_default();
+    to_json_binary(&TokenHistoryResponse { token_id, history })
+}
+
+pub fn query_repair_fee(deps: Deps, rarity: String) -> StdResult<Binary> {
+    to_json_binary(&REPAIR_FEES.may_load(deps.storage, &rarity)?)
+}
+
+pub fn query_trade(deps: Deps, trade_id: u64) -> StdResult<Binary> {
+    to_json_binary(&TRADES.may_load(deps.storage, trade_id)?)
+}
+
+pub fn query_trades(deps: Deps, start_after: Option<u64>, limit: Option<u32>) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.map(cw_storage_plus::Bound::exclusive);
+
+    let trades: Vec<TradeInfo> = TRADES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(trade_id, trade)| TradeInfo { trade_id, trade }))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&TradesResponse { trades })
+}
+
+pub fn query_redemption(deps: Deps, redemption_id: u64) -> StdResult<Binary> {
+    to_json_binary(&REDEMPTIONS.may_load(deps.storage, redemption_id)?)
+}
+
+pub fn query_pending_redemptions(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.map(cw_storage_plus::Bound::exclusive);
+
+    let redemptions = PENDING_REDEMPTIONS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|id| REDEMPTIONS.load(deps.storage, id?))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&RedemptionsResponse { redemptions })
+}
+
+pub fn query_recipe(deps: Deps, recipe_id: String) -> StdResult<Binary> {
+    to_json_binary(&RECIPES.may_load(deps.storage, &recipe_id)?)
+}
+
+pub fn query_recipes(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let recipes: Vec<RecipeInfo> = RECIPES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(recipe_id, recipe)| RecipeInfo { recipe_id, recipe }))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&RecipesResponse { recipes })
+}
+
+pub fn query_children(deps: Deps, token_id: String) -> StdResult<Binary> {
+    to_json_binary(&ChildrenResponse {
+        children: children_of(deps, &token_id)?,
+    })
+}
+
 // ─── Migrate ────────────────────────────────────────────────────────────────
 
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    // The single config.minter becomes the first entry of the minter set
+    if let Ok(legacy) = CONFIG_V1.load(deps.storage) {
+        MINTERS.save(
+            deps.storage,
+            &legacy.minter,
+            &MinterInfo { daily_quota: None },
+        )?;
+        CONFIG.save(
+            deps.storage,
+            &Config {
+                owner: legacy.owner,
+                paused: legacy.paused,
+                royalty_bps: legacy.royalty_bps,
+                royalty_recipient: legacy.royalty_recipient,
+                name: legacy.name,
+                symbol: legacy.symbol,
+            },
+        )?;
+    }
+
     // FIX: M-06 — backfill OWNER_TOKENS index by scanning TOKEN_OWNERS
     // FIX: I-02 — migrate() should be updated for future state changes
-    let all_owners: Vec<(String, Addr)> = TOKEN_OWNERS
-        .range(deps.storage, None, None, Order::Ascending)
+    let progress = match msg.backfill {
+        None => backfill_token_indexes(deps, BackfillProgress::default(), None)?,
+        Some(BackfillMsg { start_after, limit }) => {
+            // A finished backfill starts over; an unfinished one resumes
+            let mut progress = BACKFILL
+                .may_load(deps.storage)?
+                .filter(|p| !p.done)
+                .unwrap_or_default();
+            if start_after.is_some() {
+                progress.cursor = start_after;
+            }
+            let limit = limit
+                .unwrap_or(DEFAULT_BACKFILL_LIMIT)
+                .min(MAX_BACKFILL_LIMIT) as usize;
+            backfill_token_indexes(deps, progress, Some(limit))?
+        }
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("version", CONTRACT_VERSION)
+        .add_attribute("backfilled", progress.processed.to_string())
+        .add_attribute("done", progress.done.to_string()))
+}
+
+/// Index up to `limit` tokens after `progress.cursor` (all remaining tokens
+/// when None) into the owner, type and rarity indexes, and save the progress.
+fn backfill_token_indexes(
+    deps: DepsMut,
+    mut progress: BackfillProgress,
+    limit: Option<usize>,
+) -> StdResult<BackfillProgress> {
+    // Per-type mint counters start from live supply; burns before this upgrade are unknown
+    if progress.processed == 0 {
+        progress.count_minted = TYPE_MINTED.is_empty(deps.storage);
+    }
+    let start = progress
+        .cursor
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+    let owners: Vec<(String, Addr)> = TOKEN_OWNERS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit.unwrap_or(usize::MAX))
         .collect::<StdResult<Vec<_>>>()?;
 
-    for (token_id, owner) in &all_owners {
+    for (token_id, owner) in &owners {
         OWNER_TOKENS.save(deps.storage, (owner, token_id), &true)?;
+        // The single pre-upgrade approval becomes a never-expiring entry
+        if let Some(spender) = LEGACY_TOKEN_APPROVALS.may_load(deps.storage, token_id)? {
+            TOKEN_APPROVALS.save(deps.storage, (token_id, &spender), &Expiration::Never {})?;
+            LEGACY_TOKEN_APPROVALS.remove(deps.storage, token_id);
+        }
+        let data = TOKENS.load(deps.storage, token_id)?;
+        TYPE_TOKENS.save(deps.storage, (&data.metadata.item_type, token_id), &true)?;
+        RARITY_TOKENS.save(deps.storage, (&data.metadata.rarity, token_id), &true)?;
+        if progress.count_minted {
+            TYPE_MINTED.update(deps.storage, &data.metadata.item_type, |n| {
+                StdResult::Ok(n.unwrap_or_default() + 1)
+            })?;
+        }
+        if let Ok(id) = token_id.parse::<u64>() {
+            progress.highest_id = progress.highest_id.max(id);
+        }
+    }
+    progress.processed += owners.len() as u64;
+    if let Some((last, _)) = owners.last() {
+        progress.cursor = Some(last.clone());
     }
+    progress.done = limit.map_or(true, |limit| owners.len() < limit);
 
-    Ok(Response::new()
-        .add_attribute("action", "migrate")
-        .add_attribute("version", CONTRACT_VERSION))
+    // Ids were previously derived from TOKEN_COUNT; resume after the highest live id
+    if progress.done && LAST_TOKEN_ID.may_load(deps.storage)?.is_none() {
+        LAST_TOKEN_ID.save(deps.storage, &progress.highest_id)?;
+    }
+    BACKFILL.save(deps.storage, &progress)?;
+    Ok(progress)
+}
+
+pub fn query_migration_status(deps: Deps) -> StdResult<Binary> {
+    let progress = BACKFILL.may_load(deps.storage)?;
+    to_json_binary(&MigrationStatusResponse {
+        version: get_contract_version(deps.storage)?.version,
+        done: progress.as_ref().map_or(true, |p| p.done),
+        processed: progress.as_ref().map_or(0, |p| p.processed),
+        total_tokens: TOKEN_COUNT.load(deps.storage)?,
+        cursor: progress.and_then(|p| p.cursor),
+    })
 }
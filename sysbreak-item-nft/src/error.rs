@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use sysbreak_common::AdminError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -6,53 +7,181 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    Admin(#[from] AdminError),
+
     #[error("unauthorized: only {role} can perform this action")]
     Unauthorized { role: String },
 
-    #[error("contract is paused")]
-    Paused,
+    #[error("{address} is already a minter")]
+    AlreadyMinter { address: String },
+
+    #[error("{address} is not a minter")]
+    NotMinter { address: String },
 
-    #[error("contract is not paused")]
-    NotPaused,
+    #[error("minter quota exceeded: {used} minted in the last 24h, {requested} requested, quota {quota}")]
+    MintQuotaExceeded {
+        used: u32,
+        requested: u32,
+        quota: u32,
+    },
 
-    #[error("batch mint exceeds maximum of {max} items")]
+    #[error("batch exceeds maximum of {max} items")]
     BatchTooLarge { max: u32 },
 
-    #[error("batch mint list is empty")]
+    #[error("batch list is empty")]
     EmptyBatch,
 
-    #[error("no minter transfer pending")]
-    NoMinterTransferPending,
-
-    #[error("caller is not the pending minter")]
-    NotPendingMinter,
-
-    #[error("minter transfer already pending")]
-    MinterTransferAlreadyPending,
-
     #[error("invalid royalty basis points: {bps} (max 10000)")]
     InvalidRoyaltyBps { bps: u16 },
 
     #[error("token not found: {token_id}")]
     TokenNotFound { token_id: String },
 
-    #[error("{0}")]
-    Cw721(String),
+    #[error("token is soulbound and cannot be transferred")]
+    Soulbound,
 
-    #[error("{0}")]
-    Ownership(String),
+    #[error("metadata of token {token_id} is frozen")]
+    MetadataFrozen { token_id: String },
+
+    #[error("token {token_id} is locked")]
+    TokenLocked { token_id: String },
+
+    #[error("token {token_id} is not locked")]
+    TokenNotLocked { token_id: String },
+
+    #[error("supply cap of {max_supply} reached for item type {item_type}")]
+    SupplyCapReached { item_type: String, max_supply: u64 },
+
+    #[error("invalid supply cap: {reason}")]
+    InvalidSupplyCap { reason: String },
+
+    #[error("token {token_id} is broken and must be repaired")]
+    ItemBroken { token_id: String },
+
+    #[error("token {token_id} has no durability")]
+    NoDurability { token_id: String },
+
+    #[error("token {token_id} is already at full durability")]
+    ItemNotDamaged { token_id: String },
+
+    #[error("max_durability must be non-zero")]
+    InvalidDurability,
+
+    #[error("charges must be non-zero")]
+    InvalidCharges,
+
+    #[error("invalid metadata hash: {reason}")]
+    InvalidMetadataHash { reason: String },
+
+    #[error("token {token_id} is not consumable")]
+    NotConsumable { token_id: String },
+
+    #[error("token {token_id} has only {remaining} charges left")]
+    InsufficientCharges { token_id: String, remaining: u32 },
+
+    #[error("token {token_id} is held in escrow")]
+    TokenInEscrow { token_id: String },
+
+    #[error("token {token_id} is listed for sale")]
+    TokenListed { token_id: String },
+
+    #[error("{address} is already an escrow contract")]
+    AlreadyEscrowContract { address: String },
+
+    #[error("{address} is not an escrow contract")]
+    NotEscrowContract { address: String },
+
+    #[error("token {token_id} is attached to {parent}")]
+    TokenAttached { token_id: String, parent: String },
+
+    #[error("token {token_id} is not attached")]
+    NotAttached { token_id: String },
 
-    // FIX: H-04 — two-step owner transfer errors
-    #[error("no owner transfer pending")]
-    NoOwnerTransferPending,
+    #[error("invalid attachment: {reason}")]
+    InvalidAttachment { reason: String },
 
-    #[error("caller is not the pending owner")]
-    NotPendingOwner,
+    #[error("token {token_id} is not listed for sale")]
+    NotListed { token_id: String },
 
-    #[error("owner transfer already pending")]
-    OwnerTransferAlreadyPending,
+    #[error("listing price must be non-zero")]
+    InvalidPrice,
 
-    // FIX: M-08 — reject unexpected funds
-    #[error("unexpected funds sent with this message")]
-    UnexpectedFunds,
+    #[error("payment must be exactly {expected}")]
+    IncorrectPayment { expected: String },
+
+    #[error("cannot buy your own listing")]
+    SelfPurchase,
+
+    #[error("level cannot go down: current {current}, requested {requested}")]
+    LevelDowngrade { current: u32, requested: u32 },
+
+    #[error("stat {stat} out of range after applying {delta}")]
+    StatOutOfRange { stat: String, delta: i64 },
+
+    #[error("invalid stat schema: {reason}")]
+    InvalidStatSchema { reason: String },
+
+    #[error("stat {stat} is not allowed on item type {item_type}")]
+    UnknownStat { item_type: String, stat: String },
+
+    #[error("stat {stat} = {value} outside {min}..={max} for item type {item_type}")]
+    StatValueOutOfRange {
+        item_type: String,
+        stat: String,
+        value: u64,
+        min: u64,
+        max: u64,
+    },
+
+    #[error("invalid trade: {reason}")]
+    InvalidTrade { reason: String },
+
+    #[error("trade not found: {trade_id}")]
+    TradeNotFound { trade_id: u64 },
+
+    #[error("trade {trade_id} has expired")]
+    TradeExpired { trade_id: u64 },
+
+    #[error("invalid recipe: {reason}")]
+    InvalidRecipe { reason: String },
+
+    #[error("recipe not found: {recipe_id}")]
+    RecipeNotFound { recipe_id: String },
+
+    #[error("recipe needs {expected} inputs, got {got}")]
+    WrongInputCount { expected: u32, got: u32 },
+
+    #[error("token {token_id} listed more than once")]
+    DuplicateToken { token_id: String },
+
+    #[error("token {token_id} does not satisfy recipe input {slot}")]
+    RecipeInputMismatch { token_id: String, slot: u32 },
+
+    #[error("redemption code must be 1..={max} printable ASCII characters without spaces")]
+    InvalidRedemptionCode { max: usize },
+
+    #[error("redemption code {code:?} has already been used")]
+    RedemptionCodeUsed { code: String },
+
+    #[error("redemption not found: {redemption_id}")]
+    RedemptionNotFound { redemption_id: u64 },
+
+    #[error("redemption {redemption_id} is already acknowledged")]
+    RedemptionAcknowledged { redemption_id: u64 },
+
+    #[error("approval expiration is already in the past")]
+    ApprovalExpired,
+
+    #[error("token {token_id} already has the maximum of {max} approvals")]
+    TooManyApprovals { token_id: String, max: usize },
+
+    #[error("token index backfill in progress; minting resumes once it completes")]
+    BackfillInProgress,
+
+    #[error("{0}")]
+    Cw721(String),
+
+    #[error("{0}")]
+    Ownership(String),
 }
@@ -1,43 +1,262 @@
-use cosmwasm_std::{Addr, Deps, MessageInfo, StdResult};
+use cosmwasm_std::{Addr, BlockInfo, Deps, Env, HexBinary, Order, StdResult, Storage};
+use cw721::Expiration;
+use cw_storage_plus::Bound;
+use std::collections::BTreeMap;
 
 use crate::error::ContractError;
-use crate::state::{CONFIG, TOKEN_APPROVALS, TOKEN_OWNERS, OPERATOR_APPROVALS};
+use crate::state::{
+    MinterInfo, TransferRecord, TypeRoyalty, ALL_METADATA_FROZEN, CONFIG, ESCROW_CONTRACTS,
+    ITEM_PARENT, LEGACY_TOKEN_APPROVALS, LISTINGS, MINTERS, MINTER_USAGE, MINT_QUOTA_BUCKET,
+    MINT_QUOTA_WINDOW, OPERATOR_APPROVALS, SELLER_LISTINGS, STAT_SCHEMAS, TOKENS, TOKEN_APPROVALS,
+    TOKEN_HISTORY, TOKEN_OWNERS, TYPE_ROYALTIES,
+};
+
+/// Transfers kept per token; older entries are dropped.
+const MAX_TOKEN_HISTORY: usize = 20;
 
 /// Verify the caller is the contract owner.
 pub fn assert_owner(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if *sender != config.owner {
-        return Err(ContractError::Unauthorized {
-            role: "owner".to_string(),
-        });
-    }
-    Ok(())
+    Ok(sysbreak_common::assert_owner(deps, &CONFIG, sender)?)
 }
 
-/// Verify the caller is the authorized minter.
-pub fn assert_minter(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if *sender != config.minter {
-        return Err(ContractError::Unauthorized {
+/// Verify the caller is an authorized minter.
+pub fn assert_minter(deps: Deps, sender: &Addr) -> Result<MinterInfo, ContractError> {
+    MINTERS
+        .may_load(deps.storage, sender)?
+        .ok_or_else(|| ContractError::Unauthorized {
             role: "minter".to_string(),
+        })
+}
+
+/// First hourly bucket inside the quota window ending at the current block.
+fn quota_window_start(env: &Env) -> u64 {
+    let hour = env.block.time.seconds() / MINT_QUOTA_BUCKET;
+    hour.saturating_sub(MINT_QUOTA_WINDOW / MINT_QUOTA_BUCKET - 1)
+}
+
+/// Tokens `minter` minted over the last 24 hours.
+pub fn minted_in_window(storage: &dyn Storage, env: &Env, minter: &Addr) -> StdResult<u32> {
+    MINTER_USAGE
+        .prefix(minter)
+        .range(
+            storage,
+            Some(Bound::inclusive(quota_window_start(env))),
+            None,
+            Order::Ascending,
+        )
+        .map(|entry| entry.map(|(_, count)| count))
+        .sum()
+}
+
+/// Count `amount` mints against the minter's rolling quota, failing if it
+/// would go over, and drop buckets that have left the window.
+pub fn consume_mint_quota(
+    storage: &mut dyn Storage,
+    env: &Env,
+    minter: &Addr,
+    info: &MinterInfo,
+    amount: u32,
+) -> Result<(), ContractError> {
+    let Some(quota) = info.daily_quota else {
+        return Ok(());
+    };
+    let used = minted_in_window(storage, env, minter)?;
+    if used.saturating_add(amount) > quota {
+        return Err(ContractError::MintQuotaExceeded {
+            used,
+            requested: amount,
+            quota,
         });
     }
+
+    let stale: Vec<u64> = MINTER_USAGE
+        .prefix(minter)
+        .keys(
+            storage,
+            None,
+            Some(Bound::exclusive(quota_window_start(env))),
+            Order::Ascending,
+        )
+        .collect::<StdResult<_>>()?;
+    for hour in stale {
+        MINTER_USAGE.remove(storage, (minter, hour));
+    }
+    let hour = env.block.time.seconds() / MINT_QUOTA_BUCKET;
+    MINTER_USAGE.update(storage, (minter, hour), |n| {
+        StdResult::Ok(n.unwrap_or_default() + amount)
+    })?;
     Ok(())
 }
 
 /// Verify the contract is not paused.
 pub fn assert_not_paused(deps: Deps) -> Result<(), ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if config.paused {
-        return Err(ContractError::Paused);
+    Ok(sysbreak_common::assert_not_paused(deps, &CONFIG)?)
+}
+
+/// Verify the item is not soulbound.
+pub fn assert_not_soulbound(deps: Deps, token_id: &str) -> Result<(), ContractError> {
+    let data = TOKENS.load(deps.storage, token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.to_string(),
+        }
+    })?;
+    if data.soulbound {
+        return Err(ContractError::Soulbound);
     }
     Ok(())
 }
 
+/// Whether a token's metadata is frozen, individually or collection-wide.
+pub fn is_metadata_frozen(deps: Deps, token_id: &str) -> StdResult<bool> {
+    if ALL_METADATA_FROZEN
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+    {
+        return Ok(true);
+    }
+    Ok(TOKENS.load(deps.storage, token_id)?.metadata_frozen)
+}
+
+/// Verify the item is not locked (equipped in-game) or attached to a parent
+/// item, which it can only leave through Detach.
+pub fn assert_not_locked(deps: Deps, token_id: &str) -> Result<(), ContractError> {
+    let data = TOKENS.load(deps.storage, token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.to_string(),
+        }
+    })?;
+    if data.locked {
+        return Err(ContractError::TokenLocked {
+            token_id: token_id.to_string(),
+        });
+    }
+    if let Some(parent) = ITEM_PARENT.may_load(deps.storage, token_id)? {
+        return Err(ContractError::TokenAttached {
+            token_id: token_id.to_string(),
+            parent,
+        });
+    }
+    Ok(())
+}
+
+/// Reject changes to an item held in escrow, either by this contract for an
+/// open trade or by a registered escrow contract.
+pub fn assert_not_escrowed(
+    deps: Deps,
+    env: &Env,
+    owner: &Addr,
+    token_id: &str,
+) -> Result<(), ContractError> {
+    if *owner == env.contract.address || ESCROW_CONTRACTS.has(deps.storage, owner) {
+        return Err(ContractError::TokenInEscrow {
+            token_id: token_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Reject changes that would alter an item out from under its sale listing.
+pub fn assert_not_listed(deps: Deps, token_id: &str) -> Result<(), ContractError> {
+    if LISTINGS.has(deps.storage, token_id) {
+        return Err(ContractError::TokenListed {
+            token_id: token_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Verify the item is not broken (durability worn down to zero).
+pub fn assert_not_broken(deps: Deps, token_id: &str) -> Result<(), ContractError> {
+    let data = TOKENS.load(deps.storage, token_id).map_err(|_| {
+        ContractError::TokenNotFound {
+            token_id: token_id.to_string(),
+        }
+    })?;
+    if data.metadata.durability == Some(0) {
+        return Err(ContractError::ItemBroken {
+            token_id: token_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Drop a token's listing and its seller index entry, if any.
+pub fn remove_listing(storage: &mut dyn Storage, token_id: &str) {
+    if let Ok(Some(listing)) = LISTINGS.may_load(storage, token_id) {
+        SELLER_LISTINGS.remove(storage, (&listing.seller, token_id));
+        LISTINGS.remove(storage, token_id);
+    }
+}
+
+/// Append a transfer to the token's history, dropping the oldest entry past the cap.
+pub fn record_transfer(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    record: TransferRecord,
+) -> StdResult<()> {
+    let mut history = TOKEN_HISTORY
+        .may_load(storage, token_id)?
+        .unwrap_or_default();
+    history.push(record);
+    if history.len() > MAX_TOKEN_HISTORY {
+        history.remove(0);
+    }
+    TOKEN_HISTORY.save(storage, token_id, &history)
+}
+
+/// When `spender`'s approval on `token_id` lapses, or None if it holds no
+/// live approval. A legacy approval not yet backfilled never expires.
+pub fn approval_expiry(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    token_id: &str,
+    spender: &Addr,
+) -> StdResult<Option<Expiration>> {
+    if let Some(expires) = TOKEN_APPROVALS.may_load(storage, (token_id, spender))? {
+        return Ok(Some(expires).filter(|e| !e.is_expired(block)));
+    }
+    let legacy = LEGACY_TOKEN_APPROVALS.may_load(storage, token_id)?;
+    Ok((legacy.as_ref() == Some(spender)).then_some(Expiration::Never {}))
+}
+
+/// Every unexpired approval on `token_id`, ordered by spender.
+pub fn live_approvals(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    token_id: &str,
+) -> StdResult<Vec<(Addr, Expiration)>> {
+    let mut approvals: Vec<(Addr, Expiration)> = TOKEN_APPROVALS
+        .prefix(token_id)
+        .range(storage, None, None, Order::Ascending)
+        .filter(|r| r.as_ref().map_or(true, |(_, e)| !e.is_expired(block)))
+        .collect::<StdResult<_>>()?;
+    if let Some(spender) = LEGACY_TOKEN_APPROVALS.may_load(storage, token_id)? {
+        if !approvals.iter().any(|(s, _)| *s == spender) {
+            approvals.push((spender, Expiration::Never {}));
+            approvals.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+    }
+    Ok(approvals)
+}
+
+/// Drop every approval on a token, e.g. when it changes hands.
+pub fn clear_approvals(storage: &mut dyn Storage, token_id: &str) -> StdResult<()> {
+    let spenders: Vec<Addr> = TOKEN_APPROVALS
+        .prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for spender in &spenders {
+        TOKEN_APPROVALS.remove(storage, (token_id, spender));
+    }
+    LEGACY_TOKEN_APPROVALS.remove(storage, token_id);
+    Ok(())
+}
+
 /// Check if `spender` is authorized to transfer `token_id` on behalf of the owner.
-/// Returns true if spender is the owner, has token-level approval, or has operator approval.
+/// Returns true if spender is the owner, has a live token-level approval, or has operator approval.
 pub fn is_authorized(
     deps: Deps,
+    block: &BlockInfo,
     token_id: &str,
     spender: &Addr,
 ) -> StdResult<bool> {
@@ -46,10 +265,8 @@ pub fn is_authorized(
         return Ok(true);
     }
     // Check token-level approval
-    if let Some(approved) = TOKEN_APPROVALS.may_load(deps.storage, token_id)? {
-        if approved == *spender {
-            return Ok(true);
-        }
+    if approval_expiry(deps.storage, block, token_id, spender)?.is_some() {
+        return Ok(true);
     }
     // Check operator approval
     if let Some(true) = OPERATOR_APPROVALS.may_load(deps.storage, (&owner, spender))? {
@@ -58,6 +275,18 @@ pub fn is_authorized(
     Ok(false)
 }
 
+/// Royalty that applies to an item_type: its override if set, else the collection default.
+pub fn effective_royalty(deps: Deps, item_type: &str) -> StdResult<TypeRoyalty> {
+    if let Some(royalty) = TYPE_ROYALTIES.may_load(deps.storage, item_type)? {
+        return Ok(royalty);
+    }
+    let config = CONFIG.load(deps.storage)?;
+    Ok(TypeRoyalty {
+        royalty_bps: config.royalty_bps,
+        royalty_recipient: config.royalty_recipient,
+    })
+}
+
 /// Validate royalty basis points (max 10000 = 100%).
 pub fn validate_royalty_bps(bps: u16) -> Result<(), ContractError> {
     if bps > 10_000 {
@@ -66,10 +295,40 @@ pub fn validate_royalty_bps(bps: u16) -> Result<(), ContractError> {
     Ok(())
 }
 
-// FIX: M-08 — reject unexpected funds
-pub fn reject_funds(info: &MessageInfo) -> Result<(), ContractError> {
-    if !info.funds.is_empty() {
-        return Err(ContractError::UnexpectedFunds);
+/// Metadata hashes are SHA-256 digests.
+pub fn validate_metadata_hash(hash: &HexBinary) -> Result<(), ContractError> {
+    if hash.len() != 32 {
+        return Err(ContractError::InvalidMetadataHash {
+            reason: format!("expected 32 bytes, got {}", hash.len()),
+        });
+    }
+    Ok(())
+}
+
+/// Check stats against their item_type's schema, if it has one: every key must
+/// be listed and every value within its range.
+pub fn validate_stats(
+    storage: &dyn Storage,
+    item_type: &str,
+    stats: &BTreeMap<String, u64>,
+) -> Result<(), ContractError> {
+    let Some(schema) = STAT_SCHEMAS.may_load(storage, item_type)? else {
+        return Ok(());
+    };
+    for (stat, &value) in stats {
+        let range = schema.get(stat).ok_or_else(|| ContractError::UnknownStat {
+            item_type: item_type.to_string(),
+            stat: stat.clone(),
+        })?;
+        if value < range.min || value > range.max {
+            return Err(ContractError::StatValueOutOfRange {
+                item_type: item_type.to_string(),
+                stat: stat.clone(),
+                value,
+                min: range.min,
+                max: range.max,
+            });
+        }
     }
     Ok(())
 }
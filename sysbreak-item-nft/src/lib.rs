@@ -4,7 +4,13 @@ pub mod helpers;
 pub mod msg;
 pub mod state;
 
-#[cfg(not(feature = "library"))]
+#[cfg(feature = "testing")]
+pub mod testing;
+
+// Entry points are only referenced by the wasm export shims, which are not
+// generated for native targets, and by the `testing` wrapper.
+#[cfg(any(not(feature = "library"), feature = "testing"))]
+#[allow(dead_code)]
 mod entry {
     use super::*;
     use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
@@ -36,31 +42,75 @@ mod entry {
                 stats,
                 origin,
                 token_uri,
-            } => contract::execute_mint(deps, env, info, to, item_type, rarity, level, stats, origin, token_uri),
+                max_durability,
+                soulbound,
+                charges,
+                metadata_hash,
+            } => contract::execute_mint(
+                deps,
+                env,
+                info,
+                to,
+                item_type,
+                rarity,
+                level,
+                stats,
+                origin,
+                token_uri,
+                max_durability,
+                soulbound,
+                charges,
+                metadata_hash,
+            ),
             ExecuteMsg::BatchMint { mints } => contract::execute_batch_mint(deps, env, info, mints),
             ExecuteMsg::TransferNft {
                 recipient,
                 token_id,
             } => contract::execute_transfer_nft(deps, env, info, recipient, token_id),
+            ExecuteMsg::BatchTransfer { transfers } => {
+                contract::execute_batch_transfer(deps, env, info, transfers)
+            }
             ExecuteMsg::SendNft {
                 contract,
                 token_id,
                 msg,
             } => contract::execute_send_nft(deps, env, info, contract, token_id, msg),
-            ExecuteMsg::Approve { spender, token_id } => {
-                contract::execute_approve(deps, env, info, spender, token_id)
+            ExecuteMsg::Approve {
+                spender,
+                token_id,
+                expires,
+            } => contract::execute_approve(deps, env, info, spender, token_id, expires),
+            ExecuteMsg::Revoke { spender, token_id } => {
+                contract::execute_revoke(deps, env, info, spender, token_id)
             }
-            ExecuteMsg::Revoke { token_id } => contract::execute_revoke(deps, env, info, token_id),
             ExecuteMsg::ApproveAll { operator } => {
                 contract::execute_approve_all(deps, env, info, operator)
             }
             ExecuteMsg::RevokeAll { operator } => {
                 contract::execute_revoke_all(deps, env, info, operator)
             }
-            ExecuteMsg::ProposeMinter { new_minter } => {
-                contract::execute_propose_minter(deps, env, info, new_minter)
-            }
+            ExecuteMsg::ProposeMinter {
+                new_minter,
+                replaces,
+            } => contract::execute_propose_minter(deps, env, info, new_minter, replaces),
             ExecuteMsg::AcceptMinter {} => contract::execute_accept_minter(deps, env, info),
+            ExecuteMsg::AddMinter {
+                address,
+                daily_quota,
+            } => contract::execute_add_minter(deps, env, info, address, daily_quota),
+            ExecuteMsg::RemoveMinter { address } => {
+                contract::execute_remove_minter(deps, env, info, address)
+            }
+            ExecuteMsg::SetMinterQuota {
+                address,
+                daily_quota,
+            } => contract::execute_set_minter_quota(deps, env, info, address, daily_quota),
+            ExecuteMsg::AddEscrowContract { address } => {
+                contract::execute_add_escrow_contract(deps, env, info, address)
+            }
+            ExecuteMsg::RemoveEscrowContract { address } => {
+                contract::execute_remove_escrow_contract(deps, env, info, address)
+            }
             ExecuteMsg::CancelMinterTransfer {} => {
                 contract::execute_cancel_minter_transfer(deps, env, info)
             }
@@ -70,8 +120,119 @@ mod entry {
                 royalty_bps,
                 royalty_recipient,
             } => contract::execute_update_royalty(deps, env, info, royalty_bps, royalty_recipient),
+            ExecuteMsg::SetTypeRoyalty {
+                item_type,
+                royalty_bps,
+                royalty_recipient,
+            } => contract::execute_set_type_royalty(
+                deps,
+                env,
+                info,
+                item_type,
+                royalty_bps,
+                royalty_recipient,
+            ),
+            ExecuteMsg::RemoveTypeRoyalty { item_type } => {
+                contract::execute_remove_type_royalty(deps, env, info, item_type)
+            }
             // FIX: L-02
             ExecuteMsg::Burn { token_id } => contract::execute_burn(deps, env, info, token_id),
+            ExecuteMsg::ListForSale { token_id, price } => {
+                contract::execute_list_for_sale(deps, env, info, token_id, price)
+            }
+            ExecuteMsg::CancelListing { token_id } => {
+                contract::execute_cancel_listing(deps, env, info, token_id)
+            }
+            ExecuteMsg::Buy { token_id } => contract::execute_buy(deps, env, info, token_id),
+            ExecuteMsg::ProposeTrade {
+                counterparty,
+                offered_token_ids,
+                requested_token_ids,
+                expiry,
+            } => contract::execute_propose_trade(
+                deps,
+                env,
+                info,
+                counterparty,
+                offered_token_ids,
+                requested_token_ids,
+                expiry,
+            ),
+            ExecuteMsg::AcceptTrade { trade_id } => {
+                contract::execute_accept_trade(deps, env, info, trade_id)
+            }
+            ExecuteMsg::CancelTrade { trade_id } => {
+                contract::execute_cancel_trade(deps, env, info, trade_id)
+            }
+            ExecuteMsg::Lock { token_id } => contract::execute_lock(deps, env, info, token_id),
+            ExecuteMsg::Unlock { token_id } => contract::execute_unlock(deps, env, info, token_id),
+            ExecuteMsg::UpgradeItem {
+                token_id,
+                new_level,
+                stat_changes,
+            } => contract::execute_upgrade_item(deps, env, info, token_id, new_level, stat_changes),
+            ExecuteMsg::UpdateMetadata {
+                token_id,
+                token_uri,
+                metadata_hash,
+            } => contract::execute_update_metadata(
+                deps,
+                env,
+                info,
+                token_id,
+                token_uri,
+                metadata_hash,
+            ),
+            ExecuteMsg::ApplyWear { token_id, amount } => {
+                contract::execute_apply_wear(deps, env, info, token_id, amount)
+            }
+            ExecuteMsg::Repair { token_id } => contract::execute_repair(deps, env, info, token_id),
+            ExecuteMsg::Consume { token_id, amount } => {
+                contract::execute_consume(deps, env, info, token_id, amount)
+            }
+            ExecuteMsg::Redeem {
+                token_id,
+                redemption_code,
+            } => contract::execute_redeem(deps, env, info, token_id, redemption_code),
+            ExecuteMsg::AckRedemption { redemption_id } => {
+                contract::execute_ack_redemption(deps, env, info, redemption_id)
+            }
+            ExecuteMsg::SetMaxSupply {
+                item_type,
+                max_supply,
+            } => contract::execute_set_max_supply(deps, env, info, item_type, max_supply),
+            ExecuteMsg::SetRepairFee { rarity, fee } => {
+                contract::execute_set_repair_fee(deps, env, info, rarity, fee)
+            }
+            ExecuteMsg::FreezeTokenMetadata { token_id } => {
+                contract::execute_freeze_token_metadata(deps, env, info, token_id)
+            }
+            ExecuteMsg::FreezeAllMetadata {} => {
+                contract::execute_freeze_all_metadata(deps, env, info)
+            }
+            ExecuteMsg::SetStatSchema { item_type, stats } => {
+                contract::execute_set_stat_schema(deps, env, info, item_type, stats)
+            }
+            ExecuteMsg::RemoveStatSchema { item_type } => {
+                contract::execute_remove_stat_schema(deps, env, info, item_type)
+            }
+            ExecuteMsg::RegisterRecipe { recipe_id, recipe } => {
+                contract::execute_register_recipe(deps, env, info, recipe_id, recipe)
+            }
+            ExecuteMsg::RemoveRecipe { recipe_id } => {
+                contract::execute_remove_recipe(deps, env, info, recipe_id)
+            }
+            ExecuteMsg::Combine {
+                token_ids,
+                recipe_id,
+            } => contract::execute_combine(deps, env, info, token_ids, recipe_id),
+            ExecuteMsg::Attach {
+                parent_token_id,
+                child_token_id,
+            } => contract::execute_attach(deps, env, info, parent_token_id, child_token_id),
+            ExecuteMsg::Detach { child_token_id } => {
+                contract::execute_detach(deps, env, info, child_token_id)
+            }
             // FIX: H-04
             ExecuteMsg::ProposeOwner { new_owner } => {
                 contract::execute_propose_owner(deps, env, info, new_owner)
@@ -88,33 +249,91 @@ mod entry {
     }
 
     #[entry_point]
-    pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> cosmwasm_std::StdResult<Binary> {
+    pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> cosmwasm_std::StdResult<Binary> {
         match msg {
             QueryMsg::Config {} => contract::query_config(deps),
-            QueryMsg::NftInfo { token_id } => contract::query_nft_info(deps, token_id),
-            QueryMsg::OwnerOf { token_id } => contract::query_owner_of(deps, token_id),
+            QueryMsg::NftInfo { token_id } => contract::query_nft_info(deps, env, token_id),
+            QueryMsg::OwnerOf { token_id } => contract::query_owner_of(deps, env, token_id),
             QueryMsg::Tokens {
                 owner,
                 start_after,
                 limit,
             } => contract::query_tokens(deps, owner, start_after, limit),
+            QueryMsg::TokensWithInfo {
+                owner,
+                start_after,
+                limit,
+            } => contract::query_tokens_with_info(deps, env, owner, start_after, limit),
+            QueryMsg::TokensByType {
+                item_type,
+                start_after,
+                limit,
+            } => contract::query_tokens_by_type(deps, item_type, start_after, limit),
+            QueryMsg::TokensByRarity {
+                rarity,
+                start_after,
+                limit,
+            } => contract::query_tokens_by_rarity(deps, rarity, start_after, limit),
+            QueryMsg::TypeSupply { item_type } => contract::query_type_supply(deps, item_type),
+            QueryMsg::StatSchema { item_type } => contract::query_stat_schema(deps, item_type),
             QueryMsg::AllTokens {
                 start_after,
                 limit,
             } => contract::query_all_tokens(deps, start_after, limit),
             QueryMsg::NumTokens {} => contract::query_num_tokens(deps),
+            QueryMsg::MigrationStatus {} => contract::query_migration_status(deps),
             QueryMsg::RoyaltyInfo {} => contract::query_royalty_info(deps),
+            QueryMsg::Extension { msg } => contract::query_cw2981(deps, msg),
+            QueryMsg::RoyaltyInfoForToken { token_id } => {
+                contract::query_royalty_info_for_token(deps, token_id)
+            }
             QueryMsg::Approval { token_id, spender } => {
-                contract::query_approval(deps, token_id, spender)
+                contract::query_approval(deps, env, token_id, spender)
             }
+            QueryMsg::Approvals { token_id } => contract::query_approvals(deps, env, token_id),
             QueryMsg::Operator { owner, operator } => {
                 contract::query_operator(deps, owner, operator)
             }
             QueryMsg::PendingMinter {} => contract::query_pending_minter(deps),
+            QueryMsg::Minters { start_after, limit } => {
+                contract::query_minters(deps, env, start_after, limit)
+            }
+            QueryMsg::EscrowContracts { start_after, limit } => {
+                contract::query_escrow_contracts(deps, start_after, limit)
+            }
             // FIX: H-04
             QueryMsg::PendingOwner {} => contract::query_pending_owner(deps),
             // FIX: M-05
             QueryMsg::CollectionInfo {} => contract::query_collection_info(deps),
+            QueryMsg::Listings { start_after, limit } => {
+                contract::query_listings(deps, start_after, limit)
+            }
+            QueryMsg::ListingsBySeller {
+                seller,
+                start_after,
+                limit,
+            } => contract::query_listings_by_seller(deps, seller, start_after, limit),
+            QueryMsg::MetadataFrozen { token_id } => {
+                contract::query_metadata_frozen(deps, token_id)
+            }
+            QueryMsg::MetadataHash { token_id } => contract::query_metadata_hash(deps, token_id),
+            QueryMsg::TokenHistory { token_id } => contract::query_token_history(deps, token_id),
+            QueryMsg::RepairFee { rarity } => contract::query_repair_fee(deps, rarity),
+            QueryMsg::Trade { trade_id } => contract::query_trade(deps, trade_id),
+            QueryMsg::Trades { start_after, limit } => {
+                contract::query_trades(deps, start_after, limit)
+            }
+            QueryMsg::Redemption { redemption_id } => {
+                contract::query_redemption(deps, redemption_id)
+            }
+            QueryMsg::PendingRedemptions { start_after, limit } => {
+                contract::query_pending_redemptions(deps, start_after, limit)
+            }
+            QueryMsg::Recipe { recipe_id } => contract::query_recipe(deps, recipe_id),
+            QueryMsg::Recipes { start_after, limit } => {
+                contract::query_recipes(deps, start_after, limit)
+            }
+            QueryMsg::Children { token_id } => contract::query_children(deps, token_id),
         }
     }
 
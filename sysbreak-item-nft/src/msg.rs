@@ -1,12 +1,14 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use crate::state::ItemMetadata;
+pub use cw721::Expiration;
+
+use crate::state::{ItemMetadata, Recipe, StatRange, Trade, TransferRecord};
 use std::collections::BTreeMap;
 
 #[cw_serde]
 pub struct InstantiateMsg {
     /// Contract owner address
     pub owner: String,
-    /// Authorized minter address (backend wallet)
+    /// First authorized minter (backend wallet), without a quota
     pub minter: String,
     /// Royalty basis points (max 10000)
     pub royalty_bps: u16,
@@ -29,6 +31,17 @@ pub enum ExecuteMsg {
         stats: BTreeMap<String, u64>,
         origin: String,
         token_uri: Option<String>,
+        /// Starting (and maximum) durability; None mints an item that never wears
+        max_durability: Option<u32>,
+        /// Bind the item to the recipient (non-transferable)
+        #[serde(default)]
+        soulbound: bool,
+        /// Uses for a consumable (potion, booster); None mints a regular item
+        #[serde(default)]
+        charges: Option<u32>,
+        /// SHA-256 of the document at `token_uri`, so holders can check it was not swapped
+        #[serde(default)]
+        metadata_hash: Option<cosmwasm_std::HexBinary>,
     },
     /// Batch mint up to 50 items (minter only)
     BatchMint {
@@ -39,19 +52,26 @@ pub enum ExecuteMsg {
         recipient: String,
         token_id: String,
     },
+    /// Transfer up to 50 NFTs in one message; all succeed or none do
+    BatchTransfer {
+        transfers: Vec<TransferRequest>,
+    },
     /// Send an NFT to a contract with a callback message
     SendNft {
         contract: String,
         token_id: String,
         msg: cosmwasm_std::Binary,
     },
-    /// Approve a spender for a specific token
+    /// Approve a spender for a specific token, alongside any others already
+    /// approved. The approval lapses at `expires` (default: never).
     Approve {
         spender: String,
         token_id: String,
+        expires: Option<Expiration>,
     },
-    /// Revoke approval for a specific token
+    /// Revoke `spender`'s approval for a token, or all of its approvals when omitted
     Revoke {
+        spender: Option<String>,
         token_id: String,
     },
     /// Approve an operator for all tokens owned by sender
@@ -62,12 +82,41 @@ pub enum ExecuteMsg {
     RevokeAll {
         operator: String,
     },
-    /// Step 1 of minter transfer: propose a new minter (owner only)
+    /// Step 1: propose a new minter (owner only). `replaces` names a current
+    /// minter key to rotate out when the proposal is accepted.
     ProposeMinter {
         new_minter: String,
+        #[serde(default)]
+        replaces: Option<String>,
     },
-    /// Step 2 of minter transfer: new minter accepts the role
+    /// Step 2: proposed minter accepts and joins the minter set, taking over
+    /// the replaced key's quota (no quota otherwise)
     AcceptMinter {},
+    /// Authorize an additional minter, e.g. one per game service (owner only)
+    AddMinter {
+        address: String,
+        /// Most tokens it may mint in any rolling 24 hours; None is unlimited
+        daily_quota: Option<u32>,
+    },
+    /// Revoke a minter's authorization (owner only)
+    RemoveMinter {
+        address: String,
+    },
+    /// Change a minter's rolling 24h quota; None removes it (owner only)
+    SetMinterQuota {
+        address: String,
+        daily_quota: Option<u32>,
+    },
+    /// Register a contract that holds items for players, such as the
+    /// marketplace or staking; items it holds cannot be consumed, worn or
+    /// locked (owner only)
+    AddEscrowContract {
+        address: String,
+    },
+    /// Unregister an escrow contract (owner only)
+    RemoveEscrowContract {
+        address: String,
+    },
     /// Cancel a pending minter transfer (owner only)
     CancelMinterTransfer {},
     /// Pause the contract — freezes minting and transfers (owner only)
@@ -79,8 +128,150 @@ pub enum ExecuteMsg {
         royalty_bps: u16,
         royalty_recipient: String,
     },
+    /// Override the royalty for one item_type (owner only)
+    SetTypeRoyalty {
+        item_type: String,
+        royalty_bps: u16,
+        royalty_recipient: String,
+    },
+    /// Drop an item_type override, falling back to the collection royalty (owner only)
+    RemoveTypeRoyalty {
+        item_type: String,
+    },
     // FIX: L-02 — burn function
     Burn { token_id: String },
+    /// List an owned item for a fixed native-coin price; relisting replaces the price
+    ListForSale {
+        token_id: String,
+        price: cosmwasm_std::Coin,
+    },
+    /// Withdraw a listing (seller only)
+    CancelListing {
+        token_id: String,
+    },
+    /// Buy a listed item by sending exactly its price. The royalty share goes to
+    /// the royalty recipient and the rest to the seller.
+    Buy {
+        token_id: String,
+    },
+    /// Offer owned items in exchange for `counterparty`'s items. The offered items
+    /// are held by the contract until the trade is accepted or cancelled.
+    ProposeTrade {
+        counterparty: String,
+        offered_token_ids: Vec<String>,
+        requested_token_ids: Vec<String>,
+        expiry: cosmwasm_std::Timestamp,
+    },
+    /// Counterparty completes the swap; both sides move in one message
+    AcceptTrade {
+        trade_id: u64,
+    },
+    /// Proposer withdraws or counterparty declines; offered items go back to the proposer
+    CancelTrade {
+        trade_id: u64,
+    },
+    /// Mark an item equipped so it cannot be transferred, sent or approved (minter only)
+    Lock {
+        token_id: String,
+    },
+    /// Release an equipped item (minter only)
+    Unlock {
+        token_id: String,
+    },
+    /// Raise an item's level and adjust its stats in place, keeping its token_id
+    /// (minter only). Deltas are signed; a missing stat starts from 0.
+    UpgradeItem {
+        token_id: String,
+        new_level: u32,
+        stat_changes: BTreeMap<String, i64>,
+    },
+    /// Point a token at a new off-chain metadata document (minter only). The
+    /// hash of the new document is required; blocked once metadata is frozen.
+    UpdateMetadata {
+        token_id: String,
+        token_uri: String,
+        metadata_hash: cosmwasm_std::HexBinary,
+    },
+    /// Reduce an item's durability, saturating at zero (minter only). A broken
+    /// item cannot be transferred, sent or sold until repaired.
+    ApplyWear {
+        token_id: String,
+        amount: u32,
+    },
+    /// Restore an item to full durability (token owner). Must be sent exactly the
+    /// repair fee for its rarity, which goes to the royalty recipient.
+    Repair {
+        token_id: String,
+    },
+    /// Use up charges of a consumable (minter or token owner). The item is
+    /// burned once its last charge is consumed.
+    Consume {
+        token_id: String,
+        amount: u32,
+    },
+    /// Burn an item for an off-chain reward (token owner). Records a
+    /// redemption receipt under `redemption_code`, which can only be used once.
+    Redeem {
+        token_id: String,
+        redemption_code: String,
+    },
+    /// Mark a redemption's reward as granted (minter only)
+    AckRedemption {
+        redemption_id: u64,
+    },
+    /// Cap how many items of a type can ever be minted (owner only). A cap can be
+    /// lowered but never raised or removed, and not below what is already minted.
+    SetMaxSupply {
+        item_type: String,
+        max_supply: u64,
+    },
+    /// Set or clear the repair fee for a rarity (owner only)
+    SetRepairFee {
+        rarity: String,
+        fee: Option<cosmwasm_std::Coin>,
+    },
+    /// Permanently freeze one token's level and stats (owner only)
+    FreezeTokenMetadata {
+        token_id: String,
+    },
+    /// Permanently freeze level and stats of every token, present and future (owner only)
+    FreezeAllMetadata {},
+    /// Set or replace the stats an item_type may carry and their ranges (owner
+    /// only). Mint, BatchMint, Combine and UpgradeItem reject anything outside
+    /// it; tokens already minted are left as they are.
+    SetStatSchema {
+        item_type: String,
+        stats: BTreeMap<String, StatRange>,
+    },
+    /// Stop validating an item_type's stats (owner only)
+    RemoveStatSchema {
+        item_type: String,
+    },
+    /// Add or replace a crafting recipe (owner only)
+    RegisterRecipe {
+        recipe_id: String,
+        recipe: Recipe,
+    },
+    /// Retire a crafting recipe (owner only)
+    RemoveRecipe {
+        recipe_id: String,
+    },
+    /// Burn the sender's `token_ids` as the recipe's inputs (in order) and mint its output
+    Combine {
+        token_ids: Vec<String>,
+        recipe_id: String,
+    },
+    /// Socket one of your items into another (e.g. a gem into a weapon). The
+    /// child can no longer move on its own and follows the parent on every
+    /// transfer, sale and trade.
+    Attach {
+        parent_token_id: String,
+        child_token_id: String,
+    },
+    /// Take an attached item back out of its parent (token owner)
+    Detach {
+        child_token_id: String,
+    },
     // FIX: H-04 — two-step owner transfer
     ProposeOwner { new_owner: String },
     AcceptOwner {},
@@ -98,6 +289,19 @@ pub struct MintRequest {
     pub stats: BTreeMap<String, u64>,
     pub origin: String,
     pub token_uri: Option<String>,
+    pub max_durability: Option<u32>,
+    #[serde(default)]
+    pub soulbound: bool,
+    #[serde(default)]
+    pub charges: Option<u32>,
+    #[serde(default)]
+    pub metadata_hash: Option<cosmwasm_std::HexBinary>,
+}
+
+#[cw_serde]
+pub struct TransferRequest {
+    pub recipient: String,
+    pub token_id: String,
 }
 
 #[cw_serde]
@@ -119,6 +323,33 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Get the tokens owned by an address with their metadata, paginated by token_id
+    #[returns(TokensWithInfoResponse)]
+    TokensWithInfo {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Get token IDs of one item type
+    #[returns(TokensResponse)]
+    TokensByType {
+        item_type: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Get token IDs of one rarity
+    #[returns(TokensResponse)]
+    TokensByRarity {
+        rarity: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Get how many items of a type were minted and its cap, if any
+    #[returns(TypeSupplyResponse)]
+    TypeSupply { item_type: String },
+    /// Get the stat schema of an item_type; None means its stats are not checked
+    #[returns(Option<BTreeMap<String, StatRange>>)]
+    StatSchema { item_type: String },
     /// Get all token IDs in the contract
     #[returns(TokensResponse)]
     AllTokens {
@@ -128,15 +359,29 @@ pub enum QueryMsg {
     /// Get the total number of minted tokens
     #[returns(NumTokensResponse)]
     NumTokens {},
+    /// Progress of a chunked migration
+    #[returns(MigrationStatusResponse)]
+    MigrationStatus {},
     /// Get royalty info for marketplace integration
     #[returns(RoyaltyInfoResponse)]
     RoyaltyInfo {},
+    /// Get the royalty that applies to a token, honouring per-type overrides
+    #[returns(RoyaltyInfoResponse)]
+    RoyaltyInfoForToken { token_id: String },
+    /// cw2981 royalty queries, wrapped as `{"extension": {"msg": ...}}` like cw721-base
+    /// so external marketplaces can read royalties without a custom integration.
+    /// `CheckRoyalties` returns CheckRoyaltiesResponse.
+    #[returns(RoyaltiesInfoResponse)]
+    Extension { msg: Cw2981QueryMsg },
     /// Check if a spender is approved for a token
     #[returns(ApprovalResponse)]
     Approval {
         token_id: String,
         spender: String,
     },
+    /// Every unexpired approval on a token
+    #[returns(ApprovalsResponse)]
+    Approvals { token_id: String },
     /// Check if an operator is approved for all of an owner's tokens
     #[returns(OperatorResponse)]
     Operator {
@@ -146,6 +391,18 @@ pub enum QueryMsg {
     /// Get pending minter transfer info
     #[returns(Option<crate::state::PendingMinterTransfer>)]
     PendingMinter {},
+    /// Authorized minters with their quotas and usage, paginated by address
+    #[returns(MintersResponse)]
+    Minters {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Registered escrow contracts, paginated by address
+    #[returns(EscrowContractsResponse)]
+    EscrowContracts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 
     // FIX: H-04
     #[returns(Option<crate::state::PendingOwnerTransfer>)]
@@ -154,6 +411,62 @@ pub enum QueryMsg {
     // FIX: M-05 — collection info query
     #[returns(CollectionInfoResponse)]
     CollectionInfo {},
+
+    /// List active sale listings, paginated by token_id
+    #[returns(ListingsResponse)]
+    Listings {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// List one seller's active listings, paginated by token_id
+    #[returns(ListingsResponse)]
+    ListingsBySeller {
+        seller: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Check whether a token's metadata is frozen
+    #[returns(MetadataFrozenResponse)]
+    MetadataFrozen { token_id: String },
+    /// Get a token's metadata URI and the hash committed for it
+    #[returns(MetadataHashResponse)]
+    MetadataHash { token_id: String },
+    /// Get a token's recent transfers, oldest first (capped at 20)
+    #[returns(TokenHistoryResponse)]
+    TokenHistory { token_id: String },
+    /// Get the repair fee for a rarity; None means repairs are free
+    #[returns(Option<cosmwasm_std::Coin>)]
+    RepairFee { rarity: String },
+    /// Get an open trade
+    #[returns(Option<crate::state::Trade>)]
+    Trade { trade_id: u64 },
+    /// List open trades, paginated by trade_id
+    #[returns(TradesResponse)]
+    Trades {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Get a redemption receipt
+    #[returns(Option<crate::state::Redemption>)]
+    Redemption { redemption_id: u64 },
+    /// List redemptions not yet acknowledged, oldest first
+    #[returns(RedemptionsResponse)]
+    PendingRedemptions {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Get a crafting recipe
+    #[returns(Option<crate::state::Recipe>)]
+    Recipe { recipe_id: String },
+    /// List crafting recipes, paginated by recipe_id
+    #[returns(RecipesResponse)]
+    Recipes {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Items attached to a token
+    #[returns(ChildrenResponse)]
+    Children { token_id: String },
 }
 
 #[cw_serde]
@@ -162,7 +475,10 @@ pub struct NftInfoResponse {
     pub owner: String,
     pub metadata: ItemMetadata,
     pub token_uri: Option<String>,
-    pub approval: Option<String>,
+    /// Spenders holding an unexpired approval
+    pub approvals: Vec<String>,
+    pub locked: bool,
+    pub soulbound: bool,
 }
 
 #[cw_serde]
@@ -176,6 +492,11 @@ pub struct TokensResponse {
     pub tokens: Vec<String>,
 }
 
+#[cw_serde]
+pub struct TokensWithInfoResponse {
+    pub tokens: Vec<NftInfoResponse>,
+}
+
 #[cw_serde]
 pub struct NumTokensResponse {
     pub count: u64,
@@ -187,9 +508,45 @@ pub struct RoyaltyInfoResponse {
     pub royalty_recipient: String,
 }
 
+/// cw2981 query messages
+#[cw_serde]
+pub enum Cw2981QueryMsg {
+    /// Royalty owed on a sale of `token_id` at `sale_price`
+    RoyaltyInfo {
+        token_id: String,
+        sale_price: cosmwasm_std::Uint128,
+    },
+    /// Whether this contract implements cw2981
+    CheckRoyalties {},
+}
+
+#[cw_serde]
+pub struct RoyaltiesInfoResponse {
+    pub address: String,
+    pub royalty_amount: cosmwasm_std::Uint128,
+}
+
+#[cw_serde]
+pub struct CheckRoyaltiesResponse {
+    pub royalty_payments: bool,
+}
+
 #[cw_serde]
 pub struct ApprovalResponse {
     pub approved: bool,
+    /// When the approval lapses; None when not approved
+    pub expires: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct SpenderApproval {
+    pub spender: String,
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct ApprovalsResponse {
+    pub approvals: Vec<SpenderApproval>,
 }
 
 #[cw_serde]
@@ -197,6 +554,24 @@ pub struct OperatorResponse {
     pub approved: bool,
 }
 
+#[cw_serde]
+pub struct MinterEntry {
+    pub address: String,
+    pub daily_quota: Option<u32>,
+    /// Tokens minted over the last 24 hours
+    pub minted_in_window: u32,
+}
+
+#[cw_serde]
+pub struct MintersResponse {
+    pub minters: Vec<MinterEntry>,
+}
+
+#[cw_serde]
+pub struct EscrowContractsResponse {
+    pub contracts: Vec<String>,
+}
+
 // FIX: M-05
 #[cw_serde]
 pub struct CollectionInfoResponse {
@@ -205,4 +580,106 @@ pub struct CollectionInfoResponse {
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct ListingInfo {
+    pub token_id: String,
+    pub seller: String,
+    pub price: cosmwasm_std::Coin,
+}
+
+#[cw_serde]
+pub struct ListingsResponse {
+    pub listings: Vec<ListingInfo>,
+}
+
+#[cw_serde]
+pub struct RecipeInfo {
+    pub recipe_id: String,
+    pub recipe: Recipe,
+}
+
+#[cw_serde]
+pub struct RecipesResponse {
+    pub recipes: Vec<RecipeInfo>,
+}
+
+#[cw_serde]
+pub struct ChildrenResponse {
+    pub children: Vec<String>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct MigrateMsg {
+    /// Unset (the plain `{}` payload) upgrades and rebuilds the token indexes
+    /// over every token in one transaction; fine for small collections
+    #[serde(default)]
+    pub backfill: Option<BackfillMsg>,
+}
+
+/// Upgrade and index at most `limit` tokens after `start_after` (default:
+/// where the previous chunk stopped). Repeat until MigrationStatus reports
+/// `done`; minting is disabled until then.
+#[cw_serde]
+pub struct BackfillMsg {
+    pub start_after: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct MigrationStatusResponse {
+    pub version: String,
+    /// False while a chunked backfill is running
+    pub done: bool,
+    /// Tokens indexed so far in the current (or last) backfill
+    pub processed: u64,
+    /// Live tokens in the collection
+    pub total_tokens: u64,
+    /// Last token indexed
+    pub cursor: Option<String>,
+}
+
+#[cw_serde]
+pub struct TokenHistoryResponse {
+    pub token_id: String,
+    pub history: Vec<TransferRecord>,
+}
+
+#[cw_serde]
+pub struct TypeSupplyResponse {
+    pub item_type: String,
+    /// Items ever minted, including burned ones
+    pub minted: u64,
+    pub max_supply: Option<u64>,
+}
+
+#[cw_serde]
+pub struct MetadataFrozenResponse {
+    pub token_id: String,
+    pub frozen: bool,
+    /// True when frozen by the collection-wide freeze
+    pub all_frozen: bool,
+}
+
+#[cw_serde]
+pub struct MetadataHashResponse {
+    pub token_id: String,
+    pub token_uri: Option<String>,
+    /// None for tokens minted without a commitment
+    pub metadata_hash: Option<cosmwasm_std::HexBinary>,
+}
+
+#[cw_serde]
+pub struct TradeInfo {
+    pub trade_id: u64,
+    pub trade: Trade,
+}
+
+#[cw_serde]
+pub struct TradesResponse {
+    pub trades: Vec<TradeInfo>,
+}
+
+#[cw_serde]
+pub struct RedemptionsResponse {
+    pub redemptions: Vec<crate::state::Redemption>,
+}
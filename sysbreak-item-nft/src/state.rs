@@ -1,15 +1,16 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Coin, HexBinary, Timestamp};
+use cw721::Expiration;
 use cw_storage_plus::{Item, Map};
 use std::collections::BTreeMap;
+pub use sysbreak_common::{PendingMinterTransfer, PendingOwnerTransfer};
+use sysbreak_common::{Owned, Pausable, RoleTransfer};
 
 /// Contract-level configuration
 #[cw_serde]
 pub struct Config {
-    /// Contract owner — can pause, update royalties, propose minter changes
+    /// Contract owner — can pause, update royalties, manage minters
     pub owner: Addr,
-    /// Authorized minter (backend wallet)
-    pub minter: Addr,
     /// Whether the contract is paused (freezes minting + transfers)
     pub paused: bool,
     /// Royalty basis points (e.g., 500 = 5%)
@@ -21,16 +22,43 @@ pub struct Config {
     pub symbol: String,
 }
 
-/// Two-step minter transfer state
+/// Config layout before the minter set replaced the single minter; read once by migrate
 #[cw_serde]
-pub struct PendingMinterTransfer {
-    pub proposed_minter: Addr,
+pub struct ConfigV1 {
+    pub owner: Addr,
+    pub minter: Addr,
+    pub paused: bool,
+    pub royalty_bps: u16,
+    pub royalty_recipient: Addr,
+    pub name: String,
+    pub symbol: String,
 }
 
-// FIX: H-04 — two-step owner transfer state
+impl Owned for Config {
+    fn owner(&self) -> &Addr {
+        &self.owner
+    }
+
+    fn set_owner(&mut self, owner: Addr) {
+        self.owner = owner;
+    }
+}
+
+impl Pausable for Config {
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+}
+
+/// An authorized minter (one per game service or event server)
 #[cw_serde]
-pub struct PendingOwnerTransfer {
-    pub proposed_owner: Addr,
+pub struct MinterInfo {
+    /// Most tokens the minter may mint in any rolling 24 hours; None is unlimited
+    pub daily_quota: Option<u32>,
 }
 
 /// On-chain metadata for an item NFT
@@ -43,6 +71,16 @@ pub struct ItemMetadata {
     pub stats: BTreeMap<String, u64>,
     /// How this item was obtained
     pub origin: String,
+    /// Remaining durability; None for items that never wear
+    #[serde(default)]
+    pub durability: Option<u32>,
+    /// Durability restored by Repair
+    #[serde(default)]
+    pub max_durability: Option<u32>,
+    /// Uses left on a consumable; the token burns when the last one is consumed.
+    /// None for items that are not consumed.
+    #[serde(default)]
+    pub charges: Option<u32>,
 }
 
 /// Full on-chain token data (metadata + optional URI)
@@ -50,11 +88,149 @@ pub struct ItemMetadata {
 pub struct TokenData {
     pub metadata: ItemMetadata,
     pub token_uri: Option<String>,
+    /// Set by the game backend while the item is equipped; blocks transfer, send and approve
+    #[serde(default)]
+    pub locked: bool,
+    /// Bound to its holder (e.g. quest items): cannot be transferred, sent, approved or sold
+    #[serde(default)]
+    pub soulbound: bool,
+    /// Level and stats can no longer be changed; set once and never cleared
+    #[serde(default)]
+    pub metadata_frozen: bool,
+    /// SHA-256 of the off-chain metadata document behind `token_uri`
+    #[serde(default)]
+    pub metadata_hash: Option<HexBinary>,
+}
+
+/// One input slot of a crafting recipe
+#[cw_serde]
+pub struct RecipeInput {
+    pub item_type: String,
+    /// Required rarity; None accepts any
+    pub rarity: Option<String>,
+    pub min_level: u32,
+}
+
+/// Item minted to the crafter when a recipe is completed
+#[cw_serde]
+pub struct RecipeOutput {
+    pub item_type: String,
+    pub rarity: String,
+    pub level: u32,
+    pub stats: BTreeMap<String, u64>,
+    pub token_uri: Option<String>,
+    #[serde(default)]
+    pub max_durability: Option<u32>,
+    #[serde(default)]
+    pub soulbound: bool,
+    #[serde(default)]
+    pub charges: Option<u32>,
+    #[serde(default)]
+    pub metadata_hash: Option<HexBinary>,
+}
+
+/// Crafting recipe: `inputs[i]` must be satisfied by the i-th token passed to Combine
+#[cw_serde]
+pub struct Recipe {
+    pub inputs: Vec<RecipeInput>,
+    pub output: RecipeOutput,
+}
+
+/// Inclusive bounds for one stat in an item_type's schema
+#[cw_serde]
+pub struct StatRange {
+    pub min: u64,
+    pub max: u64,
+}
+
+/// Royalty override for one item_type, replacing the collection-wide default
+#[cw_serde]
+pub struct TypeRoyalty {
+    pub royalty_bps: u16,
+    pub royalty_recipient: Addr,
+}
+
+/// Fixed-price sale offer; cleared whenever the token moves, burns or locks
+#[cw_serde]
+pub struct Listing {
+    pub seller: Addr,
+    pub price: Coin,
+}
+
+/// One ownership change in a token's provenance trail
+#[cw_serde]
+pub struct TransferRecord {
+    pub from: Addr,
+    pub to: Addr,
+    pub timestamp: Timestamp,
+    /// Sale price when the transfer was a marketplace purchase
+    pub price: Option<Coin>,
+}
+
+/// Item-for-item swap offer. The offered tokens sit in the contract until the
+/// trade is accepted or cancelled.
+#[cw_serde]
+pub struct Trade {
+    pub proposer: Addr,
+    pub counterparty: Addr,
+    pub offered_token_ids: Vec<String>,
+    pub requested_token_ids: Vec<String>,
+    pub expiry: Timestamp,
+}
+
+/// Receipt for an item its owner burned in exchange for an off-chain reward.
+/// The game backend grants the reward for `redemption_code`, then a minter
+/// acknowledges it.
+#[cw_serde]
+pub struct Redemption {
+    pub id: u64,
+    pub token_id: String,
+    pub owner: Addr,
+    pub item_type: String,
+    pub rarity: String,
+    pub redemption_code: String,
+    pub redeemed_at: Timestamp,
+    /// Set by AckRedemption once the reward has been granted
+    pub acknowledged_at: Option<Timestamp>,
+    pub acknowledged_by: Option<Addr>,
+}
+
+/// How far a chunked migrate has rebuilt the token indexes from TOKEN_OWNERS
+#[cw_serde]
+#[derive(Default)]
+pub struct BackfillProgress {
+    /// Last token indexed; the next chunk resumes after it
+    pub cursor: Option<String>,
+    /// Tokens indexed so far in this run
+    pub processed: u64,
+    /// Highest numeric token id seen, saved as LAST_TOKEN_ID when done
+    pub highest_id: u64,
+    pub done: bool,
+    /// TYPE_MINTED was empty when this run started, so it is counted from live tokens
+    pub count_minted: bool,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
+/// Legacy single-minter config under the same key (read by migrate)
+pub const CONFIG_V1: Item<ConfigV1> = Item::new("config");
+/// Authorized minters: minter_addr -> MinterInfo
+pub const MINTERS: Map<&Addr, MinterInfo> = Map::new("minters");
+/// Mints per minter and hour, for the rolling quota: (minter_addr, hour) -> count
+pub const MINTER_USAGE: Map<(&Addr, u64), u32> = Map::new("minter_usage");
+/// Rolling window the minter quotas apply to (24 hours)
+pub const MINT_QUOTA_WINDOW: u64 = 86_400;
+/// Granularity of the minter quota tracking (1 hour)
+pub const MINT_QUOTA_BUCKET: u64 = 3_600;
+/// Number of live tokens (decremented on burn)
 pub const TOKEN_COUNT: Item<u64> = Item::new("token_count");
-pub const PENDING_MINTER: Item<PendingMinterTransfer> = Item::new("pending_minter");
+/// Last issued token id. Separate from TOKEN_COUNT so ids are never reused after a burn.
+pub const LAST_TOKEN_ID: Item<u64> = Item::new("last_token_id");
+pub const MINTER_TRANSFER: RoleTransfer<PendingMinterTransfer> =
+    RoleTransfer::new("minter", "pending_minter");
+/// Collection-wide metadata freeze; irreversible once set
+pub const ALL_METADATA_FROZEN: Item<bool> = Item::new("all_metadata_frozen");
+/// Token index backfill run by migrate; minting is disabled until it is done
+pub const BACKFILL: Item<BackfillProgress> = Item::new("backfill");
 
 /// token_id (string of u64) -> TokenData
 pub const TOKENS: Map<&str, TokenData> = Map::new("item_tokens");
@@ -62,15 +238,75 @@ pub const TOKENS: Map<&str, TokenData> = Map::new("item_tokens");
 /// token_id (string of u64) -> owner Addr
 pub const TOKEN_OWNERS: Map<&str, Addr> = Map::new("item_owners");
 
-/// token_id -> spender Addr (single approval per token)
-pub const TOKEN_APPROVALS: Map<&str, Addr> = Map::new("item_approvals");
+/// (token_id, spender) -> when the approval lapses (cw721 semantics)
+pub const TOKEN_APPROVALS: Map<(&str, &Addr), Expiration> = Map::new("item_token_approvals");
+/// Pre-upgrade single approval per token, moved into TOKEN_APPROVALS by the
+/// migration backfill; honoured as never expiring until then
+pub const LEGACY_TOKEN_APPROVALS: Map<&str, Addr> = Map::new("item_approvals");
+/// Most spenders approved on one token at a time
+pub const MAX_TOKEN_APPROVALS: usize = 10;
 
 /// (owner, operator) -> bool (operator approvals)
 pub const OPERATOR_APPROVALS: Map<(&Addr, &Addr), bool> = Map::new("item_operators");
 
+/// Crafting recipes: recipe_id -> Recipe
+pub const RECIPES: Map<&str, Recipe> = Map::new("recipes");
+
+/// Per-type royalty overrides: item_type -> TypeRoyalty
+pub const TYPE_ROYALTIES: Map<&str, TypeRoyalty> = Map::new("type_royalties");
+
+/// Repair fee per rarity: rarity -> Coin. Rarities without a fee repair for free.
+pub const REPAIR_FEES: Map<&str, Coin> = Map::new("repair_fees");
+
+/// Provenance: token_id -> most recent transfers, oldest first
+pub const TOKEN_HISTORY: Map<&str, Vec<TransferRecord>> = Map::new("token_history");
+
+/// Socketed items: child token_id -> parent token_id
+pub const ITEM_PARENT: Map<&str, String> = Map::new("item_parent");
+/// (parent token_id, child token_id) -> bool
+pub const ITEM_CHILDREN: Map<(&str, &str), bool> = Map::new("item_children");
+
+/// Open trades: trade_id -> Trade
+pub const TRADES: Map<u64, Trade> = Map::new("trades");
+/// Redemption receipts: id -> Redemption
+pub const REDEMPTIONS: Map<u64, Redemption> = Map::new("redemptions");
+pub const LAST_REDEMPTION_ID: Item<u64> = Item::new("last_redemption_id");
+/// Redemptions awaiting AckRedemption: id -> ()
+pub const PENDING_REDEMPTIONS: Map<u64, ()> = Map::new("pending_redemptions");
+/// Codes already redeemed, so a reward cannot be claimed twice: code -> redemption id
+pub const REDEMPTION_CODES: Map<&str, u64> = Map::new("redemption_codes");
+/// Longest accepted redemption code
+pub const MAX_REDEMPTION_CODE_LEN: usize = 64;
+pub const LAST_TRADE_ID: Item<u64> = Item::new("last_trade_id");
+
+/// Contracts that hold items on players' behalf (marketplace, staking). Items
+/// they hold cannot be consumed, worn or locked, as the holder has to be able
+/// to hand them back: contract_addr -> bool
+pub const ESCROW_CONTRACTS: Map<&Addr, bool> = Map::new("escrow_contracts");
+
+/// Active sale listings: token_id -> Listing
+pub const LISTINGS: Map<&str, Listing> = Map::new("listings");
+/// (seller_addr, token_id) -> bool, for per-seller listing queries
+pub const SELLER_LISTINGS: Map<(&Addr, &str), bool> = Map::new("seller_listings");
+
 // FIX: H-04 — pending owner transfer storage
-pub const PENDING_OWNER: Item<PendingOwnerTransfer> = Item::new("pending_owner");
+pub const OWNER_TRANSFER: RoleTransfer<PendingOwnerTransfer> =
+    RoleTransfer::new("owner", "pending_owner");
 
 // FIX: M-06 — secondary index for efficient owner-based token queries
 /// (owner_addr, token_id) -> bool
 pub const OWNER_TOKENS: Map<(&Addr, &str), bool> = Map::new("owner_tokens");
+
+/// (item_type, token_id) -> bool
+pub const TYPE_TOKENS: Map<(&str, &str), bool> = Map::new("type_tokens");
+/// item_type -> tokens ever minted of that type (burns do not give supply back)
+pub const TYPE_MINTED: Map<&str, u64> = Map::new("type_minted");
+/// item_type -> mint cap; can only be lowered once set
+pub const TYPE_MAX_SUPPLY: Map<&str, u64> = Map::new("type_max_supply");
+/// item_type -> allowed stat keys and their ranges; types without one are not checked
+pub const STAT_SCHEMAS: Map<&str, BTreeMap<String, StatRange>> = Map::new("stat_schemas");
+/// Most stats one schema can list
+pub const MAX_SCHEMA_STATS: usize = 32;
+
+/// (rarity, token_id) -> bool
+pub const RARITY_TOKENS: Map<(&str, &str), bool> = Map::new("rarity_tokens");
@@ -1,11 +1,16 @@
 use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
-use cosmwasm_std::{from_json, Addr};
+use cosmwasm_std::{from_json, Addr, Event, HexBinary};
 use std::collections::BTreeMap;
 
+use sysbreak_common::AdminError;
+use sysbreak_events::{BurnEvent, MintEvent};
 use sysbreak_item_nft::contract::*;
 use sysbreak_item_nft::error::ContractError;
 use sysbreak_item_nft::msg::*;
-use sysbreak_item_nft::state::Config;
+use sysbreak_item_nft::state::{
+    Config, Recipe, RecipeInput, RecipeOutput, Redemption, StatRange, LAST_TOKEN_ID,
+    LEGACY_TOKEN_APPROVALS, OWNER_TOKENS, RARITY_TOKENS, TYPE_MINTED, TYPE_TOKENS,
+};
 
 fn addr(deps: &cosmwasm_std::OwnedDeps<cosmwasm_std::MemoryStorage, cosmwasm_std::testing::MockApi, cosmwasm_std::testing::MockQuerier>, name: &str) -> Addr {
     deps.api.addr_make(name)
@@ -41,6 +46,38 @@ fn default_stats() -> BTreeMap<String, u64> {
     stats
 }
 
+fn mint_item(
+    deps: &mut cosmwasm_std::OwnedDeps<
+        cosmwasm_std::MemoryStorage,
+        cosmwasm_std::testing::MockApi,
+        cosmwasm_std::testing::MockQuerier,
+    >,
+    to: &Addr,
+    item_type: &str,
+    rarity: &str,
+    level: u32,
+) -> String {
+    let minter = addr(deps, "minter");
+    let res = execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        to.to_string(),
+        item_type.to_string(),
+        rarity.to_string(),
+        level,
+        default_stats(),
+        "dropped".to_string(),
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .unwrap();
+    res.events[0].attributes[0].value.clone()
+}
+
 // ─── Instantiation ──────────────────────────────────────────────────────────
 
 #[test]
@@ -51,8 +88,12 @@ fn test_instantiate() {
 
     let res: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
     assert_eq!(res.owner, owner);
-    assert_eq!(res.minter, minter);
     assert!(!res.paused);
+    let minters: MintersResponse =
+        from_json(query_minters(deps.as_ref(), mock_env(), None, None).unwrap()).unwrap();
+    assert_eq!(minters.minters.len(), 1);
+    assert_eq!(minters.minters[0].address, minter.to_string());
+    assert_eq!(minters.minters[0].daily_quota, None);
     assert_eq!(res.royalty_bps, 500);
 }
 
@@ -96,14 +137,25 @@ fn test_mint_by_minter() {
         default_stats(),
         "dropped".to_string(),
         Some("ipfs://Qm123".to_string()),
+        None,
+        false,
+        None,
+        None,
     )
     .unwrap();
 
     assert_eq!(res.attributes[0].value, "mint");
-    assert_eq!(res.attributes[1].value, "1");
+    assert_eq!(
+        res.events,
+        vec![Event::from(MintEvent {
+            token_id: "1".to_string(),
+            owner: user_a.to_string(),
+            minter: minter.to_string(),
+        })]
+    );
 
     let nft: NftInfoResponse =
-        from_json(query_nft_info(deps.as_ref(), "1".to_string()).unwrap()).unwrap();
+        from_json(query_nft_info(deps.as_ref(), mock_env(), "1".to_string()).unwrap()).unwrap();
     assert_eq!(nft.owner, user_a.to_string());
     assert_eq!(nft.metadata.item_type, "weapon");
     assert_eq!(nft.metadata.rarity, "rare");
@@ -128,6 +180,10 @@ fn test_mint_by_non_minter_fails() {
         BTreeMap::new(),
         "crafted".to_string(),
         None,
+        None,
+        false,
+        None,
+        None,
     )
     .unwrap_err();
 
@@ -157,6 +213,10 @@ fn test_batch_mint() {
             stats: BTreeMap::new(),
             origin: "crafted".to_string(),
             token_uri: None,
+            max_durability: None,
+            soulbound: false,
+            charges: None,
+            metadata_hash: None,
         })
         .collect();
 
@@ -193,6 +253,10 @@ fn test_batch_mint_too_large_fails() {
             stats: BTreeMap::new(),
             origin: "crafted".to_string(),
             token_uri: None,
+            max_durability: None,
+            soulbound: false,
+            charges: None,
+            metadata_hash: None,
         })
         .collect();
 
@@ -221,6 +285,10 @@ fn test_transfer_nft() {
         BTreeMap::new(),
         "dropped".to_string(),
         None,
+        None,
+        false,
+        None,
+        None,
     )
     .unwrap();
 
@@ -235,7 +303,7 @@ fn test_transfer_nft() {
     .unwrap();
 
     let owner: OwnerOfResponse =
-        from_json(query_owner_of(deps.as_ref(), "1".to_string()).unwrap()).unwrap();
+        from_json(query_owner_of(deps.as_ref(), mock_env(), "1".to_string()).unwrap()).unwrap();
     assert_eq!(owner.owner, user_b.to_string());
 }
 
@@ -258,6 +326,10 @@ fn test_transfer_unauthorized_fails() {
         BTreeMap::new(),
         "dropped".to_string(),
         None,
+        None,
+        false,
+        None,
+        None,
     )
     .unwrap();
 
@@ -300,6 +372,10 @@ fn test_approve_and_transfer() {
         BTreeMap::new(),
         "dropped".to_string(),
         None,
+        None,
+        false,
+        None,
+        None,
     )
     .unwrap();
 
@@ -311,11 +387,12 @@ fn test_approve_and_transfer() {
         info,
         user_b.to_string(),
         "1".to_string(),
+        None,
     )
     .unwrap();
 
     let approval: ApprovalResponse = from_json(
-        query_approval(deps.as_ref(), "1".to_string(), user_b.to_string()).unwrap(),
+        query_approval(deps.as_ref(), mock_env(), "1".to_string(), user_b.to_string()).unwrap(),
     )
     .unwrap();
     assert!(approval.approved);
@@ -332,17 +409,139 @@ fn test_approve_and_transfer() {
     .unwrap();
 
     let owner_resp: OwnerOfResponse =
-        from_json(query_owner_of(deps.as_ref(), "1".to_string()).unwrap()).unwrap();
+        from_json(query_owner_of(deps.as_ref(), mock_env(), "1".to_string()).unwrap()).unwrap();
     assert_eq!(owner_resp.owner, user_b.to_string());
 
     // Approval cleared after transfer
     let approval: ApprovalResponse = from_json(
-        query_approval(deps.as_ref(), "1".to_string(), user_b.to_string()).unwrap(),
+        query_approval(deps.as_ref(), mock_env(), "1".to_string(), user_b.to_string()).unwrap(),
     )
     .unwrap();
     assert!(!approval.approved);
 }
 
+#[test]
+fn test_multiple_approvals_with_expiry() {
+    let mut deps = setup_contract();
+    let user_a = addr(&deps, "user_a");
+    let market = addr(&deps, "market");
+    let friend = addr(&deps, "friend");
+    let token_id = mint_item(&mut deps, &user_a, "weapon", "common", 1);
+    let env = mock_env();
+    let approve = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, env, spender: &Addr, expires| {
+        execute_approve(
+            deps.as_mut(),
+            env,
+            message_info(&user_a, &[]),
+            spender.to_string(),
+            token_id.clone(),
+            expires,
+        )
+    };
+
+    let err = approve(&mut deps, env.clone(), &market, Some(Expiration::AtHeight(env.block.height)))
+        .unwrap_err();
+    assert_eq!(err, ContractError::ApprovalExpired);
+    let deadline = Expiration::AtHeight(env.block.height + 10);
+    approve(&mut deps, env.clone(), &market, Some(deadline)).unwrap();
+    approve(&mut deps, env.clone(), &friend, None).unwrap();
+
+    let mut expected = vec![
+        SpenderApproval {
+            spender: market.to_string(),
+            expires: deadline,
+        },
+        SpenderApproval {
+            spender: friend.to_string(),
+            expires: Expiration::Never {},
+        },
+    ];
+    expected.sort_by(|a, b| a.spender.cmp(&b.spender));
+    let approvals: ApprovalsResponse =
+        from_json(query_approvals(deps.as_ref(), env.clone(), token_id.clone()).unwrap()).unwrap();
+    assert_eq!(approvals.approvals, expected);
+
+    // The market's approval lapses; the friend's keeps working
+    let mut later = env.clone();
+    later.block.height += 10;
+    let err = execute_transfer_nft(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&market, &[]),
+        market.to_string(),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized { .. }));
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), later.clone(), token_id.clone()).unwrap()).unwrap();
+    assert_eq!(nft.approvals, vec![friend.to_string()]);
+
+    // Revoking one spender leaves the rest
+    approve(&mut deps, later.clone(), &market, None).unwrap();
+    execute_revoke(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&user_a, &[]),
+        Some(friend.to_string()),
+        token_id.clone(),
+    )
+    .unwrap();
+    let approval: ApprovalResponse = from_json(
+        query_approval(deps.as_ref(), later.clone(), token_id.clone(), market.to_string()).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(approval.expires, Some(Expiration::Never {}));
+
+    // Capped per token
+    for i in 1..10 {
+        let spender = addr(&deps, &format!("spender{i}"));
+        approve(&mut deps, later.clone(), &spender, None).unwrap();
+    }
+    let err = approve(&mut deps, later.clone(), &friend, None).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TooManyApprovals {
+            token_id: token_id.clone(),
+            max: 10
+        }
+    );
+
+    // A transfer clears every approval
+    execute_transfer_nft(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&market, &[]),
+        market.to_string(),
+        token_id.clone(),
+    )
+    .unwrap();
+    let approvals: ApprovalsResponse =
+        from_json(query_approvals(deps.as_ref(), later, token_id.clone()).unwrap()).unwrap();
+    assert!(approvals.approvals.is_empty());
+
+    // A pre-upgrade approval keeps working and becomes a never-expiring entry on migrate
+    LEGACY_TOKEN_APPROVALS
+        .save(deps.as_mut().storage, &token_id, &friend)
+        .unwrap();
+    let approval: ApprovalResponse = from_json(
+        query_approval(deps.as_ref(), env.clone(), token_id.clone(), friend.to_string()).unwrap(),
+    )
+    .unwrap();
+    assert!(approval.approved);
+    migrate(deps.as_mut(), env.clone(), MigrateMsg::default()).unwrap();
+    assert!(!LEGACY_TOKEN_APPROVALS.has(deps.as_ref().storage, &token_id));
+    let approvals: ApprovalsResponse =
+        from_json(query_approvals(deps.as_ref(), env, token_id).unwrap()).unwrap();
+    assert_eq!(
+        approvals.approvals,
+        vec![SpenderApproval {
+            spender: friend.to_string(),
+            expires: Expiration::Never {},
+        }]
+    );
+}
+
 #[test]
 fn test_operator_approval() {
     let mut deps = setup_contract();
@@ -363,6 +562,10 @@ fn test_operator_approval() {
             BTreeMap::new(),
             "dropped".to_string(),
             None,
+            None,
+            false,
+            None,
+            None,
         )
         .unwrap();
     }
@@ -406,7 +609,14 @@ fn test_two_step_minter_transfer() {
     let user_a = addr(&deps, "user_a");
 
     let info = message_info(&owner, &[]);
-    execute_propose_minter(deps.as_mut(), mock_env(), info, new_minter.to_string()).unwrap();
+    execute_propose_minter(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        new_minter.to_string(),
+        None,
+    )
+    .unwrap();
 
     let pending: Option<sysbreak_item_nft::state::PendingMinterTransfer> =
         from_json(query_pending_minter(deps.as_ref()).unwrap()).unwrap();
@@ -415,11 +625,65 @@ fn test_two_step_minter_transfer() {
     let info = message_info(&new_minter, &[]);
     execute_accept_minter(deps.as_mut(), mock_env(), info).unwrap();
 
-    let config: Config = from_json(query_config(deps.as_ref()).unwrap()).unwrap();
-    assert_eq!(config.minter, new_minter);
+    let minters: MintersResponse =
+        from_json(query_minters(deps.as_ref(), mock_env(), None, None).unwrap()).unwrap();
+    assert_eq!(minters.minters.len(), 2);
 
-    // Old minter can no longer mint
-    let info = message_info(&minter, &[]);
+    // Rotating a key out revokes only that key and hands its quota over
+    let event_server = addr(&deps, "event_server");
+    let event_server_v2 = addr(&deps, "event_server_v2");
+    let info = message_info(&owner, &[]);
+    execute_add_minter(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        event_server.to_string(),
+        Some(3),
+    )
+    .unwrap();
+    let err = execute_propose_minter(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        event_server_v2.to_string(),
+        Some(user_a.to_string()),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotMinter {
+            address: user_a.to_string()
+        }
+    );
+    execute_propose_minter(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        event_server_v2.to_string(),
+        Some(event_server.to_string()),
+    )
+    .unwrap();
+    let info = message_info(&event_server_v2, &[]);
+    execute_accept_minter(deps.as_mut(), mock_env(), info).unwrap();
+
+    let minters: MintersResponse =
+        from_json(query_minters(deps.as_ref(), mock_env(), None, None).unwrap()).unwrap();
+    let mut expected = vec![
+        (minter.to_string(), None),
+        (new_minter.to_string(), None),
+        (event_server_v2.to_string(), Some(3)),
+    ];
+    expected.sort();
+    let mut actual: Vec<_> = minters
+        .minters
+        .into_iter()
+        .map(|m| (m.address, m.daily_quota))
+        .collect();
+    actual.sort();
+    assert_eq!(actual, expected);
+
+    // The rotated-out key can no longer mint
+    let info = message_info(&event_server, &[]);
     let err = execute_mint(
         deps.as_mut(),
         mock_env(),
@@ -431,6 +695,10 @@ fn test_two_step_minter_transfer() {
         BTreeMap::new(),
         "dropped".to_string(),
         None,
+        None,
+        false,
+        None,
+        None,
     )
     .unwrap_err();
     assert_eq!(
@@ -442,19 +710,147 @@ fn test_two_step_minter_transfer() {
 }
 
 #[test]
-fn test_non_owner_cannot_propose_minter() {
+fn test_minter_daily_quota() {
     let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let event_server = addr(&deps, "event_server");
     let user_a = addr(&deps, "user_a");
+    let mint_as = |deps: cosmwasm_std::DepsMut, env, sender: &Addr| {
+        execute_mint(
+            deps,
+            env,
+            message_info(sender, &[]),
+            user_a.to_string(),
+            "weapon".to_string(),
+            "legendary".to_string(),
+            1,
+            BTreeMap::new(),
+            "event".to_string(),
+            None,
+            None,
+            false,
+            None,
+            None,
+        )
+    };
 
     let info = message_info(&user_a, &[]);
-    let err =
-        execute_propose_minter(deps.as_mut(), mock_env(), info, user_a.to_string()).unwrap_err();
+    let err = execute_add_minter(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        event_server.to_string(),
+        Some(3),
+    )
+    .unwrap_err();
     assert_eq!(
         err,
-        ContractError::Unauthorized {
+        ContractError::Admin(AdminError::Unauthorized {
             role: "owner".to_string()
+        })
+    );
+    let info = message_info(&owner, &[]);
+    execute_add_minter(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        event_server.to_string(),
+        Some(3),
+    )
+    .unwrap();
+    let err = execute_add_minter(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        event_server.to_string(),
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::AlreadyMinter { .. }));
+
+    // Two now, then a batch of two would go over
+    mint_as(deps.as_mut(), mock_env(), &event_server).unwrap();
+    mint_as(deps.as_mut(), mock_env(), &event_server).unwrap();
+    let request = MintRequest {
+        to: user_a.to_string(),
+        item_type: "weapon".to_string(),
+        rarity: "legendary".to_string(),
+        level: 1,
+        stats: BTreeMap::new(),
+        origin: "event".to_string(),
+        token_uri: None,
+        max_durability: None,
+        soulbound: false,
+        charges: None,
+        metadata_hash: None,
+    };
+    let err = execute_batch_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&event_server, &[]),
+        vec![request.clone(), request],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MintQuotaExceeded {
+            used: 2,
+            requested: 2,
+            quota: 3
         }
     );
+
+    // The window rolls: 12h later the first two still count
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(12 * 3_600);
+    mint_as(deps.as_mut(), env.clone(), &event_server).unwrap();
+    let err = mint_as(deps.as_mut(), env.clone(), &event_server).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::MintQuotaExceeded { used: 3, .. }
+    ));
+    let minters: MintersResponse =
+        from_json(query_minters(deps.as_ref(), env.clone(), None, None).unwrap()).unwrap();
+    let entry = minters
+        .minters
+        .iter()
+        .find(|m| m.address == event_server.to_string())
+        .unwrap();
+    assert_eq!(entry.daily_quota, Some(3));
+    assert_eq!(entry.minted_in_window, 3);
+
+    // A day after the first mints only the later one is left in the window
+    env.block.time = mock_env().block.time.plus_seconds(24 * 3_600);
+    mint_as(deps.as_mut(), env.clone(), &event_server).unwrap();
+    mint_as(deps.as_mut(), env.clone(), &event_server).unwrap();
+
+    // Lifting the quota; the unlimited default minter was never counted
+    execute_set_minter_quota(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        event_server.to_string(),
+        None,
+    )
+    .unwrap();
+    mint_as(deps.as_mut(), env.clone(), &event_server).unwrap();
+    mint_item(&mut deps, &user_a, "weapon", "common", 1);
+}
+
+#[test]
+fn test_non_owner_cannot_propose_minter() {
+    let mut deps = setup_contract();
+    let user_a = addr(&deps, "user_a");
+
+    let info = message_info(&user_a, &[]);
+    let err = execute_propose_minter(deps.as_mut(), mock_env(), info, user_a.to_string(), None)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
 }
 
 #[test]
@@ -465,11 +861,23 @@ fn test_wrong_address_cannot_accept_minter() {
     let user_a = addr(&deps, "user_a");
 
     let info = message_info(&owner, &[]);
-    execute_propose_minter(deps.as_mut(), mock_env(), info, new_minter.to_string()).unwrap();
+    execute_propose_minter(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        new_minter.to_string(),
+        None,
+    )
+    .unwrap();
 
     let info = message_info(&user_a, &[]);
     let err = execute_accept_minter(deps.as_mut(), mock_env(), info).unwrap_err();
-    assert_eq!(err, ContractError::NotPendingMinter);
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::NotPendingRole {
+            role: "minter".to_string()
+        })
+    );
 }
 
 #[test]
@@ -479,7 +887,14 @@ fn test_cancel_minter_transfer() {
     let new_minter = addr(&deps, "new_minter");
 
     let info = message_info(&owner, &[]);
-    execute_propose_minter(deps.as_mut(), mock_env(), info, new_minter.to_string()).unwrap();
+    execute_propose_minter(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        new_minter.to_string(),
+        None,
+    )
+    .unwrap();
 
     let info = message_info(&owner, &[]);
     execute_cancel_minter_transfer(deps.as_mut(), mock_env(), info).unwrap();
@@ -512,6 +927,10 @@ fn test_pause_blocks_mint_and_transfer() {
         BTreeMap::new(),
         "dropped".to_string(),
         None,
+        None,
+        false,
+        None,
+        None,
     )
     .unwrap();
 
@@ -532,9 +951,13 @@ fn test_pause_blocks_mint_and_transfer() {
         BTreeMap::new(),
         "dropped".to_string(),
         None,
+        None,
+        false,
+        None,
+        None,
     )
     .unwrap_err();
-    assert_eq!(err, ContractError::Paused);
+    assert_eq!(err, ContractError::Admin(AdminError::Paused));
 
     // Transfer fails
     let info = message_info(&user_a, &[]);
@@ -546,7 +969,7 @@ fn test_pause_blocks_mint_and_transfer() {
         "1".to_string(),
     )
     .unwrap_err();
-    assert_eq!(err, ContractError::Paused);
+    assert_eq!(err, ContractError::Admin(AdminError::Paused));
 
     // Unpause
     let info = message_info(&owner, &[]);
@@ -573,9 +996,9 @@ fn test_non_owner_cannot_pause() {
     let err = execute_pause(deps.as_mut(), mock_env(), info).unwrap_err();
     assert_eq!(
         err,
-        ContractError::Unauthorized {
+        ContractError::Admin(AdminError::Unauthorized {
             role: "owner".to_string()
-        }
+        })
     );
 }
 
@@ -592,6 +1015,40 @@ fn test_royalty_info() {
     assert_eq!(info.royalty_recipient, royalty.to_string());
 }
 
+#[test]
+fn test_cw2981_royalty_queries() {
+    let mut deps = setup_contract();
+    let royalty = addr(&deps, "royalty");
+    let user_a = addr(&deps, "user_a");
+    let token_id = mint_item(&mut deps, &user_a, "weapon", "rare", 5);
+
+    // Wire format expected by cw2981-aware marketplaces
+    let msg = QueryMsg::Extension {
+        msg: Cw2981QueryMsg::CheckRoyalties {},
+    };
+    assert_eq!(
+        cosmwasm_std::to_json_string(&msg).unwrap(),
+        r#"{"extension":{"msg":{"check_royalties":{}}}}"#
+    );
+    let res: CheckRoyaltiesResponse =
+        from_json(query_cw2981(deps.as_ref(), Cw2981QueryMsg::CheckRoyalties {}).unwrap()).unwrap();
+    assert!(res.royalty_payments);
+
+    let res: RoyaltiesInfoResponse = from_json(
+        query_cw2981(
+            deps.as_ref(),
+            Cw2981QueryMsg::RoyaltyInfo {
+                token_id,
+                sale_price: cosmwasm_std::Uint128::new(1_000_000),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.address, royalty.to_string());
+    assert_eq!(res.royalty_amount, cosmwasm_std::Uint128::new(50_000));
+}
+
 #[test]
 fn test_update_royalty() {
     let mut deps = setup_contract();
@@ -614,6 +1071,72 @@ fn test_update_royalty() {
     assert_eq!(royalty.royalty_recipient, new_royalty.to_string());
 }
 
+#[test]
+fn test_type_royalty_override() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let seller = addr(&deps, "user_a");
+    let buyer = addr(&deps, "user_b");
+    let guild = addr(&deps, "guild_treasury");
+    let legendary = mint_item(&mut deps, &seller, "legendary_weapon", "legendary", 50);
+    let plain = mint_item(&mut deps, &seller, "weapon", "common", 1);
+
+    execute_set_type_royalty(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "legendary_weapon".to_string(),
+        1_000,
+        guild.to_string(),
+    )
+    .unwrap();
+
+    let info: RoyaltyInfoResponse =
+        from_json(query_royalty_info_for_token(deps.as_ref(), legendary.clone()).unwrap()).unwrap();
+    assert_eq!(info.royalty_bps, 1_000);
+    assert_eq!(info.royalty_recipient, guild.to_string());
+    let info: RoyaltyInfoResponse =
+        from_json(query_royalty_info_for_token(deps.as_ref(), plain).unwrap()).unwrap();
+    assert_eq!(info.royalty_bps, 500);
+
+    // Marketplace sale settles at the override rate
+    let price = cosmwasm_std::coin(1_000, "ushido");
+    execute_list_for_sale(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&seller, &[]),
+        legendary.clone(),
+        price.clone(),
+    )
+    .unwrap();
+    let res = execute_buy(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&buyer, std::slice::from_ref(&price)),
+        legendary.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        cosmwasm_std::BankMsg::Send {
+            to_address: guild.to_string(),
+            amount: vec![cosmwasm_std::coin(100, "ushido")],
+        }
+        .into()
+    );
+
+    execute_remove_type_royalty(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "legendary_weapon".to_string(),
+    )
+    .unwrap();
+    let info: RoyaltyInfoResponse =
+        from_json(query_royalty_info_for_token(deps.as_ref(), legendary).unwrap()).unwrap();
+    assert_eq!(info.royalty_bps, 500);
+}
+
 // ─── Token Queries ──────────────────────────────────────────────────────────
 
 #[test]
@@ -636,6 +1159,10 @@ fn test_tokens_by_owner() {
             BTreeMap::new(),
             "dropped".to_string(),
             None,
+            None,
+            false,
+            None,
+            None,
         )
         .unwrap();
     }
@@ -651,6 +1178,10 @@ fn test_tokens_by_owner() {
             BTreeMap::new(),
             "crafted".to_string(),
             None,
+            None,
+            false,
+            None,
+            None,
         )
         .unwrap();
     }
@@ -669,13 +1200,346 @@ fn test_tokens_by_owner() {
 }
 
 #[test]
-fn test_sequential_token_ids() {
+fn test_tokens_with_info() {
     let mut deps = setup_contract();
-    let minter = addr(&deps, "minter");
     let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    let t1 = mint_item(&mut deps, &user_a, "weapon", "common", 1);
+    mint_item(&mut deps, &user_b, "implant", "rare", 3);
+    let t3 = mint_item(&mut deps, &user_a, "implant", "epic", 5);
 
-    let info = message_info(&minter, &[]);
-    for i in 1..=5u64 {
+    let page: TokensWithInfoResponse = from_json(
+        query_tokens_with_info(deps.as_ref(), mock_env(), user_a.to_string(), None, Some(1)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(page.tokens.len(), 1);
+    let first = &page.tokens[0];
+    assert_eq!(first.token_id, t1);
+    assert_eq!(first.owner, user_a.to_string());
+    assert_eq!(first.metadata.item_type, "weapon");
+    assert_eq!(
+        *first,
+        from_json::<NftInfoResponse>(query_nft_info(deps.as_ref(), mock_env(), t1.clone()).unwrap()).unwrap()
+    );
+
+    let page: TokensWithInfoResponse = from_json(
+        query_tokens_with_info(deps.as_ref(), mock_env(), user_a.to_string(), Some(t1), None).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(page.tokens.len(), 1);
+    assert_eq!(page.tokens[0].token_id, t3);
+    assert_eq!(page.tokens[0].metadata.rarity, "epic");
+}
+
+#[test]
+fn test_tokens_by_type_and_rarity() {
+    let mut deps = setup_contract();
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+    mint_item(&mut deps, &user_a, "weapon", "rare", 1);
+    mint_item(&mut deps, &user_a, "armor", "rare", 1);
+    mint_item(&mut deps, &user_a, "weapon", "common", 1);
+
+    let by_type = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, item_type: &str| -> Vec<String> {
+        let res: TokensResponse = from_json(
+            query_tokens_by_type(deps.as_ref(), item_type.to_string(), None, None).unwrap(),
+        )
+        .unwrap();
+        res.tokens
+    };
+    assert_eq!(by_type(&deps, "weapon"), vec!["1", "3"]);
+    let res: TokensResponse =
+        from_json(query_tokens_by_rarity(deps.as_ref(), "rare".to_string(), None, None).unwrap())
+            .unwrap();
+    assert_eq!(res.tokens, vec!["1", "2"]);
+
+    execute_burn(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        "1".to_string(),
+    )
+    .unwrap();
+    assert_eq!(by_type(&deps, "weapon"), vec!["3"]);
+}
+
+#[test]
+fn test_type_supply_cap() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let user_a = addr(&deps, "user_a");
+    let first = mint_item(&mut deps, &user_a, "founders_blade", "legendary", 1);
+    mint_item(&mut deps, &user_a, "founders_blade", "legendary", 1);
+
+    let err = execute_set_max_supply(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "founders_blade".to_string(),
+        1,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidSupplyCap {
+            reason: "2 already minted".to_string()
+        }
+    );
+    execute_set_max_supply(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "founders_blade".to_string(),
+        3,
+    )
+    .unwrap();
+    let err = execute_set_max_supply(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "founders_blade".to_string(),
+        4,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidSupplyCap {
+            reason: "cannot raise cap above 3".to_string()
+        }
+    );
+
+    mint_item(&mut deps, &user_a, "founders_blade", "legendary", 1);
+    // Burning does not free up supply
+    let minter = addr(&deps, "minter");
+    execute_burn(deps.as_mut(), mock_env(), message_info(&minter, &[]), first).unwrap();
+    let err = execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        user_a.to_string(),
+        "founders_blade".to_string(),
+        "legendary".to_string(),
+        1,
+        default_stats(),
+        "promo".to_string(),
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SupplyCapReached {
+            item_type: "founders_blade".to_string(),
+            max_supply: 3
+        }
+    );
+
+    let supply: TypeSupplyResponse =
+        from_json(query_type_supply(deps.as_ref(), "founders_blade".to_string()).unwrap()).unwrap();
+    assert_eq!(supply.minted, 3);
+    assert_eq!(supply.max_supply, Some(3));
+}
+
+#[test]
+fn test_stat_schema_validation() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+
+    let range = |min, max| StatRange { min, max };
+    let mut schema = BTreeMap::new();
+    schema.insert("damage".to_string(), range(1, 100));
+    schema.insert("speed".to_string(), range(20, 10));
+    let err = execute_set_stat_schema(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        "weapon".to_string(),
+        schema.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized { .. })
+    ));
+    let err = execute_set_stat_schema(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "weapon".to_string(),
+        schema.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidStatSchema {
+            reason: "speed has min above max".to_string()
+        }
+    );
+    schema.insert("speed".to_string(), range(0, 20));
+    execute_set_stat_schema(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "weapon".to_string(),
+        schema.clone(),
+    )
+    .unwrap();
+    let stored: Option<BTreeMap<String, StatRange>> =
+        from_json(query_stat_schema(deps.as_ref(), "weapon".to_string()).unwrap()).unwrap();
+    assert_eq!(stored, Some(schema));
+
+    // damage 42 and speed 10 fit the schema
+    let token_id = mint_item(&mut deps, &user_a, "weapon", "rare", 1);
+
+    let mint = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, stats: BTreeMap<String, u64>| {
+        execute_mint(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&minter, &[]),
+            user_a.to_string(),
+            "weapon".to_string(),
+            "rare".to_string(),
+            1,
+            stats,
+            "dropped".to_string(),
+            None,
+            None,
+            false,
+            None,
+            None,
+        )
+    };
+    let mut stats = default_stats();
+    stats.insert("damage".to_string(), u64::MAX);
+    let err = mint(&mut deps, stats).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::StatValueOutOfRange {
+            item_type: "weapon".to_string(),
+            stat: "damage".to_string(),
+            value: u64::MAX,
+            min: 1,
+            max: 100
+        }
+    );
+    let mut stats = default_stats();
+    stats.insert("crit".to_string(), 5);
+    let err = mint(&mut deps, stats).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnknownStat {
+            item_type: "weapon".to_string(),
+            stat: "crit".to_string()
+        }
+    );
+
+    // One bad entry fails the whole batch (and reverts the good one on chain)
+    let request = |stats: BTreeMap<String, u64>| MintRequest {
+        to: user_a.to_string(),
+        item_type: "weapon".to_string(),
+        rarity: "common".to_string(),
+        level: 1,
+        stats,
+        origin: "crafted".to_string(),
+        token_uri: None,
+        max_durability: None,
+        soulbound: false,
+        charges: None,
+        metadata_hash: None,
+    };
+    let mut bad = default_stats();
+    bad.insert("damage".to_string(), 0);
+    let err = execute_batch_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        vec![request(default_stats()), request(bad)],
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::StatValueOutOfRange { .. }));
+
+    // Upgrades are checked on the resulting stats, and unknown stats stay out
+    let mut changes = BTreeMap::new();
+    changes.insert("damage".to_string(), 58i64);
+    execute_upgrade_item(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        2,
+        changes.clone(),
+    )
+    .unwrap();
+    changes.insert("damage".to_string(), 1i64);
+    let err = execute_upgrade_item(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        3,
+        changes,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::StatValueOutOfRange { value: 101, .. }
+    ));
+    let mut changes = BTreeMap::new();
+    changes.insert("crit".to_string(), 1i64);
+    let err = execute_upgrade_item(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id,
+        3,
+        changes,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::UnknownStat { .. }));
+
+    // Other types are not checked, and removing the schema lifts the check
+    let mut stats = default_stats();
+    stats.insert("damage".to_string(), u64::MAX);
+    execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        user_a.to_string(),
+        "armor".to_string(),
+        "rare".to_string(),
+        1,
+        stats.clone(),
+        "dropped".to_string(),
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .unwrap();
+    execute_remove_stat_schema(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "weapon".to_string(),
+    )
+    .unwrap();
+    mint(&mut deps, stats).unwrap();
+}
+
+#[test]
+fn test_sequential_token_ids() {
+    let mut deps = setup_contract();
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+
+    let info = message_info(&minter, &[]);
+    for i in 1..=5u64 {
         let res = execute_mint(
             deps.as_mut(),
             mock_env(),
@@ -687,8 +1551,1680 @@ fn test_sequential_token_ids() {
             BTreeMap::new(),
             "dropped".to_string(),
             None,
+            None,
+            false,
+            None,
+            None,
         )
         .unwrap();
-        assert_eq!(res.attributes[1].value, i.to_string());
+        assert_eq!(res.events[0].attributes[0].value, i.to_string());
     }
 }
+
+#[test]
+fn test_burn_does_not_reuse_token_ids() {
+    let mut deps = setup_contract();
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+    mint_item(&mut deps, &user_a, "weapon", "common", 1);
+    mint_item(&mut deps, &user_a, "weapon", "common", 1);
+
+    execute_burn(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        "1".to_string(),
+    )
+    .unwrap();
+    let token_id = mint_item(&mut deps, &user_a, "armor", "common", 1);
+    assert_eq!(token_id, "3");
+
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), mock_env(), "2".to_string()).unwrap()).unwrap();
+    assert_eq!(nft.metadata.item_type, "weapon");
+    let count: NumTokensResponse = from_json(query_num_tokens(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(count.count, 2);
+}
+
+// ─── Marketplace ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_list_and_buy_pays_royalty() {
+    let mut deps = setup_contract();
+    let seller = addr(&deps, "user_a");
+    let buyer = addr(&deps, "user_b");
+    let royalty = addr(&deps, "royalty");
+    let token_id = mint_item(&mut deps, &seller, "weapon", "rare", 5);
+    let price = cosmwasm_std::coin(1_000_000, "ushido");
+
+    let err = execute_list_for_sale(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&buyer, &[]),
+        token_id.clone(),
+        price.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "token owner".to_string()
+        }
+    );
+    execute_list_for_sale(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&seller, &[]),
+        token_id.clone(),
+        price.clone(),
+    )
+    .unwrap();
+    let listings: ListingsResponse =
+        from_json(query_listings_by_seller(deps.as_ref(), seller.to_string(), None, None).unwrap())
+            .unwrap();
+    assert_eq!(listings.listings.len(), 1);
+
+    let err = execute_buy(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&buyer, &[cosmwasm_std::coin(999_999, "ushido")]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::IncorrectPayment {
+            expected: price.to_string()
+        }
+    );
+
+    let res = execute_buy(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&buyer, std::slice::from_ref(&price)),
+        token_id.clone(),
+    )
+    .unwrap();
+    // 5% royalty, rest to the seller
+    assert_eq!(
+        res.messages
+            .iter()
+            .map(|m| m.msg.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            cosmwasm_std::BankMsg::Send {
+                to_address: royalty.to_string(),
+                amount: vec![cosmwasm_std::coin(50_000, "ushido")],
+            }
+            .into(),
+            cosmwasm_std::BankMsg::Send {
+                to_address: seller.to_string(),
+                amount: vec![cosmwasm_std::coin(950_000, "ushido")],
+            }
+            .into(),
+        ]
+    );
+
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), mock_env(), token_id.clone()).unwrap()).unwrap();
+    assert_eq!(nft.owner, buyer.to_string());
+    let listings: ListingsResponse =
+        from_json(query_listings(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert!(listings.listings.is_empty());
+    let err = execute_buy(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&buyer, &[price]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotListed { token_id });
+}
+
+#[test]
+fn test_transfer_clears_listing() {
+    let mut deps = setup_contract();
+    let seller = addr(&deps, "user_a");
+    let other = addr(&deps, "user_b");
+    let token_id = mint_item(&mut deps, &seller, "weapon", "rare", 5);
+
+    execute_list_for_sale(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&seller, &[]),
+        token_id.clone(),
+        cosmwasm_std::coin(100, "ushido"),
+    )
+    .unwrap();
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&seller, &[]),
+        other.to_string(),
+        token_id.clone(),
+    )
+    .unwrap();
+
+    let listings: ListingsResponse =
+        from_json(query_listings(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert!(listings.listings.is_empty());
+    let listings: ListingsResponse =
+        from_json(query_listings_by_seller(deps.as_ref(), seller.to_string(), None, None).unwrap())
+            .unwrap();
+    assert!(listings.listings.is_empty());
+}
+
+#[test]
+fn test_token_history_records_transfers_and_sales() {
+    let mut deps = setup_contract();
+    let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    let user_c = addr(&deps, "user_c");
+    let token_id = mint_item(&mut deps, &user_a, "weapon", "legendary", 9);
+    let price = cosmwasm_std::coin(5_000, "ushido");
+
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        token_id.clone(),
+    )
+    .unwrap();
+    execute_list_for_sale(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_b, &[]),
+        token_id.clone(),
+        price.clone(),
+    )
+    .unwrap();
+    execute_buy(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_c, std::slice::from_ref(&price)),
+        token_id.clone(),
+    )
+    .unwrap();
+
+    let res: TokenHistoryResponse =
+        from_json(query_token_history(deps.as_ref(), token_id.clone()).unwrap()).unwrap();
+    assert_eq!(res.history.len(), 2);
+    assert_eq!(res.history[0].from, user_a);
+    assert_eq!(res.history[0].to, user_b);
+    assert_eq!(res.history[0].price, None);
+    assert_eq!(res.history[1].from, user_b);
+    assert_eq!(res.history[1].to, user_c);
+    assert_eq!(res.history[1].price, Some(price));
+    assert_eq!(res.history[1].timestamp, mock_env().block.time);
+
+    // Only the most recent transfers are kept
+    for i in 0..25 {
+        let (from, to) = if i % 2 == 0 {
+            (&user_c, &user_a)
+        } else {
+            (&user_a, &user_c)
+        };
+        execute_transfer_nft(
+            deps.as_mut(),
+            mock_env(),
+            message_info(from, &[]),
+            to.to_string(),
+            token_id.clone(),
+        )
+        .unwrap();
+    }
+    let res: TokenHistoryResponse =
+        from_json(query_token_history(deps.as_ref(), token_id).unwrap()).unwrap();
+    assert_eq!(res.history.len(), 20);
+    assert!(res.history.iter().all(|r| r.price.is_none()));
+}
+
+#[test]
+fn test_batch_transfer_is_atomic() {
+    let mut deps = setup_contract();
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    let ids: Vec<String> = (0..3)
+        .map(|_| mint_item(&mut deps, &user_a, "weapon", "common", 1))
+        .collect();
+    let foreign = mint_item(&mut deps, &user_b, "weapon", "common", 1);
+    let batch = |ids: &[String]| -> Vec<TransferRequest> {
+        ids.iter()
+            .map(|id| TransferRequest {
+                recipient: user_b.to_string(),
+                token_id: id.clone(),
+            })
+            .collect()
+    };
+
+    // A token the sender does not own fails the batch and nothing moves
+    let err = execute_batch_transfer(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        batch(&[ids[0].clone(), foreign]),
+    );
+    assert_eq!(
+        err.unwrap_err(),
+        ContractError::Unauthorized {
+            role: "owner or approved".to_string()
+        }
+    );
+    execute_lock(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        ids[2].clone(),
+    )
+    .unwrap();
+    let err = execute_batch_transfer(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        batch(&ids),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TokenLocked {
+            token_id: ids[2].clone()
+        }
+    );
+    let err = execute_batch_transfer(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        batch(&[ids[0].clone(), ids[0].clone()]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DuplicateToken {
+            token_id: ids[0].clone()
+        }
+    );
+
+    let res = execute_batch_transfer(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        batch(&ids[..2]),
+    )
+    .unwrap();
+    assert_eq!(res.attributes[1].value, "2");
+    let tokens: TokensResponse =
+        from_json(query_tokens(deps.as_ref(), user_a.to_string(), None, None).unwrap()).unwrap();
+    assert_eq!(tokens.tokens, vec![ids[2].clone()]);
+}
+
+// ─── Trading ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_trade_swaps_items_atomically() {
+    let mut deps = setup_contract();
+    let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    let user_c = addr(&deps, "user_c");
+    let sword = mint_item(&mut deps, &user_a, "weapon", "rare", 5);
+    let shield = mint_item(&mut deps, &user_b, "armor", "rare", 5);
+    let expiry = mock_env().block.time.plus_seconds(3_600);
+
+    let err = execute_propose_trade(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        vec![shield.clone()],
+        vec![],
+        expiry,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "token owner".to_string()
+        }
+    );
+    let res = execute_propose_trade(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        vec![sword.clone()],
+        vec![shield.clone()],
+        expiry,
+    )
+    .unwrap();
+    assert_eq!(res.attributes[1].value, "1");
+
+    // The offered item is held by the contract while the trade is open
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), mock_env(), sword.clone()).unwrap()).unwrap();
+    assert_eq!(nft.owner, mock_env().contract.address.to_string());
+
+    let err =
+        execute_accept_trade(deps.as_mut(), mock_env(), message_info(&user_c, &[]), 1).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "counterparty".to_string()
+        }
+    );
+    execute_accept_trade(deps.as_mut(), mock_env(), message_info(&user_b, &[]), 1).unwrap();
+
+    let nft: NftInfoResponse = from_json(query_nft_info(deps.as_ref(), mock_env(), sword).unwrap()).unwrap();
+    assert_eq!(nft.owner, user_b.to_string());
+    let nft: NftInfoResponse = from_json(query_nft_info(deps.as_ref(), mock_env(), shield).unwrap()).unwrap();
+    assert_eq!(nft.owner, user_a.to_string());
+    let trades: TradesResponse =
+        from_json(query_trades(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert!(trades.trades.is_empty());
+}
+
+#[test]
+fn test_trade_expiry_and_cancel() {
+    let mut deps = setup_contract();
+    let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    let sword = mint_item(&mut deps, &user_a, "weapon", "rare", 5);
+    let shield = mint_item(&mut deps, &user_b, "armor", "rare", 5);
+
+    execute_propose_trade(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        vec![sword.clone()],
+        vec![shield],
+        mock_env().block.time.plus_seconds(60),
+    )
+    .unwrap();
+
+    // The offered sword cannot be burned out of escrow
+    let minter = addr(&deps, "minter");
+    let err = execute_burn(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        sword.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TokenInEscrow {
+            token_id: sword.clone()
+        }
+    );
+
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(60);
+    let err = execute_accept_trade(deps.as_mut(), later.clone(), message_info(&user_b, &[]), 1)
+        .unwrap_err();
+    assert_eq!(err, ContractError::TradeExpired { trade_id: 1 });
+
+    // Counterparty declines; the escrowed item returns to the proposer
+    let res = execute_cancel_trade(deps.as_mut(), later, message_info(&user_b, &[]), 1).unwrap();
+    assert_eq!(res.attributes[3].value, sword);
+    let nft: NftInfoResponse = from_json(query_nft_info(deps.as_ref(), mock_env(), sword).unwrap()).unwrap();
+    assert_eq!(nft.owner, user_a.to_string());
+    let err =
+        execute_cancel_trade(deps.as_mut(), mock_env(), message_info(&user_a, &[]), 1).unwrap_err();
+    assert_eq!(err, ContractError::TradeNotFound { trade_id: 1 });
+}
+
+// ─── Equip Lock ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_locked_item_cannot_move() {
+    let mut deps = setup_contract();
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    let token_id = mint_item(&mut deps, &user_a, "weapon", "rare", 5);
+
+    let err = execute_lock(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "minter".to_string()
+        }
+    );
+    execute_lock(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+    )
+    .unwrap();
+
+    let locked = ContractError::TokenLocked {
+        token_id: token_id.clone(),
+    };
+    let err = execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, locked);
+    let err = execute_send_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        token_id.clone(),
+        cosmwasm_std::Binary::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err, locked);
+    let err = execute_approve(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        token_id.clone(),
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, locked);
+
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), mock_env(), token_id.clone()).unwrap()).unwrap();
+    assert!(nft.locked);
+
+    execute_unlock(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+    )
+    .unwrap();
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        token_id.clone(),
+    )
+    .unwrap();
+    let err = execute_unlock(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::TokenNotLocked { token_id });
+}
+
+// ─── Durability ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_broken_item_frozen_until_repaired() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let minter = addr(&deps, "minter");
+    let royalty = addr(&deps, "royalty");
+    let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        user_a.to_string(),
+        "armor".to_string(),
+        "rare".to_string(),
+        1,
+        default_stats(),
+        "dropped".to_string(),
+        None,
+        Some(100),
+        false,
+        None,
+        None,
+    )
+    .unwrap();
+    let token_id = "1".to_string();
+    let fee = cosmwasm_std::coin(500, "ushido");
+    execute_set_repair_fee(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "rare".to_string(),
+        Some(fee.clone()),
+    )
+    .unwrap();
+    execute_list_for_sale(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        token_id.clone(),
+        cosmwasm_std::coin(100, "ushido"),
+    )
+    .unwrap();
+
+    // A buyer gets the item as listed, so wear waits for the listing to go
+    let err = execute_apply_wear(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        250,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TokenListed {
+            token_id: token_id.clone()
+        }
+    );
+    execute_cancel_listing(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        token_id.clone(),
+    )
+    .unwrap();
+
+    let res = execute_apply_wear(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        250,
+    )
+    .unwrap();
+    assert_eq!(res.attributes[3].value, "0");
+
+    let broken = ContractError::ItemBroken {
+        token_id: token_id.clone(),
+    };
+    let err = execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, broken);
+    let err = execute_list_for_sale(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        token_id.clone(),
+        cosmwasm_std::coin(100, "ushido"),
+    )
+    .unwrap_err();
+    assert_eq!(err, broken);
+
+    let err = execute_repair(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::IncorrectPayment {
+            expected: fee.to_string()
+        }
+    );
+    let res = execute_repair(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, std::slice::from_ref(&fee)),
+        token_id.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        cosmwasm_std::BankMsg::Send {
+            to_address: royalty.to_string(),
+            amount: vec![fee.clone()],
+        }
+        .into()
+    );
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), mock_env(), token_id.clone()).unwrap()).unwrap();
+    assert_eq!(nft.metadata.durability, Some(100));
+
+    let err = execute_repair(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[fee]),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ItemNotDamaged {
+            token_id: token_id.clone()
+        }
+    );
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        token_id,
+    )
+    .unwrap();
+
+    // Items minted without durability never wear
+    let plain = mint_item(&mut deps, &user_a, "weapon", "rare", 1);
+    let err = execute_apply_wear(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        plain.clone(),
+        1,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoDurability { token_id: plain });
+}
+
+// ─── Consumables ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_consume_charges_and_burn() {
+    let mut deps = setup_contract();
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    let err = execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        user_a.to_string(),
+        "potion".to_string(),
+        "common".to_string(),
+        1,
+        BTreeMap::new(),
+        "shop".to_string(),
+        None,
+        None,
+        false,
+        Some(0),
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidCharges);
+    execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        user_a.to_string(),
+        "potion".to_string(),
+        "common".to_string(),
+        1,
+        BTreeMap::new(),
+        "shop".to_string(),
+        None,
+        None,
+        false,
+        Some(3),
+        None,
+    )
+    .unwrap();
+    let potion = "1".to_string();
+    let sword = mint_item(&mut deps, &user_a, "weapon", "rare", 1);
+
+    let err = execute_consume(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        sword.clone(),
+        1,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotConsumable { token_id: sword });
+    let err = execute_consume(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_b, &[]),
+        potion.clone(),
+        1,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "minter or token owner".to_string()
+        }
+    );
+
+    // Owner drinks one, the backend consumes another
+    let res = execute_consume(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        potion.clone(),
+        1,
+    )
+    .unwrap();
+    assert_eq!(res.attributes[5].key, "charges_left");
+    assert_eq!(res.attributes[5].value, "2");
+    execute_consume(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        potion.clone(),
+        1,
+    )
+    .unwrap();
+    let info: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), mock_env(), potion.clone()).unwrap()).unwrap();
+    assert_eq!(info.metadata.charges, Some(1));
+
+    let err = execute_consume(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        potion.clone(),
+        2,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientCharges {
+            token_id: potion.clone(),
+            remaining: 1
+        }
+    );
+
+    // The last charge burns the item
+    let res = execute_consume(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        potion.clone(),
+        1,
+    )
+    .unwrap();
+    assert_eq!(res.attributes[5].value, "0");
+    assert_eq!(res.events[0].ty, BurnEvent::TYPE);
+    assert!(query_nft_info(deps.as_ref(), mock_env(), potion).is_err());
+    let count: NumTokensResponse = from_json(query_num_tokens(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(count.count, 1);
+}
+
+#[test]
+fn test_escrowed_item_cannot_be_consumed_worn_or_locked() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+    let marketplace = addr(&deps, "marketplace");
+    execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        user_a.to_string(),
+        "potion".to_string(),
+        "common".to_string(),
+        1,
+        BTreeMap::new(),
+        "shop".to_string(),
+        None,
+        Some(100),
+        false,
+        Some(3),
+        None,
+    )
+    .unwrap();
+    let potion = "1".to_string();
+
+    let info = message_info(&owner, &[]);
+    execute_add_escrow_contract(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        marketplace.to_string(),
+    )
+    .unwrap();
+    let err = execute_add_escrow_contract(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        marketplace.to_string(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::AlreadyEscrowContract { .. }));
+    let escrows: EscrowContractsResponse =
+        from_json(query_escrow_contracts(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert_eq!(escrows.contracts, vec![marketplace.to_string()]);
+
+    // Deposited with the marketplace, the item has to come back as it went in
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        marketplace.to_string(),
+        potion.clone(),
+    )
+    .unwrap();
+    let in_escrow = ContractError::TokenInEscrow {
+        token_id: potion.clone(),
+    };
+    let minter_info = message_info(&minter, &[]);
+    let err = execute_consume(
+        deps.as_mut(),
+        mock_env(),
+        minter_info.clone(),
+        potion.clone(),
+        1,
+    )
+    .unwrap_err();
+    assert_eq!(err, in_escrow);
+    let err = execute_apply_wear(
+        deps.as_mut(),
+        mock_env(),
+        minter_info.clone(),
+        potion.clone(),
+        10,
+    )
+    .unwrap_err();
+    assert_eq!(err, in_escrow);
+    let err = execute_lock(
+        deps.as_mut(),
+        mock_env(),
+        minter_info.clone(),
+        potion.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, in_escrow);
+
+    execute_remove_escrow_contract(deps.as_mut(), mock_env(), info, marketplace.to_string())
+        .unwrap();
+    execute_consume(deps.as_mut(), mock_env(), minter_info, potion, 1).unwrap();
+}
+
+#[test]
+fn test_redeem_and_ack() {
+    let mut deps = setup_contract();
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    let voucher = mint_item(&mut deps, &user_a, "voucher", "rare", 1);
+    let other = mint_item(&mut deps, &user_a, "voucher", "common", 1);
+
+    let err = execute_redeem(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_b, &[]),
+        voucher.clone(),
+        "SKIN-001".to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "token owner".to_string()
+        }
+    );
+    let err = execute_redeem(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        voucher.clone(),
+        "bad code".to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidRedemptionCode { max: 64 });
+
+    let res = execute_redeem(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        voucher.clone(),
+        "SKIN-001".to_string(),
+    )
+    .unwrap();
+    assert_eq!(res.attributes[1].value, "1");
+    assert_eq!(res.events[0].ty, BurnEvent::TYPE);
+    assert!(query_nft_info(deps.as_ref(), mock_env(), voucher).is_err());
+
+    // A code only pays out once
+    let err = execute_redeem(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        other,
+        "SKIN-001".to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RedemptionCodeUsed {
+            code: "SKIN-001".to_string()
+        }
+    );
+
+    let receipt: Option<Redemption> =
+        from_json(query_redemption(deps.as_ref(), 1).unwrap()).unwrap();
+    let receipt = receipt.unwrap();
+    assert_eq!(receipt.owner, user_a);
+    assert_eq!(receipt.item_type, "voucher");
+    assert_eq!(receipt.redeemed_at, mock_env().block.time);
+    assert_eq!(receipt.acknowledged_at, None);
+    let pending: RedemptionsResponse =
+        from_json(query_pending_redemptions(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert_eq!(pending.redemptions.len(), 1);
+
+    let err = execute_ack_redemption(deps.as_mut(), mock_env(), message_info(&user_a, &[]), 1)
+        .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized { .. }));
+    execute_ack_redemption(deps.as_mut(), mock_env(), message_info(&minter, &[]), 1).unwrap();
+    let err = execute_ack_redemption(deps.as_mut(), mock_env(), message_info(&minter, &[]), 1)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RedemptionAcknowledged { redemption_id: 1 }
+    );
+    let err = execute_ack_redemption(deps.as_mut(), mock_env(), message_info(&minter, &[]), 9)
+        .unwrap_err();
+    assert_eq!(err, ContractError::RedemptionNotFound { redemption_id: 9 });
+
+    let receipt: Option<Redemption> =
+        from_json(query_redemption(deps.as_ref(), 1).unwrap()).unwrap();
+    assert_eq!(receipt.unwrap().acknowledged_by, Some(minter));
+    let pending: RedemptionsResponse =
+        from_json(query_pending_redemptions(deps.as_ref(), None, None).unwrap()).unwrap();
+    assert!(pending.redemptions.is_empty());
+}
+
+// ─── Soulbound ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_soulbound_item_cannot_move() {
+    let mut deps = setup_contract();
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        user_a.to_string(),
+        "quest_key".to_string(),
+        "rare".to_string(),
+        1,
+        BTreeMap::new(),
+        "quest:gatekeeper".to_string(),
+        None,
+        None,
+        true,
+        None,
+        None,
+    )
+    .unwrap();
+    let token_id = "1".to_string();
+
+    let err = execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Soulbound);
+    let err = execute_send_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        token_id.clone(),
+        cosmwasm_std::Binary::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Soulbound);
+    let err = execute_approve(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        token_id.clone(),
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Soulbound);
+    let err = execute_list_for_sale(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        token_id.clone(),
+        cosmwasm_std::coin(100, "ushido"),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Soulbound);
+
+    // An operator cannot move it either
+    execute_approve_all(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+    )
+    .unwrap();
+    let err = execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_b, &[]),
+        user_b.to_string(),
+        token_id.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Soulbound);
+
+    let nft: NftInfoResponse = from_json(query_nft_info(deps.as_ref(), mock_env(), token_id).unwrap()).unwrap();
+    assert!(nft.soulbound);
+    assert_eq!(nft.owner, user_a.to_string());
+}
+
+// ─── Upgrades ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_upgrade_item_in_place() {
+    let mut deps = setup_contract();
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+    let token_id = mint_item(&mut deps, &user_a, "weapon", "rare", 5);
+
+    let mut changes = BTreeMap::new();
+    changes.insert("damage".to_string(), 8i64);
+    changes.insert("speed".to_string(), -4i64);
+    changes.insert("crit".to_string(), 3i64);
+    let err = execute_upgrade_item(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        token_id.clone(),
+        6,
+        changes.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "minter".to_string()
+        }
+    );
+
+    let res = execute_upgrade_item(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        6,
+        changes,
+    )
+    .unwrap();
+    let attr = |key: &str| {
+        res.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .map(|a| a.value.clone())
+    };
+    assert_eq!(attr("old_stat_damage").as_deref(), Some("42"));
+    assert_eq!(attr("new_stat_damage").as_deref(), Some("50"));
+
+    let nft: NftInfoResponse =
+        from_json(query_nft_info(deps.as_ref(), mock_env(), token_id.clone()).unwrap()).unwrap();
+    assert_eq!(nft.owner, user_a.to_string());
+    assert_eq!(nft.metadata.level, 6);
+    assert_eq!(nft.metadata.stats["damage"], 50);
+    assert_eq!(nft.metadata.stats["speed"], 6);
+    assert_eq!(nft.metadata.stats["crit"], 3);
+
+    // Underflow and level downgrades are rejected without partial writes
+    let mut changes = BTreeMap::new();
+    changes.insert("damage".to_string(), 1i64);
+    changes.insert("speed".to_string(), -7i64);
+    let err = execute_upgrade_item(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        7,
+        changes,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::StatOutOfRange {
+            stat: "speed".to_string(),
+            delta: -7
+        }
+    );
+    let err = execute_upgrade_item(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        token_id.clone(),
+        5,
+        BTreeMap::new(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::LevelDowngrade {
+            current: 6,
+            requested: 5
+        }
+    );
+    let nft: NftInfoResponse = from_json(query_nft_info(deps.as_ref(), mock_env(), token_id).unwrap()).unwrap();
+    assert_eq!(nft.metadata.stats["damage"], 50);
+    assert_eq!(nft.metadata.level, 6);
+}
+
+#[test]
+fn test_frozen_metadata_blocks_upgrades() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+    let sold = mint_item(&mut deps, &user_a, "weapon", "legendary", 10);
+    let other = mint_item(&mut deps, &user_a, "weapon", "common", 1);
+    let upgrade = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, token_id: &str| {
+        execute_upgrade_item(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&minter, &[]),
+            token_id.to_string(),
+            20,
+            BTreeMap::new(),
+        )
+    };
+
+    let err = execute_freeze_token_metadata(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        sold.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+    execute_freeze_token_metadata(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        sold.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        upgrade(&mut deps, &sold).unwrap_err(),
+        ContractError::MetadataFrozen {
+            token_id: sold.clone()
+        }
+    );
+    upgrade(&mut deps, &other).unwrap();
+
+    execute_freeze_all_metadata(deps.as_mut(), mock_env(), message_info(&owner, &[])).unwrap();
+    assert_eq!(
+        upgrade(&mut deps, &other).unwrap_err(),
+        ContractError::MetadataFrozen {
+            token_id: other.clone()
+        }
+    );
+    let res: MetadataFrozenResponse =
+        from_json(query_metadata_frozen(deps.as_ref(), other).unwrap()).unwrap();
+    assert!(res.frozen);
+    assert!(res.all_frozen);
+}
+
+#[test]
+fn test_metadata_hash_commitment() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let minter = addr(&deps, "minter");
+    let user_a = addr(&deps, "user_a");
+    let first = HexBinary::from([1u8; 32]);
+    let second = HexBinary::from([2u8; 32]);
+    let short = HexBinary::from([1u8; 20]);
+    let mint = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>,
+                token_uri: Option<String>,
+                metadata_hash: HexBinary| {
+        execute_mint(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&minter, &[]),
+            user_a.to_string(),
+            "weapon".to_string(),
+            "rare".to_string(),
+            1,
+            default_stats(),
+            "dropped".to_string(),
+            token_uri,
+            None,
+            false,
+            None,
+            Some(metadata_hash),
+        )
+    };
+
+    // A hash needs a URI to describe, and must be a SHA-256 digest
+    assert!(matches!(
+        mint(&mut deps, None, first.clone()).unwrap_err(),
+        ContractError::InvalidMetadataHash { .. }
+    ));
+    assert!(matches!(
+        mint(&mut deps, Some("ipfs://a".to_string()), short).unwrap_err(),
+        ContractError::InvalidMetadataHash { .. }
+    ));
+    let res = mint(&mut deps, Some("ipfs://a".to_string()), first.clone()).unwrap();
+    let token_id = res.events[0].attributes[0].value.clone();
+    let res: MetadataHashResponse =
+        from_json(query_metadata_hash(deps.as_ref(), token_id.clone()).unwrap()).unwrap();
+    assert_eq!(res.token_uri, Some("ipfs://a".to_string()));
+    assert_eq!(res.metadata_hash, Some(first.clone()));
+
+    let update = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, sender: &Addr| {
+        execute_update_metadata(
+            deps.as_mut(),
+            mock_env(),
+            message_info(sender, &[]),
+            token_id.clone(),
+            "ipfs://b".to_string(),
+            second.clone(),
+        )
+    };
+    assert!(update(&mut deps, &user_a).is_err());
+    let res = update(&mut deps, &minter).unwrap();
+    let attr = |key: &str| {
+        res.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .unwrap()
+            .value
+            .clone()
+    };
+    assert_eq!(attr("old_token_uri"), "ipfs://a");
+    assert_eq!(attr("old_metadata_hash"), first.to_hex());
+    assert_eq!(attr("new_metadata_hash"), second.to_hex());
+    let res: MetadataHashResponse =
+        from_json(query_metadata_hash(deps.as_ref(), token_id.clone()).unwrap()).unwrap();
+    assert_eq!(res.token_uri, Some("ipfs://b".to_string()));
+    assert_eq!(res.metadata_hash, Some(second.clone()));
+
+    execute_freeze_token_metadata(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        token_id.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        update(&mut deps, &minter).unwrap_err(),
+        ContractError::MetadataFrozen { token_id }
+    );
+}
+
+// ─── Crafting ───────────────────────────────────────────────────────────────
+
+fn register_blade_recipe(
+    deps: &mut cosmwasm_std::OwnedDeps<
+        cosmwasm_std::MemoryStorage,
+        cosmwasm_std::testing::MockApi,
+        cosmwasm_std::testing::MockQuerier,
+    >,
+) {
+    let owner = addr(deps, "owner");
+    let shard = RecipeInput {
+        item_type: "shard".to_string(),
+        rarity: Some("rare".to_string()),
+        min_level: 3,
+    };
+    let recipe = Recipe {
+        inputs: vec![
+            RecipeInput {
+                item_type: "weapon".to_string(),
+                rarity: None,
+                min_level: 1,
+            },
+            shard,
+        ],
+        output: RecipeOutput {
+            item_type: "weapon".to_string(),
+            rarity: "epic".to_string(),
+            level: 10,
+            stats: default_stats(),
+            token_uri: None,
+            max_durability: None,
+            soulbound: false,
+            charges: None,
+            metadata_hash: None,
+        },
+    };
+    execute_register_recipe(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "plasma_blade".to_string(),
+        recipe,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_combine_burns_inputs_and_mints_output() {
+    let mut deps = setup_contract();
+    register_blade_recipe(&mut deps);
+    let user_a = addr(&deps, "user_a");
+    let weapon = mint_item(&mut deps, &user_a, "weapon", "common", 1);
+    let shard = mint_item(&mut deps, &user_a, "shard", "rare", 4);
+
+    let res = execute_combine(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        vec![weapon.clone(), shard.clone()],
+        "plasma_blade".to_string(),
+    )
+    .unwrap();
+    let crafted = res
+        .events
+        .iter()
+        .find(|e| e.ty == MintEvent::TYPE)
+        .unwrap()
+        .attributes[0]
+        .value
+        .clone();
+    assert_eq!(crafted, "3");
+
+    assert!(query_nft_info(deps.as_ref(), mock_env(), weapon).is_err());
+    assert!(query_nft_info(deps.as_ref(), mock_env(), shard).is_err());
+    let nft: NftInfoResponse = from_json(query_nft_info(deps.as_ref(), mock_env(), crafted).unwrap()).unwrap();
+    assert_eq!(nft.owner, user_a.to_string());
+    assert_eq!(nft.metadata.rarity, "epic");
+    assert_eq!(nft.metadata.origin, "crafted:plasma_blade");
+}
+
+#[test]
+fn test_combine_rejects_invalid_inputs() {
+    let mut deps = setup_contract();
+    register_blade_recipe(&mut deps);
+    let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    let weapon = mint_item(&mut deps, &user_a, "weapon", "common", 1);
+    let low_shard = mint_item(&mut deps, &user_a, "shard", "rare", 2);
+    let other_shard = mint_item(&mut deps, &user_b, "shard", "rare", 5);
+
+    let combine = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, ids: Vec<&String>| {
+        execute_combine(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user_a, &[]),
+            ids.into_iter().cloned().collect(),
+            "plasma_blade".to_string(),
+        )
+        .unwrap_err()
+    };
+
+    assert_eq!(
+        combine(&mut deps, vec![&weapon]),
+        ContractError::WrongInputCount {
+            expected: 2,
+            got: 1
+        }
+    );
+    assert_eq!(
+        combine(&mut deps, vec![&weapon, &low_shard]),
+        ContractError::RecipeInputMismatch {
+            token_id: low_shard.clone(),
+            slot: 1
+        }
+    );
+    assert_eq!(
+        combine(&mut deps, vec![&weapon, &weapon]),
+        ContractError::DuplicateToken {
+            token_id: weapon.clone()
+        }
+    );
+    assert_eq!(
+        combine(&mut deps, vec![&weapon, &other_shard]),
+        ContractError::Unauthorized {
+            role: "token owner".to_string()
+        }
+    );
+
+    // Nothing was burned by the failed attempts
+    let tokens: TokensResponse =
+        from_json(query_tokens(deps.as_ref(), user_a.to_string(), None, None).unwrap()).unwrap();
+    assert_eq!(tokens.tokens.len(), 2);
+}
+
+// ─── Attachments ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_attached_items_follow_parent() {
+    let mut deps = setup_contract();
+    let user_a = addr(&deps, "user_a");
+    let user_b = addr(&deps, "user_b");
+    let sword = mint_item(&mut deps, &user_a, "weapon", "epic", 5);
+    let gem = mint_item(&mut deps, &user_a, "gem", "rare", 1);
+    let other_gem = mint_item(&mut deps, &user_b, "gem", "rare", 1);
+
+    let err = execute_attach(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        sword.clone(),
+        sword.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidAttachment { .. }));
+    let err = execute_attach(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        sword.clone(),
+        other_gem,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "token owner".to_string()
+        }
+    );
+
+    execute_attach(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        sword.clone(),
+        gem.clone(),
+    )
+    .unwrap();
+    let res: ChildrenResponse =
+        from_json(query_children(deps.as_ref(), sword.clone()).unwrap()).unwrap();
+    assert_eq!(res.children, vec![gem.clone()]);
+
+    // The gem is stuck in its socket, and the sword cannot be socketed elsewhere
+    let err = execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        gem.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TokenAttached {
+            token_id: gem.clone(),
+            parent: sword.clone()
+        }
+    );
+    let err = execute_attach(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        gem.clone(),
+        sword.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidAttachment { .. }));
+
+    // Transferring the sword carries the gem along
+    let res = execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        user_b.to_string(),
+        sword.clone(),
+    )
+    .unwrap();
+    assert_eq!(res.events.len(), 2);
+    assert_eq!(res.events[1].attributes[0].value, gem);
+    let owner: OwnerOfResponse =
+        from_json(query_owner_of(deps.as_ref(), mock_env(), gem.clone()).unwrap()).unwrap();
+    assert_eq!(owner.owner, user_b.to_string());
+    let tokens: TokensResponse =
+        from_json(query_tokens(deps.as_ref(), user_a.to_string(), None, None).unwrap()).unwrap();
+    assert!(tokens.tokens.is_empty());
+
+    // Only the new owner can take it out, after which it moves freely
+    let err = execute_detach(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_a, &[]),
+        gem.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "token owner".to_string()
+        }
+    );
+    execute_detach(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_b, &[]),
+        gem.clone(),
+    )
+    .unwrap();
+    let err = execute_detach(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_b, &[]),
+        gem.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotAttached {
+            token_id: gem.clone()
+        }
+    );
+    execute_transfer_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&user_b, &[]),
+        user_a.to_string(),
+        gem,
+    )
+    .unwrap();
+    let res: ChildrenResponse = from_json(query_children(deps.as_ref(), sword).unwrap()).unwrap();
+    assert!(res.children.is_empty());
+}
+
+// ─── Migration ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_chunked_backfill_migration() {
+    let mut deps = setup_contract();
+    let user_a = addr(&deps, "user_a");
+    for _ in 0..5 {
+        mint_item(&mut deps, &user_a, "weapon", "common", 1);
+    }
+    // Simulate a store written before the token indexes and LAST_TOKEN_ID existed
+    for i in 1..=5 {
+        let token_id = i.to_string();
+        OWNER_TOKENS.remove(deps.as_mut().storage, (&user_a, &token_id));
+        TYPE_TOKENS.remove(deps.as_mut().storage, ("weapon", &token_id));
+        RARITY_TOKENS.remove(deps.as_mut().storage, ("common", &token_id));
+    }
+    TYPE_MINTED.remove(deps.as_mut().storage, "weapon");
+    LAST_TOKEN_ID.remove(deps.as_mut().storage);
+
+    let backfill = MigrateMsg {
+        backfill: Some(BackfillMsg {
+            start_after: None,
+            limit: Some(2),
+        }),
+    };
+    let res = migrate(deps.as_mut(), mock_env(), backfill.clone()).unwrap();
+    assert_eq!(res.attributes[2].value, "2");
+    assert_eq!(res.attributes[3].value, "false");
+    let status: MigrationStatusResponse =
+        from_json(query_migration_status(deps.as_ref()).unwrap()).unwrap();
+    assert!(!status.done);
+    assert_eq!(status.processed, 2);
+    assert_eq!(status.total_tokens, 5);
+    assert_eq!(status.cursor, Some("2".to_string()));
+
+    // Ids and supply caps cannot be trusted until the backfill finishes
+    let minter = addr(&deps, "minter");
+    let err = execute_mint(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&minter, &[]),
+        user_a.to_string(),
+        "weapon".to_string(),
+        "common".to_string(),
+        1,
+        default_stats(),
+        "dropped".to_string(),
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::BackfillInProgress);
+
+    // Each chunk resumes where the last one stopped
+    migrate(deps.as_mut(), mock_env(), backfill.clone()).unwrap();
+    let res = migrate(deps.as_mut(), mock_env(), backfill).unwrap();
+    assert_eq!(res.attributes[3].value, "true");
+    let status: MigrationStatusResponse =
+        from_json(query_migration_status(deps.as_ref()).unwrap()).unwrap();
+    assert!(status.done);
+    assert_eq!(status.processed, 5);
+
+    let tokens: TokensResponse =
+        from_json(query_tokens(deps.as_ref(), user_a.to_string(), None, None).unwrap()).unwrap();
+    assert_eq!(tokens.tokens.len(), 5);
+    let supply: TypeSupplyResponse =
+        from_json(query_type_supply(deps.as_ref(), "weapon".to_string()).unwrap()).unwrap();
+    assert_eq!(supply.minted, 5);
+    assert_eq!(mint_item(&mut deps, &user_a, "weapon", "common", 1), "6");
+
+    // A full rerun from the plain `{}` payload re-indexes without recounting supply
+    let full: MigrateMsg = from_json(b"{}").unwrap();
+    migrate(deps.as_mut(), mock_env(), full).unwrap();
+    let supply: TypeSupplyResponse =
+        from_json(query_type_supply(deps.as_ref(), "weapon".to_string()).unwrap()).unwrap();
+    assert_eq!(supply.minted, 6);
+}
@@ -0,0 +1,606 @@
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order,
+    Reply, Response, StdResult, SubMsg, SubMsgResult, Uint128,
+};
+use cw2::set_contract_version;
+use cw721::receiver::Cw721ReceiveMsg;
+use sysbreak_common::reject_funds;
+use sysbreak_events::SaleEvent;
+
+use crate::error::ContractError;
+use crate::helpers::{
+    assert_not_paused, assert_owner, return_nft_msg, settle_sale_msgs, single_payment,
+    transfer_nft_msg,
+};
+use crate::msg::{
+    CollectionsResponse, InstantiateMsg, ListingInfo, ListingMsg, ListingsResponse, MigrateMsg,
+    NftInfoSoulbound, NftQueryMsg,
+};
+use crate::state::{
+    Bid, Config, Listing, ListingKind, PendingOwnerTransfer, COLLECTIONS, CONFIG, LAST_LISTING_ID,
+    LISTINGS, OWNER_TRANSFER, RETURN_REPLY_ID, SALE_REPLY_ID, UNRETURNED,
+};
+
+const CONTRACT_NAME: &str = "crates.io:sysbreak-marketplace";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Longest auction accepted: 30 days
+const MAX_AUCTION_DURATION: u64 = 30 * 24 * 60 * 60;
+const DEFAULT_QUERY_LIMIT: u32 = 30;
+const MAX_QUERY_LIMIT: u32 = 100;
+
+// ─── Instantiate ────────────────────────────────────────────────────────────
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let owner = deps.api.addr_validate(&msg.owner)?;
+    for collection in &msg.collections {
+        let collection = deps.api.addr_validate(collection)?;
+        COLLECTIONS.save(deps.storage, &collection, &true)?;
+    }
+    let config = Config {
+        owner,
+        denom: msg.denom,
+        paused: false,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("contract", CONTRACT_NAME)
+        .add_attribute("owner", config.owner.as_str())
+        .add_attribute("denom", &config.denom))
+}
+
+// ─── Listings ───────────────────────────────────────────────────────────────
+
+/// SendNft hook. `info.sender` is the NFT contract, which has already moved
+/// the token to this contract; `receive.sender` is the seller.
+pub fn execute_receive_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    receive: Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+
+    let collection = info.sender;
+    if !COLLECTIONS.has(deps.storage, &collection) {
+        return Err(ContractError::CollectionNotAllowed {
+            collection: collection.to_string(),
+        });
+    }
+    // The NFT contracts already refuse to send soulbound tokens; checked again
+    // here so a misbehaving collection cannot get one listed.
+    let nft_info: NftInfoSoulbound = deps.querier.query_wasm_smart(
+        &collection,
+        &NftQueryMsg::NftInfo {
+            token_id: receive.token_id.clone(),
+        },
+    )?;
+    if nft_info.soulbound {
+        return Err(ContractError::Soulbound);
+    }
+
+    let seller = deps.api.addr_validate(&receive.sender)?;
+    let kind = match from_json::<ListingMsg>(&receive.msg)? {
+        ListingMsg::FixedPrice { price } => {
+            if price.is_zero() {
+                return Err(ContractError::InvalidPrice);
+            }
+            ListingKind::FixedPrice { price }
+        }
+        ListingMsg::Auction { min_bid, duration } => {
+            if min_bid.is_zero() {
+                return Err(ContractError::InvalidPrice);
+            }
+            if duration == 0 || duration > MAX_AUCTION_DURATION {
+                return Err(ContractError::InvalidDuration {
+                    max: MAX_AUCTION_DURATION,
+                });
+            }
+            ListingKind::Auction {
+                min_bid,
+                end_time: env.block.time.plus_seconds(duration),
+                highest_bid: None,
+            }
+        }
+    };
+
+    let listing_id = LAST_LISTING_ID.may_load(deps.storage)?.unwrap_or_default() + 1;
+    LAST_LISTING_ID.save(deps.storage, &listing_id)?;
+    LISTINGS.save(
+        deps.storage,
+        listing_id,
+        &Listing {
+            seller: seller.clone(),
+            collection: collection.clone(),
+            token_id: receive.token_id.clone(),
+            kind,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "list")
+        .add_attribute("listing_id", listing_id.to_string())
+        .add_attribute("collection", collection.as_str())
+        .add_attribute("token_id", &receive.token_id)
+        .add_attribute("seller", seller.as_str()))
+}
+
+/// Buy a fixed-price listing. The listing is removed before the token and
+/// payouts are dispatched. If the collection refuses the transfer the whole
+/// purchase reverts, so the buyer is never charged for a token they did not get.
+pub fn execute_buy(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    listing_id: u64,
+) -> Result<Response, ContractError> {
+    assert_not_paused(deps.as_ref())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let listing = load_listing(deps.as_ref(), listing_id)?;
+    let price = match listing.kind {
+        ListingKind::FixedPrice { price } => price,
+        ListingKind::Auction { .. } => return Err(ContractError::NotFixedPrice { listing_id }),
+    };
+    if info.sender == listing.seller {
+        return Err(ContractError::SelfPurchase);
+    }
+    if info.funds.len() != 1 || info.funds[0] != Coin::new(price, &config.denom) {
+        return Err(ContractError::IncorrectPayment {
+            expected: Coin::new(price, &config.denom).to_string(),
+        });
+    }
+
+    LISTINGS.remove(deps.storage, listing_id);
+    let (payouts, royalty) = settle_sale_msgs(
+        deps.as_ref(),
+        &config.denom,
+        &listing.collection,
+        &listing.token_id,
+        &listing.seller,
+        price,
+    );
+
+    Ok(Response::new()
+        .add_message(transfer_nft_msg(
+            &listing.collection,
+            &info.sender,
+            &listing.token_id,
+        )?)
+        .add_messages(payouts)
+        .add_attribute("action", "buy")
+        .add_attribute("royalty", royalty.to_string())
+        .add_event(SaleEvent {
+            listing_id: listing_id.to_string(),
+            collection: listing.collection.to_string(),
+            token_id: listing.token_id,
+            seller: listing.seller.to_string(),
+            buyer: info.sender.to_string(),
+            price: price.to_string(),
+            denom: config.denom,
+        }))
+}
+
+/// Bid on an auction. A bid must meet the minimum and beat the current
+/// highest bid; the outbid bidder is refunded in the same message.
+pub fn execute_place_bid(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    listing_id: u64,
+) -> Result<Response, ContractError> {
+    assert_not_paused(deps.as_ref())?;
+    let config = CONFIG.load(deps.storage)?;
+    let amount = single_payment(&info, &config.denom)?;
+
+    let mut listing = load_listing(deps.as_ref(), listing_id)?;
+    if info.sender == listing.seller {
+        return Err(ContractError::SelfPurchase);
+    }
+    let ListingKind::Auction {
+        min_bid,
+        end_time,
+        highest_bid,
+    } = listing.kind
+    else {
+        return Err(ContractError::NotAuction { listing_id });
+    };
+    if env.block.time >= end_time {
+        return Err(ContractError::AuctionEnded);
+    }
+    let min = match &highest_bid {
+        Some(bid) => bid.amount + Uint128::one(),
+        None => min_bid,
+    };
+    if amount < min {
+        return Err(ContractError::BidTooLow {
+            min: Coin::new(min, &config.denom).to_string(),
+        });
+    }
+
+    let mut res = Response::new();
+    if let Some(previous) = highest_bid {
+        res = res.add_message(BankMsg::Send {
+            to_address: previous.bidder.to_string(),
+            amount: vec![Coin::new(previous.amount, &config.denom)],
+        });
+    }
+    listing.kind = ListingKind::Auction {
+        min_bid,
+        end_time,
+        highest_bid: Some(Bid {
+            bidder: info.sender.clone(),
+            amount,
+        }),
+    };
+    LISTINGS.save(deps.storage, listing_id, &listing)?;
+
+    Ok(res
+        .add_attribute("action", "place_bid")
+        .add_attribute("listing_id", listing_id.to_string())
+        .add_attribute("bidder", info.sender.as_str())
+        .add_attribute("amount", amount.to_string()))
+}
+
+/// Close an ended auction. Works while paused so escrowed tokens and bids
+/// are never stuck. The winner's payment is only paid out once the token has
+/// reached them; if the collection refuses the transfer, the bid is refunded
+/// and the token is left for the seller to reclaim.
+pub fn execute_settle_auction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    listing_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+
+    let listing = load_listing(deps.as_ref(), listing_id)?;
+    let ListingKind::Auction {
+        end_time,
+        highest_bid,
+        ..
+    } = listing.kind.clone()
+    else {
+        return Err(ContractError::NotAuction { listing_id });
+    };
+    if env.block.time < end_time {
+        return Err(ContractError::AuctionNotEnded);
+    }
+
+    LISTINGS.remove(deps.storage, listing_id);
+    let res = Response::new()
+        .add_attribute("action", "settle_auction")
+        .add_attribute("listing_id", listing_id.to_string());
+    let Some(bid) = highest_bid else {
+        return Ok(res
+            .add_submessage(return_nft_msg(listing_id, &listing)?)
+            .add_attribute("winner", "none"));
+    };
+    let transfer = transfer_nft_msg(&listing.collection, &bid.bidder, &listing.token_id)?;
+    let deliver =
+        SubMsg::reply_always(transfer, SALE_REPLY_ID).with_payload(to_json_binary(&ListingInfo {
+            listing_id,
+            listing,
+        })?);
+
+    Ok(res
+        .add_submessage(deliver)
+        .add_attribute("winner", bid.bidder.as_str()))
+}
+
+pub fn execute_cancel_listing(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    listing_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+
+    let listing = load_listing(deps.as_ref(), listing_id)?;
+    if info.sender != listing.seller {
+        return Err(ContractError::Unauthorized {
+            role: "seller".to_string(),
+        });
+    }
+    if let ListingKind::Auction {
+        highest_bid: Some(_),
+        ..
+    } = listing.kind
+    {
+        return Err(ContractError::HasBids);
+    }
+    LISTINGS.remove(deps.storage, listing_id);
+
+    Ok(Response::new()
+        .add_submessage(return_nft_msg(listing_id, &listing)?)
+        .add_attribute("action", "cancel_listing")
+        .add_attribute("listing_id", listing_id.to_string()))
+}
+
+/// Retry returning a token whose transfer failed when its listing closed.
+/// Works while paused. Fails as a whole if the collection still refuses, so
+/// the token stays reclaimable.
+pub fn execute_reclaim_nft(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    listing_id: u64,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+
+    let listing = UNRETURNED
+        .may_load(deps.storage, listing_id)?
+        .ok_or(ContractError::NothingToReclaim { listing_id })?;
+    if info.sender != listing.seller {
+        return Err(ContractError::Unauthorized {
+            role: "seller".to_string(),
+        });
+    }
+    UNRETURNED.remove(deps.storage, listing_id);
+
+    Ok(Response::new()
+        .add_message(transfer_nft_msg(
+            &listing.collection,
+            &listing.seller,
+            &listing.token_id,
+        )?)
+        .add_attribute("action", "reclaim_nft")
+        .add_attribute("listing_id", listing_id.to_string()))
+}
+
+fn load_listing(deps: Deps, listing_id: u64) -> Result<Listing, ContractError> {
+    LISTINGS
+        .may_load(deps.storage, listing_id)?
+        .ok_or(ContractError::ListingNotFound { listing_id })
+}
+
+// ─── Admin ──────────────────────────────────────────────────────────────────
+
+pub fn execute_add_collection(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    collection: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let collection = deps.api.addr_validate(&collection)?;
+    if COLLECTIONS.has(deps.storage, &collection) {
+        return Err(ContractError::CollectionAlreadyAllowed {
+            collection: collection.to_string(),
+        });
+    }
+    COLLECTIONS.save(deps.storage, &collection, &true)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_collection")
+        .add_attribute("collection", collection.as_str()))
+}
+
+/// Open listings from a removed collection stay buyable and cancellable.
+pub fn execute_remove_collection(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    collection: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    let collection = deps.api.addr_validate(&collection)?;
+    if !COLLECTIONS.has(deps.storage, &collection) {
+        return Err(ContractError::CollectionNotAllowed {
+            collection: collection.to_string(),
+        });
+    }
+    COLLECTIONS.remove(deps.storage, &collection);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_collection")
+        .add_attribute("collection", collection.as_str()))
+}
+
+pub fn execute_pause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(sysbreak_common::execute_pause(deps, &info, &CONFIG)?)
+}
+
+pub fn execute_unpause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(sysbreak_common::execute_unpause(deps, &info, &CONFIG)?)
+}
+
+pub fn execute_propose_owner(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    new_owner: String,
+) -> Result<Response, ContractError> {
+    let proposed_owner = deps.api.addr_validate(&new_owner)?;
+    Ok(OWNER_TRANSFER.propose(
+        deps,
+        &info,
+        &CONFIG,
+        PendingOwnerTransfer { proposed_owner },
+    )?)
+}
+
+pub fn execute_accept_owner(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(OWNER_TRANSFER.accept(deps, &info, &CONFIG)?)
+}
+
+pub fn execute_cancel_owner_transfer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(OWNER_TRANSFER.cancel(deps, &info, &CONFIG)?)
+}
+
+// ─── Reply ──────────────────────────────────────────────────────────────────
+
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        RETURN_REPLY_ID => reply_return(deps, msg),
+        SALE_REPLY_ID => reply_sale(deps, msg),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+/// A token the seller could not take back stays here until ReclaimNft
+fn reply_return(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let error = match msg.result {
+        SubMsgResult::Ok(_) => return Ok(Response::new()),
+        SubMsgResult::Err(err) => err,
+    };
+    let ListingInfo {
+        listing_id,
+        listing,
+    } = from_json(&msg.payload)?;
+    UNRETURNED.save(deps.storage, listing_id, &listing)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "nft_return_failed")
+        .add_attribute("listing_id", listing_id.to_string())
+        .add_attribute("error", error))
+}
+
+/// Pay out an auction once the token reached the winner. If it could not,
+/// the winner gets the bid back and the token waits for the seller.
+fn reply_sale(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let ListingInfo {
+        listing_id,
+        listing,
+    } = from_json(&msg.payload)?;
+    let ListingKind::Auction {
+        highest_bid: Some(bid),
+        ..
+    } = &listing.kind
+    else {
+        return Err(ContractError::NotAuction { listing_id });
+    };
+    let config = CONFIG.load(deps.storage)?;
+
+    if let SubMsgResult::Err(error) = msg.result {
+        UNRETURNED.save(deps.storage, listing_id, &listing)?;
+        return Ok(Response::new()
+            .add_message(BankMsg::Send {
+                to_address: bid.bidder.to_string(),
+                amount: vec![Coin::new(bid.amount, &config.denom)],
+            })
+            .add_attribute("action", "auction_delivery_failed")
+            .add_attribute("listing_id", listing_id.to_string())
+            .add_attribute("refunded", bid.bidder.as_str())
+            .add_attribute("error", error));
+    }
+
+    let (payouts, royalty) = settle_sale_msgs(
+        deps.as_ref(),
+        &config.denom,
+        &listing.collection,
+        &listing.token_id,
+        &listing.seller,
+        bid.amount,
+    );
+    Ok(Response::new()
+        .add_messages(payouts)
+        .add_attribute("action", "auction_delivered")
+        .add_attribute("listing_id", listing_id.to_string())
+        .add_attribute("royalty", royalty.to_string())
+        .add_event(SaleEvent {
+            listing_id: listing_id.to_string(),
+            collection: listing.collection.to_string(),
+            token_id: listing.token_id.clone(),
+            seller: listing.seller.to_string(),
+            buyer: bid.bidder.to_string(),
+            price: bid.amount.to_string(),
+            denom: config.denom,
+        }))
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+pub fn query_config(deps: Deps) -> StdResult<Binary> {
+    to_json_binary(&CONFIG.load(deps.storage)?)
+}
+
+pub fn query_listing(deps: Deps, listing_id: u64) -> StdResult<Binary> {
+    to_json_binary(&LISTINGS.may_load(deps.storage, listing_id)?)
+}
+
+pub fn query_unreturned(deps: Deps, listing_id: u64) -> StdResult<Binary> {
+    to_json_binary(&UNRETURNED.may_load(deps.storage, listing_id)?)
+}
+
+pub fn query_listings(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.map(cw_storage_plus::Bound::exclusive);
+
+    let listings: Vec<ListingInfo> = LISTINGS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(listing_id, listing)| ListingInfo {
+                listing_id,
+                listing,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&ListingsResponse { listings })
+}
+
+pub fn query_collections(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_addr = start_after.map(Addr::unchecked);
+    let start = start_addr.as_ref().map(cw_storage_plus::Bound::exclusive);
+
+    let collections: Vec<String> = COLLECTIONS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|addr| addr.to_string()))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&CollectionsResponse { collections })
+}
+
+pub fn query_pending_owner(deps: Deps) -> StdResult<Binary> {
+    OWNER_TRANSFER.query_pending(deps)
+}
+
+// ─── Migrate ────────────────────────────────────────────────────────────────
+
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("version", CONTRACT_VERSION))
+}
@@ -0,0 +1,63 @@
+use cosmwasm_std::StdError;
+use sysbreak_common::AdminError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Admin(#[from] AdminError),
+
+    #[error("unauthorized: only {role} can perform this action")]
+    Unauthorized { role: String },
+
+    #[error("collection {collection} is not accepted by this marketplace")]
+    CollectionNotAllowed { collection: String },
+
+    #[error("collection {collection} is already accepted")]
+    CollectionAlreadyAllowed { collection: String },
+
+    #[error("token is soulbound and cannot be listed")]
+    Soulbound,
+
+    #[error("listing not found: {listing_id}")]
+    ListingNotFound { listing_id: u64 },
+
+    #[error("price must be non-zero")]
+    InvalidPrice,
+
+    #[error("auction duration must be between 1 and {max} seconds")]
+    InvalidDuration { max: u64 },
+
+    #[error("payment must be exactly {expected}")]
+    IncorrectPayment { expected: String },
+
+    #[error("cannot buy or bid on your own listing")]
+    SelfPurchase,
+
+    #[error("listing {listing_id} is not a fixed-price listing")]
+    NotFixedPrice { listing_id: u64 },
+
+    #[error("listing {listing_id} is not an auction")]
+    NotAuction { listing_id: u64 },
+
+    #[error("auction has ended")]
+    AuctionEnded,
+
+    #[error("auction has not ended yet")]
+    AuctionNotEnded,
+
+    #[error("bid must be at least {min}")]
+    BidTooLow { min: String },
+
+    #[error("auction already has bids and cannot be cancelled")]
+    HasBids,
+
+    #[error("no unreturned token for listing {listing_id}")]
+    NothingToReclaim { listing_id: u64 },
+
+    #[error("unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
+}
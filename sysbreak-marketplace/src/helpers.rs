@@ -0,0 +1,123 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Deps, MessageInfo, StdResult, SubMsg, Uint128,
+    WasmMsg,
+};
+
+use crate::error::ContractError;
+use crate::msg::{Cw2981QueryMsg, ListingInfo, NftExecuteMsg, NftQueryMsg, RoyaltiesInfoResponse};
+use crate::state::{Listing, CONFIG, RETURN_REPLY_ID};
+
+/// Verify the caller is the contract owner.
+pub fn assert_owner(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    Ok(sysbreak_common::assert_owner(deps, &CONFIG, sender)?)
+}
+
+/// Verify the contract is not paused.
+pub fn assert_not_paused(deps: Deps) -> Result<(), ContractError> {
+    Ok(sysbreak_common::assert_not_paused(deps, &CONFIG)?)
+}
+
+/// Amount of `denom` sent with the message; any other coin is rejected.
+pub fn single_payment(info: &MessageInfo, denom: &str) -> Result<Uint128, ContractError> {
+    match info.funds.as_slice() {
+        [coin] if coin.denom == denom => Ok(coin.amount),
+        _ => Err(ContractError::IncorrectPayment {
+            expected: format!("a single {} coin", denom),
+        }),
+    }
+}
+
+/// Message returning an escrowed token to `recipient`.
+pub fn transfer_nft_msg(
+    collection: &Addr,
+    recipient: &Addr,
+    token_id: &str,
+) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: collection.to_string(),
+        msg: to_json_binary(&NftExecuteMsg::TransferNft {
+            recipient: recipient.to_string(),
+            token_id: token_id.to_string(),
+        })?,
+        funds: vec![],
+    }
+    .into())
+}
+
+/// Hand an escrowed token back to its seller. A failed transfer comes back
+/// through `reply` and leaves the token for ReclaimNft, so closing the
+/// listing never depends on the collection accepting the transfer.
+pub fn return_nft_msg(listing_id: u64, listing: &Listing) -> StdResult<SubMsg> {
+    let transfer = transfer_nft_msg(&listing.collection, &listing.seller, &listing.token_id)?;
+    Ok(
+        SubMsg::reply_on_error(transfer, RETURN_REPLY_ID).with_payload(to_json_binary(
+            &ListingInfo {
+                listing_id,
+                listing: listing.clone(),
+            },
+        )?),
+    )
+}
+
+/// cw2981 royalty owed on a sale. Collections that do not implement cw2981
+/// (e.g. achievements) pay none; the amount is capped at the sale price.
+pub fn query_royalty(
+    deps: Deps,
+    collection: &Addr,
+    token_id: &str,
+    sale_price: Uint128,
+) -> Option<(Addr, Uint128)> {
+    let res: RoyaltiesInfoResponse = deps
+        .querier
+        .query_wasm_smart(
+            collection,
+            &NftQueryMsg::Extension {
+                msg: Cw2981QueryMsg::RoyaltyInfo {
+                    token_id: token_id.to_string(),
+                    sale_price,
+                },
+            },
+        )
+        .ok()?;
+    if res.royalty_amount.is_zero() {
+        return None;
+    }
+    let recipient = deps.api.addr_validate(&res.address).ok()?;
+    Some((recipient, res.royalty_amount.min(sale_price)))
+}
+
+/// Payout messages for a sale: royalty first, the remainder to the seller.
+pub fn settle_sale_msgs(
+    deps: Deps,
+    denom: &str,
+    collection: &Addr,
+    token_id: &str,
+    seller: &Addr,
+    price: Uint128,
+) -> (Vec<CosmosMsg>, Uint128) {
+    let mut msgs = vec![];
+    let royalty = match query_royalty(deps, collection, token_id, price) {
+        Some((recipient, amount)) => {
+            msgs.push(
+                BankMsg::Send {
+                    to_address: recipient.to_string(),
+                    amount: vec![Coin::new(amount, denom)],
+                }
+                .into(),
+            );
+            amount
+        }
+        None => Uint128::zero(),
+    };
+    let proceeds = price - royalty;
+    if !proceeds.is_zero() {
+        msgs.push(
+            BankMsg::Send {
+                to_address: seller.to_string(),
+                amount: vec![Coin::new(proceeds, denom)],
+            }
+            .into(),
+        );
+    }
+    (msgs, royalty)
+}
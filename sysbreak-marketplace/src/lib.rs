@@ -0,0 +1,100 @@
+pub mod contract;
+pub mod error;
+pub mod helpers;
+pub mod msg;
+pub mod state;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+// Entry points are only referenced by the wasm export shims, which are not
+// generated for native targets, and by the `testing` wrapper.
+#[cfg(any(not(feature = "library"), feature = "testing"))]
+#[allow(dead_code)]
+mod entry {
+    use super::*;
+    use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
+    use msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+    #[entry_point]
+    pub fn instantiate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response, error::ContractError> {
+        contract::instantiate(deps, env, info, msg)
+    }
+
+    #[entry_point]
+    pub fn execute(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, error::ContractError> {
+        match msg {
+            ExecuteMsg::ReceiveNft(receive) => {
+                contract::execute_receive_nft(deps, env, info, receive)
+            }
+            ExecuteMsg::Buy { listing_id } => contract::execute_buy(deps, env, info, listing_id),
+            ExecuteMsg::PlaceBid { listing_id } => {
+                contract::execute_place_bid(deps, env, info, listing_id)
+            }
+            ExecuteMsg::SettleAuction { listing_id } => {
+                contract::execute_settle_auction(deps, env, info, listing_id)
+            }
+            ExecuteMsg::CancelListing { listing_id } => {
+                contract::execute_cancel_listing(deps, env, info, listing_id)
+            }
+            ExecuteMsg::ReclaimNft { listing_id } => {
+                contract::execute_reclaim_nft(deps, env, info, listing_id)
+            }
+            ExecuteMsg::AddCollection { collection } => {
+                contract::execute_add_collection(deps, env, info, collection)
+            }
+            ExecuteMsg::RemoveCollection { collection } => {
+                contract::execute_remove_collection(deps, env, info, collection)
+            }
+            ExecuteMsg::Pause {} => contract::execute_pause(deps, env, info),
+            ExecuteMsg::Unpause {} => contract::execute_unpause(deps, env, info),
+            ExecuteMsg::ProposeOwner { new_owner } => {
+                contract::execute_propose_owner(deps, env, info, new_owner)
+            }
+            ExecuteMsg::AcceptOwner {} => contract::execute_accept_owner(deps, env, info),
+            ExecuteMsg::CancelOwnerTransfer {} => {
+                contract::execute_cancel_owner_transfer(deps, env, info)
+            }
+        }
+    }
+
+    #[entry_point]
+    pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> cosmwasm_std::StdResult<Binary> {
+        match msg {
+            QueryMsg::Config {} => contract::query_config(deps),
+            QueryMsg::Listing { listing_id } => contract::query_listing(deps, listing_id),
+            QueryMsg::Unreturned { listing_id } => contract::query_unreturned(deps, listing_id),
+            QueryMsg::Listings { start_after, limit } => {
+                contract::query_listings(deps, start_after, limit)
+            }
+            QueryMsg::Collections { start_after, limit } => {
+                contract::query_collections(deps, start_after, limit)
+            }
+            QueryMsg::PendingOwner {} => contract::query_pending_owner(deps),
+        }
+    }
+
+    #[entry_point]
+    pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, error::ContractError> {
+        contract::reply(deps, env, msg)
+    }
+
+    #[entry_point]
+    pub fn migrate(
+        deps: DepsMut,
+        env: Env,
+        msg: MigrateMsg,
+    ) -> Result<Response, error::ContractError> {
+        contract::migrate(deps, env, msg)
+    }
+}
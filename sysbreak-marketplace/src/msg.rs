@@ -0,0 +1,164 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+use cw721::receiver::Cw721ReceiveMsg;
+
+use crate::state::Listing;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Contract owner address
+    pub owner: String,
+    /// Native denom listings are priced in (e.g. "ushido")
+    pub denom: String,
+    /// NFT contracts accepted at launch
+    pub collections: Vec<String>,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// CW-721 receive hook: an allowed collection's SendNft lands here and the
+    /// embedded `ListingMsg` decides how the token is listed
+    ReceiveNft(Cw721ReceiveMsg),
+    /// Buy a fixed-price listing by sending exactly its price
+    Buy {
+        listing_id: u64,
+    },
+    /// Bid on an auction; the previous highest bidder is refunded
+    PlaceBid {
+        listing_id: u64,
+    },
+    /// Close an ended auction: the token goes to the winner (or back to the
+    /// seller if there were no bids). Anyone may call this.
+    SettleAuction {
+        listing_id: u64,
+    },
+    /// Withdraw a listing and return the token (seller only). Auctions can
+    /// only be cancelled before the first bid.
+    CancelListing {
+        listing_id: u64,
+    },
+    /// Retry handing back a token whose return failed when its listing
+    /// closed (seller only)
+    ReclaimNft {
+        listing_id: u64,
+    },
+    /// Accept listings from an NFT contract (owner only)
+    AddCollection {
+        collection: String,
+    },
+    /// Stop accepting new listings from an NFT contract (owner only)
+    RemoveCollection {
+        collection: String,
+    },
+    /// Pause new listings, purchases and bids (owner only)
+    Pause {},
+    /// Unpause the contract (owner only)
+    Unpause {},
+    ProposeOwner {
+        new_owner: String,
+    },
+    AcceptOwner {},
+    CancelOwnerTransfer {},
+}
+
+/// Payload of `Cw721ReceiveMsg.msg` sent along with SendNft
+#[cw_serde]
+pub enum ListingMsg {
+    FixedPrice {
+        price: Uint128,
+    },
+    /// English auction lasting `duration` seconds from listing
+    Auction {
+        min_bid: Uint128,
+        duration: u64,
+    },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Get contract configuration
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Get a listing
+    #[returns(Option<crate::state::Listing>)]
+    Listing { listing_id: u64 },
+    /// List open listings, paginated by listing_id
+    #[returns(ListingsResponse)]
+    Listings {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Token of a closed listing still waiting to go back to its seller
+    #[returns(Option<crate::state::Listing>)]
+    Unreturned { listing_id: u64 },
+    /// List accepted NFT contracts, paginated by address
+    #[returns(CollectionsResponse)]
+    Collections {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Option<crate::state::PendingOwnerTransfer>)]
+    PendingOwner {},
+}
+
+#[cw_serde]
+pub struct ListingInfo {
+    pub listing_id: u64,
+    pub listing: Listing,
+}
+
+#[cw_serde]
+pub struct ListingsResponse {
+    pub listings: Vec<ListingInfo>,
+}
+
+#[cw_serde]
+pub struct CollectionsResponse {
+    pub collections: Vec<String>,
+}
+
+// ─── Messages sent to NFT collections ───────────────────────────────────────
+
+/// Subset of the CW-721 execute interface used to release escrowed tokens
+#[cw_serde]
+pub enum NftExecuteMsg {
+    TransferNft { recipient: String, token_id: String },
+}
+
+/// Subset of the collection query interface used by the marketplace
+#[cw_serde]
+pub enum NftQueryMsg {
+    NftInfo {
+        token_id: String,
+    },
+    /// cw2981 royalty queries, wrapped like cw721-base
+    Extension {
+        msg: Cw2981QueryMsg,
+    },
+}
+
+#[cw_serde]
+pub enum Cw2981QueryMsg {
+    RoyaltyInfo {
+        token_id: String,
+        sale_price: Uint128,
+    },
+}
+
+#[cw_serde]
+pub struct RoyaltiesInfoResponse {
+    pub address: String,
+    pub royalty_amount: Uint128,
+}
+
+/// The only NftInfo field the marketplace reads. Unknown fields are ignored so
+/// item and achievement responses both parse.
+#[derive(serde::Deserialize)]
+pub struct NftInfoSoulbound {
+    #[serde(default)]
+    pub soulbound: bool,
+}
@@ -0,0 +1,86 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+pub use sysbreak_common::PendingOwnerTransfer;
+use sysbreak_common::{Owned, Pausable, RoleTransfer};
+
+/// Contract-level configuration
+#[cw_serde]
+pub struct Config {
+    /// Contract owner — manages the collection allowlist and pause
+    pub owner: Addr,
+    /// Native denom all listings are priced in (e.g. "ushido")
+    pub denom: String,
+    /// Whether the contract is paused (freezes new listings, purchases and bids)
+    pub paused: bool,
+}
+
+impl Owned for Config {
+    fn owner(&self) -> &Addr {
+        &self.owner
+    }
+
+    fn set_owner(&mut self, owner: Addr) {
+        self.owner = owner;
+    }
+}
+
+impl Pausable for Config {
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+}
+
+/// Highest bid on an auction; its funds are held by the contract
+#[cw_serde]
+pub struct Bid {
+    pub bidder: Addr,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum ListingKind {
+    FixedPrice {
+        price: Uint128,
+    },
+    Auction {
+        min_bid: Uint128,
+        end_time: Timestamp,
+        highest_bid: Option<Bid>,
+    },
+}
+
+/// An NFT escrowed by the marketplace for sale
+#[cw_serde]
+pub struct Listing {
+    pub seller: Addr,
+    /// NFT contract the token belongs to
+    pub collection: Addr,
+    pub token_id: String,
+    pub kind: ListingKind,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const OWNER_TRANSFER: RoleTransfer<PendingOwnerTransfer> =
+    RoleTransfer::new("owner", "pending_owner");
+
+/// NFT contracts allowed to list here: collection -> bool
+pub const COLLECTIONS: Map<&Addr, bool> = Map::new("collections");
+
+/// listing_id -> Listing
+pub const LISTINGS: Map<u64, Listing> = Map::new("listings");
+/// Last issued listing id; ids are never reused
+pub const LAST_LISTING_ID: Item<u64> = Item::new("last_listing_id");
+/// Closed listings whose token could not be handed back (e.g. the collection
+/// refused the transfer at the time); the seller retries with ReclaimNft:
+/// listing_id -> Listing
+pub const UNRETURNED: Map<u64, Listing> = Map::new("unreturned");
+
+/// Reply id for a token going back to its seller
+pub const RETURN_REPLY_ID: u64 = 1;
+/// Reply id for a token going to an auction winner
+pub const SALE_REPLY_ID: u64 = 2;
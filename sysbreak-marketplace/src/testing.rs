@@ -0,0 +1,15 @@
+//! cw-multi-test wrapper, for tests that run this contract alongside others.
+
+use cosmwasm_std::Empty;
+use cw_multi_test::{Contract, ContractWrapper};
+
+use crate::entry;
+
+/// The marketplace, ready for `App::store_code`.
+pub fn contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new(entry::execute, entry::instantiate, entry::query)
+            .with_reply(entry::reply)
+            .with_migrate(entry::migrate),
+    )
+}
@@ -0,0 +1,549 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi, MockQuerier};
+use cosmwasm_std::{
+    coin, from_json, to_json_binary, Addr, BankMsg, Binary, ContractResult, CosmosMsg, Event,
+    MemoryStorage, OwnedDeps, Reply, ReplyOn, SubMsgResponse, SubMsgResult, SystemError,
+    SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use cw721::receiver::Cw721ReceiveMsg;
+
+use sysbreak_events::SaleEvent;
+use sysbreak_marketplace::contract::*;
+use sysbreak_marketplace::error::ContractError;
+use sysbreak_marketplace::msg::*;
+use sysbreak_marketplace::state::{Bid, Listing, ListingKind, RETURN_REPLY_ID, SALE_REPLY_ID};
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const DENOM: &str = "ushido";
+/// Token id the mocked achievement collection reports as soulbound
+const SOULBOUND_TOKEN: &str = "99";
+
+fn addr(deps: &Deps, name: &str) -> Addr {
+    deps.api.addr_make(name)
+}
+
+/// Marketplace accepting "item_nft" and "achievement_nft". The item collection
+/// answers cw2981 with a 5% royalty; the achievement collection does not
+/// implement cw2981.
+fn setup_contract() -> Deps {
+    let mut deps = mock_dependencies();
+    let owner = deps.api.addr_make("owner");
+    let item_nft = deps.api.addr_make("item_nft").to_string();
+    let achievement_nft = deps.api.addr_make("achievement_nft").to_string();
+    let royalty = deps.api.addr_make("royalty").to_string();
+
+    let (item, achievement) = (item_nft.clone(), achievement_nft.clone());
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } => {
+            let msg: NftQueryMsg = from_json(msg).unwrap();
+            let res = match msg {
+                NftQueryMsg::NftInfo { token_id } => to_json_binary(&NftInfo {
+                    soulbound: *contract_addr == achievement && token_id == SOULBOUND_TOKEN,
+                    token_id,
+                }),
+                NftQueryMsg::Extension {
+                    msg: Cw2981QueryMsg::RoyaltyInfo { sale_price, .. },
+                } if *contract_addr == item => to_json_binary(&RoyaltiesInfoResponse {
+                    address: royalty.clone(),
+                    royalty_amount: sale_price.multiply_ratio(500u128, 10_000u128),
+                }),
+                NftQueryMsg::Extension { .. } => {
+                    return SystemResult::Ok(ContractResult::Err("unknown variant".to_string()))
+                }
+            };
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        }
+        _ => SystemResult::Err(SystemError::Unknown {}),
+    });
+
+    let msg = InstantiateMsg {
+        owner: owner.to_string(),
+        denom: DENOM.to_string(),
+        collections: vec![item_nft, achievement_nft],
+    };
+    instantiate(deps.as_mut(), mock_env(), message_info(&owner, &[]), msg).unwrap();
+    deps
+}
+
+/// Trimmed NftInfo response; the real ones carry more fields, which the
+/// marketplace ignores
+#[cosmwasm_schema::cw_serde]
+struct NftInfo {
+    token_id: String,
+    soulbound: bool,
+}
+
+fn list(
+    deps: &mut Deps,
+    collection: &str,
+    seller: &Addr,
+    token_id: &str,
+    listing: ListingMsg,
+) -> Result<cosmwasm_std::Response, ContractError> {
+    let collection = addr(deps, collection);
+    execute_receive_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&collection, &[]),
+        Cw721ReceiveMsg {
+            sender: seller.to_string(),
+            token_id: token_id.to_string(),
+            msg: to_json_binary(&listing).unwrap(),
+        },
+    )
+}
+
+fn transfer_msg(collection: &Addr, recipient: &Addr, token_id: &str) -> CosmosMsg {
+    WasmMsg::Execute {
+        contract_addr: collection.to_string(),
+        msg: to_json_binary(&NftExecuteMsg::TransferNft {
+            recipient: recipient.to_string(),
+            token_id: token_id.to_string(),
+        })
+        .unwrap(),
+        funds: vec![],
+    }
+    .into()
+}
+
+fn bank_msg(to: &Addr, amount: u128) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: to.to_string(),
+        amount: vec![coin(amount, DENOM)],
+    }
+    .into()
+}
+
+fn messages(res: &cosmwasm_std::Response) -> Vec<CosmosMsg> {
+    res.messages.iter().map(|m| m.msg.clone()).collect()
+}
+
+/// The reply the chain sends back for the response's first submessage
+fn reply_to(res: &cosmwasm_std::Response, result: SubMsgResult) -> Reply {
+    Reply {
+        id: res.messages[0].id,
+        payload: res.messages[0].payload.clone(),
+        gas_used: 0,
+        result,
+    }
+}
+
+#[allow(deprecated)]
+fn delivered() -> SubMsgResult {
+    SubMsgResult::Ok(SubMsgResponse {
+        events: vec![],
+        data: None,
+        msg_responses: vec![],
+    })
+}
+
+// ─── Fixed Price ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_fixed_price_sale_pays_royalty() {
+    let mut deps = setup_contract();
+    let item_nft = addr(&deps, "item_nft");
+    let seller = addr(&deps, "seller");
+    let buyer = addr(&deps, "buyer");
+    let royalty = addr(&deps, "royalty");
+
+    let res = list(
+        &mut deps,
+        "item_nft",
+        &seller,
+        "7",
+        ListingMsg::FixedPrice {
+            price: Uint128::new(1_000_000),
+        },
+    )
+    .unwrap();
+    assert_eq!(res.attributes[1].value, "1");
+
+    let err = execute_buy(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&buyer, &[coin(999_999, DENOM)]),
+        1,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::IncorrectPayment {
+            expected: coin(1_000_000, DENOM).to_string()
+        }
+    );
+    let res = execute_buy(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&buyer, &[coin(1_000_000, DENOM)]),
+        1,
+    )
+    .unwrap();
+    assert_eq!(
+        messages(&res),
+        vec![
+            transfer_msg(&item_nft, &buyer, "7"),
+            bank_msg(&royalty, 50_000),
+            bank_msg(&seller, 950_000),
+        ]
+    );
+    assert_eq!(
+        res.events,
+        vec![Event::from(SaleEvent {
+            listing_id: "1".to_string(),
+            collection: item_nft.to_string(),
+            token_id: "7".to_string(),
+            seller: seller.to_string(),
+            buyer: buyer.to_string(),
+            price: "1000000".to_string(),
+            denom: DENOM.to_string(),
+        })]
+    );
+
+    let listing: Option<sysbreak_marketplace::state::Listing> =
+        from_json(query_listing(deps.as_ref(), 1).unwrap()).unwrap();
+    assert!(listing.is_none());
+}
+
+#[test]
+fn test_collection_without_cw2981_pays_seller_in_full() {
+    let mut deps = setup_contract();
+    let achievement_nft = addr(&deps, "achievement_nft");
+    let seller = addr(&deps, "seller");
+    let buyer = addr(&deps, "buyer");
+
+    list(
+        &mut deps,
+        "achievement_nft",
+        &seller,
+        "3",
+        ListingMsg::FixedPrice {
+            price: Uint128::new(500),
+        },
+    )
+    .unwrap();
+    let res = execute_buy(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&buyer, &[coin(500, DENOM)]),
+        1,
+    )
+    .unwrap();
+    assert_eq!(
+        messages(&res),
+        vec![
+            transfer_msg(&achievement_nft, &buyer, "3"),
+            bank_msg(&seller, 500),
+        ]
+    );
+}
+
+#[test]
+fn test_listing_rejections() {
+    let mut deps = setup_contract();
+    let seller = addr(&deps, "seller");
+    let price = ListingMsg::FixedPrice {
+        price: Uint128::new(100),
+    };
+
+    let err = list(&mut deps, "random_nft", &seller, "1", price.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CollectionNotAllowed {
+            collection: addr(&deps, "random_nft").to_string()
+        }
+    );
+    let err = list(
+        &mut deps,
+        "achievement_nft",
+        &seller,
+        SOULBOUND_TOKEN,
+        price,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Soulbound);
+    let err = list(
+        &mut deps,
+        "item_nft",
+        &seller,
+        "1",
+        ListingMsg::FixedPrice {
+            price: Uint128::zero(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidPrice);
+}
+
+#[test]
+fn test_cancel_returns_token() {
+    let mut deps = setup_contract();
+    let item_nft = addr(&deps, "item_nft");
+    let seller = addr(&deps, "seller");
+    let other = addr(&deps, "other");
+    list(
+        &mut deps,
+        "item_nft",
+        &seller,
+        "7",
+        ListingMsg::FixedPrice {
+            price: Uint128::new(100),
+        },
+    )
+    .unwrap();
+
+    let err = execute_cancel_listing(deps.as_mut(), mock_env(), message_info(&other, &[]), 1)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "seller".to_string()
+        }
+    );
+    let res =
+        execute_cancel_listing(deps.as_mut(), mock_env(), message_info(&seller, &[]), 1).unwrap();
+    assert_eq!(messages(&res), vec![transfer_msg(&item_nft, &seller, "7")]);
+}
+
+// ─── Auctions ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_auction_bidding_and_settlement() {
+    let mut deps = setup_contract();
+    let item_nft = addr(&deps, "item_nft");
+    let seller = addr(&deps, "seller");
+    let alice = addr(&deps, "alice");
+    let bob = addr(&deps, "bob");
+    let royalty = addr(&deps, "royalty");
+    list(
+        &mut deps,
+        "item_nft",
+        &seller,
+        "7",
+        ListingMsg::Auction {
+            min_bid: Uint128::new(1_000),
+            duration: 3_600,
+        },
+    )
+    .unwrap();
+
+    let err = execute_place_bid(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &[coin(999, DENOM)]),
+        1,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::BidTooLow {
+            min: coin(1_000, DENOM).to_string()
+        }
+    );
+    execute_place_bid(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &[coin(1_000, DENOM)]),
+        1,
+    )
+    .unwrap();
+    let err = execute_place_bid(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&bob, &[coin(1_000, DENOM)]),
+        1,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::BidTooLow {
+            min: coin(1_001, DENOM).to_string()
+        }
+    );
+    // Outbidding refunds the previous bidder
+    let res = execute_place_bid(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&bob, &[coin(2_000, DENOM)]),
+        1,
+    )
+    .unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&alice, 1_000)]);
+
+    // Bids lock the listing against cancellation
+    let err = execute_cancel_listing(deps.as_mut(), mock_env(), message_info(&seller, &[]), 1)
+        .unwrap_err();
+    assert_eq!(err, ContractError::HasBids);
+    let err = execute_settle_auction(deps.as_mut(), mock_env(), message_info(&alice, &[]), 1)
+        .unwrap_err();
+    assert_eq!(err, ContractError::AuctionNotEnded);
+
+    let listings: ListingsResponse =
+        from_json(query_listings(deps.as_ref(), None, None).unwrap()).unwrap();
+    match &listings.listings[0].listing.kind {
+        ListingKind::Auction { highest_bid, .. } => assert_eq!(
+            highest_bid,
+            &Some(Bid {
+                bidder: bob.clone(),
+                amount: Uint128::new(2_000)
+            })
+        ),
+        kind => panic!("unexpected listing kind {:?}", kind),
+    }
+
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(3_600);
+    let err = execute_place_bid(
+        deps.as_mut(),
+        later.clone(),
+        message_info(&alice, &[coin(5_000, DENOM)]),
+        1,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AuctionEnded);
+    let res = execute_settle_auction(deps.as_mut(), later, message_info(&alice, &[]), 1).unwrap();
+    assert_eq!(messages(&res), vec![transfer_msg(&item_nft, &bob, "7")]);
+    assert_eq!(res.messages[0].id, SALE_REPLY_ID);
+    assert_eq!(res.messages[0].reply_on, ReplyOn::Always);
+
+    // The seller is paid once the token has landed
+    let res = reply(deps.as_mut(), mock_env(), reply_to(&res, delivered())).unwrap();
+    assert_eq!(
+        messages(&res),
+        vec![bank_msg(&royalty, 100), bank_msg(&seller, 1_900)]
+    );
+    assert_eq!(res.events[0].ty, SaleEvent::TYPE);
+}
+
+#[test]
+fn test_unsold_auction_returns_token() {
+    let mut deps = setup_contract();
+    let item_nft = addr(&deps, "item_nft");
+    let seller = addr(&deps, "seller");
+    list(
+        &mut deps,
+        "item_nft",
+        &seller,
+        "7",
+        ListingMsg::Auction {
+            min_bid: Uint128::new(1_000),
+            duration: 60,
+        },
+    )
+    .unwrap();
+
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(60);
+    let res = execute_settle_auction(deps.as_mut(), later, message_info(&seller, &[]), 1).unwrap();
+    assert_eq!(messages(&res), vec![transfer_msg(&item_nft, &seller, "7")]);
+}
+
+#[test]
+fn test_failed_transfers_refund_and_leave_token_reclaimable() {
+    let mut deps = setup_contract();
+    let item_nft = addr(&deps, "item_nft");
+    let seller = addr(&deps, "seller");
+    let bob = addr(&deps, "bob");
+    list(
+        &mut deps,
+        "item_nft",
+        &seller,
+        "7",
+        ListingMsg::FixedPrice {
+            price: Uint128::new(100),
+        },
+    )
+    .unwrap();
+    list(
+        &mut deps,
+        "item_nft",
+        &seller,
+        "8",
+        ListingMsg::Auction {
+            min_bid: Uint128::new(1_000),
+            duration: 60,
+        },
+    )
+    .unwrap();
+    execute_place_bid(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&bob, &[coin(1_000, DENOM)]),
+        2,
+    )
+    .unwrap();
+
+    // The collection refuses to move token 7 back; the listing still closes
+    let res =
+        execute_cancel_listing(deps.as_mut(), mock_env(), message_info(&seller, &[]), 1).unwrap();
+    assert_eq!(res.messages[0].id, RETURN_REPLY_ID);
+    assert_eq!(res.messages[0].reply_on, ReplyOn::Error);
+    let refused = SubMsgResult::Err("token is locked".to_string());
+    let res = reply(deps.as_mut(), mock_env(), reply_to(&res, refused.clone())).unwrap();
+    assert_eq!(res.attributes[0].value, "nft_return_failed");
+    assert!(
+        from_json::<Option<Listing>>(query_listing(deps.as_ref(), 1).unwrap())
+            .unwrap()
+            .is_none()
+    );
+
+    // Same for the auction winner: the bid is refunded instead of paid out
+    let mut later = mock_env();
+    later.block.time = later.block.time.plus_seconds(60);
+    let res = execute_settle_auction(deps.as_mut(), later, message_info(&bob, &[]), 2).unwrap();
+    let res = reply(deps.as_mut(), mock_env(), reply_to(&res, refused)).unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&bob, 1_000)]);
+
+    let parked: Option<Listing> = from_json(query_unreturned(deps.as_ref(), 2).unwrap()).unwrap();
+    assert_eq!(parked.unwrap().token_id, "8");
+    let err =
+        execute_reclaim_nft(deps.as_mut(), mock_env(), message_info(&bob, &[]), 2).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "seller".to_string()
+        }
+    );
+    for (listing_id, token_id) in [(1, "7"), (2, "8")] {
+        let info = message_info(&seller, &[]);
+        let res = execute_reclaim_nft(deps.as_mut(), mock_env(), info, listing_id).unwrap();
+        assert_eq!(
+            messages(&res),
+            vec![transfer_msg(&item_nft, &seller, token_id)]
+        );
+    }
+    let err =
+        execute_reclaim_nft(deps.as_mut(), mock_env(), message_info(&seller, &[]), 1).unwrap_err();
+    assert_eq!(err, ContractError::NothingToReclaim { listing_id: 1 });
+
+    let err = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 99,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: delivered(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::UnknownReplyId { id: 99 });
+}
+
+// ─── Wire Format ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_receive_hook_parses_cw721_send() {
+    // What the SYSBREAK NFT contracts emit on SendNft
+    let hook = Cw721ReceiveMsg {
+        sender: "seller".to_string(),
+        token_id: "7".to_string(),
+        msg: to_json_binary(&ListingMsg::FixedPrice {
+            price: Uint128::new(100),
+        })
+        .unwrap(),
+    };
+    let bytes: Binary = hook.clone().into_json_binary().unwrap();
+    let msg: ExecuteMsg = from_json(bytes).unwrap();
+    assert_eq!(msg, ExecuteMsg::ReceiveNft(hook));
+}
@@ -0,0 +1,515 @@
+use cosmwasm_std::{
+    from_json, to_json_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order,
+    Reply, Response, StdResult, SubMsgResult, Uint128,
+};
+use cw2::set_contract_version;
+use cw721::receiver::Cw721ReceiveMsg;
+use sysbreak_common::reject_funds;
+use sysbreak_events::ClaimEvent;
+
+use crate::error::ContractError;
+use crate::helpers::{assert_not_paused, assert_owner, earned, return_nft_msg, transfer_nft_msg};
+use crate::msg::{
+    InstantiateMsg, MigrateMsg, NftInfoRarity, NftQueryMsg, PendingRewardsResponse, RarityWeight,
+    RarityWeightsResponse, StakeInfo, StakedTokensResponse,
+};
+use crate::state::{
+    Config, PendingOwnerTransfer, Stake, UnstakedToken, ACCRUED_REWARDS, CONFIG, OWNER_TRANSFER,
+    PLAYER_STAKES, PLAYER_STAKE_COUNT, RARITY_WEIGHTS, RETURN_REPLY_ID, REWARD_POOL, STAKES,
+    UNRETURNED,
+};
+
+const CONTRACT_NAME: &str = "crates.io:sysbreak-staking";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Most tokens one player can have staked; bounds the ClaimRewards loop
+const MAX_STAKES_PER_PLAYER: u32 = 50;
+const DEFAULT_QUERY_LIMIT: u32 = 30;
+const MAX_QUERY_LIMIT: u32 = 100;
+
+// ─── Instantiate ────────────────────────────────────────────────────────────
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if msg.epoch_seconds == 0 {
+        return Err(ContractError::InvalidEpoch);
+    }
+    for RarityWeight { rarity, weight } in &msg.rarity_weights {
+        if *weight == 0 {
+            return Err(ContractError::InvalidWeight);
+        }
+        RARITY_WEIGHTS.save(deps.storage, rarity, weight)?;
+    }
+    let config = Config {
+        owner: deps.api.addr_validate(&msg.owner)?,
+        nft_contract: deps.api.addr_validate(&msg.nft_contract)?,
+        reward_denom: msg.reward_denom,
+        epoch_seconds: msg.epoch_seconds,
+        reward_per_epoch: msg.reward_per_epoch,
+        paused: false,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    REWARD_POOL.save(deps.storage, &Uint128::zero())?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("contract", CONTRACT_NAME)
+        .add_attribute("owner", config.owner.as_str())
+        .add_attribute("nft_contract", config.nft_contract.as_str()))
+}
+
+// ─── Staking ────────────────────────────────────────────────────────────────
+
+/// SendNft hook. `info.sender` is the item contract, which has already moved
+/// the token here; `receive.sender` is the staker.
+pub fn execute_receive_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    receive: Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.nft_contract {
+        return Err(ContractError::WrongCollection {
+            expected: config.nft_contract.to_string(),
+        });
+    }
+    let nft_info: NftInfoRarity = deps.querier.query_wasm_smart(
+        &config.nft_contract,
+        &NftQueryMsg::NftInfo {
+            token_id: receive.token_id.clone(),
+        },
+    )?;
+    let rarity = nft_info.metadata.rarity;
+    let weight = RARITY_WEIGHTS
+        .may_load(deps.storage, &rarity)?
+        .ok_or_else(|| ContractError::RarityNotStakeable {
+            rarity: rarity.clone(),
+        })?;
+
+    let owner = deps.api.addr_validate(&receive.sender)?;
+    let count = PLAYER_STAKE_COUNT
+        .may_load(deps.storage, &owner)?
+        .unwrap_or_default();
+    if count >= MAX_STAKES_PER_PLAYER {
+        return Err(ContractError::StakeLimitReached {
+            max: MAX_STAKES_PER_PLAYER,
+        });
+    }
+    PLAYER_STAKE_COUNT.save(deps.storage, &owner, &(count + 1))?;
+    PLAYER_STAKES.save(deps.storage, (&owner, &receive.token_id), &true)?;
+    STAKES.save(
+        deps.storage,
+        &receive.token_id,
+        &Stake {
+            owner: owner.clone(),
+            rarity: rarity.clone(),
+            weight,
+            staked_at: env.block.time,
+            accrued_until: env.block.time,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "stake")
+        .add_attribute("token_id", &receive.token_id)
+        .add_attribute("owner", owner.as_str())
+        .add_attribute("rarity", rarity)
+        .add_attribute("weight", weight.to_string()))
+}
+
+/// Return a staked token. Works while paused so tokens are never stuck. The
+/// stake is settled and dropped before the transfer, and a refused transfer
+/// does not undo that: the token is parked for ReclaimNft instead.
+pub fn execute_unstake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let stake =
+        STAKES
+            .may_load(deps.storage, &token_id)?
+            .ok_or_else(|| ContractError::NotStaked {
+                token_id: token_id.clone(),
+            })?;
+    if info.sender != stake.owner {
+        return Err(ContractError::Unauthorized {
+            role: "staker".to_string(),
+        });
+    }
+
+    let (reward, _) = earned(&config, &stake, env.block.time);
+    if !reward.is_zero() {
+        ACCRUED_REWARDS.update(deps.storage, &stake.owner, |accrued| -> StdResult<_> {
+            Ok(accrued.unwrap_or_default() + reward)
+        })?;
+    }
+    STAKES.remove(deps.storage, &token_id);
+    PLAYER_STAKES.remove(deps.storage, (&stake.owner, &token_id));
+    PLAYER_STAKE_COUNT.update(deps.storage, &stake.owner, |count| -> StdResult<_> {
+        Ok(count.unwrap_or_default().saturating_sub(1))
+    })?;
+
+    Ok(Response::new()
+        .add_submessage(return_nft_msg(
+            &config.nft_contract,
+            &stake.owner,
+            &token_id,
+        )?)
+        .add_attribute("action", "unstake")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("owner", stake.owner.as_str())
+        .add_attribute("credited", reward.to_string()))
+}
+
+/// Retry returning a token whose transfer failed on unstake. Works while
+/// paused. Fails as a whole if the item contract still refuses, so the token
+/// stays reclaimable.
+pub fn execute_reclaim_nft(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let owner = UNRETURNED
+        .may_load(deps.storage, &token_id)?
+        .ok_or_else(|| ContractError::NothingToReclaim {
+            token_id: token_id.clone(),
+        })?;
+    if info.sender != owner {
+        return Err(ContractError::Unauthorized {
+            role: "staker".to_string(),
+        });
+    }
+    UNRETURNED.remove(deps.storage, &token_id);
+
+    Ok(Response::new()
+        .add_message(transfer_nft_msg(&config.nft_contract, &owner, &token_id)?)
+        .add_attribute("action", "reclaim_nft")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("owner", owner.as_str()))
+}
+
+/// Credit every staked token's completed epochs and pay out the caller's
+/// whole balance. Fails without paying anything if the pool cannot cover it.
+pub fn execute_claim_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_not_paused(deps.as_ref())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let token_ids: Vec<String> = PLAYER_STAKES
+        .prefix(&info.sender)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    let mut total = ACCRUED_REWARDS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    let mut updated = Vec::with_capacity(token_ids.len());
+    for token_id in token_ids {
+        let mut stake = STAKES.load(deps.storage, &token_id)?;
+        let (reward, until) = earned(&config, &stake, env.block.time);
+        total += reward;
+        stake.accrued_until = until;
+        updated.push((token_id, stake));
+    }
+    if total.is_zero() {
+        return Err(ContractError::NoRewards);
+    }
+    let pool = REWARD_POOL.load(deps.storage)?;
+    if total > pool {
+        return Err(ContractError::InsufficientRewardPool {
+            available: Coin::new(pool, &config.reward_denom).to_string(),
+            owed: Coin::new(total, &config.reward_denom).to_string(),
+        });
+    }
+
+    for (token_id, stake) in &updated {
+        STAKES.save(deps.storage, token_id, stake)?;
+    }
+    ACCRUED_REWARDS.remove(deps.storage, &info.sender);
+    REWARD_POOL.save(deps.storage, &(pool - total))?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin::new(total, &config.reward_denom)],
+        })
+        .add_attribute("action", "claim_rewards")
+        .add_event(ClaimEvent {
+            recipient: info.sender.to_string(),
+            amount: total.to_string(),
+            denom: config.reward_denom,
+        }))
+}
+
+// ─── Reward Pool ────────────────────────────────────────────────────────────
+
+pub fn execute_fund_rewards(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.as_ref(), &info.sender)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let amount = match info.funds.as_slice() {
+        [coin] if coin.denom == config.reward_denom && !coin.amount.is_zero() => coin.amount,
+        _ => {
+            return Err(ContractError::IncorrectPayment {
+                denom: config.reward_denom,
+            })
+        }
+    };
+    let pool = REWARD_POOL.load(deps.storage)? + amount;
+    REWARD_POOL.save(deps.storage, &pool)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "fund_rewards")
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("pool", pool.to_string()))
+}
+
+pub fn execute_withdraw_rewards(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let pool = REWARD_POOL.load(deps.storage)?;
+    if amount.is_zero() || amount > pool {
+        return Err(ContractError::InsufficientRewardPool {
+            available: Coin::new(pool, &config.reward_denom).to_string(),
+            owed: Coin::new(amount, &config.reward_denom).to_string(),
+        });
+    }
+    REWARD_POOL.save(deps.storage, &(pool - amount))?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin::new(amount, &config.reward_denom)],
+        })
+        .add_attribute("action", "withdraw_rewards")
+        .add_attribute("amount", amount.to_string()))
+}
+
+// ─── Admin ──────────────────────────────────────────────────────────────────
+
+pub fn execute_set_rarity_weight(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    rarity: String,
+    weight: Option<u64>,
+) -> Result<Response, ContractError> {
+    reject_funds(&info)?;
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    match weight {
+        Some(0) => return Err(ContractError::InvalidWeight),
+        Some(weight) => RARITY_WEIGHTS.save(deps.storage, &rarity, &weight)?,
+        None => RARITY_WEIGHTS.remove(deps.storage, &rarity),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_rarity_weight")
+        .add_attribute("rarity", rarity)
+        .add_attribute(
+            "weight",
+            weight.map_or("none".to_string(), |w| w.to_string()),
+        ))
+}
+
+pub fn execute_pause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(sysbreak_common::execute_pause(deps, &info, &CONFIG)?)
+}
+
+pub fn execute_unpause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(sysbreak_common::execute_unpause(deps, &info, &CONFIG)?)
+}
+
+pub fn execute_propose_owner(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    new_owner: String,
+) -> Result<Response, ContractError> {
+    let proposed_owner = deps.api.addr_validate(&new_owner)?;
+    Ok(OWNER_TRANSFER.propose(
+        deps,
+        &info,
+        &CONFIG,
+        PendingOwnerTransfer { proposed_owner },
+    )?)
+}
+
+pub fn execute_accept_owner(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(OWNER_TRANSFER.accept(deps, &info, &CONFIG)?)
+}
+
+pub fn execute_cancel_owner_transfer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    Ok(OWNER_TRANSFER.cancel(deps, &info, &CONFIG)?)
+}
+
+// ─── Reply ──────────────────────────────────────────────────────────────────
+
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        RETURN_REPLY_ID => reply_return(deps, msg),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+/// A token the owner could not take back stays here until ReclaimNft; the
+/// stake itself is already settled and closed.
+fn reply_return(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let error = match msg.result {
+        SubMsgResult::Ok(_) => return Ok(Response::new()),
+        SubMsgResult::Err(err) => err,
+    };
+    let UnstakedToken { token_id, owner } = from_json(&msg.payload)?;
+    UNRETURNED.save(deps.storage, &token_id, &owner)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "nft_return_failed")
+        .add_attribute("token_id", token_id)
+        .add_attribute("owner", owner.as_str())
+        .add_attribute("error", error))
+}
+
+// ─── Queries ────────────────────────────────────────────────────────────────
+
+pub fn query_config(deps: Deps) -> StdResult<Binary> {
+    to_json_binary(&CONFIG.load(deps.storage)?)
+}
+
+pub fn query_stake(deps: Deps, token_id: String) -> StdResult<Binary> {
+    to_json_binary(&STAKES.may_load(deps.storage, &token_id)?)
+}
+
+pub fn query_unreturned(deps: Deps, token_id: String) -> StdResult<Binary> {
+    to_json_binary(&UNRETURNED.may_load(deps.storage, &token_id)?)
+}
+
+pub fn query_staked_tokens(
+    deps: Deps,
+    env: Env,
+    player: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let player = deps.api.addr_validate(&player)?;
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let tokens: Vec<StakeInfo> = PLAYER_STAKES
+        .prefix(&player)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|token_id| {
+            let token_id = token_id?;
+            let stake = STAKES.load(deps.storage, &token_id)?;
+            let (pending, _) = earned(&config, &stake, env.block.time);
+            Ok(StakeInfo {
+                token_id,
+                stake,
+                pending,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&StakedTokensResponse { tokens })
+}
+
+pub fn query_pending_rewards(deps: Deps, env: Env, player: String) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let player = deps.api.addr_validate(&player)?;
+
+    let accrued = ACCRUED_REWARDS
+        .may_load(deps.storage, &player)?
+        .unwrap_or_default();
+    let mut pending = Uint128::zero();
+    for token_id in PLAYER_STAKES
+        .prefix(&player)
+        .keys(deps.storage, None, None, Order::Ascending)
+    {
+        let stake = STAKES.load(deps.storage, &token_id?)?;
+        pending += earned(&config, &stake, env.block.time).0;
+    }
+
+    to_json_binary(&PendingRewardsResponse {
+        player: player.to_string(),
+        accrued,
+        pending,
+        total: Coin::new(accrued + pending, &config.reward_denom),
+    })
+}
+
+pub fn query_reward_pool(deps: Deps) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let pool = REWARD_POOL.load(deps.storage)?;
+    to_json_binary(&Coin::new(pool, config.reward_denom))
+}
+
+pub fn query_rarity_weights(deps: Deps) -> StdResult<Binary> {
+    let weights: Vec<RarityWeight> = RARITY_WEIGHTS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(rarity, weight)| RarityWeight { rarity, weight }))
+        .collect::<StdResult<_>>()?;
+    to_json_binary(&RarityWeightsResponse { weights })
+}
+
+pub fn query_pending_owner(deps: Deps) -> StdResult<Binary> {
+    OWNER_TRANSFER.query_pending(deps)
+}
+
+// ─── Migrate ────────────────────────────────────────────────────────────────
+
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("version", CONTRACT_VERSION))
+}
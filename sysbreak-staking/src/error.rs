@@ -0,0 +1,48 @@
+use cosmwasm_std::StdError;
+use sysbreak_common::AdminError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Admin(#[from] AdminError),
+
+    #[error("unauthorized: only {role} can perform this action")]
+    Unauthorized { role: String },
+
+    #[error("only tokens from {expected} can be staked")]
+    WrongCollection { expected: String },
+
+    #[error("rarity {rarity} cannot be staked")]
+    RarityNotStakeable { rarity: String },
+
+    #[error("rarity weight must be non-zero")]
+    InvalidWeight,
+
+    #[error("epoch length must be non-zero")]
+    InvalidEpoch,
+
+    #[error("token is not staked: {token_id}")]
+    NotStaked { token_id: String },
+
+    #[error("stake limit reached: at most {max} tokens per player")]
+    StakeLimitReached { max: u32 },
+
+    #[error("no rewards to claim")]
+    NoRewards,
+
+    #[error("reward pool has {available} left, not enough to pay {owed}")]
+    InsufficientRewardPool { available: String, owed: String },
+
+    #[error("payment must be a single {denom} coin")]
+    IncorrectPayment { denom: String },
+
+    #[error("no unreturned token {token_id}")]
+    NothingToReclaim { token_id: String },
+
+    #[error("unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
+}
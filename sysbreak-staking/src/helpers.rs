@@ -0,0 +1,61 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, CosmosMsg, Deps, StdResult, SubMsg, Timestamp, Uint128, WasmMsg,
+};
+
+use crate::error::ContractError;
+use crate::msg::NftExecuteMsg;
+use crate::state::{Config, Stake, UnstakedToken, CONFIG, RETURN_REPLY_ID};
+
+/// Verify the caller is the contract owner.
+pub fn assert_owner(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    Ok(sysbreak_common::assert_owner(deps, &CONFIG, sender)?)
+}
+
+/// Verify the contract is not paused.
+pub fn assert_not_paused(deps: Deps) -> Result<(), ContractError> {
+    Ok(sysbreak_common::assert_not_paused(deps, &CONFIG)?)
+}
+
+/// Message returning a staked token to `recipient`.
+pub fn transfer_nft_msg(
+    collection: &Addr,
+    recipient: &Addr,
+    token_id: &str,
+) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: collection.to_string(),
+        msg: to_json_binary(&NftExecuteMsg::TransferNft {
+            recipient: recipient.to_string(),
+            token_id: token_id.to_string(),
+        })?,
+        funds: vec![],
+    }
+    .into())
+}
+
+/// Return an unstaked token to its owner. A failed transfer comes back
+/// through `reply` and leaves the token for ReclaimNft.
+pub fn return_nft_msg(collection: &Addr, owner: &Addr, token_id: &str) -> StdResult<SubMsg> {
+    Ok(SubMsg::reply_on_error(
+        transfer_nft_msg(collection, owner, token_id)?,
+        RETURN_REPLY_ID,
+    )
+    .with_payload(to_json_binary(&UnstakedToken {
+        token_id: token_id.to_string(),
+        owner: owner.clone(),
+    })?))
+}
+
+/// Reward earned by a stake for the full epochs completed since
+/// `accrued_until`, and the timestamp to advance `accrued_until` to.
+pub fn earned(config: &Config, stake: &Stake, now: Timestamp) -> (Uint128, Timestamp) {
+    let elapsed = now.seconds().saturating_sub(stake.accrued_until.seconds());
+    let epochs = elapsed / config.epoch_seconds;
+    let reward = config
+        .reward_per_epoch
+        .saturating_mul(Uint128::from(epochs) * Uint128::from(stake.weight));
+    let until = stake
+        .accrued_until
+        .plus_seconds(epochs * config.epoch_seconds);
+    (reward, until)
+}
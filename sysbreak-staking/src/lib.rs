@@ -0,0 +1,96 @@
+pub mod contract;
+pub mod error;
+pub mod helpers;
+pub mod msg;
+pub mod state;
+
+// Entry points are only referenced by the wasm export shims, which are not
+// generated for native targets.
+#[cfg(not(feature = "library"))]
+#[allow(dead_code)]
+mod entry {
+    use super::*;
+    use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
+    use msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+    #[entry_point]
+    pub fn instantiate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response, error::ContractError> {
+        contract::instantiate(deps, env, info, msg)
+    }
+
+    #[entry_point]
+    pub fn execute(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, error::ContractError> {
+        match msg {
+            ExecuteMsg::ReceiveNft(receive) => {
+                contract::execute_receive_nft(deps, env, info, receive)
+            }
+            ExecuteMsg::Unstake { token_id } => {
+                contract::execute_unstake(deps, env, info, token_id)
+            }
+            ExecuteMsg::ReclaimNft { token_id } => {
+                contract::execute_reclaim_nft(deps, env, info, token_id)
+            }
+            ExecuteMsg::ClaimRewards {} => contract::execute_claim_rewards(deps, env, info),
+            ExecuteMsg::FundRewards {} => contract::execute_fund_rewards(deps, env, info),
+            ExecuteMsg::WithdrawRewards { amount } => {
+                contract::execute_withdraw_rewards(deps, env, info, amount)
+            }
+            ExecuteMsg::SetRarityWeight { rarity, weight } => {
+                contract::execute_set_rarity_weight(deps, env, info, rarity, weight)
+            }
+            ExecuteMsg::Pause {} => contract::execute_pause(deps, env, info),
+            ExecuteMsg::Unpause {} => contract::execute_unpause(deps, env, info),
+            ExecuteMsg::ProposeOwner { new_owner } => {
+                contract::execute_propose_owner(deps, env, info, new_owner)
+            }
+            ExecuteMsg::AcceptOwner {} => contract::execute_accept_owner(deps, env, info),
+            ExecuteMsg::CancelOwnerTransfer {} => {
+                contract::execute_cancel_owner_transfer(deps, env, info)
+            }
+        }
+    }
+
+    #[entry_point]
+    pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> cosmwasm_std::StdResult<Binary> {
+        match msg {
+            QueryMsg::Config {} => contract::query_config(deps),
+            QueryMsg::Stake { token_id } => contract::query_stake(deps, token_id),
+            QueryMsg::StakedTokens {
+                player,
+                start_after,
+                limit,
+            } => contract::query_staked_tokens(deps, env, player, start_after, limit),
+            QueryMsg::Unreturned { token_id } => contract::query_unreturned(deps, token_id),
+            QueryMsg::PendingRewards { player } => {
+                contract::query_pending_rewards(deps, env, player)
+            }
+            QueryMsg::RewardPool {} => contract::query_reward_pool(deps),
+            QueryMsg::RarityWeights {} => contract::query_rarity_weights(deps),
+            QueryMsg::PendingOwner {} => contract::query_pending_owner(deps),
+        }
+    }
+
+    #[entry_point]
+    pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, error::ContractError> {
+        contract::reply(deps, env, msg)
+    }
+
+    #[entry_point]
+    pub fn migrate(
+        deps: DepsMut,
+        env: Env,
+        msg: MigrateMsg,
+    ) -> Result<Response, error::ContractError> {
+        contract::migrate(deps, env, msg)
+    }
+}
@@ -0,0 +1,155 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, Uint128};
+use cw721::receiver::Cw721ReceiveMsg;
+
+use crate::state::Stake;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Contract owner address
+    pub owner: String,
+    /// Item NFT contract whose tokens can be staked
+    pub nft_contract: String,
+    /// Native denom rewards are paid in
+    pub reward_denom: String,
+    /// Reward epoch length in seconds
+    pub epoch_seconds: u64,
+    /// Reward per weight unit per full epoch
+    pub reward_per_epoch: Uint128,
+    /// Stakeable rarities and their weights
+    pub rarity_weights: Vec<RarityWeight>,
+}
+
+#[cw_serde]
+pub struct RarityWeight {
+    pub rarity: String,
+    pub weight: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// CW-721 receive hook: SendNft from the item contract stakes the token
+    /// for `receive.sender`. The embedded msg is ignored.
+    ReceiveNft(Cw721ReceiveMsg),
+    /// Return a staked token to its owner. Full epochs staked so far are
+    /// credited for ClaimRewards; the partial epoch is forfeited. The stake
+    /// is closed even if the item contract refuses the transfer (e.g. the
+    /// item was consumed or locked); the token is then left for ReclaimNft.
+    Unstake {
+        token_id: String,
+    },
+    /// Retry handing back a token whose return failed on unstake (its owner only)
+    ReclaimNft {
+        token_id: String,
+    },
+    /// Pay out all rewards earned by the caller's staked and unstaked tokens
+    ClaimRewards {},
+    /// Add the attached reward-denom funds to the reward pool (owner only)
+    FundRewards {},
+    /// Take funds back out of the reward pool (owner only)
+    WithdrawRewards {
+        amount: Uint128,
+    },
+    /// Set or remove (`None`) a rarity's weight (owner only). Applies to
+    /// tokens staked afterwards.
+    SetRarityWeight {
+        rarity: String,
+        weight: Option<u64>,
+    },
+    /// Pause staking and claims (owner only)
+    Pause {},
+    /// Unpause the contract (owner only)
+    Unpause {},
+    ProposeOwner {
+        new_owner: String,
+    },
+    AcceptOwner {},
+    CancelOwnerTransfer {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Get contract configuration
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Get a staked token's record
+    #[returns(Option<crate::state::Stake>)]
+    Stake { token_id: String },
+    /// List a player's staked tokens with their pending rewards, paginated by token_id
+    #[returns(StakedTokensResponse)]
+    StakedTokens {
+        player: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Owner of a token whose return failed on unstake, if any
+    #[returns(Option<cosmwasm_std::Addr>)]
+    Unreturned { token_id: String },
+    /// Rewards a player could claim right now
+    #[returns(PendingRewardsResponse)]
+    PendingRewards { player: String },
+    /// Funds left in the reward pool
+    #[returns(cosmwasm_std::Coin)]
+    RewardPool {},
+    /// List stakeable rarities and their weights
+    #[returns(RarityWeightsResponse)]
+    RarityWeights {},
+    #[returns(Option<crate::state::PendingOwnerTransfer>)]
+    PendingOwner {},
+}
+
+#[cw_serde]
+pub struct StakeInfo {
+    pub token_id: String,
+    pub stake: Stake,
+    /// Rewards earned by this token since it was last credited
+    pub pending: Uint128,
+}
+
+#[cw_serde]
+pub struct StakedTokensResponse {
+    pub tokens: Vec<StakeInfo>,
+}
+
+#[cw_serde]
+pub struct PendingRewardsResponse {
+    pub player: String,
+    /// Credited from unstaked tokens and earlier accruals
+    pub accrued: Uint128,
+    /// Earned by currently staked tokens
+    pub pending: Uint128,
+    /// accrued + pending
+    pub total: Coin,
+}
+
+#[cw_serde]
+pub struct RarityWeightsResponse {
+    pub weights: Vec<RarityWeight>,
+}
+
+// ─── Messages sent to the item NFT contract ─────────────────────────────────
+
+#[cw_serde]
+pub enum NftExecuteMsg {
+    TransferNft { recipient: String, token_id: String },
+}
+
+#[cw_serde]
+pub enum NftQueryMsg {
+    NftInfo { token_id: String },
+}
+
+/// The only NftInfo field staking reads; other fields are ignored.
+#[derive(serde::Deserialize)]
+pub struct NftInfoRarity {
+    pub metadata: ItemRarity,
+}
+
+#[derive(serde::Deserialize)]
+pub struct ItemRarity {
+    pub rarity: String,
+}
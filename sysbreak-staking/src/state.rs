@@ -0,0 +1,86 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+pub use sysbreak_common::PendingOwnerTransfer;
+use sysbreak_common::{Owned, Pausable, RoleTransfer};
+
+/// Contract-level configuration
+#[cw_serde]
+pub struct Config {
+    /// Contract owner — funds the reward pool and manages rarity weights
+    pub owner: Addr,
+    /// Item NFT contract whose tokens can be staked
+    pub nft_contract: Addr,
+    /// Native denom rewards are paid in (e.g. "ushido" or the credit denom)
+    pub reward_denom: String,
+    /// Length of a reward epoch in seconds
+    pub epoch_seconds: u64,
+    /// Reward paid per weight unit for every full epoch staked
+    pub reward_per_epoch: Uint128,
+    /// Whether the contract is paused (freezes staking and claims; unstaking stays open)
+    pub paused: bool,
+}
+
+impl Owned for Config {
+    fn owner(&self) -> &Addr {
+        &self.owner
+    }
+
+    fn set_owner(&mut self, owner: Addr) {
+        self.owner = owner;
+    }
+}
+
+impl Pausable for Config {
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+}
+
+/// A staked item NFT, held by this contract
+#[cw_serde]
+pub struct Stake {
+    pub owner: Addr,
+    pub rarity: String,
+    /// Rarity weight captured at stake time; later weight changes do not apply
+    pub weight: u64,
+    pub staked_at: Timestamp,
+    /// Start of the first epoch not yet credited to the owner
+    pub accrued_until: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const OWNER_TRANSFER: RoleTransfer<PendingOwnerTransfer> =
+    RoleTransfer::new("owner", "pending_owner");
+
+/// Reward weight per rarity: rarity -> weight. Rarities without a weight cannot be staked.
+pub const RARITY_WEIGHTS: Map<&str, u64> = Map::new("rarity_weights");
+
+/// token_id -> Stake
+pub const STAKES: Map<&str, Stake> = Map::new("stakes");
+/// Per-player index of staked tokens: (owner, token_id) -> bool
+pub const PLAYER_STAKES: Map<(&Addr, &str), bool> = Map::new("player_stakes");
+/// Player's staked token count, bounding the claim loop
+pub const PLAYER_STAKE_COUNT: Map<&Addr, u32> = Map::new("player_stake_count");
+
+/// Rewards credited to a player but not yet claimed (e.g. from unstaked tokens)
+pub const ACCRUED_REWARDS: Map<&Addr, Uint128> = Map::new("accrued_rewards");
+/// Tokens whose return failed on unstake: token_id -> owner, until ReclaimNft
+pub const UNRETURNED: Map<&str, Addr> = Map::new("unreturned");
+
+/// Reply id for the token transfer sent on unstake
+pub const RETURN_REPLY_ID: u64 = 1;
+
+/// Payload carried through the unstake transfer's reply
+#[cw_serde]
+pub struct UnstakedToken {
+    pub token_id: String,
+    pub owner: Addr,
+}
+
+/// Reward funds deposited by the owner and not yet paid out
+pub const REWARD_POOL: Item<Uint128> = Item::new("reward_pool");
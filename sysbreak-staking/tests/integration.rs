@@ -0,0 +1,513 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi, MockQuerier};
+use cosmwasm_std::{
+    coin, from_json, to_json_binary, Addr, BankMsg, ContractResult, CosmosMsg, Env, Event,
+    MemoryStorage, OwnedDeps, Reply, Response, SubMsgResult, SystemError, SystemResult, Uint128,
+    WasmMsg, WasmQuery,
+};
+use cw721::receiver::Cw721ReceiveMsg;
+
+use sysbreak_common::AdminError;
+use sysbreak_events::ClaimEvent;
+use sysbreak_staking::contract::*;
+use sysbreak_staking::error::ContractError;
+use sysbreak_staking::msg::*;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const DENOM: &str = "ushido";
+const EPOCH: u64 = 86_400;
+
+fn addr(deps: &Deps, name: &str) -> Addr {
+    deps.api.addr_make(name)
+}
+
+/// Trimmed item NftInfo response; the real one carries more fields, which
+/// staking ignores
+#[cosmwasm_schema::cw_serde]
+struct NftInfo {
+    token_id: String,
+    metadata: Metadata,
+}
+
+#[cosmwasm_schema::cw_serde]
+struct Metadata {
+    item_type: String,
+    rarity: String,
+}
+
+/// Token "1" is Common, "2" Legendary and "3" Mythic (not stakeable). Common
+/// weighs 1 and Legendary 5, at 100 ushido per weight unit per daily epoch.
+fn setup_contract() -> Deps {
+    let mut deps = mock_dependencies();
+    let owner = deps.api.addr_make("owner");
+    let item_nft = deps.api.addr_make("item_nft").to_string();
+
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { msg, .. } => {
+            let NftQueryMsg::NftInfo { token_id } = from_json(msg).unwrap();
+            let rarity = match token_id.as_str() {
+                "1" => "Common",
+                "2" => "Legendary",
+                _ => "Mythic",
+            };
+            let res = to_json_binary(&NftInfo {
+                token_id,
+                metadata: Metadata {
+                    item_type: "weapon".to_string(),
+                    rarity: rarity.to_string(),
+                },
+            });
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        }
+        _ => SystemResult::Err(SystemError::Unknown {}),
+    });
+
+    let msg = InstantiateMsg {
+        owner: owner.to_string(),
+        nft_contract: item_nft,
+        reward_denom: DENOM.to_string(),
+        epoch_seconds: EPOCH,
+        reward_per_epoch: Uint128::new(100),
+        rarity_weights: vec![
+            RarityWeight {
+                rarity: "Common".to_string(),
+                weight: 1,
+            },
+            RarityWeight {
+                rarity: "Legendary".to_string(),
+                weight: 5,
+            },
+        ],
+    };
+    instantiate(deps.as_mut(), mock_env(), message_info(&owner, &[]), msg).unwrap();
+    deps
+}
+
+fn stake(deps: &mut Deps, staker: &Addr, token_id: &str) -> Result<Response, ContractError> {
+    let item_nft = addr(deps, "item_nft");
+    execute_receive_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&item_nft, &[]),
+        Cw721ReceiveMsg {
+            sender: staker.to_string(),
+            token_id: token_id.to_string(),
+            msg: Default::default(),
+        },
+    )
+}
+
+fn fund(deps: &mut Deps, amount: u128) {
+    let owner = addr(deps, "owner");
+    execute_fund_rewards(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[coin(amount, DENOM)]),
+    )
+    .unwrap();
+}
+
+fn env_after(seconds: u64) -> Env {
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(seconds);
+    env
+}
+
+fn messages(res: &Response) -> Vec<CosmosMsg> {
+    res.messages.iter().map(|m| m.msg.clone()).collect()
+}
+
+fn bank_msg(to: &Addr, amount: u128) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: to.to_string(),
+        amount: vec![coin(amount, DENOM)],
+    }
+    .into()
+}
+
+// ─── Staking ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_stake_records_rarity_weight() {
+    let mut deps = setup_contract();
+    let alice = addr(&deps, "alice");
+
+    let res = stake(&mut deps, &alice, "2").unwrap();
+    assert_eq!(res.attributes[4].value, "5");
+
+    let staked: StakedTokensResponse = from_json(
+        query_staked_tokens(deps.as_ref(), mock_env(), alice.to_string(), None, None).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(staked.tokens.len(), 1);
+    assert_eq!(staked.tokens[0].token_id, "2");
+    assert_eq!(staked.tokens[0].stake.owner, alice);
+    assert_eq!(staked.tokens[0].stake.rarity, "Legendary");
+}
+
+#[test]
+fn test_stake_rejections() {
+    let mut deps = setup_contract();
+    let alice = addr(&deps, "alice");
+
+    let err = stake(&mut deps, &alice, "3").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RarityNotStakeable {
+            rarity: "Mythic".to_string()
+        }
+    );
+
+    let other_nft = addr(&deps, "other_nft");
+    let err = execute_receive_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&other_nft, &[]),
+        Cw721ReceiveMsg {
+            sender: alice.to_string(),
+            token_id: "1".to_string(),
+            msg: Default::default(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::WrongCollection {
+            expected: addr(&deps, "item_nft").to_string()
+        }
+    );
+}
+
+#[test]
+fn test_unstake_returns_token_and_credits_full_epochs() {
+    let mut deps = setup_contract();
+    let alice = addr(&deps, "alice");
+    let bob = addr(&deps, "bob");
+    let item_nft = addr(&deps, "item_nft");
+    stake(&mut deps, &alice, "1").unwrap();
+
+    let err = execute_unstake(
+        deps.as_mut(),
+        env_after(EPOCH),
+        message_info(&bob, &[]),
+        "1".to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "staker".to_string()
+        }
+    );
+
+    // Two full epochs and a partial one: the partial epoch is forfeited
+    let res = execute_unstake(
+        deps.as_mut(),
+        env_after(EPOCH * 5 / 2),
+        message_info(&alice, &[]),
+        "1".to_string(),
+    )
+    .unwrap();
+    assert_eq!(
+        messages(&res),
+        vec![CosmosMsg::from(WasmMsg::Execute {
+            contract_addr: item_nft.to_string(),
+            msg: to_json_binary(&NftExecuteMsg::TransferNft {
+                recipient: alice.to_string(),
+                token_id: "1".to_string(),
+            })
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+
+    let pending: PendingRewardsResponse = from_json(
+        query_pending_rewards(deps.as_ref(), env_after(EPOCH * 10), alice.to_string()).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(pending.accrued, Uint128::new(200));
+    assert_eq!(pending.pending, Uint128::zero());
+
+    let err = execute_unstake(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &[]),
+        "1".to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotStaked {
+            token_id: "1".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_unstake_closes_stake_when_token_cannot_be_returned() {
+    let mut deps = setup_contract();
+    let alice = addr(&deps, "alice");
+    let bob = addr(&deps, "bob");
+    let item_nft = addr(&deps, "item_nft");
+    stake(&mut deps, &alice, "1").unwrap();
+
+    // The item was consumed or locked while staked: the transfer bounces
+    let res = execute_unstake(
+        deps.as_mut(),
+        env_after(EPOCH * 2),
+        message_info(&alice, &[]),
+        "1".to_string(),
+    )
+    .unwrap();
+    let reply_msg = Reply {
+        id: res.messages[0].id,
+        payload: res.messages[0].payload.clone(),
+        gas_used: 0,
+        result: SubMsgResult::Err("token is locked".to_string()),
+    };
+    reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+
+    // The stake is settled and gone; only the token is left behind
+    let stake_record: Option<sysbreak_staking::state::Stake> =
+        from_json(query_stake(deps.as_ref(), "1".to_string()).unwrap()).unwrap();
+    assert!(stake_record.is_none());
+    let pending: PendingRewardsResponse = from_json(
+        query_pending_rewards(deps.as_ref(), env_after(EPOCH * 10), alice.to_string()).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(pending.accrued, Uint128::new(200));
+    assert_eq!(pending.pending, Uint128::zero());
+    let unreturned: Option<Addr> =
+        from_json(query_unreturned(deps.as_ref(), "1".to_string()).unwrap()).unwrap();
+    assert_eq!(unreturned, Some(alice.clone()));
+
+    let err = execute_reclaim_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&bob, &[]),
+        "1".to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized {
+            role: "staker".to_string()
+        }
+    );
+    let res = execute_reclaim_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &[]),
+        "1".to_string(),
+    )
+    .unwrap();
+    assert_eq!(
+        messages(&res),
+        vec![CosmosMsg::from(WasmMsg::Execute {
+            contract_addr: item_nft.to_string(),
+            msg: to_json_binary(&NftExecuteMsg::TransferNft {
+                recipient: alice.to_string(),
+                token_id: "1".to_string(),
+            })
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+    let err = execute_reclaim_nft(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &[]),
+        "1".to_string(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NothingToReclaim {
+            token_id: "1".to_string()
+        }
+    );
+}
+
+// ─── Rewards ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_claim_rewards_weighted_by_rarity() {
+    let mut deps = setup_contract();
+    let alice = addr(&deps, "alice");
+    stake(&mut deps, &alice, "1").unwrap();
+    stake(&mut deps, &alice, "2").unwrap();
+    fund(&mut deps, 10_000);
+
+    let err = execute_claim_rewards(
+        deps.as_mut(),
+        env_after(EPOCH - 1),
+        message_info(&alice, &[]),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoRewards);
+
+    // (1 + 5) weight * 100 * 3 epochs
+    let res = execute_claim_rewards(
+        deps.as_mut(),
+        env_after(EPOCH * 3),
+        message_info(&alice, &[]),
+    )
+    .unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&alice, 1_800)]);
+    assert_eq!(
+        res.events,
+        vec![Event::from(ClaimEvent {
+            recipient: alice.to_string(),
+            amount: "1800".to_string(),
+            denom: DENOM.to_string(),
+        })]
+    );
+
+    // Claimed epochs are not paid twice
+    let err = execute_claim_rewards(
+        deps.as_mut(),
+        env_after(EPOCH * 3 + 10),
+        message_info(&alice, &[]),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoRewards);
+    let res = execute_claim_rewards(
+        deps.as_mut(),
+        env_after(EPOCH * 4),
+        message_info(&alice, &[]),
+    )
+    .unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&alice, 600)]);
+
+    let pool: cosmwasm_std::Coin = from_json(query_reward_pool(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(pool, coin(7_600, DENOM));
+}
+
+#[test]
+fn test_claim_fails_when_pool_is_short() {
+    let mut deps = setup_contract();
+    let alice = addr(&deps, "alice");
+    stake(&mut deps, &alice, "2").unwrap();
+    fund(&mut deps, 499);
+
+    let err = execute_claim_rewards(deps.as_mut(), env_after(EPOCH), message_info(&alice, &[]))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientRewardPool {
+            available: coin(499, DENOM).to_string(),
+            owed: coin(500, DENOM).to_string(),
+        }
+    );
+
+    // Nothing was credited, so the same epoch pays out once the pool is topped up
+    fund(&mut deps, 1);
+    let res =
+        execute_claim_rewards(deps.as_mut(), env_after(EPOCH), message_info(&alice, &[])).unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&alice, 500)]);
+}
+
+#[test]
+fn test_reward_pool_owner_only() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let alice = addr(&deps, "alice");
+
+    let err = execute_fund_rewards(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &[coin(100, DENOM)]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Admin(AdminError::Unauthorized {
+            role: "owner".to_string()
+        })
+    );
+    let err = execute_fund_rewards(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[coin(100, "uatom")]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::IncorrectPayment {
+            denom: DENOM.to_string()
+        }
+    );
+
+    fund(&mut deps, 1_000);
+    let res = execute_withdraw_rewards(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        Uint128::new(400),
+    )
+    .unwrap();
+    assert_eq!(messages(&res), vec![bank_msg(&owner, 400)]);
+    let pool: cosmwasm_std::Coin = from_json(query_reward_pool(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(pool, coin(600, DENOM));
+}
+
+#[test]
+fn test_rarity_weight_changes_apply_to_new_stakes() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let alice = addr(&deps, "alice");
+    stake(&mut deps, &alice, "1").unwrap();
+
+    let err = execute_set_rarity_weight(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "Common".to_string(),
+        Some(0),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidWeight);
+    execute_set_rarity_weight(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        "Common".to_string(),
+        None,
+    )
+    .unwrap();
+
+    let pending: PendingRewardsResponse = from_json(
+        query_pending_rewards(deps.as_ref(), env_after(EPOCH), alice.to_string()).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(pending.total, coin(100, DENOM));
+
+    let weights: RarityWeightsResponse =
+        from_json(query_rarity_weights(deps.as_ref()).unwrap()).unwrap();
+    assert_eq!(weights.weights.len(), 1);
+    assert_eq!(weights.weights[0].rarity, "Legendary");
+}
+
+#[test]
+fn test_pause_keeps_unstake_open() {
+    let mut deps = setup_contract();
+    let owner = addr(&deps, "owner");
+    let alice = addr(&deps, "alice");
+    stake(&mut deps, &alice, "1").unwrap();
+    execute_pause(deps.as_mut(), mock_env(), message_info(&owner, &[])).unwrap();
+
+    assert_eq!(
+        stake(&mut deps, &alice, "2").unwrap_err(),
+        ContractError::Admin(AdminError::Paused)
+    );
+    let err = execute_claim_rewards(deps.as_mut(), env_after(EPOCH), message_info(&alice, &[]))
+        .unwrap_err();
+    assert_eq!(err, ContractError::Admin(AdminError::Paused));
+    execute_unstake(
+        deps.as_mut(),
+        env_after(EPOCH),
+        message_info(&alice, &[]),
+        "1".to_string(),
+    )
+    .unwrap();
+}